@@ -54,6 +54,8 @@ pub struct Metadata {
     workspace_members: Vec<MetadataId>,
     /// Workspace root path.
     workspace_root: PathBuf,
+    /// `offline-dir` in `[workspace.metadata.sqlx]`, relative to the workspace root.
+    workspace_offline_dir: Option<PathBuf>,
     /// Maps each dependency to its set of dependents
     reverse_deps: BTreeMap<MetadataId, BTreeSet<MetadataId>>,
     /// The target directory of the project
@@ -95,6 +97,16 @@ impl Metadata {
         &self.workspace_root
     }
 
+    /// The directory for the query cache shared by the crates of the workspace:
+    /// `offline-dir` in `[workspace.metadata.sqlx]` if set, or `.sqlx` in the workspace root.
+    pub fn workspace_offline_dir(&self) -> PathBuf {
+        self.workspace_root.join(
+            self.workspace_offline_dir
+                .as_deref()
+                .unwrap_or(Path::new(".sqlx")),
+        )
+    }
+
     pub fn target_directory(&self) -> &Path {
         &self.target_directory
     }
@@ -141,9 +153,19 @@ impl FromStr for Metadata {
             workspace_root,
             resolve,
             target_directory,
+            workspace_metadata,
             ..
         } = cargo_metadata;
 
+        let workspace_offline_dir = workspace_metadata
+            .pointer("/sqlx/offline-dir")
+            .map(|dir| {
+                dir.as_str()
+                    .map(PathBuf::from)
+                    .context("`offline-dir` in `[workspace.metadata.sqlx]` must be a string")
+            })
+            .transpose()?;
+
         let mut packages = BTreeMap::new();
         for metadata_package in metadata_packages {
             let package = Package::from(&metadata_package);
@@ -171,6 +193,7 @@ impl FromStr for Metadata {
             packages,
             workspace_members,
             workspace_root,
+            workspace_offline_dir,
             reverse_deps,
             target_directory,
             current_package,
@@ -33,6 +33,9 @@ pub enum Command {
 
         /// Generate a single workspace-level `.sqlx` folder.
         ///
+        /// The folder can be changed with `offline-dir` in `[workspace.metadata.sqlx]`,
+        /// relative to the workspace root.
+        ///
         /// This option is intended for workspaces where multiple crates use SQLx. If there is only
         /// one, it is better to run `cargo sqlx prepare` without this option inside that crate.
         #[clap(long)]
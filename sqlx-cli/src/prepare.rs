@@ -24,7 +24,7 @@ impl PrepareCtx {
     /// Path to the directory where cached queries should be placed.
     fn prepare_dir(&self) -> anyhow::Result<PathBuf> {
         if self.workspace {
-            Ok(self.metadata.workspace_root().join(".sqlx"))
+            Ok(self.metadata.workspace_offline_dir())
         } else {
             Ok(manifest_dir(&self.cargo)?.join(".sqlx"))
         }
@@ -71,15 +71,19 @@ async fn prepare(ctx: &PrepareCtx) -> anyhow::Result<()> {
     run_prepare_step(ctx, &prepare_dir)?;
 
     // Warn if no queries were generated. Glob since the directory may contain unrelated files.
-    if glob_query_files(prepare_dir)?.is_empty() {
+    if glob_query_files(&prepare_dir)?.is_empty() {
         println!("{} no queries found", style("warning:").yellow());
         return Ok(());
     }
 
     if ctx.workspace {
         println!(
-            "query data written to .sqlx in the workspace root; \
-             please check this into version control"
+            "query data written to {} in the workspace; \
+             please check this into version control",
+            prepare_dir
+                .strip_prefix(ctx.metadata.workspace_root())
+                .unwrap_or(&prepare_dir)
+                .display()
         );
     } else {
         println!(
@@ -380,4 +384,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn workspace_offline_dir_is_configurable() -> anyhow::Result<()> {
+        let sample_metadata_path = Path::new("tests")
+            .join("assets")
+            .join("sample_metadata.json");
+        let sample_metadata = std::fs::read_to_string(sample_metadata_path)?;
+        let metadata: Metadata = sample_metadata.parse()?;
+
+        assert_eq!(
+            metadata.workspace_offline_dir(),
+            metadata.workspace_root().join(".sqlx")
+        );
+
+        // `[workspace.metadata.sqlx]` is passed through as `metadata` by `cargo metadata`
+        let mut json: serde_json::Value = serde_json::from_str(&sample_metadata)?;
+        json["metadata"] = serde_json::json!({ "sqlx": { "offline-dir": "cache/sqlx" } });
+        let metadata: Metadata = json.to_string().parse()?;
+
+        assert_eq!(
+            metadata.workspace_offline_dir(),
+            metadata.workspace_root().join("cache/sqlx")
+        );
+
+        Ok(())
+    }
 }
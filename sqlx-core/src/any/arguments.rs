@@ -1,10 +1,16 @@
+use std::error::Error as StdError;
+use std::fmt::{self, Display, Formatter};
+
+use crate::any::types::repr::{AnyDate, AnyDecimal, AnyTime, AnyTimestamp, AnyUuid};
 use crate::any::value::AnyValueKind;
-use crate::any::Any;
+use crate::any::{Any, AnyTypeInfo};
 use crate::arguments::Arguments;
+use crate::database::Database;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::types::Type;
 
+#[derive(Clone)]
 pub struct AnyArguments<'q> {
     #[doc(hidden)]
     pub values: AnyArgumentBuffer<'q>,
@@ -28,8 +34,13 @@ impl<'q> Arguments<'q> for AnyArguments<'q> {
     fn len(&self) -> usize {
         self.values.0.len()
     }
+
+    fn private_try_clone(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
 
+#[derive(Clone)]
 pub struct AnyArgumentBuffer<'q>(#[doc(hidden)] pub Vec<AnyValueKind<'q>>);
 
 impl<'q> Default for AnyArguments<'q> {
@@ -54,11 +65,18 @@ impl<'q> AnyArguments<'q> {
         f64: Type<A::Database> + Encode<'a, A::Database>,
         &'a str: Type<A::Database> + Encode<'a, A::Database>,
         &'a [u8]: Type<A::Database> + Encode<'a, A::Database>,
+        AnyDecimal: Type<A::Database> + Encode<'a, A::Database>,
+        AnyDate: Type<A::Database> + Encode<'a, A::Database>,
+        AnyTime: Type<A::Database> + Encode<'a, A::Database>,
+        AnyTimestamp: Type<A::Database> + Encode<'a, A::Database>,
+        AnyUuid: Type<A::Database> + Encode<'a, A::Database>,
     {
         let mut out = A::default();
+        let mut errors = Vec::new();
 
-        for arg in &self.values.0 {
-            match arg {
+        // every argument is converted, so all of the incompatible ones can be reported at once
+        for (index, arg) in self.values.0.iter().enumerate() {
+            let result = match arg {
                 AnyValueKind::Null => out.add(Option::<i32>::None),
                 AnyValueKind::Bool(b) => out.add(b),
                 AnyValueKind::SmallInt(i) => out.add(i),
@@ -68,9 +86,123 @@ impl<'q> AnyArguments<'q> {
                 AnyValueKind::Double(d) => out.add(d),
                 AnyValueKind::Text(t) => out.add(&**t),
                 AnyValueKind::Blob(b) => out.add(&**b),
-            }?
+                AnyValueKind::Decimal(d) => out.add(AnyDecimal(d.to_string())),
+                AnyValueKind::Date(d) => out.add(*d),
+                AnyValueKind::Time(t) => out.add(*t),
+                AnyValueKind::Timestamp(t) => out.add(*t),
+                AnyValueKind::Uuid(u) => out.add(*u),
+            };
+
+            if let Err(error) = result {
+                errors.push(AnyArgumentError {
+                    index,
+                    type_info: arg.type_info(),
+                    error,
+                });
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(Box::new(AnyArgumentsError {
+                database: <A::Database as Database>::NAME,
+                arguments: self.values.0.len(),
+                errors,
+            }));
         }
 
         Ok(out)
     }
 }
+
+/// The arguments of a query through an [`AnyConnection`][crate::any::AnyConnection] which could
+/// not be converted for its database driver.
+///
+/// Returned as the source of [`Error::Encode`][crate::error::Error::Encode], and lists every
+/// argument which failed, not only the first.
+#[derive(Debug)]
+pub struct AnyArgumentsError {
+    database: &'static str,
+    arguments: usize,
+    errors: Vec<AnyArgumentError>,
+}
+
+/// An argument which could not be converted, from [`AnyArgumentsError::errors()`].
+#[derive(Debug)]
+pub struct AnyArgumentError {
+    index: usize,
+    type_info: AnyTypeInfo,
+    error: BoxDynError,
+}
+
+impl AnyArgumentsError {
+    /// The name of the database the arguments were converted for, e.g. `PostgreSQL`.
+    pub fn database(&self) -> &'static str {
+        self.database
+    }
+
+    /// The arguments which could not be converted, in order.
+    pub fn errors(&self) -> &[AnyArgumentError] {
+        &self.errors
+    }
+}
+
+impl AnyArgumentError {
+    /// The zero-based index of the argument in the query.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The type of the argument as it was bound.
+    pub fn type_info(&self) -> &AnyTypeInfo {
+        &self.type_info
+    }
+
+    /// The error from encoding the argument for the database.
+    pub fn error(&self) -> &(dyn StdError + Send + Sync + 'static) {
+        &*self.error
+    }
+}
+
+impl Display for AnyArgumentsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} arguments could not be encoded for {}",
+            self.errors.len(),
+            self.arguments,
+            self.database
+        )?;
+
+        for error in &self.errors {
+            write!(f, "\n  {error}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for AnyArgumentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "argument {} ({}): {}",
+            self.index + 1,
+            self.type_info,
+            self.error
+        )
+    }
+}
+
+impl StdError for AnyArgumentsError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.errors
+            .first()
+            .map(|error| error as &(dyn StdError + 'static))
+    }
+}
+
+impl StdError for AnyArgumentError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&*self.error)
+    }
+}
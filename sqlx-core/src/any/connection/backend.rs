@@ -1,9 +1,11 @@
 use crate::any::{Any, AnyArguments, AnyQueryResult, AnyRow, AnyStatement, AnyTypeInfo};
 use crate::describe::Describe;
+use crate::transaction::TransactionOptions;
 use either::Either;
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 use std::fmt::Debug;
+use std::time::Duration;
 
 pub trait AnyConnectionBackend: std::any::Any + Debug + Send + 'static {
     /// The backend name.
@@ -23,13 +25,30 @@ pub trait AnyConnectionBackend: std::any::Any + Debug + Send + 'static {
     fn close_hard(self: Box<Self>) -> BoxFuture<'static, crate::Result<()>>;
 
     /// Checks if a connection to the database is still valid.
-    fn ping(&mut self) -> BoxFuture<'_, crate::Result<()>>;
+    ///
+    /// Returns the time taken by the round-trip to the server.
+    fn ping(&mut self) -> BoxFuture<'_, crate::Result<Duration>>;
 
     /// Begin a new transaction or establish a savepoint within the active transaction.
     ///
     /// Returns a [`Transaction`] for controlling and tracking the new transaction.
     fn begin(&mut self) -> BoxFuture<'_, crate::Result<()>>;
 
+    /// Begin a new transaction with the given options, using the syntax of the backend.
+    ///
+    /// Fails if a transaction is already active.
+    fn begin_with(&mut self, options: TransactionOptions) -> BoxFuture<'_, crate::Result<()>> {
+        let _ = options;
+
+        let name = self.name().to_owned();
+
+        Box::pin(async move {
+            Err(crate::Error::Configuration(
+                format!("{name} does not support setting the options of a transaction").into(),
+            ))
+        })
+    }
+
     fn commit(&mut self) -> BoxFuture<'_, crate::Result<()>>;
 
     fn rollback(&mut self) -> BoxFuture<'_, crate::Result<()>>;
@@ -47,11 +66,74 @@ pub trait AnyConnectionBackend: std::any::Any + Debug + Send + 'static {
         Box::pin(async move { Ok(()) })
     }
 
+    /// Forward to [`Connection::set_statement_timeout()`].
+    ///
+    /// [`Connection::set_statement_timeout()`]: method@crate::connection::Connection::set_statement_timeout
+    fn set_statement_timeout(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'_, crate::Result<()>> {
+        let _ = timeout;
+
+        let name = self.name().to_owned();
+
+        Box::pin(async move {
+            Err(crate::Error::Configuration(
+                format!("{name} does not support setting a statement timeout").into(),
+            ))
+        })
+    }
+
+    /// Forward to [`Connection::statement_timeout()`].
+    ///
+    /// [`Connection::statement_timeout()`]: method@crate::connection::Connection::statement_timeout
+    fn statement_timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Forward to [`Connection::supports_statement_timeout()`].
+    ///
+    /// [`Connection::supports_statement_timeout()`]: method@crate::connection::Connection::supports_statement_timeout
+    fn supports_statement_timeout(&self) -> bool {
+        false
+    }
+
+    /// Forward to [`HasReturningId::supports_returning()`].
+    ///
+    /// [`HasReturningId::supports_returning()`]: crate::database::HasReturningId::supports_returning
+    fn supports_returning(&self) -> bool {
+        false
+    }
+
+    /// Forward to [`Connection::estimate_rows()`].
+    ///
+    /// [`Connection::estimate_rows()`]: method@crate::connection::Connection::estimate_rows
+    fn estimate_rows<'e>(
+        &'e mut self,
+        table_or_query: &'e str,
+    ) -> BoxFuture<'e, crate::Result<u64>> {
+        let _ = table_or_query;
+
+        let name = self.name().to_owned();
+
+        Box::pin(async move {
+            Err(crate::Error::Configuration(
+                format!("{name} does not support estimating the number of rows").into(),
+            ))
+        })
+    }
+
     /// Forward to [`Connection::shrink_buffers()`].
     ///
     /// [`Connection::shrink_buffers()`]: method@crate::connection::Connection::shrink_buffers
     fn shrink_buffers(&mut self);
 
+    /// Forward to [`Connection::reset_on_release()`].
+    ///
+    /// [`Connection::reset_on_release()`]: method@crate::connection::Connection::reset_on_release
+    #[doc(hidden)]
+    fn reset_on_release(&mut self) {}
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, crate::Result<()>>;
 
@@ -19,6 +19,10 @@ impl<'c> Executor<'c> for &'c mut AnyConnection {
         'c: 'e,
         E: 'q + Execute<'q, Any>,
     {
+        if query.timeout().is_some() {
+            return stream::once(future::ready(Err(timeout_not_supported()))).boxed();
+        }
+
         let arguments = match query.take_arguments().map_err(Error::Encode) {
             Ok(arguments) => arguments,
             Err(error) => return stream::once(future::ready(Err(error))).boxed(),
@@ -34,6 +38,10 @@ impl<'c> Executor<'c> for &'c mut AnyConnection {
         'c: 'e,
         E: 'q + Execute<'q, Self::Database>,
     {
+        if query.timeout().is_some() {
+            return future::ready(Err(timeout_not_supported())).boxed();
+        }
+
         let arguments = match query.take_arguments().map_err(Error::Encode) {
             Ok(arguments) => arguments,
             Err(error) => return future::ready(Err(error)).boxed(),
@@ -62,3 +70,7 @@ impl<'c> Executor<'c> for &'c mut AnyConnection {
         self.backend.describe(sql)
     }
 }
+
+fn timeout_not_supported() -> Error {
+    Error::Configuration("the Any driver does not support per-query timeouts".into())
+}
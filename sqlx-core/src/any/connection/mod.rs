@@ -1,4 +1,5 @@
 use futures_core::future::BoxFuture;
+use std::time::Duration;
 
 use crate::any::{Any, AnyConnectOptions};
 use crate::connection::{ConnectOptions, Connection};
@@ -79,7 +80,7 @@ impl Connection for AnyConnection {
         self.backend.close()
     }
 
-    fn ping(&mut self) -> BoxFuture<'_, Result<(), Error>> {
+    fn ping(&mut self) -> BoxFuture<'_, Result<Duration, Error>> {
         self.backend.ping()
     }
 
@@ -98,10 +99,36 @@ impl Connection for AnyConnection {
         self.backend.clear_cached_statements()
     }
 
+    fn set_statement_timeout(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'_, crate::Result<()>> {
+        self.backend.set_statement_timeout(timeout)
+    }
+
+    fn statement_timeout(&self) -> Option<Duration> {
+        self.backend.statement_timeout()
+    }
+
+    fn supports_statement_timeout(&self) -> bool {
+        self.backend.supports_statement_timeout()
+    }
+
+    fn estimate_rows<'e>(
+        &'e mut self,
+        table_or_query: &'e str,
+    ) -> BoxFuture<'e, crate::Result<u64>> {
+        self.backend.estimate_rows(table_or_query)
+    }
+
     fn shrink_buffers(&mut self) {
         self.backend.shrink_buffers()
     }
 
+    fn reset_on_release(&mut self) {
+        self.backend.reset_on_release()
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         self.backend.flush()
@@ -1,8 +1,8 @@
 use crate::any::{
     AnyArgumentBuffer, AnyArguments, AnyColumn, AnyConnection, AnyQueryResult, AnyRow,
-    AnyStatement, AnyTransactionManager, AnyTypeInfo, AnyValue, AnyValueRef,
+    AnyStatement, AnyTransactionManager, AnyTypeInfo, AnyValue, AnyValueKind, AnyValueRef,
 };
-use crate::database::{Database, HasStatementCache};
+use crate::database::{Database, HasReturningId, HasStatementCache};
 
 /// Opaque database driver. Capable of being used in place of any SQLx database driver. The actual
 /// driver used will be selected at runtime, from the connection url.
@@ -37,3 +37,18 @@ impl Database for Any {
 
 // This _may_ be true, depending on the selected database
 impl HasStatementCache for Any {}
+
+impl HasReturningId for Any {
+    fn supports_returning(conn: &AnyConnection) -> bool {
+        conn.backend.supports_returning()
+    }
+
+    fn last_insert_id(result: &AnyQueryResult) -> Option<AnyValue> {
+        result
+            .last_insert_id
+            .filter(|id| *id != 0)
+            .map(|id| AnyValue {
+                kind: AnyValueKind::BigInt(id),
+            })
+    }
+}
@@ -0,0 +1,590 @@
+//! A scripted mock database for unit testing code which uses [`AnyConnection`] or [`AnyPool`]
+//! without a live database.
+//!
+//! A [`MockDatabase`] holds a list of expected queries and the response to each of them.
+//! Connections to the mock (via the URL returned by [`MockDatabase::url()`])
+//! take the expectations in order: each query must match the next expected SQL,
+//! or it fails with [`Error::Protocol`].
+//!
+//! ```rust,no_run
+//! # async fn example() -> sqlx::Result<()> {
+//! use sqlx::any::mock::{MockDatabase, MockRow};
+//! use sqlx::{AnyPool, Row};
+//!
+//! sqlx::any::install_default_drivers();
+//!
+//! let mock = MockDatabase::new("users");
+//!
+//! mock.expect("SELECT id, name FROM users WHERE id = ?")
+//!     .returns_rows([MockRow::new().column("id", 1_i64).column("name", "Alice")]);
+//!
+//! mock.expect("DELETE FROM users WHERE id = ?").returns_rows_affected(1);
+//!
+//! let pool = AnyPool::connect(&mock.url()).await?;
+//!
+//! let row = sqlx::query("SELECT id, name FROM users WHERE id = ?")
+//!     .bind(1_i64)
+//!     .fetch_one(&pool)
+//!     .await?;
+//!
+//! assert_eq!(row.get::<String, _>("name"), "Alice");
+//!
+//! // code under test...
+//! # sqlx::query("DELETE FROM users WHERE id = ?").bind(1_i64).execute(&pool).await?;
+//!
+//! mock.verify();
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Transaction control statements (`BEGIN`, `COMMIT`, `ROLLBACK` and savepoints) are matched
+//! against the expectations like any other query, so they must be expected as well, e.g. with
+//! `mock.expect("BEGIN").succeeds()`. A rollback started when a [`Transaction`] is dropped
+//! can't return an error, so if it was not expected it only shows up in
+//! [`MockDatabase::executed()`].
+//!
+//! [`AnyPool`]: crate::any::AnyPool
+//! [`Transaction`]: crate::transaction::Transaction
+
+use std::collections::VecDeque;
+use std::fmt::{self, Debug, Formatter};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use either::Either;
+use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+use futures_util::{stream, FutureExt, StreamExt};
+use once_cell::sync::Lazy;
+
+use crate::any::driver::AnyDriver;
+use crate::any::value::AnyValueKind;
+use crate::any::{
+    Any, AnyArgumentBuffer, AnyArguments, AnyColumn, AnyConnectOptions, AnyConnection,
+    AnyConnectionBackend, AnyQueryResult, AnyRow, AnyStatement, AnyTypeInfo, AnyValue, AnyValueRef,
+};
+use crate::common::DebugFn;
+use crate::describe::Describe;
+use crate::encode::{Encode, IsNull};
+use crate::error::Error;
+use crate::ext::ustr::UStr;
+use crate::transaction::{
+    begin_ansi_transaction_sql, begin_transaction_with_options_sql, commit_ansi_transaction_sql,
+    nested_transaction_options_error, rollback_ansi_transaction_sql, TransactionOptions,
+};
+use crate::types::Type;
+use crate::value::ValueRef;
+use crate::HashMap;
+
+static DATABASES: Lazy<Mutex<HashMap<String, Arc<Shared>>>> = Lazy::new(Default::default);
+
+// Appended to the names of mocks, so tests running in parallel can use the same name.
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// The driver for `mock://` URLs.
+///
+/// Included in `sqlx::any::install_default_drivers()` when the `mock` feature is enabled.
+pub const DRIVER: AnyDriver = AnyDriver {
+    name: "Mock",
+    url_schemes: &["mock"],
+    connect: DebugFn(connect),
+    migrate_database: None,
+};
+
+fn connect(options: &AnyConnectOptions) -> BoxFuture<'_, crate::Result<AnyConnection>> {
+    let host = options.database_url.host_str().unwrap_or_default();
+
+    let shared = lock(&DATABASES).get(host).cloned();
+
+    let res = shared
+        .map(|shared| AnyConnection {
+            backend: Box::new(MockConnection::new(shared)),
+        })
+        .ok_or_else(|| Error::Configuration(format!("no mock database at {host:?}").into()));
+
+    Box::pin(async move { res })
+}
+
+/// A mock database which answers queries with scripted responses.
+///
+/// The mock can be connected to with its [URL][Self::url] until it is dropped.
+/// Expectations are shared by all connections to it, e.g. those of a pool.
+pub struct MockDatabase {
+    shared: Arc<Shared>,
+}
+
+impl MockDatabase {
+    /// Create a new mock database named `name`.
+    ///
+    /// Names don't have to be unique; each mock gets a [URL][Self::url] of its own.
+    ///
+    /// # Panics
+    /// If `name` is not a valid URL host.
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+
+        assert!(
+            url::Host::parse(&name).is_ok(),
+            "{name:?} is not a valid mock database name"
+        );
+
+        let shared = Arc::new(Shared {
+            host: format!("{name}-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed)),
+            name,
+            state: Mutex::default(),
+        });
+
+        lock(&DATABASES).insert(shared.host.clone(), shared.clone());
+
+        Self { shared }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.shared.name
+    }
+
+    /// The URL to connect to this mock with, `mock://<name>-<id>`.
+    pub fn url(&self) -> String {
+        format!("mock://{}", self.shared.host)
+    }
+
+    /// Open a connection to this mock directly, without going through the installed drivers.
+    pub fn connection(&self) -> AnyConnection {
+        AnyConnection {
+            backend: Box::new(MockConnection::new(self.shared.clone())),
+        }
+    }
+
+    /// Expect `sql` to be the next query executed.
+    ///
+    /// The SQL is compared with consecutive whitespace collapsed.
+    /// Set the response with one of the methods of [`MockExpectation`].
+    pub fn expect(&self, sql: impl Into<String>) -> MockExpectation<'_> {
+        MockExpectation {
+            database: self,
+            sql: sql.into(),
+        }
+    }
+
+    /// The queries executed so far, including those which failed.
+    pub fn executed(&self) -> Vec<MockQuery> {
+        self.shared.state().executed.clone()
+    }
+
+    /// Panic if any expected query has not been executed yet.
+    pub fn verify(&self) {
+        let state = self.shared.state();
+
+        if let Some(expected) = state.expectations.front() {
+            panic!(
+                "mock database {:?}: {} expected queries were not executed, starting with {:?}",
+                self.shared.name,
+                state.expectations.len(),
+                expected.sql
+            );
+        }
+    }
+}
+
+impl Drop for MockDatabase {
+    fn drop(&mut self) {
+        lock(&DATABASES).remove(&self.shared.host);
+    }
+}
+
+impl Debug for MockDatabase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockDatabase")
+            .field("name", &self.shared.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An expected query of a [`MockDatabase`], returned by [`MockDatabase::expect()`].
+#[must_use = "the expectation is only added once its response is set"]
+pub struct MockExpectation<'a> {
+    database: &'a MockDatabase,
+    sql: String,
+}
+
+impl MockExpectation<'_> {
+    /// Respond with `rows`.
+    pub fn returns_rows(self, rows: impl IntoIterator<Item = MockRow>) {
+        self.returns(Response::Rows(
+            rows.into_iter().map(MockRow::into_row).collect(),
+        ));
+    }
+
+    /// Respond with a result with the given number of affected rows and no rows.
+    pub fn returns_rows_affected(self, rows_affected: u64) {
+        self.returns(Response::Result(AnyQueryResult {
+            rows_affected,
+            last_insert_id: None,
+        }));
+    }
+
+    /// Respond with a result with one affected row and the given last insert ID.
+    pub fn returns_last_insert_id(self, last_insert_id: i64) {
+        self.returns(Response::Result(AnyQueryResult {
+            rows_affected: 1,
+            last_insert_id: Some(last_insert_id),
+        }));
+    }
+
+    /// Respond with a result without any rows, e.g. for `BEGIN` or `COMMIT`.
+    pub fn succeeds(self) {
+        self.returns_rows_affected(0);
+    }
+
+    /// Fail the query with `error`.
+    pub fn returns_error(self, error: Error) {
+        self.returns(Response::Error(error));
+    }
+
+    fn returns(self, response: Response) {
+        self.database
+            .shared
+            .state()
+            .expectations
+            .push_back(Expectation {
+                sql: self.sql,
+                response,
+            });
+    }
+}
+
+/// A row returned by a [`MockDatabase`].
+#[derive(Debug, Clone, Default)]
+pub struct MockRow {
+    columns: Vec<AnyColumn>,
+    values: Vec<AnyValue>,
+}
+
+impl MockRow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a column with `value`.
+    ///
+    /// The type of the column is the type of `value`, or of `T` if `value` is `None`.
+    ///
+    /// # Panics
+    /// If `value` fails to encode.
+    pub fn column<T>(mut self, name: &str, value: T) -> Self
+    where
+        T: Encode<'static, Any> + Type<Any>,
+    {
+        let mut buf = AnyArgumentBuffer(Vec::with_capacity(1));
+
+        let _: IsNull = value
+            .encode_by_ref(&mut buf)
+            .unwrap_or_else(|e| panic!("failed to encode the value of column {name:?}: {e}"));
+
+        let kind = buf.0.pop().unwrap_or(AnyValueKind::Null);
+
+        let type_info = match kind {
+            AnyValueKind::Null => T::type_info(),
+            _ => kind.type_info(),
+        };
+
+        self.columns.push(AnyColumn {
+            ordinal: self.columns.len(),
+            name: UStr::new(name),
+            type_info,
+        });
+        self.values.push(AnyValue { kind });
+
+        self
+    }
+
+    fn into_row(self) -> AnyRow {
+        let column_names = self
+            .columns
+            .iter()
+            .map(|column| (column.name.clone(), column.ordinal))
+            .collect();
+
+        AnyRow {
+            column_names: Arc::new(column_names),
+            columns: self.columns,
+            values: self.values,
+        }
+    }
+}
+
+/// A query executed against a [`MockDatabase`].
+#[derive(Debug, Clone)]
+pub struct MockQuery {
+    sql: String,
+    arguments: Vec<AnyValue>,
+}
+
+impl MockQuery {
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// The values bound to the query, if it had any.
+    pub fn arguments(&self) -> &[AnyValue] {
+        &self.arguments
+    }
+}
+
+struct Shared {
+    name: String,
+    // the key of this mock in `DATABASES`
+    host: String,
+    state: Mutex<State>,
+}
+
+impl Shared {
+    fn state(&self) -> MutexGuard<'_, State> {
+        lock(&self.state)
+    }
+
+    fn execute(&self, sql: &str, arguments: Option<AnyArguments<'_>>) -> Response {
+        let arguments = arguments
+            .map(|arguments| {
+                arguments
+                    .values
+                    .0
+                    .into_iter()
+                    .map(|kind| ValueRef::to_owned(&AnyValueRef { kind }))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut state = self.state();
+
+        state.executed.push(MockQuery {
+            sql: sql.to_owned(),
+            arguments,
+        });
+
+        match state.expectations.pop_front() {
+            Some(expected) if normalize(&expected.sql) == normalize(sql) => expected.response,
+            Some(expected) => {
+                let error = err_protocol!(
+                    "mock database {:?}: expected query {:?}, got {sql:?}",
+                    self.name,
+                    expected.sql
+                );
+
+                state.expectations.push_front(expected);
+
+                Response::Error(error)
+            }
+            None => Response::Error(err_protocol!(
+                "mock database {:?}: unexpected query {sql:?}",
+                self.name
+            )),
+        }
+    }
+
+    fn transaction_control(&self, sql: &str) -> crate::Result<()> {
+        match self.execute(sql, None) {
+            Response::Error(error) => Err(error),
+            Response::Rows(_) | Response::Result(_) => Ok(()),
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    expectations: VecDeque<Expectation>,
+    executed: Vec<MockQuery>,
+}
+
+struct Expectation {
+    sql: String,
+    response: Response,
+}
+
+enum Response {
+    Rows(Vec<AnyRow>),
+    Result(AnyQueryResult),
+    Error(Error),
+}
+
+fn normalize(sql: &str) -> String {
+    sql.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// A panic in a test while the lock is held should not fail every other test using the mock.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+struct MockConnection {
+    shared: Arc<Shared>,
+    transaction_depth: usize,
+}
+
+impl MockConnection {
+    fn new(shared: Arc<Shared>) -> Self {
+        Self {
+            shared,
+            transaction_depth: 0,
+        }
+    }
+}
+
+impl Debug for MockConnection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockConnection")
+            .field("name", &self.shared.name)
+            .finish()
+    }
+}
+
+impl AnyConnectionBackend for MockConnection {
+    fn name(&self) -> &str {
+        "Mock"
+    }
+
+    fn close(self: Box<Self>) -> BoxFuture<'static, crate::Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn close_hard(self: Box<Self>) -> BoxFuture<'static, crate::Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn ping(&mut self) -> BoxFuture<'_, crate::Result<Duration>> {
+        Box::pin(async { Ok(Duration::ZERO) })
+    }
+
+    fn begin(&mut self) -> BoxFuture<'_, crate::Result<()>> {
+        let res = self
+            .shared
+            .transaction_control(&begin_ansi_transaction_sql(self.transaction_depth));
+
+        if res.is_ok() {
+            self.transaction_depth += 1;
+        }
+
+        Box::pin(async { res })
+    }
+
+    fn begin_with(&mut self, options: TransactionOptions) -> BoxFuture<'_, crate::Result<()>> {
+        if self.transaction_depth > 0 {
+            return Box::pin(async { Err(nested_transaction_options_error()) });
+        }
+
+        let res = self
+            .shared
+            .transaction_control(&begin_transaction_with_options_sql(&options));
+
+        if res.is_ok() {
+            self.transaction_depth += 1;
+        }
+
+        Box::pin(async { res })
+    }
+
+    fn commit(&mut self) -> BoxFuture<'_, crate::Result<()>> {
+        let mut res = Ok(());
+
+        if self.transaction_depth > 0 {
+            res = self
+                .shared
+                .transaction_control(&commit_ansi_transaction_sql(self.transaction_depth));
+
+            if res.is_ok() {
+                self.transaction_depth -= 1;
+            }
+        }
+
+        Box::pin(async { res })
+    }
+
+    fn rollback(&mut self) -> BoxFuture<'_, crate::Result<()>> {
+        let mut res = Ok(());
+
+        if self.transaction_depth > 0 {
+            res = self
+                .shared
+                .transaction_control(&rollback_ansi_transaction_sql(self.transaction_depth));
+            self.transaction_depth -= 1;
+        }
+
+        Box::pin(async { res })
+    }
+
+    fn start_rollback(&mut self) {
+        if self.transaction_depth > 0 {
+            // can't fail here; an unexpected rollback only shows up in `executed()`
+            let _ = self
+                .shared
+                .transaction_control(&rollback_ansi_transaction_sql(self.transaction_depth));
+            self.transaction_depth -= 1;
+        }
+    }
+
+    fn shrink_buffers(&mut self) {}
+
+    fn flush(&mut self) -> BoxFuture<'_, crate::Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn should_flush(&self) -> bool {
+        false
+    }
+
+    fn fetch_many<'q>(
+        &'q mut self,
+        query: &'q str,
+        arguments: Option<AnyArguments<'q>>,
+    ) -> BoxStream<'q, crate::Result<Either<AnyQueryResult, AnyRow>>> {
+        let results = match self.shared.execute(query, arguments) {
+            Response::Rows(rows) => rows
+                .into_iter()
+                .map(|row| Ok(Either::Right(row)))
+                .chain([Ok(Either::Left(AnyQueryResult::default()))])
+                .collect(),
+            Response::Result(result) => vec![Ok(Either::Left(result))],
+            Response::Error(error) => vec![Err(error)],
+        };
+
+        stream::iter(results).boxed()
+    }
+
+    fn fetch_optional<'q>(
+        &'q mut self,
+        query: &'q str,
+        arguments: Option<AnyArguments<'q>>,
+    ) -> BoxFuture<'q, crate::Result<Option<AnyRow>>> {
+        let res = match self.shared.execute(query, arguments) {
+            Response::Rows(rows) => Ok(rows.into_iter().next()),
+            Response::Result(_) => Ok(None),
+            Response::Error(error) => Err(error),
+        };
+
+        async move { res }.boxed()
+    }
+
+    fn prepare_with<'c, 'q: 'c>(
+        &'c mut self,
+        sql: &'q str,
+        _parameters: &[AnyTypeInfo],
+    ) -> BoxFuture<'c, crate::Result<AnyStatement<'q>>> {
+        Box::pin(async move {
+            Ok(AnyStatement {
+                sql: sql.into(),
+                parameters: None,
+                column_names: Default::default(),
+                columns: Vec::new(),
+            })
+        })
+    }
+
+    fn describe<'q>(&'q mut self, _sql: &'q str) -> BoxFuture<'q, crate::Result<Describe<Any>>> {
+        Box::pin(async {
+            Err(Error::Configuration(
+                "mock databases do not support describing queries".into(),
+            ))
+        })
+    }
+}
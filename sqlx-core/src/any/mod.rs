@@ -13,6 +13,7 @@ mod error;
 mod kind;
 mod options;
 mod query_result;
+pub(crate) mod registry;
 pub(crate) mod row;
 mod statement;
 mod transaction;
@@ -22,10 +23,13 @@ pub(crate) mod value;
 
 pub mod driver;
 
+#[cfg(feature = "mock")]
+pub mod mock;
+
 #[cfg(feature = "migrate")]
 mod migrate;
 
-pub use arguments::{AnyArgumentBuffer, AnyArguments};
+pub use arguments::{AnyArgumentBuffer, AnyArgumentError, AnyArguments, AnyArgumentsError};
 pub use column::AnyColumn;
 pub use connection::AnyConnection;
 // Used internally in `sqlx-macros`
@@ -36,12 +40,17 @@ pub use database::Any;
 pub use kind::AnyKind;
 pub use options::AnyConnectOptions;
 pub use query_result::AnyQueryResult;
+pub use registry::{register_type, AnyMappedValue};
 pub use row::AnyRow;
 pub use statement::AnyStatement;
 pub use transaction::AnyTransactionManager;
 pub use type_info::{AnyTypeInfo, AnyTypeInfoKind};
-pub use value::{AnyValue, AnyValueRef};
+pub use value::{AnyValue, AnyValueError, AnyValueRef};
 
+#[doc(hidden)]
+pub use registry::mapped_kind;
+#[doc(hidden)]
+pub use types::repr::{AnyDate, AnyDecimal, AnyTime, AnyTimestamp, AnyUuid};
 #[doc(hidden)]
 pub use value::AnyValueKind;
 
@@ -0,0 +1,152 @@
+//! Mappings for database types which the `Any` driver does not support natively.
+
+use std::any::TypeId;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+use crate::any::{AnyTypeInfoKind, AnyValueKind};
+use crate::database::Database;
+use crate::decode::Decode;
+use crate::error::BoxDynError;
+use crate::type_info::TypeInfo;
+
+type DecodeFn<DB> = dyn for<'r> Fn(<DB as Database>::ValueRef<'r>) -> Result<AnyValueKind<'static>, BoxDynError>
+    + Send
+    + Sync;
+
+pub(crate) struct AnyTypeMapping<DB: Database> {
+    pub(crate) kind: AnyTypeInfoKind,
+    pub(crate) decode: Arc<DecodeFn<DB>>,
+}
+
+struct Entry {
+    database: TypeId,
+    type_name: String,
+    // an `AnyTypeMapping<DB>` for the database of `database`
+    mapping: Arc<dyn std::any::Any + Send + Sync>,
+}
+
+static MAPPINGS: RwLock<Vec<Entry>> = RwLock::new(Vec::new());
+
+// set once the first type is registered, so decoding doesn't take the lock when none are
+static HAS_MAPPINGS: AtomicBool = AtomicBool::new(false);
+
+/// A type which values of a database type can be converted to with [`register_type()`].
+///
+/// This is implemented for the types supported by the `Any` driver: `bool`, `i16`, `i32`, `i64`,
+/// `f32`, `f64`, `String` and `Vec<u8>`.
+pub trait AnyMappedValue: private::Sealed + 'static {
+    #[doc(hidden)]
+    fn kind() -> AnyTypeInfoKind;
+
+    #[doc(hidden)]
+    fn into_any_value_kind(self) -> AnyValueKind<'static>;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_any_mapped_value {
+    ($($ty:ty => $kind:ident),* $(,)?) => {
+        $(
+            impl private::Sealed for $ty {}
+
+            impl AnyMappedValue for $ty {
+                fn kind() -> AnyTypeInfoKind {
+                    AnyTypeInfoKind::$kind
+                }
+
+                fn into_any_value_kind(self) -> AnyValueKind<'static> {
+                    AnyValueKind::$kind(self.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_any_mapped_value!(
+    bool => Bool,
+    i16 => SmallInt,
+    i32 => Integer,
+    i64 => BigInt,
+    f32 => Real,
+    f64 => Double,
+    String => Text,
+    Vec<u8> => Blob,
+);
+
+/// Map a type of the database `DB` to a type supported by the `Any` driver.
+///
+/// Columns of the type named `type_name` are decoded as `T`, and then converted to `U` with
+/// `convert`, instead of failing with [`Error::AnyDriverError`][crate::error::Error::AnyDriverError].
+/// The name is matched case-insensitively against [`TypeInfo::name()`] of the driver, e.g.
+/// `NUMERIC` for Postgres or `DECIMAL` for MySQL.
+///
+/// A mapping takes precedence over the built-in mapping of the type, if any, and replaces an
+/// earlier mapping of the same type. Mappings apply to every connection of the process.
+///
+/// ```rust,ignore
+/// use sqlx::any::register_type;
+/// use sqlx::postgres::Postgres;
+/// use sqlx::mysql::MySql;
+/// use rust_decimal::Decimal;
+///
+/// // read `NUMERIC` and `DECIMAL` columns as text
+/// register_type::<Postgres, Decimal, String>("NUMERIC", |value| value.to_string());
+/// register_type::<MySql, Decimal, String>("DECIMAL", |value| value.to_string());
+/// ```
+pub fn register_type<DB, T, U>(type_name: &str, convert: impl Fn(T) -> U + Send + Sync + 'static)
+where
+    DB: Database,
+    T: for<'r> Decode<'r, DB>,
+    U: AnyMappedValue,
+{
+    let decode: Arc<DecodeFn<DB>> =
+        Arc::new(move |value| Ok(convert(T::decode(value)?).into_any_value_kind()));
+
+    let entry = Entry {
+        database: TypeId::of::<DB>(),
+        type_name: type_name.to_owned(),
+        mapping: Arc::new(AnyTypeMapping {
+            kind: U::kind(),
+            decode,
+        }),
+    };
+
+    let mut mappings = MAPPINGS.write().unwrap_or_else(|e| e.into_inner());
+
+    mappings.retain(|existing| {
+        existing.database != entry.database
+            || !existing.type_name.eq_ignore_ascii_case(&entry.type_name)
+    });
+    mappings.push(entry);
+
+    HAS_MAPPINGS.store(true, Ordering::Release);
+}
+
+/// Look up the mapping registered for `type_info`, if any.
+pub(crate) fn mapping<DB: Database>(type_info: &DB::TypeInfo) -> Option<Arc<AnyTypeMapping<DB>>> {
+    if !HAS_MAPPINGS.load(Ordering::Acquire) {
+        return None;
+    }
+
+    let mappings = MAPPINGS.read().unwrap_or_else(|e| e.into_inner());
+
+    let type_name = type_info.name();
+
+    mappings
+        .iter()
+        .find(|entry| {
+            entry.database == TypeId::of::<DB>() && entry.type_name.eq_ignore_ascii_case(type_name)
+        })
+        .and_then(|entry| Arc::clone(&entry.mapping).downcast().ok())
+}
+
+/// The kind of the `Any` type which `type_info` is mapped to with [`register_type()`], if any.
+///
+/// Used by the drivers when converting their type info to [`AnyTypeInfo`][crate::any::AnyTypeInfo].
+#[doc(hidden)]
+pub fn mapped_kind<DB: Database>(type_info: &DB::TypeInfo) -> Option<AnyTypeInfoKind> {
+    mapping::<DB>(type_info).map(|mapping| mapping.kind)
+}
@@ -1,5 +1,6 @@
 use crate::any::error::mismatched_types;
-use crate::any::{Any, AnyColumn, AnyTypeInfo, AnyTypeInfoKind, AnyValue, AnyValueKind};
+use crate::any::types::repr::{AnyDate, AnyDecimal, AnyTime, AnyTimestamp, AnyUuid};
+use crate::any::{Any, AnyColumn, AnyTypeInfo, AnyValue};
 use crate::column::{Column, ColumnIndex};
 use crate::database::Database;
 use crate::decode::Decode;
@@ -95,6 +96,11 @@ impl AnyRow {
         f64: Type<R::Database> + Decode<'a, R::Database>,
         String: Type<R::Database> + Decode<'a, R::Database>,
         Vec<u8>: Type<R::Database> + Decode<'a, R::Database>,
+        AnyDecimal: Type<R::Database> + Decode<'a, R::Database>,
+        AnyDate: Type<R::Database> + Decode<'a, R::Database>,
+        AnyTime: Type<R::Database> + Decode<'a, R::Database>,
+        AnyTimestamp: Type<R::Database> + Decode<'a, R::Database>,
+        AnyUuid: Type<R::Database> + Decode<'a, R::Database>,
     {
         let mut row_out = AnyRow {
             column_names,
@@ -116,29 +122,59 @@ impl AnyRow {
                     source: e.into(),
                 })?;
 
-            let value_kind = match type_info.kind {
-                _ if value.is_null() => AnyValueKind::Null,
-                AnyTypeInfoKind::Null => AnyValueKind::Null,
-                AnyTypeInfoKind::Bool => AnyValueKind::Bool(decode(value)?),
-                AnyTypeInfoKind::SmallInt => AnyValueKind::SmallInt(decode(value)?),
-                AnyTypeInfoKind::Integer => AnyValueKind::Integer(decode(value)?),
-                AnyTypeInfoKind::BigInt => AnyValueKind::BigInt(decode(value)?),
-                AnyTypeInfoKind::Real => AnyValueKind::Real(decode(value)?),
-                AnyTypeInfoKind::Double => AnyValueKind::Double(decode(value)?),
-                AnyTypeInfoKind::Blob => AnyValueKind::Blob(decode::<_, Vec<u8>>(value)?.into()),
-                AnyTypeInfoKind::Text => AnyValueKind::Text(decode::<_, String>(value)?.into()),
-            };
+            let value = AnyValue::decode_from::<R::Database>(type_info.kind, value)?;
 
             row_out.columns.push(any_col);
-            row_out.values.push(AnyValue { kind: value_kind });
+            row_out.values.push(value);
         }
 
         Ok(row_out)
     }
 }
 
-fn decode<'r, DB: Database, T: Decode<'r, DB>>(
-    valueref: <DB as Database>::ValueRef<'r>,
-) -> crate::Result<T> {
-    Decode::decode(valueref).map_err(Error::decode)
+#[cfg(feature = "json")]
+impl crate::row::SerializeColumns for AnyRow {
+    fn column_value(
+        &self,
+        index: usize,
+    ) -> Result<crate::row::ColumnValue<'_>, crate::error::BoxDynError> {
+        use crate::any::AnyValueKind;
+        use crate::row::ColumnValue;
+        use std::borrow::Cow;
+
+        let value = self
+            .values
+            .get(index)
+            .ok_or_else(|| Error::ColumnIndexOutOfBounds {
+                index,
+                len: self.values.len(),
+            })?;
+
+        Ok(match &value.kind {
+            AnyValueKind::Null => ColumnValue::Null,
+            AnyValueKind::Bool(b) => ColumnValue::Bool(*b),
+            AnyValueKind::SmallInt(i) => ColumnValue::Int((*i).into()),
+            AnyValueKind::Integer(i) => ColumnValue::Int((*i).into()),
+            AnyValueKind::BigInt(i) => ColumnValue::Int(*i),
+            AnyValueKind::Real(r) => ColumnValue::Float((*r).into()),
+            AnyValueKind::Double(d) => ColumnValue::Float(*d),
+            AnyValueKind::Text(t) => ColumnValue::Text(Cow::Borrowed(t)),
+            AnyValueKind::Blob(b) => ColumnValue::Bytes(Cow::Borrowed(b)),
+            AnyValueKind::Decimal(d) => ColumnValue::Decimal(Cow::Borrowed(d)),
+            AnyValueKind::Date(d) => ColumnValue::Date(d.0),
+            AnyValueKind::Time(t) => ColumnValue::Time(t.0),
+            AnyValueKind::Timestamp(ts) => ColumnValue::Timestamp {
+                micros: ts.0,
+                utc: false,
+            },
+            AnyValueKind::Uuid(u) => ColumnValue::uuid(u.0),
+        })
+    }
+}
+
+#[cfg(feature = "json")]
+impl serde::Serialize for AnyRow {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::row::SerializeRow::new(self).serialize(serializer)
+    }
 }
@@ -74,12 +74,14 @@ impl<'q> AnyStatement<'q> {
         AnyColumn: for<'a> TryFrom<&'a <S::Database as Database>::Column, Error = Error>,
     {
         let parameters = match statement.parameters() {
-            Some(Either::Left(parameters)) => Some(Either::Left(
+            // fall back to the number of parameters if any of their types isn't supported
+            Some(Either::Left(parameters)) => Some(
                 parameters
                     .iter()
                     .map(AnyTypeInfo::try_from)
-                    .collect::<Result<Vec<_>, _>>()?,
-            )),
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_or(Either::Right(parameters.len()), Either::Left),
+            ),
             Some(Either::Right(count)) => Some(Either::Right(count)),
             None => None,
         };
@@ -27,6 +27,11 @@ pub enum AnyTypeInfoKind {
     Double,
     Text,
     Blob,
+    Decimal,
+    Date,
+    Time,
+    Timestamp,
+    Uuid,
 }
 
 impl TypeInfo for AnyTypeInfo {
@@ -46,6 +51,11 @@ impl TypeInfo for AnyTypeInfo {
             Double => "DOUBLE",
             Text => "TEXT",
             Blob => "BLOB",
+            Decimal => "DECIMAL",
+            Date => "DATE",
+            Time => "TIME",
+            Timestamp => "TIMESTAMP",
+            Uuid => "UUID",
             Null => "NULL",
         }
     }
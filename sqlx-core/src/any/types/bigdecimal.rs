@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+
+use crate::any::{Any, AnyTypeInfo, AnyTypeInfoKind, AnyValueKind};
+use crate::database::Database;
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+impl Type<Any> for BigDecimal {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo {
+            kind: AnyTypeInfoKind::Decimal,
+        }
+    }
+
+    fn compatible(ty: &AnyTypeInfo) -> bool {
+        matches!(ty.kind, AnyTypeInfoKind::Decimal | AnyTypeInfoKind::Text) || ty.kind.is_integer()
+    }
+}
+
+impl<'q> Encode<'q, Any> for BigDecimal {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <Any as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        buf.0.push(AnyValueKind::Decimal(self.to_string().into()));
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> Decode<'r, Any> for BigDecimal {
+    fn decode(value: <Any as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(BigDecimal::from_str(&value.kind.try_decimal::<Self>()?)?)
+    }
+}
@@ -0,0 +1,156 @@
+use std::cmp;
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
+
+use crate::any::{Any, AnyDate, AnyTime, AnyTimestamp, AnyTypeInfo, AnyTypeInfoKind, AnyValueKind};
+use crate::database::Database;
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+impl Type<Any> for NaiveDate {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo {
+            kind: AnyTypeInfoKind::Date,
+        }
+    }
+
+    fn compatible(ty: &AnyTypeInfo) -> bool {
+        matches!(ty.kind, AnyTypeInfoKind::Date | AnyTypeInfoKind::Text)
+    }
+}
+
+impl<'q> Encode<'q, Any> for NaiveDate {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <Any as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        buf.0.push(AnyValueKind::Date(to_any_date(self)?));
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> Decode<'r, Any> for NaiveDate {
+    fn decode(value: <Any as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        from_any_date(value.kind.try_date::<Self>()?)
+    }
+}
+
+impl Type<Any> for NaiveTime {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo {
+            kind: AnyTypeInfoKind::Time,
+        }
+    }
+
+    fn compatible(ty: &AnyTypeInfo) -> bool {
+        matches!(ty.kind, AnyTypeInfoKind::Time | AnyTypeInfoKind::Text)
+    }
+}
+
+impl<'q> Encode<'q, Any> for NaiveTime {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <Any as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        buf.0.push(AnyValueKind::Time(to_any_time(self)?));
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> Decode<'r, Any> for NaiveTime {
+    fn decode(value: <Any as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        from_any_time(value.kind.try_time::<Self>()?)
+    }
+}
+
+impl Type<Any> for NaiveDateTime {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo {
+            kind: AnyTypeInfoKind::Timestamp,
+        }
+    }
+
+    fn compatible(ty: &AnyTypeInfo) -> bool {
+        matches!(ty.kind, AnyTypeInfoKind::Timestamp | AnyTypeInfoKind::Text)
+    }
+}
+
+impl<'q> Encode<'q, Any> for NaiveDateTime {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <Any as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let timestamp = AnyTimestamp::new(to_any_date(&self.date())?, to_any_time(&self.time())?)
+            .ok_or_else(|| format!("timestamp out of range: {self}"))?;
+
+        buf.0.push(AnyValueKind::Timestamp(timestamp));
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> Decode<'r, Any> for NaiveDateTime {
+    fn decode(value: <Any as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let timestamp = value.kind.try_timestamp::<Self>()?;
+
+        Ok(NaiveDateTime::new(
+            from_any_date(timestamp.date())?,
+            from_any_time(timestamp.time())?,
+        ))
+    }
+}
+
+impl Type<Any> for DateTime<Utc> {
+    fn type_info() -> AnyTypeInfo {
+        <NaiveDateTime as Type<Any>>::type_info()
+    }
+
+    fn compatible(ty: &AnyTypeInfo) -> bool {
+        <NaiveDateTime as Type<Any>>::compatible(ty)
+    }
+}
+
+impl<'q> Encode<'q, Any> for DateTime<Utc> {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <Any as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        Encode::<Any>::encode(self.naive_utc(), buf)
+    }
+}
+
+impl<'r> Decode<'r, Any> for DateTime<Utc> {
+    fn decode(value: <Any as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let naive = <NaiveDateTime as Decode<Any>>::decode(value)?;
+
+        Ok(Utc.from_utc_datetime(&naive))
+    }
+}
+
+fn to_any_date(date: &NaiveDate) -> Result<AnyDate, BoxDynError> {
+    AnyDate::from_ymd(date.year(), date.month(), date.day())
+        .ok_or_else(|| format!("date out of range: {date}").into())
+}
+
+fn from_any_date(date: AnyDate) -> Result<NaiveDate, BoxDynError> {
+    let (year, month, day) = date.to_ymd();
+
+    NaiveDate::from_ymd_opt(year, month, day)
+        .ok_or_else(|| format!("date out of range: {date}").into())
+}
+
+fn to_any_time(time: &NaiveTime) -> Result<AnyTime, BoxDynError> {
+    // a leap second is represented with more than 1,000,000,000 nanoseconds
+    let micro = cmp::min(time.nanosecond() / 1000, 999_999);
+
+    AnyTime::from_hms_micro(time.hour(), time.minute(), time.second(), micro)
+        .ok_or_else(|| format!("time out of range: {time}").into())
+}
+
+fn from_any_time(time: AnyTime) -> Result<NaiveTime, BoxDynError> {
+    let (hour, minute, second, micro) = time.to_hms_micro();
+
+    NaiveTime::from_hms_micro_opt(hour, minute, second, micro)
+        .ok_or_else(|| format!("time out of range: {time}").into())
+}
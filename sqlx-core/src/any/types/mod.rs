@@ -11,6 +11,22 @@
 //! | `f32`                                 | FLOAT                                                |
 //! | `f64`                                 | DOUBLE                                               |
 //! | `&str`, [`String`]                    | VARCHAR, CHAR, TEXT                                  |
+//! | `&[u8]`, `Vec<u8>`                    | BLOB, BYTEA, VARBINARY                               |
+//!
+//! ### With optional features
+//!
+//! | Rust type                             | SQL type(s)                                          |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `rust_decimal::Decimal`               | DECIMAL, NUMERIC                                     |
+//! | `bigdecimal::BigDecimal`              | DECIMAL, NUMERIC                                     |
+//! | `chrono::NaiveDate`, `time::Date`     | DATE                                                 |
+//! | `chrono::NaiveTime`, `time::Time`     | TIME                                                 |
+//! | `chrono::NaiveDateTime`, `time::PrimitiveDateTime` | TIMESTAMP, DATETIME                     |
+//! | `chrono::DateTime<Utc>`, `time::OffsetDateTime` | TIMESTAMP, TIMESTAMPTZ, DATETIME (as UTC)  |
+//! | `uuid::Uuid`                          | UUID                                                 |
+//!
+//! SQLite has no native types for these, so they are also decoded from `TEXT` (or `BLOB` for
+//! `Uuid`), and encoded as `TEXT` (or `BLOB` for `Uuid`).
 //!
 //! # Nullable
 //!
@@ -21,8 +37,20 @@ mod blob;
 mod bool;
 mod float;
 mod int;
+pub(crate) mod repr;
 mod str;
 
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "uuid")]
+mod uuid;
+
 #[test]
 fn test_type_impls() {
     use crate::any::Any;
@@ -50,4 +78,29 @@ fn test_type_impls() {
     // These imply that there are also impls for the equivalent slice types.
     has_type::<Vec<u8>>();
     has_type::<String>();
+
+    #[cfg(feature = "bigdecimal")]
+    has_type::<::bigdecimal::BigDecimal>();
+
+    #[cfg(feature = "rust_decimal")]
+    has_type::<::rust_decimal::Decimal>();
+
+    #[cfg(feature = "chrono")]
+    {
+        has_type::<::chrono::NaiveDate>();
+        has_type::<::chrono::NaiveTime>();
+        has_type::<::chrono::NaiveDateTime>();
+        has_type::<::chrono::DateTime<::chrono::Utc>>();
+    }
+
+    #[cfg(feature = "time")]
+    {
+        has_type::<::time::Date>();
+        has_type::<::time::Time>();
+        has_type::<::time::PrimitiveDateTime>();
+        has_type::<::time::OffsetDateTime>();
+    }
+
+    #[cfg(feature = "uuid")]
+    has_type::<::uuid::Uuid>();
 }
@@ -0,0 +1,357 @@
+//! The representations of `DECIMAL`, `DATE`, `TIME`, `TIMESTAMP` and `UUID` values in the `Any`
+//! driver.
+//!
+//! The drivers implement `Type`, `Encode` and `Decode` for these, so values can be converted
+//! without depending on any of the optional date and time, decimal or UUID crates.
+
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::common::civil::{civil_from_days, days_from_civil};
+use crate::error::BoxDynError;
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_DAY: i64 = 86_400 * MICROS_PER_SECOND;
+
+/// A decimal number, in its canonical text form, e.g. `-12.50`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnyDecimal(pub String);
+
+/// A date, as the number of days since 1970-01-01.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnyDate(pub i32);
+
+/// A time of day, as the number of microseconds since midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnyTime(pub i64);
+
+/// A date and time, as the number of microseconds since 1970-01-01 00:00:00.
+///
+/// Timestamps with a time zone are converted to UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnyTimestamp(pub i64);
+
+/// A UUID, as its 16 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnyUuid(pub [u8; 16]);
+
+impl AnyDate {
+    /// Returns `None` if the month or day is out of range.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        i32::try_from(days_from_civil(year, month, day))
+            .ok()
+            .map(AnyDate)
+    }
+
+    /// The year, month (1-12) and day (1-31) of the date.
+    pub fn to_ymd(self) -> (i32, u32, u32) {
+        let (year, month, day) = civil_from_days(i64::from(self.0));
+
+        // the year of an `i32` number of days always fits in an `i32`
+        (year as i32, month, day)
+    }
+}
+
+impl AnyTime {
+    /// Returns `None` if any of the components is out of range.
+    pub fn from_hms_micro(hour: u32, minute: u32, second: u32, micro: u32) -> Option<Self> {
+        if hour > 23 || minute > 59 || second > 59 || micro > 999_999 {
+            return None;
+        }
+
+        let seconds = i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second);
+
+        Some(AnyTime(seconds * MICROS_PER_SECOND + i64::from(micro)))
+    }
+
+    /// The hour, minute, second and microsecond of the time.
+    pub fn to_hms_micro(self) -> (u32, u32, u32, u32) {
+        let micros = self.0.rem_euclid(MICROS_PER_DAY);
+        let seconds = micros / MICROS_PER_SECOND;
+
+        (
+            (seconds / 3600) as u32,
+            (seconds / 60 % 60) as u32,
+            (seconds % 60) as u32,
+            (micros % MICROS_PER_SECOND) as u32,
+        )
+    }
+}
+
+impl AnyTimestamp {
+    /// Returns `None` if the timestamp is out of range.
+    pub fn new(date: AnyDate, time: AnyTime) -> Option<Self> {
+        i64::from(date.0)
+            .checked_mul(MICROS_PER_DAY)?
+            .checked_add(time.0)
+            .map(AnyTimestamp)
+    }
+
+    pub fn date(self) -> AnyDate {
+        let days = self.0.div_euclid(MICROS_PER_DAY);
+
+        // the date of an `i64` number of microseconds always fits in an `i32`
+        AnyDate(days.clamp(i32::MIN.into(), i32::MAX.into()) as i32)
+    }
+
+    pub fn time(self) -> AnyTime {
+        AnyTime(self.0.rem_euclid(MICROS_PER_DAY))
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl Display for AnyDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (year, month, day) = self.to_ymd();
+
+        // years outside of 0000-9999 are signed, as with chrono
+        if (0..=9999).contains(&year) {
+            write!(f, "{year:04}-{month:02}-{day:02}")
+        } else {
+            write!(f, "{year:+05}-{month:02}-{day:02}")
+        }
+    }
+}
+
+impl Display for AnyTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (hour, minute, second, micro) = self.to_hms_micro();
+
+        write!(f, "{hour:02}:{minute:02}:{second:02}")?;
+
+        if micro != 0 {
+            write!(f, ".{micro:06}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for AnyTimestamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.date(), self.time())
+    }
+}
+
+impl FromStr for AnyDate {
+    type Err = BoxDynError;
+
+    /// Parse a date in the format `YYYY-MM-DD`, where the year may be signed, e.g. `-0044-03-15`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_date(s)
+            .and_then(|(year, month, day)| AnyDate::from_ymd(year, month, day))
+            .ok_or_else(|| format!("invalid date: {s:?}").into())
+    }
+}
+
+impl FromStr for AnyTime {
+    type Err = BoxDynError;
+
+    /// Parse a time in the format `HH:MM:SS`, with an optional fraction of a second.
+    ///
+    /// `24:00:00` is parsed as midnight, as the Postgres driver decodes it.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_time(s)
+            .map(|micros| AnyTime(micros % MICROS_PER_DAY))
+            .ok_or_else(|| format!("invalid time: {s:?}").into())
+    }
+}
+
+impl FromStr for AnyTimestamp {
+    type Err = BoxDynError;
+
+    /// Parse a timestamp in the format `YYYY-MM-DD HH:MM:SS` (or with a `T` separator), with an
+    /// optional fraction of a second and UTC offset, e.g. `Z`, `+02` or `-05:30`.
+    ///
+    /// A time of `24:00:00` is midnight of the next day, as in Postgres.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_timestamp(s).ok_or_else(|| format!("invalid timestamp: {s:?}").into())
+    }
+}
+
+fn parse_date(s: &str) -> Option<(i32, u32, u32)> {
+    let (sign, unsigned) = match s.as_bytes().first() {
+        Some(b'-') => (-1, &s[1..]),
+        Some(b'+') => (1, &s[1..]),
+        _ => (1, s),
+    };
+
+    let (year, rest) = unsigned.split_once('-')?;
+    let (month, day) = rest.split_once('-')?;
+    let year = i32::try_from(parse_digits(year)?).ok()? * sign;
+
+    Some((year, parse_digits(month)?, parse_digits(day)?))
+}
+
+/// Parse a time of day to microseconds, which are a full day for `24:00:00`.
+fn parse_time(s: &str) -> Option<i64> {
+    let (hms, fraction) = s.split_once('.').unwrap_or((s, ""));
+
+    let mut parts = hms.splitn(3, ':');
+    let hour = parse_digits(parts.next()?)?;
+    let minute = parse_digits(parts.next()?)?;
+    let second = parse_digits(parts.next()?)?;
+
+    if fraction.is_empty() && s.ends_with('.') {
+        return None;
+    }
+
+    if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    // truncate to microseconds, e.g. `.5` is 500000 and `.123456789` is 123456
+    let micro = fraction
+        .bytes()
+        .chain(std::iter::repeat(b'0'))
+        .take(6)
+        .fold(0, |micro, digit| micro * 10 + u32::from(digit - b'0'));
+
+    if (hour, minute, second, micro) == (24, 0, 0, 0) {
+        return Some(MICROS_PER_DAY);
+    }
+
+    AnyTime::from_hms_micro(hour, minute, second, micro).map(|time| time.0)
+}
+
+fn parse_timestamp(s: &str) -> Option<AnyTimestamp> {
+    let (date, time) = s.split_once([' ', 'T'])?;
+    let date = AnyDate::from_str(date).ok()?;
+
+    // the offset starts at the first `Z`, `+` or `-` after the time
+    let (time, offset) = match time.find(['Z', '+', '-']) {
+        Some(i) => time.split_at(i),
+        None => (time, ""),
+    };
+
+    let time = parse_time(time.trim_end())?;
+    let offset_micros = parse_offset(offset)? * MICROS_PER_SECOND;
+
+    AnyTimestamp::new(date, AnyTime(time))?
+        .0
+        .checked_sub(offset_micros)
+        .map(AnyTimestamp)
+}
+
+/// Parse a UTC offset, e.g. `Z`, `+02`, `-05:30` or `+0530`, to seconds.
+fn parse_offset(s: &str) -> Option<i64> {
+    let (sign, rest) = match s.as_bytes().first() {
+        None => return Some(0),
+        Some(b'Z') if s.len() == 1 => return Some(0),
+        Some(b'+') => (1, &s[1..]),
+        Some(b'-') => (-1, &s[1..]),
+        _ => return None,
+    };
+
+    let (hours, minutes) = match rest.split_once(':') {
+        Some((hours, minutes)) => (hours, minutes),
+        None if rest.len() == 4 => rest.split_at(2),
+        None => (rest, "00"),
+    };
+
+    let hours: i64 = parse_digits(hours)?.into();
+    let minutes: i64 = parse_digits(minutes)?.into();
+
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+fn parse_digits(s: &str) -> Option<u32> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    s.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_converts_dates_to_and_from_days() {
+        assert_eq!(AnyDate::from_ymd(1970, 1, 1), Some(AnyDate(0)));
+        assert_eq!(AnyDate::from_ymd(2000, 1, 1), Some(AnyDate(10_957)));
+        assert_eq!(AnyDate::from_ymd(1969, 12, 31), Some(AnyDate(-1)));
+        assert_eq!(AnyDate::from_ymd(2024, 2, 30), None);
+        assert_eq!(AnyDate::from_ymd(2024, 13, 1), None);
+
+        for days in [-800_000, -1, 0, 59, 60, 10_957, 19_844, 2_932_896] {
+            let (year, month, day) = AnyDate(days).to_ymd();
+            assert_eq!(AnyDate::from_ymd(year, month, day), Some(AnyDate(days)));
+        }
+
+        assert_eq!(AnyDate(19_844).to_ymd(), (2024, 5, 1));
+    }
+
+    #[test]
+    fn it_formats_and_parses_dates_and_times() {
+        let date: AnyDate = "2024-05-01".parse().unwrap();
+        assert_eq!(date.to_string(), "2024-05-01");
+
+        let time: AnyTime = "12:30:05.25".parse().unwrap();
+        assert_eq!(time.to_hms_micro(), (12, 30, 5, 250_000));
+        assert_eq!(time.to_string(), "12:30:05.250000");
+
+        let timestamp: AnyTimestamp = "2024-05-01 12:30:05".parse().unwrap();
+        assert_eq!(timestamp.to_string(), "2024-05-01 12:30:05");
+        assert_eq!(timestamp.date(), date);
+
+        let with_offset: AnyTimestamp = "2024-05-01T14:30:05+02:00".parse().unwrap();
+        assert_eq!(with_offset, timestamp);
+
+        let before_epoch: AnyTimestamp = "1969-12-31 23:59:59.5Z".parse().unwrap();
+        assert_eq!(before_epoch, AnyTimestamp(-500_000));
+        assert_eq!(before_epoch.to_string(), "1969-12-31 23:59:59.500000");
+
+        assert!("2024-05-01 25:00:00".parse::<AnyTimestamp>().is_err());
+        assert!("5000000-01-01 00:00:00".parse::<AnyTimestamp>().is_err());
+        assert_eq!(AnyTimestamp::new(AnyDate(i32::MAX), AnyTime(0)), None);
+        assert_eq!(AnyTimestamp(i64::MIN).date(), AnyDate(-106_751_992));
+        assert!("12:30".parse::<AnyTime>().is_err());
+        assert!("2024-5".parse::<AnyDate>().is_err());
+    }
+
+    #[test]
+    fn it_formats_and_parses_signed_years() {
+        let bc: AnyDate = "-0044-03-15".parse().unwrap();
+        assert_eq!(bc.to_ymd(), (-44, 3, 15));
+        assert_eq!(bc.to_string(), "-0044-03-15");
+
+        let year_zero = AnyDate::from_ymd(0, 2, 29).unwrap();
+        assert_eq!(year_zero.to_string(), "0000-02-29");
+        assert_eq!("0000-02-29".parse::<AnyDate>().unwrap(), year_zero);
+
+        let far_future: AnyDate = "+10000-01-01".parse().unwrap();
+        assert_eq!(far_future.to_ymd(), (10_000, 1, 1));
+        assert_eq!(far_future.to_string(), "+10000-01-01");
+
+        let timestamp: AnyTimestamp = "-0001-12-31T23:00:00-01:00".parse().unwrap();
+        assert_eq!(timestamp.to_string(), "0000-01-01 00:00:00");
+
+        assert!("--2024-05-01".parse::<AnyDate>().is_err());
+        assert!("-".parse::<AnyDate>().is_err());
+    }
+
+    #[test]
+    fn it_parses_end_of_day() {
+        assert_eq!("24:00:00".parse::<AnyTime>().unwrap(), AnyTime(0));
+        assert_eq!("24:00:00.000".parse::<AnyTime>().unwrap(), AnyTime(0));
+        assert!("24:00:01".parse::<AnyTime>().is_err());
+        assert!("24:00:00.5".parse::<AnyTime>().is_err());
+
+        let timestamp: AnyTimestamp = "2024-05-01 24:00:00".parse().unwrap();
+        assert_eq!(timestamp.to_string(), "2024-05-02 00:00:00");
+    }
+}
@@ -0,0 +1,39 @@
+use rust_decimal::Decimal;
+
+use crate::any::{Any, AnyTypeInfo, AnyTypeInfoKind, AnyValueKind};
+use crate::database::Database;
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+impl Type<Any> for Decimal {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo {
+            kind: AnyTypeInfoKind::Decimal,
+        }
+    }
+
+    fn compatible(ty: &AnyTypeInfo) -> bool {
+        matches!(ty.kind, AnyTypeInfoKind::Decimal | AnyTypeInfoKind::Text) || ty.kind.is_integer()
+    }
+}
+
+impl<'q> Encode<'q, Any> for Decimal {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <Any as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        buf.0.push(AnyValueKind::Decimal(self.to_string().into()));
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> Decode<'r, Any> for Decimal {
+    fn decode(value: <Any as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let text = value.kind.try_decimal::<Self>()?;
+
+        // accept scientific notation, e.g. `1.5E+3`
+        Ok(Decimal::from_str_exact(&text).or_else(|_| Decimal::from_scientific(&text))?)
+    }
+}
@@ -0,0 +1,169 @@
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::any::{Any, AnyDate, AnyTime, AnyTimestamp, AnyTypeInfo, AnyTypeInfoKind, AnyValueKind};
+use crate::database::Database;
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+impl Type<Any> for Date {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo {
+            kind: AnyTypeInfoKind::Date,
+        }
+    }
+
+    fn compatible(ty: &AnyTypeInfo) -> bool {
+        matches!(ty.kind, AnyTypeInfoKind::Date | AnyTypeInfoKind::Text)
+    }
+}
+
+impl<'q> Encode<'q, Any> for Date {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <Any as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        buf.0.push(AnyValueKind::Date(to_any_date(self)?));
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> Decode<'r, Any> for Date {
+    fn decode(value: <Any as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        from_any_date(value.kind.try_date::<Self>()?)
+    }
+}
+
+impl Type<Any> for Time {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo {
+            kind: AnyTypeInfoKind::Time,
+        }
+    }
+
+    fn compatible(ty: &AnyTypeInfo) -> bool {
+        matches!(ty.kind, AnyTypeInfoKind::Time | AnyTypeInfoKind::Text)
+    }
+}
+
+impl<'q> Encode<'q, Any> for Time {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <Any as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        buf.0.push(AnyValueKind::Time(to_any_time(self)?));
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> Decode<'r, Any> for Time {
+    fn decode(value: <Any as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        from_any_time(value.kind.try_time::<Self>()?)
+    }
+}
+
+impl Type<Any> for PrimitiveDateTime {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo {
+            kind: AnyTypeInfoKind::Timestamp,
+        }
+    }
+
+    fn compatible(ty: &AnyTypeInfo) -> bool {
+        matches!(ty.kind, AnyTypeInfoKind::Timestamp | AnyTypeInfoKind::Text)
+    }
+}
+
+impl<'q> Encode<'q, Any> for PrimitiveDateTime {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <Any as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let timestamp = AnyTimestamp::new(to_any_date(&self.date())?, to_any_time(&self.time())?)
+            .ok_or_else(|| format!("timestamp out of range: {self}"))?;
+
+        buf.0.push(AnyValueKind::Timestamp(timestamp));
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> Decode<'r, Any> for PrimitiveDateTime {
+    fn decode(value: <Any as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let timestamp = value.kind.try_timestamp::<Self>()?;
+
+        Ok(PrimitiveDateTime::new(
+            from_any_date(timestamp.date())?,
+            from_any_time(timestamp.time())?,
+        ))
+    }
+}
+
+impl Type<Any> for OffsetDateTime {
+    fn type_info() -> AnyTypeInfo {
+        <PrimitiveDateTime as Type<Any>>::type_info()
+    }
+
+    fn compatible(ty: &AnyTypeInfo) -> bool {
+        <PrimitiveDateTime as Type<Any>>::compatible(ty)
+    }
+}
+
+impl<'q> Encode<'q, Any> for OffsetDateTime {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <Any as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        let utc = self.to_offset(UtcOffset::UTC);
+
+        Encode::<Any>::encode(PrimitiveDateTime::new(utc.date(), utc.time()), buf)
+    }
+}
+
+impl<'r> Decode<'r, Any> for OffsetDateTime {
+    fn decode(value: <Any as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(<PrimitiveDateTime as Decode<Any>>::decode(value)?.assume_utc())
+    }
+}
+
+fn to_any_date(date: &Date) -> Result<AnyDate, BoxDynError> {
+    AnyDate::from_ymd(
+        date.year(),
+        u8::from(date.month()).into(),
+        date.day().into(),
+    )
+    .ok_or_else(|| format!("date out of range: {date}").into())
+}
+
+fn from_any_date(date: AnyDate) -> Result<Date, BoxDynError> {
+    let (year, month, day) = date.to_ymd();
+
+    // the month and day are always in range
+    Ok(Date::from_calendar_date(
+        year,
+        Month::try_from(month as u8)?,
+        day as u8,
+    )?)
+}
+
+fn to_any_time(time: &Time) -> Result<AnyTime, BoxDynError> {
+    AnyTime::from_hms_micro(
+        time.hour().into(),
+        time.minute().into(),
+        time.second().into(),
+        time.microsecond(),
+    )
+    .ok_or_else(|| format!("time out of range: {time}").into())
+}
+
+fn from_any_time(time: AnyTime) -> Result<Time, BoxDynError> {
+    let (hour, minute, second, micro) = time.to_hms_micro();
+
+    // the components are always in range
+    Ok(Time::from_hms_micro(
+        hour as u8,
+        minute as u8,
+        second as u8,
+        micro,
+    )?)
+}
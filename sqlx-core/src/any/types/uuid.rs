@@ -0,0 +1,42 @@
+use uuid::Uuid;
+
+use crate::any::{Any, AnyTypeInfo, AnyTypeInfoKind, AnyUuid, AnyValueKind};
+use crate::database::Database;
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+impl Type<Any> for Uuid {
+    fn type_info() -> AnyTypeInfo {
+        AnyTypeInfo {
+            kind: AnyTypeInfoKind::Uuid,
+        }
+    }
+
+    fn compatible(ty: &AnyTypeInfo) -> bool {
+        matches!(
+            ty.kind,
+            AnyTypeInfoKind::Uuid | AnyTypeInfoKind::Text | AnyTypeInfoKind::Blob
+        )
+    }
+}
+
+impl<'q> Encode<'q, Any> for Uuid {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <Any as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        buf.0.push(AnyValueKind::Uuid(AnyUuid(self.into_bytes())));
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> Decode<'r, Any> for Uuid {
+    fn decode(value: <Any as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        match &value.kind {
+            AnyValueKind::Text(text) => Ok(Uuid::parse_str(text)?),
+            other => Ok(Uuid::from_bytes(other.try_uuid::<Self>()?.0)),
+        }
+    }
+}
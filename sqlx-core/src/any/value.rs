@@ -1,8 +1,11 @@
 use std::borrow::Cow;
 
-use crate::any::{Any, AnyTypeInfo, AnyTypeInfoKind};
+use crate::any::types::repr::{AnyDate, AnyDecimal, AnyTime, AnyTimestamp, AnyUuid};
+use crate::any::{registry, Any, AnyTypeInfo, AnyTypeInfoKind};
 use crate::database::Database;
-use crate::error::BoxDynError;
+use crate::decode::Decode;
+use crate::error::{BoxDynError, Error};
+use crate::type_info::TypeInfo;
 use crate::types::Type;
 use crate::value::{Value, ValueRef};
 
@@ -18,10 +21,15 @@ pub enum AnyValueKind<'a> {
     Double(f64),
     Text(Cow<'a, str>),
     Blob(Cow<'a, [u8]>),
+    Decimal(Cow<'a, str>),
+    Date(AnyDate),
+    Time(AnyTime),
+    Timestamp(AnyTimestamp),
+    Uuid(AnyUuid),
 }
 
 impl AnyValueKind<'_> {
-    fn type_info(&self) -> AnyTypeInfo {
+    pub(in crate::any) fn type_info(&self) -> AnyTypeInfo {
         AnyTypeInfo {
             kind: match self {
                 AnyValueKind::Null => AnyTypeInfoKind::Null,
@@ -33,12 +41,21 @@ impl AnyValueKind<'_> {
                 AnyValueKind::Double(_) => AnyTypeInfoKind::Double,
                 AnyValueKind::Text(_) => AnyTypeInfoKind::Text,
                 AnyValueKind::Blob(_) => AnyTypeInfoKind::Blob,
+                AnyValueKind::Decimal(_) => AnyTypeInfoKind::Decimal,
+                AnyValueKind::Date(_) => AnyTypeInfoKind::Date,
+                AnyValueKind::Time(_) => AnyTypeInfoKind::Time,
+                AnyValueKind::Timestamp(_) => AnyTypeInfoKind::Timestamp,
+                AnyValueKind::Uuid(_) => AnyTypeInfoKind::Uuid,
             },
         }
     }
 
     pub(in crate::any) fn unexpected<Expected: Type<Any>>(&self) -> Result<Expected, BoxDynError> {
-        Err(format!("expected {}, got {:?}", Expected::type_info(), self).into())
+        Err(self.mismatched::<Expected>())
+    }
+
+    fn mismatched<Expected: Type<Any>>(&self) -> BoxDynError {
+        format!("expected {}, got {:?}", Expected::type_info(), self).into()
     }
 
     pub(in crate::any) fn try_integer<T>(&self) -> Result<T, BoxDynError>
@@ -55,6 +72,59 @@ impl AnyValueKind<'_> {
             _ => return self.unexpected(),
         })
     }
+
+    // Drivers without a native type, e.g. SQLite, return these values as text.
+
+    #[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+    pub(in crate::any) fn try_decimal<T: Type<Any>>(&self) -> Result<Cow<'_, str>, BoxDynError> {
+        match self {
+            AnyValueKind::Decimal(d) | AnyValueKind::Text(d) => Ok(Cow::Borrowed(d)),
+            AnyValueKind::SmallInt(i) => Ok(Cow::Owned(i.to_string())),
+            AnyValueKind::Integer(i) => Ok(Cow::Owned(i.to_string())),
+            AnyValueKind::BigInt(i) => Ok(Cow::Owned(i.to_string())),
+            _ => Err(self.mismatched::<T>()),
+        }
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    pub(in crate::any) fn try_date<T: Type<Any>>(&self) -> Result<AnyDate, BoxDynError> {
+        match self {
+            AnyValueKind::Date(d) => Ok(*d),
+            AnyValueKind::Text(t) => t.parse(),
+            _ => Err(self.mismatched::<T>()),
+        }
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    pub(in crate::any) fn try_time<T: Type<Any>>(&self) -> Result<AnyTime, BoxDynError> {
+        match self {
+            AnyValueKind::Time(t) => Ok(*t),
+            AnyValueKind::Text(t) => t.parse(),
+            _ => Err(self.mismatched::<T>()),
+        }
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    pub(in crate::any) fn try_timestamp<T: Type<Any>>(&self) -> Result<AnyTimestamp, BoxDynError> {
+        match self {
+            AnyValueKind::Timestamp(t) => Ok(*t),
+            AnyValueKind::Text(t) => t.parse(),
+            _ => Err(self.mismatched::<T>()),
+        }
+    }
+
+    #[cfg(feature = "uuid")]
+    pub(in crate::any) fn try_uuid<T: Type<Any>>(&self) -> Result<AnyUuid, BoxDynError> {
+        match self {
+            AnyValueKind::Uuid(u) => Ok(*u),
+            AnyValueKind::Blob(b) => {
+                Ok(AnyUuid((**b).try_into().map_err(|_| {
+                    format!("expected 16 bytes for a UUID, got {}", b.len())
+                })?))
+            }
+            _ => Err(self.mismatched::<T>()),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -63,6 +133,146 @@ pub struct AnyValue {
     pub kind: AnyValueKind<'static>,
 }
 
+impl AnyValue {
+    /// Convert a value of a specific database driver to an `AnyValue`.
+    ///
+    /// Returns [`AnyValueError::UnsupportedType`] if the type of the value is not supported by
+    /// the `Any` driver, or [`AnyValueError::Decode`] if it could not be decoded.
+    ///
+    /// Drivers also implement `TryFrom` their own value type for `AnyValue` with this.
+    pub fn map_from<'r, DB: Database>(
+        value: <DB as Database>::ValueRef<'r>,
+    ) -> Result<Self, AnyValueError>
+    where
+        AnyTypeInfo: for<'b> TryFrom<&'b DB::TypeInfo, Error = Error>,
+        bool: Type<DB> + Decode<'r, DB>,
+        i16: Type<DB> + Decode<'r, DB>,
+        i32: Type<DB> + Decode<'r, DB>,
+        i64: Type<DB> + Decode<'r, DB>,
+        f32: Type<DB> + Decode<'r, DB>,
+        f64: Type<DB> + Decode<'r, DB>,
+        String: Type<DB> + Decode<'r, DB>,
+        Vec<u8>: Type<DB> + Decode<'r, DB>,
+        AnyDecimal: Type<DB> + Decode<'r, DB>,
+        AnyDate: Type<DB> + Decode<'r, DB>,
+        AnyTime: Type<DB> + Decode<'r, DB>,
+        AnyTimestamp: Type<DB> + Decode<'r, DB>,
+        AnyUuid: Type<DB> + Decode<'r, DB>,
+    {
+        let type_info = AnyTypeInfo::try_from(&value.type_info()).map_err(|_| {
+            AnyValueError::UnsupportedType {
+                database: DB::NAME,
+                type_name: value.type_info().name().to_owned(),
+            }
+        })?;
+
+        Self::decode_from::<DB>(type_info.kind, value).map_err(|e| match e {
+            Error::Decode(source) => AnyValueError::Decode(source),
+            e => AnyValueError::Decode(e.into()),
+        })
+    }
+
+    // Decode `value` as the `Any` type `kind`, which was mapped from its type info.
+    pub(in crate::any) fn decode_from<'r, DB: Database>(
+        kind: AnyTypeInfoKind,
+        value: <DB as Database>::ValueRef<'r>,
+    ) -> Result<Self, Error>
+    where
+        bool: Type<DB> + Decode<'r, DB>,
+        i16: Type<DB> + Decode<'r, DB>,
+        i32: Type<DB> + Decode<'r, DB>,
+        i64: Type<DB> + Decode<'r, DB>,
+        f32: Type<DB> + Decode<'r, DB>,
+        f64: Type<DB> + Decode<'r, DB>,
+        String: Type<DB> + Decode<'r, DB>,
+        Vec<u8>: Type<DB> + Decode<'r, DB>,
+        AnyDecimal: Type<DB> + Decode<'r, DB>,
+        AnyDate: Type<DB> + Decode<'r, DB>,
+        AnyTime: Type<DB> + Decode<'r, DB>,
+        AnyTimestamp: Type<DB> + Decode<'r, DB>,
+        AnyUuid: Type<DB> + Decode<'r, DB>,
+    {
+        if value.is_null() {
+            return Ok(AnyValue {
+                kind: AnyValueKind::Null,
+            });
+        }
+
+        if let Some(mapping) = registry::mapping::<DB>(&value.type_info()) {
+            let kind = (mapping.decode)(value).map_err(Error::decode)?;
+            return Ok(AnyValue { kind });
+        }
+
+        let kind = match kind {
+            AnyTypeInfoKind::Null => AnyValueKind::Null,
+            AnyTypeInfoKind::Bool => AnyValueKind::Bool(decode::<DB, _>(value)?),
+            AnyTypeInfoKind::SmallInt => AnyValueKind::SmallInt(decode::<DB, _>(value)?),
+            AnyTypeInfoKind::Integer => AnyValueKind::Integer(decode::<DB, _>(value)?),
+            AnyTypeInfoKind::BigInt => AnyValueKind::BigInt(decode::<DB, _>(value)?),
+            AnyTypeInfoKind::Real => AnyValueKind::Real(decode::<DB, _>(value)?),
+            AnyTypeInfoKind::Double => AnyValueKind::Double(decode::<DB, _>(value)?),
+            AnyTypeInfoKind::Blob => AnyValueKind::Blob(decode::<DB, Vec<u8>>(value)?.into()),
+            AnyTypeInfoKind::Text => AnyValueKind::Text(decode::<DB, String>(value)?.into()),
+            AnyTypeInfoKind::Decimal => {
+                AnyValueKind::Decimal(decode::<DB, AnyDecimal>(value)?.0.into())
+            }
+            AnyTypeInfoKind::Date => AnyValueKind::Date(decode::<DB, _>(value)?),
+            AnyTypeInfoKind::Time => AnyValueKind::Time(decode::<DB, _>(value)?),
+            AnyTypeInfoKind::Timestamp => AnyValueKind::Timestamp(decode::<DB, _>(value)?),
+            AnyTypeInfoKind::Uuid => AnyValueKind::Uuid(decode::<DB, _>(value)?),
+        };
+
+        Ok(AnyValue { kind })
+    }
+}
+
+/// An error converting between an [`AnyValue`] and a value of a specific database driver.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum AnyValueError {
+    /// The `Any` driver has no equivalent for the type of the driver value.
+    #[error("the Any driver does not support the {database} type {type_name}")]
+    UnsupportedType {
+        /// The name of the database, e.g. `PostgreSQL`.
+        database: &'static str,
+        /// The name of the type of the value in the database.
+        type_name: String,
+    },
+
+    /// The database driver has no equivalent for the type of the `Any` value.
+    #[error("{database} does not support the Any type {type_info}")]
+    UnsupportedKind {
+        /// The name of the database, e.g. `PostgreSQL`.
+        database: &'static str,
+        /// The type of the `Any` value.
+        type_info: AnyTypeInfo,
+    },
+
+    /// The driver value could not be decoded.
+    #[error("error decoding the value: {0}")]
+    Decode(#[source] BoxDynError),
+
+    /// The `Any` value could not be encoded for the driver.
+    #[error("error encoding the value: {0}")]
+    Encode(#[source] BoxDynError),
+}
+
+impl From<AnyValueError> for Error {
+    fn from(error: AnyValueError) -> Self {
+        match error {
+            AnyValueError::Decode(source) => Error::Decode(source),
+            AnyValueError::Encode(source) => Error::Encode(source),
+            error => Error::AnyDriverError(Box::new(error)),
+        }
+    }
+}
+
+fn decode<'r, DB: Database, T: Decode<'r, DB>>(
+    valueref: <DB as Database>::ValueRef<'r>,
+) -> crate::Result<T> {
+    Decode::decode(valueref).map_err(Error::decode)
+}
+
 #[derive(Clone, Debug)]
 pub struct AnyValueRef<'a> {
     pub(crate) kind: AnyValueKind<'a>,
@@ -83,6 +293,11 @@ impl Value for AnyValue {
                 AnyValueKind::Double(d) => AnyValueKind::Double(*d),
                 AnyValueKind::Text(t) => AnyValueKind::Text(Cow::Borrowed(t)),
                 AnyValueKind::Blob(b) => AnyValueKind::Blob(Cow::Borrowed(b)),
+                AnyValueKind::Decimal(d) => AnyValueKind::Decimal(Cow::Borrowed(d)),
+                AnyValueKind::Date(d) => AnyValueKind::Date(*d),
+                AnyValueKind::Time(t) => AnyValueKind::Time(*t),
+                AnyValueKind::Timestamp(t) => AnyValueKind::Timestamp(*t),
+                AnyValueKind::Uuid(u) => AnyValueKind::Uuid(*u),
             },
         }
     }
@@ -111,6 +326,11 @@ impl<'a> ValueRef<'a> for AnyValueRef<'a> {
                 AnyValueKind::Double(d) => AnyValueKind::Double(*d),
                 AnyValueKind::Text(t) => AnyValueKind::Text(Cow::Owned(t.to_string())),
                 AnyValueKind::Blob(b) => AnyValueKind::Blob(Cow::Owned(b.to_vec())),
+                AnyValueKind::Decimal(d) => AnyValueKind::Decimal(Cow::Owned(d.to_string())),
+                AnyValueKind::Date(d) => AnyValueKind::Date(*d),
+                AnyValueKind::Time(t) => AnyValueKind::Time(*t),
+                AnyValueKind::Timestamp(t) => AnyValueKind::Timestamp(*t),
+                AnyValueKind::Uuid(u) => AnyValueKind::Uuid(*u),
             },
         }
     }
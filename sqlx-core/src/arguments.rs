@@ -21,16 +21,74 @@ pub trait Arguments<'q>: Send + Sized + Default {
     where
         T: 'q + Encode<'q, Self::Database> + Type<Self::Database>;
 
+    /// Add a query attribute: a named value which is sent along with the query, but is not
+    /// bound to one of its parameters.
+    ///
+    /// Only MySQL 8.0.23 and later support query attributes; the default implementation
+    /// returns an error.
+    fn add_attribute<T>(&mut self, name: &str, value: T) -> Result<(), BoxDynError>
+    where
+        T: 'q + Encode<'q, Self::Database> + Type<Self::Database>,
+    {
+        let _ = (name, value);
+
+        Err("query attributes are not supported by this database".into())
+    }
+
     /// The number of arguments that were already added.
     fn len(&self) -> usize;
 
     fn format_placeholder<W: Write>(&self, writer: &mut W) -> fmt::Result {
         writer.write_str("?")
     }
+
+    /// Copy these arguments, so a query can be retried; `None` if they can't be copied.
+    #[doc(hidden)]
+    fn private_try_clone(&self) -> Option<Self> {
+        None
+    }
 }
 
+/// A value which can be converted into the arguments of a query, such as with
+/// [`query_with()`][crate::query::query_with].
+///
+/// ### Deriving
+///
+/// This trait can be derived for structs with the `derive` feature enabled. Each field is
+/// added to the arguments as a positional parameter, in the order the fields are declared.
+///
+/// ```rust,ignore
+/// #[derive(sqlx::IntoArguments)]
+/// struct NewUser<'a> {
+///     name: &'a str,
+///     email: &'a str,
+/// }
+///
+/// sqlx::query_with(
+///     "INSERT INTO users (name, email) VALUES ($1, $2)",
+///     NewUser { name: "alice", email: "alice@example.com" },
+/// )
+/// .execute(&pool)
+/// .await?;
+/// ```
+///
+/// Fields support the following attributes:
+///
+/// * `#[sqlx(position = N)]` binds the field as the `N`th parameter (starting from 1).
+///   The fields without a position fill the remaining parameters in declaration order.
+/// * `#[sqlx(skip)]` does not bind the field.
+///
+/// The derived [`into_arguments()`][Self::into_arguments] panics if encoding a field fails;
+/// [`query_with()`][crate::query::query_with] reports the error when the query is executed
+/// instead.
 pub trait IntoArguments<'q, DB: Database>: Sized + Send {
     fn into_arguments(self) -> <DB as Database>::Arguments<'q>;
+
+    /// Like [`into_arguments()`][Self::into_arguments], but returns an error if encoding
+    /// an argument failed.
+    fn try_into_arguments(self) -> Result<<DB as Database>::Arguments<'q>, BoxDynError> {
+        Ok(self.into_arguments())
+    }
 }
 
 // NOTE: required due to lack of lazy normalization
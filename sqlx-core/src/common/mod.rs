@@ -1,5 +1,8 @@
 mod statement_cache;
 
+#[cfg(any(feature = "any", feature = "json"))]
+pub(crate) mod civil;
+
 pub use statement_cache::StatementCache;
 use std::fmt::{Debug, Formatter};
 use std::ops::{Deref, DerefMut};
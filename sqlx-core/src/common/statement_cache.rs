@@ -47,6 +47,23 @@ impl<T> StatementCache<T> {
         self.inner.is_empty()
     }
 
+    /// Removes the statement for the given key from the cache, if any.
+    pub fn remove(&mut self, k: &str) -> Option<T> {
+        self.inner.remove(k)
+    }
+
+    /// Removes the statements for which `f` returns `true` from the cache, returning them.
+    pub fn remove_matching(&mut self, mut f: impl FnMut(&T) -> bool) -> Vec<T> {
+        let keys: Vec<String> = self
+            .inner
+            .iter()
+            .filter(|(_, v)| f(v))
+            .map(|(k, _)| k.clone())
+            .collect();
+
+        keys.iter().filter_map(|k| self.inner.remove(k)).collect()
+    }
+
     /// Removes the least recently used item from the cache.
     pub fn remove_lru(&mut self) -> Option<T> {
         self.inner.remove_lru().map(|(_, v)| v)
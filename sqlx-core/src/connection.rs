@@ -1,11 +1,16 @@
 use crate::database::{Database, HasStatementCache};
 use crate::error::Error;
+use crate::logger::QueryLogSink;
+use crate::statement_stats::StatementStats;
 
-use crate::transaction::Transaction;
+use crate::transaction::{
+    AccessMode, IsolationLevel, Transaction, TransactionAbort, TransactionOptions,
+};
 use futures_core::future::BoxFuture;
 use log::LevelFilter;
-use std::fmt::Debug;
+use std::fmt::{self, Debug, Formatter};
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
 use url::Url;
 
@@ -40,7 +45,10 @@ pub trait Connection: Send {
     fn close_hard(self) -> BoxFuture<'static, Result<(), Error>>;
 
     /// Checks if a connection to the database is still valid.
-    fn ping(&mut self) -> BoxFuture<'_, Result<(), Error>>;
+    ///
+    /// Returns the time taken by the round-trip to the server. The check is done at the protocol
+    /// level where possible, so it doesn't show up as a query in the server's statistics.
+    fn ping(&mut self) -> BoxFuture<'_, Result<Duration, Error>>;
 
     /// Begin a new transaction or establish a savepoint within the active transaction.
     ///
@@ -49,6 +57,52 @@ pub trait Connection: Send {
     where
         Self: Sized;
 
+    /// Begin a new transaction with the given isolation level and access mode.
+    ///
+    /// Shorthand for [`.begin_with_options()`][Self::begin_with_options].
+    fn begin_with(
+        &mut self,
+        isolation_level: IsolationLevel,
+        access_mode: AccessMode,
+    ) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
+    where
+        Self: Sized,
+    {
+        Transaction::begin_with(self, (isolation_level, access_mode).into())
+    }
+
+    /// Begin a new transaction with the given options.
+    ///
+    /// Unlike [`.begin()`][Self::begin], this cannot be used to establish a savepoint; it fails
+    /// if a transaction is already active on this connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::postgres::PgConnection;
+    /// use sqlx::{Connection, IsolationLevel, TransactionOptions};
+    ///
+    /// # pub async fn _f(conn: &mut PgConnection) -> sqlx::Result<()> {
+    /// let mut txn = conn
+    ///     .begin_with_options(
+    ///         TransactionOptions::new()
+    ///             .isolation_level(IsolationLevel::RepeatableRead)
+    ///             .read_only(true),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn begin_with_options(
+        &mut self,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<Transaction<'_, Self::Database>, Error>>
+    where
+        Self: Sized,
+    {
+        Transaction::begin_with(self, options)
+    }
+
     /// Execute the function inside a transaction.
     ///
     /// If the function returns an error, the transaction will be rolled back. If it does not
@@ -95,6 +149,83 @@ pub trait Connection: Send {
         })
     }
 
+    /// Execute the function inside a transaction, which it can roll back without failing.
+    ///
+    /// If the function returns `Ok`, the transaction is committed and its value returned in `Ok`.
+    /// If it returns [`Rollback(value)`][crate::transaction::Rollback] as an error, the transaction is rolled back
+    /// and the value returned in `Err`. If it returns any other error, the transaction is
+    /// rolled back and the error returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sqlx::postgres::PgConnection;
+    /// use sqlx::{Connection, Rollback, TransactionAbort};
+    ///
+    /// # pub async fn _f(conn: &mut PgConnection) -> sqlx::Result<()> {
+    /// let outcome = conn
+    ///     .transaction_with_rollback(|txn| Box::pin(async move {
+    ///         let balance: i64 = sqlx::query_scalar(
+    ///             "update accounts set balance = balance - 10 where id = 1 returning balance"
+    ///         )
+    ///         .fetch_one(&mut **txn)
+    ///         .await?;
+    ///
+    ///         if balance < 0 {
+    ///             Err(Rollback("insufficient funds"))?;
+    ///         }
+    ///
+    ///         Ok::<_, TransactionAbort<_>>(balance)
+    ///     }))
+    ///     .await?;
+    ///
+    /// match outcome {
+    ///     Ok(balance) => println!("new balance: {balance}"),
+    ///     Err(reason) => println!("not withdrawn: {reason}"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn transaction_with_rollback<'a, F, R, T, E>(
+        &'a mut self,
+        callback: F,
+    ) -> BoxFuture<'a, Result<Result<R, T>, E>>
+    where
+        for<'c> F: FnOnce(
+                &'c mut Transaction<'_, Self::Database>,
+            ) -> BoxFuture<'c, Result<R, TransactionAbort<T, E>>>
+            + 'a
+            + Send
+            + Sync,
+        Self: Sized,
+        R: Send,
+        T: Send,
+        E: From<Error> + Send,
+    {
+        Box::pin(async move {
+            let mut transaction = self.begin().await?;
+            let ret = callback(&mut transaction).await;
+
+            match ret {
+                Ok(ret) => {
+                    transaction.commit().await?;
+
+                    Ok(Ok(ret))
+                }
+                Err(TransactionAbort::Rollback(value)) => {
+                    transaction.rollback().await?;
+
+                    Ok(Err(value))
+                }
+                Err(TransactionAbort::Error(err)) => {
+                    transaction.rollback().await?;
+
+                    Err(err)
+                }
+            }
+        })
+    }
+
     /// The number of statements currently cached in the connection.
     fn cached_statements_size(&self) -> usize
     where
@@ -112,6 +243,83 @@ pub trait Connection: Send {
         Box::pin(async move { Ok(()) })
     }
 
+    /// Set the maximum time the database may spend executing a statement on this connection
+    /// before aborting it, or remove the limit with `None`.
+    ///
+    /// * Postgres sets `statement_timeout`.
+    /// * MySQL sets `max_execution_time`, which only applies to read-only `SELECT` statements.
+    /// * MariaDB sets `max_statement_time`.
+    ///
+    /// Other databases return an error; see
+    /// [`.supports_statement_timeout()`][Self::supports_statement_timeout].
+    ///
+    /// See also [`PoolOptions::default_statement_timeout()`][crate::pool::PoolOptions::default_statement_timeout].
+    fn set_statement_timeout(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        let _ = timeout;
+
+        Box::pin(async move {
+            Err(Error::Configuration(
+                format!(
+                    "{} does not support setting a statement timeout",
+                    <Self::Database as Database>::NAME
+                )
+                .into(),
+            ))
+        })
+    }
+
+    /// The statement timeout last set with
+    /// [`.set_statement_timeout()`][Self::set_statement_timeout], if any.
+    ///
+    /// Changes made by executing `SET` statements directly are not reflected here.
+    fn statement_timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Whether [`.set_statement_timeout()`][Self::set_statement_timeout] is supported
+    /// by this connection.
+    fn supports_statement_timeout(&self) -> bool {
+        false
+    }
+
+    /// Quickly estimate the number of rows in a table, or returned by a query, from the
+    /// statistics of the query planner, without counting them with `COUNT(*)`.
+    ///
+    /// `table_or_query` is taken as a query if it starts with `SELECT`, `WITH`, `VALUES` or
+    /// `TABLE`, and as the name of a table otherwise. Queries must not have any parameters.
+    ///
+    /// * Postgres sums `pg_class.reltuples` over the table and its partitions or child tables,
+    ///   falling back to `EXPLAIN` if any of them has never been analyzed. Queries are estimated
+    ///   from the `Plan Rows` of `EXPLAIN (FORMAT JSON)`. The table name is resolved like an
+    ///   identifier in SQL would be, so it may be schema-qualified or quoted.
+    /// * MySQL estimates from the `rows` and `filtered` columns of `EXPLAIN`. The table name,
+    ///   optionally qualified with its schema, must not be quoted.
+    ///
+    /// Other databases return an error.
+    ///
+    /// The estimate is only as accurate as the statistics of the database, and may be far off
+    /// for queries with complex conditions or joins; it's meant for decisions like whether to
+    /// show the number of pages in a UI, not for anything which needs the exact count.
+    fn estimate_rows<'e>(
+        &'e mut self,
+        table_or_query: &'e str,
+    ) -> BoxFuture<'e, Result<u64, Error>> {
+        let _ = table_or_query;
+
+        Box::pin(async move {
+            Err(Error::Configuration(
+                format!(
+                    "{} does not support estimating the number of rows",
+                    <Self::Database as Database>::NAME
+                )
+                .into(),
+            ))
+        })
+    }
+
     /// Restore any buffers in the connection to their default capacity, if possible.
     ///
     /// Sending a large query or receiving a resultset with many columns can cause the connection
@@ -126,6 +334,11 @@ pub trait Connection: Send {
     /// allow the buffers to shrink.
     fn shrink_buffers(&mut self);
 
+    /// Drop state tied to the current user of the connection when it is returned to a pool,
+    /// such as subscriptions made through it.
+    #[doc(hidden)]
+    fn reset_on_release(&mut self) {}
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>>;
 
@@ -155,12 +368,15 @@ pub trait Connection: Send {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 #[non_exhaustive]
 pub struct LogSettings {
     pub statements_level: LevelFilter,
     pub slow_statements_level: LevelFilter,
     pub slow_statements_duration: Duration,
+    pub(crate) query_logger: Option<Arc<dyn QueryLogSink>>,
+    pub(crate) query_fingerprints: bool,
+    pub(crate) statement_stats: Option<StatementStats>,
 }
 
 impl Default for LogSettings {
@@ -169,10 +385,26 @@ impl Default for LogSettings {
             statements_level: LevelFilter::Debug,
             slow_statements_level: LevelFilter::Warn,
             slow_statements_duration: Duration::from_secs(1),
+            query_logger: None,
+            query_fingerprints: false,
+            statement_stats: None,
         }
     }
 }
 
+impl Debug for LogSettings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogSettings")
+            .field("statements_level", &self.statements_level)
+            .field("slow_statements_level", &self.slow_statements_level)
+            .field("slow_statements_duration", &self.slow_statements_duration)
+            .field("query_logger", &self.query_logger.is_some())
+            .field("query_fingerprints", &self.query_fingerprints)
+            .field("statement_stats", &self.statement_stats)
+            .finish()
+    }
+}
+
 impl LogSettings {
     pub fn log_statements(&mut self, level: LevelFilter) {
         self.statements_level = level;
@@ -181,6 +413,15 @@ impl LogSettings {
         self.slow_statements_level = level;
         self.slow_statements_duration = duration;
     }
+    pub fn set_query_logger(&mut self, logger: Arc<dyn QueryLogSink>) {
+        self.query_logger = Some(logger);
+    }
+    pub fn log_query_fingerprints(&mut self, enabled: bool) {
+        self.query_fingerprints = enabled;
+    }
+    pub fn set_statement_stats(&mut self, stats: StatementStats) {
+        self.statement_stats = Some(stats);
+    }
 }
 
 pub trait ConnectOptions: 'static + Send + Sync + FromStr<Err = Error> + Debug + Clone {
@@ -230,9 +471,105 @@ pub trait ConnectOptions: 'static + Send + Sync + FromStr<Err = Error> + Debug +
     /// at the specified `level`.
     fn log_slow_statements(self, level: LevelFilter, duration: Duration) -> Self;
 
+    /// Pass every executed statement to `logger` instead of logging it.
+    ///
+    /// The levels set with [`log_statements()`][Self::log_statements] and
+    /// [`log_slow_statements()`][Self::log_slow_statements] are ignored while a logger is set.
+    ///
+    /// ```rust,no_run
+    /// # use sqlx_core::connection::ConnectOptions;
+    /// # use sqlx_core::logger::QueryLogEntry;
+    /// # use std::sync::Arc;
+    /// # fn example<O: ConnectOptions>(options: O) -> O {
+    /// options.query_logger(Arc::new(|entry: &QueryLogEntry<'_>| {
+    ///     eprintln!(
+    ///         "{} took {:?} (success: {})",
+    ///         entry.sql(),
+    ///         entry.elapsed(),
+    ///         entry.is_success()
+    ///     );
+    /// }))
+    /// # }
+    /// ```
+    ///
+    /// The default implementation ignores `logger`; the built-in drivers all support it.
+    fn query_logger(self, logger: Arc<dyn QueryLogSink>) -> Self {
+        let _ = logger;
+        self
+    }
+
+    /// Include the [`QueryFingerprint`] of executed statements in their log events,
+    /// as the `db.fingerprint` field.
+    ///
+    /// This is disabled by default, as it costs a pass over the SQL of every logged statement.
+    /// Loggers set with [`query_logger()`][Self::query_logger] can compute it on demand with
+    /// [`QueryLogEntry::fingerprint()`][crate::logger::QueryLogEntry::fingerprint] instead.
+    ///
+    ///
+    /// The default implementation ignores this; the built-in drivers all support it.
+    ///
+    /// [`QueryFingerprint`]: crate::query_fingerprint::QueryFingerprint
+    fn log_query_fingerprints(self, enabled: bool) -> Self {
+        let _ = enabled;
+        self
+    }
+
+    /// Record the execution count and time of every executed statement in `stats`.
+    ///
+    /// This is independent of statement logging, and of any [`query_logger()`][Self::query_logger].
+    /// See [`statement_stats`][crate::statement_stats] for details.
+    ///
+    /// The default implementation ignores `stats`; the built-in drivers all support it.
+    fn statement_stats(self, stats: StatementStats) -> Self {
+        let _ = stats;
+        self
+    }
+
     /// Entirely disables statement logging (both slow and regular).
     fn disable_statement_logging(self) -> Self {
         self.log_statements(LevelFilter::Off)
             .log_slow_statements(LevelFilter::Off, Duration::default())
     }
 }
+
+/// Returns `true` if `table_or_query`, as passed to [`Connection::estimate_rows()`], is a query
+/// rather than the name of a table.
+#[doc(hidden)]
+pub fn private_is_query(table_or_query: &str) -> bool {
+    let trimmed = table_or_query.trim();
+
+    // a table named like a keyword would have to be quoted anyway
+    ["SELECT", "WITH", "VALUES", "TABLE"].iter().any(|keyword| {
+        trimmed
+            .get(..keyword.len())
+            .is_some_and(|word| word.eq_ignore_ascii_case(keyword))
+            && trimmed[keyword.len()..].starts_with(|c: char| c.is_whitespace() || c == '(')
+    })
+}
+
+#[test]
+fn it_detects_queries_to_estimate() {
+    for query in [
+        "SELECT * FROM users",
+        "  select 1",
+        "WITH t AS (SELECT 1) SELECT * FROM t",
+        "VALUES (1), (2)",
+        "TABLE users",
+        "SELECT(1)",
+    ] {
+        assert!(private_is_query(query), "{query:?}");
+    }
+
+    for table in [
+        "users",
+        "public.users",
+        "\"select\"",
+        "selection",
+        "select",
+        "values_2",
+        "with_x",
+        "table.x",
+    ] {
+        assert!(!private_is_query(table), "{table:?}");
+    }
+}
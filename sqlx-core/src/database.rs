@@ -112,3 +112,25 @@ pub trait Database: 'static + Sized + Send + Debug {
 
 /// A [`Database`] that maintains a client-side cache of prepared statements.
 pub trait HasStatementCache {}
+
+/// A [`Database`] that can report the key generated for a row inserted by an `INSERT` statement.
+///
+/// See [`QueryBuilder::returning_id()`][crate::query_builder::QueryBuilder::returning_id].
+pub trait HasReturningId: Database {
+    /// Returns `true` if the generated key can be read back with a `RETURNING` clause on `conn`;
+    /// `false` if it is instead reported in the query result and retrieved with
+    /// [`last_insert_id()`][Self::last_insert_id].
+    fn supports_returning(conn: &Self::Connection) -> bool;
+
+    /// Get the key generated by the `INSERT` statement that produced `result`, if any, as a value
+    /// to decode it from.
+    fn last_insert_id(result: &Self::QueryResult) -> Option<Self::Value>;
+}
+
+/// A [`Database`] with a known limit on the number of bind parameters in a single query.
+///
+/// See [`QueryBuilder::bulk_insert()`][crate::query_builder::QueryBuilder::bulk_insert].
+pub trait HasBindLimit: Database {
+    /// The default maximum number of bind parameters in a single query.
+    const BIND_LIMIT: usize;
+}
@@ -3,7 +3,7 @@
 use std::any::type_name;
 use std::borrow::Cow;
 use std::error::Error as StdError;
-use std::fmt::Display;
+use std::fmt::{self, Debug, Display, Formatter};
 use std::io;
 
 use crate::database::Database;
@@ -26,6 +26,32 @@ pub type BoxDynError = Box<dyn StdError + 'static + Send + Sync>;
 #[error("unexpected null; try decoding as an `Option`")]
 pub struct UnexpectedNullError;
 
+/// A row which could not be decoded, yielded by `fetch_lossy()` in place of the decoded value.
+///
+/// Unlike an error from `fetch()`, this does not end the stream.
+/// See [`QueryAs::fetch_lossy()`](crate::query_as::QueryAs::fetch_lossy).
+#[derive(thiserror::Error)]
+#[error("error occurred while decoding row {index}: {source}")]
+pub struct RowDecodeError<R> {
+    /// The position of the row in the results, starting at `0`.
+    pub index: usize,
+
+    /// The row which could not be decoded.
+    pub row: R,
+
+    #[source]
+    pub source: Error,
+}
+
+impl<R> Debug for RowDecodeError<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RowDecodeError")
+            .field("index", &self.index)
+            .field("source", &self.source)
+            .finish_non_exhaustive()
+    }
+}
+
 /// Represents all the ways a method can fail within SQLx.
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
@@ -244,6 +270,14 @@ pub trait DatabaseError: 'static + Send + Sync + StdError {
     fn is_check_violation(&self) -> bool {
         matches!(self.kind(), ErrorKind::CheckViolation)
     }
+
+    /// Returns whether the statement failed because of a conflict with a concurrent transaction,
+    /// such as a serialization failure or a deadlock, and may succeed if it is retried.
+    ///
+    /// Used by [`RetryPolicy`][crate::pool::RetryPolicy] to decide which errors to retry.
+    fn is_retryable(&self) -> bool {
+        false
+    }
 }
 
 impl dyn DatabaseError {
@@ -65,6 +65,28 @@ use crate::{error::Error, row::Row};
 /// reason), `lowercase`, `UPPERCASE`, `camelCase`, `PascalCase`, `SCREAMING_SNAKE_CASE` and `kebab-case`.
 /// The styling of each option is intended to be an example of its behavior.
 ///
+/// For other naming schemes, `rename_all` also accepts the path of a function which maps each
+/// field name to its column name. The function is called with the name of the field, after
+/// `rename` if present, and may return any type implementing `AsRef<str>`:
+///
+/// ```rust,ignore
+/// fn prefixed(field: &str) -> String {
+///     format!("usr_{field}")
+/// }
+///
+/// #[derive(sqlx::FromRow)]
+/// #[sqlx(rename_all = prefixed)]
+/// struct User {
+///     // read from "usr_id"
+///     id: i32,
+///     // read from "usr_name"
+///     name: String,
+/// }
+/// ```
+///
+/// As the column names are computed when a row is read, a function is not supported by the
+/// `Type`, `Encode` and `Decode` derives.
+///
 /// #### `default`
 ///
 /// When your struct contains a field that is not present in your query,
@@ -73,11 +73,15 @@ pub mod logger;
 pub mod net;
 pub mod query_as;
 pub mod query_builder;
+pub mod query_context;
+pub mod query_fingerprint;
 pub mod query_scalar;
 
 pub mod raw_sql;
 pub mod row;
 pub mod rt;
+pub mod sequence;
+pub mod statement_stats;
 pub mod sync;
 pub mod type_checking;
 pub mod type_info;
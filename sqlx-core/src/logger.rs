@@ -1,5 +1,8 @@
 use crate::connection::LogSettings;
-use std::time::Instant;
+use crate::error::Error;
+use crate::query_context::QueryContext;
+use crate::query_fingerprint::QueryFingerprint;
+use std::time::{Duration, Instant};
 
 // Yes these look silly. `tracing` doesn't currently support dynamic levels
 // https://github.com/tokio-rs/tracing/issues/372
@@ -62,23 +65,123 @@ pub(crate) fn private_level_filter_to_trace_level(
 
 pub use sqlformat;
 
+/// Receives a [`QueryLogEntry`] for every statement executed on a connection, in place of the
+/// built-in statement logging.
+///
+/// Install with [`ConnectOptions::query_logger()`][crate::connection::ConnectOptions::query_logger]
+/// or [`PoolOptions::query_logger()`][crate::pool::PoolOptions::query_logger]. This is
+/// implemented for closures taking a `&QueryLogEntry<'_>`.
+///
+/// `log()` is called on the task executing the statement, once it has completed or the stream
+/// of its results was dropped, so it should return quickly.
+pub trait QueryLogSink: Send + Sync + 'static {
+    fn log(&self, entry: &QueryLogEntry<'_>);
+}
+
+impl<F> QueryLogSink for F
+where
+    F: Fn(&QueryLogEntry<'_>) + Send + Sync + 'static,
+{
+    fn log(&self, entry: &QueryLogEntry<'_>) {
+        self(entry)
+    }
+}
+
+/// A statement executed on a connection, passed to a [`QueryLogSink`].
+#[derive(Debug)]
+pub struct QueryLogEntry<'a> {
+    sql: &'a str,
+    arguments: usize,
+    rows_returned: u64,
+    rows_affected: u64,
+    elapsed: Duration,
+    error: Option<&'a str>,
+    context: Option<&'a QueryContext>,
+}
+
+impl<'a> QueryLogEntry<'a> {
+    /// The SQL of the statement.
+    pub fn sql(&self) -> &'a str {
+        self.sql
+    }
+
+    /// The number of arguments bound to the statement.
+    pub fn arguments(&self) -> usize {
+        self.arguments
+    }
+
+    /// The number of rows returned by the statement.
+    pub fn rows_returned(&self) -> u64 {
+        self.rows_returned
+    }
+
+    /// The number of rows affected by the statement.
+    pub fn rows_affected(&self) -> u64 {
+        self.rows_affected
+    }
+
+    /// The time from the start of execution until the statement completed or was dropped.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns `true` if the statement did not return an error.
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// The error returned by the statement, if any.
+    pub fn error(&self) -> Option<&'a str> {
+        self.error
+    }
+
+    /// The [`QueryContext`] the statement was executed in, if any.
+    pub fn context(&self) -> Option<&'a QueryContext> {
+        self.context
+    }
+
+    /// Compute the [`QueryFingerprint`] of the statement.
+    pub fn fingerprint(&self) -> QueryFingerprint {
+        QueryFingerprint::new(self.sql)
+    }
+}
+
 pub struct QueryLogger<'q> {
     sql: &'q str,
+    arguments: usize,
     rows_returned: u64,
     rows_affected: u64,
+    error: Option<String>,
     start: Instant,
     settings: LogSettings,
+    context: Option<QueryContext>,
 }
 
 impl<'q> QueryLogger<'q> {
-    pub fn new(sql: &'q str, settings: LogSettings) -> Self {
+    pub fn new(sql: &'q str, arguments: usize, settings: LogSettings) -> Self {
         Self {
             sql,
+            arguments,
             rows_returned: 0,
             rows_affected: 0,
+            error: None,
             start: Instant::now(),
             settings,
+            context: QueryContext::current(),
+        }
+    }
+
+    /// Record the error returned by the statement, if any, and pass `result` through.
+    pub fn record<T>(&mut self, result: Result<T, Error>) -> Result<T, Error> {
+        if let Err(error) = &result {
+            self.record_error(error);
         }
+
+        result
+    }
+
+    pub fn record_error(&mut self, error: &Error) {
+        self.error = Some(error.to_string());
     }
 
     pub fn increment_rows_returned(&mut self) {
@@ -92,6 +195,30 @@ impl<'q> QueryLogger<'q> {
     pub fn finish(&self) {
         let elapsed = self.start.elapsed();
 
+        if let Some(stats) = &self.settings.statement_stats {
+            stats.record(
+                self.sql,
+                elapsed,
+                self.rows_returned,
+                self.rows_affected,
+                self.error.is_none(),
+            );
+        }
+
+        if let Some(logger) = &self.settings.query_logger {
+            logger.log(&QueryLogEntry {
+                sql: self.sql,
+                arguments: self.arguments,
+                rows_returned: self.rows_returned,
+                rows_affected: self.rows_affected,
+                elapsed,
+                error: self.error.as_deref(),
+                context: self.context.as_ref(),
+            });
+
+            return;
+        }
+
         let was_slow = elapsed >= self.settings.slow_statements_duration;
 
         let lvl = if was_slow {
@@ -122,6 +249,11 @@ impl<'q> QueryLogger<'q> {
                     String::new()
                 };
 
+                let fingerprint = self
+                    .settings
+                    .query_fingerprints
+                    .then(|| QueryFingerprint::new(self.sql));
+
                 if was_slow {
                     private_tracing_dynamic_event!(
                         target: "sqlx::query",
@@ -130,6 +262,8 @@ impl<'q> QueryLogger<'q> {
                         db.statement = sql,
                         rows_affected = self.rows_affected,
                         rows_returned = self.rows_returned,
+                        context = self.context.as_ref().map(tracing::field::display),
+                        db.fingerprint = fingerprint.as_ref().map(tracing::field::display),
                         // Human-friendly - includes units (usually ms). Also kept for backward compatibility
                         ?elapsed,
                         // Search friendly - numeric
@@ -148,6 +282,8 @@ impl<'q> QueryLogger<'q> {
                         db.statement = sql,
                         rows_affected = self.rows_affected,
                         rows_returned = self.rows_returned,
+                        context = self.context.as_ref().map(tracing::field::display),
+                        db.fingerprint = fingerprint.as_ref().map(tracing::field::display),
                         // Human-friendly - includes units (usually ms). Also kept for backward compatibility
                         ?elapsed,
                         // Search friendly - numeric
@@ -1,4 +1,5 @@
 use crate::error::{BoxDynError, Error};
+use std::time::Duration;
 
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
@@ -30,6 +31,9 @@ pub enum MigrateError {
     #[error("database driver does not support force-dropping a database (Only PostgreSQL)")]
     ForceNotSupported,
 
+    #[error("database driver does not support running migrations in a schema (Only PostgreSQL and MySQL)")]
+    SchemaNotSupported,
+
     #[deprecated = "migration types are now inferred"]
     #[error("cannot mix reversible migrations with simple migrations. All migrations should be reversible or simple migrations")]
     InvalidMixReversibleAndSimple,
@@ -39,4 +43,7 @@ pub enum MigrateError {
         "migration {0} is partially applied; fix and remove row from `_sqlx_migrations` table"
     )]
     Dirty(i64),
+
+    #[error("timed out after {0:?} waiting for the migration lock")]
+    LockTimeout(Duration),
 }
@@ -43,6 +43,32 @@ pub trait Migrate {
     // any migrations.
     fn lock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>>;
 
+    // Like `lock()`, but should give up with `MigrateError::LockTimeout` if the lock
+    // could not be acquired within `timeout`. Defaults to `lock()`, ignoring the timeout.
+    fn lock_with_timeout(&mut self, timeout: Duration) -> BoxFuture<'_, Result<(), MigrateError>> {
+        let _ = timeout;
+        self.lock()
+    }
+
+    // Should create `schema` if it does not exist and make it the default for unqualified
+    // names on this connection, returning the previous setting to pass to `leave_schema()`.
+    fn enter_schema<'e>(
+        &'e mut self,
+        schema: &'e str,
+    ) -> BoxFuture<'e, Result<Option<String>, MigrateError>> {
+        let _ = schema;
+        Box::pin(async { Err(MigrateError::SchemaNotSupported) })
+    }
+
+    // Should restore the setting returned by `enter_schema()`.
+    fn leave_schema<'e>(
+        &'e mut self,
+        previous: Option<&'e str>,
+    ) -> BoxFuture<'e, Result<(), MigrateError>> {
+        let _ = previous;
+        Box::pin(async { Err(MigrateError::SchemaNotSupported) })
+    }
+
     // Should release the lock. [`Migrate`] will call this function after all
     // migrations have been run.
     fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>>;
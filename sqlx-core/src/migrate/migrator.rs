@@ -4,6 +4,7 @@ use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 use std::slice;
+use std::time::Duration;
 
 /// A resolved set of migrations, ready to be run.
 ///
@@ -23,6 +24,28 @@ pub struct Migrator {
     pub locking: bool,
     #[doc(hidden)]
     pub no_tx: bool,
+    #[doc(hidden)]
+    pub lock_timeout: Option<Duration>,
+}
+
+/// The progress of [`Migrator::run_with_progress()`], reported for each migration it applies.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum MigrationProgress<'m> {
+    /// The migration is about to be applied.
+    Applying {
+        migration: &'m Migration,
+        /// The zero-based position of this migration among those being applied.
+        index: usize,
+        /// The number of migrations being applied.
+        total: usize,
+    },
+    /// The migration was applied.
+    Applied {
+        migration: &'m Migration,
+        /// How long it took to apply the migration.
+        elapsed: Duration,
+    },
 }
 
 fn validate_applied_migrations(
@@ -51,6 +74,7 @@ impl Migrator {
         ignore_missing: false,
         no_tx: false,
         locking: true,
+        lock_timeout: None,
     };
 
     /// Creates a new instance with the given source.
@@ -100,6 +124,15 @@ impl Migrator {
         self
     }
 
+    /// Specify how long to wait for another client to release the migration lock before failing
+    /// with [`MigrateError::LockTimeout`]. Defaults to waiting indefinitely.
+    ///
+    /// This has no effect if locking is disabled, or with SQLite, which does not take a lock.
+    pub fn set_lock_timeout(&mut self, timeout: impl Into<Option<Duration>>) -> &Self {
+        self.lock_timeout = timeout.into();
+        self
+    }
+
     /// Get an iterator over all known migrations.
     pub fn iter(&self) -> slice::Iter<'_, Migration> {
         self.migrations.iter()
@@ -137,15 +170,116 @@ impl Migrator {
         self.run_direct(&mut *conn).await
     }
 
+    /// Like [`.run()`][Self::run], but calls `progress` before and after applying each
+    /// pending migration.
+    ///
+    /// This is intended for applications which migrate their database at startup, possibly from
+    /// many instances at once; the instances which have to wait for the migration lock
+    /// (see [`.set_lock_timeout()`][Self::set_lock_timeout]) find nothing left to apply.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use sqlx::migrate::MigrateError;
+    /// # fn main() -> Result<(), MigrateError> {
+    /// #     sqlx::__rt::test_block_on(async move {
+    /// use sqlx::migrate::{MigrationProgress, Migrator};
+    /// use sqlx::sqlite::SqlitePoolOptions;
+    /// use std::time::Duration;
+    ///
+    /// let mut m = Migrator::new(std::path::Path::new("./migrations")).await?;
+    /// m.set_lock_timeout(Duration::from_secs(60));
+    ///
+    /// let pool = SqlitePoolOptions::new().connect("sqlite::memory:").await?;
+    /// m.run_with_progress(&pool, |progress| {
+    ///     if let MigrationProgress::Applied { migration, elapsed, .. } = progress {
+    ///         println!("applied {} in {elapsed:?}", migration.description);
+    ///     }
+    /// })
+    /// .await
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn run_with_progress<'a, A, F>(
+        &self,
+        migrator: A,
+        mut progress: F,
+    ) -> Result<(), MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+        F: FnMut(MigrationProgress<'_>) + Send,
+    {
+        let mut conn = migrator.acquire().await?;
+        self.run_inner(&mut *conn, &mut progress).await
+    }
+
+    /// Like [`.run()`][Self::run], but runs the migrations in `schema`, creating it if it does
+    /// not exist.
+    ///
+    /// Unqualified names in the migrations, as well as the `_sqlx_migrations` table which
+    /// tracks them, resolve to `schema`, so the same migrations can be applied to one schema
+    /// per tenant and each schema keeps its own migration history.
+    ///
+    /// * Postgres sets `search_path` to `schema` for the duration of the migrations.
+    /// * MySQL, where a schema is a database, switches to it with `USE`.
+    ///
+    /// The previous setting is restored afterwards. Other databases return
+    /// [`MigrateError::SchemaNotSupported`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use sqlx::migrate::MigrateError;
+    /// # fn main() -> Result<(), MigrateError> {
+    /// #     sqlx::__rt::test_block_on(async move {
+    /// use sqlx::migrate::Migrator;
+    /// use sqlx::postgres::PgPoolOptions;
+    ///
+    /// let m = Migrator::new(std::path::Path::new("./migrations")).await?;
+    /// let pool = PgPoolOptions::new().connect("postgres://localhost/app").await?;
+    ///
+    /// for tenant in ["tenant_a", "tenant_b"] {
+    ///     m.run_in_schema(&pool, tenant).await?;
+    /// }
+    /// #     Ok(())
+    /// #     })
+    /// # }
+    /// ```
+    pub async fn run_in_schema<'a, A>(&self, migrator: A, schema: &str) -> Result<(), MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+    {
+        let mut conn = migrator.acquire().await?;
+
+        let previous = conn.enter_schema(schema).await?;
+        let res = self.run_direct(&mut *conn).await;
+        let restored = conn.leave_schema(previous.as_deref()).await;
+
+        res.and(restored)
+    }
+
     // Getting around the annoying "implementation of `Acquire` is not general enough" error
     #[doc(hidden)]
     pub async fn run_direct<C>(&self, conn: &mut C) -> Result<(), MigrateError>
+    where
+        C: Migrate,
+    {
+        self.run_inner(conn, &mut |_| {}).await
+    }
+
+    async fn run_inner<C>(
+        &self,
+        conn: &mut C,
+        progress: &mut (dyn FnMut(MigrationProgress<'_>) + Send),
+    ) -> Result<(), MigrateError>
     where
         C: Migrate,
     {
         // lock the database for exclusive access by the migrator
         if self.locking {
-            conn.lock().await?;
+            self.lock(conn).await?;
         }
 
         // creates [_migrations] table only if needed
@@ -165,6 +299,8 @@ impl Migrator {
             .map(|m| (m.version, m))
             .collect();
 
+        let mut pending = Vec::new();
+
         for migration in self.iter() {
             if migration.migration_type.is_down_migration() {
                 continue;
@@ -176,12 +312,24 @@ impl Migrator {
                         return Err(MigrateError::VersionMismatch(migration.version));
                     }
                 }
-                None => {
-                    conn.apply(migration).await?;
-                }
+                None => pending.push(migration),
             }
         }
 
+        let total = pending.len();
+
+        for (index, migration) in pending.into_iter().enumerate() {
+            progress(MigrationProgress::Applying {
+                migration,
+                index,
+                total,
+            });
+
+            let elapsed = conn.apply(migration).await?;
+
+            progress(MigrationProgress::Applied { migration, elapsed });
+        }
+
         // unlock the migrator to allow other migrators to run
         // but do nothing as we already migrated
         if self.locking {
@@ -217,7 +365,7 @@ impl Migrator {
 
         // lock the database for exclusive access by the migrator
         if self.locking {
-            conn.lock().await?;
+            self.lock(&mut *conn).await?;
         }
 
         // creates [_migrations] table only if needed
@@ -255,4 +403,14 @@ impl Migrator {
 
         Ok(())
     }
+
+    async fn lock<C>(&self, conn: &mut C) -> Result<(), MigrateError>
+    where
+        C: Migrate + ?Sized,
+    {
+        match self.lock_timeout {
+            Some(timeout) => conn.lock_with_timeout(timeout).await,
+            None => conn.lock().await,
+        }
+    }
 }
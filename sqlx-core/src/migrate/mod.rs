@@ -10,7 +10,7 @@ pub use error::MigrateError;
 pub use migrate::{Migrate, MigrateDatabase};
 pub use migration::{AppliedMigration, Migration};
 pub use migration_type::MigrationType;
-pub use migrator::Migrator;
+pub use migrator::{MigrationProgress, Migrator};
 pub use source::MigrationSource;
 
 #[doc(hidden)]
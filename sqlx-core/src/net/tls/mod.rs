@@ -1,8 +1,13 @@
 #![allow(dead_code)]
 
+use std::fmt::{self, Debug, Formatter};
+use std::future::Future;
 use std::path::PathBuf;
+use std::sync::Arc;
 
-use crate::error::Error;
+use futures_core::future::BoxFuture;
+
+use crate::error::{BoxDynError, Error};
 use crate::net::socket::WithSocket;
 use crate::net::Socket;
 
@@ -14,13 +19,48 @@ mod tls_native_tls;
 
 mod util;
 
-/// X.509 Certificate input, either a file path or a PEM encoded inline certificate(s).
+/// X.509 Certificate input, either a file path, a PEM encoded inline certificate(s),
+/// or a callback returning PEM encoded certificate(s).
+///
+/// Files and callbacks are read again for every new connection, so certificates rotated on disk
+/// or by the callback are picked up without recreating the pool.
+/// See [`Pool::reload_tls()`][crate::pool::Pool::reload_tls] to replace existing connections.
+///
+/// ### Note: Breaking Change
+/// `Callback` was added and the enum made `#[non_exhaustive]` in 0.8.0, so code matching on it
+/// needs a wildcard arm.
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub enum CertificateInput {
     /// PEM encoded certificate(s)
     Inline(Vec<u8>),
     /// Path to a file containing PEM encoded certificate(s)
     File(PathBuf),
+    /// Callback returning PEM encoded certificate(s), invoked for every new connection
+    Callback(CertificateCallback),
+}
+
+/// A callback returning PEM encoded certificate(s) or a key, for [`CertificateInput::Callback`].
+#[derive(Clone)]
+pub struct CertificateCallback(
+    Arc<dyn Fn() -> BoxFuture<'static, Result<Vec<u8>, BoxDynError>> + Send + Sync>,
+);
+
+impl CertificateInput {
+    /// Create a [`CertificateInput::Callback`] from an async function returning PEM encoded data.
+    pub fn from_fn<F, Fut>(f: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, BoxDynError>> + Send + 'static,
+    {
+        CertificateInput::Callback(CertificateCallback(Arc::new(move || Box::pin(f()))))
+    }
+}
+
+impl Debug for CertificateCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CertificateCallback").finish_non_exhaustive()
+    }
 }
 
 impl From<String> for CertificateInput {
@@ -43,6 +83,9 @@ impl CertificateInput {
         match self {
             CertificateInput::Inline(v) => Ok(v.clone()),
             CertificateInput::File(path) => fs::read(path).await,
+            CertificateInput::Callback(callback) => {
+                (callback.0)().await.map_err(std::io::Error::other)
+            }
         }
     }
 }
@@ -52,6 +95,7 @@ impl std::fmt::Display for CertificateInput {
         match self {
             CertificateInput::Inline(v) => write!(f, "{}", String::from_utf8_lossy(v.as_slice())),
             CertificateInput::File(path) => write!(f, "file: {}", path.display()),
+            CertificateInput::Callback(_) => write!(f, "callback"),
         }
     }
 }
@@ -1,16 +1,22 @@
+use std::backtrace::Backtrace;
 use std::fmt::{self, Debug, Formatter};
 use std::ops::{Deref, DerefMut};
-use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex, Weak};
 use std::time::{Duration, Instant};
 
+use futures_channel::oneshot;
+
 use crate::sync::AsyncSemaphoreReleaser;
 
 use crate::connection::Connection;
 use crate::database::Database;
 use crate::error::Error;
 
-use super::inner::{is_beyond_max_lifetime, DecrementSizeGuard, PoolInner};
-use crate::pool::options::PoolConnectionMetadata;
+use super::inner::{
+    is_beyond_max_lifetime, is_beyond_max_uses, is_tls_outdated, DecrementSizeGuard, PoolInner,
+};
+use crate::pool::options::{PoolConnectionLeak, PoolConnectionMetadata};
 use std::future::Future;
 
 /// A connection managed by a [`Pool`][crate::pool::Pool].
@@ -18,12 +24,17 @@ use std::future::Future;
 /// Will be returned to the pool on-drop.
 pub struct PoolConnection<DB: Database> {
     live: Option<Live<DB>>,
+    leak: Option<Arc<LeakTracker>>,
     pub(crate) pool: Arc<PoolInner<DB>>,
 }
 
 pub(super) struct Live<DB: Database> {
     pub(super) raw: DB::Connection,
     pub(super) created_at: Instant,
+    /// The number of times the connection has been returned to the pool.
+    pub(super) uses: u64,
+    /// The value of `PoolInner::tls_generation` when the connection was opened.
+    pub(super) tls_generation: u64,
 }
 
 pub(super) struct Idle<DB: Database> {
@@ -31,6 +42,21 @@ pub(super) struct Idle<DB: Database> {
     pub(super) idle_since: Instant,
 }
 
+/// Watched by a background task while a connection is checked out, if leak detection is enabled.
+struct LeakTracker {
+    acquired_at: Instant,
+    backtrace: Backtrace,
+    state: Mutex<LeakState>,
+    // dropped with the tracker when the connection is returned, which wakes and stops the task
+    _returned: oneshot::Sender<()>,
+}
+
+struct LeakState {
+    // `None` if the connection is exempt from leak detection
+    deadline: Option<Instant>,
+    tag: Option<String>,
+}
+
 /// RAII wrapper for connections being handled by functions that may drop them
 pub(super) struct Floating<DB: Database, C> {
     pub(super) inner: C,
@@ -110,6 +136,34 @@ impl<DB: Database> PoolConnection<DB> {
         self.take_live().raw
     }
 
+    /// Give this connection a tag to identify it if it is reported as leaked.
+    ///
+    /// See [`PoolOptions::leak_detection_threshold()`][crate::pool::PoolOptions::leak_detection_threshold].
+    pub fn set_tag(&mut self, tag: impl Into<String>) {
+        if let Some(leak) = &self.leak {
+            leak.state
+                .lock()
+                .expect("BUG: panicked while holding a lock")
+                .tag = Some(tag.into());
+        }
+    }
+
+    /// Exempt this connection from the pool's leak detection, for jobs which are expected to
+    /// hold it for a long time. It is only reported if it is still held after `timeout`
+    /// from now, or never if `timeout` is `None`.
+    ///
+    /// Unlike [`.detach()`][Self::detach], the connection is still returned to the pool on-drop.
+    ///
+    /// See [`PoolOptions::leak_detection_threshold()`][crate::pool::PoolOptions::leak_detection_threshold].
+    pub fn detach_with_timeout(&mut self, timeout: impl Into<Option<Duration>>) {
+        if let Some(leak) = &self.leak {
+            leak.state
+                .lock()
+                .expect("BUG: panicked while holding a lock")
+                .deadline = timeout.into().map(|timeout| Instant::now() + timeout);
+        }
+    }
+
     fn take_live(&mut self) -> Live<DB> {
         self.live.take().expect(EXPECT_MSG)
     }
@@ -171,6 +225,88 @@ impl<DB: Database> Drop for PoolConnection<DB> {
     }
 }
 
+impl LeakTracker {
+    fn start<DB: Database>(pool: &PoolInner<DB>) -> Option<Arc<Self>> {
+        let threshold = pool.options.leak_detection_threshold?;
+        let acquired_at = Instant::now();
+        let (returned_tx, mut returned_rx) = oneshot::channel::<()>();
+
+        let tracker = Arc::new(LeakTracker {
+            acquired_at,
+            backtrace: Backtrace::capture(),
+            state: Mutex::new(LeakState {
+                deadline: Some(acquired_at + threshold),
+                tag: None,
+            }),
+            _returned: returned_tx,
+        });
+
+        let on_leak = pool.options.on_leak.clone();
+        let weak = Arc::downgrade(&tracker);
+
+        crate::rt::spawn(async move {
+            // the connection has been returned once the `PoolConnection` is dropped
+            while let Some(deadline) = deadline(&weak) {
+                let now = Instant::now();
+
+                if deadline > now {
+                    if crate::rt::timeout(deadline - now, &mut returned_rx)
+                        .await
+                        .is_ok()
+                    {
+                        // the sender was dropped, so the connection was returned
+                        return;
+                    }
+
+                    continue;
+                }
+
+                let Some(tracker) = weak.upgrade() else {
+                    return;
+                };
+
+                // release the lock first so `on_leak` can't deadlock against `PoolConnection::set_tag()`
+                let tag = tracker
+                    .state
+                    .lock()
+                    .expect("BUG: panicked while holding a lock")
+                    .tag
+                    .clone();
+
+                let leak = PoolConnectionLeak {
+                    held_for: tracker.acquired_at.elapsed(),
+                    tag: tag.as_deref(),
+                    backtrace: &tracker.backtrace,
+                };
+
+                match &on_leak {
+                    Some(on_leak) => on_leak(&leak),
+                    None => tracing::warn!(
+                        target: "sqlx::pool::leak",
+                        held_for = ?leak.held_for,
+                        tag = leak.tag,
+                        "connection held longer than the leak detection threshold; acquired at:\n{}",
+                        leak.backtrace,
+                    ),
+                }
+
+                return;
+            }
+        });
+
+        Some(tracker)
+    }
+}
+
+fn deadline(tracker: &Weak<LeakTracker>) -> Option<Instant> {
+    tracker
+        .upgrade()?
+        .state
+        .lock()
+        .expect("BUG: panicked while holding a lock")
+        .deadline
+}
+
 impl<DB: Database> Live<DB> {
     pub fn float(self, pool: Arc<PoolInner<DB>>) -> Floating<DB, Self> {
         Floating {
@@ -203,11 +339,17 @@ impl<DB: Database> DerefMut for Idle<DB> {
 }
 
 impl<DB: Database> Floating<DB, Live<DB>> {
-    pub fn new_live(conn: DB::Connection, guard: DecrementSizeGuard<DB>) -> Self {
+    pub fn new_live(conn: DB::Connection, mut guard: DecrementSizeGuard<DB>) -> Self {
+        guard.opened();
+
+        let tls_generation = guard.pool.tls_generation.load(Ordering::Acquire);
+
         Self {
             inner: Live {
                 raw: conn,
                 created_at: Instant::now(),
+                uses: 0,
+                tls_generation,
             },
             guard,
         }
@@ -221,6 +363,7 @@ impl<DB: Database> Floating<DB, Live<DB>> {
         guard.cancel();
         PoolConnection {
             live: Some(inner),
+            leak: LeakTracker::start(&pool),
             pool,
         }
     }
@@ -239,9 +382,14 @@ impl<DB: Database> Floating<DB, Live<DB>> {
             return false;
         }
 
-        // If the connection is beyond max lifetime, close the connection and
-        // immediately create a new connection
-        if is_beyond_max_lifetime(&self.inner, &self.guard.pool.options) {
+        self.inner.uses += 1;
+
+        // If the connection is beyond max lifetime or max uses, or was opened before
+        // `Pool::reload_tls()`, close the connection and immediately create a new connection
+        if is_beyond_max_lifetime(&self.inner, &self.guard.pool.options)
+            || is_beyond_max_uses(&self.inner, &self.guard.pool.options)
+            || is_tls_outdated(&self.inner, &self.guard.pool)
+        {
             self.close().await;
             return false;
         }
@@ -264,6 +412,21 @@ impl<DB: Database> Floating<DB, Live<DB>> {
             }
         }
 
+        // reset a statement timeout changed by the user to the default of the pool
+        if let Some(timeout) = self.guard.pool.options.default_statement_timeout {
+            if self.raw.supports_statement_timeout()
+                && self.raw.statement_timeout() != Some(timeout)
+            {
+                if let Err(error) = self.raw.set_statement_timeout(Some(timeout)).await {
+                    tracing::warn!(%error, "error resetting the statement timeout on-release");
+                    self.close_hard().await;
+                    return false;
+                }
+            }
+        }
+
+        self.raw.reset_on_release();
+
         // test the connection on-release to ensure it is still viable,
         // and flush anything time-sensitive like transaction rollbacks
         // if an Executor future/stream is dropped during an `.await` call, the connection
@@ -329,7 +492,7 @@ impl<DB: Database> Floating<DB, Idle<DB>> {
         }
     }
 
-    pub async fn ping(&mut self) -> Result<(), Error> {
+    pub async fn ping(&mut self) -> Result<Duration, Error> {
         self.live.raw.ping().await
     }
 
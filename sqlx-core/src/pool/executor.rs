@@ -7,7 +7,8 @@ use crate::database::Database;
 use crate::describe::Describe;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
-use crate::pool::Pool;
+use crate::pool::retry::RetriedQuery;
+use crate::pool::{Pool, RetryPolicy};
 
 impl<'p, DB: Database> Executor<'p> for &'_ Pool<DB>
 where
@@ -25,14 +26,52 @@ where
         let pool = self.clone();
 
         Box::pin(try_stream! {
-            let mut conn = pool.acquire().await?;
-            let mut s = conn.fetch_many(query);
+            let Some(policy) = pool.query_retry_policy() else {
+                let mut conn = pool.acquire().await?;
+                let mut s = conn.fetch_many(query);
 
-            while let Some(v) = s.try_next().await? {
-                r#yield!(v);
-            }
+                while let Some(v) = s.try_next().await? {
+                    r#yield!(v);
+                }
+
+                return Ok(());
+            };
+
+            let mut query = RetriedQuery::new(query)?;
+            let mut attempt = 1;
+
+            loop {
+                let next = query.try_clone();
+                let mut yielded = false;
+
+                let mut conn = pool.acquire().await?;
+                let mut s = conn.fetch_many(query);
 
-            Ok(())
+                let error = loop {
+                    match s.try_next().await {
+                        Ok(Some(v)) => {
+                            yielded = true;
+                            r#yield!(v);
+                        }
+                        Ok(None) => return Ok(()),
+                        Err(error) => break error,
+                    }
+                };
+
+                // results were already returned, so the query can't be retried transparently
+                match next.filter(|_| !yielded).zip(policy.query_backoff_after(attempt, &error)) {
+                    Some((next, backoff)) => {
+                        tracing::debug!(%error, attempt, ?backoff, "query failed; retrying");
+                        drop(s);
+                        drop(conn);
+
+                        crate::rt::sleep(backoff).await;
+                        query = next;
+                        attempt += 1;
+                    }
+                    None => return Err(error),
+                }
+            }
         })
     }
 
@@ -45,7 +84,34 @@ where
     {
         let pool = self.clone();
 
-        Box::pin(async move { pool.acquire().await?.fetch_optional(query).await })
+        Box::pin(async move {
+            let Some(policy) = pool.query_retry_policy() else {
+                return pool.acquire().await?.fetch_optional(query).await;
+            };
+
+            let mut query = RetriedQuery::new(query)?;
+            let mut attempt = 1;
+
+            loop {
+                let next = query.try_clone();
+
+                let error = match pool.acquire().await?.fetch_optional(query).await {
+                    Ok(row) => return Ok(row),
+                    Err(error) => error,
+                };
+
+                match next.zip(policy.query_backoff_after(attempt, &error)) {
+                    Some((next, backoff)) => {
+                        tracing::debug!(%error, attempt, ?backoff, "query failed; retrying");
+
+                        crate::rt::sleep(backoff).await;
+                        query = next;
+                        attempt += 1;
+                    }
+                    None => return Err(error),
+                }
+            }
+        })
     }
 
     fn prepare_with<'e, 'q: 'e>(
@@ -69,6 +135,17 @@ where
     }
 }
 
+impl<DB: Database> Pool<DB> {
+    /// The retry policy of the pool, if it retries queries.
+    fn query_retry_policy(&self) -> Option<RetryPolicy> {
+        self.0
+            .options
+            .retry_policy
+            .clone()
+            .filter(|policy| policy.retry_queries)
+    }
+}
+
 // Causes an overflow when evaluating `&mut DB::Connection: Executor`.
 //
 //
@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+/// The result of [`Pool::health_check()`][super::Pool::health_check].
+#[derive(Debug, Clone)]
+pub struct PoolHealth {
+    pub(crate) latencies: Vec<Duration>,
+    pub(crate) num_failed: usize,
+}
+
+impl PoolHealth {
+    /// The round-trip time of each connection which responded to the ping.
+    ///
+    /// Never empty, as the health check fails if no connection responded.
+    pub fn latencies(&self) -> &[Duration] {
+        &self.latencies
+    }
+
+    /// The number of connections which failed to respond, and were closed.
+    pub fn num_failed(&self) -> usize {
+        self.num_failed
+    }
+
+    /// The shortest round-trip time.
+    pub fn min_latency(&self) -> Duration {
+        self.latencies.iter().copied().min().unwrap_or_default()
+    }
+
+    /// The longest round-trip time.
+    pub fn max_latency(&self) -> Duration {
+        self.latencies.iter().copied().max().unwrap_or_default()
+    }
+
+    /// The mean round-trip time.
+    pub fn mean_latency(&self) -> Duration {
+        let count = u32::try_from(self.latencies.len()).unwrap_or(u32::MAX);
+
+        if count == 0 {
+            return Duration::ZERO;
+        }
+
+        self.latencies.iter().sum::<Duration>() / count
+    }
+}
@@ -3,21 +3,24 @@ use crate::connection::ConnectOptions;
 use crate::connection::Connection;
 use crate::database::Database;
 use crate::error::Error;
-use crate::pool::{deadline_as_timeout, CloseEvent, Pool, PoolOptions};
+use crate::pool::metrics::MetricsRecorder;
+use crate::pool::{deadline_as_timeout, CloseEvent, Pool, PoolHealth, PoolMetrics, PoolOptions};
 use crossbeam_queue::ArrayQueue;
 
 use crate::sync::{AsyncSemaphore, AsyncSemaphoreReleaser};
 
 use std::cmp;
 use std::future::Future;
-use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
 use std::task::Poll;
 
 use crate::logger::private_level_filter_to_trace_level;
 use crate::pool::options::PoolConnectionMetadata;
+use crate::pool::waiters::{Waiter, Waiters};
+use crate::pool::watchdog::{spawn_watchdog, WaitingGuard};
 use crate::private_tracing_dynamic_event;
-use futures_util::future::{self};
+use futures_util::future::{self, Either};
 use futures_util::FutureExt;
 use std::time::{Duration, Instant};
 use tracing::Level;
@@ -26,8 +29,17 @@ pub(crate) struct PoolInner<DB: Database> {
     pub(super) connect_options: RwLock<Arc<<DB::Connection as Connection>::Options>>,
     pub(super) idle_conns: ArrayQueue<Idle<DB>>,
     pub(super) semaphore: AsyncSemaphore,
+    /// The tasks waiting in `acquire()` for a permit from `semaphore`.
+    waiters: Waiters,
     pub(super) size: AtomicU32,
     pub(super) num_idle: AtomicUsize,
+    /// The number of tasks in `acquire()`, sampled by the watchdog.
+    pub(super) num_waiting: AtomicUsize,
+    /// The longest time taken by `acquire()` since the watchdog last sampled it.
+    pub(super) max_acquire_micros: AtomicU64,
+    pub(super) metrics: MetricsRecorder,
+    /// Incremented by `Pool::reload_tls()`; connections opened before are not reused.
+    pub(super) tls_generation: AtomicU64,
     is_closed: AtomicBool,
     pub(super) on_closed: event_listener::Event,
     pub(super) options: PoolOptions<DB>,
@@ -51,12 +63,19 @@ impl<DB: Database> PoolInner<DB> {
             capacity
         };
 
+        let connect_options = options.apply_log_settings(connect_options);
+
         let pool = Self {
             connect_options: RwLock::new(Arc::new(connect_options)),
             idle_conns: ArrayQueue::new(capacity),
             semaphore: AsyncSemaphore::new(options.fair, semaphore_capacity),
+            waiters: Waiters::new(options.fairness),
             size: AtomicU32::new(0),
             num_idle: AtomicUsize::new(0),
+            num_waiting: AtomicUsize::new(0),
+            max_acquire_micros: AtomicU64::new(0),
+            metrics: MetricsRecorder::default(),
+            tls_generation: AtomicU64::new(0),
             is_closed: AtomicBool::new(false),
             on_closed: event_listener::Event::new(),
             acquire_time_level: private_level_filter_to_trace_level(options.acquire_time_level),
@@ -67,6 +86,7 @@ impl<DB: Database> PoolInner<DB> {
         let pool = Arc::new(pool);
 
         spawn_maintenance_tasks(&pool);
+        spawn_watchdog(&pool);
 
         pool
     }
@@ -84,6 +104,14 @@ impl<DB: Database> PoolInner<DB> {
         self.num_idle.load(Ordering::Acquire)
     }
 
+    pub(super) fn metrics(&self) -> PoolMetrics {
+        self.metrics.snapshot(
+            self.size(),
+            self.num_idle(),
+            self.num_waiting.load(Ordering::Acquire),
+        )
+    }
+
     pub(super) fn is_closed(&self) -> bool {
         self.is_closed.load(Ordering::Acquire)
     }
@@ -123,14 +151,18 @@ impl<DB: Database> PoolInner<DB> {
     ///
     /// If we steal a permit from the parent but *don't* open a connection,
     /// it should be returned to the parent.
-    async fn acquire_permit<'a>(self: &'a Arc<Self>) -> Result<AsyncSemaphoreReleaser<'a>, Error> {
+    async fn acquire_permit<'a>(
+        self: &'a Arc<Self>,
+        priority: u8,
+        waiter: &mut Option<Waiter<'a>>,
+    ) -> Result<AsyncSemaphoreReleaser<'a>, Error> {
         let parent = self
             .parent()
             // If we're already at the max size, we shouldn't try to steal from the parent.
             // This is just going to cause unnecessary churn in `acquire()`.
             .filter(|_| self.size() < self.options.max_connections);
 
-        let acquire_self = self.semaphore.acquire(1).fuse();
+        let acquire_self = self.acquire_own_permit(priority, waiter).fuse();
         let mut close_event = self.close_event();
 
         if let Some(parent) = parent {
@@ -176,6 +208,48 @@ impl<DB: Database> PoolInner<DB> {
         }
     }
 
+    /// Wait for a permit from `self.semaphore`, after any waiters which are to be served first.
+    ///
+    /// A task which already waited in the queue, and is trying again, keeps its place in it.
+    async fn acquire_own_permit<'a>(
+        &'a self,
+        priority: u8,
+        waiter: &mut Option<Waiter<'a>>,
+    ) -> AsyncSemaphoreReleaser<'a> {
+        if waiter.is_none() {
+            if !self.waiters.is_used_for(priority) {
+                return self.semaphore.acquire(1).await;
+            }
+
+            // "drive-by" tasks may take a permit ahead of waiters if the pool isn't fair
+            if !self.options.fair || self.waiters.is_empty() {
+                if let Some(permit) = self.semaphore.try_acquire(1) {
+                    return permit;
+                }
+            }
+        }
+
+        let waiter = waiter.get_or_insert_with(|| self.waiters.enter(priority));
+        waiter.join();
+
+        loop {
+            let listener = waiter.listen();
+
+            if waiter.is_next() {
+                let acquire = self.semaphore.acquire(1);
+                futures_util::pin_mut!(acquire);
+
+                // Stop waiting on the semaphore if another waiter overtakes this one.
+                match future::select(acquire, listener).await {
+                    Either::Left((permit, _)) => return permit,
+                    Either::Right(_) => continue,
+                }
+            }
+
+            listener.await;
+        }
+    }
+
     fn parent(&self) -> Option<&Pool<DB>> {
         self.options.parent_pool.as_ref()
     }
@@ -186,6 +260,11 @@ impl<DB: Database> PoolInner<DB> {
             return None;
         }
 
+        // Don't take a connection ahead of tasks waiting for one.
+        if self.options.fair && !self.waiters.is_empty() {
+            return None;
+        }
+
         let permit = self.semaphore.try_acquire(1)?;
 
         self.pop_idle(permit).ok()
@@ -239,26 +318,42 @@ impl<DB: Database> PoolInner<DB> {
 
         match result {
             // we successfully incremented the size
-            Ok(_) => Ok(DecrementSizeGuard::from_permit((*self).clone(), permit)),
+            Ok(_) => Ok(DecrementSizeGuard::for_new_connection(
+                (*self).clone(),
+                permit,
+            )),
             // the pool is at max capacity or is closed
             Err(_) => Err(permit),
         }
     }
 
-    pub(super) async fn acquire(self: &Arc<Self>) -> Result<Floating<DB, Live<DB>>, Error> {
+    pub(super) async fn acquire(
+        self: &Arc<Self>,
+        priority: u8,
+    ) -> Result<Floating<DB, Live<DB>>, Error> {
         if self.is_closed() {
             return Err(Error::PoolClosed);
         }
 
+        let _waiting = WaitingGuard::new(self);
+
         let acquire_started_at = Instant::now();
         let deadline = acquire_started_at + self.options.acquire_timeout;
 
         let acquired = crate::rt::timeout(
             self.options.acquire_timeout,
             async {
+                // kept between attempts so that a task which has to try again keeps its place
+                let mut waiter = None;
+
                 loop {
                     // Handles the close-event internally
-                    let permit = self.acquire_permit().await?;
+                    let permit = self.acquire_permit(priority, &mut waiter).await?;
+
+                    // let the next task in line wait for a permit while this one uses it
+                    if let Some(waiter) = &mut waiter {
+                        waiter.leave();
+                    }
 
 
                     // First attempt to pop a connection from the idle queue.
@@ -296,9 +391,16 @@ impl<DB: Database> PoolInner<DB> {
             }
         )
             .await
-            .map_err(|_| Error::PoolTimedOut)??;
+            .map_err(|_| Error::PoolTimedOut)
+            .and_then(|res| res)
+            .inspect_err(|error| {
+                if matches!(error, Error::PoolTimedOut) {
+                    self.metrics.acquire_timed_out();
+                }
+            })?;
 
         let acquired_after = acquire_started_at.elapsed();
+        self.metrics.acquired_after(acquired_after);
 
         let acquire_slow_level = self
             .acquire_slow_level
@@ -352,6 +454,17 @@ impl<DB: Database> PoolInner<DB> {
             match crate::rt::timeout(timeout, connect_options.connect()).await {
                 // successfully established connection
                 Ok(Ok(mut raw)) => {
+                    if let Some(timeout) = self
+                        .options
+                        .default_statement_timeout
+                        .filter(|_| raw.supports_statement_timeout())
+                    {
+                        if let Err(error) = raw.set_statement_timeout(Some(timeout)).await {
+                            let _ = raw.close_hard().await;
+                            return Err(error);
+                        }
+                    }
+
                     // See comment on `PoolOptions::after_connect`
                     let meta = PoolConnectionMetadata {
                         age: Duration::ZERO,
@@ -422,6 +535,74 @@ impl<DB: Database> PoolInner<DB> {
         Ok(())
     }
 
+    pub(super) async fn reload_tls(self: &Arc<Self>) {
+        self.tls_generation.fetch_add(1, Ordering::AcqRel);
+
+        // Close the idle connections now, rather than when they're next acquired.
+        // Connections which are checked out are closed when they're returned.
+        for _ in 0..self.num_idle() {
+            if let Some(conn) = self.try_acquire() {
+                if is_tls_outdated(&conn, self) {
+                    let _ = conn.close().await;
+                } else {
+                    self.release(conn.into_live());
+                }
+            }
+        }
+
+        self.min_connections_maintenance(None).await;
+    }
+
+    pub(super) async fn health_check(self: &Arc<Self>) -> Result<PoolHealth, Error> {
+        let mut health = PoolHealth {
+            latencies: Vec::new(),
+            num_failed: 0,
+        };
+        let mut last_error = None;
+
+        // Only ping the connections which are idle now, so none are pinged twice.
+        for _ in 0..self.num_idle() {
+            let Some(mut conn) = self.try_acquire() else {
+                break;
+            };
+
+            match conn.ping().await {
+                Ok(latency) => {
+                    health.latencies.push(latency);
+                    self.release(conn.into_live());
+                }
+                Err(error) => {
+                    health.num_failed += 1;
+                    last_error = Some(error);
+                    // connection is broken so don't try to close nicely
+                    let _ = conn.close_hard().await;
+                }
+            }
+        }
+
+        if health.latencies.is_empty() {
+            let mut conn = self.acquire(0).await?;
+
+            match conn.raw.ping().await {
+                Ok(latency) => {
+                    health.latencies.push(latency);
+                    // the connection was never provided to user code, so skip `after_release`
+                    self.release(conn);
+                }
+                Err(error) => {
+                    health.num_failed += 1;
+                    last_error = Some(error);
+                    conn.close_hard().await;
+                }
+            }
+        }
+
+        match last_error {
+            Some(error) if health.latencies.is_empty() => Err(error),
+            _ => Ok(health),
+        }
+    }
+
     /// Attempt to maintain `min_connections`, logging if unable.
     pub async fn min_connections_maintenance(self: &Arc<Self>, deadline: Option<Instant>) {
         let deadline = deadline.unwrap_or_else(|| {
@@ -461,6 +642,16 @@ pub(super) fn is_beyond_max_lifetime<DB: Database>(
         .map_or(false, |max| live.created_at.elapsed() > max)
 }
 
+/// Returns `true` if the connection has been used `options.max_uses` times if set, `false` otherwise.
+pub(super) fn is_beyond_max_uses<DB: Database>(live: &Live<DB>, options: &PoolOptions<DB>) -> bool {
+    options.max_uses.is_some_and(|max| live.uses >= max)
+}
+
+/// Returns `true` if the connection was opened before the last call to `Pool::reload_tls()`.
+pub(super) fn is_tls_outdated<DB: Database>(live: &Live<DB>, pool: &PoolInner<DB>) -> bool {
+    live.tls_generation != pool.tls_generation.load(Ordering::Acquire)
+}
+
 /// Returns `true` if the connection has exceeded `options.idle_timeout` if set, `false` otherwise.
 fn is_beyond_idle_timeout<DB: Database>(idle: &Idle<DB>, options: &PoolOptions<DB>) -> bool {
     options
@@ -472,6 +663,11 @@ async fn check_idle_conn<DB: Database>(
     mut conn: Floating<DB, Idle<DB>>,
     options: &PoolOptions<DB>,
 ) -> Result<Floating<DB, Live<DB>>, DecrementSizeGuard<DB>> {
+    // The connection was opened before `Pool::reload_tls()`, replace it
+    if is_tls_outdated(&conn, &conn.guard.pool) {
+        return Err(conn.close().await);
+    }
+
     if options.test_before_acquire {
         // Check that the connection is still live
         if let Err(error) = conn.ping().await {
@@ -583,6 +779,8 @@ fn spawn_maintenance_tasks<DB: Database>(pool: &Arc<PoolInner<DB>>) {
 pub(in crate::pool) struct DecrementSizeGuard<DB: Database> {
     pub(crate) pool: Arc<PoolInner<DB>>,
     cancelled: bool,
+    /// `false` until the connection this guard is for has been opened.
+    is_open: bool,
 }
 
 impl<DB: Database> DecrementSizeGuard<DB> {
@@ -591,6 +789,7 @@ impl<DB: Database> DecrementSizeGuard<DB> {
         Self {
             pool,
             cancelled: false,
+            is_open: true,
         }
     }
 
@@ -600,6 +799,22 @@ impl<DB: Database> DecrementSizeGuard<DB> {
         Self::new_permit(pool)
     }
 
+    /// Create a guard for a connection which is yet to be opened.
+    pub fn for_new_connection(
+        pool: Arc<PoolInner<DB>>,
+        permit: AsyncSemaphoreReleaser<'_>,
+    ) -> Self {
+        let mut guard = Self::from_permit(pool, permit);
+        guard.is_open = false;
+        guard
+    }
+
+    /// Mark the connection this guard is for as opened.
+    pub fn opened(&mut self) {
+        self.is_open = true;
+        self.pool.metrics.connection_opened();
+    }
+
     /// Release the semaphore permit without decreasing the pool size.
     ///
     /// If the permit was stolen from the pool's parent, it will be returned to the child's semaphore.
@@ -616,6 +831,10 @@ impl<DB: Database> DecrementSizeGuard<DB> {
 impl<DB: Database> Drop for DecrementSizeGuard<DB> {
     fn drop(&mut self) {
         if !self.cancelled {
+            if self.is_open {
+                self.pool.metrics.connection_closed();
+            }
+
             self.pool.size.fetch_sub(1, Ordering::AcqRel);
 
             // and here we release the permit we got on construction
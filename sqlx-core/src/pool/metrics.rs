@@ -0,0 +1,159 @@
+use std::cmp;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// The upper bounds of the buckets of [`PoolMetrics::acquire_time()`], in microseconds.
+///
+/// These are the default buckets of the Prometheus client libraries.
+const ACQUIRE_TIME_BUCKETS: [u64; 11] = [
+    5_000, 10_000, 25_000, 50_000, 100_000, 250_000, 500_000, 1_000_000, 2_500_000, 5_000_000,
+    10_000_000,
+];
+
+/// A snapshot of the metrics of a [`Pool`][super::Pool], returned by
+/// [`Pool::metrics()`][super::Pool::metrics].
+///
+/// Counters are totals since the pool was created, so they can be scraped periodically,
+/// e.g. into Prometheus, and the rate of change calculated from there.
+#[derive(Debug, Clone)]
+pub struct PoolMetrics {
+    pub(crate) size: u32,
+    pub(crate) num_idle: usize,
+    pub(crate) num_waiting: usize,
+    pub(crate) connections_opened: u64,
+    pub(crate) connections_closed: u64,
+    pub(crate) acquire_timeouts: u64,
+    pub(crate) acquire_time: PoolHistogram,
+}
+
+impl PoolMetrics {
+    /// The number of connections, including idle connections, when the snapshot was taken.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// The number of idle connections when the snapshot was taken.
+    pub fn num_idle(&self) -> usize {
+        self.num_idle
+    }
+
+    /// The number of tasks waiting to acquire a connection when the snapshot was taken.
+    pub fn num_waiting(&self) -> usize {
+        self.num_waiting
+    }
+
+    /// The number of connections opened by the pool.
+    pub fn connections_opened(&self) -> u64 {
+        self.connections_opened
+    }
+
+    /// The number of connections removed from the pool, because they were closed, broken,
+    /// or [detached][super::PoolConnection::detach].
+    pub fn connections_closed(&self) -> u64 {
+        self.connections_closed
+    }
+
+    /// The number of calls to `acquire()` which returned [`Error::PoolTimedOut`][crate::error::Error::PoolTimedOut].
+    pub fn acquire_timeouts(&self) -> u64 {
+        self.acquire_timeouts
+    }
+
+    /// The time taken by successful calls to `acquire()`.
+    pub fn acquire_time(&self) -> &PoolHistogram {
+        &self.acquire_time
+    }
+}
+
+/// A histogram of durations, part of [`PoolMetrics`].
+#[derive(Debug, Clone)]
+pub struct PoolHistogram {
+    buckets: Vec<(Duration, u64)>,
+    count: u64,
+    sum: Duration,
+}
+
+impl PoolHistogram {
+    /// The upper bound of each bucket, with the number of samples less than or equal to it.
+    ///
+    /// The counts are cumulative, like the `le` buckets of a Prometheus histogram. Samples
+    /// greater than the last bound are only included in [`count()`][Self::count].
+    pub fn buckets(&self) -> &[(Duration, u64)] {
+        &self.buckets
+    }
+
+    /// The total number of samples.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The sum of all samples.
+    pub fn sum(&self) -> Duration {
+        self.sum
+    }
+}
+
+/// The counters behind [`PoolMetrics`], updated by the pool.
+#[derive(Default)]
+pub(super) struct MetricsRecorder {
+    connections_opened: AtomicU64,
+    connections_closed: AtomicU64,
+    acquire_timeouts: AtomicU64,
+    // the number of samples in each bucket, not cumulative
+    acquire_time_buckets: [AtomicU64; ACQUIRE_TIME_BUCKETS.len()],
+    acquire_time_count: AtomicU64,
+    acquire_time_sum_micros: AtomicU64,
+}
+
+impl MetricsRecorder {
+    pub(super) fn connection_opened(&self) {
+        self.connections_opened.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn connection_closed(&self) {
+        self.connections_closed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn acquire_timed_out(&self) {
+        self.acquire_timeouts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(super) fn acquired_after(&self, elapsed: Duration) {
+        let micros = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+
+        if let Some(i) = ACQUIRE_TIME_BUCKETS.iter().position(|&le| micros <= le) {
+            self.acquire_time_buckets[i].fetch_add(1, Ordering::Relaxed);
+        }
+
+        self.acquire_time_count.fetch_add(1, Ordering::Relaxed);
+        self.acquire_time_sum_micros
+            .fetch_add(micros, Ordering::Relaxed);
+    }
+
+    pub(super) fn snapshot(&self, size: u32, num_idle: usize, num_waiting: usize) -> PoolMetrics {
+        let mut cumulative = 0;
+
+        let buckets = ACQUIRE_TIME_BUCKETS
+            .iter()
+            .zip(&self.acquire_time_buckets)
+            .map(|(&le, count)| {
+                cumulative += count.load(Ordering::Relaxed);
+                (Duration::from_micros(le), cumulative)
+            })
+            .collect();
+
+        PoolMetrics {
+            size,
+            num_idle,
+            num_waiting,
+            connections_opened: self.connections_opened.load(Ordering::Relaxed),
+            connections_closed: self.connections_closed.load(Ordering::Relaxed),
+            acquire_timeouts: self.acquire_timeouts.load(Ordering::Relaxed),
+            acquire_time: PoolHistogram {
+                buckets,
+                // never less than the last bucket, even if a sample is recorded concurrently
+                count: cmp::max(self.acquire_time_count.load(Ordering::Relaxed), cumulative),
+                sum: Duration::from_micros(self.acquire_time_sum_micros.load(Ordering::Relaxed)),
+            },
+        }
+    }
+}
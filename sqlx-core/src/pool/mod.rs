@@ -68,8 +68,10 @@ use crate::any::{Any, AnyKind};
 use crate::connection::Connection;
 use crate::database::Database;
 use crate::error::Error;
-use crate::transaction::Transaction;
+use crate::statement_stats::{StatementStat, StatementStats};
+use crate::transaction::{AccessMode, IsolationLevel, Transaction, TransactionOptions};
 use event_listener::EventListener;
+use futures_core::future::BoxFuture;
 use futures_core::FusedFuture;
 use futures_util::FutureExt;
 use std::fmt;
@@ -86,14 +88,27 @@ mod executor;
 pub mod maybe;
 
 mod connection;
+mod health;
 mod inner;
+mod metrics;
 mod options;
+mod replica;
+mod retry;
+mod waiters;
+mod watchdog;
 
 pub use self::connection::PoolConnection;
-pub use self::options::{PoolConnectionMetadata, PoolOptions};
+pub use self::health::PoolHealth;
+pub use self::metrics::{PoolHistogram, PoolMetrics};
+pub use self::options::{Fairness, PoolConnectionLeak, PoolConnectionMetadata, PoolOptions};
+pub use self::replica::ReplicaPool;
+pub use self::retry::RetryPolicy;
+pub use self::watchdog::{PoolWatchdog, PoolWatchdogAlert};
 
 #[doc(hidden)]
 pub use self::maybe::MaybePoolConnection;
+#[doc(hidden)]
+pub use self::replica::private_is_read_only;
 
 /// An asynchronous pool of SQLx database connections.
 ///
@@ -344,6 +359,9 @@ impl<DB: Database> Pool<DB> {
     /// [`PoolOptions::acquire_timeout`].
     /// If that timeout elapses, this will return [`Error::PoolClosed`].
     ///
+    /// If a [`RetryPolicy`] is set with [`PoolOptions::retry_policy`], failed attempts are retried
+    /// according to it, each with its own `acquire_timeout`.
+    ///
     /// ### Note: Cancellation/Timeout May Drop Connections
     /// If `acquire` is cancelled or times out after it acquires a connection from the idle queue or
     /// opens a new one, it will drop that connection because we don't want to assume it
@@ -362,8 +380,61 @@ impl<DB: Database> Pool<DB> {
     /// This should eliminate any potential `.await` points between acquiring a connection and
     /// returning it.
     pub fn acquire(&self) -> impl Future<Output = Result<PoolConnection<DB>, Error>> + 'static {
+        self.acquire_with_priority(0)
+    }
+
+    /// Retrieves a connection from the pool, ahead of tasks waiting with a lower priority.
+    ///
+    /// When there are no connections to spare, waiting tasks are served in order of priority,
+    /// highest first, and then in the order set by [`PoolOptions::fairness()`].
+    /// [`acquire()`][Self::acquire] waits with a priority of `0`.
+    ///
+    /// This lets latency-sensitive work, like health checks or interactive queries,
+    /// skip the queue ahead of batch jobs sharing the same pool.
+    ///
+    /// Until a pool is first given a priority above `0`, tasks wait for a connection in the order
+    /// they called `acquire()`, without the cost of ordering them by priority. Tasks which were
+    /// already waiting at that point may still be served before a task with a higher priority.
+    ///
+    /// Otherwise the same as [`acquire()`][Self::acquire].
+    ///
+    /// ```rust,no_run
+    /// # async fn example(pool: &sqlx::PgPool) -> sqlx::Result<()> {
+    /// let mut conn = pool.acquire_with_priority(100).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn acquire_with_priority(
+        &self,
+        priority: u8,
+    ) -> impl Future<Output = Result<PoolConnection<DB>, Error>> + 'static {
         let shared = self.0.clone();
-        async move { shared.acquire().await.map(|conn| conn.reattach()) }
+
+        async move {
+            let mut attempt = 1;
+
+            loop {
+                let error = match shared.acquire(priority).await {
+                    Ok(conn) => return Ok(conn.reattach()),
+                    Err(error) => error,
+                };
+
+                let backoff = shared
+                    .options
+                    .retry_policy
+                    .as_ref()
+                    .and_then(|policy| policy.backoff_after(attempt, &error));
+
+                let Some(backoff) = backoff else {
+                    return Err(error);
+                };
+
+                tracing::debug!(%error, attempt, ?backoff, "failed to acquire connection; retrying");
+
+                crate::rt::sleep(backoff).await;
+                attempt += 1;
+            }
+        }
     }
 
     /// Attempts to retrieve a connection from the pool if there is one available.
@@ -379,6 +450,136 @@ impl<DB: Database> Pool<DB> {
         Transaction::begin(MaybePoolConnection::PoolConnection(self.acquire().await?)).await
     }
 
+    /// Retrieves a connection and immediately begins a new transaction with the given
+    /// isolation level and access mode.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(pool: &sqlx::PgPool) -> sqlx::Result<()> {
+    /// use sqlx::{AccessMode, IsolationLevel};
+    ///
+    /// let mut tx = pool
+    ///     .begin_with(IsolationLevel::Serializable, AccessMode::ReadOnly)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn begin_with(
+        &self,
+        isolation_level: IsolationLevel,
+        access_mode: AccessMode,
+    ) -> Result<Transaction<'static, DB>, Error> {
+        self.begin_with_options((isolation_level, access_mode).into())
+            .await
+    }
+
+    /// Retrieves a connection and immediately begins a new transaction with the given options.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(pool: &sqlx::PgPool) -> sqlx::Result<()> {
+    /// use sqlx::{IsolationLevel, TransactionOptions};
+    ///
+    /// let mut tx = pool
+    ///     .begin_with_options(
+    ///         TransactionOptions::new()
+    ///             .isolation_level(IsolationLevel::Serializable)
+    ///             .read_only(true)
+    ///             .deferrable(true),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn begin_with_options(
+        &self,
+        options: TransactionOptions,
+    ) -> Result<Transaction<'static, DB>, Error> {
+        Transaction::begin_with(
+            MaybePoolConnection::PoolConnection(self.acquire().await?),
+            options,
+        )
+        .await
+    }
+
+    /// Execute the function inside a transaction with the given isolation level, retrying the
+    /// whole transaction if it fails with a serialization failure or deadlock.
+    ///
+    /// If the function returns an error, the transaction is rolled back. Otherwise, it is
+    /// committed. If the function or the commit fails with a database error for which the
+    /// [retry policy][PoolOptions::retry_policy] of the pool (or [`RetryPolicy::new()`] if it has
+    /// none) says [`is_retryable()`][RetryPolicy::is_retryable], the function is called again in a
+    /// new transaction, after the backoff of the policy, up to its maximum number of attempts.
+    ///
+    /// Other errors, including I/O errors, are returned without retrying: the transaction may
+    /// have been committed even though the connection failed while committing it.
+    ///
+    /// As the function may be called several times, it should not have side effects outside of
+    /// the transaction.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(pool: &sqlx::PgPool) -> sqlx::Result<()> {
+    /// use sqlx::IsolationLevel;
+    ///
+    /// let balance: i64 = pool
+    ///     .transaction_with_retry(IsolationLevel::Serializable, |txn| Box::pin(async move {
+    ///         sqlx::query("UPDATE accounts SET balance = balance - 10 WHERE id = 1")
+    ///             .execute(&mut **txn)
+    ///             .await?;
+    ///
+    ///         sqlx::query_scalar("SELECT SUM(balance) FROM accounts")
+    ///             .fetch_one(&mut **txn)
+    ///             .await
+    ///     }))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn transaction_with_retry<F, R>(
+        &self,
+        isolation_level: IsolationLevel,
+        mut callback: F,
+    ) -> Result<R, Error>
+    where
+        for<'c> F: FnMut(&'c mut Transaction<'static, DB>) -> BoxFuture<'c, Result<R, Error>>,
+    {
+        let policy = self.0.options.retry_policy.clone().unwrap_or_default();
+        let mut attempt = 1;
+
+        loop {
+            let mut transaction = self
+                .begin_with(isolation_level, AccessMode::ReadWrite)
+                .await?;
+
+            let error = match callback(&mut transaction).await {
+                Ok(ret) => match transaction.commit().await {
+                    Ok(()) => return Ok(ret),
+                    Err(error) => error,
+                },
+                Err(error) => {
+                    // the error of the callback decides whether to retry, not that of the rollback
+                    if let Err(rollback_error) = transaction.rollback().await {
+                        tracing::warn!(%rollback_error, "error rolling back a failed transaction");
+                    }
+
+                    error
+                }
+            };
+
+            let backoff = match error {
+                Error::Database(_) => policy.backoff_after(attempt, &error),
+                _ => None,
+            };
+
+            let Some(backoff) = backoff else {
+                return Err(error);
+            };
+
+            tracing::debug!(%error, attempt, ?backoff, "transaction failed; retrying");
+
+            crate::rt::sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+
     /// Attempts to retrieve a connection and immediately begins a new transaction if successful.
     pub async fn try_begin(&self) -> Result<Option<Transaction<'static, DB>>, Error> {
         match self.try_acquire() {
@@ -512,6 +713,54 @@ impl<DB: Database> Pool<DB> {
         self.0.num_idle()
     }
 
+    /// Returns a snapshot of the metrics of this pool, such as the time taken to acquire
+    /// connections and the number of connections opened and closed.
+    ///
+    /// See [`PoolMetrics`].
+    pub fn metrics(&self) -> PoolMetrics {
+        self.0.metrics()
+    }
+
+    /// Replace the connections in the pool, so they use the latest TLS certificates and keys.
+    ///
+    /// Certificates and keys given as a file or a callback (e.g.
+    /// [`CertificateInput::Callback`][crate::net::tls::CertificateInput::Callback]) are loaded
+    /// again for every new connection, so rotated certificates are picked up without recreating
+    /// the pool. However, connections which are already open keep the certificates they were
+    /// opened with.
+    ///
+    /// This closes the idle connections immediately, and any connections which are checked out
+    /// when they're returned to the pool. New connections are opened as needed, or to maintain
+    /// [`min_connections`][PoolOptions::min_connections].
+    pub async fn reload_tls(&self) {
+        self.0.reload_tls().await
+    }
+
+    /// Ping the idle connections of the pool, and return the round-trip time of each.
+    ///
+    /// If no connections are idle, a connection is acquired, or opened, to ping instead. The
+    /// pings are done at the protocol level where possible (see [`Connection::ping()`]), so
+    /// they don't show up as queries on the server, making this suitable for health endpoints.
+    ///
+    /// Connections which fail to respond are closed, and counted in
+    /// [`PoolHealth::num_failed()`]. Returns an error if no connection responded.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    /// let health = pool.health_check().await?;
+    ///
+    /// println!(
+    ///     "database latency: {:?} (max {:?})",
+    ///     health.mean_latency(),
+    ///     health.max_latency(),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn health_check(&self) -> Result<PoolHealth, Error> {
+        self.0.health_check().await
+    }
+
     /// Gets a clone of the connection options for this pool
     pub fn connect_options(&self) -> Arc<<DB::Connection as Connection>::Options> {
         self.0
@@ -531,13 +780,25 @@ impl<DB: Database> Pool<DB> {
             .connect_options
             .write()
             .expect("write-lock holder panicked");
-        *guard = Arc::new(connect_options);
+        *guard = Arc::new(self.0.options.apply_log_settings(connect_options));
     }
 
     /// Get the options for this pool
     pub fn options(&self) -> &PoolOptions<DB> {
         &self.0.options
     }
+
+    /// The statistics of the statements executed on this pool's connections, ordered by their
+    /// total execution time, longest first.
+    ///
+    /// Empty unless enabled with [`PoolOptions::statement_stats()`].
+    pub fn statement_stats(&self) -> Vec<StatementStat> {
+        self.0
+            .options
+            .statement_stats
+            .as_ref()
+            .map_or_else(Vec::new, StatementStats::snapshot)
+    }
 }
 
 #[cfg(all(
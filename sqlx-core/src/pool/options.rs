@@ -1,10 +1,13 @@
-use crate::connection::Connection;
+use crate::connection::{ConnectOptions, Connection};
 use crate::database::Database;
 use crate::error::Error;
+use crate::logger::QueryLogSink;
 use crate::pool::inner::PoolInner;
-use crate::pool::Pool;
+use crate::pool::{Pool, PoolWatchdog, PoolWatchdogAlert, RetryPolicy};
+use crate::statement_stats::StatementStats;
 use futures_core::future::BoxFuture;
 use log::LevelFilter;
+use std::backtrace::Backtrace;
 use std::fmt::{self, Debug, Formatter};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -81,8 +84,18 @@ pub struct PoolOptions<DB: Database> {
     pub(crate) acquire_timeout: Duration,
     pub(crate) min_connections: u32,
     pub(crate) max_lifetime: Option<Duration>,
+    pub(crate) max_uses: Option<u64>,
     pub(crate) idle_timeout: Option<Duration>,
+    pub(crate) default_statement_timeout: Option<Duration>,
+    pub(crate) leak_detection_threshold: Option<Duration>,
+    pub(crate) on_leak: Option<Arc<dyn Fn(&PoolConnectionLeak<'_>) + 'static + Send + Sync>>,
+    pub(crate) watchdog: Option<PoolWatchdog>,
+    pub(crate) on_watchdog_alert: Option<Arc<dyn Fn(&PoolWatchdogAlert) + 'static + Send + Sync>>,
+    pub(crate) query_logger: Option<Arc<dyn QueryLogSink>>,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+    pub(crate) statement_stats: Option<StatementStats>,
     pub(crate) fair: bool,
+    pub(crate) fairness: Fairness,
 
     pub(crate) parent_pool: Option<Pool<DB>>,
 }
@@ -104,8 +117,18 @@ impl<DB: Database> Clone for PoolOptions<DB> {
             acquire_timeout: self.acquire_timeout,
             min_connections: self.min_connections,
             max_lifetime: self.max_lifetime,
+            max_uses: self.max_uses,
             idle_timeout: self.idle_timeout,
+            default_statement_timeout: self.default_statement_timeout,
+            leak_detection_threshold: self.leak_detection_threshold,
+            on_leak: self.on_leak.clone(),
+            watchdog: self.watchdog.clone(),
+            on_watchdog_alert: self.on_watchdog_alert.clone(),
+            query_logger: self.query_logger.clone(),
+            retry_policy: self.retry_policy.clone(),
+            statement_stats: self.statement_stats.clone(),
             fair: self.fair,
+            fairness: self.fairness,
             parent_pool: self.parent_pool.clone(),
         }
     }
@@ -127,6 +150,52 @@ pub struct PoolConnectionMetadata {
     pub idle_for: Duration,
 }
 
+/// A connection held longer than the
+/// [`leak_detection_threshold`][PoolOptions::leak_detection_threshold], passed to the
+/// [`on_leak`][PoolOptions::on_leak] callback.
+#[derive(Debug)]
+pub struct PoolConnectionLeak<'a> {
+    pub(crate) held_for: Duration,
+    pub(crate) tag: Option<&'a str>,
+    pub(crate) backtrace: &'a Backtrace,
+}
+
+impl PoolConnectionLeak<'_> {
+    /// The duration since the connection was acquired.
+    pub fn held_for(&self) -> Duration {
+        self.held_for
+    }
+
+    /// The tag given to the connection with [`PoolConnection::set_tag()`][super::PoolConnection::set_tag], if any.
+    pub fn tag(&self) -> Option<&str> {
+        self.tag
+    }
+
+    /// The backtrace of the call which acquired the connection.
+    ///
+    /// This is only captured if enabled by the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`
+    /// environment variables; see [`Backtrace::capture()`].
+    pub fn backtrace(&self) -> &Backtrace {
+        self.backtrace
+    }
+}
+
+/// The order in which tasks waiting in `acquire()` with the same priority are given connections.
+///
+/// See [`PoolOptions::fairness()`] and [`Pool::acquire_with_priority()`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Fairness {
+    /// The task which has been waiting the longest is served first.
+    #[default]
+    Fifo,
+
+    /// The task which started waiting most recently is served first.
+    ///
+    /// Under sustained overload, this keeps the latency of most requests low at the cost of
+    /// the oldest waiters, which are likely to time out anyway.
+    Lifo,
+}
+
 impl<DB: Database> Default for PoolOptions<DB> {
     fn default() -> Self {
         Self::new()
@@ -160,7 +229,17 @@ impl<DB: Database> PoolOptions<DB> {
             acquire_timeout: Duration::from_secs(30),
             idle_timeout: Some(Duration::from_secs(10 * 60)),
             max_lifetime: Some(Duration::from_secs(30 * 60)),
+            max_uses: None,
+            default_statement_timeout: None,
+            leak_detection_threshold: None,
+            on_leak: None,
+            watchdog: None,
+            on_watchdog_alert: None,
+            query_logger: None,
+            retry_policy: None,
+            statement_stats: None,
             fair: true,
+            fairness: Fairness::Fifo,
             parent_pool: None,
         }
     }
@@ -292,6 +371,26 @@ impl<DB: Database> PoolOptions<DB> {
         self.max_lifetime
     }
 
+    /// Set the maximum number of times individual connections are checked out of the pool.
+    ///
+    /// A connection is closed instead of being returned to the pool once it has been checked out
+    /// this many times, in addition to being retired by [`max_lifetime`].
+    /// Some managed databases and connection proxies recommend this to bound the state
+    /// a session accumulates on the server.
+    ///
+    /// Defaults to `None`, for no limit.
+    ///
+    /// [`max_lifetime`]: Self::max_lifetime
+    pub fn max_uses(mut self, max_uses: impl Into<Option<u64>>) -> Self {
+        self.max_uses = max_uses.into();
+        self
+    }
+
+    /// Get the maximum number of times individual connections are checked out of the pool.
+    pub fn get_max_uses(&self) -> Option<u64> {
+        self.max_uses
+    }
+
     /// Set a maximum idle duration for individual connections.
     ///
     /// Any connection that remains in the idle queue longer than this will be closed.
@@ -307,6 +406,197 @@ impl<DB: Database> PoolOptions<DB> {
         self.idle_timeout
     }
 
+    /// Set the maximum time the database may spend executing a statement on connections
+    /// of this pool before aborting it.
+    ///
+    /// This is set with [`Connection::set_statement_timeout()`] when a connection is opened,
+    /// before [`after_connect`][Self::after_connect] is called. If the timeout of a connection
+    /// is changed with that method, it is reset when the connection is returned to the pool.
+    ///
+    /// * Postgres sets `statement_timeout`.
+    /// * MySQL sets `max_execution_time`, which only applies to read-only `SELECT` statements.
+    /// * MariaDB sets `max_statement_time`.
+    ///
+    /// It is ignored for other databases, including those of an `AnyPool` which don't support it;
+    /// see [`Connection::supports_statement_timeout()`].
+    pub fn default_statement_timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.default_statement_timeout = timeout.into();
+        self
+    }
+
+    /// Get the default statement timeout of connections.
+    pub fn get_default_statement_timeout(&self) -> Option<Duration> {
+        self.default_statement_timeout
+    }
+
+    /// Report connections which are held longer than `threshold` after being acquired,
+    /// which usually means they were leaked, e.g. forgotten in a long-lived struct or task.
+    ///
+    /// Each is reported once, to the [`on_leak`][Self::on_leak] callback if set,
+    /// or otherwise with a warning in the `sqlx::pool::leak` log target.
+    ///
+    /// A connection which is meant to be held for a long time can be exempted with
+    /// [`PoolConnection::detach_with_timeout()`][super::PoolConnection::detach_with_timeout].
+    ///
+    /// Disabled by default.
+    pub fn leak_detection_threshold(mut self, threshold: impl Into<Option<Duration>>) -> Self {
+        self.leak_detection_threshold = threshold.into();
+        self
+    }
+
+    /// Get the threshold after which held connections are reported as leaked.
+    pub fn get_leak_detection_threshold(&self) -> Option<Duration> {
+        self.leak_detection_threshold
+    }
+
+    /// Set a function to call when a connection has been held longer than the
+    /// [`leak_detection_threshold`][Self::leak_detection_threshold].
+    ///
+    /// The callback is called from a background task and should return quickly.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sqlx::postgres::PgPoolOptions;
+    /// use std::time::Duration;
+    ///
+    /// let pool = PgPoolOptions::new()
+    ///     .leak_detection_threshold(Duration::from_secs(60))
+    ///     .on_leak(|leak| {
+    ///         eprintln!(
+    ///             "connection {:?} held for {:?}, acquired at:\n{}",
+    ///             leak.tag(),
+    ///             leak.held_for(),
+    ///             leak.backtrace()
+    ///         );
+    ///     })
+    ///     .connect("postgres:// …")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_leak<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&PoolConnectionLeak<'_>) + 'static + Send + Sync,
+    {
+        self.on_leak = Some(Arc::new(callback));
+        self
+    }
+
+    /// Sample how many tasks are waiting to acquire a connection, and how long they wait,
+    /// and raise an alert when the thresholds of `watchdog` are breached.
+    ///
+    /// Alerts go to the [`on_watchdog_alert`][Self::on_watchdog_alert] callback if set,
+    /// or otherwise are logged as a warning in the `sqlx::pool::watchdog` log target.
+    /// Once raised, an alert is not raised again until a sample is within the thresholds.
+    ///
+    /// Disabled by default.
+    pub fn watchdog(mut self, watchdog: impl Into<Option<PoolWatchdog>>) -> Self {
+        self.watchdog = watchdog.into();
+        self
+    }
+
+    /// Get the thresholds of the pool's watchdog, if enabled.
+    pub fn get_watchdog(&self) -> Option<&PoolWatchdog> {
+        self.watchdog.as_ref()
+    }
+
+    /// Set a function to call when the thresholds of the [`watchdog`][Self::watchdog]
+    /// are breached.
+    ///
+    /// The callback is called from a background task and should return quickly.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn f() -> Result<(), Box<dyn std::error::Error>> {
+    /// use sqlx::pool::PoolWatchdog;
+    /// use sqlx::postgres::PgPoolOptions;
+    /// use std::time::Duration;
+    ///
+    /// let pool = PgPoolOptions::new()
+    ///     .watchdog(
+    ///         PoolWatchdog::new(Duration::from_secs(1))
+    ///             .max_waiting(50)
+    ///             .consecutive_samples(5),
+    ///     )
+    ///     .on_watchdog_alert(|alert| {
+    ///         eprintln!(
+    ///             "{} tasks waiting for a connection for {} seconds",
+    ///             alert.waiting(),
+    ///             alert.consecutive_samples()
+    ///         );
+    ///     })
+    ///     .connect("postgres:// …")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_watchdog_alert<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&PoolWatchdogAlert) + 'static + Send + Sync,
+    {
+        self.on_watchdog_alert = Some(Arc::new(callback));
+        self
+    }
+
+    /// Pass every statement executed on the pool's connections to `logger` instead of
+    /// logging it.
+    ///
+    /// This is applied to the connect options of the pool, including those set later with
+    /// [`Pool::set_connect_options()`]. See [`ConnectOptions::query_logger()`] for details.
+    ///
+    /// [`ConnectOptions::query_logger()`]: crate::connection::ConnectOptions::query_logger
+    pub fn query_logger(mut self, logger: Arc<dyn QueryLogSink>) -> Self {
+        self.query_logger = Some(logger);
+        self
+    }
+
+    /// Retry [`Pool::acquire()`], and optionally queries executed on the pool, when they fail
+    /// with a transient error. See [`RetryPolicy`] for details.
+    ///
+    /// Nothing is retried by default.
+    ///
+    /// ```rust,no_run
+    /// # async fn example() -> sqlx::Result<()> {
+    /// use sqlx::pool::RetryPolicy;
+    /// use sqlx::postgres::PgPoolOptions;
+    ///
+    /// let pool = PgPoolOptions::new()
+    ///     .retry_policy(RetryPolicy::new().max_attempts(5).retry_queries(true))
+    ///     .connect("postgres://localhost/app")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn retry_policy(mut self, policy: impl Into<Option<RetryPolicy>>) -> Self {
+        self.retry_policy = policy.into();
+        self
+    }
+
+    /// Get the retry policy of the pool, if set.
+    pub fn get_retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    /// Record the execution count and time of every statement executed on the pool's
+    /// connections in `stats`, to be read with [`Pool::statement_stats()`].
+    ///
+    /// This is applied to the connect options of the pool, including those set later with
+    /// [`Pool::set_connect_options()`]. See [`statement_stats`][crate::statement_stats] for details.
+    ///
+    /// Disabled by default.
+    pub fn statement_stats(mut self, stats: impl Into<Option<StatementStats>>) -> Self {
+        self.statement_stats = stats.into();
+        self
+    }
+
+    /// Get the statement statistics of the pool, if enabled.
+    pub fn get_statement_stats(&self) -> Option<&StatementStats> {
+        self.statement_stats.as_ref()
+    }
+
     /// If true, the health of a connection will be verified by a call to [`Connection::ping`]
     /// before returning the connection.
     ///
@@ -339,6 +629,22 @@ impl<DB: Database> PoolOptions<DB> {
         self
     }
 
+    /// Set the order in which tasks waiting for a connection with the same priority are served.
+    ///
+    /// Tasks waiting with a higher priority, given to [`Pool::acquire_with_priority()`],
+    /// are always served first.
+    ///
+    /// Defaults to [`Fairness::Fifo`].
+    pub fn fairness(mut self, fairness: Fairness) -> Self {
+        self.fairness = fairness;
+        self
+    }
+
+    /// Get the order in which tasks waiting for a connection with the same priority are served.
+    pub fn get_fairness(&self) -> Fairness {
+        self.fairness
+    }
+
     /// Perform an asynchronous action after connecting to the database.
     ///
     /// If the operation returns with an error then the error is logged, the connection is closed
@@ -551,7 +857,7 @@ impl<DB: Database> PoolOptions<DB> {
 
         // If `min_connections` is nonzero then we'll likely just pull a connection
         // from the idle queue here, but it should at least get tested first.
-        let conn = inner.acquire().await?;
+        let conn = inner.acquire(0).await?;
         inner.release(conn);
 
         Ok(Pool(inner))
@@ -580,6 +886,21 @@ impl<DB: Database> PoolOptions<DB> {
         // `min_connections` is guaranteed by the idle reaper now.
         Pool(PoolInner::new_arc(self, options))
     }
+
+    pub(crate) fn apply_log_settings(
+        &self,
+        mut options: <DB::Connection as Connection>::Options,
+    ) -> <DB::Connection as Connection>::Options {
+        if let Some(logger) = &self.query_logger {
+            options = options.query_logger(Arc::clone(logger));
+        }
+
+        if let Some(stats) = &self.statement_stats {
+            options = options.statement_stats(stats.clone());
+        }
+
+        options
+    }
 }
 
 impl<DB: Database> Debug for PoolOptions<DB> {
@@ -589,7 +910,13 @@ impl<DB: Database> Debug for PoolOptions<DB> {
             .field("min_connections", &self.min_connections)
             .field("connect_timeout", &self.acquire_timeout)
             .field("max_lifetime", &self.max_lifetime)
+            .field("max_uses", &self.max_uses)
             .field("idle_timeout", &self.idle_timeout)
+            .field("default_statement_timeout", &self.default_statement_timeout)
+            .field("leak_detection_threshold", &self.leak_detection_threshold)
+            .field("watchdog", &self.watchdog)
+            .field("retry_policy", &self.retry_policy)
+            .field("statement_stats", &self.statement_stats)
             .field("test_before_acquire", &self.test_before_acquire)
             .finish()
     }
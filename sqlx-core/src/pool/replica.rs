@@ -0,0 +1,592 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use either::Either;
+use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+use futures_util::TryStreamExt;
+
+use crate::connection::Connection;
+use crate::database::Database;
+use crate::describe::Describe;
+use crate::error::Error;
+use crate::executor::{Execute, Executor};
+use crate::pool::{Pool, PoolConnection, PoolHealth, PoolOptions};
+use crate::transaction::Transaction;
+
+/// How long a replica is skipped for after it failed, by default.
+const DEFAULT_RETRY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How long to wait for a connection from a replica before trying the next one, by default.
+const DEFAULT_REPLICA_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl<DB: Database> PoolOptions<DB> {
+    /// Connect a [`ReplicaPool`] to the primary database at `primary_url` and its read replicas
+    /// at `replica_urls`, with a pool of these options for each.
+    ///
+    /// A connection to the primary is opened immediately, like [`connect()`][Self::connect];
+    /// the replicas are connected lazily, so a replica being down doesn't prevent starting up.
+    pub async fn connect_replicated(
+        self,
+        primary_url: &str,
+        replica_urls: &[&str],
+    ) -> Result<ReplicaPool<DB>, Error> {
+        let replica_options = replica_urls
+            .iter()
+            .map(|url| url.parse())
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        self.connect_replicated_with(primary_url.parse()?, replica_options)
+            .await
+    }
+
+    /// Connect a [`ReplicaPool`] to the primary database of `primary_options` and the read
+    /// replicas of `replica_options`, with a pool of these options for each.
+    ///
+    /// See [`connect_replicated()`][Self::connect_replicated] for details.
+    pub async fn connect_replicated_with(
+        self,
+        primary_options: <DB::Connection as Connection>::Options,
+        replica_options: impl IntoIterator<Item = <DB::Connection as Connection>::Options>,
+    ) -> Result<ReplicaPool<DB>, Error> {
+        let replicas = replica_options
+            .into_iter()
+            .map(|options| self.clone().connect_lazy_with(options))
+            .collect::<Vec<_>>();
+
+        let primary = match self.connect_with(primary_options).await {
+            Ok(primary) => primary,
+            Err(e) => {
+                for replica in &replicas {
+                    replica.close().await;
+                }
+
+                return Err(e);
+            }
+        };
+
+        Ok(ReplicaPool::new(primary, replicas))
+    }
+}
+
+/// A pool for a primary database and its read replicas, from
+/// [`PoolOptions::connect_replicated()`] or [`ReplicaPool::new()`].
+///
+/// When used as an [`Executor`], a query that only reads goes to one of the replicas, in turn,
+/// and anything else to the primary. Queries are routed by their SQL without parsing it, so a
+/// query is only sent to a replica if every statement in it starts with `SELECT`, `VALUES`,
+/// `WITH` or `SHOW`, doesn't mention `INSERT`, `UPDATE`, `DELETE`, `REPLACE`, `MERGE`, `INTO`
+/// or `SHARE` (which also covers `FOR UPDATE` and `FOR SHARE`), and doesn't call any function,
+/// as it may write, like `nextval()` in Postgres. Use [`on_replica()`][Self::on_replica] to
+/// send reads which call functions, such as `COUNT(*)`, to a replica.
+///
+/// Transactions are always started on the primary. Use [`read()`][Self::read] or
+/// [`write()`][Self::write] to pick a pool explicitly, e.g. for reads that must see the latest
+/// writes, as replicas may lag behind the primary.
+///
+/// ### Failover
+/// If a connection can't be acquired from a replica, e.g. because it is down, the replica is
+/// skipped for the [`retry_interval()`][Self::retry_interval] and the next one is tried instead,
+/// ending with the primary if none of them are available. Each replica is only waited on for the
+/// [`replica_acquire_timeout()`][Self::replica_acquire_timeout], rather than the
+/// [`acquire_timeout`][PoolOptions::acquire_timeout] of its pool; a replica which times out while
+/// all of its connections are in use is busy rather than down, so it isn't skipped for later
+/// queries. Errors returned by the query itself are not retried. [`health_check()`][Self::health_check] checks the replicas proactively.
+///
+/// ```rust,no_run
+/// # async fn example() -> sqlx::Result<()> {
+/// use sqlx::postgres::PgPoolOptions;
+///
+/// let pool = PgPoolOptions::new()
+///     .max_connections(8)
+///     .connect_replicated(
+///         "postgres://primary/app",
+///         &["postgres://replica-1/app", "postgres://replica-2/app"],
+///     )
+///     .await?;
+///
+/// // executed on the primary
+/// sqlx::query("INSERT INTO events (name) VALUES ($1)")
+///     .bind("login")
+///     .execute(&pool)
+///     .await?;
+///
+/// // executed on one of the replicas
+/// let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM events")
+///     .fetch_one(&pool)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ReplicaPool<DB: Database> {
+    primary: Pool<DB>,
+    replicas: Arc<[Replica<DB>]>,
+    next_replica: Arc<AtomicUsize>,
+    retry_interval: Duration,
+    replica_acquire_timeout: Duration,
+    // set by `on_replica()`
+    replica_only: bool,
+}
+
+struct Replica<DB: Database> {
+    pool: Pool<DB>,
+    unavailable_until: Mutex<Option<Instant>>,
+}
+
+impl<DB: Database> ReplicaPool<DB> {
+    /// Route queries between the `primary` pool and the pools of its read `replicas`.
+    ///
+    /// If `replicas` is empty, everything is sent to the primary.
+    pub fn new(primary: Pool<DB>, replicas: Vec<Pool<DB>>) -> Self {
+        ReplicaPool {
+            primary,
+            replicas: replicas
+                .into_iter()
+                .map(|pool| Replica {
+                    pool,
+                    unavailable_until: Mutex::new(None),
+                })
+                .collect(),
+            next_replica: Arc::new(AtomicUsize::new(0)),
+            retry_interval: DEFAULT_RETRY_INTERVAL,
+            replica_acquire_timeout: DEFAULT_REPLICA_ACQUIRE_TIMEOUT,
+            replica_only: false,
+        }
+    }
+
+    /// Set how long a replica is skipped for after a connection couldn't be acquired from it.
+    ///
+    /// Defaults to 30 seconds.
+    pub fn retry_interval(mut self, interval: Duration) -> Self {
+        self.retry_interval = interval;
+        self
+    }
+
+    /// Set how long to wait for a connection from a replica before trying the next one.
+    ///
+    /// Defaults to 5 seconds.
+    pub fn replica_acquire_timeout(mut self, timeout: Duration) -> Self {
+        self.replica_acquire_timeout = timeout;
+        self
+    }
+
+    /// The pool of the next available replica, or of the primary if none are available.
+    pub fn read(&self) -> &Pool<DB> {
+        self.available_replicas()
+            .next()
+            .map_or(&self.primary, |replica| &replica.pool)
+    }
+
+    /// The pool of the primary.
+    pub fn write(&self) -> &Pool<DB> {
+        &self.primary
+    }
+
+    /// A handle to this pool which sends every query executed on it to a replica, as if it
+    /// only reads, with the same failover as reads.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(pool: sqlx::pool::ReplicaPool<sqlx::Postgres>) -> sqlx::Result<()> {
+    /// // calls a function, so it would be sent to the primary otherwise
+    /// let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM events")
+    ///     .fetch_one(&pool.on_replica())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_replica(&self) -> Self {
+        ReplicaPool {
+            replica_only: true,
+            ..self.clone()
+        }
+    }
+
+    /// Start a transaction on the primary.
+    pub async fn begin(&self) -> Result<Transaction<'static, DB>, Error> {
+        self.primary.begin().await
+    }
+
+    /// Check each replica with [`Pool::health_check()`], returning the results in the order the
+    /// replicas were given.
+    ///
+    /// Replicas which fail are skipped for the [`retry_interval()`][Self::retry_interval], and
+    /// replicas which pass are used again immediately.
+    pub async fn health_check(&self) -> Vec<Result<PoolHealth, Error>> {
+        let mut results = Vec::with_capacity(self.replicas.len());
+
+        for replica in self.replicas.iter() {
+            let result = replica.pool.health_check().await;
+
+            if result.is_ok() {
+                replica.mark_available();
+            } else {
+                replica.mark_unavailable(self.retry_interval);
+            }
+
+            results.push(result);
+        }
+
+        results
+    }
+
+    /// Close the primary and the replicas.
+    pub async fn close(&self) {
+        self.primary.close().await;
+
+        for replica in self.replicas.iter() {
+            replica.pool.close().await;
+        }
+    }
+
+    /// Returns `true` if [`close()`][Self::close] has been called.
+    pub fn is_closed(&self) -> bool {
+        self.primary.is_closed()
+    }
+
+    // The available replicas, starting with the next one in turn.
+    fn available_replicas(&self) -> impl Iterator<Item = &Replica<DB>> {
+        let start = match self.replicas.len() {
+            0 => 0,
+            len => self.next_replica.fetch_add(1, Ordering::Relaxed) % len,
+        };
+
+        let now = Instant::now();
+
+        self.replicas[start..]
+            .iter()
+            .chain(&self.replicas[..start])
+            .filter(move |replica| replica.is_available(now))
+    }
+
+    async fn acquire_for(&self, sql: &str) -> Result<PoolConnection<DB>, Error> {
+        if self.replica_only || private_is_read_only(sql, false) {
+            for replica in self.available_replicas() {
+                let acquire = replica.pool.acquire();
+
+                match crate::rt::timeout(self.replica_acquire_timeout, acquire).await {
+                    Ok(Ok(conn)) => return Ok(conn),
+                    Ok(Err(e)) => {
+                        tracing::warn!(
+                            error = %e,
+                            retry_interval = ?self.retry_interval,
+                            "failed to acquire a connection from a replica; skipping it"
+                        );
+                        replica.mark_unavailable(self.retry_interval);
+                    }
+                    // a replica without any connections couldn't open one, so it's likely down
+                    Err(_) if replica.pool.size() == 0 => {
+                        tracing::warn!(
+                            timeout = ?self.replica_acquire_timeout,
+                            retry_interval = ?self.retry_interval,
+                            "timed out acquiring a connection from a replica; skipping it"
+                        );
+                        replica.mark_unavailable(self.retry_interval);
+                    }
+                    Err(_) => {
+                        tracing::debug!(
+                            timeout = ?self.replica_acquire_timeout,
+                            "timed out acquiring a connection from a busy replica; trying the next one"
+                        );
+                    }
+                }
+            }
+        }
+
+        self.primary.acquire().await
+    }
+}
+
+impl<DB: Database> Replica<DB> {
+    fn is_available(&self, now: Instant) -> bool {
+        let unavailable_until = self
+            .unavailable_until
+            .lock()
+            .expect("BUG: panicked while holding a lock");
+
+        !matches!(*unavailable_until, Some(until) if until > now) && !self.pool.is_closed()
+    }
+
+    fn mark_available(&self) {
+        *self
+            .unavailable_until
+            .lock()
+            .expect("BUG: panicked while holding a lock") = None;
+    }
+
+    fn mark_unavailable(&self, interval: Duration) {
+        *self
+            .unavailable_until
+            .lock()
+            .expect("BUG: panicked while holding a lock") = Some(Instant::now() + interval);
+    }
+}
+
+impl<DB: Database> Clone for ReplicaPool<DB> {
+    fn clone(&self) -> Self {
+        ReplicaPool {
+            primary: self.primary.clone(),
+            replicas: Arc::clone(&self.replicas),
+            next_replica: Arc::clone(&self.next_replica),
+            retry_interval: self.retry_interval,
+            replica_acquire_timeout: self.replica_acquire_timeout,
+            replica_only: self.replica_only,
+        }
+    }
+}
+
+impl<DB: Database> std::fmt::Debug for ReplicaPool<DB> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReplicaPool")
+            .field("primary", &self.primary)
+            .field(
+                "replicas",
+                &self.replicas.iter().map(|r| &r.pool).collect::<Vec<_>>(),
+            )
+            .field("retry_interval", &self.retry_interval)
+            .field("replica_acquire_timeout", &self.replica_acquire_timeout)
+            .field("replica_only", &self.replica_only)
+            .finish()
+    }
+}
+
+impl<'p, DB: Database> Executor<'p> for &'_ ReplicaPool<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+{
+    type Database = DB;
+
+    fn fetch_many<'e, 'q: 'e, E>(
+        self,
+        query: E,
+    ) -> BoxStream<'e, Result<Either<DB::QueryResult, DB::Row>, Error>>
+    where
+        E: 'q + Execute<'q, Self::Database>,
+    {
+        let pool = self.clone();
+
+        Box::pin(try_stream! {
+            let mut conn = pool.acquire_for(query.sql()).await?;
+            let mut s = conn.fetch_many(query);
+
+            while let Some(v) = s.try_next().await? {
+                r#yield!(v);
+            }
+
+            Ok(())
+        })
+    }
+
+    fn fetch_optional<'e, 'q: 'e, E>(
+        self,
+        query: E,
+    ) -> BoxFuture<'e, Result<Option<DB::Row>, Error>>
+    where
+        E: 'q + Execute<'q, Self::Database>,
+    {
+        let pool = self.clone();
+
+        Box::pin(async move {
+            let mut conn = pool.acquire_for(query.sql()).await?;
+            conn.fetch_optional(query).await
+        })
+    }
+
+    fn prepare_with<'e, 'q: 'e>(
+        self,
+        sql: &'q str,
+        parameters: &'e [<Self::Database as Database>::TypeInfo],
+    ) -> BoxFuture<'e, Result<<Self::Database as Database>::Statement<'q>, Error>> {
+        let pool = self.clone();
+
+        Box::pin(async move {
+            let mut conn = pool.acquire_for(sql).await?;
+            conn.prepare_with(sql, parameters).await
+        })
+    }
+
+    #[doc(hidden)]
+    fn describe<'e, 'q: 'e>(
+        self,
+        sql: &'q str,
+    ) -> BoxFuture<'e, Result<Describe<Self::Database>, Error>> {
+        let pool = self.clone();
+
+        Box::pin(async move { pool.acquire_for(sql).await?.describe(sql).await })
+    }
+}
+
+/// Returns `true` if `sql` only reads, going by its keywords.
+///
+/// Conservative: `false` unless every statement starts with a keyword that only reads,
+/// and no keyword that writes appears anywhere outside of comments, strings and quoted names.
+/// Any function call is also taken to write, unless `allow_calls` is set.
+#[doc(hidden)]
+pub fn private_is_read_only(sql: &str, allow_calls: bool) -> bool {
+    let bytes = sql.as_bytes();
+
+    let mut statements = 0;
+    let mut at_statement_start = true;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b';' => {
+                at_statement_start = true;
+                i += 1;
+            }
+
+            b'-' if bytes.get(i + 1) == Some(&b'-') => i = skip_past(sql, i + 2, "\n"),
+
+            b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_past(sql, i + 2, "*/"),
+
+            b'\'' => i = skip_past(sql, i + 1, "'"),
+
+            // a quoted name may be that of a function
+            b'"' | b'`' | b'[' => {
+                let end = if bytes[i] == b'[' { "]" } else { &sql[i..=i] };
+
+                i = skip_past(sql, i + 1, end);
+
+                if !allow_calls && is_call(sql, i) {
+                    return false;
+                }
+            }
+
+            b if is_word_byte(b) => {
+                let start = i;
+
+                while i < bytes.len() && is_word_byte(bytes[i]) {
+                    i += 1;
+                }
+
+                let word = &sql[start..i];
+
+                if at_statement_start {
+                    if !["SELECT", "VALUES", "WITH", "SHOW"]
+                        .iter()
+                        .any(|keyword| word.eq_ignore_ascii_case(keyword))
+                    {
+                        return false;
+                    }
+
+                    statements += 1;
+                    at_statement_start = false;
+                } else if [
+                    "INSERT", "UPDATE", "DELETE", "REPLACE", "MERGE", "INTO", "SHARE",
+                ]
+                .iter()
+                .any(|keyword| word.eq_ignore_ascii_case(keyword))
+                {
+                    return false;
+                }
+
+                if !allow_calls
+                    && is_call(sql, i)
+                    && !NOT_FUNCTIONS
+                        .iter()
+                        .any(|keyword| word.eq_ignore_ascii_case(keyword))
+                {
+                    return false;
+                }
+            }
+
+            _ => i += 1,
+        }
+    }
+
+    statements > 0
+}
+
+// Keywords which may be followed by parentheses without being a function call.
+const NOT_FUNCTIONS: &[&str] = &[
+    "ALL",
+    "AND",
+    "ANY",
+    "AS",
+    "BY",
+    "DISTINCT",
+    "ELSE",
+    "EXCEPT",
+    "EXISTS",
+    "FROM",
+    "HAVING",
+    "IN",
+    "INTERSECT",
+    "JOIN",
+    "LATERAL",
+    "NOT",
+    "ON",
+    "OR",
+    "OVER",
+    "SELECT",
+    "SOME",
+    "THEN",
+    "UNION",
+    "USING",
+    "VALUES",
+    "WHEN",
+    "WHERE",
+];
+
+// Whether the name ending at `end` is followed by an opening parenthesis.
+fn is_call(sql: &str, end: usize) -> bool {
+    sql[end..].trim_start().starts_with('(')
+}
+
+// Non-ASCII bytes are part of words, so words always start and end on a `char` boundary.
+fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$' || !b.is_ascii()
+}
+
+// The index after the next `pattern` from `start` on, or the end of `sql`.
+fn skip_past(sql: &str, start: usize, pattern: &str) -> usize {
+    sql[start..]
+        .find(pattern)
+        .map_or(sql.len(), |pos| start + pos + pattern.len())
+}
+
+#[test]
+fn it_detects_read_only_queries() {
+    for sql in [
+        "SELECT * FROM users",
+        "  select 1; SELECT 2;",
+        "-- DELETE\nSELECT 'INSERT', \"update\" FROM [delete] /* REPLACE */",
+        "WITH t AS (SELECT 1) SELECT * FROM t",
+        "VALUES (1), (2)",
+        "SELECT 'it''s'",
+        "SHOW TABLES",
+        "SELECT * FROM users WHERE id IN (1, 2) AND NOT (name = 'a')",
+        "SELECT * FROM (SELECT 1) AS t UNION ALL (SELECT 2)",
+    ] {
+        assert!(private_is_read_only(sql, false), "{sql:?}");
+    }
+
+    for sql in [
+        "",
+        ";",
+        "INSERT INTO users (name) VALUES ('a')",
+        "SELECT 1; DELETE FROM users",
+        "WITH t AS (SELECT 1) DELETE FROM users",
+        "SELECT replace(name, 'a', 'b') FROM users",
+        "SELECT * FROM users FOR UPDATE",
+        "SELECT * FROM users FOR SHARE",
+        "SELECT * FROM users FOR NO KEY UPDATE",
+        "SELECT * FROM users LOCK IN SHARE MODE",
+        "SELECT nextval('users_id_seq')",
+        "SELECT COUNT (*) FROM users",
+        "SELECT \"nextval\"('users_id_seq')",
+        "SELECT * INTO archive FROM users",
+        "PRAGMA journal_mode",
+        "BEGIN",
+        "/* SELECT */ UPDATE users SET name = 'a'",
+    ] {
+        assert!(!private_is_read_only(sql, false), "{sql:?}");
+    }
+
+    assert!(private_is_read_only("SELECT COUNT(*) FROM users", true));
+    assert!(!private_is_read_only(
+        "SELECT * FROM users FOR UPDATE",
+        true
+    ));
+}
@@ -0,0 +1,304 @@
+use std::cmp;
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::arguments::Arguments;
+use crate::database::Database;
+use crate::error::{BoxDynError, Error};
+use crate::executor::Execute;
+
+/// When to retry failed operations on a pool, set with
+/// [`PoolOptions::retry_policy()`][super::PoolOptions::retry_policy].
+///
+/// A failed [`Pool::acquire()`][super::Pool::acquire] is retried if the error is retryable, up
+/// to [`max_attempts`][Self::max_attempts] attempts in total, sleeping between attempts for a
+/// backoff which starts at the initial [`backoff`][Self::backoff] and doubles after every attempt.
+///
+/// By default, an error is retryable if it's an I/O error, or a database error for which
+/// [`DatabaseError::is_retryable()`][crate::error::DatabaseError::is_retryable] returns `true`,
+/// such as a serialization failure (`40001`) or deadlock (`40P01`) in Postgres, or a deadlock
+/// (`1213`) in MySQL. Use [`retry_if`][Self::retry_if] to decide for yourself.
+///
+/// ### Retrying Queries
+/// With [`retry_queries(true)`][Self::retry_queries], queries executed directly on the pool are
+/// also retried, on a connection acquired anew, if they fail before returning any rows or
+/// results. A query which failed with an I/O error may well have been executed by the database
+/// anyway, so I/O errors of queries are only retried if [`retry_if`][Self::retry_if] says so;
+/// only do that if every query executed on the pool is idempotent.
+///
+/// Queries executed on a [`PoolConnection`][super::PoolConnection] or in a transaction are never
+/// retried, as a failed query may have aborted the transaction they were executed in.
+///
+/// ```rust
+/// use sqlx::pool::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new()
+///     .max_attempts(5)
+///     .backoff(Duration::from_millis(50), Duration::from_secs(2))
+///     .retry_queries(true);
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    pub(crate) max_attempts: u32,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+    pub(crate) retry_queries: bool,
+    pub(crate) retry_if: Option<Arc<dyn Fn(&Error) -> bool + 'static + Send + Sync>>,
+}
+
+impl RetryPolicy {
+    /// Make up to 3 attempts, with a backoff of 10 milliseconds doubling up to 1 second,
+    /// without retrying queries.
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+            retry_queries: false,
+            retry_if: None,
+        }
+    }
+
+    /// Set the number of attempts to make in total, including the first.
+    ///
+    /// ### Panics
+    /// If `attempts` is zero.
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        assert_ne!(attempts, 0, "max_attempts must not be zero");
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// Get the number of attempts to make in total.
+    pub fn get_max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Sleep for `initial` before the first retry, doubling after every retry up to `max`.
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// If true, also retry queries executed directly on the pool; see the
+    /// [type docs][Self#retrying-queries] for when this is safe.
+    ///
+    /// Defaults to `false`.
+    pub fn retry_queries(mut self, retry: bool) -> Self {
+        self.retry_queries = retry;
+        self
+    }
+
+    /// Get whether queries executed directly on the pool are retried.
+    pub fn get_retry_queries(&self) -> bool {
+        self.retry_queries
+    }
+
+    /// Retry the errors for which `retryable` returns `true`, instead of the default ones.
+    ///
+    /// This applies to [queries][Self::retry_queries] as well, so I/O errors of queries are
+    /// retried if `retryable` returns `true` for them.
+    ///
+    /// ```rust
+    /// use sqlx::pool::RetryPolicy;
+    ///
+    /// // also retry when the pool timed out
+    /// let policy = RetryPolicy::new().retry_if(|error| {
+    ///     matches!(error, sqlx::Error::PoolTimedOut) || RetryPolicy::is_retryable_by_default(error)
+    /// });
+    /// ```
+    pub fn retry_if<F>(mut self, retryable: F) -> Self
+    where
+        F: Fn(&Error) -> bool + 'static + Send + Sync,
+    {
+        self.retry_if = Some(Arc::new(retryable));
+        self
+    }
+
+    /// Returns `true` if `error` is retried by this policy.
+    pub fn is_retryable(&self, error: &Error) -> bool {
+        match &self.retry_if {
+            Some(retry_if) => retry_if(error),
+            None => Self::is_retryable_by_default(error),
+        }
+    }
+
+    /// Returns `true` if `error` is retried by a policy without [`retry_if`][Self::retry_if].
+    pub fn is_retryable_by_default(error: &Error) -> bool {
+        match error {
+            Error::Io(_) => true,
+            Error::Database(error) => error.is_retryable(),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if a query which failed with `error` is retried by this policy, when it
+    /// [retries queries][Self::retry_queries].
+    ///
+    /// Unlike [`is_retryable()`][Self::is_retryable], I/O errors are not retried by default.
+    pub fn is_retryable_query(&self, error: &Error) -> bool {
+        match &self.retry_if {
+            Some(retry_if) => retry_if(error),
+            None => matches!(error, Error::Database(error) if error.is_retryable()),
+        }
+    }
+
+    /// The time to sleep before retrying after the failed `attempt`, counting from 1,
+    /// or `None` if `error` should not be retried.
+    pub(crate) fn backoff_after(&self, attempt: u32, error: &Error) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.is_retryable(error) {
+            return None;
+        }
+
+        Some(self.backoff_for(attempt))
+    }
+
+    /// Like [`backoff_after()`][Self::backoff_after], for a failed query.
+    pub(crate) fn query_backoff_after(&self, attempt: u32, error: &Error) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.is_retryable_query(error) {
+            return None;
+        }
+
+        Some(self.backoff_for(attempt))
+    }
+
+    fn backoff_for(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .checked_mul(2u32.saturating_pow(attempt - 1))
+            .unwrap_or(self.max_backoff);
+
+        cmp::min(backoff, self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("retry_queries", &self.retry_queries)
+            .field("retry_if", &self.retry_if.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+/// A query taken apart so it can be executed again, with a copy of its arguments.
+///
+/// Prepared statements are executed by their SQL instead, which finds them in the statement cache
+/// of the connection if they are persistent.
+pub(crate) struct RetriedQuery<'q, DB: Database> {
+    sql: &'q str,
+    arguments: Option<DB::Arguments<'q>>,
+    persistent: bool,
+    timeout: Option<Duration>,
+}
+
+impl<'q, DB: Database> RetriedQuery<'q, DB> {
+    pub(crate) fn new<E: Execute<'q, DB>>(mut query: E) -> Result<Self, Error> {
+        Ok(Self {
+            sql: query.sql(),
+            arguments: query.take_arguments().map_err(Error::Encode)?,
+            persistent: query.persistent(),
+            timeout: query.timeout(),
+        })
+    }
+
+    /// A copy of this query to execute on the next attempt, or `None` if the arguments can't be
+    /// copied.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        let arguments = match &self.arguments {
+            Some(arguments) => Some(arguments.private_try_clone()?),
+            None => None,
+        };
+
+        Some(Self {
+            sql: self.sql,
+            arguments,
+            persistent: self.persistent,
+            timeout: self.timeout,
+        })
+    }
+}
+
+impl<'q, DB: Database> Execute<'q, DB> for RetriedQuery<'q, DB> {
+    fn sql(&self) -> &'q str {
+        self.sql
+    }
+
+    fn statement(&self) -> Option<&DB::Statement<'q>> {
+        None
+    }
+
+    fn take_arguments(&mut self) -> Result<Option<DB::Arguments<'q>>, BoxDynError> {
+        Ok(self.arguments.take())
+    }
+
+    fn persistent(&self) -> bool {
+        self.persistent
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[test]
+fn it_doubles_the_backoff_up_to_the_max() {
+    let policy = RetryPolicy::new()
+        .max_attempts(5)
+        .backoff(Duration::from_millis(100), Duration::from_millis(300));
+
+    let error = Error::Io(std::io::ErrorKind::ConnectionReset.into());
+
+    assert_eq!(
+        policy.backoff_after(1, &error),
+        Some(Duration::from_millis(100))
+    );
+    assert_eq!(
+        policy.backoff_after(2, &error),
+        Some(Duration::from_millis(200))
+    );
+    assert_eq!(
+        policy.backoff_after(3, &error),
+        Some(Duration::from_millis(300))
+    );
+    assert_eq!(
+        policy.backoff_after(4, &error),
+        Some(Duration::from_millis(300))
+    );
+    assert_eq!(policy.backoff_after(5, &error), None);
+
+    assert_eq!(policy.backoff_after(1, &Error::PoolTimedOut), None);
+    assert_eq!(
+        policy
+            .retry_if(|error| matches!(error, Error::PoolTimedOut))
+            .backoff_after(1, &Error::PoolTimedOut),
+        Some(Duration::from_millis(100))
+    );
+}
+
+#[test]
+fn it_only_retries_io_errors_of_queries_when_asked() {
+    let policy = RetryPolicy::new().retry_queries(true);
+
+    let error = Error::Io(std::io::ErrorKind::ConnectionReset.into());
+
+    assert!(policy.backoff_after(1, &error).is_some());
+    assert_eq!(policy.query_backoff_after(1, &error), None);
+
+    assert!(policy
+        .retry_if(|error| matches!(error, Error::Io(_)))
+        .query_backoff_after(1, &error)
+        .is_some());
+}
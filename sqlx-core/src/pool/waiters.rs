@@ -0,0 +1,169 @@
+//! The queue of tasks waiting for a permit in `acquire()` with a priority, or in a pool with
+//! [`Fairness::Lifo`].
+//!
+//! Only the task at the head of the queue waits on the semaphore, so the queue decides the order
+//! permits are handed out in: highest priority first, then by the pool's [`Fairness`].
+//!
+//! Pools which use neither wait on the semaphore directly, which is fair already.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use event_listener::{Event, EventListener};
+
+use crate::pool::Fairness;
+
+pub(super) struct Waiters {
+    fairness: Fairness,
+    // set once a task waits with a priority, and never unset
+    in_use: AtomicBool,
+    len: AtomicUsize,
+    queue: Mutex<Queue>,
+}
+
+#[derive(Default)]
+struct Queue {
+    // ordered so that the first entry is the head of the queue
+    entries: BTreeMap<Key, Arc<Event>>,
+    next_seq: u64,
+}
+
+// `(Reverse(priority), seq)` for FIFO, where `seq` counts down instead for LIFO
+type Key = (Reverse<u8>, u64);
+
+impl Waiters {
+    pub(super) fn new(fairness: Fairness) -> Self {
+        Waiters {
+            fairness,
+            in_use: AtomicBool::new(fairness == Fairness::Lifo),
+            len: AtomicUsize::new(0),
+            queue: Mutex::default(),
+        }
+    }
+
+    /// Returns `true` if a task waiting with `priority` must wait in the queue.
+    pub(super) fn is_used_for(&self, priority: u8) -> bool {
+        if priority > 0 && !self.in_use.load(Ordering::Acquire) {
+            self.in_use.store(true, Ordering::Release);
+        }
+
+        self.in_use.load(Ordering::Acquire)
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.len.load(Ordering::Acquire) == 0
+    }
+
+    /// Join the queue; the returned guard leaves it when dropped.
+    pub(super) fn enter(&self, priority: u8) -> Waiter<'_> {
+        let seq = {
+            let mut queue = self.lock();
+            queue.next_seq += 1;
+            queue.next_seq
+        };
+
+        let key = match self.fairness {
+            Fairness::Fifo => (Reverse(priority), seq),
+            Fairness::Lifo => (Reverse(priority), u64::MAX - seq),
+        };
+
+        let mut waiter = Waiter {
+            waiters: self,
+            key,
+            event: Arc::new(Event::new()),
+            queued: false,
+        };
+
+        waiter.join();
+        waiter
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Queue> {
+        // the queue is never left in an inconsistent state, so a poisoned lock is fine to use
+        self.queue.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Queue {
+    fn head(&self) -> Option<(&Key, &Arc<Event>)> {
+        self.entries.first_key_value()
+    }
+}
+
+pub(super) struct Waiter<'a> {
+    waiters: &'a Waiters,
+    key: Key,
+    // notified when this waiter becomes, or stops being, the head of the queue
+    event: Arc<Event>,
+    queued: bool,
+}
+
+impl Waiter<'_> {
+    /// Join the queue again after [`Self::leave()`], in the same place as before.
+    pub(super) fn join(&mut self) {
+        if self.queued {
+            return;
+        }
+
+        let mut queue = self.waiters.lock();
+
+        let previous_head = queue.head().map(|(_, event)| event.clone());
+
+        queue.entries.insert(self.key, self.event.clone());
+        self.waiters
+            .len
+            .store(queue.entries.len(), Ordering::Release);
+        self.queued = true;
+
+        // a previous head waiting on the semaphore must step back if it was overtaken
+        if queue.head().map(|(key, _)| *key) == Some(self.key) {
+            if let Some(previous_head) = previous_head {
+                previous_head.notify(usize::MAX);
+            }
+        }
+    }
+
+    /// Leave the queue, e.g. after being given a permit, so the next waiter can be served.
+    pub(super) fn leave(&mut self) {
+        if !self.queued {
+            return;
+        }
+
+        let mut queue = self.waiters.lock();
+
+        let was_head = queue.head().map(|(key, _)| *key) == Some(self.key);
+
+        queue.entries.remove(&self.key);
+        self.waiters
+            .len
+            .store(queue.entries.len(), Ordering::Release);
+        self.queued = false;
+
+        // the next waiter can now wait on the semaphore
+        if was_head {
+            if let Some((_, event)) = queue.head() {
+                event.notify(usize::MAX);
+            }
+        }
+    }
+
+    /// Listen for this waiter becoming, or no longer being, the head of the queue.
+    ///
+    /// Must be called *before* [`Self::is_next()`] to not miss a notification.
+    pub(super) fn listen(&self) -> EventListener {
+        self.event.listen()
+    }
+
+    /// Returns `true` if this waiter is the next to be given a permit.
+    pub(super) fn is_next(&self) -> bool {
+        self.waiters.lock().head().map(|(key, _)| *key) == Some(self.key)
+    }
+}
+
+impl Drop for Waiter<'_> {
+    fn drop(&mut self) {
+        self.leave();
+    }
+}
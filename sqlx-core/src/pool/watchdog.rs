@@ -0,0 +1,194 @@
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::database::Database;
+use crate::pool::inner::PoolInner;
+
+/// Thresholds for the pool's watchdog, set with [`PoolOptions::watchdog()`][super::PoolOptions::watchdog].
+///
+/// The watchdog samples the number of tasks waiting in [`Pool::acquire()`][super::Pool::acquire]
+/// and the longest time a call to `acquire()` took to complete since the previous sample.
+/// When either exceeds its threshold for [`consecutive_samples`][Self::consecutive_samples]
+/// samples in a row, a [`PoolWatchdogAlert`] is raised.
+///
+/// ```rust
+/// use sqlx::pool::PoolWatchdog;
+/// use std::time::Duration;
+///
+/// let watchdog = PoolWatchdog::new(Duration::from_secs(1))
+///     .max_waiting(50)
+///     .max_acquire_time(Duration::from_millis(500))
+///     .consecutive_samples(5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PoolWatchdog {
+    pub(crate) interval: Duration,
+    pub(crate) max_waiting: Option<usize>,
+    pub(crate) max_acquire_time: Option<Duration>,
+    pub(crate) consecutive_samples: u32,
+}
+
+impl PoolWatchdog {
+    /// Sample the pool every `interval`.
+    ///
+    /// No thresholds are set by default; see [`max_waiting`][Self::max_waiting] and
+    /// [`max_acquire_time`][Self::max_acquire_time].
+    ///
+    /// ### Panics
+    /// If `interval` is zero.
+    pub fn new(interval: Duration) -> Self {
+        assert!(!interval.is_zero(), "watchdog interval must not be zero");
+
+        Self {
+            interval,
+            max_waiting: None,
+            max_acquire_time: None,
+            consecutive_samples: 1,
+        }
+    }
+
+    /// Breach when more than `max` tasks are waiting to acquire a connection.
+    pub fn max_waiting(mut self, max: usize) -> Self {
+        self.max_waiting = Some(max);
+        self
+    }
+
+    /// Breach when a call to `acquire()` completed, or timed out, after longer than `max`.
+    pub fn max_acquire_time(mut self, max: Duration) -> Self {
+        self.max_acquire_time = Some(max);
+        self
+    }
+
+    /// Raise an alert only once a threshold is breached for `samples` samples in a row.
+    ///
+    /// Defaults to 1.
+    ///
+    /// ### Panics
+    /// If `samples` is zero.
+    pub fn consecutive_samples(mut self, samples: u32) -> Self {
+        assert_ne!(samples, 0, "consecutive_samples must not be zero");
+        self.consecutive_samples = samples;
+        self
+    }
+}
+
+/// An alert raised by the pool's [watchdog][PoolWatchdog], passed to the
+/// [`on_watchdog_alert`][super::PoolOptions::on_watchdog_alert] callback.
+#[derive(Debug)]
+pub struct PoolWatchdogAlert {
+    pub(crate) waiting: usize,
+    pub(crate) max_acquire_time: Duration,
+    pub(crate) consecutive_samples: u32,
+}
+
+impl PoolWatchdogAlert {
+    /// The number of tasks waiting to acquire a connection in the latest sample.
+    pub fn waiting(&self) -> usize {
+        self.waiting
+    }
+
+    /// The longest time a call to `acquire()` took in the latest sample.
+    pub fn max_acquire_time(&self) -> Duration {
+        self.max_acquire_time
+    }
+
+    /// The number of consecutive samples which breached a threshold.
+    pub fn consecutive_samples(&self) -> u32 {
+        self.consecutive_samples
+    }
+}
+
+/// Counts a task waiting in `PoolInner::acquire()`, and records how long it waited on drop.
+pub(super) struct WaitingGuard<'a> {
+    waiting: &'a AtomicUsize,
+    max_acquire_micros: &'a AtomicU64,
+    started_at: Instant,
+}
+
+impl<'a> WaitingGuard<'a> {
+    pub(super) fn new<DB: Database>(pool: &'a PoolInner<DB>) -> Self {
+        pool.num_waiting.fetch_add(1, Ordering::AcqRel);
+
+        Self {
+            waiting: &pool.num_waiting,
+            max_acquire_micros: &pool.max_acquire_micros,
+            started_at: Instant::now(),
+        }
+    }
+}
+
+impl Drop for WaitingGuard<'_> {
+    fn drop(&mut self) {
+        let micros = u64::try_from(self.started_at.elapsed().as_micros()).unwrap_or(u64::MAX);
+
+        self.max_acquire_micros.fetch_max(micros, Ordering::AcqRel);
+        self.waiting.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+pub(super) fn spawn_watchdog<DB: Database>(pool: &Arc<PoolInner<DB>>) {
+    let Some(watchdog) = pool.options.watchdog.clone() else {
+        return;
+    };
+
+    // NOTE: don't keep `PoolInner` from being dropped.
+    let pool_weak = Arc::downgrade(pool);
+
+    // Immediately cancel this task if the pool is closed.
+    let mut close_event = pool.close_event();
+
+    crate::rt::spawn(async move {
+        let _ = close_event
+            .do_until(async {
+                let mut breached_for: u32 = 0;
+
+                loop {
+                    crate::rt::sleep(watchdog.interval).await;
+
+                    let Some(pool) = pool_weak.upgrade() else {
+                        return;
+                    };
+
+                    let waiting = pool.num_waiting.load(Ordering::Acquire);
+                    let max_acquire_time =
+                        Duration::from_micros(pool.max_acquire_micros.swap(0, Ordering::AcqRel));
+
+                    let breached = watchdog.max_waiting.is_some_and(|max| waiting > max)
+                        || watchdog
+                            .max_acquire_time
+                            .is_some_and(|max| max_acquire_time > max);
+
+                    if !breached {
+                        breached_for = 0;
+                        continue;
+                    }
+
+                    breached_for = breached_for.saturating_add(1);
+
+                    // alert once per streak of breaches
+                    if breached_for != watchdog.consecutive_samples {
+                        continue;
+                    }
+
+                    let alert = PoolWatchdogAlert {
+                        waiting,
+                        max_acquire_time,
+                        consecutive_samples: breached_for,
+                    };
+
+                    match &pool.options.on_watchdog_alert {
+                        Some(on_alert) => on_alert(&alert),
+                        None => tracing::warn!(
+                            target: "sqlx::pool::watchdog",
+                            waiting = alert.waiting,
+                            max_acquire_time = ?alert.max_acquire_time,
+                            consecutive_samples = alert.consecutive_samples,
+                            "pool watchdog thresholds breached",
+                        ),
+                    }
+                }
+            })
+            .await;
+    });
+}
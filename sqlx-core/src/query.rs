@@ -1,14 +1,21 @@
+use std::hash::Hash;
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use either::Either;
 use futures_core::stream::BoxStream;
 use futures_util::{future, StreamExt, TryFutureExt, TryStreamExt};
+use indexmap::IndexMap;
 
 use crate::arguments::{Arguments, IntoArguments};
+use crate::column::ColumnIndex;
 use crate::database::{Database, HasStatementCache};
+use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::error::{BoxDynError, Error};
 use crate::executor::{Execute, Executor};
+use crate::from_row::FromRow;
+use crate::row::Row;
 use crate::statement::Statement;
 use crate::types::Type;
 
@@ -19,6 +26,7 @@ pub struct Query<'q, DB: Database, A> {
     pub(crate) arguments: Option<Result<A, BoxDynError>>,
     pub(crate) database: PhantomData<DB>,
     pub(crate) persistent: bool,
+    pub(crate) timeout: Option<Duration>,
 }
 
 /// A single SQL query that will map its results to an owned Rust type.
@@ -62,14 +70,20 @@ where
     fn take_arguments(&mut self) -> Result<Option<<DB as Database>::Arguments<'q>>, BoxDynError> {
         self.arguments
             .take()
+            .transpose()?
+            .map(IntoArguments::try_into_arguments)
             .transpose()
-            .map(|option| option.map(IntoArguments::into_arguments))
     }
 
     #[inline]
     fn persistent(&self) -> bool {
         self.persistent
     }
+
+    #[inline]
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
 }
 
 impl<'q, DB: Database> Query<'q, DB, <DB as Database>::Arguments<'q>> {
@@ -99,6 +113,29 @@ impl<'q, DB: Database> Query<'q, DB, <DB as Database>::Arguments<'q>> {
         self
     }
 
+    /// Send a query attribute with this SQL query.
+    ///
+    /// Query attributes are named values sent along with the query which are not bound to its
+    /// parameters, such as metadata read by auditing or routing plugins on the server. Only
+    /// MySQL 8.0.23 and later support them; for other databases, an error is returned when this
+    /// query is executed.
+    ///
+    /// If encoding the value fails, the error is stored and later surfaced when executing the query.
+    pub fn attribute<T: 'q + Encode<'q, DB> + Type<DB>>(mut self, name: &str, value: T) -> Self {
+        let Ok(arguments) = self.get_arguments() else {
+            return self;
+        };
+
+        if let Err(error) = arguments.add_attribute(name, value) {
+            self.arguments = Some(Err(format!(
+                "Encoding query attribute `{name}` failed: {error}"
+            )
+            .into()));
+        }
+
+        self
+    }
+
     /// Like [`Query::try_bind`] but immediately returns an error if encoding the value failed.
     pub fn try_bind<T: 'q + Encode<'q, DB> + Type<DB>>(
         &mut self,
@@ -133,6 +170,10 @@ where
     ///
     /// If `false`, the prepared statement will be closed after execution.
     ///
+    /// Set this to `false` for dynamically generated SQL which is unlikely to be executed again,
+    /// such as an `IN (...)` list with a varying number of values. Otherwise each variation
+    /// is cached separately and may evict the statements which are executed repeatedly.
+    ///
     /// Default: `true`.
     pub fn persistent(mut self, value: bool) -> Self {
         self.persistent = value;
@@ -140,6 +181,27 @@ where
     }
 }
 
+impl<'q, DB: Database, A> Query<'q, DB, A> {
+    /// Set the maximum time the database may spend executing this query before aborting it,
+    /// instead of the statement timeout of the connection.
+    ///
+    /// * Postgres sets `statement_timeout` before executing the query and restores it after,
+    ///   which costs an extra round-trip.
+    /// * MySQL sets `max_execution_time`, which only applies to read-only `SELECT` statements,
+    ///   before executing the query. It is restored before the next query without a timeout.
+    /// * MariaDB does the same with `max_statement_time`.
+    /// * SQLite interrupts the query with a progress handler, so this cannot be used while one
+    ///   is set with `LockedSqliteHandle::set_progress_handler()`.
+    ///
+    /// The connection's timeout is the one set with
+    /// [`Connection::set_statement_timeout()`][crate::connection::Connection::set_statement_timeout]
+    /// or when connecting, or else the default of the server. The `Any` driver returns an error.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
 impl<'q, DB, A: Send> Query<'q, DB, A>
 where
     DB: Database,
@@ -252,6 +314,31 @@ where
         executor.fetch_all(self).await
     }
 
+    /// Execute the query and append all the resulting rows to `buf`, returning how many were added.
+    ///
+    /// Unlike [`fetch_all()`][Self::fetch_all], this reuses the existing capacity of `buf`,
+    /// which avoids reallocating on every execution when the same query is run repeatedly,
+    /// e.g. in a polling loop. If an error is returned, `buf` is left as it was.
+    ///
+    /// ### Note: beware result set size.
+    /// This will attempt to collect the full result set of the query into memory.
+    ///
+    /// To avoid exhausting available memory, ensure the result set has a known upper bound,
+    /// e.g. using `LIMIT`.
+    #[inline]
+    pub async fn fetch_into<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+        buf: &mut Vec<DB::Row>,
+    ) -> Result<usize, Error>
+    where
+        'q: 'e,
+        A: 'e,
+        E: Executor<'c, Database = DB>,
+    {
+        extend_from_stream(buf, self.fetch(executor)).await
+    }
+
     /// Execute the query, returning the first row or [`Error::RowNotFound`] otherwise.
     ///
     /// ### Note: for best performance, ensure the query returns at most one row.
@@ -295,6 +382,60 @@ where
     {
         executor.fetch_optional(self).await
     }
+
+    /// Execute the query and group the resulting rows by the value of the `key` column,
+    /// mapping each row to `V` with [`FromRow`].
+    ///
+    /// Rows are added to their group as they are received, in order, so the result set is not
+    /// collected into memory twice. This suits the common pattern of joining a parent table with
+    /// its children and grouping the children by the parent's ID.
+    ///
+    /// The groups are in the order their first row was received, so an `ORDER BY` of the query
+    /// applies to the groups as well.
+    ///
+    /// ### Note: beware result set size.
+    /// This will attempt to collect the full result set of the query into memory.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    /// #[derive(sqlx::FromRow)]
+    /// struct Comment {
+    ///     id: i64,
+    ///     body: String,
+    /// }
+    ///
+    /// let comments = sqlx::query("SELECT post_id, id, body FROM comments ORDER BY id")
+    ///     .fetch_grouped::<i64, Comment>("post_id", &pool)
+    ///     .await?;
+    ///
+    /// for (post_id, comments) in comments {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_grouped<'e, 'c: 'e, K, V>(
+        self,
+        key: impl ColumnIndex<DB::Row>,
+        executor: impl Executor<'c, Database = DB>,
+    ) -> Result<IndexMap<K, Vec<V>>, Error>
+    where
+        'q: 'e,
+        A: 'e,
+        K: Eq + Hash + for<'r> Decode<'r, DB> + Type<DB>,
+        V: for<'r> FromRow<'r, DB::Row>,
+    {
+        let mut rows = executor.fetch(self);
+        let mut groups = IndexMap::<K, Vec<V>>::new();
+
+        while let Some(row) = rows.try_next().await? {
+            let value = V::from_row(&row)?;
+
+            groups.entry(row.try_get(&key)?).or_default().push(value);
+        }
+
+        Ok(groups)
+    }
 }
 
 impl<'q, DB, F: Send, A: Send> Execute<'q, DB> for Map<'q, DB, F, A>
@@ -321,6 +462,11 @@ where
     fn persistent(&self) -> bool {
         self.inner.arguments.is_some()
     }
+
+    #[inline]
+    fn timeout(&self) -> Option<Duration> {
+        self.inner.timeout
+    }
 }
 
 impl<'q, DB, F, O, A> Map<'q, DB, F, A>
@@ -438,6 +584,32 @@ where
         self.fetch(executor).try_collect().await
     }
 
+    /// Execute the query and append all the resulting rows to `buf`, returning how many were added.
+    ///
+    /// Unlike [`fetch_all()`][Self::fetch_all], this reuses the existing capacity of `buf`,
+    /// which avoids reallocating on every execution when the same query is run repeatedly,
+    /// e.g. in a polling loop. If an error is returned, `buf` is left as it was.
+    ///
+    /// ### Note: beware result set size.
+    /// This will attempt to collect the full result set of the query into memory.
+    ///
+    /// To avoid exhausting available memory, ensure the result set has a known upper bound,
+    /// e.g. using `LIMIT`.
+    pub async fn fetch_into<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+        buf: &mut Vec<O>,
+    ) -> Result<usize, Error>
+    where
+        'q: 'e,
+        E: 'e + Executor<'c, Database = DB>,
+        DB: 'e,
+        F: 'e,
+        O: 'e,
+    {
+        extend_from_stream(buf, self.fetch(executor)).await
+    }
+
     /// Execute the query, returning the first row or [`Error::RowNotFound`] otherwise.
     ///
     /// ### Note: for best performance, ensure the query returns at most one row.
@@ -496,6 +668,26 @@ where
     }
 }
 
+/// Append the items of `stream` to `buf`, truncating it back to its original length on error.
+pub(crate) async fn extend_from_stream<T>(
+    buf: &mut Vec<T>,
+    mut stream: BoxStream<'_, Result<T, Error>>,
+) -> Result<usize, Error> {
+    let len = buf.len();
+
+    while let Some(item) = stream.next().await {
+        match item {
+            Ok(item) => buf.push(item),
+            Err(e) => {
+                buf.truncate(len);
+                return Err(e);
+            }
+        }
+    }
+
+    Ok(buf.len() - len)
+}
+
 /// Execute a single SQL query as a prepared statement (explicitly created).
 pub fn query_statement<'q, DB>(
     statement: &'q DB::Statement<'q>,
@@ -508,6 +700,7 @@ where
         arguments: Some(Ok(Default::default())),
         statement: Either::Right(statement),
         persistent: true,
+        timeout: None,
     }
 }
 
@@ -525,6 +718,7 @@ where
         arguments: Some(Ok(arguments)),
         statement: Either::Right(statement),
         persistent: true,
+        timeout: None,
     }
 }
 
@@ -661,6 +855,7 @@ where
         arguments: Some(Ok(Default::default())),
         statement: Either::Left(sql),
         persistent: true,
+        timeout: None,
     }
 }
 
@@ -689,5 +884,6 @@ where
         arguments: Some(arguments),
         statement: Either::Left(sql),
         persistent: true,
+        timeout: None,
     }
 }
@@ -1,4 +1,5 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use either::Either;
 use futures_core::stream::BoxStream;
@@ -7,10 +8,12 @@ use futures_util::{StreamExt, TryStreamExt};
 use crate::arguments::IntoArguments;
 use crate::database::{Database, HasStatementCache};
 use crate::encode::Encode;
-use crate::error::{BoxDynError, Error};
+use crate::error::{BoxDynError, Error, RowDecodeError};
 use crate::executor::{Execute, Executor};
 use crate::from_row::FromRow;
-use crate::query::{query, query_statement, query_statement_with, query_with_result, Query};
+use crate::query::{
+    extend_from_stream, query, query_statement, query_statement_with, query_with_result, Query,
+};
 use crate::types::Type;
 
 /// A single SQL query as a prepared statement, mapping results using [`FromRow`].
@@ -45,6 +48,11 @@ where
     fn persistent(&self) -> bool {
         self.inner.persistent()
     }
+
+    #[inline]
+    fn timeout(&self) -> Option<Duration> {
+        Execute::timeout(&self.inner)
+    }
 }
 
 impl<'q, DB: Database, O> QueryAs<'q, DB, O, <DB as Database>::Arguments<'q>> {
@@ -55,6 +63,14 @@ impl<'q, DB: Database, O> QueryAs<'q, DB, O, <DB as Database>::Arguments<'q>> {
         self.inner = self.inner.bind(value);
         self
     }
+
+    /// Send a query attribute with this SQL query.
+    ///
+    /// See [`Query::attribute`](Query::attribute).
+    pub fn attribute<T: 'q + Encode<'q, DB> + Type<DB>>(mut self, name: &str, value: T) -> Self {
+        self.inner = self.inner.attribute(name, value);
+        self
+    }
 }
 
 impl<'q, DB, O, A> QueryAs<'q, DB, O, A>
@@ -64,19 +80,23 @@ where
     /// If `true`, the statement will get prepared once and cached to the
     /// connection's statement cache.
     ///
-    /// If queried once with the flag set to `true`, all subsequent queries
-    /// matching the one with the flag will use the cached statement until the
-    /// cache is cleared.
-    ///
-    /// If `false`, the prepared statement will be closed after execution.
-    ///
-    /// Default: `true`.
+    /// See [`Query::persistent`](Query::persistent).
     pub fn persistent(mut self, value: bool) -> Self {
         self.inner = self.inner.persistent(value);
         self
     }
 }
 
+impl<'q, DB: Database, O, A> QueryAs<'q, DB, O, A> {
+    /// Set the maximum time the database may spend executing this query before aborting it.
+    ///
+    /// See [`Query::timeout`](Query::timeout).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.timeout(timeout);
+        self
+    }
+}
+
 // FIXME: This is very close, nearly 1:1 with `Map`
 // noinspection DuplicatedCode
 impl<'q, DB, O, A> QueryAs<'q, DB, O, A>
@@ -102,6 +122,58 @@ where
             .boxed()
     }
 
+    /// Execute the query and return the generated results as a stream,
+    /// continuing past rows which fail to decode.
+    ///
+    /// A row which fails to decode is yielded as an `Ok(Err(_))` holding the row itself,
+    /// and the stream continues with the next row. Any other error ends the stream as usual.
+    ///
+    /// This is intended for scanning data which may not match the expected types,
+    /// e.g. to find and repair invalid rows.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(pool: sqlx::PgPool) -> sqlx::Result<()> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut rows = sqlx::query_as::<_, (i64, String)>("SELECT id, email FROM users")
+    ///     .fetch_lossy(&pool);
+    ///
+    /// while let Some(res) = rows.try_next().await? {
+    ///     match res {
+    ///         Ok((id, email)) => { /* ... */ }
+    ///         Err(e) => eprintln!("skipping row {}: {}", e.index, e.source),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fetch_lossy<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+    ) -> BoxStream<'e, Result<Result<O, RowDecodeError<DB::Row>>, Error>>
+    where
+        'q: 'e,
+        E: 'e + Executor<'c, Database = DB>,
+        DB: 'e,
+        O: 'e,
+        A: 'e,
+    {
+        let mut index = 0;
+
+        executor
+            .fetch(self.inner)
+            .map_ok(move |row| {
+                let res = match O::from_row(&row) {
+                    Ok(output) => Ok(output),
+                    Err(source) => Err(RowDecodeError { index, row, source }),
+                };
+
+                index += 1;
+                res
+            })
+            .boxed()
+    }
+
     /// Execute multiple queries and return the generated results as a stream
     /// from each query, in a stream.
     #[deprecated = "Only the SQLite driver supports multiple statements in one prepared statement and that behavior is deprecated. Use `sqlx::raw_sql()` instead. See https://github.com/launchbadge/sqlx/issues/3108 for discussion."]
@@ -145,6 +217,33 @@ where
         self.fetch(executor).try_collect().await
     }
 
+    /// Execute the query and append all the resulting rows to `buf`, returning how many were added.
+    ///
+    /// Unlike [`fetch_all()`][Self::fetch_all], this reuses the existing capacity of `buf`,
+    /// which avoids reallocating on every execution when the same query is run repeatedly,
+    /// e.g. in a polling loop. If an error is returned, `buf` is left as it was.
+    ///
+    /// ### Note: beware result set size.
+    /// This will attempt to collect the full result set of the query into memory.
+    ///
+    /// To avoid exhausting available memory, ensure the result set has a known upper bound,
+    /// e.g. using `LIMIT`.
+    #[inline]
+    pub async fn fetch_into<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+        buf: &mut Vec<O>,
+    ) -> Result<usize, Error>
+    where
+        'q: 'e,
+        E: 'e + Executor<'c, Database = DB>,
+        DB: 'e,
+        O: 'e,
+        A: 'e,
+    {
+        extend_from_stream(buf, self.fetch(executor)).await
+    }
+
     /// Execute the query, returning the first row or [`Error::RowNotFound`] otherwise.
     ///
     /// ### Note: for best performance, ensure the query returns at most one row.
@@ -1,19 +1,30 @@
 //! Runtime query-builder API.
 
+use std::cmp;
 use std::fmt::Display;
 use std::fmt::Write;
 use std::marker::PhantomData;
+use std::ops::Not;
 
+use crate::acquire::Acquire;
 use crate::arguments::{Arguments, IntoArguments};
-use crate::database::Database;
+use crate::database::{Database, HasBindLimit, HasReturningId};
+use crate::decode::Decode;
 use crate::encode::Encode;
+use crate::error::Error;
+use crate::executor::Executor;
 use crate::from_row::FromRow;
 use crate::query::Query;
 use crate::query_as::QueryAs;
 use crate::query_scalar::QueryScalar;
 use crate::types::Type;
+use crate::value::Value;
 use crate::Either;
 
+pub use paginate::{Page, PageKey, PageKeyEncode, Paginate};
+
+mod paginate;
+
 /// A builder type for constructing queries at runtime.
 ///
 /// See [`.push_values()`][Self::push_values] for an example of building a bulk `INSERT` statement.
@@ -433,6 +444,63 @@ where
         separated.query_builder
     }
 
+    /// Push a parenthesized list of bind argument placeholders, one for each item in `values`,
+    /// and bind the values to them.
+    ///
+    /// The placeholders are numbered for the database like with [`.push_bind()`][Self::push_bind],
+    /// e.g. `($1, $2, $3)` for Postgres or `(?, ?, ?)` for MySQL and SQLite, continuing from any
+    /// arguments bound before. This is mostly useful for `IN` lists.
+    ///
+    /// ### Notes
+    ///
+    /// If `values` is empty, this pushes `()`, which most databases reject as a syntax error.
+    ///
+    /// See [`.push_values()`][Self::push_values] on binding by-reference from an iterator,
+    /// and on varying lengths filling up the prepared statement cache.
+    ///
+    /// ### Example (Postgres)
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "postgres")]
+    /// # {
+    /// use sqlx::{Execute, Postgres, QueryBuilder};
+    ///
+    /// let mut query_builder: QueryBuilder<Postgres> =
+    ///     QueryBuilder::new("SELECT * FROM users WHERE status = ");
+    ///
+    /// query_builder
+    ///     .push_bind("active")
+    ///     .push(" AND id IN ")
+    ///     .push_placeholder_list([1, 2, 3]);
+    ///
+    /// let query = query_builder.build();
+    ///
+    /// assert_eq!(
+    ///     query.sql(),
+    ///     "SELECT * FROM users WHERE status = $1 AND id IN ($2, $3, $4)"
+    /// );
+    /// # }
+    /// ```
+    pub fn push_placeholder_list<I>(&mut self, values: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: 'args + Encode<'args, DB> + Type<DB>,
+    {
+        self.sanity_check();
+
+        self.push("(");
+
+        let mut separated = self.separated(", ");
+
+        for value in values {
+            separated.push_bind(value);
+        }
+
+        separated.push_unseparated(")");
+
+        separated.query_builder
+    }
+
     /// Produce an executable query from this builder.
     ///
     /// ### Note: Query is not Checked
@@ -453,6 +521,7 @@ where
             arguments: self.arguments.take().map(Ok),
             database: PhantomData,
             persistent: true,
+            timeout: None,
         }
     }
 
@@ -501,6 +570,92 @@ where
         }
     }
 
+    /// Execute the built `INSERT` statement and return the key generated for the inserted row.
+    ///
+    /// On databases supporting it (Postgres, SQLite and MariaDB 10.5+), ` RETURNING <column>` is
+    /// appended to the query and the key is decoded from the returned row, so it may be of any
+    /// type, e.g. a UUID. On MySQL, the statement is executed as-is and the key is the value of
+    /// `LAST_INSERT_ID()` reported by the server, so `column` must be an `AUTO_INCREMENT` column.
+    /// As the server doesn't report the type of the column, the key is decoded into `O` without
+    /// type checking; any integer type it fits in can be used.
+    ///
+    /// With the `Any` driver, the same applies to whichever database is connected to.
+    ///
+    /// Returns [`Error::RowNotFound`] if no key was generated.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(conn: &mut sqlx::PgConnection) -> sqlx::Result<()> {
+    /// use sqlx::{Postgres, QueryBuilder};
+    ///
+    /// let mut query_builder: QueryBuilder<Postgres> = QueryBuilder::new(
+    ///     "INSERT INTO users(username, email) VALUES ("
+    /// );
+    ///
+    /// query_builder
+    ///     .push_bind("alice")
+    ///     .push(", ")
+    ///     .push_bind("alice@example.com")
+    ///     .push(")");
+    ///
+    /// let id: i64 = query_builder.returning_id("id", conn).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ### Note: Reuse
+    /// As with [`.build()`][Self::build], you must call [`.reset()`][Self::reset] before
+    /// reusing this builder.
+    pub async fn returning_id<'q, 'c, O, A>(
+        &'q mut self,
+        column: &str,
+        acquire: A,
+    ) -> Result<O, Error>
+    where
+        DB: HasReturningId,
+        A: Acquire<'c, Database = DB>,
+        O: Send + Unpin + for<'r> Decode<'r, DB> + Type<DB>,
+        (O,): for<'r> FromRow<'r, DB::Row>,
+        <DB as Database>::Arguments<'args>: IntoArguments<'q, DB>,
+        for<'e> &'e mut <DB as Database>::Connection: Executor<'e, Database = DB>,
+    {
+        let mut conn = acquire.acquire().await?;
+
+        if DB::supports_returning(&conn) {
+            self.push(" RETURNING ").push(column);
+
+            return self.build_query_scalar::<O>().fetch_one(&mut *conn).await;
+        }
+
+        let result = self.build().execute(&mut *conn).await?;
+
+        let id = DB::last_insert_id(&result).ok_or(Error::RowNotFound)?;
+
+        O::decode(id.as_ref()).map_err(Error::Decode)
+    }
+
+    /// Push a condition built with [`Cond`], binding its values in order.
+    ///
+    /// The condition is not parenthesized as a whole; it can be pushed directly after `WHERE`.
+    pub fn push_cond(&mut self, cond: Cond<'args, DB>) -> &mut Self {
+        self.sanity_check();
+
+        cond.push_to(self);
+
+        self
+    }
+
+    /// Push the `ORDER BY` (for keyset pagination) and the `LIMIT` and `OFFSET` of `paginate`.
+    ///
+    /// This should be pushed at the end of the query, after the `WHERE` clause, which for keyset
+    /// pagination must include [`paginate.cond()`][Paginate::cond]. See [`Paginate`] for details.
+    pub fn push_paginate<K: PageKey>(&mut self, paginate: &Paginate<K>) -> &mut Self {
+        self.sanity_check();
+
+        paginate.push_to(self);
+
+        self
+    }
+
     /// Reset this `QueryBuilder` back to its initial state.
     ///
     /// The query is truncated to the initial fragment provided to [`new()`][Self::new] and
@@ -523,6 +678,113 @@ where
     }
 }
 
+impl<DB> QueryBuilder<'_, DB>
+where
+    DB: HasBindLimit,
+{
+    /// Insert `rows` into `table`, split into as many `INSERT` statements as needed to stay under
+    /// the bind parameter limit of the database, and return the combined results.
+    ///
+    /// Each statement is built with [`.push_values()`][Self::push_values], calling `push_row` for
+    /// every row, which must bind one value for each of `columns`. The statements are executed
+    /// in a transaction (or a savepoint, if `acquire` is already a transaction) so that either all
+    /// of the rows are inserted or none of them are.
+    ///
+    /// See [`.push_bind()`][Self::push_bind] for the limit of each database. With Postgres,
+    /// binding arrays and using `UNNEST()` is usually faster; [see our FAQ] for details.
+    ///
+    /// ### Warning: SQL Injection
+    /// `table` and `columns` are pushed into the query as-is, so they must not come from
+    /// untrusted input.
+    ///
+    /// ### Panics
+    /// If `columns` is empty.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,no_run
+    /// # async fn example(pool: &sqlx::PgPool) -> sqlx::Result<()> {
+    /// use sqlx::{Postgres, QueryBuilder};
+    ///
+    /// let users = (0..100_000).map(|i| (format!("user_{i}"), format!("user-{i}@example.com")));
+    ///
+    /// let result = QueryBuilder::<Postgres>::bulk_insert(
+    ///     "users",
+    ///     &["username", "email"],
+    ///     users,
+    ///     |mut b, (username, email)| {
+    ///         b.push_bind(username).push_bind(email);
+    ///     },
+    ///     pool,
+    /// )
+    /// .await?;
+    ///
+    /// assert_eq!(result.rows_affected(), 100_000);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [see our FAQ]: https://github.com/launchbadge/sqlx/blob/master/FAQ.md#how-can-i-bind-an-array-to-a-values-clause-how-can-i-do-bulk-inserts
+    pub async fn bulk_insert<'c, A, I, F>(
+        table: &str,
+        columns: &[&str],
+        rows: I,
+        mut push_row: F,
+        acquire: A,
+    ) -> Result<DB::QueryResult, Error>
+    where
+        A: Acquire<'c, Database = DB>,
+        I: IntoIterator,
+        F: for<'q> FnMut(Separated<'_, 'q, DB, &'static str>, I::Item),
+        for<'q> <DB as Database>::Arguments<'q>: IntoArguments<'q, DB>,
+        for<'e> &'e mut <DB as Database>::Connection: Executor<'e, Database = DB>,
+    {
+        assert!(
+            !columns.is_empty(),
+            "`bulk_insert()` requires at least one column"
+        );
+
+        let rows_per_statement = cmp::max(DB::BIND_LIMIT / columns.len(), 1);
+
+        let mut rows = rows.into_iter().peekable();
+        let mut result = DB::QueryResult::default();
+
+        if rows.peek().is_none() {
+            return Ok(result);
+        }
+
+        let init = format!("INSERT INTO {table} ({}) ", columns.join(", "));
+        let mut tx = acquire.begin().await?;
+
+        while rows.peek().is_some() {
+            // `.build()` would borrow the builder for as long as the values it binds, so the SQL
+            // and arguments are taken out of it instead
+            let (sql, arguments) = {
+                let mut query_builder = QueryBuilder::<DB>::new(&*init);
+
+                query_builder.push_values(rows.by_ref().take(rows_per_statement), &mut push_row);
+
+                (
+                    query_builder.query,
+                    query_builder
+                        .arguments
+                        .expect("BUG: Arguments taken already"),
+                )
+            };
+
+            let statement = crate::query::query_with(&sql, arguments)
+                .execute(&mut *tx)
+                .await?;
+
+            result.extend([statement]);
+        }
+
+        tx.commit().await?;
+
+        Ok(result)
+    }
+}
+
 /// A wrapper around `QueryBuilder` for creating comma(or other token)-separated lists.
 ///
 /// See [`QueryBuilder::separated()`] for details.
@@ -594,6 +856,185 @@ where
     }
 }
 
+/// A condition for a `WHERE` clause, built as a tree of [`all()`][Self::all] and
+/// [`any()`][Self::any] groups, negated with `!`, and rendered with [`QueryBuilder::push_cond()`].
+///
+/// Rendering adds parentheses around every sub-condition, so the fragments of a
+/// condition do not need to account for operator precedence, and binds values in the order
+/// they appear in the query, so the placeholders are numbered correctly for every database.
+///
+/// ```rust
+/// # #[cfg(feature = "postgres")] {
+/// use sqlx::query_builder::Cond;
+/// use sqlx::{Postgres, QueryBuilder};
+///
+/// let name: Option<&str> = Some("Alice");
+/// let min_age: Option<i32> = None;
+///
+/// let mut filters = vec![Cond::sql("deleted_at IS NULL")];
+///
+/// if let Some(name) = name {
+///     filters.push(Cond::any([
+///         Cond::sql("first_name = ").bind(name),
+///         Cond::sql("last_name = ").bind(name),
+///     ]));
+/// }
+///
+/// if let Some(min_age) = min_age {
+///     filters.push(Cond::sql("age >= ").bind(min_age));
+/// }
+///
+/// let mut query_builder = QueryBuilder::<Postgres>::new("SELECT * FROM users WHERE ");
+/// query_builder.push_cond(Cond::all(filters));
+///
+/// assert_eq!(
+///     query_builder.sql(),
+///     "SELECT * FROM users WHERE (deleted_at IS NULL) AND ((first_name = $1) OR (last_name = $2))"
+/// );
+/// # }
+/// ```
+#[must_use = "a condition must be pushed to a `QueryBuilder` to have any effect"]
+pub struct Cond<'args, DB: Database> {
+    kind: CondKind<'args, DB>,
+}
+
+enum CondKind<'args, DB: Database> {
+    Expr(Vec<CondPart<'args, DB>>),
+    All(Vec<Cond<'args, DB>>),
+    Any(Vec<Cond<'args, DB>>),
+    Not(Box<Cond<'args, DB>>),
+}
+
+enum CondPart<'args, DB: Database> {
+    Sql(String),
+    Bind(Box<dyn FnOnce(&mut QueryBuilder<'args, DB>) + 'args>),
+    Cond(Cond<'args, DB>),
+}
+
+impl<'args, DB: Database> Cond<'args, DB> {
+    /// A condition starting with the SQL fragment `sql`.
+    ///
+    /// Continue it with [`.push()`][Self::push] and [`.bind()`][Self::bind].
+    ///
+    /// ### Warning: Beware SQL Injection Vulnerabilities and Untrusted Input!
+    /// The fragment is not sanitized. See [`QueryBuilder::push()`] for details.
+    pub fn sql(sql: impl Display) -> Self {
+        Self {
+            kind: CondKind::Expr(vec![CondPart::Sql(sql.to_string())]),
+        }
+    }
+
+    /// A condition which holds if every condition in `conds` holds, or always if there are none.
+    pub fn all(conds: impl IntoIterator<Item = Self>) -> Self {
+        Self {
+            kind: CondKind::All(conds.into_iter().collect()),
+        }
+    }
+
+    /// A condition which holds if any condition in `conds` holds, or never if there are none.
+    pub fn any(conds: impl IntoIterator<Item = Self>) -> Self {
+        Self {
+            kind: CondKind::Any(conds.into_iter().collect()),
+        }
+    }
+
+    /// Append a SQL fragment to this condition.
+    ///
+    /// If this is a group of conditions, the group is parenthesized first.
+    pub fn push(self, sql: impl Display) -> Self {
+        self.push_part(CondPart::Sql(sql.to_string()))
+    }
+
+    /// Append a bind argument placeholder to this condition and bind `value` to it.
+    ///
+    /// If this is a group of conditions, the group is parenthesized first.
+    pub fn bind<T>(self, value: T) -> Self
+    where
+        T: 'args + Encode<'args, DB> + Type<DB>,
+    {
+        self.push_part(CondPart::Bind(Box::new(move |query_builder| {
+            query_builder.push_bind(value);
+        })))
+    }
+
+    fn push_part(self, part: CondPart<'args, DB>) -> Self {
+        let mut parts = match self.kind {
+            CondKind::Expr(parts) => parts,
+            kind => vec![CondPart::Cond(Cond { kind })],
+        };
+
+        parts.push(part);
+
+        Self {
+            kind: CondKind::Expr(parts),
+        }
+    }
+
+    fn push_to(self, query_builder: &mut QueryBuilder<'args, DB>) {
+        match self.kind {
+            CondKind::Expr(parts) => {
+                for part in parts {
+                    match part {
+                        CondPart::Sql(sql) => {
+                            query_builder.push(sql);
+                        }
+                        CondPart::Bind(push_bind) => push_bind(query_builder),
+                        CondPart::Cond(cond) => cond.push_parenthesized(query_builder),
+                    }
+                }
+            }
+            CondKind::All(conds) => Self::push_group(conds, " AND ", "1=1", query_builder),
+            CondKind::Any(conds) => Self::push_group(conds, " OR ", "1=0", query_builder),
+            CondKind::Not(cond) => {
+                query_builder.push("NOT ");
+                cond.push_parenthesized(query_builder);
+            }
+        }
+    }
+
+    fn push_group(
+        conds: Vec<Self>,
+        separator: &str,
+        empty: &str,
+        query_builder: &mut QueryBuilder<'args, DB>,
+    ) {
+        match conds.len() {
+            0 => {
+                query_builder.push(empty);
+            }
+            1 => conds
+                .into_iter()
+                .for_each(|cond| cond.push_to(query_builder)),
+            _ => {
+                for (i, cond) in conds.into_iter().enumerate() {
+                    if i > 0 {
+                        query_builder.push(separator);
+                    }
+
+                    cond.push_parenthesized(query_builder);
+                }
+            }
+        }
+    }
+
+    fn push_parenthesized(self, query_builder: &mut QueryBuilder<'args, DB>) {
+        query_builder.push("(");
+        self.push_to(query_builder);
+        query_builder.push(")");
+    }
+}
+
+/// A condition which holds if this one does not.
+impl<'args, DB: Database> Not for Cond<'args, DB> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self {
+            kind: CondKind::Not(Box::new(self)),
+        }
+    }
+}
+
 #[cfg(all(test, feature = "postgres"))]
 mod test {
     use crate::postgres::Postgres;
@@ -642,6 +1083,21 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_push_placeholder_list() {
+        let mut qb: QueryBuilder<'_, Postgres> =
+            QueryBuilder::new("SELECT * FROM users WHERE membership_level = ");
+
+        qb.push_bind(3i32)
+            .push(" AND id IN ")
+            .push_placeholder_list([1i32, 2, 3]);
+
+        assert_eq!(
+            qb.query,
+            "SELECT * FROM users WHERE membership_level = $1 AND id IN ($2, $3, $4)"
+        );
+    }
+
     #[test]
     fn test_build() {
         let mut qb: QueryBuilder<'_, Postgres> = QueryBuilder::new("SELECT * FROM users");
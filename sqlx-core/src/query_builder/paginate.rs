@@ -0,0 +1,462 @@
+//! Pagination by `LIMIT`/`OFFSET` or by keyset, for queries built with [`QueryBuilder`].
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::database::Database;
+use crate::encode::Encode;
+use crate::error::BoxDynError;
+use crate::query_builder::{Cond, QueryBuilder};
+use crate::types::Type;
+
+/// Paginate a query built with [`QueryBuilder`], by `LIMIT`/`OFFSET` or by keyset.
+///
+/// Each page fetches one row more than the page size, so [`Page::has_more`] is known without
+/// a separate `COUNT(*)`. Where to continue is encoded in [`Page::next_token`], an opaque string
+/// to hand to a client and pass back to [`.resume()`][Self::resume] for the next page.
+///
+/// ### Keyset pagination
+/// [`keyset()`][Self::keyset] pages by the values of one or more columns which are unique
+/// together, e.g. `created_at, id`, rather than by skipping rows. This stays fast for deep
+/// pages and doesn't skip or repeat rows when rows are inserted or deleted between pages.
+///
+/// The key type `K` is a tuple of the column types, e.g. `(i64,)`, which is bound to the query
+/// and encoded into the token with `Display` and parsed back with `FromStr`.
+///
+/// Push [`.cond()`][Self::cond] into the `WHERE` clause, then [`QueryBuilder::push_paginate()`]
+/// after it, which pushes the `ORDER BY` for the keyset and the `LIMIT`:
+///
+/// ```rust
+/// # #[cfg(feature = "postgres")] {
+/// use sqlx::query_builder::{Cond, Paginate};
+/// use sqlx::{Postgres, QueryBuilder};
+///
+/// struct Post {
+///     id: i64,
+///     title: String,
+/// }
+///
+/// // `None` for the first page
+/// let token = Some("42");
+///
+/// let paginate = Paginate::<(i64,)>::keyset(20, ["id"]).resume(token).unwrap();
+///
+/// let mut query_builder = QueryBuilder::<Postgres>::new("SELECT id, title FROM posts WHERE ");
+/// query_builder
+///     .push_cond(Cond::all([Cond::sql("author_id = ").bind(7_i64), paginate.cond()]))
+///     .push_paginate(&paginate);
+///
+/// assert_eq!(
+///     query_builder.sql(),
+///     "SELECT id, title FROM posts WHERE (author_id = $1) AND ((id) > ($2)) ORDER BY id LIMIT 21"
+/// );
+///
+/// // with the rows from `query_builder.build_query_as()`
+/// let rows: Vec<Post> = (43..=63).map(|id| Post { id, title: format!("post {id}") }).collect();
+/// let page = paginate.page(rows, |post| (post.id,));
+///
+/// assert_eq!(page.items.len(), 20);
+/// assert!(page.has_more);
+/// assert_eq!(page.next_token.as_deref(), Some("62"));
+/// # }
+/// ```
+///
+/// ### `LIMIT`/`OFFSET` pagination
+/// [`offset()`][Self::offset] skips the rows of the previous pages. The query needs an
+/// `ORDER BY` which makes the order of the rows unique, or pages may overlap.
+/// [`.cond()`][Self::cond] is always true, so it doesn't need to be pushed.
+#[derive(Debug, Clone)]
+pub struct Paginate<K = ()> {
+    page_size: u32,
+    kind: PaginateKind<K>,
+}
+
+#[derive(Debug, Clone)]
+enum PaginateKind<K> {
+    Offset(u64),
+    Keyset {
+        columns: Vec<String>,
+        descending: bool,
+        after: Option<K>,
+    },
+}
+
+/// A page of rows from [`Paginate::page()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Page<T> {
+    /// The rows of this page, at most the page size.
+    pub items: Vec<T>,
+
+    /// `true` if there are rows after this page.
+    pub has_more: bool,
+
+    /// The token to [resume][Paginate::resume] from for the next page, if there is one.
+    pub next_token: Option<String>,
+}
+
+/// A key for keyset pagination with [`Paginate::keyset()`]: a tuple of the types of the
+/// key columns, each of which is encoded into page tokens with `Display` and parsed with `FromStr`.
+///
+/// Implemented for tuples of up to 4 elements, and for `()` for `LIMIT`/`OFFSET` pagination.
+pub trait PageKey: Sized {
+    /// The number of columns in the key.
+    const LEN: usize;
+
+    #[doc(hidden)]
+    fn write_token_parts(&self, parts: &mut Vec<String>);
+
+    #[doc(hidden)]
+    fn read_token_parts(parts: &mut dyn Iterator<Item = String>) -> Result<Self, BoxDynError>;
+}
+
+/// A [`PageKey`] whose columns can be bound to queries for `DB`.
+pub trait PageKeyEncode<'args, DB: Database>: PageKey {
+    #[doc(hidden)]
+    fn bind_to(self, cond: Cond<'args, DB>) -> Cond<'args, DB>;
+}
+
+impl Paginate<()> {
+    /// Paginate with `LIMIT` and `OFFSET`, starting at the first page.
+    ///
+    /// ### Panics
+    /// If `page_size` is zero.
+    pub fn offset(page_size: u32) -> Self {
+        assert!(page_size > 0, "page size must not be zero");
+
+        Self {
+            page_size,
+            kind: PaginateKind::Offset(0),
+        }
+    }
+}
+
+impl<K: PageKey> Paginate<K> {
+    /// Paginate by the values of `columns` in ascending order, starting at the first page.
+    ///
+    /// `columns` are pushed to the query as-is, so they must not come from untrusted input,
+    /// and must be unique together for every row.
+    ///
+    /// ### Panics
+    /// If `page_size` is zero, there are no `columns`, or the number of `columns` is not the length
+    /// of the key type `K`.
+    pub fn keyset(page_size: u32, columns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        assert!(page_size > 0, "page size must not be zero");
+
+        let columns: Vec<String> = columns.into_iter().map(Into::into).collect();
+
+        assert!(
+            !columns.is_empty(),
+            "keyset pagination needs at least one column"
+        );
+
+        assert_eq!(
+            columns.len(),
+            K::LEN,
+            "the number of keyset columns must be the length of the key"
+        );
+
+        Self {
+            page_size,
+            kind: PaginateKind::Keyset {
+                columns,
+                descending: false,
+                after: None,
+            },
+        }
+    }
+
+    /// Order the keyset in descending order instead. Has no effect with `LIMIT`/`OFFSET`.
+    pub fn descending(mut self) -> Self {
+        if let PaginateKind::Keyset { descending, .. } = &mut self.kind {
+            *descending = true;
+        }
+
+        self
+    }
+
+    /// Continue after the page which returned `token` as its [`Page::next_token`],
+    /// or start at the first page for `None`.
+    ///
+    /// Returns an error if `token` was not returned by a `Paginate` of the same kind and key type,
+    /// or its offset is out of range.
+    pub fn resume(mut self, token: Option<&str>) -> Result<Self, BoxDynError> {
+        let Some(token) = token else {
+            return Ok(self);
+        };
+
+        match &mut self.kind {
+            PaginateKind::Offset(offset) => {
+                // databases take a signed 64-bit `OFFSET`, which also keeps the offset of the
+                // next page in `.page()` from overflowing
+                *offset = token
+                    .parse::<i64>()
+                    .ok()
+                    .and_then(|offset| u64::try_from(offset).ok())
+                    .ok_or_else(|| format!("invalid page token: {token:?}"))?;
+            }
+
+            PaginateKind::Keyset { after, .. } => {
+                let mut parts = token.split(',').map(unescape_token_part);
+                let key = K::read_token_parts(&mut parts)?;
+
+                if parts.next().is_some() {
+                    return Err(format!("invalid page token: {token:?}").into());
+                }
+
+                *after = Some(key);
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// The number of rows per page.
+    pub fn page_size(&self) -> u32 {
+        self.page_size
+    }
+
+    /// The condition for rows after the previous page, like `(created_at, id) > (?, ?)`,
+    /// to push into the `WHERE` clause with [`QueryBuilder::push_cond()`].
+    ///
+    /// Always true for the first page, and for `LIMIT`/`OFFSET` pagination.
+    pub fn cond<'args, DB>(&self) -> Cond<'args, DB>
+    where
+        DB: Database,
+        K: Clone + PageKeyEncode<'args, DB>,
+    {
+        match &self.kind {
+            PaginateKind::Keyset {
+                columns,
+                descending,
+                after: Some(after),
+            } => {
+                let cond = Cond::sql(format!(
+                    "({}) {} (",
+                    columns.join(", "),
+                    if *descending { "<" } else { ">" }
+                ));
+
+                after.clone().bind_to(cond).push(")")
+            }
+
+            _ => Cond::all([]),
+        }
+    }
+
+    /// Make a page of the rows fetched by the query, taking the key of a row with `key`.
+    ///
+    /// `key` is only called for the last row of the page, and only with keyset pagination;
+    /// with `LIMIT`/`OFFSET` pagination, pass `|_| ()`.
+    pub fn page<T>(&self, mut rows: Vec<T>, key: impl FnOnce(&T) -> K) -> Page<T> {
+        let page_size = self.page_size as usize;
+        let has_more = rows.len() > page_size;
+
+        rows.truncate(page_size);
+
+        let next_token = match &self.kind {
+            _ if !has_more => None,
+
+            PaginateKind::Offset(offset) => Some((offset + self.page_size as u64).to_string()),
+
+            PaginateKind::Keyset { .. } => rows.last().map(|row| {
+                let mut parts = Vec::with_capacity(K::LEN);
+                key(row).write_token_parts(&mut parts);
+
+                parts
+                    .iter()
+                    .map(|part| escape_token_part(part))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            }),
+        };
+
+        Page {
+            items: rows,
+            has_more,
+            next_token,
+        }
+    }
+
+    pub(super) fn push_to<DB: Database>(&self, query_builder: &mut QueryBuilder<'_, DB>) {
+        match &self.kind {
+            PaginateKind::Offset(offset) => {
+                query_builder.push(format_args!(
+                    " LIMIT {} OFFSET {offset}",
+                    self.page_size as u64 + 1
+                ));
+            }
+
+            PaginateKind::Keyset {
+                columns,
+                descending,
+                ..
+            } => {
+                query_builder.push(" ORDER BY ");
+
+                for (i, column) in columns.iter().enumerate() {
+                    if i > 0 {
+                        query_builder.push(", ");
+                    }
+
+                    query_builder.push(column);
+
+                    if *descending {
+                        query_builder.push(" DESC");
+                    }
+                }
+
+                query_builder.push(format_args!(" LIMIT {}", self.page_size as u64 + 1));
+            }
+        }
+    }
+}
+
+// `,` separates the columns of a key in a token
+fn escape_token_part(part: &str) -> String {
+    let mut escaped = String::with_capacity(part.len());
+
+    for c in part.chars() {
+        match c {
+            '%' => escaped.push_str("%25"),
+            ',' => escaped.push_str("%2C"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn unescape_token_part(part: &str) -> String {
+    part.replace("%2C", ",").replace("%25", "%")
+}
+
+macro_rules! impl_page_key_for_tuple {
+    ($len:literal; $idx0:tt: $T0:ident $(, $idx:tt: $T:ident)*) => {
+        impl<$T0, $($T),*> PageKey for ($T0, $($T,)*)
+        where
+            $T0: Display + FromStr,
+            <$T0 as FromStr>::Err: Into<BoxDynError>,
+            $($T: Display + FromStr, <$T as FromStr>::Err: Into<BoxDynError>,)*
+        {
+            const LEN: usize = $len;
+
+            fn write_token_parts(&self, parts: &mut Vec<String>) {
+                parts.push(self.$idx0.to_string());
+                $(parts.push(self.$idx.to_string());)*
+            }
+
+            fn read_token_parts(
+                parts: &mut dyn Iterator<Item = String>,
+            ) -> Result<Self, BoxDynError> {
+                Ok((
+                    read_token_part::<$T0>(parts)?,
+                    $(read_token_part::<$T>(parts)?,)*
+                ))
+            }
+        }
+
+        impl<'args, DB: Database, $T0, $($T),*> PageKeyEncode<'args, DB> for ($T0, $($T,)*)
+        where
+            Self: PageKey,
+            $T0: 'args + Encode<'args, DB> + Type<DB>,
+            $($T: 'args + Encode<'args, DB> + Type<DB>,)*
+        {
+            fn bind_to(self, cond: Cond<'args, DB>) -> Cond<'args, DB> {
+                let cond = cond.bind(self.$idx0);
+                $(let cond = cond.push(", ").bind(self.$idx);)*
+                cond
+            }
+        }
+    };
+}
+
+/// The key of `LIMIT`/`OFFSET` pagination, which has no key columns.
+impl PageKey for () {
+    const LEN: usize = 0;
+
+    fn write_token_parts(&self, _parts: &mut Vec<String>) {}
+
+    fn read_token_parts(_parts: &mut dyn Iterator<Item = String>) -> Result<Self, BoxDynError> {
+        Ok(())
+    }
+}
+
+fn read_token_part<T>(parts: &mut dyn Iterator<Item = String>) -> Result<T, BoxDynError>
+where
+    T: FromStr,
+    T::Err: Into<BoxDynError>,
+{
+    parts
+        .next()
+        .ok_or("page token has too few columns")?
+        .parse()
+        .map_err(Into::into)
+}
+
+impl_page_key_for_tuple!(1; 0: T1);
+impl_page_key_for_tuple!(2; 0: T1, 1: T2);
+impl_page_key_for_tuple!(3; 0: T1, 1: T2, 2: T3);
+impl_page_key_for_tuple!(4; 0: T1, 1: T2, 2: T3, 3: T4);
+
+#[test]
+fn it_encodes_and_resumes_page_tokens() {
+    let paginate = Paginate::<(String, i64)>::keyset(2, ["name", "id"]);
+
+    let page = paginate.page(
+        vec![
+            ("a".to_string(), 1),
+            ("b,%2C".to_string(), 2),
+            ("c".into(), 3),
+        ],
+        |row| row.clone(),
+    );
+
+    assert_eq!(page.items.len(), 2);
+    assert!(page.has_more);
+    assert_eq!(page.next_token.as_deref(), Some("b%2C%252C,2"));
+
+    let resumed = paginate.resume(page.next_token.as_deref()).unwrap();
+    assert!(matches!(
+        resumed.kind,
+        PaginateKind::Keyset { after: Some((ref name, 2)), .. } if name == "b,%2C"
+    ));
+
+    let last = resumed.page(vec![("c".to_string(), 3)], |row| row.clone());
+    assert!(!last.has_more);
+    assert_eq!(last.next_token, None);
+
+    assert!(Paginate::<(i64,)>::keyset(2, ["id"])
+        .resume(Some("1,2"))
+        .is_err());
+    assert!(Paginate::<(i64, i64)>::keyset(2, ["a", "b"])
+        .resume(Some("1"))
+        .is_err());
+    assert!(Paginate::<(i64,)>::keyset(2, ["id"])
+        .resume(Some("x"))
+        .is_err());
+
+    let page = Paginate::offset(2).page(vec![1, 2, 3], |_| ());
+    assert_eq!(page.next_token.as_deref(), Some("2"));
+
+    let resumed = Paginate::offset(2).resume(Some("2")).unwrap();
+    assert_eq!(
+        resumed.page(vec![3, 4, 5], |_| ()).next_token.as_deref(),
+        Some("4")
+    );
+
+    assert!(Paginate::offset(2).resume(Some("-1")).is_err());
+    assert!(Paginate::offset(2)
+        .resume(Some(&u64::MAX.to_string()))
+        .is_err());
+
+    let resumed = Paginate::offset(u32::MAX)
+        .resume(Some(&i64::MAX.to_string()))
+        .unwrap();
+    assert_eq!(
+        resumed
+            .page(vec![(); u32::MAX as usize + 1], |_| ())
+            .next_token,
+        Some((i64::MAX as u64 + u32::MAX as u64).to_string())
+    );
+}
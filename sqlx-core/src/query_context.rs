@@ -0,0 +1,190 @@
+//! Context attached to the logs of every query executed within a scope.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+thread_local! {
+    static CURRENT: RefCell<Option<QueryContext>> = const { RefCell::new(None) };
+}
+
+/// A set of key-value pairs (e.g. a request ID or user ID) which is attached to the log event
+/// of every query executed within [`scope()`][Self::scope], as the `context` field.
+///
+/// This makes it possible to correlate query logs with the request that caused them
+/// without passing the context to every call site.
+///
+/// ```rust,no_run
+/// # async fn example(pool: sqlx::PgPool, request_id: u64) -> sqlx::Result<()> {
+/// use sqlx::QueryContext;
+///
+/// QueryContext::new()
+///     .with("request_id", request_id)
+///     .with("user_id", 42)
+///     .scope(async {
+///         // logged with `context="request_id=... user_id=42"`
+///         sqlx::query("SELECT 1").execute(&pool).await
+///     })
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Scopes are not inherited by spawned tasks; wrap the spawned future in a scope of its own.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct QueryContext {
+    fields: Arc<Vec<(Cow<'static, str>, String)>>,
+}
+
+impl QueryContext {
+    /// Create an empty context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value of `key`, replacing any previous value.
+    pub fn with(mut self, key: impl Into<Cow<'static, str>>, value: impl Display) -> Self {
+        let key = key.into();
+        let value = value.to_string();
+        let fields = Arc::make_mut(&mut self.fields);
+
+        match fields.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => *v = value,
+            None => fields.push((key, value)),
+        }
+
+        self
+    }
+
+    /// Get the value of `key`, if it is set.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    /// Iterate over the key-value pairs in the order they were first set.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.fields.iter().map(|(k, v)| (&**k, &**v))
+    }
+
+    /// `true` if no keys are set.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// The context of the innermost enclosing scope, if any.
+    pub fn current() -> Option<QueryContext> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Run `future` with this as the current context.
+    ///
+    /// A nested scope replaces the context of the enclosing scope for its duration.
+    pub fn scope<F: Future>(self, future: F) -> Scoped<F> {
+        Scoped {
+            context: self,
+            future: Box::pin(future),
+        }
+    }
+
+    /// Run `f` with this as the current context.
+    pub fn sync_scope<R>(self, f: impl FnOnce() -> R) -> R {
+        let _guard = self.enter();
+        f()
+    }
+
+    /// Set this as the current context of the current thread until the guard is dropped.
+    ///
+    /// Used by drivers which execute queries on a background thread.
+    #[doc(hidden)]
+    pub fn enter(self) -> QueryContextGuard {
+        QueryContextGuard {
+            previous: CURRENT.with(|current| current.replace(Some(self))),
+        }
+    }
+}
+
+impl Display for QueryContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, (key, value)) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+
+            write!(f, "{key}={value}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Debug for QueryContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Restores the previous context when dropped. See [`QueryContext::enter()`].
+#[doc(hidden)]
+pub struct QueryContextGuard {
+    previous: Option<QueryContext>,
+}
+
+impl Drop for QueryContextGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// A future which runs with a [`QueryContext`]. Returned by [`QueryContext::scope()`].
+pub struct Scoped<F> {
+    context: QueryContext,
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for Scoped<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let _guard = self.context.clone().enter();
+
+        self.future.as_mut().poll(cx)
+    }
+}
+
+impl<F> Debug for Scoped<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scoped")
+            .field("context", &self.context)
+            .finish_non_exhaustive()
+    }
+}
+
+#[test]
+fn test_query_context() {
+    assert_eq!(QueryContext::current(), None);
+
+    let context = QueryContext::new()
+        .with("request_id", "abc")
+        .with("user_id", 42)
+        .with("request_id", "def");
+
+    assert_eq!(context.get("request_id"), Some("def"));
+    assert_eq!(context.to_string(), "request_id=def user_id=42");
+
+    context.clone().sync_scope(|| {
+        assert_eq!(QueryContext::current().as_ref(), Some(&context));
+
+        QueryContext::new().with("nested", true).sync_scope(|| {
+            assert_eq!(QueryContext::current().unwrap().to_string(), "nested=true");
+        });
+
+        assert_eq!(QueryContext::current().as_ref(), Some(&context));
+    });
+
+    assert_eq!(QueryContext::current(), None);
+}
@@ -0,0 +1,488 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+/// A normalized form of an SQL string along with a stable hash of it, for grouping the
+/// executions of a query regardless of the values it was run with.
+///
+/// The SQL is normalized by:
+/// * replacing string and numeric literals, and bind parameters in any placeholder style
+///   (`$1`, `?`, `?1`, `:name`, `@name`, `$name`) with Postgres-style numbered parameters,
+///   as in the `query` column of the `pg_stat_statements` view; literals are numbered after the
+///   largest numbered parameter, so they never share a number with one;
+/// * removing comments and collapsing whitespace;
+/// * lowercasing keywords and unquoted identifiers.
+///
+/// Quoted identifiers are kept as-is. The SQL is not parsed, so this is a best effort:
+/// statements that differ only in the length of a list of values, for example, have different
+/// fingerprints.
+///
+/// The hash is stable across platforms and releases of SQLx, so it can be stored or compared
+/// between processes. It is displayed as 16 hexadecimal digits. It is *not* the `queryid` of
+/// `pg_stat_statements`, which is computed by Postgres from the parsed statement; match entries
+/// there by their `query` text against [`normalized()`][Self::normalized] instead.
+///
+/// Statements executed on a connection can be logged with their fingerprint by enabling
+/// [`ConnectOptions::log_query_fingerprints()`][crate::connection::ConnectOptions::log_query_fingerprints].
+///
+/// ```rust
+/// # use sqlx_core::query_fingerprint::QueryFingerprint;
+/// let a = QueryFingerprint::new("SELECT * FROM users WHERE id = 42 -- by id");
+/// let b = QueryFingerprint::new("select *\n  from users\n where id = ?");
+///
+/// assert_eq!(a, b);
+/// assert_eq!(a.normalized(), "select * from users where id = $1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QueryFingerprint {
+    normalized: String,
+    hash: u64,
+}
+
+impl QueryFingerprint {
+    /// Compute the fingerprint of `sql`.
+    pub fn new(sql: &str) -> Self {
+        let normalized = normalize(sql);
+        let hash = fnv1a(normalized.as_bytes());
+
+        QueryFingerprint { normalized, hash }
+    }
+
+    /// The normalized SQL.
+    pub fn normalized(&self) -> &str {
+        &self.normalized
+    }
+
+    /// The hash of the normalized SQL.
+    pub fn as_u64(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl Display for QueryFingerprint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.hash)
+    }
+}
+
+// 64-bit FNV-1a, as `std`'s hashers are not guaranteed to be stable.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Token {
+    // Identifiers, keywords and parameters; separated from each other by a space.
+    Word,
+    // Operators; separated from everything but brackets by a space.
+    Operator,
+    OpenBracket,
+    CloseBracket,
+    // `,` and `;`
+    Separator,
+    // `.` and `::`; not separated by a space.
+    Dot,
+}
+
+struct Normalizer<'a> {
+    sql: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    out: String,
+    last: Option<Token>,
+    next_param: u32,
+    named_params: HashMap<&'a str, u32>,
+    // The largest `$N` or `?N` seen so far.
+    max_numbered_param: u32,
+}
+
+fn normalize(sql: &str) -> String {
+    let first = Normalizer::new(sql, 1).run();
+
+    // Literals and named parameters seen before the largest numbered parameter may have been
+    // given a number it or another numbered parameter uses, so number them after it instead.
+    if first.max_numbered_param > 0 {
+        return Normalizer::new(sql, first.max_numbered_param + 1).run().out;
+    }
+
+    first.out
+}
+
+impl<'a> Normalizer<'a> {
+    fn new(sql: &'a str, next_param: u32) -> Self {
+        Normalizer {
+            sql,
+            chars: sql.char_indices().peekable(),
+            out: String::with_capacity(sql.len()),
+            last: None,
+            next_param,
+            named_params: HashMap::new(),
+            max_numbered_param: 0,
+        }
+    }
+
+    fn run(mut self) -> Self {
+        while let Some((start, c)) = self.chars.next() {
+            match c {
+                c if c.is_whitespace() => {}
+
+                '-' if self.eat('-') => self.skip_while(|c| c != '\n'),
+
+                '/' if self.eat('*') => self.skip_block_comment(),
+
+                '\'' => {
+                    self.skip_string('\'', false);
+                    self.push_anonymous_param();
+                }
+
+                '"' | '`' => {
+                    self.skip_string(c, false);
+                    let identifier = self.slice_from(start);
+                    self.push(Token::Word, identifier);
+                }
+
+                '0'..='9' => {
+                    self.skip_number();
+                    self.push_anonymous_param();
+                }
+
+                '.' if self.peek().is_some_and(|c| c.is_ascii_digit()) => {
+                    self.skip_number();
+                    self.push_anonymous_param();
+                }
+
+                '$' if self.peek().is_some_and(|c| c.is_ascii_digit()) => {
+                    let number = self.take_while(|c| c.is_ascii_digit());
+                    self.push_numbered_param(number);
+                }
+
+                '$' if self
+                    .peek()
+                    .is_some_and(|c| c == '$' || is_identifier_start(c)) =>
+                {
+                    let tag_start = self.position();
+                    self.skip_while(is_identifier_char);
+
+                    if self.eat('$') {
+                        // A dollar-quoted string, e.g. `$$...$$` or `$tag$...$tag$`.
+                        let tag = self.slice_from(start);
+                        self.skip_dollar_quoted(tag);
+                        self.push_anonymous_param();
+                    } else {
+                        let name = self.slice_from(tag_start);
+                        self.push_named_param(name);
+                    }
+                }
+
+                '?' if self.peek().is_some_and(|c| c.is_ascii_digit()) => {
+                    let number = self.take_while(|c| c.is_ascii_digit());
+                    self.push_numbered_param(number);
+                }
+
+                // `?|` and `?&` are Postgres operators.
+                '?' if !self.peek().is_some_and(|c| c == '|' || c == '&') => {
+                    self.push_anonymous_param();
+                }
+
+                ':' | '@' if self.peek().is_some_and(is_identifier_start) => {
+                    let name = self.take_while(is_identifier_char);
+                    self.push_named_param(name);
+                }
+
+                c if is_identifier_start(c) => {
+                    self.skip_while(is_identifier_char);
+                    let word = self.slice_from(start);
+
+                    if self.peek() == Some('\'')
+                        && matches!(word, "e" | "E" | "b" | "B" | "x" | "X" | "n" | "N")
+                    {
+                        // A prefixed string, e.g. `E'\n'` or `X'00'`.
+                        self.chars.next();
+                        self.skip_string('\'', word.eq_ignore_ascii_case("e"));
+                        self.push_anonymous_param();
+                    } else {
+                        self.push(Token::Word, &word.to_ascii_lowercase());
+                    }
+                }
+
+                '(' | '[' => self.push_char(Token::OpenBracket, c),
+                ')' | ']' => self.push_char(Token::CloseBracket, c),
+                ',' | ';' => self.push_char(Token::Separator, c),
+                '.' => self.push_char(Token::Dot, c),
+                ':' if self.eat(':') => self.push(Token::Dot, "::"),
+
+                c if is_operator_char(c) => {
+                    self.skip_while(is_operator_char);
+                    let operator = self.slice_from(start);
+                    self.push(Token::Operator, operator);
+                }
+
+                c => self.push_char(Token::Operator, c),
+            }
+        }
+
+        // A trailing `;` doesn't change the statement.
+        if self.last == Some(Token::Separator) && self.out.ends_with(';') {
+            self.out.pop();
+        }
+
+        self
+    }
+
+    fn push(&mut self, token: Token, text: &str) {
+        let space = match (self.last, token) {
+            (None, _) => false,
+            (Some(Token::OpenBracket | Token::Dot), _) => false,
+            (_, Token::CloseBracket | Token::Separator | Token::Dot) => false,
+            // Function calls and array subscripts, e.g. `count(*)` or `a[1]`.
+            (Some(Token::Word | Token::CloseBracket), Token::OpenBracket) => false,
+            _ => true,
+        };
+
+        if space {
+            self.out.push(' ');
+        }
+
+        self.out.push_str(text);
+        self.last = Some(token);
+    }
+
+    fn push_char(&mut self, token: Token, c: char) {
+        self.push(token, c.encode_utf8(&mut [0; 4]));
+    }
+
+    fn push_param(&mut self, number: u32) {
+        self.push(Token::Word, &format!("${number}"));
+    }
+
+    fn push_anonymous_param(&mut self) {
+        let number = self.next_param;
+        self.next_param += 1;
+        self.push_param(number);
+    }
+
+    fn push_numbered_param(&mut self, number: &str) {
+        match number.parse::<u32>() {
+            Ok(number) => {
+                self.max_numbered_param = std::cmp::max(self.max_numbered_param, number);
+                self.next_param = std::cmp::max(self.next_param, number.saturating_add(1));
+                self.push_param(number);
+            }
+            Err(_) => self.push_anonymous_param(),
+        }
+    }
+
+    fn push_named_param(&mut self, name: &'a str) {
+        let number = match self.named_params.get(name) {
+            Some(&number) => number,
+            None => {
+                let number = self.next_param;
+                self.next_param += 1;
+                self.named_params.insert(name, number);
+                number
+            }
+        };
+
+        self.push_param(number);
+    }
+
+    fn position(&mut self) -> usize {
+        self.chars.peek().map_or(self.sql.len(), |&(i, _)| i)
+    }
+
+    fn slice_from(&mut self, start: usize) -> &'a str {
+        let end = self.position();
+        &self.sql[start..end]
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        self.chars.next_if(|&(_, c)| c == expected).is_some()
+    }
+
+    fn skip_while(&mut self, mut predicate: impl FnMut(char) -> bool) {
+        while self.chars.next_if(|&(_, c)| predicate(c)).is_some() {}
+    }
+
+    fn take_while(&mut self, predicate: impl FnMut(char) -> bool) -> &'a str {
+        let start = self.position();
+        self.skip_while(predicate);
+        self.slice_from(start)
+    }
+
+    fn skip_block_comment(&mut self) {
+        // Postgres allows block comments to be nested.
+        let mut depth = 1;
+
+        while let Some((_, c)) = self.chars.next() {
+            match c {
+                '*' if self.eat('/') => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        return;
+                    }
+                }
+                '/' if self.eat('*') => depth += 1,
+                _ => {}
+            }
+        }
+    }
+
+    // Skips to the closing `quote`, which may be escaped by doubling it.
+    fn skip_string(&mut self, quote: char, backslash_escapes: bool) {
+        while let Some((_, c)) = self.chars.next() {
+            if c == quote {
+                if !self.eat(quote) {
+                    return;
+                }
+            } else if c == '\\' && backslash_escapes {
+                self.chars.next();
+            }
+        }
+    }
+
+    fn skip_dollar_quoted(&mut self, tag: &str) {
+        let start = self.position();
+
+        match self.sql[start..].find(tag) {
+            Some(offset) => {
+                let end = start + offset + tag.len();
+                while self.chars.next_if(|&(i, _)| i < end).is_some() {}
+            }
+            None => while self.chars.next().is_some() {},
+        }
+    }
+
+    fn skip_number(&mut self) {
+        self.skip_while(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+
+        // The sign of an exponent, e.g. `1e-5`.
+        let end = self.position();
+
+        if self.sql[..end].ends_with(['e', 'E'])
+            && self.peek().is_some_and(|c| c == '+' || c == '-')
+        {
+            self.chars.next();
+            self.skip_while(|c| c.is_ascii_digit());
+        }
+    }
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn is_operator_char(c: char) -> bool {
+    matches!(
+        c,
+        '+' | '-'
+            | '*'
+            | '/'
+            | '<'
+            | '>'
+            | '='
+            | '~'
+            | '!'
+            | '@'
+            | '#'
+            | '%'
+            | '^'
+            | '&'
+            | '|'
+            | '?'
+            | ':'
+    )
+}
+
+#[test]
+fn it_normalizes_literals_and_whitespace() {
+    assert_eq!(
+        normalize("SELECT  id, name\nFROM users\tWHERE name = 'O''Brien' AND age > 42.5;"),
+        "select id, name from users where name = $1 and age > $2"
+    );
+    assert_eq!(
+        normalize(
+            "select count(*) from t where x in (1, 2e-3, .5, 0x1F) and y = E'\\'' and z = X'00'"
+        ),
+        "select count(*) from t where x in($1, $2, $3, $4) and y = $5 and z = $6"
+    );
+    assert_eq!(
+        normalize("select $$it's$$, $tag$a$$b$tag$ -- comment\n /* outer /* inner */ */ from t"),
+        "select $1, $2 from t"
+    );
+    assert_eq!(
+        normalize(r#"SELECT "UserId", `Name` FROM "Users" u WHERE u.a::text <= 'x'"#),
+        r#"select "UserId", `Name` from "Users" u where u.a::text <= $1"#
+    );
+}
+
+#[test]
+fn it_normalizes_placeholder_styles() {
+    let expected = "select * from t where a = $1 and b = $2 and c = $1";
+
+    assert_eq!(
+        normalize("select * from t where a = $1 and b = $2 and c = $1"),
+        expected
+    );
+    assert_eq!(
+        normalize("select * from t where a = ?1 and b = ?2 and c = ?1"),
+        expected
+    );
+    assert_eq!(
+        normalize("select * from t where a = :a and b = :b and c = :a"),
+        expected
+    );
+    assert_eq!(
+        normalize("select * from t where a = @a and b = @b and c = $a"),
+        expected
+    );
+
+    assert_eq!(
+        normalize("select * from t where a = ? and b = ?"),
+        "select * from t where a = $1 and b = $2"
+    );
+    // Literals are numbered after the bind parameters, as Postgres does.
+    assert_eq!(
+        normalize("select * from t where a = $2 and b = 'x' and c = $1"),
+        "select * from t where a = $2 and b = $3 and c = $1"
+    );
+    // Even when they come first.
+    assert_eq!(normalize("select 'x', $1"), "select $2, $1");
+    assert_ne!(
+        QueryFingerprint::new("select 'x', $1"),
+        QueryFingerprint::new("select $1, $1")
+    );
+    assert_eq!(
+        normalize("select :a, 1, ?2 from t where b = ?1"),
+        "select $3, $4, $2 from t where b = $1"
+    );
+    // Postgres `jsonb` operators are not parameters.
+    assert_eq!(
+        normalize("select * from t where doc ?| array['a'] and doc ?& array['b']"),
+        "select * from t where doc ?| array[$1] and doc ?& array[$2]"
+    );
+}
+
+#[test]
+fn it_hashes_the_normalized_sql() {
+    let fingerprint = QueryFingerprint::new("SELECT 1");
+
+    assert_eq!(fingerprint, QueryFingerprint::new("select\n  2;"));
+    assert_ne!(fingerprint, QueryFingerprint::new("select 1, 2"));
+    assert_eq!(fingerprint.as_u64(), fnv1a(b"select $1"));
+    assert_eq!(
+        fingerprint.to_string(),
+        format!("{:016x}", fnv1a(b"select $1"))
+    );
+    assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+}
@@ -1,13 +1,15 @@
 use either::Either;
 use futures_core::stream::BoxStream;
 use futures_util::{StreamExt, TryFutureExt, TryStreamExt};
+use std::time::Duration;
 
 use crate::arguments::IntoArguments;
 use crate::database::{Database, HasStatementCache};
 use crate::encode::Encode;
-use crate::error::{BoxDynError, Error};
+use crate::error::{BoxDynError, Error, RowDecodeError};
 use crate::executor::{Execute, Executor};
 use crate::from_row::FromRow;
+use crate::query::extend_from_stream;
 use crate::query_as::{
     query_as, query_as_with_result, query_statement_as, query_statement_as_with, QueryAs,
 };
@@ -42,6 +44,11 @@ where
     fn persistent(&self) -> bool {
         Execute::persistent(&self.inner)
     }
+
+    #[inline]
+    fn timeout(&self) -> Option<Duration> {
+        Execute::timeout(&self.inner)
+    }
 }
 
 impl<'q, DB: Database, O> QueryScalar<'q, DB, O, <DB as Database>::Arguments<'q>> {
@@ -52,6 +59,14 @@ impl<'q, DB: Database, O> QueryScalar<'q, DB, O, <DB as Database>::Arguments<'q>
         self.inner = self.inner.bind(value);
         self
     }
+
+    /// Send a query attribute with this SQL query.
+    ///
+    /// See [`Query::attribute`](crate::query::Query::attribute).
+    pub fn attribute<T: 'q + Encode<'q, DB> + Type<DB>>(mut self, name: &str, value: T) -> Self {
+        self.inner = self.inner.attribute(name, value);
+        self
+    }
 }
 
 impl<'q, DB, O, A> QueryScalar<'q, DB, O, A>
@@ -61,19 +76,23 @@ where
     /// If `true`, the statement will get prepared once and cached to the
     /// connection's statement cache.
     ///
-    /// If queried once with the flag set to `true`, all subsequent queries
-    /// matching the one with the flag will use the cached statement until the
-    /// cache is cleared.
-    ///
-    /// If `false`, the prepared statement will be closed after execution.
-    ///
-    /// Default: `true`.
+    /// See [`Query::persistent`](crate::query::Query::persistent).
     pub fn persistent(mut self, value: bool) -> Self {
         self.inner = self.inner.persistent(value);
         self
     }
 }
 
+impl<'q, DB: Database, O, A> QueryScalar<'q, DB, O, A> {
+    /// Set the maximum time the database may spend executing this query before aborting it.
+    ///
+    /// See [`Query::timeout`](crate::query::Query::timeout).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.timeout(timeout);
+        self
+    }
+}
+
 // FIXME: This is very close, nearly 1:1 with `Map`
 // noinspection DuplicatedCode
 impl<'q, DB, O, A> QueryScalar<'q, DB, O, A>
@@ -96,6 +115,28 @@ where
         self.inner.fetch(executor).map_ok(|it| it.0).boxed()
     }
 
+    /// Execute the query and return the generated results as a stream,
+    /// continuing past rows which fail to decode.
+    ///
+    /// See [`QueryAs::fetch_lossy()`].
+    #[inline]
+    pub fn fetch_lossy<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+    ) -> BoxStream<'e, Result<Result<O, RowDecodeError<DB::Row>>, Error>>
+    where
+        'q: 'e,
+        E: 'e + Executor<'c, Database = DB>,
+        DB: 'e,
+        A: 'e,
+        O: 'e,
+    {
+        self.inner
+            .fetch_lossy(executor)
+            .map_ok(|res| res.map(|it| it.0))
+            .boxed()
+    }
+
     /// Execute multiple queries and return the generated results as a stream
     /// from each query, in a stream.
     #[inline]
@@ -141,6 +182,33 @@ where
             .await
     }
 
+    /// Execute the query and append all the resulting rows to `buf`, returning how many were added.
+    ///
+    /// Unlike [`fetch_all()`][Self::fetch_all], this reuses the existing capacity of `buf`,
+    /// which avoids reallocating on every execution when the same query is run repeatedly,
+    /// e.g. in a polling loop. If an error is returned, `buf` is left as it was.
+    ///
+    /// ### Note: beware result set size.
+    /// This will attempt to collect the full result set of the query into memory.
+    ///
+    /// To avoid exhausting available memory, ensure the result set has a known upper bound,
+    /// e.g. using `LIMIT`.
+    #[inline]
+    pub async fn fetch_into<'e, 'c: 'e, E>(
+        self,
+        executor: E,
+        buf: &mut Vec<O>,
+    ) -> Result<usize, Error>
+    where
+        'q: 'e,
+        E: 'e + Executor<'c, Database = DB>,
+        DB: 'e,
+        (O,): 'e,
+        A: 'e,
+    {
+        extend_from_stream(buf, self.inner.fetch(executor).map_ok(|it| it.0).boxed()).await
+    }
+
     /// Execute the query, returning the first row or [`Error::RowNotFound`] otherwise.
     ///
     /// ### Note: for best performance, ensure the query returns at most one row.
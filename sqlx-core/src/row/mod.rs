@@ -175,3 +175,12 @@ pub trait Row: Unpin + Send + Sync + 'static {
     where
         I: ColumnIndex<Self>;
 }
+
+#[cfg(feature = "json")]
+mod serialize;
+
+#[cfg(feature = "json")]
+pub use serialize::{
+    BytesFormat, ColumnValue, DecimalFormat, SerializeColumns, SerializeOptions, SerializeRow,
+    TimestampFormat,
+};
@@ -0,0 +1,323 @@
+//! Serialization of rows with `serde`, as a map of column names to values.
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use serde::ser::{Error as _, SerializeMap};
+use serde::{Serialize, Serializer};
+
+use crate::column::Column;
+use crate::common::civil::{civil_from_days, days_from_civil};
+use crate::error::BoxDynError;
+use crate::row::Row;
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_DAY: i64 = 86_400 * MICROS_PER_SECOND;
+
+/// How binary values are serialized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BytesFormat {
+    /// A string of lowercase hexadecimal digits, e.g. `"deadbeef"`.
+    #[default]
+    Hex,
+
+    /// An array of numbers, one per byte.
+    Array,
+}
+
+/// How exact decimal numbers (`NUMERIC` and `DECIMAL`) are serialized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecimalFormat {
+    /// A string of the number, e.g. `"12.50"`, which keeps all of its digits.
+    #[default]
+    String,
+
+    /// A floating-point number, which may lose precision.
+    ///
+    /// Values which are not finite numbers, e.g. `NaN`, are serialized as strings.
+    Number,
+}
+
+/// How timestamps are serialized.
+///
+/// Dates and times of day are always serialized as ISO 8601 strings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimestampFormat {
+    /// An ISO 8601 string, e.g. `"2024-05-01T12:30:00Z"`.
+    ///
+    /// Timestamps without a time zone have no `Z` suffix.
+    #[default]
+    Iso8601,
+
+    /// The number of whole seconds since `1970-01-01 00:00:00`.
+    UnixSeconds,
+
+    /// The number of milliseconds since `1970-01-01 00:00:00`.
+    UnixMillis,
+}
+
+/// Options for [`SerializeRow`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SerializeOptions {
+    bytes: BytesFormat,
+    decimals: DecimalFormat,
+    timestamps: TimestampFormat,
+}
+
+impl SerializeOptions {
+    /// Creates the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how binary values are serialized. Defaults to [`BytesFormat::Hex`].
+    pub fn bytes(mut self, format: BytesFormat) -> Self {
+        self.bytes = format;
+        self
+    }
+
+    /// Sets how decimal numbers are serialized. Defaults to [`DecimalFormat::String`].
+    pub fn decimals(mut self, format: DecimalFormat) -> Self {
+        self.decimals = format;
+        self
+    }
+
+    /// Sets how timestamps are serialized. Defaults to [`TimestampFormat::Iso8601`].
+    pub fn timestamps(mut self, format: TimestampFormat) -> Self {
+        self.timestamps = format;
+        self
+    }
+}
+
+/// The value of a column, as it is passed to a [`Serializer`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ColumnValue<'r> {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+
+    /// An exact decimal number, e.g. `-12.50`.
+    Decimal(Cow<'r, str>),
+    Text(Cow<'r, str>),
+    Bytes(Cow<'r, [u8]>),
+    Json(serde_json::Value),
+
+    /// The number of days since `1970-01-01`.
+    Date(i32),
+
+    /// The number of microseconds since midnight.
+    Time(i64),
+
+    /// The number of microseconds since `1970-01-01 00:00:00`, in UTC if `utc` is `true`.
+    Timestamp {
+        micros: i64,
+        utc: bool,
+    },
+}
+
+impl ColumnValue<'_> {
+    /// A date from the year, month (1-12) and day of the month.
+    ///
+    /// Returns `None` if the date is out of range.
+    pub fn date(year: i32, month: u32, day: u32) -> Option<Self> {
+        i32::try_from(days_from_civil(year, month, day))
+            .ok()
+            .map(ColumnValue::Date)
+    }
+
+    /// A timestamp from a date and the microseconds since midnight of that date.
+    ///
+    /// Returns `None` if the timestamp is out of range.
+    pub fn timestamp(year: i32, month: u32, day: u32, time_micros: i64, utc: bool) -> Option<Self> {
+        let micros = days_from_civil(year, month, day)
+            .checked_mul(MICROS_PER_DAY)?
+            .checked_add(time_micros)?;
+
+        Some(ColumnValue::Timestamp { micros, utc })
+    }
+
+    /// A UUID from its bytes, as hyphenated text.
+    pub fn uuid(bytes: [u8; 16]) -> Self {
+        let hex = hex::encode(bytes);
+
+        ColumnValue::Text(Cow::Owned(format!(
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        )))
+    }
+}
+
+/// Implemented by rows which can be serialized as a map of column names to values.
+///
+/// This is implemented by the rows of all drivers when the `json` feature is enabled,
+/// which then also implement [`Serialize`] with the default [`SerializeOptions`].
+pub trait SerializeColumns: Row {
+    /// Get the value of the column at `index` to serialize it.
+    fn column_value(&self, index: usize) -> Result<ColumnValue<'_>, BoxDynError>;
+
+    /// Serialize this row with the given options instead of the defaults.
+    fn serialize_with(&self, options: SerializeOptions) -> SerializeRow<'_, Self>
+    where
+        Self: Sized,
+    {
+        SerializeRow::new(self).options(options)
+    }
+}
+
+/// Serializes a row as a map of column names to values, e.g. as a JSON object.
+///
+/// Columns are serialized in the order of the query. If several columns have the same name,
+/// formats like JSON keep only the last one, so they should be given unique names in the query.
+///
+/// ```rust
+/// use sqlx::row::{SerializeColumns, SerializeOptions, TimestampFormat};
+///
+/// fn to_json(row: &impl SerializeColumns) -> serde_json::Result<String> {
+///     let options = SerializeOptions::new().timestamps(TimestampFormat::UnixMillis);
+///
+///     serde_json::to_string(&row.serialize_with(options))
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SerializeRow<'r, R> {
+    row: &'r R,
+    options: SerializeOptions,
+}
+
+impl<'r, R: SerializeColumns> SerializeRow<'r, R> {
+    /// Serializes `row` with the default [`SerializeOptions`].
+    pub fn new(row: &'r R) -> Self {
+        SerializeRow {
+            row,
+            options: SerializeOptions::default(),
+        }
+    }
+
+    /// Sets the options to serialize the row with.
+    pub fn options(mut self, options: SerializeOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl<R: SerializeColumns> Serialize for SerializeRow<'_, R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let columns = self.row.columns();
+        let mut map = serializer.serialize_map(Some(columns.len()))?;
+
+        for (index, column) in columns.iter().enumerate() {
+            let value = self.row.column_value(index).map_err(|e| {
+                S::Error::custom(format!("error serializing column {:?}: {e}", column.name()))
+            })?;
+
+            map.serialize_entry(
+                column.name(),
+                &SerializeValue {
+                    value: &value,
+                    options: &self.options,
+                },
+            )?;
+        }
+
+        map.end()
+    }
+}
+
+struct SerializeValue<'a, 'r> {
+    value: &'a ColumnValue<'r>,
+    options: &'a SerializeOptions,
+}
+
+impl Serialize for SerializeValue<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.value {
+            ColumnValue::Null => serializer.serialize_none(),
+            ColumnValue::Bool(value) => serializer.serialize_bool(*value),
+            ColumnValue::Int(value) => serializer.serialize_i64(*value),
+            ColumnValue::UInt(value) => serializer.serialize_u64(*value),
+            ColumnValue::Float(value) => serializer.serialize_f64(*value),
+
+            ColumnValue::Decimal(value) => match self.options.decimals {
+                DecimalFormat::Number => match value.parse::<f64>() {
+                    Ok(number) if number.is_finite() => serializer.serialize_f64(number),
+                    _ => serializer.serialize_str(value),
+                },
+                DecimalFormat::String => serializer.serialize_str(value),
+            },
+
+            ColumnValue::Text(value) => serializer.serialize_str(value),
+
+            ColumnValue::Bytes(value) => match self.options.bytes {
+                BytesFormat::Array => serializer.collect_seq(value.iter()),
+                BytesFormat::Hex => serializer.serialize_str(&hex::encode(value)),
+            },
+
+            ColumnValue::Json(value) => value.serialize(serializer),
+            ColumnValue::Date(days) => serializer.serialize_str(&format_date((*days).into())),
+            ColumnValue::Time(micros) => serializer.serialize_str(&format_time(*micros)),
+
+            ColumnValue::Timestamp { micros, utc } => match self.options.timestamps {
+                TimestampFormat::UnixSeconds => {
+                    serializer.serialize_i64(micros.div_euclid(MICROS_PER_SECOND))
+                }
+                TimestampFormat::UnixMillis => serializer.serialize_i64(micros.div_euclid(1_000)),
+                TimestampFormat::Iso8601 => {
+                    let days = micros.div_euclid(MICROS_PER_DAY);
+                    let mut s = format!(
+                        "{}T{}",
+                        format_date(days),
+                        format_time(micros.rem_euclid(MICROS_PER_DAY))
+                    );
+
+                    if *utc {
+                        s.push('Z');
+                    }
+
+                    serializer.serialize_str(&s)
+                }
+            },
+        }
+    }
+}
+
+fn format_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+fn format_time(micros: i64) -> String {
+    let seconds = micros / MICROS_PER_SECOND;
+    let mut s = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+
+    if micros % MICROS_PER_SECOND != 0 {
+        write!(s, ".{:06}", micros % MICROS_PER_SECOND).unwrap();
+    }
+
+    s
+}
+
+#[test]
+fn it_formats_dates_and_times() {
+    assert_eq!(format_date(-1), "1969-12-31");
+    assert_eq!(format_date(i64::from(i32::MAX) + 1), "5881580-07-12");
+    assert_eq!(ColumnValue::date(i32::MAX, 1, 1), None);
+    assert_eq!(ColumnValue::timestamp(5_000_000, 1, 1, 0, false), None);
+    assert_eq!(format_time(45_296_000_000), "12:34:56");
+    assert_eq!(format_time(45_296_000_500), "12:34:56.000500");
+}
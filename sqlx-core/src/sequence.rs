@@ -0,0 +1,94 @@
+//! Allocating IDs from database sequences.
+//!
+//! [`Sequence`] hands out unique integers by name, for applications which need to know the ID of
+//! a row before inserting it, e.g. to reference it from other rows written in the same batch.
+//!
+//! | Database | Implementation                                                                    |
+//! |----------|-----------------------------------------------------------------------------------|
+//! | Postgres | `nextval()`; the sequence must have been created with `CREATE SEQUENCE`.          |
+//! | MariaDB  | `NEXTVAL()`, if a sequence of that name was created with `CREATE SEQUENCE`.       |
+//! | MySQL    | Emulated with a counter in the `_sqlx_sequences` table, created on first use.[^1] |
+//! | SQLite   | Emulated with a counter in the `_sqlx_sequences` table, created on first use.     |
+//!
+//! [^1]: Creating a table implicitly commits the current transaction in MySQL, so the first use
+//! must not be in a transaction; it returns an error instead.
+//!
+//! Emulated sequences start at 1 and increase by 1. Unlike real sequences, their counter is
+//! updated within the current transaction: the row is locked until the transaction ends, and
+//! if it is rolled back, the values allocated in it will be allocated again.
+//!
+//! In all cases, values are unique but not necessarily contiguous, even within a single call to
+//! [`Sequence::next_n()`], as other connections may allocate values from the same sequence
+//! concurrently.
+use futures_core::future::BoxFuture;
+
+use crate::connection::Connection;
+use crate::database::Database;
+use crate::error::Error;
+
+/// A database which supports allocating values from a [`Sequence`].
+pub trait SequenceSupport: Database {
+    /// Allocate `n` values from the sequence `name`, which is not empty.
+    fn next_sequence_values<'c>(
+        conn: &'c mut Self::Connection,
+        name: &'c str,
+        n: u32,
+    ) -> BoxFuture<'c, Result<Vec<i64>, Error>>;
+}
+
+/// Allocate unique IDs from a named sequence. See the [module docs][self] for how sequences are
+/// implemented by each database.
+///
+/// ```rust,no_run
+/// # async fn example<C>(conn: &mut C) -> sqlx_core::Result<()>
+/// # where
+/// #     C: sqlx_core::connection::Connection,
+/// #     C::Database: sqlx_core::sequence::SequenceSupport<Connection = C>,
+/// # {
+/// use sqlx_core::sequence::Sequence;
+///
+/// let order_id = Sequence::next(conn, "order_ids").await?;
+/// let line_ids = Sequence::next_n(conn, "order_line_ids", 3).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub enum Sequence {}
+
+impl Sequence {
+    /// Allocate the next value from the sequence `name`.
+    pub async fn next<C>(conn: &mut C, name: &str) -> Result<i64, Error>
+    where
+        C: Connection,
+        C::Database: SequenceSupport<Connection = C>,
+    {
+        Self::next_n(conn, name, 1)
+            .await?
+            .pop()
+            .ok_or_else(|| err_protocol!("no value was allocated from sequence {name:?}"))
+    }
+
+    /// Allocate `n` values from the sequence `name`.
+    ///
+    /// The values are returned in ascending order.
+    pub async fn next_n<C>(conn: &mut C, name: &str, n: u32) -> Result<Vec<i64>, Error>
+    where
+        C: Connection,
+        C::Database: SequenceSupport<Connection = C>,
+    {
+        if name.is_empty() {
+            return Err(Error::Configuration(
+                "sequence name must not be empty".into(),
+            ));
+        }
+
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut values = C::Database::next_sequence_values(conn, name, n).await?;
+        values.sort_unstable();
+
+        Ok(values)
+    }
+}
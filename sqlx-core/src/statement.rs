@@ -28,8 +28,15 @@ pub trait Statement<'q>: Send + Sync {
 
     /// Get the expected parameters for this statement.
     ///
-    /// The information returned depends on what is available from the driver. SQLite can
-    /// only tell us the number of parameters. PostgreSQL can give us full type information.
+    /// The information returned depends on what is available from the driver:
+    ///
+    /// * PostgreSQL gives us full type information.
+    /// * MySQL gives us the types it could infer from the query, and a generic string type
+    ///   for any other parameter.
+    /// * SQLite can only tell us the number of parameters.
+    ///
+    /// For [`AnyStatement`][crate::any::AnyStatement], this is the number of parameters
+    /// instead if any of their types is not supported by the `Any` driver.
     fn parameters(&self) -> Option<Either<&[<Self::Database as Database>::TypeInfo], usize>>;
 
     /// Get the columns expected to be returned by executing this statement.
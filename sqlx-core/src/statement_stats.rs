@@ -0,0 +1,265 @@
+//! Execution statistics of statements, for an in-process view of the "top queries".
+//!
+//! Create a [`StatementStats`] and pass it to
+//! [`PoolOptions::statement_stats()`][crate::pool::PoolOptions::statement_stats], or to
+//! [`ConnectOptions::statement_stats()`][crate::connection::ConnectOptions::statement_stats]
+//! for single connections. Every statement executed on the connections is then counted, along
+//! with the time it took, grouped by its SQL or by its [`QueryFingerprint`].
+//!
+//! ```rust,no_run
+//! # async fn example(pool: &sqlx::SqlitePool) {
+//! for stat in pool.statement_stats().iter().take(10) {
+//!     println!(
+//!         "{:>8} executions, {:>10.3?} total, {:>10.3?} mean: {}",
+//!         stat.executions(),
+//!         stat.total_time(),
+//!         stat.mean_time(),
+//!         stat.sql()
+//!     );
+//! }
+//! # }
+//! ```
+use std::cmp;
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::query_fingerprint::QueryFingerprint;
+
+/// Records statistics of executed statements, shared by all connections it is set on.
+///
+/// Clones record to, and read from, the same statistics.
+#[derive(Clone)]
+pub struct StatementStats {
+    by_fingerprint: bool,
+    max_statements: usize,
+    recorded: Arc<Mutex<Recorded>>,
+}
+
+#[derive(Default)]
+struct Recorded {
+    statements: HashMap<String, StatementStat>,
+    dropped_executions: u64,
+}
+
+impl StatementStats {
+    /// Group statements by their SQL text, for up to 1000 distinct statements.
+    pub fn new() -> Self {
+        Self {
+            by_fingerprint: false,
+            max_statements: 1000,
+            recorded: Arc::default(),
+        }
+    }
+
+    /// If true, group statements by their [`QueryFingerprint`] instead of their SQL text, so
+    /// statements which only differ in their literals, comments or whitespace are counted together.
+    ///
+    /// This costs a pass over the SQL of every executed statement. Defaults to `false`.
+    pub fn by_fingerprint(mut self, enabled: bool) -> Self {
+        self.by_fingerprint = enabled;
+        self
+    }
+
+    /// Set the number of distinct statements to keep statistics for.
+    ///
+    /// Once reached, executions of statements which weren't seen before are only counted by
+    /// [`dropped_executions()`][Self::dropped_executions]. This bounds the memory used when the
+    /// application builds SQL with literals instead of binding arguments.
+    pub fn max_statements(mut self, max: usize) -> Self {
+        self.max_statements = max;
+        self
+    }
+
+    /// The statistics of each statement, ordered by their total execution time, longest first.
+    pub fn snapshot(&self) -> Vec<StatementStat> {
+        let mut stats: Vec<StatementStat> = self.lock().statements.values().cloned().collect();
+
+        stats.sort_by(|a, b| {
+            b.total_time
+                .cmp(&a.total_time)
+                .then_with(|| a.sql.cmp(&b.sql))
+        });
+
+        stats
+    }
+
+    /// The number of executions which weren't recorded because
+    /// [`max_statements`][Self::max_statements] was reached.
+    pub fn dropped_executions(&self) -> u64 {
+        self.lock().dropped_executions
+    }
+
+    /// Discard everything recorded so far.
+    pub fn reset(&self) {
+        *self.lock() = Recorded::default();
+    }
+
+    pub(crate) fn record(
+        &self,
+        sql: &str,
+        elapsed: Duration,
+        rows_returned: u64,
+        rows_affected: u64,
+        is_success: bool,
+    ) {
+        let fingerprint = self.by_fingerprint.then(|| QueryFingerprint::new(sql));
+        let key = fingerprint
+            .as_ref()
+            .map_or(sql, QueryFingerprint::normalized);
+
+        let mut recorded = self.lock();
+
+        if !recorded.statements.contains_key(key) {
+            if recorded.statements.len() >= self.max_statements {
+                recorded.dropped_executions += 1;
+                return;
+            }
+
+            recorded.statements.insert(
+                key.to_owned(),
+                StatementStat {
+                    sql: key.to_owned(),
+                    executions: 0,
+                    errors: 0,
+                    total_time: Duration::ZERO,
+                    max_time: Duration::ZERO,
+                    rows_returned: 0,
+                    rows_affected: 0,
+                },
+            );
+        }
+
+        let stat = recorded
+            .statements
+            .get_mut(key)
+            .expect("BUG: statement was just inserted");
+
+        stat.executions += 1;
+        stat.errors += u64::from(!is_success);
+        stat.total_time += elapsed;
+        stat.max_time = cmp::max(stat.max_time, elapsed);
+        stat.rows_returned += rows_returned;
+        stat.rows_affected += rows_affected;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Recorded> {
+        self.recorded
+            .lock()
+            .expect("BUG: panicked while holding a lock")
+    }
+}
+
+impl Default for StatementStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for StatementStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StatementStats")
+            .field("by_fingerprint", &self.by_fingerprint)
+            .field("max_statements", &self.max_statements)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The statistics of one statement, from [`StatementStats::snapshot()`].
+#[derive(Debug, Clone)]
+pub struct StatementStat {
+    sql: String,
+    executions: u64,
+    errors: u64,
+    total_time: Duration,
+    max_time: Duration,
+    rows_returned: u64,
+    rows_affected: u64,
+}
+
+impl StatementStat {
+    /// The SQL of the statement, or its normalized form when grouped by fingerprint.
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// The number of times the statement was executed.
+    pub fn executions(&self) -> u64 {
+        self.executions
+    }
+
+    /// The number of executions which returned an error.
+    pub fn errors(&self) -> u64 {
+        self.errors
+    }
+
+    /// The time taken by all executions together.
+    pub fn total_time(&self) -> Duration {
+        self.total_time
+    }
+
+    /// The mean time taken by an execution.
+    pub fn mean_time(&self) -> Duration {
+        // `executions` is at least 1 for every recorded statement
+        self.total_time
+            .checked_div(u32::try_from(self.executions).unwrap_or(u32::MAX))
+            .unwrap_or_default()
+    }
+
+    /// The longest time taken by an execution.
+    pub fn max_time(&self) -> Duration {
+        self.max_time
+    }
+
+    /// The number of rows returned by all executions together.
+    pub fn rows_returned(&self) -> u64 {
+        self.rows_returned
+    }
+
+    /// The number of rows affected by all executions together.
+    pub fn rows_affected(&self) -> u64 {
+        self.rows_affected
+    }
+}
+
+#[test]
+fn it_records_statement_stats() {
+    let stats = StatementStats::new().max_statements(2);
+
+    stats.record("SELECT 1", Duration::from_millis(10), 1, 0, true);
+    stats.record("SELECT 1", Duration::from_millis(30), 1, 0, false);
+    stats.record("UPDATE t SET x = 1", Duration::from_millis(5), 0, 3, true);
+    stats.record("DELETE FROM t", Duration::from_millis(5), 0, 3, true);
+
+    let snapshot = stats.snapshot();
+    assert_eq!(snapshot.len(), 2);
+    assert_eq!(stats.dropped_executions(), 1);
+
+    assert_eq!(snapshot[0].sql(), "SELECT 1");
+    assert_eq!(snapshot[0].executions(), 2);
+    assert_eq!(snapshot[0].errors(), 1);
+    assert_eq!(snapshot[0].total_time(), Duration::from_millis(40));
+    assert_eq!(snapshot[0].mean_time(), Duration::from_millis(20));
+    assert_eq!(snapshot[0].max_time(), Duration::from_millis(30));
+    assert_eq!(snapshot[0].rows_returned(), 2);
+
+    assert_eq!(snapshot[1].sql(), "UPDATE t SET x = 1");
+    assert_eq!(snapshot[1].rows_affected(), 3);
+
+    stats.reset();
+    assert!(stats.snapshot().is_empty());
+
+    let stats = StatementStats::new().by_fingerprint(true);
+
+    stats.record("SELECT * FROM t WHERE id = 1", Duration::ZERO, 1, 0, true);
+    stats.record("select *  from t where id = 2", Duration::ZERO, 1, 0, true);
+
+    let snapshot = stats.snapshot();
+    assert_eq!(snapshot.len(), 1);
+    assert_eq!(snapshot[0].executions(), 2);
+    assert_eq!(
+        snapshot[0].sql(),
+        QueryFingerprint::new("SELECT * FROM t WHERE id = 1").normalized()
+    );
+}
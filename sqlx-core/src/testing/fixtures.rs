@@ -59,6 +59,47 @@ macro_rules! fixture_assert (
     }
 );
 
+impl<DB> FixtureSnapshot<DB> {
+    // Used by the drivers to build a snapshot; table and column names are written to the
+    // fixture verbatim, so they must already be quoted if needed. Values are SQL literals.
+    #[doc(hidden)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        FixtureSnapshot {
+            tables: BTreeMap::new(),
+            db: PhantomData,
+        }
+    }
+
+    /// `foreign_keys` lists `(column, referenced table, referenced column)`, which are used to
+    /// order the `INSERT`s.
+    #[doc(hidden)]
+    pub fn add_table(
+        &mut self,
+        name: &str,
+        columns: Vec<String>,
+        rows: Vec<Vec<String>>,
+        foreign_keys: Vec<(String, String, String)>,
+    ) {
+        let name = TableName::from(name);
+
+        self.tables.insert(
+            name.clone(),
+            Table {
+                name,
+                columns: columns.into_iter().map(ColumnName::from).collect(),
+                rows,
+                foreign_keys: foreign_keys
+                    .into_iter()
+                    .map(|(column, ref_table, ref_column)| {
+                        (column.into(), (ref_table.into(), ref_column.into()))
+                    })
+                    .collect(),
+            },
+        );
+    }
+}
+
 impl<DB: Database> FixtureSnapshot<DB> {
     /// Generate a fixture to reproduce this snapshot from an empty database using `INSERT`s.
     ///
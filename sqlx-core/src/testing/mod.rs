@@ -1,7 +1,12 @@
+use std::any::Any;
+use std::fmt::{Debug, Write};
 use std::future::Future;
+use std::sync::Arc;
 use std::time::Duration;
 
 use futures_core::future::BoxFuture;
+use futures_intrusive::sync::Mutex;
+use once_cell::sync::Lazy;
 
 pub use fixtures::FixtureSnapshot;
 
@@ -11,9 +16,19 @@ use crate::error::Error;
 use crate::executor::Executor;
 use crate::migrate::{Migrate, Migrator};
 use crate::pool::{Pool, PoolConnection, PoolOptions};
+use crate::transaction::TransactionManager;
+use crate::HashMap;
 
 mod fixtures;
 
+pub mod plan;
+
+// The databases of `#[sqlx::test(rollback)]` tests, by the migrations, fixtures and seeder applied
+// to them.
+// Each is set up by the first test that needs it and kept until the next run of the test binary.
+static SHARED_DATABASES: Lazy<Mutex<HashMap<String, Box<dyn Any + Send>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new(), true));
+
 pub trait TestSupport: Database {
     /// Get parameters to construct a `Pool` suitable for testing.
     ///
@@ -52,6 +67,88 @@ pub struct TestArgs {
     pub test_path: &'static str,
     pub migrator: Option<&'static Migrator>,
     pub fixtures: &'static [TestFixture],
+    pub seeder: Option<TestSeeder>,
+    pub rollback: bool,
+}
+
+/// An `async fn` which seeds the database of a test, given with `#[sqlx::test(seeder = ...)]`.
+///
+/// Implemented for functions like `async fn seed(conn: &mut PgConnection) -> Result<(), E>`.
+pub trait TestSeedFn<'c, C: 'c>: Send + Sync + 'static {
+    type Error: Debug;
+    type Future: Future<Output = Result<(), Self::Error>> + Send + 'c;
+
+    fn seed(&self, conn: &'c mut C) -> Self::Future;
+}
+
+impl<'c, C, F, Fut, E> TestSeedFn<'c, C> for F
+where
+    C: 'c,
+    F: Fn(&'c mut C) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), E>> + Send + 'c,
+    E: Debug,
+{
+    type Error = E;
+    type Future = Fut;
+
+    fn seed(&self, conn: &'c mut C) -> Self::Future {
+        self(conn)
+    }
+}
+
+type ErasedSeedFn<C> = Arc<dyn for<'c> Fn(&'c mut C) -> BoxFuture<'c, ()> + Send + Sync>;
+
+/// The seeder of a test, for any type of connection.
+pub struct TestSeeder {
+    name: &'static str,
+    seed: Box<dyn Any + Send + Sync>,
+}
+
+impl TestSeeder {
+    pub fn new<C, F>(seeder: F) -> Self
+    where
+        C: Connection + 'static,
+        F: for<'c> TestSeedFn<'c, C>,
+    {
+        fn erase<C, F>(seed: F) -> ErasedSeedFn<C>
+        where
+            F: for<'c> Fn(&'c mut C) -> BoxFuture<'c, ()> + Send + Sync + 'static,
+        {
+            Arc::new(seed)
+        }
+
+        let name = std::any::type_name::<F>();
+
+        let seed = erase(move |conn: &mut C| {
+            let seeding = seeder.seed(conn);
+
+            Box::pin(async move {
+                if let Err(e) = seeding.await {
+                    panic!("failed to run test seeder {name}: {e:?}");
+                }
+            })
+        });
+
+        TestSeeder {
+            name,
+            seed: Box::new(seed),
+        }
+    }
+
+    async fn seed<C: Connection + 'static>(&self, conn: &mut C) {
+        let seed = self
+            .seed
+            .downcast_ref::<ErasedSeedFn<C>>()
+            .unwrap_or_else(|| {
+                panic!(
+                    "test seeder {} does not take a connection to {}",
+                    self.name,
+                    <C::Database as Database>::NAME
+                )
+            });
+
+        seed(conn).await;
+    }
 }
 
 pub trait TestFn {
@@ -81,6 +178,7 @@ where
     type Output = Fut::Output;
 
     fn run_test(self, args: TestArgs) -> Self::Output {
+        assert_no_rollback(&args);
         run_test_with_pool(args, self)
     }
 }
@@ -96,6 +194,10 @@ where
     type Output = Fut::Output;
 
     fn run_test(self, args: TestArgs) -> Self::Output {
+        if args.rollback {
+            return run_test_in_transaction(args, self);
+        }
+
         run_test_with_pool(args, |pool| async move {
             let conn = pool
                 .acquire()
@@ -119,6 +221,7 @@ where
     type Output = Fut::Output;
 
     fn run_test(self, args: TestArgs) -> Self::Output {
+        assert_no_rollback(&args);
         run_test(args, self)
     }
 }
@@ -134,6 +237,11 @@ where
             args.fixtures.is_empty(),
             "fixtures cannot be applied for a bare function"
         );
+        assert!(
+            args.seeder.is_none(),
+            "a seeder cannot be run for a bare function"
+        );
+        assert_no_rollback(&args);
         crate::rt::test_block_on(self())
     }
 }
@@ -144,6 +252,8 @@ impl TestArgs {
             test_path,
             migrator: None,
             fixtures: &[],
+            seeder: None,
+            rollback: false,
         }
     }
 
@@ -154,6 +264,18 @@ impl TestArgs {
     pub fn fixtures(&mut self, fixtures: &'static [TestFixture]) {
         self.fixtures = fixtures;
     }
+
+    pub fn seeder<C, F>(&mut self, seeder: F)
+    where
+        C: Connection + 'static,
+        F: for<'c> TestSeedFn<'c, C>,
+    {
+        self.seeder = Some(TestSeeder::new(seeder));
+    }
+
+    pub fn rollback(&mut self) {
+        self.rollback = true;
+    }
 }
 
 impl TestTermination for () {
@@ -229,6 +351,117 @@ where
     })
 }
 
+fn assert_no_rollback(args: &TestArgs) {
+    assert!(
+        !args.rollback,
+        "`#[sqlx::test(rollback)]` requires the test to take a `PoolConnection`"
+    );
+}
+
+fn run_test_in_transaction<DB, F, Fut>(args: TestArgs, test_fn: F) -> Fut::Output
+where
+    DB: TestSupport,
+    DB::Connection: Migrate,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    F: FnOnce(PoolConnection<DB>) -> Fut,
+    Fut: Future,
+    Fut::Output: TestTermination,
+{
+    crate::rt::test_block_on(async move {
+        let (pool_opts, connect_opts) = shared_test_db::<DB>(&args).await;
+
+        let pool = pool_opts
+            // The transaction of the test, and any savepoint it left open, is rolled back
+            // by closing the connection.
+            .after_release(|conn, _| {
+                Box::pin(async move {
+                    DB::TransactionManager::rollback(conn).await?;
+                    Ok(false)
+                })
+            })
+            .connect_with(connect_opts)
+            .await
+            .expect("failed to connect test pool");
+
+        let mut conn = pool
+            .acquire()
+            .await
+            .expect("failed to acquire test pool connection");
+
+        DB::TransactionManager::begin(&mut conn)
+            .await
+            .expect("failed to begin test transaction");
+
+        let res = test_fn(conn).await;
+
+        let close_timed_out = crate::rt::timeout(Duration::from_secs(10), pool.close())
+            .await
+            .is_err();
+
+        if close_timed_out {
+            eprintln!(
+                "test {} held onto its connection after exiting",
+                args.test_path
+            );
+        }
+
+        res
+    })
+}
+
+// Get the database shared by the `rollback` tests with the same migrations and fixtures as `args`,
+// creating and setting it up if this is the first of them.
+async fn shared_test_db<DB>(
+    args: &TestArgs,
+) -> (PoolOptions<DB>, <DB::Connection as Connection>::Options)
+where
+    DB: TestSupport,
+    DB::Connection: Migrate,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+{
+    let mut key = format!("{}:", DB::NAME);
+
+    for migration in args.migrator.iter().flat_map(|migrator| migrator.iter()) {
+        write!(key, "{}:", migration.version).ok();
+
+        for byte in migration.checksum.iter() {
+            write!(key, "{byte:02x}").ok();
+        }
+
+        key.push(';');
+    }
+
+    for fixture in args.fixtures {
+        write!(key, "{};", fixture.path).ok();
+    }
+
+    if let Some(seeder) = &args.seeder {
+        write!(key, "{};", seeder.name).ok();
+    }
+
+    // Held while the database is set up, so it is only set up once.
+    let mut shared_databases = SHARED_DATABASES.lock().await;
+
+    if let Some(shared) = shared_databases.get(&key) {
+        return shared
+            .downcast_ref::<(PoolOptions<DB>, <DB::Connection as Connection>::Options)>()
+            .expect("BUG: shared test database of the wrong type")
+            .clone();
+    }
+
+    let test_context = DB::test_context(args)
+        .await
+        .expect("failed to connect to setup test database");
+
+    setup_test_db::<DB>(&test_context.connect_opts, args).await;
+
+    let shared = (test_context.pool_opts, test_context.connect_opts);
+
+    shared_databases.insert(key, Box::new(shared.clone()));
+
+    shared
+}
+
 async fn setup_test_db<DB: Database>(
     copts: &<DB::Connection as Connection>::Options,
     args: &TestArgs,
@@ -255,6 +488,10 @@ async fn setup_test_db<DB: Database>(
             .unwrap_or_else(|e| panic!("failed to apply test fixture {:?}: {:?}", fixture.path, e));
     }
 
+    if let Some(seeder) = &args.seeder {
+        seeder.seed(&mut conn).await;
+    }
+
     conn.close()
         .await
         .expect("failed to close setup connection");
@@ -0,0 +1,133 @@
+//! Assertions on query plans, to lock in index usage as part of a test suite.
+//!
+//! See [`assert_plan!()`][crate::assert_plan].
+
+use std::fmt::{self, Display, Formatter};
+
+use futures_core::future::BoxFuture;
+
+use crate::error::Error;
+
+/// Fetch the plan the database uses to execute a query.
+///
+/// Implemented by the connection types of the built-in drivers:
+///
+/// * Postgres runs `EXPLAIN (ANALYZE, COSTS OFF, TIMING OFF, SUMMARY OFF)`.
+///   Note that this **executes** the query; wrap data-modifying statements in a transaction.
+/// * MySQL runs `EXPLAIN` and renders each row as a line of `column=value` pairs.
+/// * SQLite runs `EXPLAIN QUERY PLAN` and renders the plan as an indented tree.
+///
+/// The plan is normalized to remove details which vary from run to run,
+/// such as costs, timings and row estimates.
+///
+/// The query is sent as-is and so can not have bind parameters.
+pub trait ExplainPlan {
+    fn explain_plan<'e>(&'e mut self, query: &'e str) -> BoxFuture<'e, Result<QueryPlan, Error>>;
+}
+
+/// A normalized query plan as returned by [`ExplainPlan::explain_plan()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryPlan {
+    lines: Vec<String>,
+}
+
+impl QueryPlan {
+    pub fn new(lines: Vec<String>) -> Self {
+        Self { lines }
+    }
+
+    /// The lines of the plan, in the order the database returned them.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Returns `true` if any line of the plan contains `needle`.
+    pub fn contains(&self, needle: &str) -> bool {
+        self.lines.iter().any(|line| line.contains(needle))
+    }
+}
+
+impl Display for QueryPlan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{line}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A condition a [`QueryPlan`] is expected to satisfy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanPredicate {
+    /// Some line of the plan contains the given string.
+    Contains(String),
+    /// No line of the plan contains the given string.
+    NotContains(String),
+}
+
+impl PlanPredicate {
+    pub fn matches(&self, plan: &QueryPlan) -> bool {
+        match self {
+            PlanPredicate::Contains(needle) => plan.contains(needle),
+            PlanPredicate::NotContains(needle) => !plan.contains(needle),
+        }
+    }
+}
+
+impl Display for PlanPredicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanPredicate::Contains(needle) => write!(f, "contains({needle:?})"),
+            PlanPredicate::NotContains(needle) => write!(f, "not_contains({needle:?})"),
+        }
+    }
+}
+
+/// Expect some line of the plan to contain `needle`.
+pub fn contains(needle: impl Into<String>) -> PlanPredicate {
+    PlanPredicate::Contains(needle.into())
+}
+
+/// Expect no line of the plan to contain `needle`.
+pub fn not_contains(needle: impl Into<String>) -> PlanPredicate {
+    PlanPredicate::NotContains(needle.into())
+}
+
+/// Assert that the plan for a query satisfies a predicate, panicking with the plan if it does not.
+///
+/// The connection may be anything which dereferences to a connection implementing
+/// [`ExplainPlan`], such as a `PgConnection`, a `PoolConnection` or a `Transaction`.
+/// The predicates [`contains()`] and [`not_contains()`] are in scope for the last argument.
+///
+/// This must be used in an `async` context.
+///
+/// ```rust,no_run
+/// # async fn example(mut conn: sqlx::PgConnection) {
+/// sqlx::assert_plan!(
+///     conn,
+///     "SELECT * FROM users WHERE email = 'alice@example.com'",
+///     contains("Index Scan using users_email_key")
+/// );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_plan {
+    ($conn:expr, $query:expr, $predicate:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::testing::plan::{contains, not_contains, ExplainPlan as _};
+
+        let query: &str = &$query;
+        let predicate: $crate::testing::plan::PlanPredicate = $predicate;
+
+        let plan = ($conn)
+            .explain_plan(query)
+            .await
+            .unwrap_or_else(|e| panic!("failed to fetch the plan for {query:?}: {e}"));
+
+        assert!(
+            predicate.matches(&plan),
+            "expected the plan for {query:?} to satisfy `{predicate}`, got:\n{plan}"
+        );
+    }};
+}
@@ -6,6 +6,7 @@ use futures_core::future::BoxFuture;
 
 use crate::database::Database;
 use crate::error::Error;
+use crate::executor::Executor;
 use crate::pool::MaybePoolConnection;
 
 /// Generic management of database transactions.
@@ -20,6 +21,26 @@ pub trait TransactionManager {
         conn: &mut <Self::Database as Database>::Connection,
     ) -> BoxFuture<'_, Result<(), Error>>;
 
+    /// Begin a new transaction with the given options.
+    ///
+    /// Fails if a transaction is already active, as these can not be set for a savepoint.
+    fn begin_with(
+        conn: &mut <Self::Database as Database>::Connection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        let _ = (conn, options);
+
+        Box::pin(async move {
+            Err(Error::Configuration(
+                format!(
+                    "{} does not support setting the options of a transaction",
+                    <Self::Database as Database>::NAME
+                )
+                .into(),
+            ))
+        })
+    }
+
     /// Commit the active transaction or release the most recent savepoint.
     fn commit(
         conn: &mut <Self::Database as Database>::Connection,
@@ -56,6 +77,8 @@ where
 {
     connection: MaybePoolConnection<'c, DB>,
     open: bool,
+    isolation_level: Option<IsolationLevel>,
+    access_mode: Option<AccessMode>,
 }
 
 impl<'c, DB> Transaction<'c, DB>
@@ -74,10 +97,43 @@ where
             Ok(Self {
                 connection: conn,
                 open: true,
+                isolation_level: None,
+                access_mode: None,
+            })
+        })
+    }
+
+    #[doc(hidden)]
+    pub fn begin_with(
+        conn: impl Into<MaybePoolConnection<'c, DB>>,
+        options: TransactionOptions,
+    ) -> BoxFuture<'c, Result<Self, Error>> {
+        let mut conn = conn.into();
+
+        Box::pin(async move {
+            DB::TransactionManager::begin_with(&mut conn, options).await?;
+
+            Ok(Self {
+                connection: conn,
+                open: true,
+                isolation_level: options.isolation_level,
+                access_mode: options.get_access_mode(),
             })
         })
     }
 
+    /// The isolation level this transaction was started with, or `None` if it uses the
+    /// default of the database or session.
+    pub fn isolation_level(&self) -> Option<IsolationLevel> {
+        self.isolation_level
+    }
+
+    /// The access mode this transaction was started with, or `None` if it uses the
+    /// default of the database or session.
+    pub fn access_mode(&self) -> Option<AccessMode> {
+        self.access_mode
+    }
+
     /// Commits this transaction or savepoint.
     pub async fn commit(mut self) -> Result<(), Error> {
         DB::TransactionManager::commit(&mut self.connection).await?;
@@ -95,6 +151,70 @@ where
     }
 }
 
+impl<'c, DB> Transaction<'c, DB>
+where
+    DB: Database,
+    for<'e> &'e mut DB::Connection: Executor<'e, Database = DB>,
+{
+    /// Establish a savepoint named `name` within this transaction.
+    ///
+    /// Unlike a nested transaction started with [`begin()`][crate::acquire::Acquire::begin],
+    /// a named savepoint can be rolled back to any number of times with
+    /// [`rollback_to()`][Self::rollback_to], which makes it suitable for retrying part of a
+    /// transaction. Establishing a savepoint with the name of an existing one hides the
+    /// earlier savepoint until the new one is released.
+    ///
+    /// `name` must consist of ASCII letters, digits and underscores, and must not start with a
+    /// digit or with `_sqlx_`, which is reserved for the savepoints of nested transactions.
+    /// Named savepoints should be released or rolled back to within the transaction or nested
+    /// transaction they were established in.
+    pub async fn savepoint(&mut self, name: &str) -> Result<(), Error> {
+        let sql = savepoint_sql("SAVEPOINT", name)?;
+        self.connection.execute(&*sql).await?;
+
+        Ok(())
+    }
+
+    /// Undo every change made since the savepoint named `name` was established.
+    ///
+    /// The savepoint is kept, so it can be rolled back to again, but savepoints established
+    /// after it are released.
+    pub async fn rollback_to(&mut self, name: &str) -> Result<(), Error> {
+        let sql = savepoint_sql("ROLLBACK TO SAVEPOINT", name)?;
+        self.connection.execute(&*sql).await?;
+
+        Ok(())
+    }
+
+    /// Release the savepoint named `name`, and the savepoints established after it.
+    ///
+    /// The changes made since the savepoint was established are kept.
+    pub async fn release(&mut self, name: &str) -> Result<(), Error> {
+        let sql = savepoint_sql("RELEASE SAVEPOINT", name)?;
+        self.connection.execute(&*sql).await?;
+
+        Ok(())
+    }
+}
+
+// Savepoint names are not quoted, as each database quotes identifiers differently.
+fn savepoint_sql(command: &str, name: &str) -> Result<String, Error> {
+    let is_valid = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !name.starts_with("_sqlx_");
+
+    if !is_valid {
+        return Err(Error::Configuration(
+            format!("invalid savepoint name: {name:?}").into(),
+        ));
+    }
+
+    Ok(format!("{command} {name}"))
+}
+
 // NOTE: fails to compile due to lack of lazy normalization
 // impl<'c, 't, DB: Database> crate::executor::Executor<'t>
 //     for &'t mut crate::transaction::Transaction<'c, DB>
@@ -240,6 +360,197 @@ where
     }
 }
 
+/// The isolation level of a transaction.
+///
+/// See [`TransactionOptions`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl IsolationLevel {
+    /// The SQL keywords for this isolation level, e.g. `REPEATABLE READ`.
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::Serializable => "SERIALIZABLE",
+        }
+    }
+}
+
+/// The access mode of a transaction.
+///
+/// See [`TransactionOptions`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AccessMode {
+    ReadWrite,
+    ReadOnly,
+}
+
+impl AccessMode {
+    /// The SQL keywords for this access mode, e.g. `READ ONLY`.
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            AccessMode::ReadWrite => "READ WRITE",
+            AccessMode::ReadOnly => "READ ONLY",
+        }
+    }
+}
+
+/// The options of a new transaction, for
+/// [`Pool::begin_with_options()`][crate::pool::Pool::begin_with_options] and
+/// [`Connection::begin_with_options()`][crate::connection::Connection::begin_with_options].
+///
+/// Each driver translates these into its own syntax; an option the database does not support
+/// makes beginning the transaction fail instead of being ignored. Options which are not set use
+/// the default of the database or session.
+///
+/// ```rust
+/// use sqlx::{IsolationLevel, TransactionOptions};
+///
+/// // for a long-running report in Postgres, which then can't fail with a serialization error
+/// let options = TransactionOptions::new()
+///     .isolation_level(IsolationLevel::Serializable)
+///     .read_only(true)
+///     .deferrable(true);
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TransactionOptions {
+    isolation_level: Option<IsolationLevel>,
+    read_only: Option<bool>,
+    deferrable: bool,
+}
+
+impl TransactionOptions {
+    /// Options which leave everything to the default of the database or session.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the isolation level of the transaction.
+    pub fn isolation_level(mut self, isolation_level: IsolationLevel) -> Self {
+        self.isolation_level = Some(isolation_level);
+        self
+    }
+
+    /// Get the isolation level of the transaction, if set.
+    pub fn get_isolation_level(&self) -> Option<IsolationLevel> {
+        self.isolation_level
+    }
+
+    /// Set whether the transaction is `READ ONLY` or `READ WRITE`.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = Some(read_only);
+        self
+    }
+
+    /// Get whether the transaction is `READ ONLY`, if set.
+    pub fn get_read_only(&self) -> Option<bool> {
+        self.read_only
+    }
+
+    /// Get the access mode of the transaction, if set.
+    pub fn get_access_mode(&self) -> Option<AccessMode> {
+        self.read_only.map(|read_only| {
+            if read_only {
+                AccessMode::ReadOnly
+            } else {
+                AccessMode::ReadWrite
+            }
+        })
+    }
+
+    /// If true, make the transaction `DEFERRABLE`.
+    ///
+    /// A `SERIALIZABLE`, `READ ONLY` and `DEFERRABLE` transaction may wait for a snapshot when it
+    /// begins, but then can't fail with a serialization error.
+    /// Only supported by Postgres. Defaults to `false`.
+    pub fn deferrable(mut self, deferrable: bool) -> Self {
+        self.deferrable = deferrable;
+        self
+    }
+
+    /// Get whether the transaction is `DEFERRABLE`.
+    pub fn get_deferrable(&self) -> bool {
+        self.deferrable
+    }
+}
+
+impl From<(IsolationLevel, AccessMode)> for TransactionOptions {
+    fn from((isolation_level, access_mode): (IsolationLevel, AccessMode)) -> Self {
+        Self {
+            isolation_level: Some(isolation_level),
+            read_only: Some(access_mode == AccessMode::ReadOnly),
+            deferrable: false,
+        }
+    }
+}
+
+/// Returned as an error from the callback of
+/// [`Connection::transaction_with_rollback()`][crate::connection::Connection::transaction_with_rollback]
+/// to roll back the transaction, and return the value to the caller instead of an error.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rollback<T>(pub T);
+
+/// Why the callback of
+/// [`Connection::transaction_with_rollback()`][crate::connection::Connection::transaction_with_rollback]
+/// rolled back the transaction.
+///
+/// Both [`Rollback`] and [`Error`] convert into this with `?`.
+#[derive(Debug)]
+pub enum TransactionAbort<T, E = Error> {
+    /// Roll back, and return the value to the caller.
+    Rollback(T),
+    /// Roll back, and return the error to the caller.
+    Error(E),
+}
+
+impl<T, E> From<Rollback<T>> for TransactionAbort<T, E> {
+    fn from(Rollback(value): Rollback<T>) -> Self {
+        TransactionAbort::Rollback(value)
+    }
+}
+
+impl<T, E: From<Error>> From<Error> for TransactionAbort<T, E> {
+    fn from(error: Error) -> Self {
+        TransactionAbort::Error(error.into())
+    }
+}
+
+/// The error for setting the options of a transaction which would be nested in another.
+#[doc(hidden)]
+pub fn nested_transaction_options_error() -> Error {
+    Error::Configuration("the options of a nested transaction can not be set".into())
+}
+
+/// `BEGIN` with the options in the syntax of Postgres, e.g.
+/// `BEGIN ISOLATION LEVEL SERIALIZABLE READ ONLY DEFERRABLE`.
+#[doc(hidden)]
+pub fn begin_transaction_with_options_sql(options: &TransactionOptions) -> String {
+    let mut sql = String::from("BEGIN");
+
+    if let Some(isolation_level) = options.isolation_level {
+        sql.push_str(" ISOLATION LEVEL ");
+        sql.push_str(isolation_level.as_sql());
+    }
+
+    if let Some(access_mode) = options.get_access_mode() {
+        sql.push(' ');
+        sql.push_str(access_mode.as_sql());
+    }
+
+    if options.deferrable {
+        sql.push_str(" DEFERRABLE");
+    }
+
+    sql
+}
+
 pub fn begin_ansi_transaction_sql(depth: usize) -> Cow<'static, str> {
     if depth == 0 {
         Cow::Borrowed("BEGIN")
@@ -16,6 +16,11 @@
 //!
 //! To represent nullable SQL types, `Option<T>` is supported where `T` implements `Type`.
 //! An `Option<T>` represents a potentially `NULL` value from SQL.
+//!
+//! When binding, borrowed optional values are supported as well, so fields of a struct can be
+//! bound without cloning them: `Option<&T>` (e.g. from [`Option::as_ref()`]), `&Option<T>`
+//! and `Option<&str>` (from [`Option::as_deref()`]) all encode the same as `Option<T>`.
+//! Nested options such as `Option<Option<T>>` are `NULL` if any level is `None`.
 
 use crate::database::Database;
 use crate::type_info::TypeInfo;
@@ -42,7 +47,7 @@ pub use uuid::{self, Uuid};
 pub mod chrono {
     #[doc(no_inline)]
     pub use chrono::{
-        DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+        DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Utc,
     };
 }
 
@@ -55,7 +60,7 @@ pub use bit_vec::BitVec;
 #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
 pub mod time {
     #[doc(no_inline)]
-    pub use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+    pub use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
 }
 
 #[cfg(feature = "bigdecimal")]
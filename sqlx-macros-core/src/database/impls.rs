@@ -3,11 +3,18 @@ macro_rules! impl_database_ext {
         $database:path,
         row: $row:path,
         $(describe-blocking: $describe:path,)?
+        $(return-type-override: $override:path,)?
     ) => {
         impl $crate::database::DatabaseExt for $database {
             const DATABASE_PATH: &'static str = stringify!($database);
             const ROW_PATH: &'static str = stringify!($row);
             impl_describe_blocking!($database, $($describe)?);
+
+            $(
+                fn return_type_override(info: &Self::TypeInfo) -> Option<&'static str> {
+                    $override(info)
+                }
+            )?
         }
     }
 }
@@ -53,12 +60,85 @@ mod sqlx {
 impl_database_ext! {
     sqlx::mysql::MySql,
     row: sqlx::mysql::MySqlRow,
+    return-type-override: mysql_return_type_override,
+}
+
+#[cfg(feature = "mysql")]
+fn mysql_return_type_override(info: &sqlx_mysql::MySqlTypeInfo) -> Option<&'static str> {
+    // `BOOLEAN` is an alias of `TINYINT(1)`, which is otherwise inferred as `i8`.
+    #[cfg(feature = "macros")]
+    if crate::query::mysql_tinyint1_as_bool()
+        && sqlx_core::type_info::TypeInfo::name(info) == "BOOLEAN"
+    {
+        return Some("bool");
+    }
+
+    // `BINARY(16)` is the usual way to store a UUID in MySQL, but it is otherwise inferred as `Vec<u8>`.
+    #[cfg(feature = "uuid")]
+    if crate::query::mysql_binary16_as_uuid() && info.__is_binary16() {
+        return Some("sqlx::types::Uuid");
+    }
+
+    // `TIME` may be negative or exceed 24 hours, which a time of day can't hold.
+    #[cfg(all(feature = "macros", any(feature = "chrono", feature = "time")))]
+    if crate::query::mysql_time_as_duration()
+        && sqlx_core::type_info::TypeInfo::name(info) == "TIME"
+    {
+        #[cfg(feature = "time")]
+        return Some("sqlx::types::time::Duration");
+
+        #[cfg(not(feature = "time"))]
+        return Some("sqlx::types::chrono::TimeDelta");
+    }
+
+    #[cfg(not(any(feature = "uuid", feature = "macros")))]
+    let _ = info;
+
+    None
 }
 
 #[cfg(feature = "postgres")]
 impl_database_ext! {
     sqlx::postgres::Postgres,
     row: sqlx::postgres::PgRow,
+    return-type-override: postgres_return_type_override,
+}
+
+// `TIMESTAMPTZ` is otherwise inferred as `DateTime<Utc>`, which drops the session offset.
+#[cfg(all(
+    feature = "postgres",
+    feature = "macros",
+    feature = "chrono",
+    not(feature = "time")
+))]
+fn postgres_return_type_override(info: &sqlx_postgres::PgTypeInfo) -> Option<&'static str> {
+    use sqlx_core::type_info::TypeInfo;
+
+    let is_array = match info.name() {
+        "TIMESTAMPTZ" => false,
+        "TIMESTAMPTZ[]" => true,
+        _ => return None,
+    };
+
+    match (crate::query::postgres_timestamptz(), is_array) {
+        ("fixed_offset", false) => {
+            Some("sqlx::types::chrono::DateTime<sqlx::types::chrono::FixedOffset>")
+        }
+        ("fixed_offset", true) => {
+            Some("Vec<sqlx::types::chrono::DateTime<sqlx::types::chrono::FixedOffset>>")
+        }
+        ("local", false) => Some("sqlx::types::chrono::DateTime<sqlx::types::chrono::Local>"),
+        ("local", true) => Some("Vec<sqlx::types::chrono::DateTime<sqlx::types::chrono::Local>>"),
+        _ => None,
+    }
+}
+
+#[cfg(all(
+    feature = "postgres",
+    not(all(feature = "macros", feature = "chrono", not(feature = "time")))
+))]
+fn postgres_return_type_override(_info: &sqlx_postgres::PgTypeInfo) -> Option<&'static str> {
+    None
 }
 
 #[cfg(feature = "sqlite")]
@@ -26,6 +26,12 @@ pub trait DatabaseExt: Database + TypeChecking {
     }
 
     fn describe_blocking(query: &str, database_url: &str) -> sqlx_core::Result<Describe<Self>>;
+
+    /// Get the Rust type to infer for an output column of the given type in place of
+    /// [`TypeChecking::return_type_for_id()`], if the macros are configured to override it.
+    fn return_type_override(_info: &Self::TypeInfo) -> Option<&'static str> {
+        None
+    }
 }
 
 #[allow(dead_code)]
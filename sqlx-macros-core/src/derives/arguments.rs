@@ -0,0 +1,148 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse_quote, Data, DataStruct, DeriveInput, Fields, Index, Lifetime, LifetimeParam, Member,
+};
+
+use super::attributes::parse_child_attributes;
+
+pub fn expand_derive_into_arguments(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => &fields.named,
+
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(fields),
+            ..
+        }) => &fields.unnamed,
+
+        Data::Struct(DataStruct {
+            fields: Fields::Unit,
+            ..
+        }) => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "unit structs are not supported",
+            ))
+        }
+
+        Data::Enum(_) => return Err(syn::Error::new_spanned(input, "enums are not supported")),
+
+        Data::Union(_) => return Err(syn::Error::new_spanned(input, "unions are not supported")),
+    };
+
+    // Fields with a `position` take that parameter, and the others fill the remaining
+    // parameters in declaration order.
+    let mut bound = Vec::new();
+    let mut positioned = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let attributes = parse_child_attributes(&field.attrs)?;
+
+        if attributes.skip {
+            continue;
+        }
+
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+
+        match attributes.position {
+            Some(position) => positioned.push((position, member, &field.ty)),
+            None => bound.push(Some((member, &field.ty))),
+        }
+    }
+
+    let count = bound.len() + positioned.len();
+    let mut slots: Vec<Option<_>> = (0..count).map(|_| None).collect();
+
+    for (position, member, ty) in positioned {
+        let value: usize = position.base10_parse()?;
+
+        if value == 0 || value > count {
+            return Err(syn::Error::new_spanned(
+                &position,
+                format!("position must be between 1 and {count}"),
+            ));
+        }
+
+        if slots[value - 1].is_some() {
+            return Err(syn::Error::new_spanned(&position, "duplicate position"));
+        }
+
+        slots[value - 1] = Some((member, ty));
+    }
+
+    let mut bound = bound.into_iter().flatten();
+
+    let fields: Vec<_> = slots
+        .into_iter()
+        .map(|slot| slot.or_else(|| bound.next()).unwrap())
+        .collect();
+
+    let ident = &input.ident;
+
+    let lifetime = Lifetime::new("'q", Span::call_site());
+
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let mut generics = input.generics.clone();
+    generics
+        .params
+        .insert(0, parse_quote!(DB: ::sqlx::Database));
+
+    if !generics.lifetimes().any(|def| def.lifetime == lifetime) {
+        generics
+            .params
+            .insert(0, LifetimeParam::new(lifetime.clone()).into());
+    }
+
+    let predicates = &mut generics.make_where_clause().predicates;
+
+    for (_, ty) in &fields {
+        predicates.push(parse_quote!(#ty: #lifetime + ::std::marker::Send));
+        predicates.push(parse_quote!(#ty: ::sqlx::encode::Encode<#lifetime, DB>));
+        predicates.push(parse_quote!(#ty: ::sqlx::types::Type<DB>));
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let members: Vec<_> = fields.iter().map(|(member, _)| member).collect();
+
+    Ok(quote!(
+        #[automatically_derived]
+        impl #impl_generics ::sqlx::IntoArguments<#lifetime, DB> for #ident #ty_generics #where_clause {
+            fn into_arguments(self) -> <DB as ::sqlx::Database>::Arguments<#lifetime> {
+                match ::sqlx::IntoArguments::<#lifetime, DB>::try_into_arguments(self) {
+                    ::std::result::Result::Ok(arguments) => arguments,
+                    ::std::result::Result::Err(error) => {
+                        ::std::panic!("failed to encode arguments: {error}")
+                    }
+                }
+            }
+
+            fn try_into_arguments(
+                self,
+            ) -> ::std::result::Result<
+                <DB as ::sqlx::Database>::Arguments<#lifetime>,
+                ::sqlx::error::BoxDynError,
+            > {
+                let mut arguments =
+                    <<DB as ::sqlx::Database>::Arguments<#lifetime> as ::std::default::Default>::default();
+
+                ::sqlx::Arguments::reserve(
+                    &mut arguments,
+                    #count,
+                    0 #(+ ::sqlx::encode::Encode::<DB>::size_hint(&self.#members))*,
+                );
+
+                #(::sqlx::Arguments::add(&mut arguments, self.#members)?;)*
+
+                ::std::result::Result::Ok(arguments)
+            }
+        }
+    ))
+}
@@ -1,8 +1,8 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use syn::{
-    punctuated::Punctuated, token::Comma, Attribute, DeriveInput, Field, LitStr, Meta, Token, Type,
-    Variant,
+    punctuated::Punctuated, token::Comma, Attribute, DeriveInput, Field, LitInt, LitStr, Meta,
+    Path, Token, Type, Variant,
 };
 
 macro_rules! assert_attribute {
@@ -56,6 +56,8 @@ pub struct SqlxContainerAttributes {
     pub transparent: bool,
     pub type_name: Option<TypeName>,
     pub rename_all: Option<RenameAll>,
+    /// A function mapping field names to column names, `#[sqlx(rename_all = path::to::fn)]`.
+    pub rename_all_fn: Option<Path>,
     pub repr: Option<Ident>,
     pub no_pg_array: bool,
     pub default: bool,
@@ -68,6 +70,7 @@ pub struct SqlxChildAttributes {
     pub try_from: Option<Type>,
     pub skip: bool,
     pub json: bool,
+    pub position: Option<LitInt>,
 }
 
 pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContainerAttributes> {
@@ -75,6 +78,7 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
     let mut repr = None;
     let mut type_name = None;
     let mut rename_all = None;
+    let mut rename_all_fn = None;
     let mut no_pg_array = None;
     let mut default = None;
 
@@ -89,8 +93,24 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                     try_set!(default, true, attr);
                 } else if meta.path.is_ident("rename_all") {
                     meta.input.parse::<Token![=]>()?;
+
+                    if !meta.input.peek(LitStr) {
+                        let path: Path = meta.input.parse()?;
+
+                        if rename_all.is_some() {
+                            fail!(path, "duplicate attribute");
+                        }
+
+                        try_set!(rename_all_fn, path.clone(), path);
+                        return Ok(());
+                    }
+
                     let lit: LitStr = meta.input.parse()?;
 
+                    if rename_all_fn.is_some() {
+                        fail!(lit, "duplicate attribute");
+                    }
+
                     let val = match lit.value().as_str() {
                         "lowercase" => RenameAll::LowerCase,
                         "snake_case" => RenameAll::SnakeCase,
@@ -133,6 +153,7 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
         repr,
         type_name,
         rename_all,
+        rename_all_fn,
         no_pg_array: no_pg_array.unwrap_or(false),
         default: default.unwrap_or(false),
     })
@@ -145,6 +166,7 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
     let mut flatten = false;
     let mut skip: bool = false;
     let mut json = false;
+    let mut position = None;
 
     for attr in input.iter().filter(|a| a.path().is_ident("sqlx")) {
         attr.parse_nested_meta(|meta| {
@@ -164,6 +186,10 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
                 skip = true;
             } else if meta.path.is_ident("json") {
                 json = true;
+            } else if meta.path.is_ident("position") {
+                meta.input.parse::<Token![=]>()?;
+                let val: LitInt = meta.input.parse()?;
+                try_set!(position, val, meta.path);
             }
 
             Ok(())
@@ -184,6 +210,7 @@ pub fn parse_child_attributes(input: &[Attribute]) -> syn::Result<SqlxChildAttri
         try_from,
         skip,
         json,
+        position,
     })
 }
 
@@ -194,7 +221,7 @@ pub fn check_transparent_attributes(
     let attributes = parse_container_attributes(&input.attrs)?;
 
     assert_attribute!(
-        attributes.rename_all.is_none(),
+        attributes.rename_all.is_none() && attributes.rename_all_fn.is_none(),
         "unexpected #[sqlx(rename_all = ..)]",
         field
     );
@@ -219,6 +246,13 @@ pub fn check_enum_attributes(input: &DeriveInput) -> syn::Result<SqlxContainerAt
         input
     );
 
+    if let Some(path) = &attributes.rename_all_fn {
+        fail!(
+            path,
+            "a function for #[sqlx(rename_all = ..)] is only supported by #[derive(FromRow)]"
+        );
+    }
+
     Ok(attributes)
 }
 
@@ -273,7 +307,7 @@ pub fn check_struct_attributes(
     );
 
     assert_attribute!(
-        attributes.rename_all.is_none(),
+        attributes.rename_all.is_none() && attributes.rename_all_fn.is_none(),
         "unexpected #[sqlx(rename_all = ..)]",
         input
     );
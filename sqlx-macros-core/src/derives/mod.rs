@@ -1,9 +1,11 @@
+mod arguments;
 mod attributes;
 mod decode;
 mod encode;
 mod row;
 mod r#type;
 
+pub use arguments::expand_derive_into_arguments;
 pub use decode::expand_derive_decode;
 pub use encode::expand_derive_encode;
 pub use r#type::expand_derive_type;
@@ -61,10 +61,17 @@ fn expand_derive_from_row_struct(
 
     let predicates = &mut generics.make_where_clause().predicates;
 
-    predicates.push(parse_quote!(&#lifetime ::std::primitive::str: ::sqlx::ColumnIndex<R>));
-
     let container_attributes = parse_container_attributes(&input.attrs)?;
 
+    if container_attributes.rename_all_fn.is_some() {
+        // the column names are computed when the row is read, so they don't live for `'a`
+        predicates.push(parse_quote!(
+            for<'__column> &'__column ::std::primitive::str: ::sqlx::ColumnIndex<R>
+        ));
+    } else {
+        predicates.push(parse_quote!(&#lifetime ::std::primitive::str: ::sqlx::ColumnIndex<R>));
+    }
+
     let default_instance: Option<Stmt> = if container_attributes.default {
         predicates.push(parse_quote!(#ident: ::std::default::Default));
         Some(parse_quote!(
@@ -96,6 +103,13 @@ fn expand_derive_from_row_struct(
                 })
                 .unwrap();
 
+            let column: Expr = match &container_attributes.rename_all_fn {
+                Some(rename_fn) => parse_quote!(
+                    ::std::convert::AsRef::<::std::primitive::str>::as_ref(&#rename_fn(#id_s))
+                ),
+                None => parse_quote!(#id_s),
+            };
+
             let expr: Expr = match (attributes.flatten, attributes.try_from, attributes.json) {
                 // <No attributes>
                 (false, None, false) => {
@@ -103,7 +117,7 @@ fn expand_derive_from_row_struct(
                         .push(parse_quote!(#ty: ::sqlx::decode::Decode<#lifetime, R::Database>));
                     predicates.push(parse_quote!(#ty: ::sqlx::types::Type<R::Database>));
 
-                    parse_quote!(row.try_get(#id_s))
+                    parse_quote!(row.try_get(#column))
                 }
                 // Flatten
                 (true, None, false) => {
@@ -125,7 +139,7 @@ fn expand_derive_from_row_struct(
                         .push(parse_quote!(#try_from: ::sqlx::decode::Decode<#lifetime, R::Database>));
                     predicates.push(parse_quote!(#try_from: ::sqlx::types::Type<R::Database>)); 
 
-                    parse_quote!(row.try_get(#id_s).and_then(|v| <#ty as ::std::convert::TryFrom::<#try_from>>::try_from(v).map_err(|e| ::sqlx::Error::ColumnNotFound("FromRow: try_from failed".to_string()))))
+                    parse_quote!(row.try_get(#column).and_then(|v| <#ty as ::std::convert::TryFrom::<#try_from>>::try_from(v).map_err(|e| ::sqlx::Error::ColumnNotFound("FromRow: try_from failed".to_string()))))
                 }
                 // Try from + Json
                 (false, Some(try_from), true) => {
@@ -134,7 +148,7 @@ fn expand_derive_from_row_struct(
                     predicates.push(parse_quote!(::sqlx::types::Json<#try_from>: ::sqlx::types::Type<R::Database>));
 
                     parse_quote!(
-                        row.try_get::<::sqlx::types::Json<_>, _>(#id_s).and_then(|v|
+                        row.try_get::<::sqlx::types::Json<_>, _>(#column).and_then(|v|
                             <#ty as ::std::convert::TryFrom::<#try_from>>::try_from(v.0)
                             .map_err(|e| ::sqlx::Error::ColumnNotFound("FromRow: try_from failed".to_string()))
                         )
@@ -146,7 +160,7 @@ fn expand_derive_from_row_struct(
                         .push(parse_quote!(::sqlx::types::Json<#ty>: ::sqlx::decode::Decode<#lifetime, R::Database>));
                     predicates.push(parse_quote!(::sqlx::types::Json<#ty>: ::sqlx::types::Type<R::Database>));
 
-                    parse_quote!(row.try_get::<::sqlx::types::Json<_>, _>(#id_s).map(|x| x.0))
+                    parse_quote!(row.try_get::<::sqlx::types::Json<_>, _>(#column).map(|x| x.0))
                 },
             };
 
@@ -0,0 +1,151 @@
+//! Inspect and prune the cached query data used by the query macros in offline mode.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::query::data::{hash_string, DynQueryData};
+
+/// A directory of `query-<hash>.json` files, such as `.sqlx`, written by `cargo sqlx prepare`.
+///
+/// To find the entries which are no longer used, compare the cache to the queries of a fresh
+/// build, e.g. one compiled with `SQLX_OFFLINE_DIR` pointing to an empty directory:
+///
+/// ```rust,no_run
+/// use sqlx_macros_core::query::cache::QueryCache;
+///
+/// # fn main() -> sqlx_macros_core::Result<()> {
+/// let current = QueryCache::new("target/sqlx-fresh").entries()?;
+///
+/// let removed = QueryCache::new(".sqlx").prune(current.iter().map(|entry| &entry.query))?;
+///
+/// for entry in removed {
+///     println!("removed {}", entry.path.display());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct QueryCache {
+    dir: PathBuf,
+}
+
+/// A `query-<hash>.json` file in a [`QueryCache`].
+#[derive(Debug, Clone)]
+pub struct QueryCacheEntry {
+    /// The path of the file.
+    pub path: PathBuf,
+
+    /// The name of the database the query was prepared for, e.g. `PostgreSQL`.
+    pub db_name: String,
+
+    /// The SQL of the query.
+    pub query: String,
+
+    /// The hash of the query as written in the file, which is also in the file name.
+    pub hash: String,
+}
+
+/// The entries of a [`QueryCache`], split by whether they're used by a set of queries.
+#[derive(Debug, Clone, Default)]
+pub struct QueryCacheUsage {
+    /// The entries used by one of the queries.
+    pub referenced: Vec<QueryCacheEntry>,
+
+    /// The entries not used by any of the queries, which can be deleted.
+    pub stale: Vec<QueryCacheEntry>,
+}
+
+impl QueryCache {
+    /// The cache in `dir`, which doesn't need to exist yet.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        QueryCache { dir: dir.into() }
+    }
+
+    /// The directory of the cache.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Read every `query-<hash>.json` file in the directory, sorted by path.
+    ///
+    /// Returns an empty list if the directory doesn't exist.
+    pub fn entries(&self) -> crate::Result<Vec<QueryCacheEntry>> {
+        let read_dir = match fs::read_dir(&self.dir) {
+            Ok(read_dir) => read_dir,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => {
+                return Err(format!("failed to read {}: {e}", self.dir.display()).into());
+            }
+        };
+
+        let mut entries = Vec::new();
+
+        for dir_entry in read_dir {
+            let path = dir_entry?.path();
+
+            let is_query_file = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("query-") && name.ends_with(".json"));
+
+            if !is_query_file {
+                continue;
+            }
+
+            let contents = fs::read_to_string(&path)
+                .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+            let data: DynQueryData = serde_json::from_str(&contents)
+                .map_err(|e| format!("failed to parse {}: {e}", path.display()))?;
+
+            entries.push(QueryCacheEntry {
+                path,
+                db_name: data.db_name,
+                query: data.query,
+                hash: data.hash,
+            });
+        }
+
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(entries)
+    }
+
+    /// Split the entries into those used by one of `queries`, and those which aren't.
+    ///
+    /// Queries which only differ in whitespace are considered the same.
+    pub fn usage<I>(&self, queries: I) -> crate::Result<QueryCacheUsage>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let hashes: HashSet<String> = queries
+            .into_iter()
+            .map(|query| hash_string(query.as_ref()))
+            .collect();
+
+        let (referenced, stale) = self
+            .entries()?
+            .into_iter()
+            // re-hash the query, as entries written by older versions use a different hash
+            .partition(|entry| hashes.contains(&hash_string(&entry.query)));
+
+        Ok(QueryCacheUsage { referenced, stale })
+    }
+
+    /// Delete the entries which aren't used by any of `queries`, returning the deleted entries.
+    pub fn prune<I>(&self, queries: I) -> crate::Result<Vec<QueryCacheEntry>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let QueryCacheUsage { stale, .. } = self.usage(queries)?;
+
+        for entry in &stale {
+            fs::remove_file(&entry.path)
+                .map_err(|e| format!("failed to delete {}: {e}", entry.path.display()))?;
+        }
+
+        Ok(stale)
+    }
+}
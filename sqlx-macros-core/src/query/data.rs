@@ -92,7 +92,7 @@ impl DynQueryData {
                 guard
             });
         if let Some(cached) = cache.get(path).cloned() {
-            if query != cached.query {
+            if !queries_match(query, &cached.query) {
                 return Err("hash collision for saved query data".into());
             }
             return Ok(cached);
@@ -115,7 +115,7 @@ impl DynQueryData {
             .map_err(|e| format!("failed to read saved query path {}: {}", path.display(), e))?;
         let dyn_data: DynQueryData = serde_json::from_str(&offline_data_contents)?;
 
-        if query != dyn_data.query {
+        if !queries_match(query, &dyn_data.query) {
             return Err("hash collision for saved query data".into());
         }
 
@@ -154,6 +154,15 @@ where
         use std::io::ErrorKind;
 
         let path = dir.as_ref().join(format!("query-{}.json", self.hash));
+
+        let data = serde_json::to_string_pretty(self)
+            .map_err(|err| format!("failed to serialize query data: {err:?}"))?;
+
+        // Crates sharing a workspace cache may save the same query; leave identical data alone.
+        if std::fs::read_to_string(&path).is_ok_and(|existing| existing.trim_end() == data) {
+            return Ok(());
+        }
+
         match std::fs::remove_file(&path) {
             Ok(()) => {}
             Err(err)
@@ -176,8 +185,6 @@ where
             }
         };
 
-        let data = serde_json::to_string_pretty(self)
-            .map_err(|err| format!("failed to serialize query data: {err:?}"))?;
         file.write_all(data.as_bytes())
             .map_err(|err| format!("failed to write query data to file: {err:?}"))?;
 
@@ -190,9 +197,126 @@ where
     }
 }
 
+/// Hash a query for the name of its `query-<hash>.json` file.
+///
+/// The query is normalized with [`normalize_query()`] first, so changes to whitespace alone
+/// don't invalidate the cached data.
 pub(super) fn hash_string(query: &str) -> String {
+    legacy_hash_string(&normalize_query(query))
+}
+
+/// The hash used before queries were normalized, to keep reading cached data written by
+/// older versions.
+pub(super) fn legacy_hash_string(query: &str) -> String {
     // picked `sha2` because it's already in the dependency tree for both MySQL and Postgres
     use sha2::{Digest, Sha256};
 
     hex::encode(Sha256::digest(query.as_bytes()))
 }
+
+/// Returns `true` if the queries only differ in whitespace.
+pub(super) fn queries_match(a: &str, b: &str) -> bool {
+    a == b || normalize_query(a) == normalize_query(b)
+}
+
+/// Collapse each run of whitespace in `query` to a single space, or a single newline if it
+/// contains one, and trim the start and end.
+///
+/// Whitespace in string literals, quoted identifiers and comments is left as-is. Newlines are
+/// kept so the end of a `--` comment doesn't move.
+pub(super) fn normalize_query(query: &str) -> String {
+    let mut normalized = String::with_capacity(query.len());
+    let mut chars = query.trim().chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                let mut newline = c == '\n';
+
+                while let Some(c) = chars.next_if(|c| c.is_whitespace()) {
+                    newline |= c == '\n';
+                }
+
+                normalized.push(if newline { '\n' } else { ' ' });
+            }
+
+            '\'' | '"' | '`' => {
+                normalized.push(c);
+
+                // Treating a backslash as an escape (as MySQL does) is conservative: at worst,
+                // some whitespace in the rest of the query isn't collapsed.
+                while let Some(next) = chars.next() {
+                    normalized.push(next);
+
+                    if next == '\\' {
+                        normalized.extend(chars.next());
+                    } else if next == c {
+                        break;
+                    }
+                }
+            }
+
+            '-' if chars.peek() == Some(&'-') => {
+                normalized.push(c);
+
+                // The newline ending the comment is handled as whitespace.
+                while let Some(next) = chars.next_if(|&c| c != '\n') {
+                    normalized.push(next);
+                }
+            }
+
+            '/' if chars.peek() == Some(&'*') => {
+                normalized.push(c);
+                normalized.extend(chars.next());
+
+                let mut prev = '\0';
+
+                for next in chars.by_ref() {
+                    normalized.push(next);
+
+                    if prev == '*' && next == '/' {
+                        break;
+                    }
+
+                    prev = next;
+                }
+            }
+
+            // Postgres dollar-quoted strings, e.g. `$$ ... $$` or `$body$ ... $body$`
+            '$' if chars
+                .peek()
+                .is_some_and(|&c| c == '$' || c == '_' || c.is_alphabetic()) =>
+            {
+                let mut tag = String::from('$');
+
+                while let Some(next) = chars.next_if(|&c| c == '_' || c.is_alphanumeric()) {
+                    tag.push(next);
+                }
+
+                normalized.push_str(&tag);
+
+                // `$name` without a closing `$` is not a dollar quote
+                if chars.next_if_eq(&'$').is_none() {
+                    continue;
+                }
+
+                tag.push('$');
+                normalized.push('$');
+
+                let start = normalized.len();
+
+                for next in chars.by_ref() {
+                    normalized.push(next);
+
+                    if normalized[start..].ends_with(&tag) {
+                        break;
+                    }
+                }
+            }
+
+            c => normalized.push(c),
+        }
+    }
+
+    normalized
+}
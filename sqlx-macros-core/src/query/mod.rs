@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::{fs, io};
 
@@ -12,12 +12,13 @@ use sqlx_core::database::Database;
 use sqlx_core::{column::Column, describe::Describe, type_info::TypeInfo};
 
 use crate::database::DatabaseExt;
-use crate::query::data::{hash_string, DynQueryData, QueryData};
+use crate::query::data::{hash_string, legacy_hash_string, DynQueryData, QueryData};
 use crate::query::input::RecordType;
 use either::Either;
 use url::Url;
 
 mod args;
+pub mod cache;
 mod data;
 mod input;
 mod output;
@@ -73,13 +74,42 @@ struct Metadata {
     manifest_dir: PathBuf,
     offline: bool,
     database_url: Option<String>,
-    workspace_root: Arc<Mutex<Option<PathBuf>>>,
+    #[allow(unused)]
+    mysql_binary16_as_uuid: bool,
+    #[allow(unused)]
+    mysql_tinyint1_as_bool: bool,
+    #[allow(unused)]
+    mysql_time_as_duration: bool,
+    // the error is returned by `expand_input()`, so it points at the macro invocation
+    #[allow(unused)]
+    postgres_timestamptz: Result<&'static str, String>,
+    workspace: Arc<Mutex<Option<Workspace>>>,
+}
+
+#[derive(Clone)]
+struct Workspace {
+    root: PathBuf,
+    // `offline-dir` in `[workspace.metadata.sqlx]`, relative to the root
+    offline_dir: Option<PathBuf>,
 }
 
 impl Metadata {
-    pub fn workspace_root(&self) -> PathBuf {
-        let mut root = self.workspace_root.lock().unwrap();
-        if root.is_none() {
+    /// The query cache shared by the crates of the workspace: `offline-dir` in
+    /// `[workspace.metadata.sqlx]` if set, or `.sqlx` in the workspace root.
+    pub fn workspace_offline_dir(&self) -> PathBuf {
+        let workspace = self.workspace();
+
+        workspace.root.join(
+            workspace
+                .offline_dir
+                .as_deref()
+                .unwrap_or(Path::new(".sqlx")),
+        )
+    }
+
+    fn workspace(&self) -> Workspace {
+        let mut workspace = self.workspace.lock().unwrap();
+        if workspace.is_none() {
             use serde::Deserialize;
             use std::process::Command;
 
@@ -95,14 +125,33 @@ impl Metadata {
             #[derive(Deserialize)]
             struct CargoMetadata {
                 workspace_root: PathBuf,
+                // `[workspace.metadata]`
+                metadata: Option<WorkspaceMetadata>,
+            }
+
+            #[derive(Deserialize)]
+            struct WorkspaceMetadata {
+                sqlx: Option<SqlxMetadata>,
+            }
+
+            #[derive(Deserialize)]
+            #[serde(rename_all = "kebab-case")]
+            struct SqlxMetadata {
+                offline_dir: Option<PathBuf>,
             }
 
             let metadata: CargoMetadata =
                 serde_json::from_slice(&output.stdout).expect("Invalid `cargo metadata` output");
 
-            *root = Some(metadata.workspace_root);
+            *workspace = Some(Workspace {
+                root: metadata.workspace_root,
+                offline_dir: metadata
+                    .metadata
+                    .and_then(|metadata| metadata.sqlx)
+                    .and_then(|sqlx| sqlx.offline_dir),
+            });
         }
-        root.clone().unwrap()
+        workspace.clone().unwrap()
     }
 }
 
@@ -141,18 +190,76 @@ static METADATA: Lazy<Metadata> = Lazy::new(|| {
 
     let database_url = env("DATABASE_URL").ok();
 
+    let mysql_binary16_as_uuid = env("SQLX_MYSQL_BINARY16_AS_UUID")
+        .map(|s| s.eq_ignore_ascii_case("true") || s == "1")
+        .unwrap_or(false);
+
+    let mysql_tinyint1_as_bool = env("SQLX_MYSQL_TINYINT1_AS_BOOL")
+        .map(|s| s.eq_ignore_ascii_case("true") || s == "1")
+        .unwrap_or(true);
+
+    let mysql_time_as_duration = env("SQLX_MYSQL_TIME_AS_DURATION")
+        .map(|s| s.eq_ignore_ascii_case("true") || s == "1")
+        .unwrap_or(false);
+
+    let postgres_timestamptz = match env("SQLX_POSTGRES_TIMESTAMPTZ") {
+        Err(_) => Ok("utc"),
+        Ok(s) => match s.to_ascii_lowercase().as_str() {
+            "utc" => Ok("utc"),
+            "fixed_offset" => Ok("fixed_offset"),
+            "local" => Ok("local"),
+            _ => Err(format!(
+                "`SQLX_POSTGRES_TIMESTAMPTZ` must be `utc`, `fixed_offset` or `local`, got {s:?}"
+            )),
+        },
+    };
+
     Metadata {
         manifest_dir,
         offline,
         database_url,
-        workspace_root: Arc::new(Mutex::new(None)),
+        mysql_binary16_as_uuid,
+        mysql_tinyint1_as_bool,
+        mysql_time_as_duration,
+        postgres_timestamptz,
+        workspace: Arc::new(Mutex::new(None)),
     }
 });
 
+/// Whether `BINARY(16)` columns should be inferred as `Uuid`, set by `SQLX_MYSQL_BINARY16_AS_UUID`.
+#[cfg(all(feature = "mysql", feature = "uuid"))]
+pub(crate) fn mysql_binary16_as_uuid() -> bool {
+    METADATA.mysql_binary16_as_uuid
+}
+
+/// Whether `TINYINT(1)` columns should be inferred as `bool`, set by `SQLX_MYSQL_TINYINT1_AS_BOOL`.
+#[cfg(feature = "mysql")]
+pub(crate) fn mysql_tinyint1_as_bool() -> bool {
+    METADATA.mysql_tinyint1_as_bool
+}
+
+/// Whether `TIME` columns should be inferred as a duration, set by `SQLX_MYSQL_TIME_AS_DURATION`.
+#[cfg(all(feature = "mysql", any(feature = "chrono", feature = "time")))]
+pub(crate) fn mysql_time_as_duration() -> bool {
+    METADATA.mysql_time_as_duration
+}
+
+/// The time zone to infer `TIMESTAMPTZ` columns in, set by `SQLX_POSTGRES_TIMESTAMPTZ`:
+/// `utc` (the default), `fixed_offset` or `local`.
+#[cfg(all(feature = "postgres", feature = "chrono", not(feature = "time")))]
+pub(crate) fn postgres_timestamptz() -> &'static str {
+    // checked by `expand_input()`
+    METADATA.postgres_timestamptz.as_deref().unwrap_or("utc")
+}
+
 pub fn expand_input<'a>(
     input: QueryMacroInput,
     drivers: impl IntoIterator<Item = &'a QueryDriver>,
 ) -> crate::Result<TokenStream> {
+    if let Err(message) = &METADATA.postgres_timestamptz {
+        return Err(syn::Error::new(input.src_span, message).into());
+    }
+
     let data_source = match &*METADATA {
         Metadata {
             offline: false,
@@ -161,19 +268,23 @@ pub fn expand_input<'a>(
         } => QueryDataSource::live(db_url)?,
 
         Metadata { offline, .. } => {
-            // Try load the cached query metadata file.
-            let filename = format!("query-{}.json", hash_string(&input.sql));
+            // Try load the cached query metadata file, falling back to the name used before
+            // queries were normalized for hashing.
+            let filenames = [
+                format!("query-{}.json", hash_string(&input.sql)),
+                format!("query-{}.json", legacy_hash_string(&input.sql)),
+            ];
 
-            // Check SQLX_OFFLINE_DIR, then local .sqlx, then workspace .sqlx.
+            // Check SQLX_OFFLINE_DIR, then local .sqlx, then the workspace cache.
             let dirs = [
                 || env("SQLX_OFFLINE_DIR").ok().map(PathBuf::from),
                 || Some(METADATA.manifest_dir.join(".sqlx")),
-                || Some(METADATA.workspace_root().join(".sqlx")),
+                || Some(METADATA.workspace_offline_dir()),
             ];
             let Some(data_file_path) = dirs
                 .iter()
                 .filter_map(|path| path())
-                .map(|path| path.join(&filename))
+                .flat_map(|path| filenames.iter().map(move |filename| path.join(filename)))
                 .find(|path| path.exists())
             else {
                 return Err(
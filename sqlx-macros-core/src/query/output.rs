@@ -221,33 +221,35 @@ pub fn quote_query_scalar<DB: DatabaseExt>(
 fn get_column_type<DB: DatabaseExt>(i: usize, column: &DB::Column) -> TokenStream {
     let type_info = column.type_info();
 
-    <DB as TypeChecking>::return_type_for_id(type_info).map_or_else(
-        || {
-            let message =
-                if let Some(feature_gate) = <DB as TypeChecking>::get_feature_gate(type_info) {
-                    format!(
-                        "optional sqlx feature `{feat}` required for type {ty} of {col}",
-                        ty = &type_info,
-                        feat = feature_gate,
-                        col = DisplayColumn {
-                            idx: i,
-                            name: column.name()
-                        }
-                    )
-                } else {
-                    format!(
-                        "unsupported type {ty} of {col}",
-                        ty = type_info,
-                        col = DisplayColumn {
-                            idx: i,
-                            name: column.name()
-                        }
-                    )
-                };
-            syn::Error::new(Span::call_site(), message).to_compile_error()
-        },
-        |t| t.parse().unwrap(),
-    )
+    DB::return_type_override(type_info)
+        .or_else(|| <DB as TypeChecking>::return_type_for_id(type_info))
+        .map_or_else(
+            || {
+                let message =
+                    if let Some(feature_gate) = <DB as TypeChecking>::get_feature_gate(type_info) {
+                        format!(
+                            "optional sqlx feature `{feat}` required for type {ty} of {col}",
+                            ty = &type_info,
+                            feat = feature_gate,
+                            col = DisplayColumn {
+                                idx: i,
+                                name: column.name()
+                            }
+                        )
+                    } else {
+                        format!(
+                            "unsupported type {ty} of {col}",
+                            ty = type_info,
+                            col = DisplayColumn {
+                                idx: i,
+                                name: column.name()
+                            }
+                        )
+                    };
+                syn::Error::new(Span::call_site(), message).to_compile_error()
+            },
+            |t| t.parse().unwrap(),
+        )
 }
 
 impl ColumnDecl {
@@ -6,6 +6,8 @@ use syn::parse::Parser;
 struct Args {
     fixtures: Vec<(FixturesType, Vec<syn::LitStr>)>,
     migrations: MigrationsOpt,
+    seeder: Option<syn::Path>,
+    rollback: bool,
 }
 
 #[cfg(feature = "migrate")]
@@ -163,6 +165,10 @@ fn expand_advanced(args: AttributeArgs, input: syn::ItemFn) -> crate::Result<Tok
         _ => quote! {},
     };
 
+    let seeder = args.seeder.map(|seeder| quote! { args.seeder(#seeder); });
+
+    let rollback = args.rollback.then(|| quote! { args.rollback(); });
+
     Ok(quote! {
         #(#attrs)*
         #[::core::prelude::v1::test]
@@ -177,6 +183,10 @@ fn expand_advanced(args: AttributeArgs, input: syn::ItemFn) -> crate::Result<Tok
 
             args.fixtures(&[#(#fixtures),*]);
 
+            #seeder
+
+            #rollback
+
             // We need to give a coercion site or else we get "unimplemented trait" errors.
             let f: fn(#(#fn_arg_types),*) -> _ = #name;
 
@@ -191,6 +201,8 @@ fn parse_args(attr_args: AttributeArgs) -> syn::Result<Args> {
 
     let mut fixtures = Vec::new();
     let mut migrations = MigrationsOpt::InferredPath;
+    let mut seeder = None;
+    let mut rollback = false;
 
     for arg in attr_args {
         let path = arg.path().clone();
@@ -277,10 +289,37 @@ fn parse_args(attr_args: AttributeArgs) -> syn::Result<Args> {
 
                 migrations = MigrationsOpt::ExplicitMigrator(lit.parse()?);
             }
+            // seeder = <rust path>
+            Meta::NameValue(MetaNameValue { value, .. }) if path.is_ident("seeder") => {
+                if seeder.is_some() {
+                    return Err(syn::Error::new_spanned(path, "duplicate `seeder` arg"));
+                }
+
+                seeder = Some(match value {
+                    Expr::Path(expr) => expr.path,
+                    Expr::Lit(syn::ExprLit {
+                        lit: Lit::Str(lit), ..
+                    }) => lit.parse()?,
+                    value => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            "expected path to a function",
+                        ))
+                    }
+                });
+            }
+            // rollback
+            Meta::Path(path) if path.is_ident("rollback") => {
+                if rollback {
+                    return Err(syn::Error::new_spanned(path, "duplicate `rollback` arg"));
+                }
+
+                rollback = true;
+            }
             arg => {
                 return Err(syn::Error::new_spanned(
                     arg,
-                    r#"expected `fixtures("<filename>", ...)` or `migrations = "<path>" | false` or `migrator = "<rust path>"`"#,
+                    r#"expected `fixtures("<filename>", ...)` or `migrations = "<path>" | false` or `migrator = "<rust path>"` or `seeder = <rust path>` or `rollback`"#,
                 ))
             }
         }
@@ -289,6 +328,8 @@ fn parse_args(attr_args: AttributeArgs) -> syn::Result<Args> {
     Ok(Args {
         fixtures,
         migrations,
+        seeder,
+        rollback,
     })
 }
 
@@ -1,22 +1,29 @@
+use crate::io::MySqlBufExt;
 use crate::protocol::text::ColumnType;
 use crate::{
     MySql, MySqlColumn, MySqlConnectOptions, MySqlConnection, MySqlQueryResult, MySqlRow,
-    MySqlTransactionManager, MySqlTypeInfo,
+    MySqlTransactionManager, MySqlTypeInfo, MySqlValue,
 };
+use bytes::Bytes;
 use either::Either;
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 use futures_util::{stream, StreamExt, TryFutureExt, TryStreamExt};
 use sqlx_core::any::{
-    Any, AnyArguments, AnyColumn, AnyConnectOptions, AnyConnectionBackend, AnyQueryResult, AnyRow,
-    AnyStatement, AnyTypeInfo, AnyTypeInfoKind,
+    Any, AnyArguments, AnyColumn, AnyConnectOptions, AnyConnectionBackend, AnyDecimal,
+    AnyQueryResult, AnyRow, AnyStatement, AnyTypeInfo, AnyTypeInfoKind, AnyValue, AnyValueError,
+    AnyValueKind,
 };
 use sqlx_core::connection::Connection;
-use sqlx_core::database::Database;
+use sqlx_core::database::{Database, HasReturningId};
 use sqlx_core::describe::Describe;
+use sqlx_core::encode::{Encode, IsNull};
 use sqlx_core::executor::Executor;
-use sqlx_core::transaction::TransactionManager;
+use sqlx_core::transaction::{TransactionManager, TransactionOptions};
+use sqlx_core::types::Type;
+use sqlx_core::value::Value;
 use std::future;
+use std::time::Duration;
 
 sqlx_core::declare_driver_with_optional_migrate!(DRIVER = MySql);
 
@@ -33,7 +40,7 @@ impl AnyConnectionBackend for MySqlConnection {
         Connection::close_hard(*self)
     }
 
-    fn ping(&mut self) -> BoxFuture<'_, sqlx_core::Result<()>> {
+    fn ping(&mut self) -> BoxFuture<'_, sqlx_core::Result<Duration>> {
         Connection::ping(self)
     }
 
@@ -41,6 +48,10 @@ impl AnyConnectionBackend for MySqlConnection {
         MySqlTransactionManager::begin(self)
     }
 
+    fn begin_with(&mut self, options: TransactionOptions) -> BoxFuture<'_, sqlx_core::Result<()>> {
+        MySqlTransactionManager::begin_with(self, options)
+    }
+
     fn commit(&mut self) -> BoxFuture<'_, sqlx_core::Result<()>> {
         MySqlTransactionManager::commit(self)
     }
@@ -53,6 +64,32 @@ impl AnyConnectionBackend for MySqlConnection {
         MySqlTransactionManager::start_rollback(self)
     }
 
+    fn set_statement_timeout(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'_, sqlx_core::Result<()>> {
+        Connection::set_statement_timeout(self, timeout)
+    }
+
+    fn statement_timeout(&self) -> Option<Duration> {
+        Connection::statement_timeout(self)
+    }
+
+    fn supports_statement_timeout(&self) -> bool {
+        true
+    }
+
+    fn supports_returning(&self) -> bool {
+        MySql::supports_returning(self)
+    }
+
+    fn estimate_rows<'e>(
+        &'e mut self,
+        table_or_query: &'e str,
+    ) -> BoxFuture<'e, sqlx_core::Result<u64>> {
+        Connection::estimate_rows(self, table_or_query)
+    }
+
     fn shrink_buffers(&mut self) {
         Connection::shrink_buffers(self);
     }
@@ -86,7 +123,7 @@ impl AnyConnectionBackend for MySqlConnection {
         };
 
         Box::pin(
-            self.run(query, arguments, persistent)
+            self.run(query, arguments, persistent, None)
                 .try_flatten_stream()
                 .map(|res| {
                     Ok(match res? {
@@ -111,7 +148,7 @@ impl AnyConnectionBackend for MySqlConnection {
 
         Box::pin(async move {
             let arguments = arguments?;
-            let stream = self.run(query, arguments, persistent).await?;
+            let stream = self.run(query, arguments, persistent, None).await?;
             futures_util::pin_mut!(stream);
 
             while let Some(result) = stream.try_next().await? {
@@ -151,6 +188,10 @@ impl<'a> TryFrom<&'a MySqlTypeInfo> for AnyTypeInfo {
     type Error = sqlx_core::Error;
 
     fn try_from(type_info: &'a MySqlTypeInfo) -> Result<Self, Self::Error> {
+        if let Some(kind) = sqlx_core::any::mapped_kind::<MySql>(type_info) {
+            return Ok(AnyTypeInfo { kind });
+        }
+
         Ok(AnyTypeInfo {
             kind: match &type_info.r#type {
                 ColumnType::Null => AnyTypeInfoKind::Null,
@@ -166,6 +207,10 @@ impl<'a> TryFrom<&'a MySqlTypeInfo> for AnyTypeInfo {
                 ColumnType::String | ColumnType::VarString | ColumnType::VarChar => {
                     AnyTypeInfoKind::Text
                 }
+                ColumnType::Decimal | ColumnType::NewDecimal => AnyTypeInfoKind::Decimal,
+                ColumnType::Date => AnyTypeInfoKind::Date,
+                ColumnType::Time => AnyTypeInfoKind::Time,
+                ColumnType::Datetime | ColumnType::Timestamp => AnyTypeInfoKind::Timestamp,
                 _ => {
                     return Err(sqlx_core::Error::AnyDriverError(
                         format!("Any driver does not support MySql type {type_info:?}").into(),
@@ -198,11 +243,96 @@ impl<'a> TryFrom<&'a MySqlRow> for AnyRow {
     }
 }
 
+impl<'a> TryFrom<&'a MySqlValue> for AnyValue {
+    type Error = AnyValueError;
+
+    fn try_from(value: &'a MySqlValue) -> Result<Self, Self::Error> {
+        AnyValue::map_from::<MySql>(value.as_ref())
+    }
+}
+
+impl<'a> TryFrom<&'a AnyValue> for MySqlValue {
+    type Error = AnyValueError;
+
+    fn try_from(value: &'a AnyValue) -> Result<Self, Self::Error> {
+        match &value.kind {
+            AnyValueKind::Null => Ok(MySqlValue::binary(
+                None,
+                MySqlTypeInfo::binary(ColumnType::Null),
+            )),
+            AnyValueKind::Bool(b) => encode_value(*b),
+            AnyValueKind::SmallInt(i) => encode_value(*i),
+            AnyValueKind::Integer(i) => encode_value(*i),
+            AnyValueKind::BigInt(i) => encode_value(*i),
+            AnyValueKind::Real(r) => encode_value(*r),
+            AnyValueKind::Double(d) => encode_value(*d),
+            AnyValueKind::Text(t) => encode_value(&**t),
+            AnyValueKind::Blob(b) => encode_value(&**b),
+            AnyValueKind::Decimal(d) => encode_value(AnyDecimal(d.to_string())),
+            AnyValueKind::Date(d) => encode_value(*d),
+            AnyValueKind::Time(t) => encode_value(*t),
+            AnyValueKind::Timestamp(t) => encode_value(*t),
+            AnyValueKind::Uuid(u) => encode_value(*u),
+            _ => Err(AnyValueError::UnsupportedKind {
+                database: MySql::NAME,
+                type_info: value.type_info().into_owned(),
+            }),
+        }
+    }
+}
+
+fn encode_value<'q, T>(value: T) -> Result<MySqlValue, AnyValueError>
+where
+    T: Encode<'q, MySql> + Type<MySql>,
+{
+    let type_info = value.produces().unwrap_or_else(T::type_info);
+
+    let mut buf = Vec::new();
+    let is_null = value.encode(&mut buf).map_err(AnyValueError::Encode)?;
+
+    let value = match is_null {
+        IsNull::Yes => None,
+        IsNull::No => {
+            let mut value = Bytes::from(buf);
+
+            // strings and blobs are length-prefixed as arguments, but rows store them without it
+            if !matches!(
+                type_info.r#type,
+                ColumnType::Tiny
+                    | ColumnType::Short
+                    | ColumnType::Long
+                    | ColumnType::Int24
+                    | ColumnType::LongLong
+                    | ColumnType::Year
+                    | ColumnType::Float
+                    | ColumnType::Double
+                    | ColumnType::Date
+                    | ColumnType::Time
+                    | ColumnType::Datetime
+                    | ColumnType::Timestamp
+            ) {
+                let len = value.get_uint_lenenc() as usize;
+                value.truncate(len);
+            }
+
+            Some(value)
+        }
+    };
+
+    Ok(MySqlValue::binary(value, type_info))
+}
+
 impl<'a> TryFrom<&'a AnyConnectOptions> for MySqlConnectOptions {
     type Error = sqlx_core::Error;
 
     fn try_from(any_opts: &'a AnyConnectOptions) -> Result<Self, Self::Error> {
         let mut opts = Self::parse_from_url(&any_opts.database_url)?;
+        if let Some(socket) = &any_opts.socket {
+            opts = opts.socket(socket);
+        }
+        for command in &any_opts.init_commands {
+            opts = opts.init_command(command.clone());
+        }
         opts.log_settings = any_opts.log_settings.clone();
         Ok(opts)
     }
@@ -214,3 +344,58 @@ fn map_result(result: MySqlQueryResult) -> AnyQueryResult {
         last_insert_id: Some(result.last_insert_id as i64),
     }
 }
+
+#[test]
+fn it_converts_values_to_and_from_any() {
+    use sqlx_core::any::{AnyDate, AnyTime, AnyTimestamp, AnyUuid};
+    use std::borrow::Cow;
+
+    let kinds = [
+        AnyValueKind::Null,
+        AnyValueKind::SmallInt(-2),
+        AnyValueKind::Integer(70_000),
+        AnyValueKind::BigInt(1 << 40),
+        AnyValueKind::Real(0.5),
+        AnyValueKind::Double(-1.25),
+        AnyValueKind::Text(Cow::Borrowed("hello")),
+        AnyValueKind::Blob(Cow::Borrowed(b"\x00\x01")),
+        AnyValueKind::Decimal(Cow::Borrowed("-12.50")),
+        AnyValueKind::Date(AnyDate(19_844)),
+        AnyValueKind::Time(AnyTime(45_005_250_000)),
+        AnyValueKind::Timestamp(AnyTimestamp(1_714_567_890_123_456)),
+    ];
+
+    for kind in kinds {
+        let expected = format!("{kind:?}");
+
+        let mysql_value = MySqlValue::try_from(&AnyValue { kind }).unwrap();
+        let any_value = AnyValue::try_from(&mysql_value).unwrap();
+
+        assert_eq!(format!("{:?}", any_value.kind), expected);
+    }
+
+    // MySQL has no UUID type, so they come back as the 16 bytes of a `BINARY(16)`
+    let uuid = MySqlValue::try_from(&AnyValue {
+        kind: AnyValueKind::Uuid(AnyUuid(*b"0123456789abcdef")),
+    })
+    .unwrap();
+
+    assert!(matches!(
+        AnyValue::try_from(&uuid).unwrap().kind,
+        AnyValueKind::Blob(b) if *b == *b"0123456789abcdef"
+    ));
+
+    // `BOOLEAN` is a `TINYINT`, which the Any driver does not support
+    let boolean = MySqlValue::try_from(&AnyValue {
+        kind: AnyValueKind::Bool(true),
+    })
+    .unwrap();
+
+    assert!(matches!(
+        AnyValue::try_from(&boolean),
+        Err(AnyValueError::UnsupportedType {
+            database: "MySQL",
+            ref type_name,
+        }) if type_name == "BOOLEAN"
+    ));
+}
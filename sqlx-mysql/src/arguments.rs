@@ -11,6 +11,15 @@ pub struct MySqlArguments {
     pub(crate) values: Vec<u8>,
     pub(crate) types: Vec<MySqlTypeInfo>,
     pub(crate) null_bitmap: NullBitMap,
+    pub(crate) attributes: Vec<MySqlQueryAttribute>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct MySqlQueryAttribute {
+    pub(crate) name: String,
+    pub(crate) type_info: MySqlTypeInfo,
+    // `None` if the value is NULL
+    pub(crate) value: Option<Vec<u8>>,
 }
 
 impl MySqlArguments {
@@ -35,6 +44,47 @@ impl MySqlArguments {
 
         Ok(())
     }
+
+    pub(crate) fn add_attribute<'q, T>(&mut self, name: &str, value: T) -> Result<(), BoxDynError>
+    where
+        T: Encode<'q, MySql> + Type<MySql>,
+    {
+        let type_info = value.produces().unwrap_or_else(T::type_info);
+
+        let mut buf = Vec::new();
+        let value = match value.encode(&mut buf)? {
+            IsNull::No => Some(buf),
+            IsNull::Yes => None,
+        };
+
+        self.attributes.push(MySqlQueryAttribute {
+            name: name.to_owned(),
+            type_info,
+            value,
+        });
+
+        Ok(())
+    }
+
+    // The NULL bitmap of the parameters followed by the query attributes.
+    pub(crate) fn null_bitmap_with_attributes(&self) -> NullBitMap {
+        let mut null_bitmap = NullBitMap::default();
+
+        for index in 0..self.null_bitmap.length {
+            let is_null = self.null_bitmap.bytes[index / 8] & (1 << (index % 8)) != 0;
+            null_bitmap.push(if is_null { IsNull::Yes } else { IsNull::No });
+        }
+
+        for attribute in &self.attributes {
+            null_bitmap.push(if attribute.value.is_none() {
+                IsNull::Yes
+            } else {
+                IsNull::No
+            });
+        }
+
+        null_bitmap
+    }
 }
 
 impl<'q> Arguments<'q> for MySqlArguments {
@@ -52,9 +102,20 @@ impl<'q> Arguments<'q> for MySqlArguments {
         self.add(value)
     }
 
+    fn add_attribute<T>(&mut self, name: &str, value: T) -> Result<(), BoxDynError>
+    where
+        T: Encode<'q, Self::Database> + Type<Self::Database>,
+    {
+        self.add_attribute(name, value)
+    }
+
     fn len(&self) -> usize {
         self.types.len()
     }
+
+    fn private_try_clone(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -9,10 +9,10 @@ use sha2::Sha256;
 
 use crate::connection::stream::MySqlStream;
 use crate::error::Error;
-use crate::protocol::auth::AuthPlugin;
+use crate::protocol::auth::MySqlAuthPlugin;
 use crate::protocol::Packet;
 
-impl AuthPlugin {
+impl MySqlAuthPlugin {
     pub(super) async fn scramble(
         self,
         stream: &mut MySqlStream,
@@ -21,14 +21,14 @@ impl AuthPlugin {
     ) -> Result<Vec<u8>, Error> {
         match self {
             // https://mariadb.com/kb/en/caching_sha2_password-authentication-plugin/
-            AuthPlugin::CachingSha2Password => Ok(scramble_sha256(password, nonce).to_vec()),
+            MySqlAuthPlugin::CachingSha2Password => Ok(scramble_sha256(password, nonce).to_vec()),
 
-            AuthPlugin::MySqlNativePassword => Ok(scramble_sha1(password, nonce).to_vec()),
+            MySqlAuthPlugin::MySqlNativePassword => Ok(scramble_sha1(password, nonce).to_vec()),
 
             // https://mariadb.com/kb/en/sha256_password-plugin/
-            AuthPlugin::Sha256Password => encrypt_rsa(stream, 0x01, password, nonce).await,
+            MySqlAuthPlugin::Sha256Password => encrypt_rsa(stream, 0x01, password, nonce).await,
 
-            AuthPlugin::MySqlClearPassword => {
+            MySqlAuthPlugin::MySqlClearPassword => {
                 let mut pw_bytes = password.as_bytes().to_owned();
                 pw_bytes.push(0); // null terminate
                 Ok(pw_bytes)
@@ -44,7 +44,7 @@ impl AuthPlugin {
         nonce: &Chain<Bytes, Bytes>,
     ) -> Result<bool, Error> {
         match self {
-            AuthPlugin::CachingSha2Password if packet[0] == 0x01 => {
+            MySqlAuthPlugin::CachingSha2Password if packet[0] == 0x01 => {
                 match packet[1] {
                     // AUTH_OK
                     0x03 => Ok(true),
@@ -11,11 +11,17 @@ use crate::protocol::connect::{
     AuthSwitchRequest, AuthSwitchResponse, Handshake, HandshakeResponse,
 };
 use crate::protocol::Capabilities;
-use crate::{MySqlConnectOptions, MySqlConnection, MySqlSslMode};
+use crate::{MySqlArguments, MySqlAuthPlugin, MySqlConnectOptions, MySqlConnection, MySqlSslMode};
 
 impl MySqlConnection {
     pub(crate) async fn establish(options: &MySqlConnectOptions) -> Result<Self, Error> {
-        let do_handshake = DoHandshake::new(options)?;
+        // fetched before connecting, so the server doesn't wait on the callback
+        let password = match &options.password_callback {
+            Some(callback) => Some((callback.0)().await.map_err(Error::Configuration)?),
+            None => options.password.clone(),
+        };
+
+        let do_handshake = DoHandshake::new(options, password)?;
 
         let handshake = match &options.socket {
             Some(path) => crate::net::connect_uds(path, do_handshake).await?,
@@ -24,12 +30,24 @@ impl MySqlConnection {
 
         let stream = handshake.await?;
 
+        let mut query_attributes = MySqlArguments::default();
+
+        for (name, value) in &options.query_attributes {
+            query_attributes
+                .add_attribute(name, value.as_str())
+                .map_err(Error::Encode)?;
+        }
+
         Ok(Self {
             inner: Box::new(MySqlConnectionInner {
                 stream,
                 transaction_depth: 0,
+                statement_timeout: None,
+                statement_timeout_override: None,
                 cache_statement: StatementCache::new(options.statement_cache_capacity),
                 log_settings: options.log_settings.clone(),
+                strict_bools: options.strict_bools,
+                query_attributes: query_attributes.attributes,
             }),
         })
     }
@@ -37,12 +55,13 @@ impl MySqlConnection {
 
 struct DoHandshake<'a> {
     options: &'a MySqlConnectOptions,
+    password: Option<String>,
     charset: CharSet,
     collation: Collation,
 }
 
 impl<'a> DoHandshake<'a> {
-    fn new(options: &'a MySqlConnectOptions) -> Result<Self, Error> {
+    fn new(options: &'a MySqlConnectOptions, password: Option<String>) -> Result<Self, Error> {
         let charset: CharSet = options.charset.parse()?;
         let collation: Collation = options
             .collation
@@ -51,7 +70,7 @@ impl<'a> DoHandshake<'a> {
             .transpose()?
             .unwrap_or_else(|| charset.default_collation());
 
-        if options.enable_cleartext_plugin
+        if options.is_auth_plugin_allowed(MySqlAuthPlugin::MySqlClearPassword)
             && matches!(
                 options.ssl_mode,
                 MySqlSslMode::Disabled | MySqlSslMode::Preferred
@@ -62,6 +81,7 @@ impl<'a> DoHandshake<'a> {
 
         Ok(Self {
             options,
+            password,
             charset,
             collation,
         })
@@ -70,6 +90,7 @@ impl<'a> DoHandshake<'a> {
     async fn do_handshake<S: Socket>(self, socket: S) -> Result<MySqlStream, Error> {
         let DoHandshake {
             options,
+            password,
             charset,
             collation,
         } = self;
@@ -81,13 +102,29 @@ impl<'a> DoHandshake<'a> {
 
         let handshake: Handshake = stream.recv_packet().await?.decode()?;
 
-        let mut plugin = handshake.auth_plugin;
+        // offer the first allowed plugin instead of one that isn't
+        let mut plugin = handshake.auth_plugin.and_then(|plugin| {
+            if options.is_auth_plugin_allowed(plugin) {
+                Some(plugin)
+            } else {
+                options.auth_plugins.as_ref()?.first().copied()
+            }
+        });
         let nonce = handshake.auth_plugin_data;
 
         // FIXME: server version parse is a bit ugly
         // expecting MAJOR.MINOR.PATCH
 
-        let mut server_version = handshake.server_version.split('.');
+        // e.g. `5.5.5-10.4.7-MariaDB-1:10.4.7+maria~bionic`; the `5.5.5-` prefix was sent for
+        // compatibility with old clients before MariaDB 11
+        let is_mariadb = handshake.server_version.contains("MariaDB");
+
+        let mut server_version = if is_mariadb {
+            handshake.server_version.trim_start_matches("5.5.5-")
+        } else {
+            &handshake.server_version
+        }
+        .split('.');
 
         let server_version_major: u16 = server_version
             .next()
@@ -112,13 +149,14 @@ impl<'a> DoHandshake<'a> {
             server_version_minor,
             server_version_patch,
         );
+        stream.is_mariadb = is_mariadb;
 
         stream.capabilities &= handshake.server_capabilities;
         stream.capabilities |= Capabilities::PROTOCOL_41;
 
-        let mut stream = tls::maybe_upgrade(stream, self.options).await?;
+        let mut stream = tls::maybe_upgrade(stream, options).await?;
 
-        let auth_response = if let (Some(plugin), Some(password)) = (plugin, &options.password) {
+        let auth_response = if let (Some(plugin), Some(password)) = (plugin, &password) {
             Some(plugin.scramble(&mut stream, password, &nonce).await?)
         } else {
             None
@@ -145,19 +183,23 @@ impl<'a> DoHandshake<'a> {
                 }
 
                 0xfe => {
-                    let switch: AuthSwitchRequest =
-                        packet.decode_with(self.options.enable_cleartext_plugin)?;
+                    let switch: AuthSwitchRequest = packet.decode_with(
+                        options.is_auth_plugin_allowed(MySqlAuthPlugin::MySqlClearPassword),
+                    )?;
+
+                    if !options.is_auth_plugin_allowed(switch.plugin) {
+                        return Err(err_protocol!(
+                            "server requested auth plugin '{}', which is not allowed",
+                            switch.plugin.name()
+                        ));
+                    }
 
                     plugin = Some(switch.plugin);
                     let nonce = switch.data.chain(Bytes::new());
 
                     let response = switch
                         .plugin
-                        .scramble(
-                            &mut stream,
-                            options.password.as_deref().unwrap_or_default(),
-                            &nonce,
-                        )
+                        .scramble(&mut stream, password.as_deref().unwrap_or_default(), &nonce)
                         .await?;
 
                     stream.write_packet(AuthSwitchResponse(response));
@@ -165,7 +207,7 @@ impl<'a> DoHandshake<'a> {
                 }
 
                 id => {
-                    if let (Some(plugin), Some(password)) = (plugin, &options.password) {
+                    if let (Some(plugin), Some(password)) = (plugin, &password) {
                         if plugin.handle(&mut stream, packet, password, &nonce).await? {
                             // plugin signaled authentication is ok
                             break;
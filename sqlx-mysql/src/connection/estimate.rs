@@ -0,0 +1,66 @@
+use sqlx_core::connection::private_is_query;
+
+use crate::error::Error;
+use crate::executor::Executor;
+use crate::row::Row;
+use crate::{MySqlConnection, MySqlRow};
+
+impl MySqlConnection {
+    /// See [`Connection::estimate_rows()`][crate::connection::Connection::estimate_rows].
+    pub(super) async fn estimate_rows_of(&mut self, table_or_query: &str) -> Result<u64, Error> {
+        let explain = if private_is_query(table_or_query) {
+            format!("EXPLAIN {table_or_query}")
+        } else {
+            let table = table_or_query
+                .split('.')
+                .map(|name| format!("`{}`", name.replace('`', "``")))
+                .collect::<Vec<_>>()
+                .join(".");
+
+            format!("EXPLAIN SELECT 1 FROM {table}")
+        };
+
+        // sent without arguments so this uses the text protocol and every value is a string
+        let rows = self.fetch_all(&*explain).await?;
+
+        estimate_from_explain(&rows)
+    }
+}
+
+/// The tables of the outermost `SELECT` are joined in nested loops, so each table in the plan
+/// multiplies the number of rows by the rows it's expected to produce for every row before it.
+fn estimate_from_explain(rows: &[MySqlRow]) -> Result<u64, Error> {
+    let mut estimate = 1.0;
+
+    for row in rows {
+        if row.try_get_unchecked::<Option<String>, _>("id")?.as_deref() != Some("1") {
+            continue;
+        }
+
+        // NULL when no table is read, e.g. for `SELECT 1`
+        let Some(table_rows) = row.try_get_unchecked::<Option<String>, _>("rows")? else {
+            continue;
+        };
+
+        // MariaDB doesn't have this column without `EXPLAIN EXTENDED`
+        let filtered = match row.try_get_unchecked::<Option<String>, _>("filtered") {
+            Ok(filtered) => filtered,
+            Err(Error::ColumnNotFound(_)) => None,
+            Err(e) => return Err(e),
+        };
+
+        estimate *= parse_number(&table_rows)?;
+
+        if let Some(filtered) = filtered {
+            estimate *= parse_number(&filtered)? / 100.0;
+        }
+    }
+
+    Ok(estimate.round() as u64)
+}
+
+fn parse_number(value: &str) -> Result<f64, Error> {
+    value
+        .parse()
+        .map_err(|_| err_protocol!("expected a number in the output of EXPLAIN, got {value:?}"))
+}
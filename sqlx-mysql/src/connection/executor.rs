@@ -1,7 +1,7 @@
 use super::MySqlStream;
 use crate::connection::stream::Waiting;
 use crate::describe::Describe;
-use crate::error::Error;
+use crate::error::{error_codes, DatabaseError, Error, MySqlDatabaseError};
 use crate::executor::{Execute, Executor};
 use crate::ext::ustr::UStr;
 use crate::io::MySqlBufExt;
@@ -11,6 +11,7 @@ use crate::protocol::statement::{
     BinaryRow, Execute as StatementExecute, Prepare, PrepareOk, StmtClose,
 };
 use crate::protocol::text::{ColumnDefinition, ColumnFlags, Query, TextRow};
+use crate::protocol::Capabilities;
 use crate::statement::{MySqlStatement, MySqlStatementMetadata};
 use crate::HashMap;
 use crate::{
@@ -22,6 +23,7 @@ use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 use futures_core::Stream;
 use futures_util::{pin_mut, TryStreamExt};
+use std::time::Duration;
 use std::{borrow::Cow, sync::Arc};
 
 impl MySqlConnection {
@@ -39,12 +41,17 @@ impl MySqlConnection {
 
         let ok: PrepareOk = self.inner.stream.recv().await?;
 
-        // the parameter definitions are very unreliable so we skip over them
-        // as we have little use
+        // the parameter definitions are very unreliable, as the server reports a generic type
+        // for any parameter whose type it can't infer, so we only expose them through
+        // `Statement::parameters()` and don't type check against them
+
+        let mut parameters = Vec::with_capacity(ok.params as usize);
 
         if ok.params > 0 {
             for _ in 0..ok.params {
-                let _def: ColumnDefinition = self.inner.stream.recv().await?;
+                let def: ColumnDefinition = self.inner.stream.recv().await?;
+
+                parameters.push(MySqlTypeInfo::from_column(&def));
             }
 
             self.inner.stream.maybe_recv_eof().await?;
@@ -64,7 +71,7 @@ impl MySqlConnection {
 
         let id = ok.statement_id;
         let metadata = MySqlStatementMetadata {
-            parameters: ok.params as usize,
+            parameters: Arc::new(parameters),
             columns: Arc::new(columns),
             column_names: Arc::new(column_names),
         };
@@ -98,147 +105,297 @@ impl MySqlConnection {
         Ok((id, metadata))
     }
 
+    // The timeout of a query overrides the connection's and is left in place afterwards, so
+    // that consecutive queries with the same timeout do not need an extra round-trip. It is
+    // restored before the next query without a timeout.
+    async fn apply_statement_timeout(&mut self, timeout: Option<Duration>) -> Result<(), Error> {
+        let current = self
+            .inner
+            .statement_timeout_override
+            .or(self.inner.statement_timeout);
+        let target = timeout.or(self.inner.statement_timeout);
+
+        if target != current {
+            // `DEFAULT` restores the global value
+            let sql = self.set_statement_timeout_sql(target, "DEFAULT");
+
+            self.inner.stream.waiting.push_back(Waiting::Result);
+            self.inner.stream.send_packet(Query(&sql, &[])).await?;
+            self.inner.stream.wait_until_ready().await?;
+        }
+
+        self.inner.statement_timeout_override =
+            timeout.filter(|_| timeout != self.inner.statement_timeout);
+
+        Ok(())
+    }
+
+    // Execute `sql` with the text protocol and get the columns of its first result set.
+    async fn describe_with_text_protocol(&mut self, sql: &str) -> Result<Vec<MySqlColumn>, Error> {
+        self.inner.stream.waiting.push_back(Waiting::Result);
+        self.inner.stream.send_packet(Query(sql, &[])).await?;
+
+        let mut packet = self.inner.stream.recv_packet().await?;
+        let mut columns = Vec::new();
+
+        if packet[0] == 0x00 {
+            // no result set at all
+            let ok = packet.ok()?;
+
+            if !ok.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
+                self.inner.stream.waiting.pop_front();
+            }
+        } else {
+            *self.inner.stream.waiting.front_mut().unwrap() = Waiting::Row;
+
+            let num_columns = packet.get_uint_lenenc() as usize; // column count
+
+            recv_result_metadata(&mut self.inner.stream, num_columns, &mut columns).await?;
+        }
+
+        // skip the rows, and any further result sets
+        self.inner.stream.wait_until_ready().await?;
+
+        Ok(columns)
+    }
+
     #[allow(clippy::needless_lifetimes)]
     pub(crate) async fn run<'e, 'c: 'e, 'q: 'e>(
         &'c mut self,
         sql: &'q str,
         arguments: Option<MySqlArguments>,
         persistent: bool,
+        timeout: Option<Duration>,
     ) -> Result<impl Stream<Item = Result<Either<MySqlQueryResult, MySqlRow>, Error>> + 'e, Error>
     {
-        let mut logger = QueryLogger::new(sql, self.inner.log_settings.clone());
+        let num_arguments = arguments
+            .as_ref()
+            .map_or(0, |arguments| arguments.types.len());
+        let mut logger = QueryLogger::new(sql, num_arguments, self.inner.log_settings.clone());
+
+        // the attributes of the connection are sent before those of the query
+        let mut arguments = arguments;
+        let mut attributes = self.inner.query_attributes.clone();
+
+        if let Some(arguments) = &mut arguments {
+            attributes.append(&mut arguments.attributes);
+            arguments.attributes.clone_from(&attributes);
+        }
 
-        self.inner.stream.wait_until_ready().await?;
-        self.inner.stream.waiting.push_back(Waiting::Result);
+        let has_attributes = !attributes.is_empty();
 
-        Ok(Box::pin(try_stream! {
-            // make a slot for the shared column data
-            // as long as a reference to a row is not held past one iteration, this enables us
-            // to re-use this memory freely between result sets
-            let mut columns = Arc::new(Vec::new());
-
-            let (mut column_names, format, mut needs_metadata) = if let Some(arguments) = arguments {
-                if persistent && self.inner.cache_statement.is_enabled() {
-                    let (id, metadata) = self
-                        .get_or_prepare_statement(sql)
-                        .await?;
+        if has_attributes
+            && !self
+                .inner
+                .stream
+                .capabilities
+                .contains(Capabilities::QUERY_ATTRIBUTES)
+        {
+            return logger.record(Err(Error::Protocol(
+                "the server does not support query attributes (MySQL 8.0.23 or later is required)"
+                    .into(),
+            )));
+        }
 
-                    // https://dev.mysql.com/doc/internals/en/com-stmt-execute.html
-                    self.inner.stream
-                        .send_packet(StatementExecute {
-                            statement: id,
-                            arguments: &arguments,
-                        })
-                        .await?;
+        logger.record(self.inner.stream.wait_until_ready().await)?;
+        logger.record(self.apply_statement_timeout(timeout).await)?;
+        self.inner.stream.waiting.push_back(Waiting::Result);
 
-                    (metadata.column_names, MySqlValueFormat::Binary, false)
+        Ok(Box::pin(try_stream! {
+            let result = async {
+                // make a slot for the shared column data
+                // as long as a reference to a row is not held past one iteration, this enables us
+                // to re-use this memory freely between result sets
+                let mut columns = Arc::new(Vec::new());
+
+                let (mut column_names, format, mut needs_metadata) = if let Some(arguments) = arguments {
+                    if persistent && self.inner.cache_statement.is_enabled() {
+                        let (id, metadata) = self
+                            .get_or_prepare_statement(sql)
+                            .await?;
+
+                        // https://dev.mysql.com/doc/internals/en/com-stmt-execute.html
+                        self.inner.stream
+                            .send_packet(StatementExecute {
+                                statement: id,
+                                arguments: &arguments,
+                            })
+                            .await?;
+
+                        (metadata.column_names, MySqlValueFormat::Binary, false)
+                    } else {
+                        let (id, metadata) = self
+                            .prepare_statement(sql)
+                            .await?;
+
+                        // https://dev.mysql.com/doc/internals/en/com-stmt-execute.html
+                        self.inner.stream
+                            .send_packet(StatementExecute {
+                                statement: id,
+                                arguments: &arguments,
+                            })
+                            .await?;
+
+                        self.inner.stream.send_packet(StmtClose { statement: id }).await?;
+
+                        (metadata.column_names, MySqlValueFormat::Binary, false)
+                    }
                 } else {
-                    let (id, metadata) = self
-                        .prepare_statement(sql)
-                        .await?;
+                    // https://dev.mysql.com/doc/internals/en/com-query.html
+                    self.inner.stream.send_packet(Query(sql, &attributes)).await?;
 
-                    // https://dev.mysql.com/doc/internals/en/com-stmt-execute.html
-                    self.inner.stream
-                        .send_packet(StatementExecute {
-                            statement: id,
-                            arguments: &arguments,
-                        })
-                        .await?;
+                    (Arc::default(), MySqlValueFormat::Text, true)
+                };
 
-                    self.inner.stream.send_packet(StmtClose { statement: id }).await?;
+                loop {
+                    // query response is a meta-packet which may be one of:
+                    //  Ok, Err, ResultSet, or (unhandled) LocalInfileRequest
+                    let mut packet = self.inner.stream.recv_packet().await?;
+
+                    if packet[0] == 0x00 || packet[0] == 0xff {
+                        // first packet in a query response is OK or ERR
+                        // this indicates either a successful query with no rows at all or a failed query
+                        let ok = packet.ok()?;
+
+                        let rows_affected = ok.affected_rows;
+                        logger.increase_rows_affected(rows_affected);
+                        let done = MySqlQueryResult {
+                            rows_affected,
+                            last_insert_id: ok.last_insert_id,
+                            session_state_changes: ok.session_state_changes,
+                        };
+
+                        r#yield!(Either::Left(done));
+
+                        if ok.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
+                            // more result sets exist, continue to the next one
+                            continue;
+                        }
 
-                    (metadata.column_names, MySqlValueFormat::Binary, false)
-                }
-            } else {
-                // https://dev.mysql.com/doc/internals/en/com-query.html
-                self.inner.stream.send_packet(Query(sql)).await?;
+                        self.inner.stream.waiting.pop_front();
+                        return Ok(());
+                    }
 
-                (Arc::default(), MySqlValueFormat::Text, true)
-            };
+                    // otherwise, this first packet is the start of the result-set metadata,
+                    *self.inner.stream.waiting.front_mut().unwrap() = Waiting::Row;
 
-            loop {
-                // query response is a meta-packet which may be one of:
-                //  Ok, Err, ResultSet, or (unhandled) LocalInfileRequest
-                let mut packet = self.inner.stream.recv_packet().await?;
+                    let num_columns = packet.get_uint_lenenc() as usize; // column count
 
-                if packet[0] == 0x00 || packet[0] == 0xff {
-                    // first packet in a query response is OK or ERR
-                    // this indicates either a successful query with no rows at all or a failed query
-                    let ok = packet.ok()?;
+                    if needs_metadata {
+                        column_names = Arc::new(recv_result_metadata(&mut self.inner.stream, num_columns, Arc::make_mut(&mut columns)).await?);
+                    } else {
+                        // next time we hit here, it'll be a new result set and we'll need the
+                        // full metadata
+                        needs_metadata = true;
 
-                    let rows_affected = ok.affected_rows;
-                    logger.increase_rows_affected(rows_affected);
-                    let done = MySqlQueryResult {
-                        rows_affected,
-                        last_insert_id: ok.last_insert_id,
-                    };
+                        recv_result_columns(&mut self.inner.stream, num_columns, Arc::make_mut(&mut columns)).await?;
+                    }
 
-                    r#yield!(Either::Left(done));
+                    // finally, there will be none or many result-rows
+                    loop {
+                        let packet = self.inner.stream.recv_packet().await?;
 
-                    if ok.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
-                        // more result sets exist, continue to the next one
-                        continue;
-                    }
+                        if packet[0] == 0xfe && packet.len() < 9 {
+                            let eof = packet.eof(self.inner.stream.capabilities)?;
 
-                    self.inner.stream.waiting.pop_front();
-                    return Ok(());
-                }
+                            r#yield!(Either::Left(MySqlQueryResult::default()));
 
-                // otherwise, this first packet is the start of the result-set metadata,
-                *self.inner.stream.waiting.front_mut().unwrap() = Waiting::Row;
+                            if eof.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
+                                // more result sets exist, continue to the next one
+                                *self.inner.stream.waiting.front_mut().unwrap() = Waiting::Result;
+                                break;
+                            }
 
-                let num_columns = packet.get_uint_lenenc() as usize; // column count
+                            self.inner.stream.waiting.pop_front();
+                            return Ok(());
+                        }
 
-                if needs_metadata {
-                    column_names = Arc::new(recv_result_metadata(&mut self.inner.stream, num_columns, Arc::make_mut(&mut columns)).await?);
-                } else {
-                    // next time we hit here, it'll be a new result set and we'll need the
-                    // full metadata
-                    needs_metadata = true;
+                        let row = match format {
+                            MySqlValueFormat::Binary => packet.decode_with::<BinaryRow, _>(&columns)?.0,
+                            MySqlValueFormat::Text => packet.decode_with::<TextRow, _>(&columns)?.0,
+                        };
 
-                    recv_result_columns(&mut self.inner.stream, num_columns, Arc::make_mut(&mut columns)).await?;
-                }
+                        let v = Either::Right(MySqlRow {
+                            row,
+                            format,
+                            columns: Arc::clone(&columns),
+                            column_names: Arc::clone(&column_names),
+                            strict_bools: self.inner.strict_bools,
+                        });
 
-                // finally, there will be none or many result-rows
-                loop {
-                    let packet = self.inner.stream.recv_packet().await?;
+                        logger.increment_rows_returned();
 
-                    if packet[0] == 0xfe && packet.len() < 9 {
-                        let eof = packet.eof(self.inner.stream.capabilities)?;
+                        r#yield!(v);
+                    }
+                }
+            }
+            .await;
 
-                        r#yield!(Either::Left(MySqlQueryResult {
-                            rows_affected: 0,
-                            last_insert_id: 0,
-                        }));
+            logger.record(result)
+        }))
+    }
 
-                        if eof.status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
-                            // more result sets exist, continue to the next one
-                            *self.inner.stream.waiting.front_mut().unwrap() = Waiting::Result;
-                            break;
-                        }
+    /// Execute a string of one or more statements, separated by semicolons, with the text
+    /// protocol, and return the results of each statement in turn.
+    ///
+    /// Each item of the stream holds the rows returned by one statement, if any, followed by its
+    /// [`MySqlQueryResult`].
+    ///
+    /// The statements run one after the other, and the first statement to fail ends the stream
+    /// with its error. Statements cannot take arguments.
+    ///
+    /// Returns an error if multi-statements were disabled with
+    /// [`MySqlConnectOptions::multi_statements()`][crate::MySqlConnectOptions::multi_statements].
+    ///
+    /// ```rust,no_run
+    /// # async fn example(conn: &mut sqlx::MySqlConnection) -> sqlx::Result<()> {
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut results = conn.execute_many_statements(
+    ///     "CREATE TEMPORARY TABLE users (id INT); INSERT INTO users VALUES (1), (2); SELECT * FROM users",
+    /// );
+    ///
+    /// while let Some(result) = results.try_next().await? {
+    ///     // one `Vec` for each statement, in order
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn execute_many_statements<'e, 'q: 'e>(
+        &'e mut self,
+        sql: &'q str,
+    ) -> BoxStream<'e, Result<Vec<Either<MySqlQueryResult, MySqlRow>>, Error>> {
+        Box::pin(try_stream! {
+            if !self
+                .inner
+                .stream
+                .capabilities
+                .contains(Capabilities::MULTI_STATEMENTS)
+            {
+                return Err(Error::Configuration(
+                    "multi-statements are disabled; see `MySqlConnectOptions::multi_statements()`"
+                        .into(),
+                ));
+            }
 
-                        self.inner.stream.waiting.pop_front();
-                        return Ok(());
-                    }
+            let s = self.run(sql, None, false, None).await?;
+            pin_mut!(s);
 
-                    let row = match format {
-                        MySqlValueFormat::Binary => packet.decode_with::<BinaryRow, _>(&columns)?.0,
-                        MySqlValueFormat::Text => packet.decode_with::<TextRow, _>(&columns)?.0,
-                    };
+            let mut current = Vec::new();
 
-                    let v = Either::Right(MySqlRow {
-                        row,
-                        format,
-                        columns: Arc::clone(&columns),
-                        column_names: Arc::clone(&column_names),
-                    });
+            while let Some(v) = s.try_next().await? {
+                // every statement ends with exactly one result
+                let is_result = v.is_left();
 
-                    logger.increment_rows_returned();
+                current.push(v);
 
-                    r#yield!(v);
+                if is_result {
+                    r#yield!(std::mem::take(&mut current));
                 }
             }
-        }))
+
+            Ok(())
+        })
     }
 }
 
@@ -258,10 +415,11 @@ impl<'c> Executor<'c> for &'c mut MySqlConnection {
         let sql = query.sql();
         let arguments = query.take_arguments().map_err(Error::Encode);
         let persistent = query.persistent();
+        let timeout = query.timeout();
 
         Box::pin(try_stream! {
             let arguments = arguments?;
-            let s = self.run(sql, arguments, persistent).await?;
+            let s = self.run(sql, arguments, persistent, timeout).await?;
             pin_mut!(s);
 
             while let Some(v) = s.try_next().await? {
@@ -332,14 +490,26 @@ impl<'c> Executor<'c> for &'c mut MySqlConnection {
         Box::pin(async move {
             self.inner.stream.wait_until_ready().await?;
 
-            let (id, metadata) = self.prepare_statement(sql).await?;
+            let (parameters, columns) = match self.prepare_statement(sql).await {
+                Ok((id, metadata)) => {
+                    self.inner
+                        .stream
+                        .send_packet(StmtClose { statement: id })
+                        .await?;
 
-            self.inner
-                .stream
-                .send_packet(StmtClose { statement: id })
-                .await?;
+                    (metadata.parameters.len(), (*metadata.columns).clone())
+                }
+
+                // some statements, such as certain `SHOW` statements, can only be executed
+                // with the text protocol; those that only read are executed to get their columns
+                Err(Error::Database(e))
+                    if is_unsupported_by_prepare(&*e) && is_read_only_statement(sql) =>
+                {
+                    (0, self.describe_with_text_protocol(sql).await?)
+                }
 
-            let columns = (*metadata.columns).clone();
+                Err(e) => return Err(e),
+            };
 
             let nullable = columns
                 .iter()
@@ -350,7 +520,7 @@ impl<'c> Executor<'c> for &'c mut MySqlConnection {
                 .collect();
 
             Ok(Describe {
-                parameters: Some(Either::Right(metadata.parameters)),
+                parameters: Some(Either::Right(parameters)),
                 columns,
                 nullable,
             })
@@ -358,6 +528,43 @@ impl<'c> Executor<'c> for &'c mut MySqlConnection {
     }
 }
 
+fn is_unsupported_by_prepare(error: &dyn DatabaseError) -> bool {
+    error
+        .try_downcast_ref::<MySqlDatabaseError>()
+        .is_some_and(|error| error.number() == error_codes::ER_UNSUPPORTED_PS)
+}
+
+// Whether `sql` is a statement that is safe to execute to describe it,
+// by the first keyword after any leading whitespace and comments.
+fn is_read_only_statement(sql: &str) -> bool {
+    let mut sql = sql.trim_start();
+
+    loop {
+        if let Some(rest) = sql.strip_prefix("--").or_else(|| sql.strip_prefix('#')) {
+            sql = rest
+                .split_once('\n')
+                .map_or("", |(_, rest)| rest)
+                .trim_start();
+        } else if let Some(rest) = sql.strip_prefix("/*") {
+            sql = rest
+                .split_once("*/")
+                .map_or("", |(_, rest)| rest)
+                .trim_start();
+        } else {
+            break;
+        }
+    }
+
+    let keyword = sql
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or_default();
+
+    ["SHOW", "DESCRIBE", "DESC", "EXPLAIN", "HELP"]
+        .iter()
+        .any(|read_only| keyword.eq_ignore_ascii_case(read_only))
+}
+
 async fn recv_result_columns(
     stream: &mut MySqlStream,
     num_columns: usize,
@@ -421,3 +628,26 @@ async fn recv_result_metadata(
 
     Ok(column_names)
 }
+
+#[test]
+fn it_detects_read_only_statements() {
+    for sql in [
+        "SHOW TABLES",
+        "  show create table users",
+        "-- comment\nDESCRIBE users",
+        "/* comment */ EXPLAIN SELECT 1",
+        "# comment\nHELP 'contents'",
+    ] {
+        assert!(is_read_only_statement(sql), "{sql:?}");
+    }
+
+    for sql in [
+        "",
+        "LOCK TABLES users READ",
+        "CREATE PROCEDURE p() SELECT 1",
+        "SHOWN",
+        "/* SHOW */ DROP TABLE users",
+    ] {
+        assert!(!is_read_only_statement(sql), "{sql:?}");
+    }
+}
@@ -1,12 +1,16 @@
+use std::cmp;
 use std::fmt::{self, Debug, Formatter};
+use std::time::{Duration, Instant};
 
 use futures_core::future::BoxFuture;
 use futures_util::FutureExt;
 pub(crate) use sqlx_core::connection::*;
 pub(crate) use stream::{MySqlStream, Waiting};
 
+use crate::arguments::MySqlQueryAttribute;
 use crate::common::StatementCache;
 use crate::error::Error;
+use crate::executor::Executor;
 use crate::protocol::statement::StmtClose;
 use crate::protocol::text::{Ping, Quit};
 use crate::statement::MySqlStatementMetadata;
@@ -15,6 +19,7 @@ use crate::{MySql, MySqlConnectOptions};
 
 mod auth;
 mod establish;
+mod estimate;
 mod executor;
 mod stream;
 mod tls;
@@ -35,10 +40,22 @@ pub(crate) struct MySqlConnectionInner {
     // transaction status
     pub(crate) transaction_depth: usize,
 
+    // last value set with `Connection::set_statement_timeout()`
+    statement_timeout: Option<Duration>,
+
+    // timeout of the last query run with `Query::timeout()`, if it differs from the above
+    statement_timeout_override: Option<Duration>,
+
     // cache by query string to the statement id and metadata
     cache_statement: StatementCache<(u32, MySqlStatementMetadata)>,
 
     log_settings: LogSettings,
+
+    // set by `MySqlConnectOptions::strict_bools()`
+    strict_bools: bool,
+
+    // set by `MySqlConnectOptions::query_attribute()`, sent with every statement
+    query_attributes: Vec<MySqlQueryAttribute>,
 }
 
 impl Debug for MySqlConnection {
@@ -68,13 +85,16 @@ impl Connection for MySqlConnection {
         })
     }
 
-    fn ping(&mut self) -> BoxFuture<'_, Result<(), Error>> {
+    fn ping(&mut self) -> BoxFuture<'_, Result<Duration, Error>> {
         Box::pin(async move {
             self.inner.stream.wait_until_ready().await?;
+
+            let started_at = Instant::now();
+
             self.inner.stream.send_packet(Ping).await?;
             self.inner.stream.recv_ok().await?;
 
-            Ok(())
+            Ok(started_at.elapsed())
         })
     }
 
@@ -114,7 +134,69 @@ impl Connection for MySqlConnection {
         Transaction::begin(self)
     }
 
+    fn set_statement_timeout(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            // `0` disables the timeout
+            let sql = self.set_statement_timeout_sql(timeout, "0");
+
+            self.execute(&*sql).await?;
+            self.inner.statement_timeout = timeout;
+
+            Ok(())
+        })
+    }
+
+    fn statement_timeout(&self) -> Option<Duration> {
+        self.inner.statement_timeout
+    }
+
+    fn supports_statement_timeout(&self) -> bool {
+        true
+    }
+
+    fn estimate_rows<'e>(
+        &'e mut self,
+        table_or_query: &'e str,
+    ) -> BoxFuture<'e, Result<u64, Error>> {
+        Box::pin(self.estimate_rows_of(table_or_query))
+    }
+
     fn shrink_buffers(&mut self) {
         self.inner.stream.shrink_buffers();
     }
 }
+
+impl MySqlConnection {
+    // The statement setting the session's statement timeout, which is `max_statement_time` in
+    // seconds on MariaDB and `max_execution_time` in milliseconds on MySQL. `None` sets
+    // `value_for_none` instead, e.g. `0` to disable the timeout or `DEFAULT` to restore the
+    // global value.
+    pub(crate) fn set_statement_timeout_sql(
+        &self,
+        timeout: Option<Duration>,
+        value_for_none: &str,
+    ) -> String {
+        // a timeout rounded down to `0` would disable it instead
+        if self.inner.stream.is_mariadb {
+            let value = timeout.map_or_else(
+                || value_for_none.to_owned(),
+                |timeout| {
+                    let seconds = cmp::max(timeout, Duration::from_micros(1)).as_secs_f64();
+                    seconds.to_string()
+                },
+            );
+
+            format!("SET SESSION max_statement_time = {value}")
+        } else {
+            let value = timeout.map_or_else(
+                || value_for_none.to_owned(),
+                |timeout| cmp::max(timeout.as_millis(), 1).to_string(),
+            );
+
+            format!("SET SESSION max_execution_time = {value}")
+        }
+    }
+}
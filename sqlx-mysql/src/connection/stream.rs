@@ -16,6 +16,7 @@ pub struct MySqlStream<S = Box<dyn Socket>> {
     // Wrapping the socket in `Box` allows us to unsize in-place.
     pub(crate) socket: BufferedSocket<S>,
     pub(crate) server_version: (u16, u16, u16),
+    pub(crate) is_mariadb: bool,
     pub(super) capabilities: Capabilities,
     pub(crate) sequence_id: u8,
     pub(crate) waiting: VecDeque<Waiting>,
@@ -47,20 +48,29 @@ impl<S: Socket> MySqlStream<S> {
             | Capabilities::TRANSACTIONS
             | Capabilities::SECURE_CONNECTION
             | Capabilities::PLUGIN_AUTH_LENENC_DATA
-            | Capabilities::MULTI_STATEMENTS
             | Capabilities::MULTI_RESULTS
             | Capabilities::PLUGIN_AUTH
             | Capabilities::PS_MULTI_RESULTS
+            | Capabilities::QUERY_ATTRIBUTES
             | Capabilities::SSL;
 
         if options.database.is_some() {
             capabilities |= Capabilities::CONNECT_WITH_DB;
         }
 
+        if options.multi_statements {
+            capabilities |= Capabilities::MULTI_STATEMENTS;
+        }
+
+        if options.track_session_state {
+            capabilities |= Capabilities::SESSION_TRACK;
+        }
+
         Self {
             waiting: VecDeque::new(),
             capabilities,
             server_version: (0, 0, 0),
+            is_mariadb: false,
             sequence_id: 0,
             collation,
             charset,
@@ -217,6 +227,7 @@ impl<S: Socket> MySqlStream<S> {
         MySqlStream {
             socket: self.socket.boxed(),
             server_version: self.server_version,
+            is_mariadb: self.is_mariadb,
             capabilities: self.capabilities,
             sequence_id: self.sequence_id,
             waiting: self.waiting,
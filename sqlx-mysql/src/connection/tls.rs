@@ -10,6 +10,7 @@ use std::collections::VecDeque;
 
 struct MapStream {
     server_version: (u16, u16, u16),
+    is_mariadb: bool,
     capabilities: Capabilities,
     sequence_id: u8,
     waiting: VecDeque<Waiting>,
@@ -81,6 +82,7 @@ pub(super) async fn maybe_upgrade<S: Socket>(
         tls_config,
         MapStream {
             server_version: stream.server_version,
+            is_mariadb: stream.is_mariadb,
             capabilities: stream.capabilities,
             sequence_id: stream.sequence_id,
             waiting: stream.waiting,
@@ -98,6 +100,7 @@ impl WithSocket for MapStream {
         MySqlStream {
             socket: BufferedSocket::new(Box::new(socket)),
             server_version: self.server_version,
+            is_mariadb: self.is_mariadb,
             capabilities: self.capabilities,
             sequence_id: self.sequence_id,
             waiting: self.waiting,
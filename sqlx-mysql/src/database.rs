@@ -3,7 +3,7 @@ use crate::{
     MySqlArguments, MySqlColumn, MySqlConnection, MySqlQueryResult, MySqlRow, MySqlStatement,
     MySqlTransactionManager, MySqlTypeInfo,
 };
-pub(crate) use sqlx_core::database::{Database, HasStatementCache};
+pub(crate) use sqlx_core::database::{Database, HasBindLimit, HasReturningId, HasStatementCache};
 
 /// MySQL database driver.
 #[derive(Debug)]
@@ -36,3 +36,20 @@ impl Database for MySql {
 }
 
 impl HasStatementCache for MySql {}
+
+impl HasReturningId for MySql {
+    fn supports_returning(conn: &MySqlConnection) -> bool {
+        // `INSERT ... RETURNING` is supported since MariaDB 10.5, but not by MySQL
+        conn.inner.stream.is_mariadb && conn.inner.stream.server_version >= (10, 5, 0)
+    }
+
+    fn last_insert_id(result: &MySqlQueryResult) -> Option<MySqlValue> {
+        Some(result.last_insert_id())
+            .filter(|id| *id != 0)
+            .map(MySqlValue::unsigned_bigint)
+    }
+}
+
+impl HasBindLimit for MySql {
+    const BIND_LIMIT: usize = 65535;
+}
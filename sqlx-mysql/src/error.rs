@@ -79,6 +79,10 @@ impl DatabaseError for MySqlDatabaseError {
         self
     }
 
+    fn is_retryable(&self) -> bool {
+        self.number() == error_codes::ER_LOCK_DEADLOCK
+    }
+
     fn kind(&self) -> ErrorKind {
         match self.number() {
             error_codes::ER_DUP_KEY
@@ -150,8 +154,14 @@ pub(crate) mod error_codes {
     /// Caused when inserting a DEFAULT value to a column marked as NOT NULL, which also doesn't have a default value set.
     pub const ER_NO_DEFAULT_FOR_FIELD: u16 = 1364;
 
+    /// Caused when a statement is prepared that can't be executed as a prepared statement.
+    pub const ER_UNSUPPORTED_PS: u16 = 1295;
+
     /// Caused when a check constraint is violated.
     ///
     /// Only available after 8.0.16.
     pub const ER_CHECK_CONSTRAINT_VIOLATED: u16 = 3819;
+
+    /// Caused when a transaction was rolled back to resolve a deadlock.
+    pub const ER_LOCK_DEADLOCK: u16 = 1213;
 }
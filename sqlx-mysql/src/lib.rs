@@ -21,6 +21,9 @@ mod options;
 mod protocol;
 mod query_result;
 mod row;
+mod sequence;
+#[cfg(feature = "json")]
+mod serialize;
 mod statement;
 mod transaction;
 mod type_checking;
@@ -40,7 +43,8 @@ pub use connection::MySqlConnection;
 pub use database::MySql;
 pub use error::MySqlDatabaseError;
 pub use options::{MySqlConnectOptions, MySqlSslMode};
-pub use query_result::MySqlQueryResult;
+pub use protocol::auth::MySqlAuthPlugin;
+pub use query_result::{MySqlQueryResult, MySqlSessionStateChange};
 pub use row::MySqlRow;
 pub use statement::MySqlStatement;
 pub use transaction::MySqlTransactionManager;
@@ -152,6 +152,30 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn lock_with_timeout(&mut self, timeout: Duration) -> BoxFuture<'_, Result<(), MigrateError>> {
+        Box::pin(async move {
+            let database_name = current_database(self).await?;
+            let lock_id = generate_lock_id(&database_name);
+
+            // `GET_LOCK()` takes a whole number of seconds
+            let seconds = timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0);
+
+            // returns 1 if the lock was acquired, or 0 if it timed out
+            // language=MySQL
+            let locked: Option<i64> = query_scalar("SELECT GET_LOCK(?, ?)")
+                .bind(lock_id)
+                .bind(seconds)
+                .fetch_one(&mut *self)
+                .await?;
+
+            if locked != Some(1) {
+                return Err(MigrateError::LockTimeout(timeout));
+            }
+
+            Ok(())
+        })
+    }
+
     fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
             let database_name = current_database(self).await?;
@@ -167,6 +191,45 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn enter_schema<'e>(
+        &'e mut self,
+        schema: &'e str,
+    ) -> BoxFuture<'e, Result<Option<String>, MigrateError>> {
+        Box::pin(async move {
+            // language=MySQL
+            let previous: Option<String> = query_scalar("SELECT DATABASE()")
+                .fetch_one(&mut *self)
+                .await?;
+
+            let schema = quote_identifier(schema);
+
+            let _ = self
+                .execute(&*format!("CREATE DATABASE IF NOT EXISTS {schema}"))
+                .await?;
+
+            let _ = self.execute(&*format!("USE {schema}")).await?;
+
+            Ok(previous)
+        })
+    }
+
+    fn leave_schema<'e>(
+        &'e mut self,
+        previous: Option<&'e str>,
+    ) -> BoxFuture<'e, Result<(), MigrateError>> {
+        Box::pin(async move {
+            // MySQL cannot switch back to no database; unqualified names keep resolving to the
+            // schema until the connection is closed or switched to another database.
+            if let Some(previous) = previous {
+                let _ = self
+                    .execute(&*format!("USE {}", quote_identifier(previous)))
+                    .await?;
+            }
+
+            Ok(())
+        })
+    }
+
     fn apply<'e: 'm, 'm>(
         &'e mut self,
         migration: &'m Migration,
@@ -199,8 +262,7 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
             .execute(&mut *tx)
             .await?;
 
-            let _ = tx
-                .execute(&*migration.sql)
+            execute_script(&mut tx, &migration.sql)
                 .await
                 .map_err(|e| MigrateError::ExecuteMigration(e, migration.version))?;
 
@@ -268,7 +330,7 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
             .execute(&mut *tx)
             .await?;
 
-            tx.execute(&*migration.sql).await?;
+            execute_script(&mut tx, &migration.sql).await?;
 
             // language=SQL
             let _ = query(r#"DELETE FROM _sqlx_migrations WHERE version = ?"#)
@@ -285,6 +347,149 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
     }
 }
 
+fn quote_identifier(name: &str) -> String {
+    format!("`{}`", name.replace('`', "``"))
+}
+
+/// Execute a migration script, one statement at a time if it changes the delimiter.
+///
+/// `DELIMITER` is a command of the `mysql` client, not of the server, but it's needed to write
+/// e.g. `CREATE PROCEDURE` statements with a body of several statements in a script.
+async fn execute_script(conn: &mut MySqlConnection, sql: &str) -> Result<(), Error> {
+    match split_delimited_statements(sql) {
+        Some(statements) => {
+            for statement in statements {
+                let _ = conn.execute(statement).await?;
+            }
+        }
+        None => {
+            let _ = conn.execute(sql).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Split a script into its statements, following the `DELIMITER` commands in it.
+///
+/// Returns `None` if the script doesn't contain a `DELIMITER` command.
+fn split_delimited_statements<'s>(sql: &'s str) -> Option<Vec<&'s str>> {
+    let mut statements = Vec::new();
+    let mut delimiter = ";";
+    let mut found_command = false;
+
+    // the start of the current statement, and whether it contains more than comments
+    let mut start = 0;
+    let mut has_code = false;
+
+    let mut i = 0;
+    let mut line_start = true;
+
+    let mut push_statement = |statement: &'s str, has_code: bool| {
+        if has_code {
+            statements.push(statement.trim());
+        }
+    };
+
+    while i < sql.len() {
+        let rest = &sql[i..];
+
+        if line_start {
+            let indent = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+
+            if let Some((new_delimiter, len)) = delimiter_command(&rest[indent..]) {
+                push_statement(&sql[start..i], has_code);
+
+                delimiter = new_delimiter;
+                found_command = true;
+
+                i += indent + len;
+                start = i;
+                has_code = false;
+                continue;
+            }
+        }
+
+        if rest.starts_with(delimiter) {
+            push_statement(&sql[start..i], has_code);
+
+            i += delimiter.len();
+            start = i;
+            has_code = false;
+            line_start = false;
+            continue;
+        }
+
+        let c = rest
+            .chars()
+            .next()
+            .expect("BUG: `rest` should not be empty");
+        line_start = c == '\n';
+
+        let is_line_comment = c == '#'
+            || rest
+                .strip_prefix("--")
+                .is_some_and(|after| after.is_empty() || after.starts_with(char::is_whitespace));
+
+        if is_line_comment {
+            // skip to the end of the line, leaving the newline to start the next one
+            i += rest.find('\n').unwrap_or(rest.len());
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            i += comment.find("*/").map_or(rest.len(), |end| end + 4);
+            // `/*! ... */` comments are executed by MySQL
+            has_code |= comment.starts_with('!');
+        } else if matches!(c, '\'' | '"' | '`') {
+            i += quoted_len(rest, c);
+            has_code = true;
+        } else {
+            i += c.len_utf8();
+            has_code |= !c.is_whitespace();
+        }
+    }
+
+    push_statement(&sql[start..], has_code);
+
+    found_command.then_some(statements)
+}
+
+/// Parse a `DELIMITER <delimiter>` command at the start of `line`, returning the new delimiter
+/// and the length of the line.
+fn delimiter_command(line: &str) -> Option<(&str, usize)> {
+    let len = line.find('\n').map_or(line.len(), |end| end + 1);
+    let command = line[..len].trim_end();
+
+    let keyword = command.get(..9)?;
+    let delimiter = &command[9..];
+
+    if !keyword.eq_ignore_ascii_case("DELIMITER") || !delimiter.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let delimiter = delimiter.trim();
+
+    if delimiter.is_empty() || delimiter.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some((delimiter, len))
+}
+
+/// The length of the quoted string or identifier at the start of `s`, including the quotes.
+fn quoted_len(s: &str, quote: char) -> usize {
+    let mut chars = s.char_indices().skip(1);
+
+    while let Some((i, c)) = chars.next() {
+        if c == '\\' && quote != '`' {
+            // skip the escaped character
+            chars.next();
+        } else if c == quote {
+            return i + 1;
+        }
+    }
+
+    s.len()
+}
+
 async fn current_database(conn: &mut MySqlConnection) -> Result<String, MigrateError> {
     // language=MySQL
     Ok(query_scalar("SELECT DATABASE()").fetch_one(conn).await?)
@@ -299,3 +504,34 @@ fn generate_lock_id(database_name: &str) -> String {
         0x3d32ad9e * (CRC_IEEE.checksum(database_name.as_bytes()) as i64)
     )
 }
+
+#[test]
+fn it_splits_scripts_with_delimiter_commands() {
+    let sql = r#"
+-- a procedure with a body of several statements
+DELIMITER //
+
+CREATE PROCEDURE add_user(name TEXT)
+BEGIN
+    INSERT INTO users (name) VALUES (name);
+    SELECT 'done; //';
+END //
+
+DELIMITER ;
+
+CALL add_user("alice"); /* a comment; */ CALL add_user('bob\'s');
+# a trailing comment
+"#;
+
+    assert_eq!(
+        split_delimited_statements(sql).unwrap(),
+        [
+            "CREATE PROCEDURE add_user(name TEXT)\nBEGIN\n    INSERT INTO users (name) VALUES (name);\n    SELECT 'done; //';\nEND",
+            "CALL add_user(\"alice\")",
+            "/* a comment; */ CALL add_user('bob\\'s')",
+        ]
+    );
+
+    assert_eq!(split_delimited_statements("SELECT 1; SELECT 2;"), None);
+    assert_eq!(split_delimited_statements("SELECT 'DELIMITER //';\n"), None);
+}
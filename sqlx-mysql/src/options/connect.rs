@@ -1,10 +1,13 @@
 use crate::connection::ConnectOptions;
 use crate::error::Error;
 use crate::executor::Executor;
+use crate::logger::QueryLogSink;
 use crate::{MySqlConnectOptions, MySqlConnection};
 use futures_core::future::BoxFuture;
 use log::LevelFilter;
+use sqlx_core::statement_stats::StatementStats;
 use sqlx_core::Url;
+use std::sync::Arc;
 use std::time::Duration;
 
 impl ConnectOptions for MySqlConnectOptions {
@@ -81,6 +84,10 @@ impl ConnectOptions for MySqlConnectOptions {
                     .await?;
             }
 
+            for command in &self.init_commands {
+                conn.execute(&**command).await?;
+            }
+
             Ok(conn)
         })
     }
@@ -94,4 +101,19 @@ impl ConnectOptions for MySqlConnectOptions {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
+
+    fn query_logger(mut self, logger: Arc<dyn QueryLogSink>) -> Self {
+        self.log_settings.set_query_logger(logger);
+        self
+    }
+
+    fn log_query_fingerprints(mut self, enabled: bool) -> Self {
+        self.log_settings.log_query_fingerprints(enabled);
+        self
+    }
+
+    fn statement_stats(mut self, stats: StatementStats) -> Self {
+        self.log_settings.set_statement_stats(stats);
+        self
+    }
 }
@@ -1,10 +1,16 @@
+use std::fmt::{self, Debug, Formatter};
+use std::future::Future;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use futures_core::future::BoxFuture;
 
 mod connect;
 mod parse;
 mod ssl_mode;
 
-use crate::{connection::LogSettings, net::tls::CertificateInput};
+use crate::error::BoxDynError;
+use crate::{connection::LogSettings, net::tls::CertificateInput, MySqlAuthPlugin};
 pub use ssl_mode::MySqlSslMode;
 
 /// Options and flags which can be used to configure a MySQL connection.
@@ -66,6 +72,7 @@ pub struct MySqlConnectOptions {
     pub(crate) socket: Option<PathBuf>,
     pub(crate) username: String,
     pub(crate) password: Option<String>,
+    pub(crate) password_callback: Option<PasswordCallback>,
     pub(crate) database: Option<String>,
     pub(crate) ssl_mode: MySqlSslMode,
     pub(crate) ssl_ca: Option<CertificateInput>,
@@ -77,9 +84,15 @@ pub struct MySqlConnectOptions {
     pub(crate) log_settings: LogSettings,
     pub(crate) pipes_as_concat: bool,
     pub(crate) enable_cleartext_plugin: bool,
+    pub(crate) auth_plugins: Option<Vec<MySqlAuthPlugin>>,
     pub(crate) no_engine_subsitution: bool,
     pub(crate) timezone: Option<String>,
     pub(crate) set_names: bool,
+    pub(crate) multi_statements: bool,
+    pub(crate) strict_bools: bool,
+    pub(crate) track_session_state: bool,
+    pub(crate) query_attributes: Vec<(String, String)>,
+    pub(crate) init_commands: Vec<String>,
 }
 
 impl Default for MySqlConnectOptions {
@@ -97,6 +110,7 @@ impl MySqlConnectOptions {
             socket: None,
             username: String::from("root"),
             password: None,
+            password_callback: None,
             database: None,
             charset: String::from("utf8mb4"),
             collation: None,
@@ -108,9 +122,15 @@ impl MySqlConnectOptions {
             log_settings: Default::default(),
             pipes_as_concat: true,
             enable_cleartext_plugin: false,
+            auth_plugins: None,
             no_engine_subsitution: true,
             timezone: Some(String::from("+00:00")),
             set_names: true,
+            multi_statements: true,
+            strict_bools: false,
+            track_session_state: false,
+            query_attributes: Vec::new(),
+            init_commands: Vec::new(),
         }
     }
 
@@ -147,8 +167,37 @@ impl MySqlConnectOptions {
     }
 
     /// Sets the password to connect with.
+    ///
+    /// Replaces a callback set with [`password_from_fn()`][Self::password_from_fn].
     pub fn password(mut self, password: &str) -> Self {
         self.password = Some(password.to_owned());
+        self.password_callback = None;
+        self
+    }
+
+    /// Sets a callback returning the password to connect with.
+    ///
+    /// The callback is invoked for every new connection, so it can return short-lived
+    /// credentials, such as the authentication tokens of AWS RDS IAM authentication,
+    /// without recreating the pool. Replaces a password set with [`password()`][Self::password].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_mysql::{MySqlSslMode, MySqlConnectOptions};
+    /// # async fn fetch_token() -> String { String::new() }
+    /// let options = MySqlConnectOptions::new()
+    ///     .ssl_mode(MySqlSslMode::VerifyIdentity)
+    ///     .enable_cleartext_plugin(true)
+    ///     .password_from_fn(|| async { Ok(fetch_token().await) });
+    /// ```
+    pub fn password_from_fn<F, Fut>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, BoxDynError>> + Send + 'static,
+    {
+        self.password = None;
+        self.password_callback = Some(PasswordCallback(Arc::new(move || Box::pin(f()))));
         self
     }
 
@@ -206,6 +255,33 @@ impl MySqlConnectOptions {
         self
     }
 
+    /// Sets a callback returning the trusted SSL Certificate Authorities (CA) as PEM-encoded bytes.
+    ///
+    /// The callback is invoked whenever a connection is opened, so a pool picks up rotated
+    /// certificates and keys without being recreated. Call
+    /// [`Pool::reload_tls()`][sqlx_core::pool::Pool::reload_tls] to also replace the connections
+    /// which are already open.
+    ///
+    /// The same goes for [`ssl_client_cert_from_fn()`][Self::ssl_client_cert_from_fn] and
+    /// [`ssl_client_key_from_fn()`][Self::ssl_client_key_from_fn].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_mysql::{MySqlSslMode, MySqlConnectOptions};
+    /// let options = MySqlConnectOptions::new()
+    ///     .ssl_mode(MySqlSslMode::VerifyCa)
+    ///     .ssl_ca_from_fn(|| async { Ok(std::fs::read("path/to/ca.crt")?) });
+    /// ```
+    pub fn ssl_ca_from_fn<F, Fut>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, BoxDynError>> + Send + 'static,
+    {
+        self.ssl_ca = Some(CertificateInput::from_fn(f));
+        self
+    }
+
     /// Sets the name of a file containing SSL client certificate.
     ///
     /// # Example
@@ -246,6 +322,27 @@ impl MySqlConnectOptions {
         self
     }
 
+    /// Sets a callback returning the SSL client certificate as PEM-encoded bytes.
+    ///
+    /// See [`ssl_ca_from_fn()`][Self::ssl_ca_from_fn] for when the callback is invoked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_mysql::{MySqlSslMode, MySqlConnectOptions};
+    /// let options = MySqlConnectOptions::new()
+    ///     .ssl_mode(MySqlSslMode::VerifyCa)
+    ///     .ssl_client_cert_from_fn(|| async { Ok(std::fs::read("path/to/client.crt")?) });
+    /// ```
+    pub fn ssl_client_cert_from_fn<F, Fut>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, BoxDynError>> + Send + 'static,
+    {
+        self.ssl_client_cert = Some(CertificateInput::from_fn(f));
+        self
+    }
+
     /// Sets the name of a file containing SSL client key.
     ///
     /// # Example
@@ -286,6 +383,27 @@ impl MySqlConnectOptions {
         self
     }
 
+    /// Sets a callback returning the SSL client key as PEM-encoded bytes.
+    ///
+    /// See [`ssl_ca_from_fn()`][Self::ssl_ca_from_fn] for when the callback is invoked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_mysql::{MySqlSslMode, MySqlConnectOptions};
+    /// let options = MySqlConnectOptions::new()
+    ///     .ssl_mode(MySqlSslMode::VerifyCa)
+    ///     .ssl_client_key_from_fn(|| async { Ok(std::fs::read("path/to/client.key")?) });
+    /// ```
+    pub fn ssl_client_key_from_fn<F, Fut>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, BoxDynError>> + Send + 'static,
+    {
+        self.ssl_client_key = Some(CertificateInput::from_fn(f));
+        self
+    }
+
     /// Sets the capacity of the connection's statement cache in a number of stored
     /// distinct statements. Caching is handled using LRU, meaning when the
     /// amount of queries hits the defined limit, the oldest statement will get
@@ -335,11 +453,36 @@ impl MySqlConnectOptions {
     ///
     /// It is strongly recommended to set `.ssl_mode` to `Required`,
     /// `VerifyCa`, or `VerifyIdentity` when enabling cleartext plugin.
+    ///
+    /// Has no effect if the plugins are set with [`auth_plugins()`][Self::auth_plugins].
     pub fn enable_cleartext_plugin(mut self, flag_val: bool) -> Self {
         self.enable_cleartext_plugin = flag_val;
         self
     }
 
+    /// Sets the authentication plugins the server may ask the client to authenticate with.
+    ///
+    /// The connection fails if the server asks for any other plugin. The first of these
+    /// plugins is offered to the server if the plugin it announces is not one of them.
+    ///
+    /// By default, every plugin is allowed except `mysql_clear_password`, which is only allowed
+    /// with [`enable_cleartext_plugin()`][Self::enable_cleartext_plugin]. Listing
+    /// [`MySqlClearPassword`][MySqlAuthPlugin::MySqlClearPassword] here allows it regardless,
+    /// with the same security concerns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_mysql::{MySqlAuthPlugin, MySqlConnectOptions};
+    /// // Refuse to downgrade to `mysql_native_password`
+    /// let options = MySqlConnectOptions::new()
+    ///     .auth_plugins([MySqlAuthPlugin::CachingSha2Password]);
+    /// ```
+    pub fn auth_plugins(mut self, plugins: impl IntoIterator<Item = MySqlAuthPlugin>) -> Self {
+        self.auth_plugins = Some(plugins.into_iter().collect());
+        self
+    }
+
     /// Flag that enables or disables the `NO_ENGINE_SUBSTITUTION` sql_mode setting after
     /// connection.
     ///
@@ -398,6 +541,81 @@ impl MySqlConnectOptions {
         self.set_names = flag_val;
         self
     }
+
+    /// Sets the flag that enables or disables the `CLIENT_MULTI_STATEMENTS` capability, which
+    /// allows a query without arguments to consist of several statements separated by
+    /// semicolons.
+    ///
+    /// Enabled by default, as [`raw_sql()`][sqlx_core::raw_sql::raw_sql] and migrations rely on
+    /// it. Disabling it makes the server reject such queries, which limits the damage of an
+    /// SQL injection, and makes
+    /// [`MySqlConnection::execute_many_statements()`][crate::MySqlConnection::execute_many_statements]
+    /// return an error.
+    pub fn multi_statements(mut self, flag_val: bool) -> Self {
+        self.multi_statements = flag_val;
+        self
+    }
+
+    /// Sets the flag that makes decoding a `bool` fail for integers other than `0` and `1`.
+    ///
+    /// MySQL has no actual `BOOLEAN` type, so booleans are usually stored in `TINYINT(1)`
+    /// columns, which can hold any value from `-128` to `127`. By default, any value other than
+    /// `0` decodes as `true`; with this flag set, such values are an error instead.
+    pub fn strict_bools(mut self, flag_val: bool) -> Self {
+        self.strict_bools = flag_val;
+        self
+    }
+
+    /// Sets the flag that enables or disables the `CLIENT_SESSION_TRACK` capability, with which
+    /// the server reports changes to the session state after each statement.
+    ///
+    /// The changes are read with
+    /// [`MySqlQueryResult::session_state_changes()`][crate::MySqlQueryResult::session_state_changes]
+    /// and [`MySqlQueryResult::gtids()`][crate::MySqlQueryResult::gtids]. Disabled by default,
+    /// as it makes the server send these changes with the result of every statement.
+    pub fn track_session_state(mut self, flag_val: bool) -> Self {
+        self.track_session_state = flag_val;
+        self
+    }
+
+    /// Adds a query attribute to send with every statement executed on the connection,
+    /// such as a trace ID or the name of the application.
+    ///
+    /// The attributes are sent before any added to a query with
+    /// [`Query::attribute()`][sqlx_core::query::Query::attribute], and can be read on the
+    /// server with `mysql_query_attribute()`. Requires MySQL 8.0.23 or later; other servers
+    /// return an error for every statement.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_mysql::MySqlConnectOptions;
+    /// let options = MySqlConnectOptions::new()
+    ///     .query_attribute("app_name", "billing");
+    /// ```
+    pub fn query_attribute(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query_attributes.push((name.into(), value.into()));
+        self
+    }
+
+    /// Adds a statement to execute on every new connection, after the session variables set by
+    /// these options, such as [`timezone`][Self::timezone] and [`set_names`][Self::set_names].
+    ///
+    /// The statements are executed in the order they were added. They can also be given with
+    /// the `init-command` parameter of the connection URL, which may be repeated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_mysql::MySqlConnectOptions;
+    /// let options = MySqlConnectOptions::new()
+    ///     .init_command("SET SESSION sql_mode = CONCAT(@@sql_mode, ',STRICT_ALL_TABLES')")
+    ///     .init_command("SET SESSION max_execution_time = 5000");
+    /// ```
+    pub fn init_command(mut self, sql: impl Into<String>) -> Self {
+        self.init_commands.push(sql.into());
+        self
+    }
 }
 
 impl MySqlConnectOptions {
@@ -510,4 +728,40 @@ impl MySqlConnectOptions {
     pub fn get_collation(&self) -> Option<&str> {
         self.collation.as_deref()
     }
+
+    /// Get the statements executed on every new connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_mysql::MySqlConnectOptions;
+    /// let options = MySqlConnectOptions::new()
+    ///     .init_command("SET SESSION max_execution_time = 5000");
+    /// assert_eq!(options.get_init_commands(), ["SET SESSION max_execution_time = 5000"]);
+    /// ```
+    pub fn get_init_commands(&self) -> &[String] {
+        &self.init_commands
+    }
+}
+
+impl MySqlConnectOptions {
+    pub(crate) fn is_auth_plugin_allowed(&self, plugin: MySqlAuthPlugin) -> bool {
+        match &self.auth_plugins {
+            Some(plugins) => plugins.contains(&plugin),
+            None => plugin != MySqlAuthPlugin::MySqlClearPassword || self.enable_cleartext_plugin,
+        }
+    }
+}
+
+/// A callback returning the password to connect with, from
+/// [`MySqlConnectOptions::password_from_fn()`].
+#[derive(Clone)]
+pub(crate) struct PasswordCallback(
+    pub(crate) Arc<dyn Fn() -> BoxFuture<'static, Result<String, BoxDynError>> + Send + Sync>,
+);
+
+impl Debug for PasswordCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PasswordCallback").finish_non_exhaustive()
+    }
 }
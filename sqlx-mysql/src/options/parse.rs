@@ -72,6 +72,10 @@ impl MySqlConnectOptions {
                     options = options.socket(&*value);
                 }
 
+                "init-command" => {
+                    options = options.init_command(value);
+                }
+
                 _ => {}
             }
         }
@@ -135,6 +139,10 @@ impl MySqlConnectOptions {
                 .append_pair("socket", &socket.to_string_lossy());
         }
 
+        for command in &self.init_commands {
+            url.query_pairs_mut().append_pair("init-command", command);
+        }
+
         url
     }
 }
@@ -164,6 +172,18 @@ fn it_parses_password_with_non_ascii_chars_correctly() {
     assert_eq!(Some("p@ssw0rd".into()), opts.password);
 }
 
+#[test]
+fn it_parses_init_commands() {
+    let url =
+        "mysql://root@localhost/db?init-command=SET%20@a%20%3D%201&init-command=SET%20@b%20%3D%202";
+    let opts = MySqlConnectOptions::from_str(url).unwrap();
+
+    assert_eq!(opts.get_init_commands(), ["SET @a = 1", "SET @b = 2"]);
+
+    let opts = MySqlConnectOptions::parse_from_url(&opts.build_url()).unwrap();
+    assert_eq!(opts.get_init_commands(), ["SET @a = 1", "SET @b = 2"]);
+}
+
 #[test]
 fn it_returns_the_parsed_url() {
     let url = "mysql://username:p@ssw0rd@hostname:3306/database";
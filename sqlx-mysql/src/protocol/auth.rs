@@ -2,36 +2,45 @@ use std::str::FromStr;
 
 use crate::error::Error;
 
-#[derive(Debug, Copy, Clone)]
+/// An authentication plugin the server may ask the client to authenticate with.
+///
+/// See [`MySqlConnectOptions::auth_plugins()`][crate::MySqlConnectOptions::auth_plugins].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 // These have all the same suffix but they match the auth plugin names.
 #[allow(clippy::enum_variant_names)]
-pub enum AuthPlugin {
+pub enum MySqlAuthPlugin {
+    /// `mysql_native_password`
     MySqlNativePassword,
+    /// `caching_sha2_password`
     CachingSha2Password,
+    /// `sha256_password`
     Sha256Password,
+    /// `mysql_clear_password`, which sends the password to the server as is.
     MySqlClearPassword,
 }
 
-impl AuthPlugin {
-    pub(crate) fn name(self) -> &'static str {
+impl MySqlAuthPlugin {
+    /// The name of the plugin, as used by the server.
+    pub fn name(self) -> &'static str {
         match self {
-            AuthPlugin::MySqlNativePassword => "mysql_native_password",
-            AuthPlugin::CachingSha2Password => "caching_sha2_password",
-            AuthPlugin::Sha256Password => "sha256_password",
-            AuthPlugin::MySqlClearPassword => "mysql_clear_password",
+            MySqlAuthPlugin::MySqlNativePassword => "mysql_native_password",
+            MySqlAuthPlugin::CachingSha2Password => "caching_sha2_password",
+            MySqlAuthPlugin::Sha256Password => "sha256_password",
+            MySqlAuthPlugin::MySqlClearPassword => "mysql_clear_password",
         }
     }
 }
 
-impl FromStr for AuthPlugin {
+impl FromStr for MySqlAuthPlugin {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "mysql_native_password" => Ok(AuthPlugin::MySqlNativePassword),
-            "caching_sha2_password" => Ok(AuthPlugin::CachingSha2Password),
-            "sha256_password" => Ok(AuthPlugin::Sha256Password),
-            "mysql_clear_password" => Ok(AuthPlugin::MySqlClearPassword),
+            "mysql_native_password" => Ok(MySqlAuthPlugin::MySqlNativePassword),
+            "caching_sha2_password" => Ok(MySqlAuthPlugin::CachingSha2Password),
+            "sha256_password" => Ok(MySqlAuthPlugin::Sha256Password),
+            "mysql_clear_password" => Ok(MySqlAuthPlugin::MySqlClearPassword),
 
             _ => Err(err_protocol!("unknown authentication plugin: {}", s)),
         }
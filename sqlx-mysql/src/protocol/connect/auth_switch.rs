@@ -3,14 +3,14 @@ use bytes::{Buf, Bytes};
 use crate::error::Error;
 use crate::io::Encode;
 use crate::io::{BufExt, Decode};
-use crate::protocol::auth::AuthPlugin;
+use crate::protocol::auth::MySqlAuthPlugin;
 use crate::protocol::Capabilities;
 
 // https://dev.mysql.com/doc/dev/mysql-server/8.0.12/page_protocol_connection_phase_packets_protocol_auth_switch_request.html
 
 #[derive(Debug)]
 pub struct AuthSwitchRequest {
-    pub plugin: AuthPlugin,
+    pub plugin: MySqlAuthPlugin,
     pub data: Bytes,
 }
 
@@ -26,11 +26,11 @@ impl Decode<'_, bool> for AuthSwitchRequest {
 
         let plugin = buf.get_str_nul()?.parse()?;
 
-        if matches!(plugin, AuthPlugin::MySqlClearPassword) && !enable_cleartext_plugin {
+        if matches!(plugin, MySqlAuthPlugin::MySqlClearPassword) && !enable_cleartext_plugin {
             return Err(err_protocol!("mysql_cleartext_plugin disabled"));
         }
 
-        if matches!(plugin, AuthPlugin::MySqlClearPassword) && buf.is_empty() {
+        if matches!(plugin, MySqlAuthPlugin::MySqlClearPassword) && buf.is_empty() {
             // Contrary to the MySQL protocol, AWS Aurora with IAM sends
             // no data. That is fine because the mysql_clear_password says to
             // ignore any data sent.
@@ -70,7 +70,7 @@ fn test_decode_auth_switch_packet_data() {
 
     let p = AuthSwitchRequest::decode_with(AUTH_SWITCH_NO_DATA.into(), true).unwrap();
 
-    assert!(matches!(p.plugin, AuthPlugin::CachingSha2Password));
+    assert!(matches!(p.plugin, MySqlAuthPlugin::CachingSha2Password));
     assert_eq!(p.data, &b"abcdefghijabcdefghij"[..]);
 }
 
@@ -92,6 +92,6 @@ fn test_decode_auth_switch_packet_no_data() {
 
     let p = AuthSwitchRequest::decode_with(AUTH_SWITCH_NO_DATA.into(), true).unwrap();
 
-    assert!(matches!(p.plugin, AuthPlugin::MySqlClearPassword));
+    assert!(matches!(p.plugin, MySqlAuthPlugin::MySqlClearPassword));
     assert_eq!(p.data, Bytes::new());
 }
@@ -4,7 +4,7 @@ use std::cmp;
 
 use crate::error::Error;
 use crate::io::{BufExt, Decode};
-use crate::protocol::auth::AuthPlugin;
+use crate::protocol::auth::MySqlAuthPlugin;
 use crate::protocol::response::Status;
 use crate::protocol::Capabilities;
 
@@ -23,7 +23,7 @@ pub(crate) struct Handshake {
     pub(crate) server_default_collation: u8,
     #[allow(unused)]
     pub(crate) status: Status,
-    pub(crate) auth_plugin: Option<AuthPlugin>,
+    pub(crate) auth_plugin: Option<MySqlAuthPlugin>,
     pub(crate) auth_plugin_data: Chain<Bytes, Bytes>,
 }
 
@@ -135,7 +135,7 @@ fn test_decode_handshake_mysql_8_0_18() {
 
     assert!(matches!(
         p.auth_plugin,
-        Some(AuthPlugin::CachingSha2Password)
+        Some(MySqlAuthPlugin::CachingSha2Password)
     ));
 
     assert_eq!(
@@ -188,7 +188,7 @@ fn test_decode_handshake_mariadb_10_4_7() {
     assert!(p.status.contains(Status::SERVER_STATUS_AUTOCOMMIT));
     assert!(matches!(
         p.auth_plugin,
-        Some(AuthPlugin::MySqlNativePassword)
+        Some(MySqlAuthPlugin::MySqlNativePassword)
     ));
 
     assert_eq!(
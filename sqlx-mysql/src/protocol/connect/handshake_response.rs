@@ -1,6 +1,6 @@
 use crate::io::MySqlBufMutExt;
 use crate::io::{BufMutExt, Encode};
-use crate::protocol::auth::AuthPlugin;
+use crate::protocol::auth::MySqlAuthPlugin;
 use crate::protocol::connect::ssl_request::SslRequest;
 use crate::protocol::Capabilities;
 
@@ -21,7 +21,7 @@ pub struct HandshakeResponse<'a> {
     pub username: &'a str,
 
     /// Authentication method used by the client
-    pub auth_plugin: Option<AuthPlugin>,
+    pub auth_plugin: Option<MySqlAuthPlugin>,
 
     /// Opaque authentication response
     pub auth_response: Option<&'a [u8]>,
@@ -1,9 +1,10 @@
 use bytes::{Buf, Bytes};
 
 use crate::error::Error;
-use crate::io::Decode;
 use crate::io::MySqlBufExt;
+use crate::io::{BufExt, Decode};
 use crate::protocol::response::Status;
+use crate::query_result::MySqlSessionStateChange;
 
 /// Indicates successful completion of a previous command sent by the client.
 #[derive(Debug)]
@@ -12,6 +13,7 @@ pub struct OkPacket {
     pub last_insert_id: u64,
     pub status: Status,
     pub warnings: u16,
+    pub session_state_changes: Vec<MySqlSessionStateChange>,
 }
 
 impl Decode<'_> for OkPacket {
@@ -29,15 +31,117 @@ impl Decode<'_> for OkPacket {
         let status = Status::from_bits_truncate(buf.get_u16_le());
         let warnings = buf.get_u16_le();
 
+        // the server only reports session state changes if the client negotiated
+        // `CLIENT_SESSION_TRACK`, in which case the human-readable info is length-encoded
+        let session_state_changes =
+            if status.contains(Status::SERVER_SESSION_STATE_CHANGED) && buf.has_remaining() {
+                let _info = try_get_bytes_lenenc(&mut buf)?;
+
+                decode_session_state_changes(try_get_bytes_lenenc(&mut buf)?)?
+            } else {
+                Vec::new()
+            };
+
         Ok(Self {
             affected_rows,
             last_insert_id,
             status,
             warnings,
+            session_state_changes,
         })
     }
 }
 
+// https://dev.mysql.com/doc/dev/mysql-server/8.0.12/page_protocol_basic_ok_packet.html
+// https://mariadb.com/kb/en/ok_packet/#session-change-type
+fn decode_session_state_changes(mut buf: Bytes) -> Result<Vec<MySqlSessionStateChange>, Error> {
+    let mut changes = Vec::new();
+
+    while buf.has_remaining() {
+        let r#type = buf.get_u8();
+        let mut data = try_get_bytes_lenenc(&mut buf)?;
+
+        let change = match r#type {
+            // SESSION_TRACK_SYSTEM_VARIABLES
+            0x00 => MySqlSessionStateChange::SystemVariable {
+                name: try_get_str_lenenc(&mut data)?,
+                value: try_get_str_lenenc(&mut data)?,
+            },
+
+            // SESSION_TRACK_SCHEMA
+            0x01 => MySqlSessionStateChange::Schema(try_get_str_lenenc(&mut data)?),
+
+            // SESSION_TRACK_STATE_CHANGE
+            0x02 => MySqlSessionStateChange::StateChange(try_get_str_lenenc(&mut data)? == "1"),
+
+            // SESSION_TRACK_GTIDS
+            0x03 => {
+                // encoding specification; only `0` (the GTID set as text) is defined
+                if !data.has_remaining() {
+                    return Err(truncated());
+                }
+
+                let _encoding = data.get_u8();
+
+                MySqlSessionStateChange::Gtids(try_get_str_lenenc(&mut data)?)
+            }
+
+            // SESSION_TRACK_TRANSACTION_CHARACTERISTICS
+            0x04 => {
+                MySqlSessionStateChange::TransactionCharacteristics(try_get_str_lenenc(&mut data)?)
+            }
+
+            // SESSION_TRACK_TRANSACTION_STATE
+            0x05 => MySqlSessionStateChange::TransactionState(try_get_str_lenenc(&mut data)?),
+
+            r#type => MySqlSessionStateChange::Unknown { r#type, data },
+        };
+
+        changes.push(change);
+    }
+
+    Ok(changes)
+}
+
+// The session state comes from the server as-is, so unlike the rest of the packet, it's read
+// with these instead of the panicking methods of `MySqlBufExt`.
+
+fn try_get_uint_lenenc(buf: &mut Bytes) -> Result<u64, Error> {
+    let width = match buf.first() {
+        Some(0xfc) => 2,
+        Some(0xfd) => 3,
+        Some(0xfe) => 8,
+        Some(_) => 0,
+        None => return Err(truncated()),
+    };
+
+    if buf.remaining() <= width {
+        return Err(truncated());
+    }
+
+    Ok(buf.get_uint_lenenc())
+}
+
+fn try_get_bytes_lenenc(buf: &mut Bytes) -> Result<Bytes, Error> {
+    let len = try_get_uint_lenenc(buf)?;
+
+    match usize::try_from(len) {
+        Ok(len) if len <= buf.remaining() => Ok(buf.split_to(len)),
+        _ => Err(truncated()),
+    }
+}
+
+fn try_get_str_lenenc(buf: &mut Bytes) -> Result<String, Error> {
+    let mut data = try_get_bytes_lenenc(buf)?;
+    let len = data.len();
+
+    data.get_str(len)
+}
+
+fn truncated() -> Error {
+    err_protocol!("truncated session state in OK_Packet")
+}
+
 #[test]
 fn test_decode_ok_packet() {
     const DATA: &[u8] = b"\x00\x00\x00\x02@\x00\x00";
@@ -49,4 +153,43 @@ fn test_decode_ok_packet() {
     assert_eq!(p.warnings, 0);
     assert!(p.status.contains(Status::SERVER_STATUS_AUTOCOMMIT));
     assert!(p.status.contains(Status::SERVER_SESSION_STATE_CHANGED));
+    assert!(p.session_state_changes.is_empty());
+}
+
+#[test]
+fn test_decode_ok_packet_with_session_state_changes() {
+    const DATA: &[u8] = b"\x00\x01\x00\x02@\x00\x00\x00\x35\
+        \x00\x0f\x0aautocommit\x03OFF\
+        \x01\x05\x04test\
+        \x03\x1b\x00\x1900000000-0000-0000-0000:1";
+
+    let p = OkPacket::decode(DATA.into()).unwrap();
+
+    assert_eq!(p.affected_rows, 1);
+    assert_eq!(
+        p.session_state_changes,
+        [
+            MySqlSessionStateChange::SystemVariable {
+                name: "autocommit".into(),
+                value: "OFF".into(),
+            },
+            MySqlSessionStateChange::Schema("test".into()),
+            MySqlSessionStateChange::Gtids("00000000-0000-0000-0000:1".into()),
+        ]
+    );
+}
+
+#[test]
+fn test_decode_ok_packet_with_truncated_session_state_changes() {
+    const DATA: &[u8] = b"\x00\x01\x00\x02@\x00\x00\x00\x35\
+        \x00\x0f\x0aautocommit\x03OFF\
+        \x01\x05\x04test\
+        \x03\x1b\x00\x1900000000-0000-0000-0000:1";
+
+    for len in 8..DATA.len() {
+        assert!(
+            OkPacket::decode(Bytes::copy_from_slice(&DATA[..len])).is_err(),
+            "{len}"
+        );
+    }
 }
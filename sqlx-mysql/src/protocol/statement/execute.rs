@@ -1,10 +1,13 @@
-use crate::io::Encode;
+use crate::io::{Encode, MySqlBufMutExt};
 use crate::protocol::text::ColumnFlags;
 use crate::protocol::Capabilities;
-use crate::MySqlArguments;
+use crate::{MySqlArguments, MySqlTypeInfo};
 
 // https://dev.mysql.com/doc/dev/mysql-server/8.0.12/page_protocol_com_stmt_execute.html
 
+// The parameter count, which includes the query attributes, is sent before the parameters.
+const PARAMETER_COUNT_AVAILABLE: u8 = 0x08;
+
 #[derive(Debug)]
 pub struct Execute<'q> {
     pub statement: u32,
@@ -12,27 +15,107 @@ pub struct Execute<'q> {
 }
 
 impl<'q> Encode<'_, Capabilities> for Execute<'q> {
-    fn encode_with(&self, buf: &mut Vec<u8>, _: Capabilities) {
+    fn encode_with(&self, buf: &mut Vec<u8>, capabilities: Capabilities) {
+        let query_attributes = capabilities.contains(Capabilities::QUERY_ATTRIBUTES);
+
         buf.push(0x17); // COM_STMT_EXECUTE
         buf.extend(&self.statement.to_le_bytes());
-        buf.push(0); // NO_CURSOR
+
+        // NO_CURSOR
+        buf.push(if query_attributes {
+            PARAMETER_COUNT_AVAILABLE
+        } else {
+            0
+        });
+
         buf.extend(&1_u32.to_le_bytes()); // iterations (always 1): int<4>
 
-        if !self.arguments.types.is_empty() {
-            buf.extend_from_slice(&self.arguments.null_bitmap);
-            buf.push(1); // send type to server
+        if !query_attributes {
+            if !self.arguments.types.is_empty() {
+                buf.extend_from_slice(&self.arguments.null_bitmap);
+                buf.push(1); // send type to server
 
-            for ty in &self.arguments.types {
-                buf.push(ty.r#type as u8);
+                for ty in &self.arguments.types {
+                    encode_type(buf, ty);
+                }
 
-                buf.push(if ty.flags.contains(ColumnFlags::UNSIGNED) {
-                    0x80
-                } else {
-                    0
-                });
+                buf.extend(&*self.arguments.values);
             }
 
-            buf.extend(&*self.arguments.values);
+            return;
+        }
+
+        let attributes = &self.arguments.attributes;
+
+        buf.put_uint_lenenc((self.arguments.types.len() + attributes.len()) as u64);
+
+        if self.arguments.types.is_empty() && attributes.is_empty() {
+            return;
+        }
+
+        buf.extend_from_slice(&self.arguments.null_bitmap_with_attributes());
+        buf.push(1); // send type to server
+
+        for ty in &self.arguments.types {
+            encode_type(buf, ty);
+            buf.put_str_lenenc(""); // parameters are unnamed
+        }
+
+        for attribute in attributes {
+            encode_type(buf, &attribute.type_info);
+            buf.put_str_lenenc(&attribute.name);
+        }
+
+        buf.extend(&*self.arguments.values);
+
+        for attribute in attributes {
+            buf.extend(attribute.value.iter().flatten());
         }
     }
 }
+
+pub(crate) fn encode_type(buf: &mut Vec<u8>, ty: &MySqlTypeInfo) {
+    buf.push(ty.r#type as u8);
+
+    buf.push(if ty.flags.contains(ColumnFlags::UNSIGNED) {
+        0x80
+    } else {
+        0
+    });
+}
+
+#[test]
+fn test_encode_execute_with_query_attributes() {
+    let mut arguments = MySqlArguments::default();
+    arguments.add(1_i32).unwrap();
+    arguments.add_attribute("a", "x").unwrap();
+
+    let execute = Execute {
+        statement: 1,
+        arguments: &arguments,
+    };
+
+    let mut buf = Vec::new();
+    execute.encode_with(&mut buf, Capabilities::QUERY_ATTRIBUTES);
+
+    assert_eq!(
+        buf,
+        b"\x17\x01\x00\x00\x00\x08\x01\x00\x00\x00\
+          \x02\x00\x01\
+          \x03\x00\x00\
+          \xfd\x00\x01a\
+          \x01\x00\x00\x00\
+          \x01x"
+    );
+
+    let mut buf = Vec::new();
+    execute.encode_with(&mut buf, Capabilities::empty());
+
+    assert_eq!(
+        buf,
+        b"\x17\x01\x00\x00\x00\x00\x01\x00\x00\x00\
+          \x00\x01\
+          \x03\x00\
+          \x01\x00\x00\x00"
+    );
+}
@@ -4,7 +4,7 @@ mod prepare_ok;
 mod row;
 mod stmt_close;
 
-pub(crate) use execute::Execute;
+pub(crate) use execute::{encode_type, Execute};
 pub(crate) use prepare::Prepare;
 pub(crate) use prepare_ok::PrepareOk;
 pub(crate) use row::BinaryRow;
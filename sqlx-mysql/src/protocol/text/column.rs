@@ -194,6 +194,7 @@ impl ColumnType {
             ColumnType::Bit => "BIT",
             ColumnType::Enum => "ENUM",
             ColumnType::Set => "SET",
+            ColumnType::Decimal | ColumnType::NewDecimal if is_unsigned => "DECIMAL UNSIGNED",
             ColumnType::Decimal | ColumnType::NewDecimal => "DECIMAL",
             ColumnType::Geometry => "GEOMETRY",
             ColumnType::Json => "JSON",
@@ -1,14 +1,46 @@
-use crate::io::Encode;
+use crate::arguments::MySqlQueryAttribute;
+use crate::io::{Encode, MySqlBufMutExt};
+use crate::protocol::statement::encode_type;
 use crate::protocol::Capabilities;
 
 // https://dev.mysql.com/doc/internals/en/com-query.html
 
 #[derive(Debug)]
-pub(crate) struct Query<'q>(pub(crate) &'q str);
+pub(crate) struct Query<'q>(pub(crate) &'q str, pub(crate) &'q [MySqlQueryAttribute]);
 
 impl Encode<'_, Capabilities> for Query<'_> {
-    fn encode_with(&self, buf: &mut Vec<u8>, _: Capabilities) {
+    fn encode_with(&self, buf: &mut Vec<u8>, capabilities: Capabilities) {
         buf.push(0x03); // COM_QUERY
+
+        if capabilities.contains(Capabilities::QUERY_ATTRIBUTES) {
+            let attributes = self.1;
+
+            buf.put_uint_lenenc(attributes.len() as u64); // parameter_count
+            buf.put_uint_lenenc(1); // parameter_set_count (always 1)
+
+            if !attributes.is_empty() {
+                let mut null_bitmap = vec![0_u8; attributes.len().div_ceil(8)];
+
+                for (index, attribute) in attributes.iter().enumerate() {
+                    if attribute.value.is_none() {
+                        null_bitmap[index / 8] |= 1 << (index % 8);
+                    }
+                }
+
+                buf.extend(&null_bitmap);
+                buf.push(1); // new_params_bind_flag (always 1)
+
+                for attribute in attributes {
+                    encode_type(buf, &attribute.type_info);
+                    buf.put_str_lenenc(&attribute.name);
+                }
+
+                for attribute in attributes {
+                    buf.extend(attribute.value.iter().flatten());
+                }
+            }
+        }
+
         buf.extend(self.0.as_bytes())
     }
 }
@@ -1,9 +1,12 @@
 use std::iter::{Extend, IntoIterator};
 
+use bytes::Bytes;
+
 #[derive(Debug, Default)]
 pub struct MySqlQueryResult {
     pub(super) rows_affected: u64,
     pub(super) last_insert_id: u64,
+    pub(super) session_state_changes: Vec<MySqlSessionStateChange>,
 }
 
 impl MySqlQueryResult {
@@ -14,6 +17,33 @@ impl MySqlQueryResult {
     pub fn rows_affected(&self) -> u64 {
         self.rows_affected
     }
+
+    /// The changes to the session state reported by the server for this query.
+    ///
+    /// These are only reported if enabled with
+    /// [`MySqlConnectOptions::track_session_state()`][crate::MySqlConnectOptions::track_session_state].
+    /// Which changes are reported is controlled by the `session_track_*` system variables,
+    /// e.g. `session_track_gtids` or `session_track_system_variables`.
+    pub fn session_state_changes(&self) -> &[MySqlSessionStateChange] {
+        &self.session_state_changes
+    }
+
+    /// The GTIDs reported by the server after this query, if any.
+    ///
+    /// This requires
+    /// [`MySqlConnectOptions::track_session_state()`][crate::MySqlConnectOptions::track_session_state],
+    /// and `session_track_gtids` to be set to `OWN_GTID` or `ALL_GTIDS` for the session.
+    /// The GTIDs can be used to wait for a replica to catch up on a write
+    /// (e.g. with `WAIT_FOR_EXECUTED_GTID_SET()`) before reading from it.
+    pub fn gtids(&self) -> Option<&str> {
+        self.session_state_changes
+            .iter()
+            .rev()
+            .find_map(|change| match change {
+                MySqlSessionStateChange::Gtids(gtids) => Some(&**gtids),
+                _ => None,
+            })
+    }
 }
 
 impl Extend<MySqlQueryResult> for MySqlQueryResult {
@@ -21,6 +51,36 @@ impl Extend<MySqlQueryResult> for MySqlQueryResult {
         for elem in iter {
             self.rows_affected += elem.rows_affected;
             self.last_insert_id = elem.last_insert_id;
+            self.session_state_changes
+                .extend(elem.session_state_changes);
         }
     }
 }
+
+/// A change to the session state reported by the server in an OK packet.
+///
+/// See [MySQL: Tracking Client Session State](https://dev.mysql.com/doc/refman/8.0/en/session-state-tracking.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MySqlSessionStateChange {
+    /// A tracked system variable was assigned (`session_track_system_variables`).
+    SystemVariable { name: String, value: String },
+
+    /// The default schema was changed (`session_track_schema`).
+    Schema(String),
+
+    /// Some part of the session state was changed (`session_track_state_change`).
+    StateChange(bool),
+
+    /// The GTIDs of committed transactions (`session_track_gtids`).
+    Gtids(String),
+
+    /// The characteristics of the current transaction (`session_track_transaction_info`).
+    TransactionCharacteristics(String),
+
+    /// The state of the current transaction (`session_track_transaction_info`).
+    TransactionState(String),
+
+    /// A change of a type not known to SQLx, with its raw data.
+    Unknown { r#type: u8, data: Bytes },
+}
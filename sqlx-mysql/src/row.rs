@@ -15,6 +15,7 @@ pub struct MySqlRow {
     pub(crate) format: MySqlValueFormat,
     pub(crate) columns: Arc<Vec<MySqlColumn>>,
     pub(crate) column_names: Arc<HashMap<UStr, usize>>,
+    pub(crate) strict_bools: bool,
 }
 
 impl Row for MySqlRow {
@@ -37,6 +38,7 @@ impl Row for MySqlRow {
             row: Some(&self.row.storage),
             type_info: column.type_info.clone(),
             value,
+            strict_bools: self.strict_bools,
         })
     }
 }
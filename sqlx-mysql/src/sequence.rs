@@ -0,0 +1,87 @@
+use futures_core::future::BoxFuture;
+
+use sqlx_core::sequence::SequenceSupport;
+
+use crate::error::Error;
+use crate::executor::Executor;
+use crate::query::query;
+use crate::query_as::query_as;
+use crate::query_scalar::query_scalar;
+use crate::{MySql, MySqlConnection};
+
+impl SequenceSupport for MySql {
+    fn next_sequence_values<'c>(
+        conn: &'c mut MySqlConnection,
+        name: &'c str,
+        n: u32,
+    ) -> BoxFuture<'c, Result<Vec<i64>, Error>> {
+        Box::pin(async move {
+            // MariaDB has sequences, but MySQL doesn't; this finds nothing on the latter.
+            // language=MySQL
+            let (is_sequence, has_table): (i64, i64) = query_as(
+                "SELECT \
+                 EXISTS (SELECT 1 FROM information_schema.tables WHERE table_schema = database() \
+                     AND table_name = ? AND table_type = 'SEQUENCE'), \
+                 EXISTS (SELECT 1 FROM information_schema.tables WHERE table_schema = database() \
+                     AND table_name = '_sqlx_sequences')",
+            )
+            .bind(name)
+            .fetch_one(&mut *conn)
+            .await?;
+
+            if is_sequence != 0 {
+                let name = format!("`{}`", name.replace('`', "``"));
+                let mut values = Vec::with_capacity(n as usize);
+
+                for _ in 0..n {
+                    values.push(
+                        query_scalar(&format!("SELECT NEXTVAL({name})"))
+                            .fetch_one(&mut *conn)
+                            .await?,
+                    );
+                }
+
+                return Ok(values);
+            }
+
+            if has_table == 0 {
+                // DDL implicitly commits the current transaction
+                if conn.inner.transaction_depth > 0 {
+                    return Err(Error::Configuration(
+                        "the `_sqlx_sequences` table of emulated sequences cannot be created \
+                         in a transaction; allocate a value outside of one first"
+                            .into(),
+                    ));
+                }
+
+                // language=MySQL
+                conn.execute(
+                    "CREATE TABLE IF NOT EXISTS _sqlx_sequences (
+                        name VARCHAR(255) PRIMARY KEY,
+                        value BIGINT NOT NULL
+                    )",
+                )
+                .await?;
+            }
+
+            // `LAST_INSERT_ID(expr)` makes the server return the new value of the counter
+            // as the last insert ID of the statement.
+            // language=MySQL
+            let last = query(
+                "INSERT INTO _sqlx_sequences (name, value) VALUES (?, LAST_INSERT_ID(?)) \
+                 ON DUPLICATE KEY UPDATE value = LAST_INSERT_ID(value + ?)",
+            )
+            .bind(name)
+            .bind(i64::from(n))
+            .bind(i64::from(n))
+            .execute(&mut *conn)
+            .await?
+            .last_insert_id();
+
+            let last = i64::try_from(last)
+                .map_err(|_| err_protocol!("sequence {name:?} overflowed: {last}"))?;
+
+            Ok((last - i64::from(n) + 1..=last).collect())
+        })
+    }
+}
@@ -0,0 +1,169 @@
+use std::borrow::Cow;
+
+use bytes::Buf;
+use sqlx_core::row::{ColumnValue, Row, SerializeColumns, SerializeRow};
+use sqlx_core::type_info::TypeInfo;
+use sqlx_core::types::JsonValue;
+use sqlx_core::value::ValueRef;
+
+use crate::decode::Decode;
+use crate::error::BoxDynError;
+use crate::protocol::text::{ColumnFlags, ColumnType};
+use crate::types::MySqlTime;
+use crate::{MySql, MySqlRow, MySqlValueFormat, MySqlValueRef};
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+
+/// Columns of `GEOMETRY` and other types which have no JSON equivalent return an error;
+/// convert them in the query instead, e.g. with `ST_AsText()`.
+impl SerializeColumns for MySqlRow {
+    fn column_value(&self, index: usize) -> Result<ColumnValue<'_>, BoxDynError> {
+        let value = self.try_get_raw(index)?;
+
+        if value.is_null() {
+            return Ok(ColumnValue::Null);
+        }
+
+        let ty = &value.type_info;
+        let unsigned = ty.flags.contains(ColumnFlags::UNSIGNED);
+
+        Ok(match ty.r#type {
+            // `BOOLEAN` is an alias of `TINYINT(1)`
+            ColumnType::Tiny if ty.max_size == Some(1) => {
+                ColumnValue::Bool(decode::<i64>(value.clone())? != 0)
+            }
+
+            ColumnType::Tiny
+            | ColumnType::Short
+            | ColumnType::Int24
+            | ColumnType::Long
+            | ColumnType::LongLong
+            | ColumnType::Year
+                if !unsigned =>
+            {
+                ColumnValue::Int(decode(value.clone())?)
+            }
+
+            ColumnType::Tiny
+            | ColumnType::Short
+            | ColumnType::Int24
+            | ColumnType::Long
+            | ColumnType::LongLong
+            | ColumnType::Year
+            | ColumnType::Bit => ColumnValue::UInt(decode(value.clone())?),
+
+            ColumnType::Float => ColumnValue::Float(decode::<f32>(value.clone())?.into()),
+            ColumnType::Double => ColumnValue::Float(decode(value.clone())?),
+
+            // DECIMAL is sent as text in both protocols
+            ColumnType::Decimal | ColumnType::NewDecimal => {
+                ColumnValue::Decimal(Cow::Borrowed(value.as_str()?))
+            }
+
+            ColumnType::Json => ColumnValue::Json(decode::<JsonValue>(value.clone())?),
+
+            ColumnType::VarChar
+            | ColumnType::VarString
+            | ColumnType::String
+            | ColumnType::TinyBlob
+            | ColumnType::MediumBlob
+            | ColumnType::LongBlob
+            | ColumnType::Blob
+                if ty.flags.contains(ColumnFlags::BINARY) =>
+            {
+                ColumnValue::Bytes(Cow::Borrowed(value.as_bytes()?))
+            }
+
+            ColumnType::VarChar
+            | ColumnType::VarString
+            | ColumnType::String
+            | ColumnType::TinyBlob
+            | ColumnType::MediumBlob
+            | ColumnType::LongBlob
+            | ColumnType::Blob
+            | ColumnType::Enum
+            | ColumnType::Set => ColumnValue::Text(Cow::Borrowed(value.as_str()?)),
+
+            ColumnType::Time => {
+                let time = decode::<MySqlTime>(value.clone())?;
+
+                if time.is_valid_time_of_day() {
+                    let seconds = i64::from(time.hours()) * 3600
+                        + i64::from(time.minutes()) * 60
+                        + i64::from(time.seconds());
+
+                    ColumnValue::Time(seconds * MICROS_PER_SECOND + i64::from(time.microseconds()))
+                } else {
+                    // a negative interval or one of a day or more
+                    ColumnValue::Text(Cow::Owned(time.to_string()))
+                }
+            }
+
+            ColumnType::Date | ColumnType::Datetime | ColumnType::Timestamp => {
+                match value.format() {
+                    MySqlValueFormat::Binary => binary_date_value(ty.r#type, value.as_bytes()?)?,
+
+                    // passed on as MySQL formats it
+                    MySqlValueFormat::Text => ColumnValue::Text(Cow::Borrowed(value.as_str()?)),
+                }
+            }
+
+            _ => return Err(format!("cannot serialize a value of type {}", ty.name()).into()),
+        })
+    }
+}
+
+impl serde::Serialize for MySqlRow {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializeRow::new(self).serialize(serializer)
+    }
+}
+
+// `DATE`, `DATETIME` and `TIMESTAMP` in the binary protocol, with the length prefix
+fn binary_date_value(ty: ColumnType, buf: &[u8]) -> Result<ColumnValue<'static>, BoxDynError> {
+    let Some((&len, mut buf)) = buf.split_first() else {
+        return Err("empty buffer".into());
+    };
+
+    if len == 0 {
+        // the "zero" date, which is not a valid date
+        return Ok(ColumnValue::Text(Cow::Borrowed(match ty {
+            ColumnType::Date => "0000-00-00",
+            _ => "0000-00-00 00:00:00",
+        })));
+    }
+
+    if buf.len() < usize::from(len) || len < 4 {
+        return Err(format!("expected {len} bytes for a date, got {}", buf.len()).into());
+    }
+
+    let year = buf.get_u16_le().into();
+    let month = buf.get_u8().into();
+    let day = buf.get_u8().into();
+
+    if ty == ColumnType::Date {
+        return ColumnValue::date(year, month, day).ok_or_else(|| "date out of range".into());
+    }
+
+    let mut micros = 0;
+
+    if len >= 7 {
+        let hour = i64::from(buf.get_u8());
+        let minute = i64::from(buf.get_u8());
+        let second = i64::from(buf.get_u8());
+
+        micros = (hour * 3600 + minute * 60 + second) * MICROS_PER_SECOND;
+
+        if len >= 11 {
+            micros += i64::from(buf.get_u32_le());
+        }
+    }
+
+    // `TIMESTAMP` values are sent in the time zone of the session
+    ColumnValue::timestamp(year, month, day, micros, false)
+        .ok_or_else(|| "timestamp out of range".into())
+}
+
+fn decode<'r, T: Decode<'r, MySql>>(value: MySqlValueRef<'r>) -> Result<T, BoxDynError> {
+    T::decode(value)
+}
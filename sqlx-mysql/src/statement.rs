@@ -20,7 +20,7 @@ pub struct MySqlStatement<'q> {
 pub(crate) struct MySqlStatementMetadata {
     pub(crate) columns: Arc<Vec<MySqlColumn>>,
     pub(crate) column_names: Arc<HashMap<UStr, usize>>,
-    pub(crate) parameters: usize,
+    pub(crate) parameters: Arc<Vec<MySqlTypeInfo>>,
 }
 
 impl<'q> Statement<'q> for MySqlStatement<'q> {
@@ -38,7 +38,7 @@ impl<'q> Statement<'q> for MySqlStatement<'q> {
     }
 
     fn parameters(&self) -> Option<Either<&[MySqlTypeInfo], usize>> {
-        Some(Either::Right(self.metadata.parameters))
+        Some(Either::Left(&self.metadata.parameters))
     }
 
     fn columns(&self) -> &[MySqlColumn] {
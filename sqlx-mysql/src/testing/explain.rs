@@ -0,0 +1,49 @@
+use std::fmt::Write;
+
+use futures_core::future::BoxFuture;
+
+use crate::column::Column;
+use crate::error::Error;
+use crate::executor::Executor;
+use crate::row::Row;
+use crate::{MySqlConnection, MySqlRow};
+
+use super::plan::{ExplainPlan, QueryPlan};
+
+/// Columns of the `EXPLAIN` output which are estimates rather than part of the plan.
+const VOLATILE_COLUMNS: &[&str] = &["rows", "filtered"];
+
+impl ExplainPlan for MySqlConnection {
+    fn explain_plan<'e>(&'e mut self, query: &'e str) -> BoxFuture<'e, Result<QueryPlan, Error>> {
+        Box::pin(async move {
+            let explain = format!("EXPLAIN {query}");
+
+            // sent without arguments so this uses the text protocol and every value is a string
+            let rows = self.fetch_all(&*explain).await?;
+
+            Ok(QueryPlan::new(
+                rows.iter().map(render).collect::<Result<_, _>>()?,
+            ))
+        })
+    }
+}
+
+fn render(row: &MySqlRow) -> Result<String, Error> {
+    let mut line = String::new();
+
+    for column in row.columns() {
+        if VOLATILE_COLUMNS.contains(&column.name()) {
+            continue;
+        }
+
+        if let Some(value) = row.try_get_unchecked::<Option<String>, _>(column.ordinal())? {
+            if !line.is_empty() {
+                line.push(' ');
+            }
+
+            let _ = write!(line, "{}={value}", column.name());
+        }
+    }
+
+    Ok(line)
+}
@@ -1,29 +1,33 @@
-use std::fmt::Write;
 use std::ops::Deref;
 use std::str::FromStr;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, SystemTime};
 
 use futures_core::future::BoxFuture;
+use futures_util::{stream, StreamExt, TryStreamExt};
 
 use once_cell::sync::OnceCell;
 
-use crate::connection::Connection;
-
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::pool::{Pool, PoolOptions};
 use crate::query::query;
+use crate::query_as::query_as;
 use crate::query_builder::QueryBuilder;
 use crate::query_scalar::query_scalar;
+use crate::row::Row;
 use crate::{MySql, MySqlConnectOptions, MySqlConnection};
 
 pub(crate) use sqlx_core::testing::*;
 
+mod explain;
+
 // Using a blocking `OnceCell` here because the critical sections are short.
 static MASTER_POOL: OnceCell<Pool<MySql>> = OnceCell::new();
 // Automatically delete any databases created before the start of the test binary.
 static DO_CLEANUP: AtomicBool = AtomicBool::new(true);
+// The number of test databases dropped at once by `do_cleanup()`.
+const CLEANUP_CONCURRENCY: usize = 8;
 
 impl TestSupport for MySql {
     fn test_context(args: &TestArgs) -> BoxFuture<'_, Result<TestContext<Self>, Error>> {
@@ -56,24 +60,25 @@ impl TestSupport for MySql {
         Box::pin(async move {
             let url = dotenvy::var("DATABASE_URL").expect("DATABASE_URL must be set");
 
-            let mut conn = MySqlConnection::connect(&url).await?;
+            let pool = PoolOptions::new()
+                .max_connections(CLEANUP_CONCURRENCY as u32)
+                .connect(&url)
+                .await?;
 
             let now = SystemTime::now()
                 .duration_since(SystemTime::UNIX_EPOCH)
                 .unwrap();
 
-            let num_deleted = do_cleanup(&mut conn, now).await?;
-            let _ = conn.close().await;
-            Ok(Some(num_deleted))
+            let num_deleted = do_cleanup(&pool, now).await;
+            pool.close().await;
+            Ok(Some(num_deleted?))
         })
     }
 
     fn snapshot(
-        _conn: &mut Self::Connection,
+        conn: &mut Self::Connection,
     ) -> BoxFuture<'_, Result<FixtureSnapshot<Self>, Error>> {
-        // TODO: I want to get the testing feature out the door so this will have to wait,
-        // but I'm keeping the code around for now because I plan to come back to it.
-        todo!()
+        Box::pin(async move { snapshot(conn).await })
     }
 }
 
@@ -134,7 +139,7 @@ async fn test_context(args: &TestArgs) -> Result<TestContext<MySql>, Error> {
 
     // Only run cleanup if the test binary just started.
     if DO_CLEANUP.swap(false, Ordering::SeqCst) {
-        do_cleanup(&mut conn, now).await?;
+        do_cleanup(master_pool, now).await?;
     }
 
     query("insert into _sqlx_test_databases(test_path) values (?)")
@@ -172,7 +177,7 @@ async fn test_context(args: &TestArgs) -> Result<TestContext<MySql>, Error> {
     })
 }
 
-async fn do_cleanup(conn: &mut MySqlConnection, created_before: Duration) -> Result<usize, Error> {
+async fn do_cleanup(pool: &Pool<MySql>, created_before: Duration) -> Result<usize, Error> {
     // since SystemTime is not monotonic we added a little margin here to avoid race conditions with other threads
     let created_before_as_secs = created_before.as_secs() - 2;
     let delete_db_ids: Vec<u64> = query_scalar(
@@ -180,47 +185,195 @@ async fn do_cleanup(conn: &mut MySqlConnection, created_before: Duration) -> Res
             where created_at < from_unixtime(?)",
     )
     .bind(created_before_as_secs)
-    .fetch_all(&mut *conn)
+    .fetch_all(pool)
     .await?;
 
     if delete_db_ids.is_empty() {
         return Ok(0);
     }
 
-    let mut deleted_db_ids = Vec::with_capacity(delete_db_ids.len());
+    // Dropping a database mostly waits on the server deleting its files,
+    // so several are dropped at once, each on a connection of its own.
+    let mut drops = stream::iter(delete_db_ids)
+        .map(|db_id| async move {
+            let db_name = db_name(db_id);
+
+            // `if exists` because another process may be cleaning up the same databases.
+            match pool
+                .execute(&*format!("drop database if exists {db_name}"))
+                .await
+            {
+                Ok(_deleted) => Ok(Some(db_id)),
+                // Assume a database error just means the DB is still in use.
+                Err(Error::Database(dbe)) => {
+                    eprintln!("could not clean test database {db_id:?}: {dbe}");
+                    Ok(None)
+                }
+                // Bubble up other errors
+                Err(e) => Err(e),
+            }
+        })
+        .buffer_unordered(CLEANUP_CONCURRENCY);
+
+    let mut deleted_db_ids = Vec::new();
 
-    let mut command = String::new();
+    while let Some(deleted) = drops.try_next().await? {
+        deleted_db_ids.extend(deleted);
+    }
 
-    for db_id in delete_db_ids {
-        command.clear();
+    // Keep well under the limit of 65535 bind parameters in a statement.
+    for db_ids in deleted_db_ids.chunks(1000) {
+        let mut query = QueryBuilder::new("delete from _sqlx_test_databases where db_id in (");
 
-        let db_name = db_name(db_id);
+        let mut separated = query.separated(",");
 
-        writeln!(command, "drop database if exists {db_name}").ok();
-        match conn.execute(&*command).await {
-            Ok(_deleted) => {
-                deleted_db_ids.push(db_id);
-            }
-            // Assume a database error just means the DB is still in use.
-            Err(Error::Database(dbe)) => {
-                eprintln!("could not clean test database {db_id:?}: {dbe}")
-            }
-            // Bubble up other errors
-            Err(e) => return Err(e),
+        for db_id in db_ids {
+            separated.push_bind(db_id);
         }
+
+        query.push(")").build().execute(pool).await?;
     }
 
-    let mut query = QueryBuilder::new("delete from _sqlx_test_databases where db_id in (");
+    Ok(deleted_db_ids.len())
+}
 
-    let mut separated = query.separated(",");
+async fn snapshot(conn: &mut MySqlConnection) -> Result<FixtureSnapshot<MySql>, Error> {
+    // language=MySQL
+    let tables: Vec<String> = query_scalar(
+        r#"
+        select cast(table_name as char) from information_schema.tables
+        where table_schema = database()
+            and table_type = 'BASE TABLE'
+            and table_name <> '_sqlx_migrations'
+        order by table_name
+    "#,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
 
-    for db_id in &deleted_db_ids {
-        separated.push_bind(db_id);
+    // Foreign keys of a table on itself would be reported as a cycle, and don't affect the
+    // order of the tables anyway.
+    // language=MySQL
+    let foreign_keys: Vec<(String, String, String, String)> = query_as(
+        r#"
+        select
+            cast(table_name as char),
+            cast(column_name as char),
+            cast(referenced_table_name as char),
+            cast(referenced_column_name as char)
+        from information_schema.key_column_usage
+        where table_schema = database()
+            and referenced_table_schema = database()
+            and referenced_table_name <> table_name
+    "#,
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let mut snapshot = FixtureSnapshot::new();
+
+    for table in tables {
+        // Generated columns cannot be inserted into.
+        // language=MySQL
+        let columns: Vec<(String, String)> = query_as(
+            r#"
+            select cast(column_name as char), cast(data_type as char)
+            from information_schema.columns
+            where table_schema = database()
+                and table_name = ?
+                and extra not like '%GENERATED%'
+            order by ordinal_position
+        "#,
+        )
+        .bind(&table)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        if columns.is_empty() {
+            continue;
+        }
+
+        // Have the server format each value as an SQL literal. Binary values are written
+        // in hex as they may not be valid UTF-8; the literals may still be flagged as binary
+        // strings depending on the column's collation, so they are decoded unchecked.
+        let mut select = QueryBuilder::<MySql>::new("select ");
+        let mut separated = select.separated(", ");
+
+        for (column, data_type) in &columns {
+            let column = quote_identifier(column);
+
+            if is_binary_data_type(data_type) {
+                separated.push(format_args!(
+                    "if({column} is null, 'NULL', concat('0x', hex({column})))"
+                ));
+            } else {
+                separated.push(format_args!("quote({column})"));
+            }
+        }
+
+        select.push(format_args!(" from {}", quote_identifier(&table)));
+
+        let rows = conn
+            .fetch_all(select.sql())
+            .await?
+            .iter()
+            .map(|row| {
+                (0..columns.len())
+                    .map(|i| row.try_get_unchecked::<String, _>(i))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let table_foreign_keys = foreign_keys
+            .iter()
+            .filter(|(name, ..)| *name == table)
+            .map(|(_, column, ref_table, ref_column)| {
+                (
+                    quote_identifier(column),
+                    quote_identifier(ref_table),
+                    quote_identifier(ref_column),
+                )
+            })
+            .collect();
+
+        snapshot.add_table(
+            &quote_identifier(&table),
+            columns
+                .iter()
+                .map(|(column, _)| quote_identifier(column))
+                .collect(),
+            rows,
+            table_foreign_keys,
+        );
     }
 
-    query.push(")").build().execute(&mut *conn).await?;
+    Ok(snapshot)
+}
 
-    Ok(deleted_db_ids.len())
+fn is_binary_data_type(data_type: &str) -> bool {
+    matches!(
+        &*data_type.to_ascii_lowercase(),
+        "binary"
+            | "varbinary"
+            | "tinyblob"
+            | "blob"
+            | "mediumblob"
+            | "longblob"
+            | "bit"
+            | "geometry"
+            | "point"
+            | "linestring"
+            | "polygon"
+            | "multipoint"
+            | "multilinestring"
+            | "multipolygon"
+            | "geometrycollection"
+            | "geomcollection"
+    )
+}
+
+fn quote_identifier(name: &str) -> String {
+    format!("`{}`", name.replace('`', "``"))
 }
 
 fn db_name(id: u64) -> String {
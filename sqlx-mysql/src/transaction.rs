@@ -25,6 +25,43 @@ impl TransactionManager for MySqlTransactionManager {
         })
     }
 
+    fn begin_with(
+        conn: &mut MySqlConnection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            if conn.inner.transaction_depth > 0 {
+                return Err(nested_transaction_options_error());
+            }
+
+            if options.get_deferrable() {
+                return Err(Error::Configuration(
+                    "MySQL does not support deferrable transactions".into(),
+                ));
+            }
+
+            if let Some(isolation_level) = options.get_isolation_level() {
+                // without `GLOBAL` or `SESSION`, this only applies to the next transaction
+                conn.execute(&*format!(
+                    "SET TRANSACTION ISOLATION LEVEL {}",
+                    isolation_level.as_sql()
+                ))
+                .await?;
+            }
+
+            match options.get_access_mode() {
+                Some(access_mode) => {
+                    conn.execute(&*format!("START TRANSACTION {}", access_mode.as_sql()))
+                        .await?
+                }
+                None => conn.execute("START TRANSACTION").await?,
+            };
+            conn.inner.transaction_depth = 1;
+
+            Ok(())
+        })
+    }
+
     fn commit(conn: &mut MySqlConnection) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
             let depth = conn.inner.transaction_depth;
@@ -59,7 +96,7 @@ impl TransactionManager for MySqlTransactionManager {
             conn.inner.stream.sequence_id = 0;
             conn.inner
                 .stream
-                .write_packet(Query(&rollback_ansi_transaction_sql(depth)));
+                .write_packet(Query(&rollback_ansi_transaction_sql(depth), &[]));
 
             conn.inner.transaction_depth = depth - 1;
         }
@@ -1,10 +1,14 @@
 // Type mappings used by the macros and `Debug` impls.
 
-#[allow(unused_imports)]
-use sqlx_core as sqlx;
-
 use crate::MySql;
 
+// The paths used below will also be emitted by the macros so they have to match the final facade.
+#[allow(unused_imports, dead_code)]
+mod sqlx {
+    pub use crate as mysql;
+    pub use sqlx_core::*;
+}
+
 impl_type_checking!(
     MySql {
         u8,
@@ -39,6 +43,10 @@ impl_type_checking!(
         #[cfg(all(feature = "chrono", not(feature = "time")))]
         sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>,
 
+        // only inferred for `TIME` with `SQLX_MYSQL_TIME_AS_DURATION=true`
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        sqlx::types::chrono::TimeDelta,
+
         #[cfg(feature = "time")]
         sqlx::types::time::Time,
 
@@ -51,12 +59,19 @@ impl_type_checking!(
         #[cfg(feature = "time")]
         sqlx::types::time::OffsetDateTime,
 
+        // only inferred for `TIME` with `SQLX_MYSQL_TIME_AS_DURATION=true`
+        #[cfg(feature = "time")]
+        sqlx::types::time::Duration,
+
         #[cfg(feature = "bigdecimal")]
         sqlx::types::BigDecimal,
 
         #[cfg(feature = "rust_decimal")]
         sqlx::types::Decimal,
 
+        // exact `DECIMAL` values if neither of the above is enabled
+        sqlx::mysql::types::MySqlDecimal,
+
         #[cfg(feature = "json")]
         sqlx::types::JsonValue,
     },
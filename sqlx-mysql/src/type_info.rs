@@ -42,12 +42,18 @@ impl MySqlTypeInfo {
             }
 
             ColumnType::Json => Some("json"),
-            ColumnType::NewDecimal => Some("bigdecimal"),
 
             _ => None,
         }
     }
 
+    #[doc(hidden)]
+    pub fn __is_binary16(&self) -> bool {
+        self.r#type == ColumnType::String
+            && self.flags.contains(ColumnFlags::BINARY)
+            && self.max_size == Some(16)
+    }
+
     pub(crate) fn from_column(column: &ColumnDefinition) -> Self {
         Self {
             r#type: column.r#type,
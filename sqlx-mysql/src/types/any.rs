@@ -0,0 +1,270 @@
+//! The representations of the `Any` driver for `DECIMAL`, `DATE`, `TIME`, `DATETIME`,
+//! `TIMESTAMP` and UUIDs.
+
+use bytes::Buf;
+use sqlx_core::any::{AnyDate, AnyDecimal, AnyTime, AnyTimestamp, AnyUuid};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::{BoxDynError, UnexpectedNullError};
+use crate::io::MySqlBufMutExt;
+use crate::protocol::text::ColumnType;
+use crate::type_info::MySqlTypeInfo;
+use crate::types::{MySqlTime, MySqlTimeSign, Type};
+use crate::{MySql, MySqlValueFormat, MySqlValueRef};
+
+impl Type<MySql> for AnyDecimal {
+    fn type_info() -> MySqlTypeInfo {
+        MySqlTypeInfo::binary(ColumnType::NewDecimal)
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        matches!(ty.r#type, ColumnType::Decimal | ColumnType::NewDecimal)
+    }
+}
+
+impl Encode<'_, MySql> for AnyDecimal {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        buf.put_str_lenenc(&self.0);
+
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, MySql> for AnyDecimal {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        // DECIMAL is sent as text in both protocols
+        Ok(AnyDecimal(value.as_str()?.to_owned()))
+    }
+}
+
+impl Type<MySql> for AnyDate {
+    fn type_info() -> MySqlTypeInfo {
+        MySqlTypeInfo::binary(ColumnType::Date)
+    }
+}
+
+impl Encode<'_, MySql> for AnyDate {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        buf.push(4);
+
+        encode_date(*self, buf)?;
+
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        5
+    }
+}
+
+impl Decode<'_, MySql> for AnyDate {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            MySqlValueFormat::Binary => {
+                let buf = value.as_bytes()?;
+
+                // Row decoding should have left the length prefix.
+                if buf.is_empty() {
+                    return Err("empty buffer".into());
+                }
+
+                decode_date(&buf[1..])?.ok_or_else(|| UnexpectedNullError.into())
+            }
+
+            MySqlValueFormat::Text => value.as_str()?.parse(),
+        }
+    }
+}
+
+impl Type<MySql> for AnyTime {
+    fn type_info() -> MySqlTypeInfo {
+        MySqlTime::type_info()
+    }
+}
+
+impl Encode<'_, MySql> for AnyTime {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        let (hour, minute, second, micro) = self.to_hms_micro();
+
+        MySqlTime::new(
+            MySqlTimeSign::Positive,
+            hour,
+            minute as u8,
+            second as u8,
+            micro,
+        )?
+        .encode_by_ref(buf)
+    }
+}
+
+/// Decode from a `TIME` value.
+///
+/// ### Errors
+/// Returns an error if the `TIME` value is negative or exceeds `23:59:59.999999`.
+impl Decode<'_, MySql> for AnyTime {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let time = MySqlTime::decode(value)?;
+
+        time.is_valid_time_of_day()
+            .then(|| {
+                AnyTime::from_hms_micro(
+                    time.hours(),
+                    time.minutes().into(),
+                    time.seconds().into(),
+                    time.microseconds(),
+                )
+            })
+            .flatten()
+            .ok_or_else(|| format!("`TIME` value is not a time of day: {time}").into())
+    }
+}
+
+impl Type<MySql> for AnyTimestamp {
+    fn type_info() -> MySqlTypeInfo {
+        MySqlTypeInfo::binary(ColumnType::Datetime)
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        matches!(ty.r#type, ColumnType::Datetime | ColumnType::Timestamp)
+    }
+}
+
+/// Note: `TIMESTAMP` values assume the connection's `time_zone` is set to `+00:00` (UTC).
+impl Encode<'_, MySql> for AnyTimestamp {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        let len = Encode::<MySql>::size_hint(self) - 1;
+        buf.push(len as u8);
+
+        encode_date(self.date(), buf)?;
+
+        if len > 4 {
+            let (hour, minute, second, micro) = self.time().to_hms_micro();
+
+            buf.push(hour as u8);
+            buf.push(minute as u8);
+            buf.push(second as u8);
+
+            if len > 7 {
+                buf.extend(micro.to_le_bytes());
+            }
+        }
+
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        match self.time().to_hms_micro() {
+            // if hour, minutes, seconds and micro_seconds are all 0,
+            // length is 4 and no other field is sent
+            (0, 0, 0, 0) => 5,
+
+            // if micro_seconds is 0, length is 7
+            // and micro_seconds is not sent
+            (_, _, _, 0) => 8,
+
+            // otherwise length is 11
+            (_, _, _, _) => 12,
+        }
+    }
+}
+
+/// Note: `TIMESTAMP` values assume the connection's `time_zone` is set to `+00:00` (UTC).
+impl Decode<'_, MySql> for AnyTimestamp {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            MySqlValueFormat::Binary => {
+                let buf = value.as_bytes()?;
+
+                if buf.is_empty() {
+                    return Err("empty buffer".into());
+                }
+
+                let len = buf[0];
+                let date = decode_date(&buf[1..])?.ok_or(UnexpectedNullError)?;
+
+                let time = if len > 4 {
+                    let mut buf = &buf[5..];
+
+                    let hour = buf.get_u8();
+                    let minute = buf.get_u8();
+                    let second = buf.get_u8();
+                    let micro = if len > 7 { buf.get_u32_le() } else { 0 };
+
+                    AnyTime::from_hms_micro(hour.into(), minute.into(), second.into(), micro)
+                        .ok_or_else(|| {
+                            format!("server returned invalid time: {hour:02}:{minute:02}:{second:02}; micros: {micro}")
+                        })?
+                } else {
+                    AnyTime(0)
+                };
+
+                AnyTimestamp::new(date, time).ok_or_else(|| {
+                    format!("server returned invalid timestamp: {date} {time}").into()
+                })
+            }
+
+            MySqlValueFormat::Text => value.as_str()?.parse(),
+        }
+    }
+}
+
+impl Type<MySql> for AnyUuid {
+    fn type_info() -> MySqlTypeInfo {
+        <&[u8] as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        <&[u8] as Type<MySql>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, MySql> for AnyUuid {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        buf.put_bytes_lenenc(&self.0);
+
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, MySql> for AnyUuid {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = <&[u8] as Decode<MySql>>::decode(value)?;
+
+        bytes
+            .try_into()
+            .map(AnyUuid)
+            .map_err(|_| format!("expected 16 bytes for a UUID, got {}", bytes.len()).into())
+    }
+}
+
+fn encode_date(date: AnyDate, buf: &mut Vec<u8>) -> Result<(), BoxDynError> {
+    let (year, month, day) = date.to_ymd();
+
+    // MySQL supports years from 1000 - 9999
+    let year = u16::try_from(year).map_err(|_| format!("date out of range for Mysql: {date}"))?;
+
+    buf.extend_from_slice(&year.to_le_bytes());
+    buf.push(month as u8);
+    buf.push(day as u8);
+
+    Ok(())
+}
+
+fn decode_date(mut buf: &[u8]) -> Result<Option<AnyDate>, BoxDynError> {
+    match buf.len() {
+        // MySQL specifies that if there are no bytes, this is all zeros
+        0 => Ok(None),
+        4.. => {
+            let year = buf.get_u16_le();
+            let month = buf[0];
+            let day = buf[1];
+
+            let date = AnyDate::from_ymd(year.into(), month.into(), day.into())
+                .ok_or_else(|| format!("server returned invalid date: {year}/{month}/{day}"))?;
+
+            Ok(Some(date))
+        }
+        len => Err(format!("expected at least 4 bytes for date, got {len}").into()),
+    }
+}
@@ -5,7 +5,7 @@ use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::io::MySqlBufMutExt;
 use crate::protocol::text::ColumnType;
-use crate::types::Type;
+use crate::types::{MySqlDecimal, Type};
 use crate::{MySql, MySqlTypeInfo, MySqlValueRef};
 
 impl Type<MySql> for BigDecimal {
@@ -31,3 +31,11 @@ impl Decode<'_, MySql> for BigDecimal {
         Ok(value.as_str()?.parse()?)
     }
 }
+
+impl TryFrom<MySqlDecimal> for BigDecimal {
+    type Error = BoxDynError;
+
+    fn try_from(value: MySqlDecimal) -> Result<Self, Self::Error> {
+        Ok(value.as_str().parse()?)
+    }
+}
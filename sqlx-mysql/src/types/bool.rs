@@ -38,6 +38,16 @@ impl Encode<'_, MySql> for bool {
 
 impl Decode<'_, MySql> for bool {
     fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
-        Ok(<i8 as Decode<MySql>>::decode(value)? != 0)
+        let strict = value.strict_bools;
+
+        match <i8 as Decode<MySql>>::decode(value)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            v if strict => Err(format!(
+                "invalid value {v} for a boolean, expected 0 or 1 (strict booleans are enabled)"
+            )
+            .into()),
+            _ => Ok(true),
+        }
     }
 }
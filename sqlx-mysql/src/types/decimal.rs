@@ -0,0 +1,139 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::io::MySqlBufMutExt;
+use crate::protocol::text::ColumnType;
+use crate::types::Type;
+use crate::{MySql, MySqlTypeInfo, MySqlValueRef};
+
+/// An exact `DECIMAL` value, kept as the text MySQL sends for it.
+///
+/// A `DECIMAL` column may hold up to 65 digits, which is more than `rust_decimal::Decimal`
+/// can represent (28 digits). This type never loses precision, so it can be used to store or
+/// pass along such values, and converted to a numeric type where one is available.
+///
+/// The text is kept as-is, including trailing zeros of the scale of the column, so
+/// `1.50` and `1.5` are *not* equal.
+///
+/// ```rust
+/// use sqlx_mysql::types::MySqlDecimal;
+///
+/// let value: MySqlDecimal = "-12345678901234567890123456789012345.678901234567890123456789012345"
+///     .parse()
+///     .unwrap();
+///
+/// assert!(value.is_negative());
+/// assert_eq!(value.scale(), 30);
+///
+/// assert!("1e10".parse::<MySqlDecimal>().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MySqlDecimal(String);
+
+impl MySqlDecimal {
+    /// The value as text, e.g. `-123.4500`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if the value has a leading minus sign.
+    ///
+    /// Note that MySQL sends `0`, never `-0`, for zero.
+    pub fn is_negative(&self) -> bool {
+        self.0.starts_with('-')
+    }
+
+    /// The number of digits after the decimal point.
+    pub fn scale(&self) -> usize {
+        self.0
+            .split_once('.')
+            .map_or(0, |(_, fraction)| fraction.len())
+    }
+}
+
+impl Display for MySqlDecimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Parses plain decimal notation, e.g. `-123.45`; exponents are not accepted.
+impl FromStr for MySqlDecimal {
+    type Err = BoxDynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix(['-', '+']).unwrap_or(s);
+
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+        let valid = !(integer.is_empty() && fraction.is_empty())
+            && integer.bytes().all(|b| b.is_ascii_digit())
+            && fraction.bytes().all(|b| b.is_ascii_digit());
+
+        if !valid {
+            return Err(format!("invalid decimal: {s:?}").into());
+        }
+
+        Ok(Self(s.to_owned()))
+    }
+}
+
+impl From<MySqlDecimal> for String {
+    fn from(value: MySqlDecimal) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<String> for MySqlDecimal {
+    type Error = BoxDynError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse::<Self>()?;
+
+        Ok(Self(value))
+    }
+}
+
+impl Type<MySql> for MySqlDecimal {
+    fn type_info() -> MySqlTypeInfo {
+        MySqlTypeInfo::binary(ColumnType::NewDecimal)
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        matches!(ty.r#type, ColumnType::Decimal | ColumnType::NewDecimal)
+    }
+}
+
+impl Encode<'_, MySql> for MySqlDecimal {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        buf.put_str_lenenc(&self.0);
+
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, MySql> for MySqlDecimal {
+    fn decode(value: MySqlValueRef<'_>) -> Result<Self, BoxDynError> {
+        // `DECIMAL` is sent as text in both the text and the binary protocol
+        value.as_str()?.parse()
+    }
+}
+
+#[test]
+fn it_parses_decimals() {
+    for s in ["0", "-1", "+1", "12.3400", ".5", "5.", "-0.000001"] {
+        assert_eq!(s.parse::<MySqlDecimal>().unwrap().as_str(), s);
+    }
+
+    for s in ["", "-", ".", "1e3", "1.2.3", "1,5", " 1", "NaN"] {
+        assert!(s.parse::<MySqlDecimal>().is_err(), "{s:?}");
+    }
+
+    let value: MySqlDecimal = "-12.3400".parse().unwrap();
+    assert!(value.is_negative());
+    assert_eq!(value.scale(), 4);
+    assert_eq!("12".parse::<MySqlDecimal>().unwrap().scale(), 0);
+}
@@ -4,6 +4,7 @@ use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::protocol::text::{ColumnFlags, ColumnType};
+use crate::types::uint::year_compatible;
 use crate::types::Type;
 use crate::{MySql, MySqlTypeInfo, MySqlValueFormat, MySqlValueRef};
 
@@ -34,7 +35,7 @@ impl Type<MySql> for i16 {
     }
 
     fn compatible(ty: &MySqlTypeInfo) -> bool {
-        int_compatible(ty)
+        int_compatible(ty) || year_compatible(ty)
     }
 }
 
@@ -44,7 +45,7 @@ impl Type<MySql> for i32 {
     }
 
     fn compatible(ty: &MySqlTypeInfo) -> bool {
-        int_compatible(ty)
+        int_compatible(ty) || year_compatible(ty)
     }
 }
 
@@ -54,7 +55,7 @@ impl Type<MySql> for i64 {
     }
 
     fn compatible(ty: &MySqlTypeInfo) -> bool {
-        int_compatible(ty)
+        int_compatible(ty) || year_compatible(ty)
     }
 }
 
@@ -6,13 +6,13 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `bool`                                | TINYINT(1), BOOLEAN, BOOL (see below)                |
 //! | `i8`                                  | TINYINT                                              |
-//! | `i16`                                 | SMALLINT                                             |
-//! | `i32`                                 | INT                                                  |
-//! | `i64`                                 | BIGINT                                               |
+//! | `i16`                                 | SMALLINT, YEAR                                       |
+//! | `i32`                                 | INT, YEAR                                            |
+//! | `i64`                                 | BIGINT, YEAR                                         |
 //! | `u8`                                  | TINYINT UNSIGNED                                     |
-//! | `u16`                                 | SMALLINT UNSIGNED                                    |
-//! | `u32`                                 | INT UNSIGNED                                         |
-//! | `u64`                                 | BIGINT UNSIGNED                                      |
+//! | `u16`                                 | SMALLINT UNSIGNED, YEAR                              |
+//! | `u32`                                 | INT UNSIGNED, YEAR                                   |
+//! | `u64`                                 | BIGINT UNSIGNED, YEAR                                |
 //! | `f32`                                 | FLOAT                                                |
 //! | `f64`                                 | DOUBLE                                               |
 //! | `&str`, [`String`]                    | VARCHAR, CHAR, TEXT                                  |
@@ -21,7 +21,16 @@
 //! | `Ipv4Addr`                            | INET4 (MariaDB-only), VARCHAR, TEXT                  |
 //! | `Ipv6Addr`                            | INET6 (MariaDB-only), VARCHAR, TEXT                  |
 //! | [`MySqlTime`]                         | TIME (encode and decode full range)                  |
-//! | [`Duration`]                          | TIME (for decoding positive values only)             |
+//! | [`Duration`]                          | TIME (positive values only)                          |
+//! | [`MySqlDecimal`]                      | DECIMAL, DECIMAL UNSIGNED                            |
+//!
+//! ##### Note: Exact `DECIMAL` values
+//! A `DECIMAL` column may hold up to 65 digits. [`MySqlDecimal`] keeps the text of the value,
+//! so it never loses precision, even for values `rust_decimal::Decimal` cannot represent.
+//! It converts to and from `BigDecimal` and `Decimal` if the respective feature is enabled.
+//!
+//! The query macros infer `DECIMAL` columns as [`MySqlDecimal`] if neither the `bigdecimal`
+//! nor the `rust_decimal` feature is enabled.
 //!
 //! ##### Note: `BOOLEAN`/`BOOL` Type
 //! MySQL and MariaDB treat `BOOLEAN` as an alias of the `TINYINT` type:
@@ -33,18 +42,23 @@
 //! using the dynamic query interface, or passing a boolean as a parameter to the query macros
 //! (`query!()` _et al._).
 //!
-//! However, because the MySQL wire protocol does not distinguish between `TINYINT` and `BOOLEAN`,
-//! the query macros cannot know that a `TINYINT` column is semantically a boolean.
-//! By default, they will map a `TINYINT` column as `i8` instead, as that is the safer assumption.
+//! The MySQL wire protocol does not distinguish between `TINYINT(1)` and `BOOLEAN`, so the query
+//! macros infer every `TINYINT(1)` column as `bool`, and other `TINYINT` columns as `i8`.
+//! To infer `TINYINT(1)` as `i8` instead, set `SQLX_MYSQL_TINYINT1_AS_BOOL=false` at build time,
+//! or use the type override syntax for a single column. See the docs for `query!()` and
+//! `query_as!()` for details on this syntax.
 //!
-//! Thus, you must use the type override syntax in the query to tell the macros you are expecting
-//! a `bool` column. See the docs for `query!()` and `query_as!()` for details on this syntax.
+//! A `TINYINT(1)` column may still hold any value from `-128` to `127`, and any value other than
+//! `0` decodes as `true`. Enable
+//! [`MySqlConnectOptions::strict_bools()`][crate::MySqlConnectOptions::strict_bools] to make
+//! values other than `0` and `1` an error instead.
 //!
 //! ### NOTE: MySQL's `TIME` type is signed
 //! MySQL's `TIME` type can be used as either a time-of-day value, or a signed interval.
 //! Thus, it may take on negative values.
 //!
 //! Decoding a [`std::time::Duration`] returns an error if the `TIME` value is negative.
+//! Encoding a [`std::time::Duration`] returns an error if the value exceeds `838:59:59`.
 //!
 //! ### [`chrono`](https://crates.io/crates/chrono)
 //!
@@ -57,7 +71,7 @@
 //! | `chrono::NaiveDateTime`               | DATETIME                                             |
 //! | `chrono::NaiveDate`                   | DATE                                                 |
 //! | `chrono::NaiveTime`                   | TIME (time-of-day only)                              |
-//! | `chrono::TimeDelta`                   | TIME (decodes full range; see note for encoding)     |
+//! | `chrono::TimeDelta`                   | TIME (full range)                                    |
 //!
 //! ### NOTE: MySQL's `TIME` type is dual-purpose
 //! MySQL's `TIME` type can be used as either a time-of-day value, or an interval.
@@ -67,9 +81,12 @@
 //!
 //! The [`MySqlTime`] type supports the full range and it also implements `TryInto<chrono::NaiveTime>`.
 //!
-//! Decoding a `chrono::TimeDelta` also supports the full range.
+//! Encoding and decoding a `chrono::TimeDelta` also supports the full range;
+//! encoding returns an error if the magnitude of the value exceeds `838:59:59`.
 //!
-//! To encode a `chrono::TimeDelta`, convert it to [`MySqlTime`] first using `TryFrom`/`TryInto`.
+//! The query macros infer `TIME` as `chrono::NaiveTime`. As a `TIME` column may hold values that
+//! are negative or exceed a day, set `SQLX_MYSQL_TIME_AS_DURATION=true` at build time to infer
+//! `chrono::TimeDelta` instead, or use the type override syntax for a single column.
 //!
 //! ### [`time`](https://crates.io/crates/time)
 //!
@@ -81,7 +98,7 @@
 //! | `time::OffsetDateTime`                | TIMESTAMP                                            |
 //! | `time::Date`                          | DATE                                                 |
 //! | `time::Time`                          | TIME (time-of-day only)                              |
-//! | `time::Duration`                      | TIME (decodes full range; see note for encoding)     |
+//! | `time::Duration`                      | TIME (full range)                                    |
 //!
 //! ### NOTE: MySQL's `TIME` type is dual-purpose
 //! MySQL's `TIME` type can be used as either a time-of-day value, or an interval.
@@ -91,23 +108,26 @@
 //!
 //! The [`MySqlTime`] type supports the full range, and it also implements `TryInto<time::Time>`.
 //!
-//! Decoding a `time::Duration` also supports the full range.
+//! Encoding and decoding a `time::Duration` also supports the full range;
+//! encoding returns an error if the magnitude of the value exceeds `838:59:59`.
 //!
-//! To encode a `time::Duration`, convert it to [`MySqlTime`] first using `TryFrom`/`TryInto`.
+//! The query macros infer `TIME` as `time::Time`. As a `TIME` column may hold values that
+//! are negative or exceed a day, set `SQLX_MYSQL_TIME_AS_DURATION=true` at build time to infer
+//! `time::Duration` instead, or use the type override syntax for a single column.
 //!
 //! ### [`bigdecimal`](https://crates.io/crates/bigdecimal)
 //! Requires the `bigdecimal` Cargo feature flag.
 //!
 //! | Rust type                             | MySQL/MariaDB type(s)                                |
 //! |---------------------------------------|------------------------------------------------------|
-//! | `bigdecimal::BigDecimal`              | DECIMAL                                              |
+//! | `bigdecimal::BigDecimal`              | DECIMAL, DECIMAL UNSIGNED                            |
 //!
 //! ### [`decimal`](https://crates.io/crates/rust_decimal)
 //! Requires the `decimal` Cargo feature flag.
 //!
 //! | Rust type                             | MySQL/MariaDB type(s)                                |
 //! |---------------------------------------|------------------------------------------------------|
-//! | `rust_decimal::Decimal`               | DECIMAL                                              |
+//! | `rust_decimal::Decimal`               | DECIMAL, DECIMAL UNSIGNED                            |
 //!
 //! ### [`uuid`](https://crates.io/crates/uuid)
 //!
@@ -119,6 +139,9 @@
 //! | `uuid::fmt::Hyphenated`               | CHAR(36), UUID (MariaDB-only)                        |
 //! | `uuid::fmt::Simple`                   | CHAR(32)                                             |
 //!
+//! The query macros infer `BINARY(16)` as `Vec<u8>` unless `SQLX_MYSQL_BINARY16_AS_UUID=true`
+//! is set at build time.
+//!
 //! ### [`json`](https://crates.io/crates/serde_json)
 //!
 //! Requires the `json` Cargo feature flag.
@@ -136,10 +159,12 @@
 
 pub(crate) use sqlx_core::types::*;
 
+pub use decimal::MySqlDecimal;
 pub use mysql_time::{MySqlTime, MySqlTimeError, MySqlTimeSign};
 
 mod bool;
 mod bytes;
+mod decimal;
 mod float;
 mod inet;
 mod int;
@@ -148,6 +173,9 @@ mod str;
 mod text;
 mod uint;
 
+#[cfg(feature = "any")]
+mod any;
+
 #[cfg(feature = "json")]
 mod json;
 
@@ -540,6 +540,23 @@ impl Type<MySql> for Duration {
     }
 }
 
+/// Encode as a `TIME` value.
+///
+/// ### Errors
+/// Returns an error if the value exceeds `838:59:59`.
+impl<'q> Encode<'q, MySql> for Duration {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <MySql as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        MySqlTime::try_from(*self)?.encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        MySqlTime::try_from(*self).map_or(0, |time| time.size_hint())
+    }
+}
+
 impl<'r> Decode<'r, MySql> for Duration {
     fn decode(value: <MySql as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
         let time = MySqlTime::decode(value)?;
@@ -5,7 +5,7 @@ use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::io::MySqlBufMutExt;
 use crate::protocol::text::ColumnType;
-use crate::types::Type;
+use crate::types::{MySqlDecimal, Type};
 use crate::{MySql, MySqlTypeInfo, MySqlValueRef};
 
 impl Type<MySql> for Decimal {
@@ -31,3 +31,21 @@ impl Decode<'_, MySql> for Decimal {
         Ok(value.as_str()?.parse()?)
     }
 }
+
+impl From<Decimal> for MySqlDecimal {
+    fn from(value: Decimal) -> Self {
+        // `Decimal` always formats in plain notation
+        value
+            .to_string()
+            .parse()
+            .expect("BUG: `Decimal` formatted as an invalid decimal")
+    }
+}
+
+impl TryFrom<MySqlDecimal> for Decimal {
+    type Error = BoxDynError;
+
+    fn try_from(value: MySqlDecimal) -> Result<Self, Self::Error> {
+        Ok(value.as_str().parse()?)
+    }
+}
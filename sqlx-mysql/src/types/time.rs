@@ -136,6 +136,20 @@ impl Type<MySql> for time::Duration {
     }
 }
 
+/// Encode as a `TIME` value.
+///
+/// ### Errors
+/// Returns an error if the magnitude of the value exceeds `838:59:59`.
+impl Encode<'_, MySql> for time::Duration {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> Result<IsNull, BoxDynError> {
+        MySqlTime::try_from(*self)?.encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        MySqlTime::try_from(*self).map_or(0, |time| time.size_hint())
+    }
+}
+
 impl<'r> Decode<'r, MySql> for time::Duration {
     fn decode(value: <MySql as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
         Ok(MySqlTime::decode(value)?.into())
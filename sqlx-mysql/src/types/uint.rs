@@ -22,11 +22,16 @@ fn uint_compatible(ty: &MySqlTypeInfo) -> bool {
             | ColumnType::Long
             | ColumnType::Int24
             | ColumnType::LongLong
-            | ColumnType::Year
             | ColumnType::Bit
     ) && ty.flags.contains(ColumnFlags::UNSIGNED)
 }
 
+// `YEAR` ranges from 1901 to 2155, so it only fits in 16 bits or more. Older servers and
+// expressions do not always flag it as `UNSIGNED`.
+pub(crate) fn year_compatible(ty: &MySqlTypeInfo) -> bool {
+    ty.r#type == ColumnType::Year
+}
+
 impl Type<MySql> for u8 {
     fn type_info() -> MySqlTypeInfo {
         uint_type_info(ColumnType::Tiny)
@@ -43,7 +48,7 @@ impl Type<MySql> for u16 {
     }
 
     fn compatible(ty: &MySqlTypeInfo) -> bool {
-        uint_compatible(ty)
+        uint_compatible(ty) || year_compatible(ty)
     }
 }
 
@@ -53,7 +58,7 @@ impl Type<MySql> for u32 {
     }
 
     fn compatible(ty: &MySqlTypeInfo) -> bool {
-        uint_compatible(ty)
+        uint_compatible(ty) || year_compatible(ty)
     }
 }
 
@@ -63,7 +68,7 @@ impl Type<MySql> for u64 {
     }
 
     fn compatible(ty: &MySqlTypeInfo) -> bool {
-        uint_compatible(ty)
+        uint_compatible(ty) || year_compatible(ty)
     }
 }
 
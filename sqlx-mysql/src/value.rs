@@ -5,7 +5,7 @@ use bytes::Bytes;
 pub(crate) use sqlx_core::value::*;
 
 use crate::error::{BoxDynError, UnexpectedNullError};
-use crate::protocol::text::ColumnType;
+use crate::protocol::text::{ColumnFlags, ColumnType};
 use crate::{MySql, MySqlTypeInfo};
 
 #[derive(Debug, Clone, Copy)]
@@ -21,6 +21,7 @@ pub struct MySqlValue {
     value: Option<Bytes>,
     type_info: MySqlTypeInfo,
     format: MySqlValueFormat,
+    strict_bools: bool,
 }
 
 /// Implementation of [`ValueRef`] for MySQL.
@@ -30,6 +31,8 @@ pub struct MySqlValueRef<'r> {
     pub(crate) row: Option<&'r Bytes>,
     pub(crate) type_info: MySqlTypeInfo,
     pub(crate) format: MySqlValueFormat,
+    // set by `MySqlConnectOptions::strict_bools()`
+    pub(crate) strict_bools: bool,
 }
 
 impl<'r> MySqlValueRef<'r> {
@@ -49,6 +52,36 @@ impl<'r> MySqlValueRef<'r> {
     }
 }
 
+impl MySqlValue {
+    /// A `BIGINT UNSIGNED` in the text format, which decodes into any integer type it fits in
+    /// when not type-checked.
+    pub(crate) fn unsigned_bigint(value: u64) -> Self {
+        MySqlValue {
+            value: Some(Bytes::from(value.to_string())),
+            type_info: MySqlTypeInfo {
+                r#type: ColumnType::LongLong,
+                flags: ColumnFlags::BINARY | ColumnFlags::UNSIGNED,
+                max_size: None,
+            },
+            format: MySqlValueFormat::Text,
+            strict_bools: false,
+        }
+    }
+}
+
+impl MySqlValue {
+    /// A value in the binary format, without the length prefix of strings and blobs.
+    #[cfg(feature = "any")]
+    pub(crate) fn binary(value: Option<Bytes>, type_info: MySqlTypeInfo) -> Self {
+        MySqlValue {
+            value,
+            type_info,
+            format: MySqlValueFormat::Binary,
+            strict_bools: false,
+        }
+    }
+}
+
 impl Value for MySqlValue {
     type Database = MySql;
 
@@ -58,6 +91,7 @@ impl Value for MySqlValue {
             row: None,
             type_info: self.type_info.clone(),
             format: self.format,
+            strict_bools: self.strict_bools,
         }
     }
 
@@ -86,6 +120,7 @@ impl<'r> ValueRef<'r> for MySqlValueRef<'r> {
             value,
             format: self.format,
             type_info: self.type_info.clone(),
+            strict_bools: self.strict_bools,
         }
     }
 
@@ -1,21 +1,27 @@
+use crate::arguments::PgArgumentBuffer;
 use crate::{
     Either, PgColumn, PgConnectOptions, PgConnection, PgQueryResult, PgRow, PgTransactionManager,
-    PgTypeInfo, Postgres,
+    PgTypeInfo, PgValue, PgValueFormat, Postgres,
 };
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 use futures_util::{stream, StreamExt, TryFutureExt, TryStreamExt};
 use std::future;
+use std::time::Duration;
 
 pub use sqlx_core::any::*;
 
 use crate::type_info::PgType;
+use sqlx_core::bytes::Bytes;
 use sqlx_core::connection::Connection;
 use sqlx_core::database::Database;
 use sqlx_core::describe::Describe;
+use sqlx_core::encode::{Encode, IsNull};
 use sqlx_core::executor::Executor;
 use sqlx_core::ext::ustr::UStr;
-use sqlx_core::transaction::TransactionManager;
+use sqlx_core::transaction::{TransactionManager, TransactionOptions};
+use sqlx_core::types::Type;
+use sqlx_core::value::Value;
 
 sqlx_core::declare_driver_with_optional_migrate!(DRIVER = Postgres);
 
@@ -32,7 +38,7 @@ impl AnyConnectionBackend for PgConnection {
         Connection::close_hard(*self)
     }
 
-    fn ping(&mut self) -> BoxFuture<'_, sqlx_core::Result<()>> {
+    fn ping(&mut self) -> BoxFuture<'_, sqlx_core::Result<Duration>> {
         Connection::ping(self)
     }
 
@@ -40,6 +46,10 @@ impl AnyConnectionBackend for PgConnection {
         PgTransactionManager::begin(self)
     }
 
+    fn begin_with(&mut self, options: TransactionOptions) -> BoxFuture<'_, sqlx_core::Result<()>> {
+        PgTransactionManager::begin_with(self, options)
+    }
+
     fn commit(&mut self) -> BoxFuture<'_, sqlx_core::Result<()>> {
         PgTransactionManager::commit(self)
     }
@@ -52,10 +62,40 @@ impl AnyConnectionBackend for PgConnection {
         PgTransactionManager::start_rollback(self)
     }
 
+    fn set_statement_timeout(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'_, sqlx_core::Result<()>> {
+        Connection::set_statement_timeout(self, timeout)
+    }
+
+    fn statement_timeout(&self) -> Option<Duration> {
+        Connection::statement_timeout(self)
+    }
+
+    fn supports_statement_timeout(&self) -> bool {
+        true
+    }
+
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    fn estimate_rows<'e>(
+        &'e mut self,
+        table_or_query: &'e str,
+    ) -> BoxFuture<'e, sqlx_core::Result<u64>> {
+        Connection::estimate_rows(self, table_or_query)
+    }
+
     fn shrink_buffers(&mut self) {
         Connection::shrink_buffers(self);
     }
 
+    fn reset_on_release(&mut self) {
+        Connection::reset_on_release(self);
+    }
+
     fn flush(&mut self) -> BoxFuture<'_, sqlx_core::Result<()>> {
         Connection::flush(self)
     }
@@ -85,7 +125,7 @@ impl AnyConnectionBackend for PgConnection {
         };
 
         Box::pin(
-            self.run(query, arguments, 0, persistent, None)
+            self.run(query, arguments, 0, persistent, None, None)
                 .try_flatten_stream()
                 .map(
                     move |res: sqlx_core::Result<Either<PgQueryResult, PgRow>>| match res? {
@@ -110,7 +150,9 @@ impl AnyConnectionBackend for PgConnection {
 
         Box::pin(async move {
             let arguments = arguments?;
-            let stream = self.run(query, arguments, 1, persistent, None).await?;
+            let stream = self
+                .run(query, arguments, 1, persistent, None, None)
+                .await?;
             futures_util::pin_mut!(stream);
 
             if let Some(Either::Right(row)) = stream.try_next().await? {
@@ -180,6 +222,10 @@ impl<'a> TryFrom<&'a PgTypeInfo> for AnyTypeInfo {
     type Error = sqlx_core::Error;
 
     fn try_from(pg_type: &'a PgTypeInfo) -> Result<Self, Self::Error> {
+        if let Some(kind) = sqlx_core::any::mapped_kind::<Postgres>(pg_type) {
+            return Ok(AnyTypeInfo { kind });
+        }
+
         Ok(AnyTypeInfo {
             kind: match &pg_type.0 {
                 PgType::Bool => AnyTypeInfoKind::Bool,
@@ -192,6 +238,11 @@ impl<'a> TryFrom<&'a PgTypeInfo> for AnyTypeInfo {
                 PgType::Bytea => AnyTypeInfoKind::Blob,
                 PgType::Text | PgType::Varchar => AnyTypeInfoKind::Text,
                 PgType::DeclareWithName(UStr::Static("citext")) => AnyTypeInfoKind::Text,
+                PgType::Numeric => AnyTypeInfoKind::Decimal,
+                PgType::Date => AnyTypeInfoKind::Date,
+                PgType::Time => AnyTypeInfoKind::Time,
+                PgType::Timestamp | PgType::Timestamptz => AnyTypeInfoKind::Timestamp,
+                PgType::Uuid => AnyTypeInfoKind::Uuid,
                 _ => {
                     return Err(sqlx_core::Error::AnyDriverError(
                         format!("Any driver does not support the Postgres type {pg_type:?}").into(),
@@ -228,11 +279,77 @@ impl<'a> TryFrom<&'a PgRow> for AnyRow {
     }
 }
 
+impl<'a> TryFrom<&'a PgValue> for AnyValue {
+    type Error = AnyValueError;
+
+    fn try_from(value: &'a PgValue) -> Result<Self, Self::Error> {
+        AnyValue::map_from::<Postgres>(value.as_ref())
+    }
+}
+
+impl<'a> TryFrom<&'a AnyValue> for PgValue {
+    type Error = AnyValueError;
+
+    fn try_from(value: &'a AnyValue) -> Result<Self, Self::Error> {
+        match &value.kind {
+            AnyValueKind::Null => Ok(PgValue {
+                value: None,
+                type_info: PgTypeInfo::VOID,
+                format: PgValueFormat::Binary,
+                time_zone_offset: None,
+            }),
+            AnyValueKind::Bool(b) => encode_value(*b),
+            AnyValueKind::SmallInt(i) => encode_value(*i),
+            AnyValueKind::Integer(i) => encode_value(*i),
+            AnyValueKind::BigInt(i) => encode_value(*i),
+            AnyValueKind::Real(r) => encode_value(*r),
+            AnyValueKind::Double(d) => encode_value(*d),
+            AnyValueKind::Text(t) => encode_value(&**t),
+            AnyValueKind::Blob(b) => encode_value(&**b),
+            AnyValueKind::Decimal(d) => encode_value(AnyDecimal(d.to_string())),
+            AnyValueKind::Date(d) => encode_value(*d),
+            AnyValueKind::Time(t) => encode_value(*t),
+            AnyValueKind::Timestamp(t) => encode_value(*t),
+            AnyValueKind::Uuid(u) => encode_value(*u),
+            _ => Err(AnyValueError::UnsupportedKind {
+                database: Postgres::NAME,
+                type_info: value.type_info().into_owned(),
+            }),
+        }
+    }
+}
+
+fn encode_value<'q, T>(value: T) -> Result<PgValue, AnyValueError>
+where
+    T: Encode<'q, Postgres> + Type<Postgres>,
+{
+    let type_info = value.produces().unwrap_or_else(T::type_info);
+
+    let mut buf = PgArgumentBuffer::default();
+    let is_null = value.encode(&mut buf).map_err(AnyValueError::Encode)?;
+
+    Ok(PgValue {
+        value: match is_null {
+            IsNull::No => Some(Bytes::from(buf.to_vec())),
+            IsNull::Yes => None,
+        },
+        type_info,
+        format: PgValueFormat::Binary,
+        time_zone_offset: None,
+    })
+}
+
 impl<'a> TryFrom<&'a AnyConnectOptions> for PgConnectOptions {
     type Error = sqlx_core::Error;
 
     fn try_from(value: &'a AnyConnectOptions) -> Result<Self, Self::Error> {
         let mut opts = PgConnectOptions::parse_from_url(&value.database_url)?;
+        if let Some(socket) = &value.socket {
+            opts = opts.socket(socket);
+        }
+        for command in &value.init_commands {
+            opts = opts.init_command(command.clone());
+        }
         opts.log_settings = value.log_settings.clone();
         Ok(opts)
     }
@@ -244,3 +361,99 @@ fn map_result(res: PgQueryResult) -> AnyQueryResult {
         last_insert_id: None,
     }
 }
+
+#[test]
+fn it_converts_values_to_and_from_any() {
+    use std::borrow::Cow;
+
+    let kinds = [
+        AnyValueKind::Null,
+        AnyValueKind::Bool(true),
+        AnyValueKind::SmallInt(-2),
+        AnyValueKind::Integer(70_000),
+        AnyValueKind::BigInt(1 << 40),
+        AnyValueKind::Real(0.5),
+        AnyValueKind::Double(-1.25),
+        AnyValueKind::Text(Cow::Borrowed("hello")),
+        AnyValueKind::Blob(Cow::Borrowed(b"\x00\x01")),
+        AnyValueKind::Decimal(Cow::Borrowed("-12.50")),
+        AnyValueKind::Date(AnyDate(19_844)),
+        AnyValueKind::Time(AnyTime(45_005_250_000)),
+        AnyValueKind::Timestamp(AnyTimestamp(-500_000)),
+        AnyValueKind::Uuid(AnyUuid(*b"0123456789abcdef")),
+    ];
+
+    for kind in kinds {
+        let expected = format!("{kind:?}");
+
+        let pg_value = PgValue::try_from(&AnyValue { kind }).unwrap();
+        let any_value = AnyValue::try_from(&pg_value).unwrap();
+
+        assert_eq!(format!("{:?}", any_value.kind), expected);
+    }
+
+    let json = PgValue {
+        value: Some(Bytes::from_static(b"{}")),
+        type_info: PgTypeInfo::JSON,
+        format: PgValueFormat::Text,
+        time_zone_offset: None,
+    };
+
+    assert!(matches!(
+        AnyValue::try_from(&json),
+        Err(AnyValueError::UnsupportedType {
+            database: "PostgreSQL",
+            ref type_name,
+        }) if type_name == "JSON"
+    ));
+}
+
+#[test]
+fn it_reports_every_argument_that_fails_to_convert() {
+    use std::borrow::Cow;
+
+    let arguments = AnyArguments {
+        values: AnyArgumentBuffer(vec![
+            AnyValueKind::Decimal(Cow::Borrowed("1.5")),
+            AnyValueKind::Decimal(Cow::Borrowed("one")),
+            AnyValueKind::Integer(1),
+            AnyValueKind::Decimal(Cow::Borrowed("1e")),
+        ]),
+    };
+
+    let Err(error) = arguments.convert_to::<crate::PgArguments>() else {
+        panic!("expected the arguments to fail to convert");
+    };
+    let error = error.downcast_ref::<AnyArgumentsError>().unwrap();
+
+    assert_eq!(error.database(), "PostgreSQL");
+    assert_eq!(
+        error
+            .errors()
+            .iter()
+            .map(|error| (error.index(), error.type_info().to_string()))
+            .collect::<Vec<_>>(),
+        [(1, "DECIMAL".to_string()), (3, "DECIMAL".to_string())]
+    );
+
+    let message = error.to_string();
+    assert!(
+        message.starts_with(
+            "2 of 4 arguments could not be encoded for PostgreSQL\n  argument 2 (DECIMAL): "
+        ),
+        "{message}"
+    );
+    assert!(message.contains("\n  argument 4 (DECIMAL): "), "{message}");
+}
+
+#[test]
+fn it_connects_through_the_socket_of_any_options() {
+    let any_opts: AnyConnectOptions = "postgres+unix:///var/run/postgresql?database=sqlx"
+        .parse()
+        .unwrap();
+
+    let opts = PgConnectOptions::try_from(&any_opts).unwrap();
+
+    assert_eq!(opts.socket, Some("/var/run/postgresql".into()));
+    assert_eq!(opts.database.as_deref(), Some("sqlx"));
+}
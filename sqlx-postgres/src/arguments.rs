@@ -1,5 +1,6 @@
 use std::fmt::{self, Write};
 use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 
 use crate::encode::{Encode, IsNull};
 use crate::error::Error;
@@ -20,7 +21,7 @@ use sqlx_core::error::BoxDynError;
 //            that has a patch, we then apply the patch which should write to &mut Vec<u8>,
 //            backtrack and update the prefixed-len, then write until the next patch offset
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct PgArgumentBuffer {
     buffer: Vec<u8>,
 
@@ -44,15 +45,16 @@ pub struct PgArgumentBuffer {
     type_holes: Vec<(usize, UStr)>, // Vec<{ offset, type_name }>
 }
 
+#[derive(Clone)]
 struct Patch {
     buf_offset: usize,
     arg_index: usize,
     #[allow(clippy::type_complexity)]
-    callback: Box<dyn Fn(&mut [u8], &PgTypeInfo) + 'static + Send + Sync>,
+    callback: Arc<dyn Fn(&mut [u8], &PgTypeInfo) + 'static + Send + Sync>,
 }
 
 /// Implementation of [`Arguments`] for PostgreSQL.
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct PgArguments {
     // Types of each bind parameter
     pub(crate) types: Vec<PgTypeInfo>,
@@ -137,6 +139,10 @@ impl<'q> Arguments<'q> for PgArguments {
     fn len(&self) -> usize {
         self.buffer.count
     }
+
+    fn private_try_clone(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
 
 impl PgArgumentBuffer {
@@ -176,7 +182,7 @@ impl PgArgumentBuffer {
         self.patches.push(Patch {
             buf_offset: offset,
             arg_index,
-            callback: Box::new(callback),
+            callback: Arc::new(callback),
         });
     }
 
@@ -189,6 +195,19 @@ impl PgArgumentBuffer {
         self.type_holes.push((offset, type_name.clone()));
     }
 
+    // Binary `COPY` data is encoded with the same buffer as bind parameters, but is sent
+    // while the connection is busy copying, so OIDs cannot be looked up afterwards.
+    pub(crate) fn has_patches(&self) -> bool {
+        !self.patches.is_empty() || !self.type_holes.is_empty()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.buffer.clear();
+        self.count = 0;
+        self.patches.clear();
+        self.type_holes.clear();
+    }
+
     fn snapshot(&self) -> PgArgumentBufferSnapshot {
         let Self {
             buffer,
@@ -1,6 +1,6 @@
 use crate::error::Error;
 use crate::ext::ustr::UStr;
-use crate::message::{ParameterDescription, RowDescription};
+use crate::message::{Close, ParameterDescription, RowDescription};
 use crate::query_as::query_as;
 use crate::query_scalar::{query_scalar, query_scalar_with};
 use crate::statement::PgStatementMetadata;
@@ -25,6 +25,7 @@ enum TypType {
     Enum,
     Pseudo,
     Range,
+    Multirange,
 }
 
 impl TryFrom<u8> for TypType {
@@ -38,6 +39,7 @@ impl TryFrom<u8> for TypType {
             b'e' => Self::Enum,
             b'p' => Self::Pseudo,
             b'r' => Self::Range,
+            b'm' => Self::Multirange,
             _ => return Err(()),
         };
         Ok(t)
@@ -237,6 +239,10 @@ impl PgConnection {
                     self.fetch_range_by_oid(oid, name).await
                 }
 
+                (Ok(TypType::Multirange), Ok(TypCategory::Range)) => {
+                    self.fetch_multirange_by_oid(oid, name).await
+                }
+
                 (Ok(TypType::Enum), Ok(TypCategory::Enum)) => {
                     self.fetch_enum_by_oid(oid, name).await
                 }
@@ -355,6 +361,33 @@ WHERE rngtypid = $1
         })
     }
 
+    fn fetch_multirange_by_oid(
+        &mut self,
+        oid: Oid,
+        name: String,
+    ) -> BoxFuture<'_, Result<PgTypeInfo, Error>> {
+        Box::pin(async move {
+            let range_oid: Oid = query_scalar(
+                r#"
+SELECT rngtypid
+FROM pg_catalog.pg_range
+WHERE rngmultitypid = $1
+                "#,
+            )
+            .bind(oid)
+            .fetch_one(&mut *self)
+            .await?;
+
+            let range = self.maybe_fetch_type_info_by_oid(range_oid, true).await?;
+
+            Ok(PgTypeInfo(PgType::Custom(Arc::new(PgCustomType {
+                kind: PgTypeKind::Multirange(range),
+                name: name.into(),
+                oid,
+            }))))
+        })
+    }
+
     pub(crate) async fn fetch_type_id_by_name(&mut self, name: &str) -> Result<Oid, Error> {
         if let Some(oid) = self.cache_type_oid.get(name) {
             return Ok(*oid);
@@ -373,6 +406,55 @@ WHERE rngtypid = $1
         Ok(oid)
     }
 
+    // fetch the type `oid` again, after evicting it and every type that refers to it
+    pub(crate) async fn refresh_type_by_oid(&mut self, oid: Oid) -> Result<PgTypeInfo, Error> {
+        self.evict_type(oid).await?;
+
+        self.maybe_fetch_type_info_by_oid(oid, true).await
+    }
+
+    // remove `oid`, and every cached type that refers to it (such as its array type), from the
+    // type caches, and close the cached statements which use any of them
+    pub(crate) async fn evict_type(&mut self, oid: Oid) -> Result<(), Error> {
+        let evicted: Vec<Oid> = self
+            .cache_type_info
+            .iter()
+            .filter(|(_, info)| refers_to_type(info, oid))
+            .map(|(oid, _)| *oid)
+            .chain([oid])
+            .collect();
+
+        self.cache_type_info.retain(|oid, _| !evicted.contains(oid));
+        self.cache_type_oid.retain(|_, oid| !evicted.contains(oid));
+
+        let statements = self.cache_statement.remove_matching(|(_, metadata)| {
+            metadata
+                .columns
+                .iter()
+                .map(|column| &column.type_info)
+                .chain(&metadata.parameters)
+                .any(|info| evicted.iter().any(|oid| refers_to_type(info, *oid)))
+        });
+
+        if statements.is_empty() {
+            return Ok(());
+        }
+
+        self.wait_until_ready().await?;
+
+        for (id, _) in &statements {
+            self.stream.write(Close::Statement(*id));
+        }
+
+        self.write_sync();
+        self.stream.flush().await?;
+
+        self.wait_for_close_complete(statements.len()).await?;
+        self.recv_ready_for_query().await?;
+
+        Ok(())
+    }
+
     pub(crate) async fn get_nullable_for_columns(
         &mut self,
         stmt_id: Oid,
@@ -602,3 +684,29 @@ fn explain_parsing() {
         "unexpected parse from {utility_statement:?}: {utility_statement_parsed:?}"
     )
 }
+
+// `true` if `info` is the user-defined type `oid`, or is made of it
+fn refers_to_type(info: &PgTypeInfo, oid: Oid) -> bool {
+    match &info.0 {
+        PgType::DeclareWithOid(declared) => *declared == oid,
+
+        PgType::Custom(ty) => {
+            ty.oid == oid
+                || match &ty.kind {
+                    PgTypeKind::Domain(info)
+                    | PgTypeKind::Array(info)
+                    | PgTypeKind::Range(info)
+                    | PgTypeKind::Multirange(info) => refers_to_type(info, oid),
+
+                    PgTypeKind::Composite(fields) => {
+                        fields.iter().any(|(_, info)| refers_to_type(info, oid))
+                    }
+
+                    _ => false,
+                }
+        }
+
+        // built-in types, and types that are only declared by name
+        _ => false,
+    }
+}
@@ -1,7 +1,7 @@
 use crate::HashMap;
 
 use crate::common::StatementCache;
-use crate::connection::{sasl, stream::PgStream};
+use crate::connection::{sasl, statement_timeout_millis, stream::PgStream};
 use crate::error::Error;
 use crate::io::Decode;
 use crate::message::{
@@ -44,6 +44,21 @@ impl PgConnection {
             params.push(("options", options));
         }
 
+        let statement_timeout = options
+            .statement_timeout
+            .map(|timeout| statement_timeout_millis(Some(timeout)).to_string());
+
+        if let Some(ref statement_timeout) = statement_timeout {
+            params.push(("statement_timeout", statement_timeout));
+        }
+
+        for (key, value) in &options.startup_params {
+            match params.iter_mut().find(|(k, _)| k == key) {
+                Some((_, v)) => *v = value,
+                None => params.push((key, value)),
+            }
+        }
+
         stream
             .send(Startup {
                 username: Some(&options.username),
@@ -74,11 +89,9 @@ impl PgConnection {
                         // The frontend must now send a [PasswordMessage] containing the
                         // password in clear-text form.
 
-                        stream
-                            .send(Password::Cleartext(
-                                options.password.as_deref().unwrap_or_default(),
-                            ))
-                            .await?;
+                        let password = options.fetch_password().await?;
+
+                        stream.send(Password::Cleartext(&password)).await?;
                     }
 
                     Authentication::Md5Password(body) => {
@@ -87,10 +100,12 @@ impl PgConnection {
                         // using the 4-byte random salt specified in the
                         // [AuthenticationMD5Password] message.
 
+                        let password = options.fetch_password().await?;
+
                         stream
                             .send(Password::Md5 {
                                 username: &options.username,
-                                password: options.password.as_deref().unwrap_or_default(),
+                                password: &password,
                                 salt: body.salt,
                             })
                             .await?;
@@ -141,9 +156,11 @@ impl PgConnection {
             secret_key,
             transaction_status,
             transaction_depth: 0,
+            statement_timeout: options.statement_timeout,
             pending_ready_for_query_count: 0,
             next_statement_id: Oid(1),
             cache_statement: StatementCache::new(options.statement_cache_capacity),
+            auto_reprepare: options.auto_reprepare,
             cache_type_oid: HashMap::new(),
             cache_type_info: HashMap::new(),
             log_settings: options.log_settings.clone(),
@@ -0,0 +1,96 @@
+use sqlx_core::connection::private_is_query;
+
+use crate::error::Error;
+use crate::query_as::query_as;
+use crate::query_scalar::query_scalar;
+use crate::types::Json;
+use crate::PgConnection;
+
+impl PgConnection {
+    /// See [`Connection::estimate_rows()`][crate::connection::Connection::estimate_rows].
+    pub(super) async fn estimate_rows_of(&mut self, table_or_query: &str) -> Result<u64, Error> {
+        if private_is_query(table_or_query) {
+            return self.estimate_rows_from_explain(table_or_query).await;
+        }
+
+        // Partitioned tables have no storage of their own, so the estimate is the sum over their
+        // partitions, and the partitions of those. This also includes the children of tables
+        // using inheritance. `reltuples` is -1 for tables which have never been analyzed,
+        // since Postgres 14; partitioned tables are skipped as they either have no estimate or,
+        // since Postgres 14, one which already counts their partitions.
+        // language=PostgreSQL
+        let (table, reltuples, unanalyzed): (String, f64, bool) = query_as(
+            r#"
+            WITH RECURSIVE tree(oid) AS (
+                SELECT $1::regclass::oid
+                UNION ALL
+                SELECT inhrelid FROM pg_catalog.pg_inherits JOIN tree ON inhparent = tree.oid
+            )
+            SELECT
+                $1::regclass::text,
+                COALESCE(SUM(GREATEST(c.reltuples, 0)) FILTER (WHERE c.relkind <> 'p'), 0)::float8,
+                COALESCE(bool_or(c.reltuples < 0 AND c.relkind IN ('r', 'm', 'f')), false)
+            FROM tree JOIN pg_catalog.pg_class c ON c.oid = tree.oid
+            "#,
+        )
+        .bind(table_or_query)
+        .fetch_one(&mut *self)
+        .await?;
+
+        if unanalyzed {
+            // the planner estimates these from the number of pages instead
+            return self
+                .estimate_rows_from_explain(&format!("SELECT 1 FROM {table}"))
+                .await;
+        }
+
+        Ok(reltuples.round() as u64)
+    }
+
+    async fn estimate_rows_from_explain(&mut self, query: &str) -> Result<u64, Error> {
+        let Json(explain): Json<Vec<Explain>> =
+            query_scalar(&format!("EXPLAIN (FORMAT JSON) {query}"))
+                .fetch_one(&mut *self)
+                .await?;
+
+        let plan_rows = explain
+            .first()
+            .map(|explain| explain.plan.plan_rows)
+            .ok_or_else(|| err_protocol!("EXPLAIN returned no plan for the query"))?;
+
+        Ok(plan_rows.round() as u64)
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct Explain {
+    #[serde(rename = "Plan")]
+    plan: Plan,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct Plan {
+    #[serde(rename = "Plan Rows")]
+    plan_rows: f64,
+}
+
+#[test]
+fn it_parses_plan_rows() {
+    let explain = r#"[
+      {
+        "Plan": {
+          "Node Type": "Seq Scan",
+          "Parallel Aware": false,
+          "Relation Name": "tweet",
+          "Alias": "tweet",
+          "Startup Cost": 0.00,
+          "Total Cost": 22.70,
+          "Plan Rows": 1270,
+          "Plan Width": 44
+        }
+      }
+    ]"#;
+
+    let explain = serde_json::from_str::<Vec<Explain>>(explain).unwrap();
+    assert_eq!(explain[0].plan.plan_rows, 1270.0);
+}
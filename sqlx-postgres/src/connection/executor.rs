@@ -1,14 +1,16 @@
+use super::statement_timeout_millis;
 use crate::describe::Describe;
 use crate::error::Error;
 use crate::executor::{Execute, Executor};
 use crate::logger::QueryLogger;
 use crate::message::{
     self, Bind, Close, CommandComplete, DataRow, MessageFormat, ParameterDescription, Parse, Query,
-    RowDescription,
+    RowDescription, TransactionStatus,
 };
 use crate::statement::PgStatementMetadata;
-use crate::type_info::PgType;
+use crate::type_info::{PgType, PgTypeKind};
 use crate::types::Oid;
+use crate::HashMap;
 use crate::{
     statement::PgStatement, PgArguments, PgConnection, PgQueryResult, PgRow, PgTypeInfo,
     PgValueFormat, Postgres,
@@ -18,6 +20,7 @@ use futures_core::stream::BoxStream;
 use futures_core::Stream;
 use futures_util::{pin_mut, TryStreamExt};
 use sqlx_core::Either;
+use std::time::Duration;
 use std::{borrow::Cow, sync::Arc};
 
 async fn prepare(
@@ -64,7 +67,7 @@ async fn prepare(
 
     // indicates that the SQL query string is now successfully parsed and has semantic validity
     conn.stream
-        .recv_expect(MessageFormat::ParseComplete)
+        .recv_expect::<()>(MessageFormat::ParseComplete)
         .await?;
 
     let metadata = if let Some(metadata) = metadata {
@@ -152,6 +155,16 @@ impl PgConnection {
         Ok(())
     }
 
+    // `None` restores the default of the session
+    fn queue_statement_timeout(&mut self, timeout: Option<Duration>) {
+        let value = match timeout {
+            Some(_) => statement_timeout_millis(timeout).to_string(),
+            None => "DEFAULT".to_owned(),
+        };
+
+        self.queue_simple_query(&format!("SET statement_timeout = {value}"));
+    }
+
     pub(crate) fn write_sync(&mut self) {
         self.stream.write(message::Sync);
 
@@ -159,7 +172,7 @@ impl PgConnection {
         self.pending_ready_for_query_count += 1;
     }
 
-    async fn get_or_prepare<'a>(
+    pub(super) async fn get_or_prepare<'a>(
         &mut self,
         sql: &str,
         parameters: &[PgTypeInfo],
@@ -190,172 +203,343 @@ impl PgConnection {
         Ok(statement)
     }
 
-    pub(crate) async fn run<'e, 'c: 'e, 'q: 'e>(
-        &'c mut self,
-        query: &'q str,
-        arguments: Option<PgArguments>,
+    // bind the arguments to a prepared statement and execute it up to `limit` rows
+    fn write_bind_execute(&mut self, statement: Oid, arguments: &PgArguments, limit: u8) {
+        // bind to attach the arguments to the statement and create a portal
+        self.stream.write(Bind {
+            portal: None,
+            statement,
+            formats: &[PgValueFormat::Binary],
+            num_params: arguments.types.len() as i16,
+            params: &arguments.buffer,
+            result_formats: &[PgValueFormat::Binary],
+        });
+
+        // executes the portal up to the passed limit
+        // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
+        self.stream.write(message::Execute {
+            portal: None,
+            limit: limit.into(),
+        });
+        // From https://www.postgresql.org/docs/current/protocol-flow.html:
+        //
+        // "An unnamed portal is destroyed at the end of the transaction, or as
+        // soon as the next Bind statement specifying the unnamed portal as
+        // destination is issued. (Note that a simple Query message also
+        // destroys the unnamed portal."
+
+        // we ask the database server to close the unnamed portal and free the associated resources
+        // earlier - after the execution of the current query.
+        self.stream.write(message::Close::Portal(None));
+
+        // finally, [Sync] asks postgres to process the messages that we sent and respond with
+        // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
+        // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
+        // is still serial but it would reduce round-trips. Some kind of builder pattern that is
+        // termed batching might suit this.
+        self.write_sync();
+    }
+
+    // remove a cached statement whose result type has changed, prepare it again and
+    // re-send the query, returning the new metadata of the statement
+    async fn reprepare(
+        &mut self,
+        query: &str,
+        arguments: &mut PgArguments,
         limit: u8,
         persistent: bool,
-        metadata_opt: Option<Arc<PgStatementMetadata>>,
-    ) -> Result<impl Stream<Item = Result<Either<PgQueryResult, PgRow>, Error>> + 'e, Error> {
-        let mut logger = QueryLogger::new(query, self.log_settings.clone());
+        timeout: Option<Duration>,
+        override_timeout: bool,
+    ) -> Result<Arc<PgStatementMetadata>, Error> {
+        if let Some((id, _)) = self.cache_statement.remove(query) {
+            self.stream.write(Close::Statement(id));
+            self.write_sync();
 
-        // before we continue, wait until we are "ready" to accept more queries
-        self.wait_until_ready().await?;
+            self.stream.flush().await?;
 
-        let mut metadata: Arc<PgStatementMetadata>;
+            self.wait_for_close_complete(1).await?;
+            self.recv_ready_for_query().await?;
+        }
 
-        let format = if let Some(mut arguments) = arguments {
-            // prepare the statement if this our first time executing it
-            // always return the statement ID here
-            let (statement, metadata_) = self
-                .get_or_prepare(query, &arguments.types, persistent, metadata_opt)
-                .await?;
+        let (statement, metadata) = self
+            .get_or_prepare(query, &arguments.types, persistent, None)
+            .await?;
 
-            metadata = metadata_;
+        arguments.apply_patches(self, &metadata.parameters).await?;
 
-            // patch holes created during encoding
-            arguments.apply_patches(self, &metadata.parameters).await?;
+        if override_timeout {
+            self.queue_statement_timeout(timeout);
+        }
 
-            // consume messages till `ReadyForQuery` before bind and execute
-            self.wait_until_ready().await?;
+        self.wait_until_ready().await?;
 
-            // bind to attach the arguments to the statement and create a portal
-            self.stream.write(Bind {
-                portal: None,
-                statement,
-                formats: &[PgValueFormat::Binary],
-                num_params: arguments.types.len() as i16,
-                params: &arguments.buffer,
-                result_formats: &[PgValueFormat::Binary],
-            });
-
-            // executes the portal up to the passed limit
-            // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
-            self.stream.write(message::Execute {
-                portal: None,
-                limit: limit.into(),
-            });
-            // From https://www.postgresql.org/docs/current/protocol-flow.html:
-            //
-            // "An unnamed portal is destroyed at the end of the transaction, or as
-            // soon as the next Bind statement specifying the unnamed portal as
-            // destination is issued. (Note that a simple Query message also
-            // destroys the unnamed portal."
-
-            // we ask the database server to close the unnamed portal and free the associated resources
-            // earlier - after the execution of the current query.
-            self.stream.write(message::Close::Portal(None));
-
-            // finally, [Sync] asks postgres to process the messages that we sent and respond with
-            // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
-            // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
-            // is still serial but it would reduce round-trips. Some kind of builder pattern that is
-            // termed batching might suit this.
-            self.write_sync();
+        self.write_bind_execute(statement, arguments, limit);
 
-            // prepared statements are binary
-            PgValueFormat::Binary
-        } else {
-            // Query will trigger a ReadyForQuery
-            self.stream.write(Query(query));
-            self.pending_ready_for_query_count += 1;
+        if override_timeout {
+            self.queue_statement_timeout(self.statement_timeout);
+        }
 
-            // metadata starts out as "nothing"
-            metadata = Arc::new(PgStatementMetadata::default());
+        self.stream.flush().await?;
 
-            // and unprepared statements are text
-            PgValueFormat::Text
-        };
+        Ok(metadata)
+    }
 
-        self.stream.flush().await?;
+    pub(crate) async fn run<'e, 'c: 'e, 'q: 'e>(
+        &'c mut self,
+        query: &'q str,
+        mut arguments: Option<PgArguments>,
+        limit: u8,
+        persistent: bool,
+        metadata_opt: Option<Arc<PgStatementMetadata>>,
+        timeout: Option<Duration>,
+    ) -> Result<impl Stream<Item = Result<Either<PgQueryResult, PgRow>, Error>> + 'e, Error> {
+        let num_arguments = arguments
+            .as_ref()
+            .map_or(0, |arguments| arguments.types.len());
+        let mut logger = QueryLogger::new(query, num_arguments, self.log_settings.clone());
 
-        Ok(try_stream! {
-            loop {
-                let message = self.stream.recv().await?;
-
-                match message.format {
-                    MessageFormat::BindComplete
-                    | MessageFormat::ParseComplete
-                    | MessageFormat::ParameterDescription
-                    | MessageFormat::NoData
-                    // unnamed portal has been closed
-                    | MessageFormat::CloseComplete
-                    => {
-                        // harmless messages to ignore
-                    }
+        // the timeout of this query overrides the connection's until it completes
+        let override_timeout = timeout.is_some() && timeout != self.statement_timeout;
 
-                    // "Execute phase is always terminated by the appearance of
-                    // exactly one of these messages: CommandComplete,
-                    // EmptyQueryResponse (if the portal was created from an
-                    // empty query string), ErrorResponse, or PortalSuspended"
-                    MessageFormat::CommandComplete => {
-                        // a SQL command completed normally
-                        let cc: CommandComplete = message.decode()?;
-
-                        let rows_affected = cc.rows_affected();
-                        logger.increase_rows_affected(rows_affected);
-                        r#yield!(Either::Left(PgQueryResult {
-                            rows_affected,
-                        }));
-                    }
+        let preamble = async {
+            // before we continue, wait until we are "ready" to accept more queries
+            self.wait_until_ready().await?;
 
-                    MessageFormat::EmptyQueryResponse => {
-                        // empty query string passed to an unprepared execute
-                    }
+            let (format, metadata) = if let Some(arguments) = &mut arguments {
+                // prepare the statement if this our first time executing it
+                // always return the statement ID here
+                let (statement, metadata) = self
+                    .get_or_prepare(query, &arguments.types, persistent, metadata_opt)
+                    .await?;
 
-                    // Message::ErrorResponse is handled in self.stream.recv()
+                // patch holes created during encoding
+                arguments.apply_patches(self, &metadata.parameters).await?;
 
-                    // incomplete query execution has finished
-                    MessageFormat::PortalSuspended => {}
+                if override_timeout {
+                    self.queue_statement_timeout(timeout);
+                }
 
-                    MessageFormat::RowDescription => {
-                        // indicates that a *new* set of rows are about to be returned
-                        let (columns, column_names) = self
-                            .handle_row_description(Some(message.decode()?), false)
-                            .await?;
+                // consume messages till `ReadyForQuery` before bind and execute
+                self.wait_until_ready().await?;
 
-                        metadata = Arc::new(PgStatementMetadata {
-                            column_names: Arc::new(column_names),
-                            columns,
-                            parameters: Vec::default(),
-                        });
-                    }
+                self.write_bind_execute(statement, arguments, limit);
+
+                // prepared statements are binary
+                (PgValueFormat::Binary, metadata)
+            } else {
+                if override_timeout {
+                    self.queue_statement_timeout(timeout);
+                    self.wait_until_ready().await?;
+                }
 
-                    MessageFormat::DataRow => {
-                        logger.increment_rows_returned();
+                // Query will trigger a ReadyForQuery
+                self.stream.write(Query(query));
+                self.pending_ready_for_query_count += 1;
+
+                // metadata starts out as "nothing", and unprepared statements are text
+                (
+                    PgValueFormat::Text,
+                    Arc::new(PgStatementMetadata::default()),
+                )
+            };
+
+            if override_timeout {
+                // restored after the query whether or not it succeeds, and received the next time
+                // the connection is used
+                self.queue_statement_timeout(self.statement_timeout);
+            }
 
-                        // one of the set of rows returned by a SELECT, FETCH, etc query
-                        let data: DataRow = message.decode()?;
-                        let row = PgRow {
-                            data,
-                            format,
-                            metadata: Arc::clone(&metadata),
-                        };
+            self.stream.flush().await?;
 
-                        r#yield!(Either::Right(row));
-                    }
+            Ok((format, metadata))
+        }
+        .await;
 
-                    MessageFormat::ReadyForQuery => {
-                        // processing of the query string is complete
-                        self.handle_ready_for_query(message)?;
-                        break;
-                    }
+        let (format, mut metadata) = logger.record(preamble)?;
+
+        // a cached statement is only prepared again once per query
+        let mut reprepare = self.auto_reprepare && arguments.is_some();
 
-                    _ => {
-                        return Err(err_protocol!(
-                            "execute: unexpected message: {:?}",
-                            message.format
-                        ));
+        // enum types which have gained labels since they were cached
+        let mut stale_enums = Vec::new();
+
+        Ok(try_stream! {
+            let result = async {
+                loop {
+                    let message = match self.stream.recv().await {
+                        Err(error) if reprepare && is_result_type_changed(&error) => {
+                            reprepare = false;
+
+                            // the rest of the query was skipped after the error
+                            self.wait_until_ready().await?;
+
+                            // the error has aborted the transaction, if there is one
+                            if !matches!(self.transaction_status, TransactionStatus::Idle) {
+                                return Err(error);
+                            }
+
+                            if let Some(arguments) = &mut arguments {
+                                metadata = self
+                                    .reprepare(query, arguments, limit, persistent, timeout, override_timeout)
+                                    .await?;
+                            }
+
+                            continue;
+                        }
+
+                        message => message?,
+                    };
+
+                    match message.format {
+                        MessageFormat::BindComplete
+                        | MessageFormat::ParseComplete
+                        | MessageFormat::ParameterDescription
+                        | MessageFormat::NoData
+                        // unnamed portal has been closed
+                        | MessageFormat::CloseComplete
+                        => {
+                            // harmless messages to ignore
+                        }
+
+                        // "Execute phase is always terminated by the appearance of
+                        // exactly one of these messages: CommandComplete,
+                        // EmptyQueryResponse (if the portal was created from an
+                        // empty query string), ErrorResponse, or PortalSuspended"
+                        MessageFormat::CommandComplete => {
+                            // a SQL command completed normally
+                            let cc: CommandComplete = message.decode()?;
+
+                            let rows_affected = cc.rows_affected();
+                            logger.increase_rows_affected(rows_affected);
+                            r#yield!(Either::Left(PgQueryResult {
+                                rows_affected,
+                            }));
+                        }
+
+                        MessageFormat::EmptyQueryResponse => {
+                            // empty query string passed to an unprepared execute
+                        }
+
+                        // Message::ErrorResponse is handled in self.stream.recv()
+
+                        // incomplete query execution has finished
+                        MessageFormat::PortalSuspended => {}
+
+                        MessageFormat::RowDescription => {
+                            // indicates that a *new* set of rows are about to be returned
+                            let (columns, column_names) = self
+                                .handle_row_description(Some(message.decode()?), false)
+                                .await?;
+
+                            metadata = Arc::new(PgStatementMetadata {
+                                column_names: Arc::new(column_names),
+                                columns,
+                                parameters: Vec::default(),
+                            });
+                        }
+
+                        MessageFormat::DataRow => {
+                            logger.increment_rows_returned();
+
+                            // one of the set of rows returned by a SELECT, FETCH, etc query
+                            let data: DataRow = message.decode()?;
+
+                            find_stale_enums(&metadata, &data, &self.cache_type_info, &mut stale_enums);
+
+                            let row = PgRow {
+                                data,
+                                format,
+                                metadata: Arc::clone(&metadata),
+                                time_zone_offset: self.stream.time_zone_offset,
+                            };
+
+                            r#yield!(Either::Right(row));
+                        }
+
+                        MessageFormat::ReadyForQuery => {
+                            // processing of the query string is complete
+                            self.handle_ready_for_query(message)?;
+
+                            // the connection is idle now, so the stale types can be fetched again
+                            if !matches!(self.transaction_status, TransactionStatus::Error) {
+                                for oid in stale_enums.drain(..) {
+                                    self.refresh_type_by_oid(oid).await?;
+                                }
+                            }
+
+                            break;
+                        }
+
+                        _ => {
+                            return Err(err_protocol!(
+                                "execute: unexpected message: {:?}",
+                                message.format
+                            ));
+                        }
                     }
                 }
+
+                Ok(())
             }
+            .await;
 
-            Ok(())
+            logger.record(result)
         })
     }
 }
 
+// "cached plan must not change result type", raised when a cached statement is executed after
+// the columns it returns have changed, e.g. by `ALTER TABLE`
+fn is_result_type_changed(error: &Error) -> bool {
+    match error {
+        Error::Database(error) => {
+            error.code().as_deref() == Some("0A000")
+                && error
+                    .message()
+                    .contains("cached plan must not change result type")
+        }
+        _ => false,
+    }
+}
+
+// Add the enum types of the columns of `row` with a label missing from the cached type,
+// which must have been added since, e.g. with `ALTER TYPE ... ADD VALUE`.
+fn find_stale_enums(
+    metadata: &PgStatementMetadata,
+    row: &DataRow,
+    cache_type_info: &HashMap<Oid, PgTypeInfo>,
+    stale_enums: &mut Vec<Oid>,
+) {
+    let has_label = |info: &PgTypeInfo, value: &[u8]| match info.kind() {
+        PgTypeKind::Enum(labels) => labels.iter().any(|label| label.as_bytes() == value),
+        _ => false,
+    };
+
+    for (i, column) in metadata.columns.iter().enumerate() {
+        let PgType::Custom(ty) = &column.type_info.0 else {
+            continue;
+        };
+
+        if !matches!(ty.kind, PgTypeKind::Enum(_)) || stale_enums.contains(&ty.oid) {
+            continue;
+        }
+
+        // enum values are sent as their label in both the text and the binary format
+        let Some(value) = row.get(i) else {
+            continue;
+        };
+
+        // the metadata of a statement may be older than the type cache
+        if !has_label(&column.type_info, value)
+            && !cache_type_info
+                .get(&ty.oid)
+                .is_some_and(|info| has_label(info, value))
+        {
+            stale_enums.push(ty.oid);
+        }
+    }
+}
+
 impl<'c> Executor<'c> for &'c mut PgConnection {
     type Database = Postgres;
 
@@ -375,10 +559,11 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         let metadata = query.statement().map(|s| Arc::clone(&s.metadata));
         let arguments = query.take_arguments().map_err(Error::Encode);
         let persistent = query.persistent();
+        let timeout = query.timeout();
 
         Box::pin(try_stream! {
             let arguments = arguments?;
-            let s = self.run(sql, arguments, 0, persistent, metadata).await?;
+            let s = self.run(sql, arguments, 0, persistent, metadata, timeout).await?;
             pin_mut!(s);
 
             while let Some(v) = s.try_next().await? {
@@ -402,10 +587,13 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         let metadata = query.statement().map(|s| Arc::clone(&s.metadata));
         let arguments = query.take_arguments().map_err(Error::Encode);
         let persistent = query.persistent();
+        let timeout = query.timeout();
 
         Box::pin(async move {
             let arguments = arguments?;
-            let s = self.run(sql, arguments, 1, persistent, metadata).await?;
+            let s = self
+                .run(sql, arguments, 1, persistent, metadata, timeout)
+                .await?;
             pin_mut!(s);
 
             // With deferred constraints we need to check all responses as we
@@ -1,12 +1,17 @@
+use std::cmp;
 use std::fmt::{self, Debug, Formatter};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use crate::HashMap;
+use futures_channel::mpsc;
 use futures_core::future::BoxFuture;
+use futures_core::Stream;
 use futures_util::FutureExt;
 
 use crate::common::StatementCache;
 use crate::error::Error;
+use crate::executor::Executor;
 use crate::ext::ustr::UStr;
 use crate::io::Decode;
 use crate::message::{
@@ -15,15 +20,18 @@ use crate::message::{
 use crate::statement::PgStatementMetadata;
 use crate::transaction::Transaction;
 use crate::types::Oid;
-use crate::{PgConnectOptions, PgTypeInfo, Postgres};
+use crate::{PgConnectOptions, PgNotice, PgTypeInfo, Postgres};
 
 pub(crate) use sqlx_core::connection::*;
 
+pub use self::pipeline::PgPipeline;
 pub use self::stream::PgStream;
 
 pub(crate) mod describe;
 mod establish;
+mod estimate;
 mod executor;
+mod pipeline;
 mod sasl;
 mod stream;
 mod tls;
@@ -52,6 +60,9 @@ pub struct PgConnection {
     // cache statement by query string to the id and columns
     cache_statement: StatementCache<(Oid, Arc<PgStatementMetadata>)>,
 
+    // prepare cached statements again when their result type has changed
+    auto_reprepare: bool,
+
     // cache user-defined types by id <-> info
     cache_type_info: HashMap<Oid, PgTypeInfo>,
     cache_type_oid: HashMap<UStr, Oid>,
@@ -63,6 +74,9 @@ pub struct PgConnection {
     transaction_status: TransactionStatus,
     pub(crate) transaction_depth: usize,
 
+    // last value set with `Connection::set_statement_timeout()`
+    statement_timeout: Option<Duration>,
+
     log_settings: LogSettings,
 }
 
@@ -72,6 +86,55 @@ impl PgConnection {
         self.stream.server_version_num
     }
 
+    /// Subscribe to the notices and warnings sent by the server on this connection, such as
+    /// those raised by `RAISE NOTICE` in PL/pgSQL.
+    ///
+    /// Notices are only received while the connection is in use, so the returned stream
+    /// yields them as queries on this connection are executed. It ends when the connection
+    /// is closed or dropped, or when it is returned to the pool it was acquired from, so
+    /// notices meant for the next user of the connection are never received.
+    ///
+    /// Notices are buffered until they are read, without limit; a stream that won't be read
+    /// from anymore should be dropped.
+    pub fn notices(&mut self) -> impl Stream<Item = PgNotice> + Send + Unpin + 'static {
+        let (sender, receiver) = mpsc::unbounded();
+        self.stream.notice_subscribers.push(sender);
+        receiver
+    }
+
+    /// Fetch the user-defined type `name` from the database again, replacing what this
+    /// connection has cached about it and about the types made of it, such as its array type.
+    ///
+    /// Cached statements which use any of these types are closed, to be prepared again when
+    /// they are next executed.
+    ///
+    /// This is needed after a type was dropped and created again, which gives it a new OID,
+    /// or after its definition was changed. Labels added to an enum with `ALTER TYPE ... ADD VALUE`
+    /// are noticed the first time a query returns one, and the enum is refreshed then.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(conn: &mut sqlx::PgConnection) -> sqlx::Result<()> {
+    /// sqlx::query("ALTER TYPE mood ADD VALUE 'ecstatic'")
+    ///     .execute(&mut *conn)
+    ///     .await?;
+    ///
+    /// let mood = conn.refresh_type("mood").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn refresh_type(&mut self, name: &str) -> Result<PgTypeInfo, Error> {
+        if let Some(oid) = self.cache_type_oid.remove(name) {
+            self.evict_type(oid).await?;
+        }
+
+        let oid = self.fetch_type_id_by_name(name).await?;
+        let info = self.refresh_type_by_oid(oid).await?;
+
+        self.cache_type_oid.insert(name.to_string().into(), oid);
+
+        Ok(info)
+    }
+
     // will return when the connection is ready for another query
     pub(crate) async fn wait_until_ready(&mut self) -> Result<(), Error> {
         if !self.stream.write_buffer_mut().is_empty() {
@@ -117,6 +180,11 @@ impl PgConnection {
     }
 }
 
+// The value of `statement_timeout` for `timeout`, where `0` disables the timeout.
+pub(crate) fn statement_timeout_millis(timeout: Option<Duration>) -> u128 {
+    timeout.map_or(0, |timeout| cmp::max(timeout.as_millis(), 1))
+}
+
 impl Debug for PgConnection {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("PgConnection").finish()
@@ -151,15 +219,22 @@ impl Connection for PgConnection {
         })
     }
 
-    fn ping(&mut self) -> BoxFuture<'_, Result<(), Error>> {
+    fn ping(&mut self) -> BoxFuture<'_, Result<Duration, Error>> {
         // Users were complaining about this showing up in query statistics on the server.
         // By sending a comment we avoid an error if the connection was in the middle of a rowset
         // self.execute("/* SQLx ping */").map_ok(|_| ()).boxed()
 
         Box::pin(async move {
+            // don't count the time taken to finish a previous command
+            self.wait_until_ready().await?;
+
+            let started_at = Instant::now();
+
             // The simplest call-and-response that's possible.
             self.write_sync();
-            self.wait_until_ready().await
+            self.wait_until_ready().await?;
+
+            Ok(started_at.elapsed())
         })
     }
 
@@ -199,10 +274,45 @@ impl Connection for PgConnection {
         })
     }
 
+    fn set_statement_timeout(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        let millis = statement_timeout_millis(timeout);
+
+        Box::pin(async move {
+            self.execute(&*format!("SET statement_timeout = {millis}"))
+                .await?;
+            self.statement_timeout = timeout;
+
+            Ok(())
+        })
+    }
+
+    fn statement_timeout(&self) -> Option<Duration> {
+        self.statement_timeout
+    }
+
+    fn supports_statement_timeout(&self) -> bool {
+        true
+    }
+
+    fn estimate_rows<'e>(
+        &'e mut self,
+        table_or_query: &'e str,
+    ) -> BoxFuture<'e, Result<u64, Error>> {
+        Box::pin(self.estimate_rows_of(table_or_query))
+    }
+
     fn shrink_buffers(&mut self) {
         self.stream.shrink_buffers();
     }
 
+    fn reset_on_release(&mut self) {
+        // end the streams from `notices()` so they don't receive the notices of the next user
+        self.stream.notice_subscribers.clear();
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         self.wait_until_ready().boxed()
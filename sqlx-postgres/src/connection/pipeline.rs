@@ -0,0 +1,250 @@
+use std::sync::Arc;
+
+use sqlx_core::Either;
+
+use crate::error::{BoxDynError, Error};
+use crate::executor::Execute;
+use crate::logger::QueryLogger;
+use crate::message::{self, Bind, CommandComplete, DataRow, MessageFormat};
+use crate::statement::PgStatementMetadata;
+use crate::{PgArguments, PgConnection, PgQueryResult, PgRow, PgValueFormat, Postgres};
+
+/// A batch of queries to send to the server in a single round-trip.
+///
+/// Created by [`PgConnection::pipeline()`].
+///
+/// Statements which have not been prepared on this connection before are still prepared one at
+/// a time. Then every query is bound and executed, followed by a single `Sync`, so the results
+/// of all the queries are read after one round-trip.
+///
+/// Because of the single `Sync`, the queries run in one implicit transaction, unless the
+/// connection is already in a transaction: if a query fails, the remaining queries are skipped
+/// and the changes made by the preceding queries are rolled back.
+///
+/// Each query must consist of a single statement, as queries without arguments are also
+/// prepared. Queries with a [timeout][sqlx_core::query::Query::timeout] are not supported.
+///
+/// ```rust,no_run
+/// # async fn example(conn: &mut sqlx::PgConnection) -> sqlx::Result<()> {
+/// let mut pipeline = conn.pipeline();
+///
+/// for id in 1..=3_i64 {
+///     pipeline.push(sqlx::query("UPDATE users SET active = true WHERE id = $1").bind(id));
+/// }
+///
+/// pipeline.push(sqlx::query("SELECT count(*) FROM users WHERE active"));
+///
+/// // one `Vec` for each query, in the order they were pushed
+/// let results = pipeline.execute().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "the queries are not sent until `.execute()` is called"]
+pub struct PgPipeline<'c, 'q> {
+    conn: &'c mut PgConnection,
+    queries: Vec<PipelineQuery<'q>>,
+}
+
+struct PipelineQuery<'q> {
+    sql: &'q str,
+    arguments: Result<Option<PgArguments>, BoxDynError>,
+    persistent: bool,
+    metadata: Option<Arc<PgStatementMetadata>>,
+    has_timeout: bool,
+}
+
+impl PgConnection {
+    /// Start a batch of queries to send to the server in a single round-trip.
+    ///
+    /// See [`PgPipeline`].
+    pub fn pipeline<'q>(&mut self) -> PgPipeline<'_, 'q> {
+        PgPipeline {
+            conn: self,
+            queries: Vec::new(),
+        }
+    }
+}
+
+impl<'c, 'q> PgPipeline<'c, 'q> {
+    /// Add a query to the end of the batch.
+    pub fn push<E>(&mut self, mut query: E) -> &mut Self
+    where
+        E: Execute<'q, Postgres>,
+    {
+        // False positive: https://github.com/rust-lang/rust-clippy/issues/12560
+        #[allow(clippy::map_clone)]
+        let metadata = query.statement().map(|s| Arc::clone(&s.metadata));
+
+        self.queries.push(PipelineQuery {
+            sql: query.sql(),
+            metadata,
+            arguments: query.take_arguments(),
+            persistent: query.persistent(),
+            has_timeout: query.timeout().is_some(),
+        });
+
+        self
+    }
+
+    /// The number of queries in the batch.
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    /// Returns `true` if no queries have been added to the batch.
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+
+    /// Send the queries and return the results of each, in the order they were added.
+    ///
+    /// If a query fails, its error is returned and the results of the preceding queries are
+    /// discarded.
+    pub async fn execute(self) -> Result<Vec<Vec<Either<PgQueryResult, PgRow>>>, Error> {
+        let Self { conn, queries } = self;
+
+        if queries.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        if queries.iter().any(|query| query.has_timeout) {
+            return Err(Error::Configuration(
+                "per-query timeouts are not supported in a pipeline".into(),
+            ));
+        }
+
+        conn.wait_until_ready().await?;
+
+        let mut loggers = Vec::with_capacity(queries.len());
+        let mut prepared = Vec::with_capacity(queries.len());
+
+        // prepare every statement first, as this may need a round-trip of its own
+        for query in queries {
+            let mut arguments = query.arguments.map_err(Error::Encode)?.unwrap_or_default();
+
+            let (statement, metadata) = conn
+                .get_or_prepare(
+                    query.sql,
+                    &arguments.types,
+                    query.persistent,
+                    query.metadata,
+                )
+                .await?;
+
+            // patch holes created during encoding
+            arguments.apply_patches(conn, &metadata.parameters).await?;
+
+            loggers.push(QueryLogger::new(
+                query.sql,
+                arguments.types.len(),
+                conn.log_settings.clone(),
+            ));
+            prepared.push((statement, metadata, arguments));
+        }
+
+        conn.wait_until_ready().await?;
+
+        for (statement, _, arguments) in &prepared {
+            conn.stream.write(Bind {
+                portal: None,
+                statement: *statement,
+                formats: &[PgValueFormat::Binary],
+                num_params: arguments.types.len() as i16,
+                params: &arguments.buffer,
+                result_formats: &[PgValueFormat::Binary],
+            });
+
+            conn.stream.write(message::Execute {
+                portal: None,
+                limit: 0,
+            });
+
+            conn.stream.write(message::Close::Portal(None));
+        }
+
+        conn.write_sync();
+        conn.stream.flush().await?;
+
+        let mut results: Vec<Vec<_>> = Vec::with_capacity(prepared.len());
+        let mut current = Vec::new();
+
+        loop {
+            // the index of the query the message is a result of
+            let index = results.len();
+
+            let message = conn.stream.recv().await;
+
+            if let (Err(error), Some(logger)) = (&message, loggers.get_mut(index)) {
+                logger.record_error(error);
+            }
+
+            let message = message?;
+
+            match message.format {
+                MessageFormat::BindComplete | MessageFormat::CloseComplete => {
+                    // harmless messages to ignore
+                }
+
+                MessageFormat::CommandComplete
+                | MessageFormat::EmptyQueryResponse
+                | MessageFormat::DataRow
+                    if index >= prepared.len() =>
+                {
+                    return Err(err_protocol!(
+                        "pipeline: received {:?} after the results of every query",
+                        message.format
+                    ));
+                }
+
+                MessageFormat::CommandComplete => {
+                    let cc: CommandComplete = message.decode()?;
+
+                    let rows_affected = cc.rows_affected();
+                    loggers[index].increase_rows_affected(rows_affected);
+                    current.push(Either::Left(PgQueryResult { rows_affected }));
+
+                    results.push(std::mem::take(&mut current));
+                }
+
+                MessageFormat::EmptyQueryResponse => {
+                    // the query string was empty
+                    results.push(std::mem::take(&mut current));
+                }
+
+                MessageFormat::DataRow => {
+                    loggers[index].increment_rows_returned();
+
+                    let data: DataRow = message.decode()?;
+                    current.push(Either::Right(PgRow {
+                        data,
+                        format: PgValueFormat::Binary,
+                        metadata: Arc::clone(&prepared[index].1),
+                        time_zone_offset: conn.stream.time_zone_offset,
+                    }));
+                }
+
+                MessageFormat::ReadyForQuery => {
+                    conn.handle_ready_for_query(message)?;
+                    break;
+                }
+
+                _ => {
+                    return Err(err_protocol!(
+                        "pipeline: unexpected message: {:?}",
+                        message.format
+                    ));
+                }
+            }
+        }
+
+        if results.len() != prepared.len() {
+            return Err(err_protocol!(
+                "pipeline: expected results for {} queries but received {}",
+                prepared.len(),
+                results.len()
+            ));
+        }
+
+        Ok(results)
+    }
+}
@@ -12,7 +12,8 @@ use crate::error::Error;
 use crate::io::{Decode, Encode};
 use crate::message::{Message, MessageFormat, Notice, Notification, ParameterStatus};
 use crate::net::{self, BufferedSocket, Socket};
-use crate::{PgConnectOptions, PgDatabaseError, PgSeverity};
+use crate::notice::PgNoticeCallback;
+use crate::{PgConnectOptions, PgDatabaseError, PgNotice, PgSeverity};
 
 // the stream is a separate type from the connection to uphold the invariant where an instantiated
 // [PgConnection] is a **valid** connection to postgres
@@ -33,9 +34,19 @@ pub struct PgStream {
     // re-used for query execution in-between receiving messages
     pub(crate) notifications: Option<UnboundedSender<Notification>>,
 
+    // callback from the connect options invoked for every notice received
+    notice_callback: Option<PgNoticeCallback>,
+
+    // subscribers created by `PgConnection::notices()`; closed ones are dropped as notices arrive,
+    // and all of them when the connection is returned to a pool
+    pub(crate) notice_subscribers: Vec<UnboundedSender<PgNotice>>,
+
     pub(crate) parameter_statuses: BTreeMap<String, String>,
 
     pub(crate) server_version_num: Option<u32>,
+
+    // offset of the session `TimeZone` in seconds east of UTC, if it is a fixed offset
+    pub(crate) time_zone_offset: Option<i32>,
 }
 
 impl PgStream {
@@ -50,8 +61,11 @@ impl PgStream {
         Ok(Self {
             inner: BufferedSocket::new(socket),
             notifications: None,
+            notice_callback: options.notice_callback.clone(),
+            notice_subscribers: Vec::new(),
             parameter_statuses: BTreeMap::default(),
             server_version_num: None,
+            time_zone_offset: None,
         })
     }
 
@@ -127,6 +141,10 @@ impl PgStream {
                         "server_version" => {
                             self.server_version_num = parse_server_version(&value);
                         }
+                        "TimeZone" => {
+                            self.time_zone_offset = parse_time_zone_offset(&value);
+                            self.parameter_statuses.insert(name, value);
+                        }
                         _ => {
                             self.parameter_statuses.insert(name, value);
                         }
@@ -166,6 +184,8 @@ impl PgStream {
                         );
                     }
 
+                    self.dispatch_notice(notice);
+
                     continue;
                 }
 
@@ -177,6 +197,23 @@ impl PgStream {
     }
 }
 
+impl PgStream {
+    fn dispatch_notice(&mut self, notice: Notice) {
+        if self.notice_callback.is_none() && self.notice_subscribers.is_empty() {
+            return;
+        }
+
+        let notice = PgNotice(notice);
+
+        self.notice_subscribers
+            .retain(|subscriber| subscriber.unbounded_send(notice.clone()).is_ok());
+
+        if let Some(callback) = &self.notice_callback {
+            callback.call(notice);
+        }
+    }
+}
+
 impl Deref for PgStream {
     type Target = BufferedSocket<Box<dyn Socket>>;
 
@@ -238,9 +275,55 @@ fn parse_server_version(s: &str) -> Option<u32> {
     Some(version_num)
 }
 
+// Parses the `TimeZone` reported by the server into an offset in seconds east of UTC.
+//
+// Only zones without daylight saving time have a single offset, such as `UTC`, `Etc/GMT-2` or
+// the POSIX-style `<+02>-02` reported after `SET TIME ZONE 2`. Other zones return `None`.
+fn parse_time_zone_offset(s: &str) -> Option<i32> {
+    const UTC: &[&str] = &["UTC", "UCT", "GMT", "Z", "Zulu", "Universal", "Greenwich"];
+
+    let name = s.strip_prefix("Etc/").unwrap_or(s);
+
+    if UTC.iter().any(|utc| name.eq_ignore_ascii_case(utc)) {
+        return Some(0);
+    }
+
+    let posix = if let Some(rest) = name.strip_prefix('<') {
+        // skip a quoted abbreviation, e.g. `<+02>`
+        &rest[rest.find('>')? + 1..]
+    } else if name.len() > 3 && name.is_char_boundary(3) && name[..3].eq_ignore_ascii_case("GMT") {
+        &name[3..]
+    } else {
+        name
+    };
+
+    let (sign, offset) = match posix.as_bytes().first()? {
+        b'+' => (1, &posix[1..]),
+        b'-' => (-1, &posix[1..]),
+        _ => (1, posix),
+    };
+
+    if offset.split(':').count() > 3 {
+        return None;
+    }
+
+    let mut seconds = 0;
+
+    for (part, unit) in offset.split(':').zip([3600, 60, 1]) {
+        if part.is_empty() || part.len() > 3 || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        seconds += part.parse::<i32>().ok()? * unit;
+    }
+
+    // POSIX offsets are positive west of Greenwich
+    Some(-sign * seconds)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::parse_server_version;
+    use super::{parse_server_version, parse_time_zone_offset};
 
     #[test]
     fn test_parse_server_version_num() {
@@ -256,4 +339,24 @@ mod tests {
         // unknown
         assert_eq!(parse_server_version("unknown"), None);
     }
+
+    #[test]
+    fn test_parse_time_zone_offset() {
+        assert_eq!(parse_time_zone_offset("UTC"), Some(0));
+        assert_eq!(parse_time_zone_offset("Etc/UTC"), Some(0));
+        assert_eq!(parse_time_zone_offset("GMT"), Some(0));
+        // POSIX-style, as reported after `SET TIME ZONE 2` or `SET TIME ZONE '-05:30'`
+        assert_eq!(parse_time_zone_offset("<+02>-02"), Some(7200));
+        assert_eq!(parse_time_zone_offset("<-05:30>+05:30"), Some(-19800));
+        assert_eq!(parse_time_zone_offset("Etc/GMT-2"), Some(7200));
+        assert_eq!(parse_time_zone_offset("Etc/GMT+5"), Some(-18000));
+        assert_eq!(parse_time_zone_offset("3"), Some(-10800));
+        // zones with daylight saving time
+        assert_eq!(parse_time_zone_offset("Europe/Berlin"), None);
+        assert_eq!(parse_time_zone_offset("EST5EDT"), None);
+        assert_eq!(
+            parse_time_zone_offset("<+02>-02<+03>,M3.5.0,M10.5.0/3"),
+            None
+        );
+    }
 }
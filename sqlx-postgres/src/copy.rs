@@ -1,12 +1,17 @@
 use futures_core::future::BoxFuture;
 use std::borrow::Cow;
+use std::collections::hash_map::{Entry, HashMap};
+use std::hash::Hash;
 use std::ops::{Deref, DerefMut};
 
-use futures_core::stream::BoxStream;
+use futures_core::stream::{BoxStream, Stream};
+use futures_util::{future, TryStreamExt};
 use sqlx_core::bytes::{BufMut, Bytes};
 
+use crate::arguments::PgArgumentBuffer;
 use crate::connection::PgConnection;
-use crate::error::{Error, Result};
+use crate::encode::Encode;
+use crate::error::{BoxDynError, Error, Result};
 use crate::ext::async_stream::TryAsyncStream;
 use crate::io::{AsyncRead, AsyncReadExt};
 use crate::message::{
@@ -15,6 +20,15 @@ use crate::message::{
 use crate::pool::{Pool, PoolConnection};
 use crate::Postgres;
 
+// The signature, flags and header extension length which start binary `COPY` data.
+const BINARY_HEADER: &[u8] = b"PGCOPY\n\xff\r\n\0\0\0\0\0\0\0\0\0";
+
+// The field count which ends binary `COPY` data.
+const BINARY_TRAILER: &[u8] = &(-1_i16).to_be_bytes();
+
+// How much encoded data `PgCopyInBinary` collects before sending it.
+const BINARY_CHUNK_SIZE: usize = 64 * 1024;
+
 impl PgConnection {
     /// Issue a `COPY FROM STDIN` statement and transition the connection to streaming data
     /// to Postgres. This is a more efficient way to import data into Postgres as compared to
@@ -269,7 +283,7 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
                     Some(Cow::Borrowed("57014")) => {
                         // postgres abort received error code
                         conn.stream
-                            .recv_expect(MessageFormat::ReadyForQuery)
+                            .recv_expect::<()>(MessageFormat::ReadyForQuery)
                             .await?;
                         Ok(())
                     }
@@ -303,13 +317,507 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
         };
 
         conn.stream
-            .recv_expect(MessageFormat::ReadyForQuery)
+            .recv_expect::<()>(MessageFormat::ReadyForQuery)
             .await?;
 
         Ok(cc.rows_affected())
     }
+
+    /// Encode the rows to send in the binary `COPY` format, for a statement with
+    /// `FORMAT BINARY`, such as:
+    ///
+    /// ```sql
+    /// COPY users (id, name) FROM STDIN (FORMAT BINARY)
+    /// ```
+    ///
+    /// Returns an error if Postgres is expecting data in text or CSV format.
+    ///
+    /// ### Note
+    /// [PgCopyInBinary::finish] or [PgCopyInBinary::abort] *must* be called when finished or
+    /// the connection will return an error the next time it is used.
+    pub fn with_binary_format(self) -> Result<PgCopyInBinary<C>> {
+        if self.is_textual() {
+            return Err(Error::Protocol(
+                "binary rows can only be sent to `COPY ... FROM STDIN (FORMAT BINARY)`".into(),
+            ));
+        }
+
+        let buf = BINARY_HEADER.to_vec();
+
+        Ok(PgCopyInBinary {
+            copy: self,
+            buf,
+            row: PgArgumentBuffer::default(),
+        })
+    }
+}
+
+/// A connection in streaming `COPY FROM STDIN` mode, which encodes each row in the binary
+/// `COPY` format.
+///
+/// Created by [PgCopyIn::with_binary_format].
+///
+/// ```rust,no_run
+/// # async fn example(conn: &mut sqlx::PgConnection) -> sqlx::Result<()> {
+/// let mut copy = conn
+///     .copy_in_raw("COPY users (id, name) FROM STDIN (FORMAT BINARY)")
+///     .await?
+///     .with_binary_format()?;
+///
+/// for (id, name) in [(1_i64, "alice"), (2, "bob")] {
+///     copy.send_row((id, name)).await?;
+/// }
+///
+/// let rows = copy.finish().await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ### Note
+/// [PgCopyInBinary::finish] or [PgCopyInBinary::abort] *must* be called when finished or the
+/// connection will return an error the next time it is used.
+#[must_use = "connection will error on next use if `.finish()` or `.abort()` is not called"]
+pub struct PgCopyInBinary<C: DerefMut<Target = PgConnection>> {
+    copy: PgCopyIn<C>,
+    // encoded rows not yet sent
+    buf: Vec<u8>,
+    // scratch space for encoding a row
+    row: PgArgumentBuffer,
+}
+
+impl<C: DerefMut<Target = PgConnection>> PgCopyInBinary<C> {
+    /// Encode a row and send it once enough data has been collected.
+    ///
+    /// The row is a tuple with a value for each column of the `COPY` statement, in order.
+    /// Each value must be encoded as the exact type of its column, as Postgres does not
+    /// convert binary `COPY` data; for example, an `INT4` column requires an `i32`.
+    ///
+    /// Values which need to look up type OIDs by name, such as arrays of custom types, are not
+    /// supported and return an error.
+    pub async fn send_row<'q, R>(&mut self, row: R) -> Result<&mut Self>
+    where
+        R: PgCopyRow<'q>,
+    {
+        if R::NUM_COLUMNS != self.copy.num_columns() {
+            return Err(Error::Protocol(format!(
+                "COPY expects {} columns, but the row has {}",
+                self.copy.num_columns(),
+                R::NUM_COLUMNS
+            )));
+        }
+
+        self.row.clear();
+        self.row
+            .extend_from_slice(&(R::NUM_COLUMNS as i16).to_be_bytes());
+
+        row.encode_row(&mut self.row).map_err(Error::Encode)?;
+
+        if self.row.has_patches() {
+            return Err(Error::Encode(
+                "values which need type OIDs looked up by name cannot be sent with COPY".into(),
+            ));
+        }
+
+        self.buf.extend_from_slice(&self.row);
+
+        if self.buf.len() >= BINARY_CHUNK_SIZE {
+            self.flush().await?;
+        }
+
+        Ok(self)
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        if !self.buf.is_empty() {
+            self.copy.send(&self.buf[..]).await?;
+            self.buf.clear();
+        }
+
+        Ok(())
+    }
+
+    /// Signal that the `COPY` process should be aborted and any data received should be discarded.
+    ///
+    /// See [PgCopyIn::abort].
+    pub async fn abort(self, msg: impl Into<String>) -> Result<()> {
+        self.copy.abort(msg).await
+    }
+
+    /// Send the remaining rows and signal that the `COPY` process is complete.
+    ///
+    /// The number of rows affected is returned.
+    pub async fn finish(mut self) -> Result<u64> {
+        self.buf.extend_from_slice(BINARY_TRAILER);
+        self.flush().await?;
+
+        self.copy.finish().await
+    }
 }
 
+/// Routes rows to a separate binary `COPY FROM STDIN` for each partition of a table,
+/// such as one child table per day for time-series data.
+///
+/// The `COPY` for a partition is started, on its own connection from the pool, when the first
+/// row for that partition is sent. The `COPY`s of all partitions are open at the same time,
+/// and are finished concurrently by [`finish()`][Self::finish].
+///
+/// ```rust,no_run
+/// # async fn example(pool: &sqlx::PgPool) -> sqlx::Result<()> {
+/// use sqlx::postgres::PgPartitionedCopyIn;
+///
+/// let mut copy = PgPartitionedCopyIn::new(pool, |day: &String| {
+///     format!("COPY events_{day} (ts, payload) FROM STDIN (FORMAT BINARY)")
+/// });
+///
+/// for (ts, payload) in [(1_700_000_000_i64, "a"), (1_700_090_000, "b")] {
+///     let day = (ts / 86_400).to_string();
+///     copy.send_row(day, (ts, payload)).await?;
+/// }
+///
+/// // the number of rows copied into each partition
+/// let rows = copy.finish().await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ### Note
+/// Each partition holds a connection until the `COPY` is finished, so the number of partitions
+/// is limited to the pool's `max_connections` by default; see [`max_partitions()`][Self::max_partitions].
+#[must_use = "the rows are discarded if `.finish()` is not called"]
+pub struct PgPartitionedCopyIn<K, F> {
+    pool: Pool<Postgres>,
+    statement: F,
+    max_partitions: usize,
+    copies: HashMap<K, PgCopyInBinary<PoolConnection<Postgres>>>,
+}
+
+impl<K, F> PgPartitionedCopyIn<K, F>
+where
+    K: Eq + Hash,
+    F: Fn(&K) -> String,
+{
+    /// Create a router which starts a `COPY` for each partition with the statement returned
+    /// by `statement`.
+    ///
+    /// Each statement must be a `COPY ... FROM STDIN (FORMAT BINARY)` command.
+    pub fn new(pool: &Pool<Postgres>, statement: F) -> Self {
+        Self {
+            pool: pool.clone(),
+            statement,
+            max_partitions: pool.options().get_max_connections() as usize,
+            copies: HashMap::new(),
+        }
+    }
+
+    /// Return an error instead of starting a `COPY` for more than `max` partitions.
+    pub fn max_partitions(mut self, max: usize) -> Self {
+        self.max_partitions = max;
+        self
+    }
+
+    /// The partitions with a `COPY` in progress.
+    pub fn partitions(&self) -> impl Iterator<Item = &K> {
+        self.copies.keys()
+    }
+
+    /// Send a row to the `COPY` of `partition`, starting it if necessary.
+    ///
+    /// See [`PgCopyInBinary::send_row()`].
+    pub async fn send_row<'q, R>(&mut self, partition: K, row: R) -> Result<&mut Self>
+    where
+        R: PgCopyRow<'q>,
+    {
+        let num_partitions = self.copies.len();
+
+        let copy = match self.copies.entry(partition) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                if num_partitions >= self.max_partitions {
+                    return Err(Error::Configuration(
+                        format!(
+                            "COPY into more than {} partitions at once",
+                            self.max_partitions
+                        )
+                        .into(),
+                    ));
+                }
+
+                let statement = (self.statement)(entry.key());
+                let conn = self.pool.acquire().await?;
+                let copy = PgCopyIn::begin(conn, &statement)
+                    .await?
+                    .with_binary_format()?;
+
+                entry.insert(copy)
+            }
+        };
+
+        copy.send_row(row).await?;
+
+        Ok(self)
+    }
+
+    /// Send every row of `rows` to the `COPY` of the partition returned by `partition_key`.
+    pub async fn send_stream<'q, R, S>(
+        &mut self,
+        rows: S,
+        partition_key: impl Fn(&R) -> K,
+    ) -> Result<&mut Self>
+    where
+        R: PgCopyRow<'q>,
+        S: Stream<Item = Result<R>>,
+    {
+        let mut rows = std::pin::pin!(rows);
+
+        while let Some(row) = rows.try_next().await? {
+            let partition = partition_key(&row);
+            self.send_row(partition, row).await?;
+        }
+
+        Ok(self)
+    }
+
+    /// Signal that every `COPY` should be aborted and the rows sent should be discarded.
+    pub async fn abort(self, msg: impl Into<String>) -> Result<()> {
+        let msg = msg.into();
+
+        future::try_join_all(
+            self.copies
+                .into_values()
+                .map(|copy| copy.abort(msg.clone())),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Finish the `COPY` of every partition concurrently, returning the number of rows copied
+    /// into each.
+    ///
+    /// If any `COPY` fails, its error is returned and the `COPY`s which aren't finished yet
+    /// are aborted.
+    pub async fn finish(self) -> Result<HashMap<K, u64>> {
+        future::try_join_all(
+            self.copies
+                .into_iter()
+                .map(|(partition, copy)| async move { Ok((partition, copy.finish().await?)) }),
+        )
+        .await
+        .map(HashMap::from_iter)
+    }
+}
+
+/// A row of values to send with [PgCopyInBinary::send_row].
+///
+/// Implemented for tuples of up to 16 values implementing [`Encode`].
+pub trait PgCopyRow<'q> {
+    /// The number of values in the row.
+    const NUM_COLUMNS: usize;
+
+    /// Encode each value of the row into `buf`, prefixed with its length.
+    fn encode_row(self, buf: &mut PgArgumentBuffer) -> Result<(), BoxDynError>;
+}
+
+macro_rules! impl_copy_row_for_tuple {
+    ($( ($idx:tt) -> $T:ident );+;) => {
+        impl<'q, $($T,)+> PgCopyRow<'q> for ($($T,)+)
+        where
+            $($T: Encode<'q, Postgres>,)+
+        {
+            const NUM_COLUMNS: usize = [$($idx),+].len();
+
+            fn encode_row(self, buf: &mut PgArgumentBuffer) -> Result<(), BoxDynError> {
+                $(buf.encode(self.$idx)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+    (13) -> T14;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+    (13) -> T14;
+    (14) -> T15;
+);
+
+impl_copy_row_for_tuple!(
+    (0) -> T1;
+    (1) -> T2;
+    (2) -> T3;
+    (3) -> T4;
+    (4) -> T5;
+    (5) -> T6;
+    (6) -> T7;
+    (7) -> T8;
+    (8) -> T9;
+    (9) -> T10;
+    (10) -> T11;
+    (11) -> T12;
+    (12) -> T13;
+    (13) -> T14;
+    (14) -> T15;
+    (15) -> T16;
+);
+
 impl<C: DerefMut<Target = PgConnection>> Drop for PgCopyIn<C> {
     fn drop(&mut self) {
         if let Some(mut conn) = self.conn.take() {
@@ -336,15 +844,15 @@ async fn pg_begin_copy_out<'c, C: DerefMut<Target = PgConnection> + Send + 'c>(
         loop {
             match conn.stream.recv().await {
                 Err(e) => {
-                    conn.stream.recv_expect(MessageFormat::ReadyForQuery).await?;
+                    conn.stream.recv_expect::<()>(MessageFormat::ReadyForQuery).await?;
                     return Err(e);
                 },
                 Ok(msg) => match msg.format {
                     MessageFormat::CopyData => r#yield!(msg.decode::<CopyData<Bytes>>()?.0),
                     MessageFormat::CopyDone => {
                         let _ = msg.decode::<CopyDone>()?;
-                        conn.stream.recv_expect(MessageFormat::CommandComplete).await?;
-                        conn.stream.recv_expect(MessageFormat::ReadyForQuery).await?;
+                        conn.stream.recv_expect::<()>(MessageFormat::CommandComplete).await?;
+                        conn.stream.recv_expect::<()>(MessageFormat::ReadyForQuery).await?;
                         return Ok(())
                     },
                     _ => return Err(err_protocol!("unexpected message format during copy out: {:?}", msg.format))
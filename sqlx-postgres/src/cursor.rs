@@ -0,0 +1,124 @@
+use crate::error::{Error, Result};
+use crate::executor::{Execute, Executor};
+use crate::query::query_with;
+use crate::{PgArguments, PgConnection, PgRow, Postgres};
+
+impl PgConnection {
+    /// Declare a cursor `WITH HOLD` for `query`, to fetch its rows a page at a time.
+    ///
+    /// Unlike a regular cursor, which only exists until the end of the transaction it was
+    /// declared in, a holdable cursor survives the transaction being committed, or being declared
+    /// outside of a transaction at all. This allows paging through the results of a query across
+    /// several requests without keeping a transaction open in between.
+    ///
+    /// When the transaction is committed, Postgres materializes the remaining rows of the query,
+    /// so later pages see a consistent snapshot but the cursor holds on to the memory (or
+    /// temporary files) for them.
+    ///
+    /// ### Note
+    /// The cursor belongs to this connection; later pages must be fetched with the same
+    /// connection, e.g. one [detached][crate::pool::PoolConnection::detach] from a pool.
+    /// [PgHoldableCursor::close] *should* be called when finished, or the cursor is kept
+    /// until the connection is closed.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(conn: &mut sqlx::PgConnection, customer_id: i64) -> sqlx::Result<()> {
+    /// let cursor = conn
+    ///     .fetch_paged_holdable(
+    ///         "orders_report",
+    ///         sqlx::query("SELECT * FROM orders WHERE customer_id = $1 ORDER BY id")
+    ///             .bind(customer_id),
+    ///     )
+    ///     .await?;
+    ///
+    /// // rows 51 to 100
+    /// let rows = cursor.fetch_page(conn, 1, 50).await?;
+    ///
+    /// cursor.close(conn).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_paged_holdable<'q, E>(
+        &mut self,
+        name: &str,
+        mut query: E,
+    ) -> Result<PgHoldableCursor>
+    where
+        E: Execute<'q, Postgres>,
+    {
+        let cursor = PgHoldableCursor::from_name(name);
+
+        let arguments: PgArguments = query
+            .take_arguments()
+            .map_err(Error::Encode)?
+            .unwrap_or_default();
+
+        let sql = format!(
+            r#"DECLARE "{}" SCROLL CURSOR WITH HOLD FOR {}"#,
+            cursor.ident(),
+            query.sql()
+        );
+
+        self.execute(query_with(&sql, arguments).persistent(false))
+            .await?;
+
+        Ok(cursor)
+    }
+}
+
+/// A cursor declared `WITH HOLD`, returned by [`PgConnection::fetch_paged_holdable()`].
+///
+/// This is only the name of the cursor, so it can be stored between requests and recreated with
+/// [`from_name()`][Self::from_name].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgHoldableCursor {
+    name: String,
+}
+
+impl PgHoldableCursor {
+    /// Refer to a cursor which was declared on a connection by
+    /// [`PgConnection::fetch_paged_holdable()`].
+    pub fn from_name(name: impl Into<String>) -> Self {
+        PgHoldableCursor { name: name.into() }
+    }
+
+    /// The name of the cursor.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Fetch up to `page_size` rows, starting at the row `page * page_size` of the query.
+    ///
+    /// Pages can be fetched in any order. An empty page is returned past the end of the rows.
+    pub async fn fetch_page(
+        &self,
+        conn: &mut PgConnection,
+        page: u64,
+        page_size: u32,
+    ) -> Result<Vec<PgRow>> {
+        // an offset past the end of the rows leaves the cursor after the last row
+        let offset = i64::try_from(page.saturating_mul(page_size.into())).unwrap_or(i64::MAX);
+
+        // position the cursor on the row before the page
+        conn.execute(&*format!(r#"MOVE ABSOLUTE {offset} IN "{}""#, self.ident()))
+            .await?;
+
+        let sql = format!(r#"FETCH FORWARD {page_size} FROM "{}""#, self.ident());
+
+        conn.fetch_all(query_with(&sql, PgArguments::default()).persistent(false))
+            .await
+    }
+
+    /// Close the cursor, releasing the rows held by it.
+    pub async fn close(self, conn: &mut PgConnection) -> Result<()> {
+        conn.execute(&*format!(r#"CLOSE "{}""#, self.ident()))
+            .await?;
+
+        Ok(())
+    }
+
+    fn ident(&self) -> String {
+        // Any double quotes must be escaped
+        self.name.replace('"', "\"\"")
+    }
+}
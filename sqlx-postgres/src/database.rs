@@ -5,7 +5,7 @@ use crate::{
     PgTypeInfo,
 };
 
-pub(crate) use sqlx_core::database::{Database, HasStatementCache};
+pub(crate) use sqlx_core::database::{Database, HasBindLimit, HasReturningId, HasStatementCache};
 
 /// PostgreSQL database driver.
 #[derive(Debug)]
@@ -38,3 +38,17 @@ impl Database for Postgres {
 }
 
 impl HasStatementCache for Postgres {}
+
+impl HasReturningId for Postgres {
+    fn supports_returning(_conn: &PgConnection) -> bool {
+        true
+    }
+
+    fn last_insert_id(_result: &PgQueryResult) -> Option<Self::Value> {
+        None
+    }
+}
+
+impl HasBindLimit for Postgres {
+    const BIND_LIMIT: usize = 65535;
+}
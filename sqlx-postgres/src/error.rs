@@ -208,6 +208,14 @@ impl DatabaseError for PgDatabaseError {
         self.table()
     }
 
+    fn is_retryable(&self) -> bool {
+        [
+            error_codes::SERIALIZATION_FAILURE,
+            error_codes::DEADLOCK_DETECTED,
+        ]
+        .contains(&self.code())
+    }
+
     fn kind(&self) -> ErrorKind {
         match self.code() {
             error_codes::UNIQUE_VIOLATION => ErrorKind::UniqueViolation,
@@ -229,4 +237,8 @@ pub(crate) mod error_codes {
     pub const NOT_NULL_VIOLATION: &str = "23502";
     /// Caused when a check constraint is violated.
     pub const CHECK_VIOLATION: &str = "23514";
+    /// Caused when a transaction can't be serialized with concurrent transactions.
+    pub const SERIALIZATION_FAILURE: &str = "40001";
+    /// Caused when a transaction was chosen to be aborted to resolve a deadlock.
+    pub const DEADLOCK_DETECTED: &str = "40P01";
 }
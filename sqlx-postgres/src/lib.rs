@@ -10,14 +10,21 @@ mod arguments;
 mod column;
 mod connection;
 mod copy;
+mod cursor;
 mod database;
 mod error;
 mod io;
 mod listener;
+mod listener_hub;
 mod message;
+mod notice;
 mod options;
 mod query_result;
+mod replication;
 mod row;
+mod sequence;
+#[cfg(feature = "json")]
+mod serialize;
 mod statement;
 mod transaction;
 mod type_checking;
@@ -45,14 +52,21 @@ pub(crate) use sqlx_core::driver_prelude::*;
 pub use advisory_lock::{PgAdvisoryLock, PgAdvisoryLockGuard, PgAdvisoryLockKey};
 pub use arguments::{PgArgumentBuffer, PgArguments};
 pub use column::PgColumn;
-pub use connection::PgConnection;
-pub use copy::{PgCopyIn, PgPoolCopyExt};
+pub use connection::{PgConnection, PgPipeline};
+pub use copy::{PgCopyIn, PgCopyInBinary, PgCopyRow, PgPartitionedCopyIn, PgPoolCopyExt};
+pub use cursor::PgHoldableCursor;
 pub use database::Postgres;
 pub use error::{PgDatabaseError, PgErrorPosition};
 pub use listener::{PgListener, PgNotification};
+pub use listener_hub::{PgListenerHub, PgListenerHubEvent, PgListenerHubEvents, PgSubscription};
 pub use message::PgSeverity;
+pub use notice::PgNotice;
 pub use options::{PgConnectOptions, PgSslMode};
 pub use query_result::PgQueryResult;
+pub use replication::{
+    PgIdentifySystem, PgKeepalive, PgLsn, PgReplicationConnection, PgReplicationMessage,
+    PgStandbyStatus, PgXLogData,
+};
 pub use row::PgRow;
 pub use statement::PgStatement;
 pub use transaction::PgTransactionManager;
@@ -32,6 +32,7 @@ pub struct PgListener {
 }
 
 /// An asynchronous notification from Postgres.
+#[derive(Clone)]
 pub struct PgNotification(Notification);
 
 impl PgListener {
@@ -95,11 +96,17 @@ impl PgListener {
 
     /// Starts listening for notifications on a channel.
     /// The channel name is quoted here to ensure case sensitivity.
+    ///
+    /// Listening on a channel again is counted rather than ignored: it takes as many calls to
+    /// [`unlisten()`][Self::unlisten] to stop listening on it, so that parts of an application
+    /// sharing this listener don't stop each other's notifications.
     pub async fn listen(&mut self, channel: &str) -> Result<(), Error> {
-        self.connection()
-            .await?
-            .execute(&*format!(r#"LISTEN "{}""#, ident(channel)))
-            .await?;
+        if !self.channels.iter().any(|s| s == channel) {
+            self.connection()
+                .await?
+                .execute(&*format!(r#"LISTEN "{}""#, ident(channel)))
+                .await?;
+        }
 
         self.channels.push(channel.to_owned());
 
@@ -107,6 +114,8 @@ impl PgListener {
     }
 
     /// Starts listening for notifications on all channels.
+    ///
+    /// Like [`listen()`][Self::listen], each channel is counted once more.
     pub async fn listen_all(
         &mut self,
         channels: impl IntoIterator<Item = &str>,
@@ -114,15 +123,40 @@ impl PgListener {
         let beg = self.channels.len();
         self.channels.extend(channels.into_iter().map(|s| s.into()));
 
-        let query = build_listen_all_query(&self.channels[beg..]);
-        self.connection().await?.execute(&*query).await?;
+        let (listening, new) = self.channels.split_at(beg);
+        let mut new_channels: Vec<&String> = Vec::new();
+
+        for channel in new {
+            if !listening.contains(channel) && !new_channels.contains(&channel) {
+                new_channels.push(channel);
+            }
+        }
+
+        let query = build_listen_all_query(new_channels);
+
+        if !query.is_empty() {
+            self.connection().await?.execute(&*query).await?;
+        }
 
         Ok(())
     }
 
     /// Stops listening for notifications on a channel.
     /// The channel name is quoted here to ensure case sensitivity.
+    ///
+    /// If [`listen()`][Self::listen] was called more than once for the channel, this only
+    /// undoes one of the calls, and notifications are still received until the last is undone.
     pub async fn unlisten(&mut self, channel: &str) -> Result<(), Error> {
+        let Some(pos) = self.channels.iter().position(|s| s == channel) else {
+            return Ok(());
+        };
+
+        // still needed by another call to `listen()`
+        if self.channels.iter().filter(|s| *s == channel).count() > 1 {
+            self.channels.remove(pos);
+            return Ok(());
+        }
+
         // use RAW connection and do NOT re-connect automatically, since this is not required for
         // UNLISTEN (we've disconnected anyways)
         if let Some(connection) = self.connection.as_mut() {
@@ -131,14 +165,13 @@ impl PgListener {
                 .await?;
         }
 
-        if let Some(pos) = self.channels.iter().position(|s| s == channel) {
-            self.channels.remove(pos);
-        }
+        self.channels.remove(pos);
 
         Ok(())
     }
 
-    /// Stops listening for notifications on all channels.
+    /// Stops listening for notifications on all channels, however many times
+    /// [`listen()`][Self::listen] was called for each.
     pub async fn unlisten_all(&mut self) -> Result<(), Error> {
         // use RAW connection and do NOT re-connect automatically, since this is not required for
         // UNLISTEN (we've disconnected anyways)
@@ -152,7 +185,7 @@ impl PgListener {
     }
 
     #[inline]
-    async fn connect_if_needed(&mut self) -> Result<(), Error> {
+    pub(crate) async fn connect_if_needed(&mut self) -> Result<(), Error> {
         if self.connection.is_none() {
             let mut connection = self.pool.acquire().await?;
             connection.stream.notifications = self.buffer_tx.take();
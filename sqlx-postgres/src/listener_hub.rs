@@ -0,0 +1,413 @@
+use std::collections::hash_map::Entry;
+use std::fmt::{self, Debug};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_channel::{mpsc, oneshot};
+use futures_core::stream::Stream;
+use futures_util::StreamExt;
+
+use crate::error::Error;
+use crate::{HashMap, PgListener, PgNotification, PgPool};
+
+// How long to wait for the background task before waking it again, in case the
+// wake-up notification was sent while its connection was being re-established.
+const WAKE_INTERVAL: Duration = Duration::from_secs(1);
+
+// How long to wait before retrying after the background task failed to receive a notification.
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Shares a single [`PgListener`] connection between many subscribers.
+///
+/// Each [`subscribe()`][Self::subscribe] call returns a stream of the notifications on one
+/// channel, optionally filtered. A background task holds one connection from the pool and
+/// issues `LISTEN` for each channel once, however many subscribers it has, and `UNLISTEN`
+/// after the last subscriber of a channel is dropped.
+///
+/// Subscribing to a channel not yet listened to briefly uses a second connection from the pool
+/// to wake the background task, so the pool must allow at least two connections.
+///
+/// Like [`PgListener`], the background connection is re-established if it is lost, and
+/// listens on every subscribed channel again. Notifications already received stay queued in
+/// each subscription, but those sent while the connection was lost are missed, as Postgres does
+/// not keep them; [`events()`][Self::events] reports when this happens, so that callers can
+/// catch up some other way.
+///
+/// This handle is cheap to clone. The background task stops when [`close()`][Self::close]
+/// is called, or once every handle and subscription has been dropped and the next
+/// notification arrives.
+///
+/// ```rust,no_run
+/// # async fn example(pool: sqlx::PgPool) -> sqlx::Result<()> {
+/// use futures_util::StreamExt;
+/// use sqlx::postgres::PgListenerHub;
+///
+/// let hub = PgListenerHub::connect_with(&pool).await?;
+///
+/// let mut orders = hub.subscribe("orders").await?;
+/// let mut big_orders = hub
+///     .subscribe_with_filter("orders", |n| n.payload().len() > 100)
+///     .await?;
+///
+/// while let Some(notification) = orders.next().await {
+///     println!("{}", notification.payload());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct PgListenerHub {
+    pool: PgPool,
+    commands: mpsc::UnboundedSender<Command>,
+    control_channel: Arc<str>,
+    events: EventSenders,
+}
+
+/// A change in the connection of a [`PgListenerHub`], returned by [`PgListenerHub::events()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PgListenerHubEvent {
+    /// The connection was lost. Notifications sent until it is re-established are missed.
+    Disconnected,
+    /// The connection was re-established and is listening on every subscribed channel again.
+    Reconnected,
+}
+
+/// A stream of the [`PgListenerHubEvent`]s of a [`PgListenerHub`].
+///
+/// The stream ends when the [`PgListenerHub`] is closed.
+pub struct PgListenerHubEvents {
+    events: mpsc::UnboundedReceiver<PgListenerHubEvent>,
+}
+
+// `None` once the background task has stopped
+type EventSenders = Arc<Mutex<Option<Vec<mpsc::UnboundedSender<PgListenerHubEvent>>>>>;
+
+/// A stream of the notifications on one channel, returned by [`PgListenerHub::subscribe()`].
+///
+/// The stream ends when the [`PgListenerHub`] is closed.
+pub struct PgSubscription {
+    channel: String,
+    notifications: mpsc::UnboundedReceiver<PgNotification>,
+}
+
+type Filter = Box<dyn Fn(&PgNotification) -> bool + Send + Sync>;
+
+enum Command {
+    Subscribe {
+        channel: String,
+        subscriber: Subscriber,
+        ready: oneshot::Sender<Result<(), Error>>,
+    },
+    Close,
+}
+
+struct Subscriber {
+    filter: Option<Filter>,
+    notifications: mpsc::UnboundedSender<PgNotification>,
+}
+
+impl PgListenerHub {
+    /// Start a background task listening on a connection from `pool`.
+    pub async fn connect_with(pool: &PgPool) -> Result<Self, Error> {
+        let mut listener = PgListener::connect_with(pool).await?;
+
+        // Subscribers wake the task by sending a notification on a channel of its own.
+        let process_id: i32 = crate::query_scalar::query_scalar("SELECT pg_backend_pid()")
+            .fetch_one(&mut listener)
+            .await?;
+
+        let control_channel: Arc<str> = format!("_sqlx_listener_hub_{process_id}").into();
+        listener.listen(&control_channel).await?;
+
+        let (commands_tx, commands_rx) = mpsc::unbounded();
+        let events = EventSenders::new(Mutex::new(Some(Vec::new())));
+
+        crate::rt::spawn(run(
+            listener,
+            commands_rx,
+            control_channel.clone(),
+            events.clone(),
+        ));
+
+        Ok(Self {
+            pool: pool.clone(),
+            commands: commands_tx,
+            control_channel,
+            events,
+        })
+    }
+
+    /// Get a stream of the changes in the connection of the background task from now on.
+    pub fn events(&self) -> PgListenerHubEvents {
+        let (events_tx, events_rx) = mpsc::unbounded();
+
+        // the stream ends immediately if the background task has already stopped
+        if let Some(events) = &mut *self
+            .events
+            .lock()
+            .expect("BUG: panicked while holding a lock")
+        {
+            events.push(events_tx);
+        }
+
+        PgListenerHubEvents { events: events_rx }
+    }
+
+    /// Subscribe to the notifications on `channel`.
+    ///
+    /// Returns once the background task is listening on the channel.
+    pub async fn subscribe(&self, channel: &str) -> Result<PgSubscription, Error> {
+        self.subscribe_inner(channel, None).await
+    }
+
+    /// Subscribe to the notifications on `channel` for which `filter` returns `true`.
+    ///
+    /// `filter` is called on the background task and should return quickly.
+    pub async fn subscribe_with_filter<F>(
+        &self,
+        channel: &str,
+        filter: F,
+    ) -> Result<PgSubscription, Error>
+    where
+        F: Fn(&PgNotification) -> bool + Send + Sync + 'static,
+    {
+        self.subscribe_inner(channel, Some(Box::new(filter))).await
+    }
+
+    async fn subscribe_inner(
+        &self,
+        channel: &str,
+        filter: Option<Filter>,
+    ) -> Result<PgSubscription, Error> {
+        let (notifications_tx, notifications_rx) = mpsc::unbounded();
+        let (ready_tx, mut ready_rx) = oneshot::channel();
+
+        self.commands
+            .unbounded_send(Command::Subscribe {
+                channel: channel.to_owned(),
+                subscriber: Subscriber {
+                    filter,
+                    notifications: notifications_tx,
+                },
+                ready: ready_tx,
+            })
+            .map_err(|_| Error::WorkerCrashed)?;
+
+        loop {
+            self.wake().await?;
+
+            if let Ok(ready) = crate::rt::timeout(WAKE_INTERVAL, &mut ready_rx).await {
+                ready.map_err(|_| Error::WorkerCrashed)??;
+                break;
+            }
+        }
+
+        Ok(PgSubscription {
+            channel: channel.to_owned(),
+            notifications: notifications_rx,
+        })
+    }
+
+    /// Stop the background task, ending every subscription and returning its connection
+    /// to the pool.
+    pub async fn close(&self) -> Result<(), Error> {
+        if self.commands.unbounded_send(Command::Close).is_ok() {
+            self.wake().await?;
+        }
+
+        Ok(())
+    }
+
+    async fn wake(&self) -> Result<(), Error> {
+        crate::query::query("SELECT pg_notify($1, '')")
+            .bind(&*self.control_channel)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+async fn run(
+    mut listener: PgListener,
+    mut commands: mpsc::UnboundedReceiver<Command>,
+    control_channel: Arc<str>,
+    events: EventSenders,
+) {
+    // the event streams end when the task returns
+    let _events_guard = CloseEventsOnDrop(&events);
+
+    let mut subscribers: HashMap<String, Vec<Subscriber>> = HashMap::new();
+    let mut handles_dropped = false;
+
+    loop {
+        while !handles_dropped {
+            match commands.try_next() {
+                Ok(Some(Command::Subscribe {
+                    channel,
+                    subscriber,
+                    ready,
+                })) => {
+                    let res = match subscribers.entry(channel) {
+                        Entry::Occupied(mut entry) => {
+                            entry.get_mut().push(subscriber);
+                            Ok(())
+                        }
+                        Entry::Vacant(entry) => listener.listen(entry.key()).await.map(|()| {
+                            entry.insert(vec![subscriber]);
+                        }),
+                    };
+
+                    let _ = ready.send(res);
+                }
+
+                Ok(Some(Command::Close)) => return,
+
+                // every `PgListenerHub` has been dropped
+                Ok(None) => handles_dropped = true,
+
+                // no pending commands
+                Err(_) => break,
+            }
+        }
+
+        // Drop the subscriptions which have been dropped, and stop listening on their channel
+        // if it has none left.
+        let mut unused = Vec::new();
+
+        for (channel, channel_subscribers) in &mut subscribers {
+            channel_subscribers.retain(|subscriber| !subscriber.notifications.is_closed());
+
+            if channel_subscribers.is_empty() {
+                unused.push(channel.clone());
+            }
+        }
+
+        for channel in unused {
+            subscribers.remove(&channel);
+
+            if let Err(error) = listener.unlisten(&channel).await {
+                tracing::warn!(%error, channel, "error unlistening on channel");
+            }
+        }
+
+        if handles_dropped && subscribers.is_empty() {
+            return;
+        }
+
+        let notification = match listener.try_recv().await {
+            Ok(Some(notification)) => notification,
+
+            // the connection was lost
+            Ok(None) => {
+                send_event(&events, PgListenerHubEvent::Disconnected);
+
+                // reconnect now, rather than on the next `try_recv()`, to report it
+                loop {
+                    match listener.connect_if_needed().await {
+                        Ok(()) => break,
+                        Err(Error::PoolClosed) => return,
+                        Err(error) => {
+                            tracing::warn!(%error, "error reconnecting PgListenerHub");
+                            crate::rt::sleep(RETRY_DELAY).await;
+                        }
+                    }
+                }
+
+                send_event(&events, PgListenerHubEvent::Reconnected);
+                continue;
+            }
+
+            Err(Error::PoolClosed) => return,
+            Err(error) => {
+                tracing::warn!(%error, "error receiving notifications in PgListenerHub");
+                crate::rt::sleep(RETRY_DELAY).await;
+                continue;
+            }
+        };
+
+        if notification.channel() == &*control_channel {
+            continue;
+        }
+
+        for subscriber in subscribers
+            .get(notification.channel())
+            .into_iter()
+            .flatten()
+        {
+            let matches = match &subscriber.filter {
+                Some(filter) => filter(&notification),
+                None => true,
+            };
+
+            if matches {
+                let _ = subscriber
+                    .notifications
+                    .unbounded_send(notification.clone());
+            }
+        }
+    }
+}
+
+fn send_event(events: &EventSenders, event: PgListenerHubEvent) {
+    if let Some(events) = &mut *events.lock().expect("BUG: panicked while holding a lock") {
+        events.retain(|events| events.unbounded_send(event).is_ok());
+    }
+}
+
+struct CloseEventsOnDrop<'a>(&'a EventSenders);
+
+impl Drop for CloseEventsOnDrop<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut events) = self.0.lock() {
+            *events = None;
+        }
+    }
+}
+
+impl PgSubscription {
+    /// The channel this subscription receives the notifications of.
+    pub fn channel(&self) -> &str {
+        &self.channel
+    }
+}
+
+impl Stream for PgSubscription {
+    type Item = PgNotification;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.notifications.poll_next_unpin(cx)
+    }
+}
+
+impl Stream for PgListenerHubEvents {
+    type Item = PgListenerHubEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.events.poll_next_unpin(cx)
+    }
+}
+
+impl Debug for PgListenerHub {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgListenerHub").finish_non_exhaustive()
+    }
+}
+
+impl Debug for PgSubscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgSubscription")
+            .field("channel", &self.channel)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Debug for PgListenerHubEvents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgListenerHubEvents")
+            .finish_non_exhaustive()
+    }
+}
@@ -12,7 +12,6 @@ mod copy;
 mod data_row;
 mod describe;
 mod execute;
-mod flush;
 mod notification;
 mod parameter_description;
 mod parameter_status;
@@ -38,7 +37,6 @@ pub use data_row::DataRow;
 pub use describe::Describe;
 pub use execute::Execute;
 #[allow(unused_imports)]
-pub use flush::Flush;
 pub use notification::Notification;
 pub use parameter_description::ParameterDescription;
 pub use parameter_status::ParameterStatus;
@@ -63,6 +61,7 @@ pub enum MessageFormat {
     CloseComplete,
     CommandComplete,
     CopyData,
+    CopyBothResponse,
     CopyDone,
     CopyInResponse,
     CopyOutResponse,
@@ -107,6 +106,7 @@ impl MessageFormat {
             b'C' => MessageFormat::CommandComplete,
             b'd' => MessageFormat::CopyData,
             b'c' => MessageFormat::CopyDone,
+            b'W' => MessageFormat::CopyBothResponse,
             b'G' => MessageFormat::CopyInResponse,
             b'H' => MessageFormat::CopyOutResponse,
             b'D' => MessageFormat::DataRow,
@@ -188,6 +188,38 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn lock_with_timeout(&mut self, timeout: Duration) -> BoxFuture<'_, Result<(), MigrateError>> {
+        Box::pin(async move {
+            let database_name = current_database(self).await?;
+            let lock_id = generate_lock_id(&database_name);
+
+            let deadline = Instant::now() + timeout;
+            let mut delay = Duration::from_millis(50);
+
+            // poll instead of blocking in `pg_advisory_lock()` so we can give up at the deadline
+            loop {
+                // language=SQL
+                let locked: bool = query_scalar("SELECT pg_try_advisory_lock($1)")
+                    .bind(lock_id)
+                    .fetch_one(&mut *self)
+                    .await?;
+
+                if locked {
+                    return Ok(());
+                }
+
+                let now = Instant::now();
+
+                if now >= deadline {
+                    return Err(MigrateError::LockTimeout(timeout));
+                }
+
+                crate::rt::sleep(std::cmp::min(delay, deadline - now)).await;
+                delay = std::cmp::min(delay * 2, Duration::from_secs(1));
+            }
+        })
+    }
+
     fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
             let database_name = current_database(self).await?;
@@ -203,6 +235,41 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn enter_schema<'e>(
+        &'e mut self,
+        schema: &'e str,
+    ) -> BoxFuture<'e, Result<Option<String>, MigrateError>> {
+        Box::pin(async move {
+            // language=SQL
+            let previous: String = query_scalar("SELECT current_setting('search_path')")
+                .fetch_one(&mut *self)
+                .await?;
+
+            let schema = format!("\"{}\"", schema.replace('"', "\"\""));
+
+            let _ = self
+                .execute(&*format!("CREATE SCHEMA IF NOT EXISTS {schema}"))
+                .await?;
+
+            set_search_path(self, &schema).await?;
+
+            Ok(Some(previous))
+        })
+    }
+
+    fn leave_schema<'e>(
+        &'e mut self,
+        previous: Option<&'e str>,
+    ) -> BoxFuture<'e, Result<(), MigrateError>> {
+        Box::pin(async move {
+            if let Some(previous) = previous {
+                set_search_path(self, previous).await?;
+            }
+
+            Ok(())
+        })
+    }
+
     fn apply<'e: 'm, 'm>(
         &'e mut self,
         migration: &'m Migration,
@@ -298,6 +365,16 @@ async fn execute_migration(
     Ok(())
 }
 
+async fn set_search_path(conn: &mut PgConnection, search_path: &str) -> Result<(), MigrateError> {
+    // language=SQL
+    let _ = query("SELECT set_config('search_path', $1, false)")
+        .bind(search_path)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
 async fn current_database(conn: &mut PgConnection) -> Result<String, MigrateError> {
     // language=SQL
     Ok(query_scalar("SELECT current_database()")
@@ -0,0 +1,110 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+use atoi::atoi;
+
+use crate::message::{Notice, PgSeverity};
+
+/// A notice or warning sent by the server, e.g. from `RAISE NOTICE` in PL/pgSQL.
+///
+/// Notices are always logged under the `sqlx::postgres::notice` target. To capture them
+/// programmatically, register a callback with [`PgConnectOptions::notice_callback()`] or
+/// subscribe to the notices of a single connection with [`PgConnection::notices()`].
+///
+/// [`PgConnectOptions::notice_callback()`]: crate::PgConnectOptions::notice_callback
+/// [`PgConnection::notices()`]: crate::PgConnection::notices
+#[derive(Clone)]
+pub struct PgNotice(pub(crate) Notice);
+
+// Notice message fields are documented:
+// https://www.postgresql.org/docs/current/protocol-error-fields.html
+
+impl PgNotice {
+    #[inline]
+    pub fn severity(&self) -> PgSeverity {
+        self.0.severity()
+    }
+
+    /// The [SQLSTATE](https://www.postgresql.org/docs/current/errcodes-appendix.html) code for
+    /// this notice.
+    #[inline]
+    pub fn code(&self) -> &str {
+        self.0.code()
+    }
+
+    /// The primary human-readable message.
+    #[inline]
+    pub fn message(&self) -> &str {
+        self.0.message()
+    }
+
+    /// An optional secondary message carrying more detail. Might run to multiple lines.
+    #[inline]
+    pub fn detail(&self) -> Option<&str> {
+        self.0.get(b'D')
+    }
+
+    /// An optional suggestion of what to do about the condition being reported.
+    #[inline]
+    pub fn hint(&self) -> Option<&str> {
+        self.0.get(b'H')
+    }
+
+    /// An indication of the context in which the notice was raised, such as a call stack
+    /// traceback of active procedural language functions.
+    pub fn r#where(&self) -> Option<&str> {
+        self.0.get(b'W')
+    }
+
+    /// The file name of the source-code location where this notice was reported.
+    pub fn file(&self) -> Option<&str> {
+        self.0.get(b'F')
+    }
+
+    /// The line number of the source-code location where this notice was reported.
+    pub fn line(&self) -> Option<usize> {
+        self.0.get_raw(b'L').and_then(atoi)
+    }
+
+    /// The name of the source-code routine reporting this notice.
+    pub fn routine(&self) -> Option<&str> {
+        self.0.get(b'R')
+    }
+}
+
+impl Debug for PgNotice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgNotice")
+            .field("severity", &self.severity())
+            .field("code", &self.code())
+            .field("message", &self.message())
+            .field("detail", &self.detail())
+            .field("hint", &self.hint())
+            .field("where", &self.r#where())
+            .finish()
+    }
+}
+
+/// A callback invoked for every notice received on connections created from the same
+/// [`PgConnectOptions`][crate::PgConnectOptions].
+#[derive(Clone)]
+pub(crate) struct PgNoticeCallback(Arc<dyn Fn(PgNotice) + Send + Sync + 'static>);
+
+impl PgNoticeCallback {
+    pub(crate) fn new<F>(callback: F) -> Self
+    where
+        F: Fn(PgNotice) + Send + Sync + 'static,
+    {
+        Self(Arc::new(callback))
+    }
+
+    pub(crate) fn call(&self, notice: PgNotice) {
+        (self.0)(notice)
+    }
+}
+
+impl Debug for PgNoticeCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PgNoticeCallback").finish_non_exhaustive()
+    }
+}
@@ -1,9 +1,13 @@
 use crate::connection::ConnectOptions;
 use crate::error::Error;
+use crate::executor::Executor;
+use crate::logger::QueryLogSink;
 use crate::{PgConnectOptions, PgConnection};
 use futures_core::future::BoxFuture;
 use log::LevelFilter;
+use sqlx_core::statement_stats::StatementStats;
 use sqlx_core::Url;
+use std::sync::Arc;
 use std::time::Duration;
 
 impl ConnectOptions for PgConnectOptions {
@@ -21,7 +25,15 @@ impl ConnectOptions for PgConnectOptions {
     where
         Self::Connection: Sized,
     {
-        Box::pin(PgConnection::establish(self))
+        Box::pin(async move {
+            let mut conn = PgConnection::establish(self).await?;
+
+            for command in &self.init_commands {
+                conn.execute(&**command).await?;
+            }
+
+            Ok(conn)
+        })
     }
 
     fn log_statements(mut self, level: LevelFilter) -> Self {
@@ -33,4 +45,19 @@ impl ConnectOptions for PgConnectOptions {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
+
+    fn query_logger(mut self, logger: Arc<dyn QueryLogSink>) -> Self {
+        self.log_settings.set_query_logger(logger);
+        self
+    }
+
+    fn log_query_fingerprints(mut self, enabled: bool) -> Self {
+        self.log_settings.log_query_fingerprints(enabled);
+        self
+    }
+
+    fn statement_stats(mut self, stats: StatementStats) -> Self {
+        self.log_settings.set_statement_stats(stats);
+        self
+    }
 }
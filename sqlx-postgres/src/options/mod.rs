@@ -1,11 +1,18 @@
 use std::borrow::Cow;
 use std::env::var;
-use std::fmt::{Display, Write};
+use std::fmt::{self, Debug, Display, Formatter, Write};
+use std::future::Future;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_core::future::BoxFuture;
 
 pub use ssl_mode::PgSslMode;
 
-use crate::{connection::LogSettings, net::tls::CertificateInput};
+use crate::error::{BoxDynError, Error};
+use crate::notice::PgNoticeCallback;
+use crate::{connection::LogSettings, net::tls::CertificateInput, PgNotice};
 
 mod connect;
 mod parse;
@@ -92,16 +99,22 @@ pub struct PgConnectOptions {
     pub(crate) socket: Option<PathBuf>,
     pub(crate) username: String,
     pub(crate) password: Option<String>,
+    pub(crate) password_provider: Option<PasswordProvider>,
     pub(crate) database: Option<String>,
     pub(crate) ssl_mode: PgSslMode,
     pub(crate) ssl_root_cert: Option<CertificateInput>,
     pub(crate) ssl_client_cert: Option<CertificateInput>,
     pub(crate) ssl_client_key: Option<CertificateInput>,
     pub(crate) statement_cache_capacity: usize,
+    pub(crate) auto_reprepare: bool,
     pub(crate) application_name: Option<String>,
     pub(crate) log_settings: LogSettings,
     pub(crate) extra_float_digits: Option<Cow<'static, str>>,
     pub(crate) options: Option<String>,
+    pub(crate) startup_params: Vec<(String, String)>,
+    pub(crate) statement_timeout: Option<Duration>,
+    pub(crate) notice_callback: Option<PgNoticeCallback>,
+    pub(crate) init_commands: Vec<String>,
 }
 
 impl Default for PgConnectOptions {
@@ -155,6 +168,7 @@ impl PgConnectOptions {
             socket: None,
             username,
             password: var("PGPASSWORD").ok(),
+            password_provider: None,
             database,
             ssl_root_cert: var("PGSSLROOTCERT").ok().map(CertificateInput::from),
             ssl_client_cert: var("PGSSLCERT").ok().map(CertificateInput::from),
@@ -164,10 +178,15 @@ impl PgConnectOptions {
                 .and_then(|v| v.parse().ok())
                 .unwrap_or_default(),
             statement_cache_capacity: 100,
+            auto_reprepare: true,
             application_name: var("PGAPPNAME").ok(),
             extra_float_digits: Some("2".into()),
             log_settings: Default::default(),
             options: var("PGOPTIONS").ok(),
+            startup_params: Vec::new(),
+            statement_timeout: None,
+            notice_callback: None,
+            init_commands: Vec::new(),
         }
     }
 
@@ -259,6 +278,34 @@ impl PgConnectOptions {
     /// ```
     pub fn password(mut self, password: &str) -> Self {
         self.password = Some(password.to_owned());
+        self.password_provider = None;
+        self
+    }
+
+    /// Sets a function returning the password to authenticate with.
+    ///
+    /// The function is called for every new connection when the server asks for a password,
+    /// so it can generate short-lived credentials, such as AWS RDS IAM authentication tokens
+    /// or Vault dynamic credentials, instead of them being fixed in the URL.
+    /// Replaces a password set with [`password()`][Self::password], from the URL or the
+    /// environment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_postgres::{PgConnectOptions, PgSslMode};
+    /// # async fn generate_token() -> String { String::new() }
+    /// let options = PgConnectOptions::new()
+    ///     .ssl_mode(PgSslMode::VerifyFull)
+    ///     .password_provider(|| async { Ok(generate_token().await) });
+    /// ```
+    pub fn password_provider<F, Fut>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, BoxDynError>> + Send + 'static,
+    {
+        self.password = None;
+        self.password_provider = Some(PasswordProvider(Arc::new(move || Box::pin(f()))));
         self
     }
 
@@ -356,6 +403,33 @@ impl PgConnectOptions {
         self
     }
 
+    /// Sets a callback returning the SSL client certificate as PEM-encoded bytes.
+    ///
+    /// The callback is invoked whenever a connection is opened, so a pool picks up rotated
+    /// certificates and keys without being recreated. Call
+    /// [`Pool::reload_tls()`][sqlx_core::pool::Pool::reload_tls] to also replace the connections
+    /// which are already open.
+    ///
+    /// The same goes for [`ssl_client_key_from_fn()`][Self::ssl_client_key_from_fn] and
+    /// [`ssl_root_cert_from_fn()`][Self::ssl_root_cert_from_fn].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_postgres::{PgSslMode, PgConnectOptions};
+    /// let options = PgConnectOptions::new()
+    ///     .ssl_mode(PgSslMode::VerifyCa)
+    ///     .ssl_client_cert_from_fn(|| async { Ok(std::fs::read("./client.crt")?) });
+    /// ```
+    pub fn ssl_client_cert_from_fn<F, Fut>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, BoxDynError>> + Send + 'static,
+    {
+        self.ssl_client_cert = Some(CertificateInput::from_fn(f));
+        self
+    }
+
     /// Sets the name of a file containing SSL client key.
     ///
     /// # Example
@@ -398,6 +472,28 @@ impl PgConnectOptions {
         self
     }
 
+    /// Sets a callback returning the SSL client key as PEM-encoded bytes.
+    ///
+    /// See [`ssl_client_cert_from_fn()`][Self::ssl_client_cert_from_fn] for when the callback is
+    /// invoked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_postgres::{PgSslMode, PgConnectOptions};
+    /// let options = PgConnectOptions::new()
+    ///     .ssl_mode(PgSslMode::VerifyCa)
+    ///     .ssl_client_key_from_fn(|| async { Ok(std::fs::read("./client.key")?) });
+    /// ```
+    pub fn ssl_client_key_from_fn<F, Fut>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, BoxDynError>> + Send + 'static,
+    {
+        self.ssl_client_key = Some(CertificateInput::from_fn(f));
+        self
+    }
+
     /// Sets PEM encoded trusted SSL Certificate Authorities (CA).
     ///
     /// # Example
@@ -414,6 +510,28 @@ impl PgConnectOptions {
         self
     }
 
+    /// Sets a callback returning the trusted SSL Certificate Authorities (CA) as PEM-encoded bytes.
+    ///
+    /// See [`ssl_client_cert_from_fn()`][Self::ssl_client_cert_from_fn] for when the callback is
+    /// invoked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_postgres::{PgSslMode, PgConnectOptions};
+    /// let options = PgConnectOptions::new()
+    ///     .ssl_mode(PgSslMode::VerifyCa)
+    ///     .ssl_root_cert_from_fn(|| async { Ok(std::fs::read("./ca-certificate.crt")?) });
+    /// ```
+    pub fn ssl_root_cert_from_fn<F, Fut>(mut self, f: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, BoxDynError>> + Send + 'static,
+    {
+        self.ssl_root_cert = Some(CertificateInput::from_fn(f));
+        self
+    }
+
     /// Sets the capacity of the connection's statement cache in a number of stored
     /// distinct statements. Caching is handled using LRU, meaning when the
     /// amount of queries hits the defined limit, the oldest statement will get
@@ -425,6 +543,29 @@ impl PgConnectOptions {
         self
     }
 
+    /// Sets whether cached statements are prepared again when their result type changes.
+    ///
+    /// Altering a table, e.g. to add a column, changes the columns returned by a cached
+    /// `SELECT *` from it, and executing the statement again fails with
+    /// `cached plan must not change result type`. If enabled, the statement is removed from the
+    /// cache, prepared again and executed once more instead.
+    ///
+    /// The statement is not retried inside of a transaction, as the error has aborted it.
+    ///
+    /// Enabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .auto_reprepare(false);
+    /// ```
+    pub fn auto_reprepare(mut self, auto_reprepare: bool) -> Self {
+        self.auto_reprepare = auto_reprepare;
+        self
+    }
+
     /// Sets the application name. Defaults to None
     ///
     /// # Example
@@ -519,6 +660,112 @@ impl PgConnectOptions {
         self
     }
 
+    /// Sets the maximum time the server may spend executing a statement before aborting it.
+    ///
+    /// This is sent as the `statement_timeout` parameter when connecting, so it becomes the
+    /// default of the session, and is returned by
+    /// [`Connection::statement_timeout()`][sqlx_core::connection::Connection::statement_timeout].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use sqlx_postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .statement_timeout(Duration::from_secs(30));
+    /// ```
+    pub fn statement_timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.statement_timeout = timeout.into();
+        self
+    }
+
+    /// Sets a parameter to send to the server in the startup message.
+    ///
+    /// Any run-time parameter of the server can be set this way, as can the parameters which
+    /// connection poolers and proxies read from the startup message to classify connections.
+    ///
+    /// The `application_name`, `options`, `extra_float_digits`, `user` and `database` keys
+    /// replace the value set by the method of the same name; note that `options` replaces the
+    /// whole string of command-line arguments rather than adding to it. Setting `DateStyle` or
+    /// `TimeZone` replaces the value SQLx sends by default, which may break the decoding of
+    /// values in text format, and `client_encoding` must remain `UTF8`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .startup_param("options", "-c geqo=off")
+    ///     .startup_param("search_path", "app");
+    /// assert_eq!(options.get_startup_param("search_path"), Some("app"));
+    /// ```
+    pub fn startup_param(mut self, key: &str, value: &str) -> Self {
+        match key {
+            "application_name" => self.application_name = Some(value.to_owned()),
+            "options" => self.options = Some(value.to_owned()),
+            "extra_float_digits" => self.extra_float_digits = Some(value.to_owned().into()),
+            "user" => self.username = value.to_owned(),
+            "database" => self.database = Some(value.to_owned()),
+            _ => match self.startup_params.iter_mut().find(|(k, _)| k == key) {
+                Some((_, v)) => *v = value.to_owned(),
+                None => self.startup_params.push((key.to_owned(), value.to_owned())),
+            },
+        }
+
+        self
+    }
+
+    /// Sets a callback to be invoked for every notice or warning sent by the server, such as
+    /// those raised by `RAISE NOTICE` in PL/pgSQL.
+    ///
+    /// The callback is shared by all connections opened with these options, and is called
+    /// in addition to the notice being logged. It runs while the connection is reading from
+    /// the server, so it should return quickly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .notice_callback(|notice| println!("{}: {}", notice.code(), notice.message()));
+    /// ```
+    pub fn notice_callback<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(PgNotice) + Send + Sync + 'static,
+    {
+        self.notice_callback = Some(PgNoticeCallback::new(callback));
+        self
+    }
+
+    /// Adds a statement to execute on every new connection, once it is established.
+    ///
+    /// Use this for session setup which can't be sent in the startup message, as with
+    /// [`startup_param`][Self::startup_param], such as `SET ROLE` or `LOAD`. The statements are
+    /// executed in the order they were added, with the simple query protocol, so each may
+    /// contain several statements. They can also be given with the `init-command` parameter of
+    /// the connection URL, which may be repeated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .init_command("SET ROLE reporting")
+    ///     .init_command("SET search_path TO reporting, public");
+    /// ```
+    pub fn init_command(mut self, sql: impl Into<String>) -> Self {
+        self.init_commands.push(sql.into());
+        self
+    }
+
+    /// The password to authenticate with, from the password provider if one is set.
+    pub(crate) async fn fetch_password(&self) -> Result<String, Error> {
+        match &self.password_provider {
+            Some(provider) => (provider.0)().await.map_err(Error::Configuration),
+            None => Ok(self.password.clone().unwrap_or_default()),
+        }
+    }
+
     /// We try using a socket if hostname starts with `/` or if socket parameter
     /// is specified.
     pub(crate) fn fetch_socket(&self) -> Option<String> {
@@ -647,6 +894,60 @@ impl PgConnectOptions {
     pub fn get_options(&self) -> Option<&str> {
         self.options.as_deref()
     }
+
+    /// Get the statement timeout.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use sqlx_postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .statement_timeout(Duration::from_secs(30));
+    /// assert_eq!(options.get_statement_timeout(), Some(Duration::from_secs(30)));
+    /// ```
+    pub fn get_statement_timeout(&self) -> Option<Duration> {
+        self.statement_timeout
+    }
+
+    /// Get the statements executed on every new connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .init_command("SET ROLE reporting");
+    /// assert_eq!(options.get_init_commands(), ["SET ROLE reporting"]);
+    /// ```
+    pub fn get_init_commands(&self) -> &[String] {
+        &self.init_commands
+    }
+
+    /// Get a parameter to send to the server in the startup message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use sqlx_postgres::PgConnectOptions;
+    /// let options = PgConnectOptions::new()
+    ///     .startup_param("application_name", "service");
+    /// assert_eq!(options.get_startup_param("application_name"), Some("service"));
+    /// ```
+    pub fn get_startup_param(&self, key: &str) -> Option<&str> {
+        match key {
+            "application_name" => self.application_name.as_deref(),
+            "options" => self.options.as_deref(),
+            "extra_float_digits" => self.extra_float_digits.as_deref(),
+            "user" => Some(&self.username),
+            "database" => self.database.as_deref(),
+            _ => self
+                .startup_params
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| &**v),
+        }
+    }
 }
 
 fn default_host(port: u16) -> String {
@@ -668,6 +969,19 @@ fn default_host(port: u16) -> String {
     "localhost".to_owned()
 }
 
+/// A function returning the password to authenticate with, from
+/// [`PgConnectOptions::password_provider()`].
+#[derive(Clone)]
+pub(crate) struct PasswordProvider(
+    Arc<dyn Fn() -> BoxFuture<'static, Result<String, BoxDynError>> + Send + Sync>,
+);
+
+impl Debug for PasswordProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PasswordProvider").finish_non_exhaustive()
+    }
+}
+
 #[test]
 fn test_options_formatting() {
     let options = PgConnectOptions::new().options([("geqo", "off")]);
@@ -685,3 +999,18 @@ fn test_options_formatting() {
     let options = PgConnectOptions::new();
     assert_eq!(options.options, None);
 }
+
+#[test]
+fn test_startup_params() {
+    let options = PgConnectOptions::new()
+        .startup_param("application_name", "service")
+        .startup_param("search_path", "sqlx")
+        .startup_param("search_path", "app");
+    assert_eq!(options.application_name, Some("service".to_string()));
+    assert_eq!(
+        options.startup_params,
+        vec![("search_path".to_string(), "app".to_string())]
+    );
+    assert_eq!(options.get_startup_param("search_path"), Some("app"));
+    assert_eq!(options.get_startup_param("geqo"), None);
+}
@@ -94,6 +94,8 @@ impl PgConnectOptions {
                     }
                 }
 
+                "init-command" => options = options.init_command(value),
+
                 k if k.starts_with("options[") => {
                     if let Some(key) = k.strip_prefix("options[").unwrap().strip_suffix(']') {
                         options = options.options([(key, &*value)]);
@@ -162,6 +164,10 @@ impl PgConnectOptions {
             &self.statement_cache_capacity.to_string(),
         );
 
+        for command in &self.init_commands {
+            url.query_pairs_mut().append_pair("init-command", command);
+        }
+
         url
     }
 }
@@ -176,6 +182,23 @@ impl FromStr for PgConnectOptions {
     }
 }
 
+#[test]
+fn it_parses_init_commands() {
+    let url = "postgres://localhost/?init-command=SET%20ROLE%20app&init-command=LOAD%20%27auto_explain%27";
+    let opts = PgConnectOptions::from_str(url).unwrap();
+
+    assert_eq!(
+        opts.get_init_commands(),
+        ["SET ROLE app", "LOAD 'auto_explain'"]
+    );
+
+    let opts = PgConnectOptions::parse_from_url(&opts.build_url()).unwrap();
+    assert_eq!(
+        opts.get_init_commands(),
+        ["SET ROLE app", "LOAD 'auto_explain'"]
+    );
+}
+
 #[test]
 fn it_parses_socket_correctly_from_parameter() {
     let url = "postgres:///?host=/var/run/postgres/";
@@ -0,0 +1,562 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures_core::stream::Stream;
+use sqlx_core::bytes::{Buf, BufMut, Bytes};
+use sqlx_core::connection::Connection;
+use sqlx_core::row::Row;
+
+use crate::connection::PgConnection;
+use crate::error::{BoxDynError, Error};
+use crate::executor::Executor;
+use crate::message::{CopyData, CopyDone, CopyResponse, MessageFormat, Query};
+use crate::{PgConnectOptions, PgRow};
+
+// 2000-01-01, the epoch of Postgres, in seconds since 1970-01-01
+const POSTGRES_EPOCH_SECS: u64 = 946_684_800;
+
+/// A position in the write-ahead log of Postgres, the `pg_lsn` type.
+///
+/// Formatted and parsed as two hexadecimal numbers of 32 bits each, e.g. `16/B374D848`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PgLsn(pub u64);
+
+impl Display for PgLsn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:X}/{:X}", self.0 >> 32, self.0 as u32)
+    }
+}
+
+impl FromStr for PgLsn {
+    type Err = BoxDynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hi, lo) = s
+            .split_once('/')
+            .ok_or_else(|| format!("expected an LSN like `16/B374D848`, got {s:?}"))?;
+
+        Ok(PgLsn(
+            (u64::from(u32::from_str_radix(hi, 16)?) << 32)
+                | u64::from(u32::from_str_radix(lo, 16)?),
+        ))
+    }
+}
+
+/// A message from Postgres while streaming a replication slot.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum PgReplicationMessage {
+    /// Data from the write-ahead log, as decoded by the output plugin of the slot.
+    XLogData(PgXLogData),
+
+    /// A heartbeat, which may ask for a standby status update.
+    Keepalive(PgKeepalive),
+}
+
+/// Data from the write-ahead log, sent by Postgres while streaming a replication slot.
+#[derive(Debug, Clone)]
+pub struct PgXLogData {
+    start_lsn: PgLsn,
+    end_lsn: PgLsn,
+    server_time: SystemTime,
+    data: Bytes,
+}
+
+impl PgXLogData {
+    /// The position of this data in the write-ahead log.
+    pub fn start_lsn(&self) -> PgLsn {
+        self.start_lsn
+    }
+
+    /// The current end of the write-ahead log on the server.
+    pub fn end_lsn(&self) -> PgLsn {
+        self.end_lsn
+    }
+
+    /// The time on the server when this message was sent.
+    pub fn server_time(&self) -> SystemTime {
+        self.server_time
+    }
+
+    /// The data in the format of the output plugin of the slot, e.g. `pgoutput` or `wal2json`.
+    pub fn data(&self) -> &Bytes {
+        &self.data
+    }
+
+    /// Take the data in the format of the output plugin of the slot.
+    pub fn into_data(self) -> Bytes {
+        self.data
+    }
+}
+
+/// A heartbeat sent by Postgres while streaming a replication slot.
+#[derive(Debug, Clone)]
+pub struct PgKeepalive {
+    end_lsn: PgLsn,
+    server_time: SystemTime,
+    reply_requested: bool,
+}
+
+impl PgKeepalive {
+    /// The current end of the write-ahead log on the server.
+    pub fn end_lsn(&self) -> PgLsn {
+        self.end_lsn
+    }
+
+    /// The time on the server when this message was sent.
+    pub fn server_time(&self) -> SystemTime {
+        self.server_time
+    }
+
+    /// Returns `true` if Postgres expects a standby status update as soon as possible,
+    /// and will otherwise time out the connection.
+    pub fn reply_requested(&self) -> bool {
+        self.reply_requested
+    }
+}
+
+/// The progress of a replication client, sent with
+/// [`PgReplicationConnection::send_standby_status()`].
+///
+/// Postgres keeps the write-ahead log from the flushed position on, so it should be updated
+/// regularly, or the log will grow without bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PgStandbyStatus {
+    write_lsn: PgLsn,
+    flush_lsn: PgLsn,
+    apply_lsn: PgLsn,
+    reply_requested: bool,
+}
+
+impl PgStandbyStatus {
+    /// Report that everything up to `lsn` has been written, flushed and applied.
+    pub fn new(lsn: PgLsn) -> Self {
+        PgStandbyStatus {
+            write_lsn: lsn,
+            flush_lsn: lsn,
+            apply_lsn: lsn,
+            reply_requested: false,
+        }
+    }
+
+    /// Set the position up to which data has been received.
+    pub fn write_lsn(mut self, lsn: PgLsn) -> Self {
+        self.write_lsn = lsn;
+        self
+    }
+
+    /// Set the position up to which data has been durably stored.
+    ///
+    /// Postgres may discard the write-ahead log up to this position.
+    pub fn flush_lsn(mut self, lsn: PgLsn) -> Self {
+        self.flush_lsn = lsn;
+        self
+    }
+
+    /// Set the position up to which data has been applied.
+    pub fn apply_lsn(mut self, lsn: PgLsn) -> Self {
+        self.apply_lsn = lsn;
+        self
+    }
+
+    /// Ask Postgres to reply immediately with a [`PgKeepalive`].
+    pub fn reply_requested(mut self, reply_requested: bool) -> Self {
+        self.reply_requested = reply_requested;
+        self
+    }
+}
+
+/// The result of [`PgReplicationConnection::identify_system()`].
+#[derive(Debug, Clone)]
+pub struct PgIdentifySystem {
+    system_id: String,
+    timeline: i32,
+    xlog_pos: PgLsn,
+    database: Option<String>,
+}
+
+impl PgIdentifySystem {
+    /// The unique identifier of the database cluster.
+    pub fn system_id(&self) -> &str {
+        &self.system_id
+    }
+
+    /// The current timeline of the server.
+    pub fn timeline(&self) -> i32 {
+        self.timeline
+    }
+
+    /// The current end of the write-ahead log on the server.
+    pub fn xlog_pos(&self) -> PgLsn {
+        self.xlog_pos
+    }
+
+    /// The database the connection is for.
+    pub fn database(&self) -> Option<&str> {
+        self.database.as_deref()
+    }
+}
+
+/// A connection in logical replication mode, which streams changes from a replication slot.
+///
+/// The slot must already exist, e.g. from `pg_create_logical_replication_slot()`, and the user
+/// needs the `REPLICATION` attribute. Changes are streamed in the format of the output plugin
+/// of the slot, which is not decoded here.
+///
+/// ```rust,no_run
+/// # async fn example() -> sqlx::Result<()> {
+/// use sqlx::postgres::{PgLsn, PgReplicationConnection, PgReplicationMessage, PgStandbyStatus};
+///
+/// let mut conn = PgReplicationConnection::connect("postgres://replicator@localhost/app").await?;
+///
+/// let options = [("proto_version", "1"), ("publication_names", "app")];
+/// conn.start_replication("my_slot", PgLsn(0), &options).await?;
+///
+/// let mut flushed = PgLsn(0);
+///
+/// while let Some(message) = conn.recv().await? {
+///     match message {
+///         PgReplicationMessage::XLogData(data) => {
+///             // store `data.data()` somewhere durable
+///             flushed = data.start_lsn();
+///         }
+///         PgReplicationMessage::Keepalive(keepalive) if keepalive.reply_requested() => {
+///             conn.send_standby_status(PgStandbyStatus::new(flushed)).await?;
+///         }
+///         _ => {}
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct PgReplicationConnection {
+    conn: PgConnection,
+    // `true` between `START_REPLICATION` and the end of the stream
+    streaming: bool,
+}
+
+impl PgReplicationConnection {
+    /// Connect to the database of `url` in logical replication mode.
+    pub async fn connect(url: &str) -> Result<Self, Error> {
+        Self::connect_with(&url.parse()?).await
+    }
+
+    /// Connect with the given options in logical replication mode.
+    pub async fn connect_with(options: &PgConnectOptions) -> Result<Self, Error> {
+        let options = options.clone().startup_param("replication", "database");
+
+        Ok(PgReplicationConnection {
+            conn: PgConnection::establish(&options).await?,
+            streaming: false,
+        })
+    }
+
+    /// Identify the server, e.g. to get the current end of the write-ahead log.
+    pub async fn identify_system(&mut self) -> Result<PgIdentifySystem, Error> {
+        self.assert_not_streaming()?;
+
+        // walsenders only understand the simple query protocol, used by queries without arguments
+        let row: PgRow = self.conn.fetch_one("IDENTIFY_SYSTEM").await?;
+
+        Ok(PgIdentifySystem {
+            system_id: row.try_get("systemid")?,
+            timeline: row.try_get("timeline")?,
+            xlog_pos: row
+                .try_get::<&str, _>("xlogpos")?
+                .parse()
+                .map_err(Error::Decode)?,
+            database: row.try_get("dbname")?,
+        })
+    }
+
+    /// Start streaming changes from the logical replication slot `slot`, from `start_lsn` on.
+    ///
+    /// `options` are passed to the output plugin of the slot, e.g. `("proto_version", "1")`
+    /// for `pgoutput`. Postgres starts at the position the slot was last confirmed at if it is
+    /// after `start_lsn`, so `PgLsn(0)` resumes where the slot left off.
+    pub async fn start_replication(
+        &mut self,
+        slot: &str,
+        start_lsn: PgLsn,
+        options: &[(&str, &str)],
+    ) -> Result<(), Error> {
+        self.assert_not_streaming()?;
+
+        let mut statement = format!(
+            r#"START_REPLICATION SLOT "{}" LOGICAL {start_lsn}"#,
+            ident(slot)
+        );
+
+        if !options.is_empty() {
+            let options: Vec<_> = options
+                .iter()
+                .map(|(name, value)| {
+                    format!(r#""{}" '{}'"#, ident(name), value.replace('\'', "''"))
+                })
+                .collect();
+
+            statement.push_str(&format!(" ({})", options.join(", ")));
+        }
+
+        self.conn.wait_until_ready().await?;
+        self.conn.stream.send(Query(&statement)).await?;
+
+        if let Err(e) = self
+            .conn
+            .stream
+            .recv_expect::<CopyResponse>(MessageFormat::CopyBothResponse)
+            .await
+        {
+            self.conn
+                .stream
+                .recv_expect::<()>(MessageFormat::ReadyForQuery)
+                .await?;
+            return Err(e);
+        }
+
+        self.streaming = true;
+
+        Ok(())
+    }
+
+    /// Receive the next message of the replication stream.
+    ///
+    /// Returns `None` once the stream has ended, after which replication can be started again.
+    ///
+    /// Postgres sends a [`PgKeepalive`] asking for a reply when it has not received a standby
+    /// status update for a while, and closes the connection if none is sent in time;
+    /// see `wal_sender_timeout`.
+    pub async fn recv(&mut self) -> Result<Option<PgReplicationMessage>, Error> {
+        if !self.streaming {
+            return Ok(None);
+        }
+
+        let message = match self.conn.stream.recv().await {
+            Ok(message) => message,
+            Err(e) => {
+                self.streaming = false;
+
+                if let Error::Database(_) = e {
+                    self.conn
+                        .stream
+                        .recv_expect::<()>(MessageFormat::ReadyForQuery)
+                        .await?;
+                }
+
+                return Err(e);
+            }
+        };
+
+        match message.format {
+            MessageFormat::CopyData => {
+                let CopyData(data) = message.decode::<CopyData<Bytes>>()?;
+
+                decode_message(data).map(Some)
+            }
+
+            MessageFormat::CopyDone => {
+                // the server ended the stream, e.g. because it is shutting down
+                self.conn.stream.send(CopyDone).await?;
+                self.finish().await?;
+
+                Ok(None)
+            }
+
+            _ => Err(err_protocol!(
+                "unexpected message during replication: {:?}",
+                message.format
+            )),
+        }
+    }
+
+    /// A stream of the messages of [`recv()`][Self::recv], until the replication stream ends.
+    ///
+    /// The stream borrows the connection, so it has to be dropped to send
+    /// a standby status update.
+    pub fn messages(&mut self) -> impl Stream<Item = Result<PgReplicationMessage, Error>> + '_ {
+        futures_util::stream::try_unfold(self, |conn| async move {
+            Ok(conn.recv().await?.map(|message| (message, conn)))
+        })
+    }
+
+    /// Report the progress of this client to Postgres.
+    pub async fn send_standby_status(&mut self, status: PgStandbyStatus) -> Result<(), Error> {
+        if !self.streaming {
+            return Err(err_protocol!("replication has not been started"));
+        }
+
+        let mut buf = Vec::with_capacity(34);
+
+        buf.put_u8(b'r');
+        buf.put_u64(status.write_lsn.0);
+        buf.put_u64(status.flush_lsn.0);
+        buf.put_u64(status.apply_lsn.0);
+        buf.put_i64(to_postgres_time(SystemTime::now()));
+        buf.put_u8(status.reply_requested.into());
+
+        self.conn.stream.send(CopyData(buf)).await
+    }
+
+    /// Stop streaming changes, discarding any messages which have not been received yet.
+    pub async fn stop_replication(&mut self) -> Result<(), Error> {
+        if !self.streaming {
+            return Ok(());
+        }
+
+        self.conn.stream.send(CopyDone).await?;
+
+        loop {
+            let message = self.conn.stream.recv().await?;
+
+            match message.format {
+                MessageFormat::CopyData => {}
+                MessageFormat::CopyDone => break,
+                _ => {
+                    return Err(err_protocol!(
+                        "unexpected message stopping replication: {:?}",
+                        message.format
+                    ));
+                }
+            }
+        }
+
+        self.finish().await
+    }
+
+    /// Close the connection, stopping replication if it was started.
+    pub async fn close(mut self) -> Result<(), Error> {
+        self.stop_replication().await?;
+        self.conn.close().await
+    }
+
+    // The rest of the response to `START_REPLICATION`, after the end of the stream.
+    async fn finish(&mut self) -> Result<(), Error> {
+        self.streaming = false;
+
+        loop {
+            let message = self.conn.stream.recv().await?;
+
+            match message.format {
+                // the result set with the next timeline, sent after physical replication
+                MessageFormat::RowDescription
+                | MessageFormat::DataRow
+                | MessageFormat::CommandComplete => {}
+
+                MessageFormat::ReadyForQuery => return Ok(()),
+
+                _ => {
+                    return Err(err_protocol!(
+                        "unexpected message after replication: {:?}",
+                        message.format
+                    ));
+                }
+            }
+        }
+    }
+
+    fn assert_not_streaming(&self) -> Result<(), Error> {
+        if self.streaming {
+            return Err(err_protocol!(
+                "replication is in progress; call `stop_replication()` first"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn decode_message(mut buf: Bytes) -> Result<PgReplicationMessage, Error> {
+    if buf.is_empty() {
+        return Err(err_protocol!("empty replication message"));
+    }
+
+    match buf.get_u8() {
+        b'w' if buf.len() >= 24 => Ok(PgReplicationMessage::XLogData(PgXLogData {
+            start_lsn: PgLsn(buf.get_u64()),
+            end_lsn: PgLsn(buf.get_u64()),
+            server_time: from_postgres_time(buf.get_i64()),
+            data: buf,
+        })),
+
+        b'k' if buf.len() >= 17 => Ok(PgReplicationMessage::Keepalive(PgKeepalive {
+            end_lsn: PgLsn(buf.get_u64()),
+            server_time: from_postgres_time(buf.get_i64()),
+            reply_requested: buf.get_u8() != 0,
+        })),
+
+        tag => Err(err_protocol!(
+            "unknown or truncated replication message: {:?}",
+            tag as char
+        )),
+    }
+}
+
+// microseconds since 2000-01-01
+fn from_postgres_time(micros: i64) -> SystemTime {
+    let epoch = UNIX_EPOCH + Duration::from_secs(POSTGRES_EPOCH_SECS);
+
+    match u64::try_from(micros) {
+        Ok(micros) => epoch + Duration::from_micros(micros),
+        Err(_) => epoch - Duration::from_micros(micros.unsigned_abs()),
+    }
+}
+
+fn to_postgres_time(time: SystemTime) -> i64 {
+    let epoch = UNIX_EPOCH + Duration::from_secs(POSTGRES_EPOCH_SECS);
+
+    match time.duration_since(epoch) {
+        Ok(since) => since.as_micros() as i64,
+        Err(e) => -(e.duration().as_micros() as i64),
+    }
+}
+
+fn ident(name: &str) -> String {
+    name.replace('"', "\"\"")
+}
+
+#[test]
+fn it_formats_and_parses_lsns() {
+    let lsn = PgLsn(0x16_B374_D848);
+
+    assert_eq!(lsn.to_string(), "16/B374D848");
+    assert_eq!("16/B374D848".parse::<PgLsn>().unwrap(), lsn);
+    assert_eq!("0/0".parse::<PgLsn>().unwrap(), PgLsn(0));
+    assert!("16B374D848".parse::<PgLsn>().is_err());
+}
+
+#[test]
+fn it_decodes_replication_messages() {
+    let mut keepalive = vec![b'k'];
+    keepalive.put_u64(0x10);
+    keepalive.put_i64(1_000_000);
+    keepalive.put_u8(1);
+
+    let PgReplicationMessage::Keepalive(keepalive) = decode_message(keepalive.into()).unwrap()
+    else {
+        panic!("expected a keepalive");
+    };
+
+    assert_eq!(keepalive.end_lsn(), PgLsn(0x10));
+    assert_eq!(
+        keepalive.server_time(),
+        UNIX_EPOCH + Duration::from_secs(POSTGRES_EPOCH_SECS + 1)
+    );
+    assert!(keepalive.reply_requested());
+
+    let mut xlog_data = vec![b'w'];
+    xlog_data.put_u64(0x8);
+    xlog_data.put_u64(0x10);
+    xlog_data.put_i64(0);
+    xlog_data.extend_from_slice(b"BEGIN 1");
+
+    let PgReplicationMessage::XLogData(xlog_data) = decode_message(xlog_data.into()).unwrap()
+    else {
+        panic!("expected XLogData");
+    };
+
+    assert_eq!(xlog_data.start_lsn(), PgLsn(0x8));
+    assert_eq!(&xlog_data.data()[..], b"BEGIN 1");
+
+    assert!(decode_message(Bytes::from_static(b"k\0")).is_err());
+}
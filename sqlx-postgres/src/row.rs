@@ -15,6 +15,7 @@ pub struct PgRow {
     pub(crate) data: DataRow,
     pub(crate) format: PgValueFormat,
     pub(crate) metadata: Arc<PgStatementMetadata>,
+    pub(crate) time_zone_offset: Option<i32>,
 }
 
 impl Row for PgRow {
@@ -37,6 +38,7 @@ impl Row for PgRow {
             row: Some(&self.data.storage),
             type_info: column.type_info.clone(),
             value,
+            time_zone_offset: self.time_zone_offset,
         })
     }
 }
@@ -0,0 +1,25 @@
+use futures_core::future::BoxFuture;
+
+use sqlx_core::sequence::SequenceSupport;
+
+use crate::error::Error;
+use crate::query_scalar::query_scalar;
+use crate::{PgConnection, Postgres};
+
+impl SequenceSupport for Postgres {
+    fn next_sequence_values<'c>(
+        conn: &'c mut PgConnection,
+        name: &'c str,
+        n: u32,
+    ) -> BoxFuture<'c, Result<Vec<i64>, Error>> {
+        Box::pin(async move {
+            // Casting to `regclass` resolves the name like an identifier in SQL would,
+            // so it may be schema-qualified or quoted.
+            query_scalar("SELECT nextval($1::regclass) FROM generate_series(1, $2)")
+                .bind(name)
+                .bind(i64::from(n))
+                .fetch_all(conn)
+                .await
+        })
+    }
+}
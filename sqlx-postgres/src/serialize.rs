@@ -0,0 +1,131 @@
+use std::borrow::Cow;
+
+use sqlx_core::row::{ColumnValue, Row, SerializeColumns, SerializeRow};
+use sqlx_core::value::ValueRef;
+
+use crate::decode::Decode;
+use crate::error::BoxDynError;
+use crate::type_info::PgType;
+use crate::types::numeric::{format_numeric, PgNumeric};
+use crate::types::Type;
+use crate::{PgRow, PgTypeInfo, PgTypeKind, PgValueFormat, PgValueRef, Postgres};
+
+// 2000-01-01, the epoch of Postgres, in days and microseconds since 1970-01-01
+const POSTGRES_EPOCH_DAYS: i32 = 10_957;
+const POSTGRES_EPOCH_MICROS: i64 = 946_684_800_000_000;
+
+/// Columns of arrays, ranges, composite types, geometric types, `INTERVAL` and other types
+/// which have no JSON equivalent return an error; cast them in the query instead, e.g. to `TEXT`.
+impl SerializeColumns for PgRow {
+    fn column_value(&self, index: usize) -> Result<ColumnValue<'_>, BoxDynError> {
+        let value = self.try_get_raw(index)?;
+
+        if value.is_null() {
+            return Ok(ColumnValue::Null);
+        }
+
+        // domains are sent as their base type
+        let mut ty = &value.type_info;
+
+        while let PgTypeKind::Domain(base) = ty.kind() {
+            ty = base;
+        }
+
+        match value.format() {
+            PgValueFormat::Binary => binary_value(ty, value.clone()),
+            PgValueFormat::Text => text_value(ty, value.as_str()?),
+        }
+    }
+}
+
+impl serde::Serialize for PgRow {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializeRow::new(self).serialize(serializer)
+    }
+}
+
+fn binary_value<'r>(
+    ty: &PgTypeInfo,
+    value: PgValueRef<'r>,
+) -> Result<ColumnValue<'r>, BoxDynError> {
+    Ok(match &ty.0 {
+        PgType::Bool => ColumnValue::Bool(decode(value)?),
+        PgType::Int2 => ColumnValue::Int(decode::<i16>(value)?.into()),
+        PgType::Int4 => ColumnValue::Int(decode::<i32>(value)?.into()),
+        PgType::Int8 => ColumnValue::Int(decode::<i64>(value)?),
+        PgType::Oid => ColumnValue::Int(u32::from_be_bytes(value.as_bytes()?.try_into()?).into()),
+        PgType::Float4 => ColumnValue::Float(decode::<f32>(value)?.into()),
+        PgType::Float8 => ColumnValue::Float(decode::<f64>(value)?),
+
+        PgType::Numeric => ColumnValue::Decimal(Cow::Owned(format_numeric(&PgNumeric::decode(
+            value.as_bytes()?,
+        )?))),
+
+        PgType::Bytea => ColumnValue::Bytes(Cow::Borrowed(value.as_bytes()?)),
+        PgType::Json | PgType::Jsonb => ColumnValue::Json(decode(value)?),
+
+        PgType::Date => {
+            let days = decode::<i32>(value)?;
+
+            ColumnValue::Date(
+                days.checked_add(POSTGRES_EPOCH_DAYS)
+                    .ok_or_else(|| format!("date is out of range: {days} days"))?,
+            )
+        }
+
+        PgType::Time => ColumnValue::Time(decode::<i64>(value)?),
+
+        PgType::Timestamp | PgType::Timestamptz => {
+            let micros = decode::<i64>(value)?;
+
+            ColumnValue::Timestamp {
+                micros: micros
+                    .checked_add(POSTGRES_EPOCH_MICROS)
+                    .ok_or_else(|| format!("timestamp is out of range: {micros} microseconds"))?,
+                utc: matches!(ty.0, PgType::Timestamptz),
+            }
+        }
+
+        PgType::Uuid => ColumnValue::uuid(value.as_bytes()?.try_into()?),
+
+        // enums are sent as text, as are types like `CITEXT`
+        _ if matches!(ty.kind(), PgTypeKind::Enum(_))
+            || <&str as Type<Postgres>>::compatible(ty) =>
+        {
+            ColumnValue::Text(Cow::Borrowed(value.as_str()?))
+        }
+
+        _ => return Err(unsupported(ty)),
+    })
+}
+
+// values of simple queries
+fn text_value<'r>(ty: &PgTypeInfo, s: &'r str) -> Result<ColumnValue<'r>, BoxDynError> {
+    Ok(match &ty.0 {
+        PgType::Bool => ColumnValue::Bool(s == "t"),
+        PgType::Int2 | PgType::Int4 | PgType::Int8 | PgType::Oid => ColumnValue::Int(s.parse()?),
+        PgType::Float4 | PgType::Float8 => ColumnValue::Float(s.parse()?),
+        PgType::Numeric => ColumnValue::Decimal(Cow::Borrowed(s)),
+        PgType::Json | PgType::Jsonb => ColumnValue::Json(serde_json::from_str(s)?),
+
+        PgType::Bytea => match s.strip_prefix("\\x") {
+            Some(hex) => ColumnValue::Bytes(Cow::Owned(hex::decode(hex)?)),
+            None => return Err(format!("expected BYTEA in hex format, got {s:?}").into()),
+        },
+
+        // everything else is passed on as Postgres formats it
+        _ => ColumnValue::Text(Cow::Borrowed(s)),
+    })
+}
+
+fn decode<'r, T: Decode<'r, Postgres>>(value: PgValueRef<'r>) -> Result<T, BoxDynError> {
+    T::decode(value)
+}
+
+fn unsupported(ty: &PgTypeInfo) -> BoxDynError {
+    format!(
+        "cannot serialize a value of type {}; cast it in the query, e.g. to TEXT",
+        ty.0.display_name()
+    )
+    .into()
+}
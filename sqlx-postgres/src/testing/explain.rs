@@ -0,0 +1,69 @@
+use futures_core::future::BoxFuture;
+
+use crate::error::Error;
+use crate::query_scalar::query_scalar;
+use crate::PgConnection;
+
+use super::plan::{ExplainPlan, QueryPlan};
+
+impl ExplainPlan for PgConnection {
+    fn explain_plan<'e>(&'e mut self, query: &'e str) -> BoxFuture<'e, Result<QueryPlan, Error>> {
+        Box::pin(async move {
+            let explain = format!("EXPLAIN (ANALYZE, COSTS OFF, TIMING OFF, SUMMARY OFF) {query}");
+
+            let lines: Vec<String> = query_scalar(&explain).fetch_all(self).await?;
+
+            Ok(QueryPlan::new(
+                lines.iter().filter_map(|line| normalize(line)).collect(),
+            ))
+        })
+    }
+}
+
+/// Details of the plan which depend on the data or statistics rather than on the plan itself.
+const VOLATILE_DETAILS: &[&str] = &[
+    "Rows Removed by",
+    "Heap Fetches:",
+    "Heap Blocks:",
+    "Buckets:",
+    "Sort Method:",
+    "Memory Usage:",
+    "Batches:",
+    "Worker ",
+];
+
+fn normalize(line: &str) -> Option<String> {
+    let detail = line.trim_start();
+
+    if VOLATILE_DETAILS.iter().any(|v| detail.starts_with(v)) {
+        return None;
+    }
+
+    // e.g. `Seq Scan on users (actual rows=1 loops=1)` or `(never executed)`
+    let line = match line
+        .find(" (actual ")
+        .or_else(|| line.find(" (never executed)"))
+    {
+        Some(start) => &line[..start],
+        None => line,
+    };
+
+    Some(line.trim_end().to_owned())
+}
+
+#[test]
+fn test_normalize() {
+    assert_eq!(
+        normalize("Seq Scan on users (actual rows=3 loops=1)").as_deref(),
+        Some("Seq Scan on users")
+    );
+    assert_eq!(
+        normalize("  ->  Index Scan using users_pkey on users (never executed)").as_deref(),
+        Some("  ->  Index Scan using users_pkey on users")
+    );
+    assert_eq!(
+        normalize("        Index Cond: (id = 1)").as_deref(),
+        Some("        Index Cond: (id = 1)")
+    );
+    assert_eq!(normalize("  Rows Removed by Filter: 42"), None);
+}
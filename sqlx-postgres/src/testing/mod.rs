@@ -2,11 +2,12 @@ use std::fmt::Write;
 use std::ops::Deref;
 use std::str::FromStr;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
 use std::time::{Duration, SystemTime};
 
 use futures_core::future::BoxFuture;
 
-use once_cell::sync::OnceCell;
+use once_cell::sync::{Lazy, OnceCell};
 
 use crate::connection::Connection;
 
@@ -15,14 +16,23 @@ use crate::executor::Executor;
 use crate::pool::{Pool, PoolOptions};
 use crate::query::query;
 use crate::query_scalar::query_scalar;
-use crate::{PgConnectOptions, PgConnection, Postgres};
+use crate::{HashMap, PgConnectOptions, PgConnection, Postgres};
+use sqlx_core::migrate::Migrator;
+use sqlx_core::sync::AsyncSemaphore;
 
 pub(crate) use sqlx_core::testing::*;
 
+mod explain;
+
 // Using a blocking `OnceCell` here because the critical sections are short.
 static MASTER_POOL: OnceCell<Pool<Postgres>> = OnceCell::new();
 // Automatically delete any databases created before the start of the test binary.
 static DO_CLEANUP: AtomicBool = AtomicBool::new(true);
+// Databases that are migrated once per run of the test binary, and copied for every test with
+// the same migrations, by the versions and checksums of the migrations.
+static TEMPLATES: Lazy<Mutex<HashMap<String, String>>> = Lazy::new(Default::default);
+// Held while a template is created, so each is only created once.
+static TEMPLATES_LOCK: Lazy<AsyncSemaphore> = Lazy::new(|| AsyncSemaphore::new(true, 1));
 
 impl TestSupport for Postgres {
     fn test_context(args: &TestArgs) -> BoxFuture<'_, Result<TestContext<Self>, Error>> {
@@ -158,8 +168,20 @@ async fn test_context(args: &TestArgs) -> Result<TestContext<Postgres>, Error> {
     .fetch_one(&mut *conn)
     .await?;
 
-    conn.execute(&format!("create database {new_db_name:?}")[..])
-        .await?;
+    // Copying a migrated template is much faster than migrating every test database,
+    // and `setup_test_db()` finds the migrations already applied.
+    match args.migrator {
+        Some(migrator) => {
+            let template = template_db(&mut conn, master_pool, migrator, args.test_path).await?;
+
+            conn.execute(&format!("create database {new_db_name:?} template {template:?}")[..])
+                .await?;
+        }
+        None => {
+            conn.execute(&format!("create database {new_db_name:?}")[..])
+                .await?;
+        }
+    }
 
     Ok(TestContext {
         pool_opts: PoolOptions::new()
@@ -179,6 +201,66 @@ async fn test_context(args: &TestArgs) -> Result<TestContext<Postgres>, Error> {
     })
 }
 
+// Get the template database with the migrations of `migrator` applied, creating it if this is
+// the first test in this run with these migrations.
+async fn template_db(
+    conn: &mut PgConnection,
+    master_pool: &Pool<Postgres>,
+    migrator: &Migrator,
+    test_path: &str,
+) -> Result<String, Error> {
+    let mut key = String::new();
+
+    for migration in migrator.iter() {
+        write!(key, "{}:", migration.version).ok();
+
+        for byte in migration.checksum.iter() {
+            write!(key, "{byte:02x}").ok();
+        }
+
+        key.push(';');
+    }
+
+    let _permit = TEMPLATES_LOCK.acquire(1).await;
+
+    if let Some(template) = TEMPLATES.lock().unwrap().get(&key) {
+        return Ok(template.clone());
+    }
+
+    // Registered like the test databases, so it is deleted the next time the tests are run.
+    let template: String = query_scalar(
+        r#"
+            insert into _sqlx_test.databases(db_name, test_path)
+            select '_sqlx_test_template_' || nextval('_sqlx_test.database_ids'), $1
+            returning db_name
+        "#,
+    )
+    .bind(test_path)
+    .fetch_one(&mut *conn)
+    .await?;
+
+    conn.execute(&format!("create database {template:?}")[..])
+        .await?;
+
+    // A database can only be copied while no one is connected to it.
+    let mut template_conn = PgConnection::connect_with(
+        &master_pool
+            .connect_options()
+            .deref()
+            .clone()
+            .database(&template),
+    )
+    .await?;
+
+    migrator.run_direct(&mut template_conn).await?;
+
+    template_conn.close().await?;
+
+    TEMPLATES.lock().unwrap().insert(key, template.clone());
+
+    Ok(template)
+}
+
 async fn do_cleanup(conn: &mut PgConnection, created_before: Duration) -> Result<usize, Error> {
     // since SystemTime is not monotonic we added a little margin here to avoid race conditions with other threads
     let created_before = i64::try_from(created_before.as_secs()).unwrap() - 2;
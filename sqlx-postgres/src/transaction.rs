@@ -26,6 +26,27 @@ impl TransactionManager for PgTransactionManager {
         })
     }
 
+    fn begin_with(
+        conn: &mut PgConnection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            if conn.transaction_depth > 0 {
+                return Err(nested_transaction_options_error());
+            }
+
+            let rollback = Rollback::new(conn);
+            rollback
+                .conn
+                .queue_simple_query(&begin_transaction_with_options_sql(&options));
+            rollback.conn.transaction_depth += 1;
+            rollback.conn.wait_until_ready().await?;
+            rollback.defuse();
+
+            Ok(())
+        })
+    }
+
     fn commit(conn: &mut PgConnection) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
             if conn.transaction_depth > 0 {
@@ -30,6 +30,8 @@ impl_type_checking!(
 
         sqlx::postgres::types::PgLQuery,
 
+        sqlx::postgres::types::PgHstore,
+
         #[cfg(feature = "uuid")]
         sqlx::types::Uuid,
 
@@ -94,6 +96,9 @@ impl_type_checking!(
         Vec<f64> | &[f64],
         Vec<sqlx::postgres::types::Oid> | &[sqlx::postgres::types::Oid],
         Vec<sqlx::postgres::types::PgMoney> | &[sqlx::postgres::types::PgMoney],
+        Vec<sqlx::postgres::types::PgLTree> | &[sqlx::postgres::types::PgLTree],
+        Vec<sqlx::postgres::types::PgCiText> | &[sqlx::postgres::types::PgCiText],
+        Vec<sqlx::postgres::types::PgHstore> | &[sqlx::postgres::types::PgHstore],
 
         #[cfg(feature = "uuid")]
         Vec<sqlx::types::Uuid> | &[sqlx::types::Uuid],
@@ -207,6 +212,73 @@ impl_type_checking!(
         #[cfg(feature = "time")]
         Vec<sqlx::postgres::types::PgRange<sqlx::types::time::OffsetDateTime>> |
             &[sqlx::postgres::types::PgRange<sqlx::types::time::OffsetDateTime>],
+
+        // Multiranges
+
+        sqlx::postgres::types::PgMultiRange<i32>,
+        sqlx::postgres::types::PgMultiRange<i64>,
+
+        #[cfg(feature = "bigdecimal")]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::BigDecimal>,
+
+        #[cfg(feature = "rust_decimal")]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::Decimal>,
+
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::chrono::NaiveDate>,
+
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::chrono::NaiveDateTime>,
+
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>> |
+            sqlx::postgres::types::PgMultiRange<sqlx::types::chrono::DateTime<_>>,
+
+        #[cfg(feature = "time")]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::time::Date>,
+
+        #[cfg(feature = "time")]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::time::PrimitiveDateTime>,
+
+        #[cfg(feature = "time")]
+        sqlx::postgres::types::PgMultiRange<sqlx::types::time::OffsetDateTime>,
+
+        // Multirange arrays
+
+        Vec<sqlx::postgres::types::PgMultiRange<i32>> | &[sqlx::postgres::types::PgMultiRange<i32>],
+        Vec<sqlx::postgres::types::PgMultiRange<i64>> | &[sqlx::postgres::types::PgMultiRange<i64>],
+
+        #[cfg(feature = "bigdecimal")]
+        Vec<sqlx::postgres::types::PgMultiRange<sqlx::types::BigDecimal>> |
+            &[sqlx::postgres::types::PgMultiRange<sqlx::types::BigDecimal>],
+
+        #[cfg(feature = "rust_decimal")]
+        Vec<sqlx::postgres::types::PgMultiRange<sqlx::types::Decimal>> |
+            &[sqlx::postgres::types::PgMultiRange<sqlx::types::Decimal>],
+
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        Vec<sqlx::postgres::types::PgMultiRange<sqlx::types::chrono::NaiveDate>> |
+            &[sqlx::postgres::types::PgMultiRange<sqlx::types::chrono::NaiveDate>],
+
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        Vec<sqlx::postgres::types::PgMultiRange<sqlx::types::chrono::NaiveDateTime>> |
+            &[sqlx::postgres::types::PgMultiRange<sqlx::types::chrono::NaiveDateTime>],
+
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        Vec<sqlx::postgres::types::PgMultiRange<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>>> |
+            &[sqlx::postgres::types::PgMultiRange<sqlx::types::chrono::DateTime<_>>],
+
+        #[cfg(feature = "time")]
+        Vec<sqlx::postgres::types::PgMultiRange<sqlx::types::time::Date>> |
+            &[sqlx::postgres::types::PgMultiRange<sqlx::types::time::Date>],
+
+        #[cfg(feature = "time")]
+        Vec<sqlx::postgres::types::PgMultiRange<sqlx::types::time::PrimitiveDateTime>> |
+            &[sqlx::postgres::types::PgMultiRange<sqlx::types::time::PrimitiveDateTime>],
+
+        #[cfg(feature = "time")]
+        Vec<sqlx::postgres::types::PgMultiRange<sqlx::types::time::OffsetDateTime>> |
+            &[sqlx::postgres::types::PgMultiRange<sqlx::types::time::OffsetDateTime>],
     },
     ParamChecking::Strong,
     feature-types: info => info.__type_feature_gate(),
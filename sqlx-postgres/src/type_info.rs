@@ -114,6 +114,18 @@ pub enum PgType {
     DateRangeArray,
     Int8Range,
     Int8RangeArray,
+    Int4Multirange,
+    Int4MultirangeArray,
+    NumMultirange,
+    NumMultirangeArray,
+    TsMultirange,
+    TsMultirangeArray,
+    TstzMultirange,
+    TstzMultirangeArray,
+    DateMultirange,
+    DateMultirangeArray,
+    Int8Multirange,
+    Int8MultirangeArray,
     Jsonpath,
     JsonpathArray,
     Money,
@@ -153,6 +165,7 @@ pub enum PgTypeKind {
     Array(PgTypeInfo),
     Enum(Arc<[String]>),
     Range(PgTypeInfo),
+    Multirange(PgTypeInfo),
 }
 
 impl PgTypeInfo {
@@ -347,6 +360,18 @@ impl PgType {
             3913 => PgType::DateRangeArray,
             3926 => PgType::Int8Range,
             3927 => PgType::Int8RangeArray,
+            4451 => PgType::Int4Multirange,
+            6150 => PgType::Int4MultirangeArray,
+            4532 => PgType::NumMultirange,
+            6151 => PgType::NumMultirangeArray,
+            4533 => PgType::TsMultirange,
+            6152 => PgType::TsMultirangeArray,
+            4534 => PgType::TstzMultirange,
+            6153 => PgType::TstzMultirangeArray,
+            4535 => PgType::DateMultirange,
+            6155 => PgType::DateMultirangeArray,
+            4536 => PgType::Int8Multirange,
+            6157 => PgType::Int8MultirangeArray,
             4072 => PgType::Jsonpath,
             4073 => PgType::JsonpathArray,
 
@@ -455,6 +480,18 @@ impl PgType {
             PgType::DateRangeArray => Oid(3913),
             PgType::Int8Range => Oid(3926),
             PgType::Int8RangeArray => Oid(3927),
+            PgType::Int4Multirange => Oid(4451),
+            PgType::Int4MultirangeArray => Oid(6150),
+            PgType::NumMultirange => Oid(4532),
+            PgType::NumMultirangeArray => Oid(6151),
+            PgType::TsMultirange => Oid(4533),
+            PgType::TsMultirangeArray => Oid(6152),
+            PgType::TstzMultirange => Oid(4534),
+            PgType::TstzMultirangeArray => Oid(6153),
+            PgType::DateMultirange => Oid(4535),
+            PgType::DateMultirangeArray => Oid(6155),
+            PgType::Int8Multirange => Oid(4536),
+            PgType::Int8MultirangeArray => Oid(6157),
             PgType::Jsonpath => Oid(4072),
             PgType::JsonpathArray => Oid(4073),
 
@@ -556,6 +593,18 @@ impl PgType {
             PgType::DateRangeArray => "DATERANGE[]",
             PgType::Int8Range => "INT8RANGE",
             PgType::Int8RangeArray => "INT8RANGE[]",
+            PgType::Int4Multirange => "INT4MULTIRANGE",
+            PgType::Int4MultirangeArray => "INT4MULTIRANGE[]",
+            PgType::NumMultirange => "NUMMULTIRANGE",
+            PgType::NumMultirangeArray => "NUMMULTIRANGE[]",
+            PgType::TsMultirange => "TSMULTIRANGE",
+            PgType::TsMultirangeArray => "TSMULTIRANGE[]",
+            PgType::TstzMultirange => "TSTZMULTIRANGE",
+            PgType::TstzMultirangeArray => "TSTZMULTIRANGE[]",
+            PgType::DateMultirange => "DATEMULTIRANGE",
+            PgType::DateMultirangeArray => "DATEMULTIRANGE[]",
+            PgType::Int8Multirange => "INT8MULTIRANGE",
+            PgType::Int8MultirangeArray => "INT8MULTIRANGE[]",
             PgType::Jsonpath => "JSONPATH",
             PgType::JsonpathArray => "JSONPATH[]",
             PgType::Money => "MONEY",
@@ -656,6 +705,18 @@ impl PgType {
             PgType::DateRangeArray => "_daterange",
             PgType::Int8Range => "int8range",
             PgType::Int8RangeArray => "_int8range",
+            PgType::Int4Multirange => "int4multirange",
+            PgType::Int4MultirangeArray => "_int4multirange",
+            PgType::NumMultirange => "nummultirange",
+            PgType::NumMultirangeArray => "_nummultirange",
+            PgType::TsMultirange => "tsmultirange",
+            PgType::TsMultirangeArray => "_tsmultirange",
+            PgType::TstzMultirange => "tstzmultirange",
+            PgType::TstzMultirangeArray => "_tstzmultirange",
+            PgType::DateMultirange => "datemultirange",
+            PgType::DateMultirangeArray => "_datemultirange",
+            PgType::Int8Multirange => "int8multirange",
+            PgType::Int8MultirangeArray => "_int8multirange",
             PgType::Jsonpath => "jsonpath",
             PgType::JsonpathArray => "_jsonpath",
             PgType::Money => "money",
@@ -756,6 +817,18 @@ impl PgType {
             PgType::DateRangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::DateRange)),
             PgType::Int8Range => &PgTypeKind::Range(PgTypeInfo::INT8),
             PgType::Int8RangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::Int8Range)),
+            PgType::Int4Multirange => &PgTypeKind::Multirange(PgTypeInfo(PgType::Int4Range)),
+            PgType::Int4MultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::Int4Multirange)),
+            PgType::NumMultirange => &PgTypeKind::Multirange(PgTypeInfo(PgType::NumRange)),
+            PgType::NumMultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::NumMultirange)),
+            PgType::TsMultirange => &PgTypeKind::Multirange(PgTypeInfo(PgType::TsRange)),
+            PgType::TsMultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::TsMultirange)),
+            PgType::TstzMultirange => &PgTypeKind::Multirange(PgTypeInfo(PgType::TstzRange)),
+            PgType::TstzMultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::TstzMultirange)),
+            PgType::DateMultirange => &PgTypeKind::Multirange(PgTypeInfo(PgType::DateRange)),
+            PgType::DateMultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::DateMultirange)),
+            PgType::Int8Multirange => &PgTypeKind::Multirange(PgTypeInfo(PgType::Int8Range)),
+            PgType::Int8MultirangeArray => &PgTypeKind::Array(PgTypeInfo(PgType::Int8Multirange)),
             PgType::Jsonpath => &PgTypeKind::Simple,
             PgType::JsonpathArray => &PgTypeKind::Array(PgTypeInfo(PgType::Jsonpath)),
             PgType::Money => &PgTypeKind::Simple,
@@ -869,6 +942,18 @@ impl PgType {
             PgType::DateRangeArray => Some(Cow::Owned(PgTypeInfo(PgType::DateRange))),
             PgType::Int8Range => None,
             PgType::Int8RangeArray => Some(Cow::Owned(PgTypeInfo(PgType::Int8Range))),
+            PgType::Int4Multirange => None,
+            PgType::Int4MultirangeArray => Some(Cow::Owned(PgTypeInfo(PgType::Int4Multirange))),
+            PgType::NumMultirange => None,
+            PgType::NumMultirangeArray => Some(Cow::Owned(PgTypeInfo(PgType::NumMultirange))),
+            PgType::TsMultirange => None,
+            PgType::TsMultirangeArray => Some(Cow::Owned(PgTypeInfo(PgType::TsMultirange))),
+            PgType::TstzMultirange => None,
+            PgType::TstzMultirangeArray => Some(Cow::Owned(PgTypeInfo(PgType::TstzMultirange))),
+            PgType::DateMultirange => None,
+            PgType::DateMultirangeArray => Some(Cow::Owned(PgTypeInfo(PgType::DateMultirange))),
+            PgType::Int8Multirange => None,
+            PgType::Int8MultirangeArray => Some(Cow::Owned(PgTypeInfo(PgType::Int8Multirange))),
             PgType::Jsonpath => None,
             PgType::JsonpathArray => Some(Cow::Owned(PgTypeInfo(PgType::Jsonpath))),
             // There is no `UnknownArray`
@@ -884,6 +969,7 @@ impl PgType {
                 PgTypeKind::Array(ref elem_type_info) => Some(Cow::Borrowed(elem_type_info)),
                 PgTypeKind::Enum(_) => None,
                 PgTypeKind::Range(_) => None,
+                PgTypeKind::Multirange(_) => None,
             },
             PgType::DeclareWithOid(oid) => {
                 unreachable!("(bug) use of unresolved type declaration [oid={}]", oid.0);
@@ -1124,6 +1210,29 @@ impl PgTypeInfo {
     pub(crate) const INT8_RANGE: Self = Self(PgType::Int8Range);
     pub(crate) const INT8_RANGE_ARRAY: Self = Self(PgType::Int8RangeArray);
 
+    //
+    // multirange types
+    // https://www.postgresql.org/docs/current/rangetypes.html
+    //
+
+    pub(crate) const INT4_MULTIRANGE: Self = Self(PgType::Int4Multirange);
+    pub(crate) const INT4_MULTIRANGE_ARRAY: Self = Self(PgType::Int4MultirangeArray);
+
+    pub(crate) const NUM_MULTIRANGE: Self = Self(PgType::NumMultirange);
+    pub(crate) const NUM_MULTIRANGE_ARRAY: Self = Self(PgType::NumMultirangeArray);
+
+    pub(crate) const TS_MULTIRANGE: Self = Self(PgType::TsMultirange);
+    pub(crate) const TS_MULTIRANGE_ARRAY: Self = Self(PgType::TsMultirangeArray);
+
+    pub(crate) const TSTZ_MULTIRANGE: Self = Self(PgType::TstzMultirange);
+    pub(crate) const TSTZ_MULTIRANGE_ARRAY: Self = Self(PgType::TstzMultirangeArray);
+
+    pub(crate) const DATE_MULTIRANGE: Self = Self(PgType::DateMultirange);
+    pub(crate) const DATE_MULTIRANGE_ARRAY: Self = Self(PgType::DateMultirangeArray);
+
+    pub(crate) const INT8_MULTIRANGE: Self = Self(PgType::Int8Multirange);
+    pub(crate) const INT8_MULTIRANGE_ARRAY: Self = Self(PgType::Int8MultirangeArray);
+
     //
     // pseudo types
     // https://www.postgresql.org/docs/9.3/datatype-pseudo.html
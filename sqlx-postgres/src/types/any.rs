@@ -0,0 +1,289 @@
+//! The representations of the `Any` driver for `NUMERIC`, `DATE`, `TIME`, `TIMESTAMP(TZ)`
+//! and `UUID`.
+
+use std::cmp;
+
+use sqlx_core::any::{AnyDate, AnyDecimal, AnyTime, AnyTimestamp, AnyUuid};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::numeric::{format_numeric, PgNumeric, PgNumericSign};
+use crate::types::Type;
+use crate::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+// 2000-01-01, the epoch of Postgres, in days and microseconds since 1970-01-01
+const POSTGRES_EPOCH_DAYS: i32 = 10_957;
+const POSTGRES_EPOCH_MICROS: i64 = 946_684_800_000_000;
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+impl Type<Postgres> for AnyDecimal {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUMERIC
+    }
+}
+
+impl Encode<'_, Postgres> for AnyDecimal {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        parse_numeric(&self.0)?.encode(buf);
+
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, Postgres> for AnyDecimal {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(AnyDecimal(match value.format() {
+            PgValueFormat::Binary => format_numeric(&PgNumeric::decode(value.as_bytes()?)?),
+            PgValueFormat::Text => value.as_str()?.to_owned(),
+        }))
+    }
+}
+
+impl Type<Postgres> for AnyDate {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE
+    }
+}
+
+impl Encode<'_, Postgres> for AnyDate {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let days = self
+            .0
+            .checked_sub(POSTGRES_EPOCH_DAYS)
+            .ok_or("date is out of range for Postgres")?;
+
+        Encode::<Postgres>::encode(days, buf)
+    }
+}
+
+impl Decode<'_, Postgres> for AnyDate {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let days: i32 = Decode::<Postgres>::decode(value)?;
+
+                days.checked_add(POSTGRES_EPOCH_DAYS)
+                    .map(AnyDate)
+                    .ok_or_else(|| format!("date is out of range: {days} days").into())
+            }
+            PgValueFormat::Text => value.as_str()?.parse(),
+        }
+    }
+}
+
+impl Type<Postgres> for AnyTime {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TIME
+    }
+}
+
+impl Encode<'_, Postgres> for AnyTime {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        // TIME is encoded as the microseconds since midnight
+        Encode::<Postgres>::encode(self.0, buf)
+    }
+}
+
+impl Decode<'_, Postgres> for AnyTime {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let micros: i64 = Decode::<Postgres>::decode(value)?;
+
+                // `24:00:00` is decoded as midnight, as with chrono and time
+                Ok(AnyTime(micros % MICROS_PER_DAY))
+            }
+            PgValueFormat::Text => value.as_str()?.parse(),
+        }
+    }
+}
+
+impl Type<Postgres> for AnyTimestamp {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TIMESTAMP
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::TIMESTAMP || *ty == PgTypeInfo::TIMESTAMPTZ
+    }
+}
+
+impl Encode<'_, Postgres> for AnyTimestamp {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let micros = self
+            .0
+            .checked_sub(POSTGRES_EPOCH_MICROS)
+            .ok_or("timestamp is out of range for Postgres")?;
+
+        Encode::<Postgres>::encode(micros, buf)
+    }
+}
+
+impl Decode<'_, Postgres> for AnyTimestamp {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                // TIMESTAMP and TIMESTAMPTZ are both encoded as the microseconds since the epoch,
+                // the latter in UTC
+                let micros: i64 = Decode::<Postgres>::decode(value)?;
+
+                micros
+                    .checked_add(POSTGRES_EPOCH_MICROS)
+                    .map(AnyTimestamp)
+                    .ok_or_else(|| {
+                        format!("timestamp is out of range: {micros} microseconds").into()
+                    })
+            }
+            // TIMESTAMPTZ is formatted with the offset of the session, which the parser applies
+            PgValueFormat::Text => value.as_str()?.parse(),
+        }
+    }
+}
+
+impl Type<Postgres> for AnyUuid {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::UUID
+    }
+}
+
+impl Encode<'_, Postgres> for AnyUuid {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.extend_from_slice(&self.0);
+
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, Postgres> for AnyUuid {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = match value.format() {
+            PgValueFormat::Binary => value.as_bytes()?.to_vec(),
+            PgValueFormat::Text => hex::decode(value.as_str()?.replace('-', ""))?,
+        };
+
+        bytes
+            .try_into()
+            .map(AnyUuid)
+            .map_err(|bytes| format!("expected 16 bytes for a UUID, got {}", bytes.len()).into())
+    }
+}
+
+/// Parse a decimal number, e.g. `-12.50` or `1.5e-3`, into base-10000 digits.
+fn parse_numeric(s: &str) -> Result<PgNumeric, BoxDynError> {
+    let invalid = || format!("invalid decimal number: {s:?}");
+
+    if s.eq_ignore_ascii_case("NaN") {
+        return Ok(PgNumeric::NotANumber);
+    }
+
+    let (sign, unsigned) = match s.as_bytes().first() {
+        Some(b'-') => (PgNumericSign::Negative, &s[1..]),
+        Some(b'+') => (PgNumericSign::Positive, &s[1..]),
+        _ => (PgNumericSign::Positive, s),
+    };
+
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().map_err(|_| invalid())?),
+        None => (unsigned, 0),
+    };
+
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    if integer.is_empty() && fraction.is_empty()
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid().into());
+    }
+
+    let scale = i16::try_from(cmp::max(fraction.len() as i64 - i64::from(exponent), 0))
+        .map_err(|_| invalid())?;
+
+    // the decimal digits without leading zeros, and the position of the decimal point in them
+    let all_digits = format!("{integer}{fraction}");
+    let significant = all_digits.trim_start_matches('0');
+    let point =
+        integer.len() as i64 + i64::from(exponent) - (all_digits.len() - significant.len()) as i64;
+
+    if significant.is_empty() {
+        return Ok(PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            digits: Vec::new(),
+            weight: 0,
+            scale,
+        });
+    }
+
+    // align the digits to groups of 4 around the decimal point
+    let pad_start = (4 - point.rem_euclid(4)) % 4;
+    let weight = i16::try_from((point + pad_start) / 4 - 1).map_err(|_| invalid())?;
+
+    let pad_end = (4 - (pad_start as usize + significant.len()) % 4) % 4;
+    let padded = format!(
+        "{}{significant}{}",
+        "0".repeat(pad_start as usize),
+        "0".repeat(pad_end)
+    );
+
+    let mut digits: Vec<i16> = padded
+        .as_bytes()
+        .chunks(4)
+        .map(|group| {
+            group
+                .iter()
+                .fold(0, |digit, &b| digit * 10 + i16::from(b - b'0'))
+        })
+        .collect();
+
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    if i16::try_from(digits.len()).is_err() {
+        return Err(invalid().into());
+    }
+
+    Ok(PgNumeric::Number {
+        sign,
+        digits,
+        weight,
+        scale,
+    })
+}
+
+#[test]
+fn it_converts_decimal_strings_to_and_from_numeric() {
+    for (s, digits, weight, scale) in [
+        ("0", vec![], 0, 0),
+        ("12.5", vec![12, 5000], 0, 1),
+        ("0.001", vec![10], -1, 3),
+        ("-10000.00", vec![1], 1, 2),
+        ("123456789.0123", vec![1, 2345, 6789, 123], 2, 4),
+    ] {
+        let sign = if s.starts_with('-') {
+            PgNumericSign::Negative
+        } else {
+            PgNumericSign::Positive
+        };
+
+        let numeric = PgNumeric::Number {
+            sign,
+            digits,
+            weight,
+            scale,
+        };
+
+        assert_eq!(parse_numeric(s).unwrap(), numeric, "{s}");
+        assert_eq!(format_numeric(&numeric), s);
+    }
+
+    assert_eq!(format_numeric(&parse_numeric("1.5e-3").unwrap()), "0.0015");
+    assert_eq!(format_numeric(&parse_numeric("25E2").unwrap()), "2500");
+    assert_eq!(parse_numeric("NaN").unwrap(), PgNumeric::NotANumber);
+    assert!(parse_numeric("1.2.3").is_err());
+    assert!(parse_numeric("").is_err());
+}
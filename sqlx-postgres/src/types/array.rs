@@ -204,6 +204,7 @@ where
 {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
         let format = value.format();
+        let time_zone_offset = value.time_zone_offset;
 
         match format {
             PgValueFormat::Binary => {
@@ -256,6 +257,7 @@ where
                         &mut buf,
                         format,
                         element_type_info.clone(),
+                        time_zone_offset,
                     ))?)
                 }
 
@@ -336,6 +338,7 @@ where
                         row: None,
                         type_info: element_type_info.clone(),
                         format,
+                        time_zone_offset,
                     })?);
 
                     value.clear();
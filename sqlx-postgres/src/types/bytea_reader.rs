@@ -0,0 +1,220 @@
+use std::cmp;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::stream::{BoxStream, Stream};
+use futures_io::{AsyncBufRead, AsyncRead};
+use sqlx_core::bytes::{Buf, Bytes};
+
+use crate::decode::Decode;
+use crate::error::{BoxDynError, Error};
+use crate::query::Query;
+use crate::row::Row;
+use crate::types::bytes::text_decode;
+use crate::types::Type;
+use crate::{PgArguments, PgConnection, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+/// A `BYTEA` or `TEXT` value which is read incrementally instead of being copied out of the row.
+///
+/// Decoding shares the buffer of the row rather than copying the value
+/// (except for `BYTEA` in a simple query, which is sent hex-encoded and must be decoded first),
+/// so the value can be passed on in chunks, e.g. as the body of an HTTP response.
+///
+/// This implements [`AsyncRead`] and [`AsyncBufRead`] from `futures-io`,
+/// and can be turned into a [`Stream`] of chunks with [`into_chunks()`][Self::into_chunks].
+/// To use it with Tokio I/O, wrap the stream in `tokio_util::io::StreamReader`.
+///
+/// The whole row is still received before it can be decoded. For values too large to hold
+/// in memory at all, fetch them piece by piece with [`PgConnection::fetch_bytea_chunks()`].
+///
+/// ```rust,no_run
+/// # async fn example(mut conn: sqlx::PgConnection) -> sqlx::Result<()> {
+/// use futures_util::io::AsyncReadExt;
+/// use sqlx::postgres::types::PgByteaReader;
+///
+/// let mut reader: PgByteaReader = sqlx::query_scalar("SELECT data FROM blobs WHERE id = 1")
+///     .fetch_one(&mut conn)
+///     .await?;
+///
+/// let mut header = [0u8; 4];
+/// reader.read_exact(&mut header).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PgByteaReader {
+    data: Bytes,
+}
+
+impl PgByteaReader {
+    /// The number of bytes which have not been read yet.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if all bytes have been read.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Take the bytes which have not been read yet.
+    pub fn into_bytes(self) -> Bytes {
+        self.data
+    }
+
+    /// Split the bytes which have not been read yet into chunks of at most `chunk_size` bytes.
+    ///
+    /// The chunks share the buffer of the row and so are not copied.
+    ///
+    /// # Panics
+    /// If `chunk_size` is zero.
+    pub fn into_chunks(
+        mut self,
+        chunk_size: usize,
+    ) -> impl Stream<Item = io::Result<Bytes>> + Send + Unpin + 'static {
+        assert!(chunk_size > 0, "chunk_size must be greater than zero");
+
+        futures_util::stream::iter(std::iter::from_fn(move || {
+            (!self.data.is_empty())
+                .then(|| Ok(self.data.split_to(cmp::min(chunk_size, self.data.len()))))
+        }))
+    }
+}
+
+impl AsyncRead for PgByteaReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let len = cmp::min(buf.len(), this.data.len());
+
+        this.data.copy_to_slice(&mut buf[..len]);
+
+        Poll::Ready(Ok(len))
+    }
+}
+
+impl AsyncBufRead for PgByteaReader {
+    fn poll_fill_buf(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        Poll::Ready(Ok(&self.get_mut().data))
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        self.get_mut().data.advance(amt);
+    }
+}
+
+impl From<Bytes> for PgByteaReader {
+    fn from(data: Bytes) -> Self {
+        Self { data }
+    }
+}
+
+impl Type<Postgres> for PgByteaReader {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::BYTEA
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::BYTEA || <str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Decode<'_, Postgres> for PgByteaReader {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        if value.format() == PgValueFormat::Text && value.type_info == PgTypeInfo::BYTEA {
+            let data = text_decode(value)?;
+
+            return Ok(Self { data: data.into() });
+        }
+
+        let bytes = value.as_bytes()?;
+
+        let data = match value.row {
+            Some(row) => row.slice_ref(bytes),
+            None => Bytes::copy_from_slice(bytes),
+        };
+
+        Ok(Self { data })
+    }
+}
+
+impl PgConnection {
+    /// Fetch a large `BYTEA` or `TEXT` value in chunks of `chunk_size` bytes,
+    /// running a separate query for each chunk so that the whole value is never held in memory.
+    ///
+    /// `query` is called with the 1-based offset and the length of each chunk, and should return
+    /// a query selecting that part of the value, e.g. with `substring()`.
+    /// The stream ends after the first chunk which is shorter than `chunk_size` or `NULL`,
+    /// or when the query returns no rows.
+    ///
+    /// Chunks are not read in a single snapshot unless this runs in a transaction
+    /// with an isolation level of `REPEATABLE READ` or higher.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(mut conn: sqlx::PgConnection) -> sqlx::Result<()> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let id = 1_i64;
+    ///
+    /// let mut chunks = conn.fetch_bytea_chunks(64 * 1024, |offset, len| {
+    ///     sqlx::query("SELECT substring(data FROM $1 FOR $2) FROM blobs WHERE id = $3")
+    ///         .bind(offset)
+    ///         .bind(len)
+    ///         .bind(id)
+    /// });
+    ///
+    /// while let Some(chunk) = chunks.try_next().await? {
+    ///     // write `chunk` somewhere
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// If `chunk_size` is not greater than zero.
+    pub fn fetch_bytea_chunks<'c, F>(
+        &'c mut self,
+        chunk_size: i32,
+        mut query: F,
+    ) -> BoxStream<'c, Result<Bytes, Error>>
+    where
+        F: FnMut(i32, i32) -> Query<'c, Postgres, PgArguments> + Send + 'c,
+    {
+        assert!(chunk_size > 0, "chunk_size must be greater than zero");
+
+        Box::pin(try_stream! {
+            let mut offset = 1_i32;
+
+            loop {
+                let Some(row) = query(offset, chunk_size).fetch_optional(&mut *self).await? else {
+                    break;
+                };
+
+                let Some(chunk) = row.try_get::<Option<PgByteaReader>, _>(0)? else {
+                    break;
+                };
+
+                let chunk = chunk.into_bytes();
+                let is_last = chunk.len() < chunk_size as usize;
+
+                if !chunk.is_empty() {
+                    r#yield!(chunk);
+                }
+
+                if is_last {
+                    break;
+                }
+
+                offset = offset.checked_add(chunk_size).ok_or_else(|| {
+                    Error::Protocol("value is too large to fetch in chunks".into())
+                })?;
+            }
+
+            Ok(())
+        })
+    }
+}
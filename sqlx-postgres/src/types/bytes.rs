@@ -71,20 +71,50 @@ impl<'r> Decode<'r, Postgres> for &'r [u8] {
     }
 }
 
-fn text_hex_decode_input(value: PgValueRef<'_>) -> Result<&[u8], BoxDynError> {
-    // BYTEA is formatted as \x followed by hex characters
-    value
-        .as_bytes()?
-        .strip_prefix(b"\\x")
-        .ok_or("text does not start with \\x")
-        .map_err(Into::into)
+pub(super) fn text_decode(value: PgValueRef<'_>) -> Result<Vec<u8>, BoxDynError> {
+    decode_text(value.as_bytes()?)
+}
+
+// BYTEA is formatted as \x followed by hex characters, unless `bytea_output = 'escape'`
+// in which case printable characters are sent as-is, and other bytes as \ followed by
+// three octal digits, or \\ for a backslash.
+fn decode_text(text: &[u8]) -> Result<Vec<u8>, BoxDynError> {
+    if let Some(hex) = text.strip_prefix(b"\\x") {
+        return Ok(hex::decode(hex)?);
+    }
+
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+
+        if byte != b'\\' {
+            bytes.push(byte);
+            continue;
+        }
+
+        match rest {
+            [b'\\', tail @ ..] => {
+                bytes.push(b'\\');
+                rest = tail;
+            }
+            [high @ b'0'..=b'3', mid @ b'0'..=b'7', low @ b'0'..=b'7', tail @ ..] => {
+                bytes.push((high - b'0') << 6 | (mid - b'0') << 3 | (low - b'0'));
+                rest = tail;
+            }
+            _ => return Err("invalid escape sequence in BYTEA text".into()),
+        }
+    }
+
+    Ok(bytes)
 }
 
 impl Decode<'_, Postgres> for Box<[u8]> {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(match value.format() {
             PgValueFormat::Binary => Box::from(value.as_bytes()?),
-            PgValueFormat::Text => Box::from(hex::decode(text_hex_decode_input(value)?)?),
+            PgValueFormat::Text => text_decode(value)?.into_boxed_slice(),
         })
     }
 }
@@ -93,20 +123,39 @@ impl Decode<'_, Postgres> for Vec<u8> {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         Ok(match value.format() {
             PgValueFormat::Binary => value.as_bytes()?.to_owned(),
-            PgValueFormat::Text => hex::decode(text_hex_decode_input(value)?)?,
+            PgValueFormat::Text => text_decode(value)?,
         })
     }
 }
 
 impl<const N: usize> Decode<'_, Postgres> for [u8; N] {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
-        let mut bytes = [0u8; N];
-        match value.format() {
-            PgValueFormat::Binary => {
-                bytes = value.as_bytes()?.try_into()?;
-            }
-            PgValueFormat::Text => hex::decode_to_slice(text_hex_decode_input(value)?, &mut bytes)?,
-        };
-        Ok(bytes)
+        Ok(match value.format() {
+            PgValueFormat::Binary => value.as_bytes()?.try_into()?,
+            PgValueFormat::Text => text_decode(value)?.as_slice().try_into()?,
+        })
     }
 }
+
+#[test]
+fn test_decode_text_hex() {
+    assert_eq!(decode_text(b"\\x").unwrap(), b"");
+    assert_eq!(
+        decode_text(b"\\xdeadBEEF").unwrap(),
+        [0xDE, 0xAD, 0xBE, 0xEF]
+    );
+    assert!(decode_text(b"\\xabc").is_err());
+}
+
+#[test]
+fn test_decode_text_escape() {
+    assert_eq!(decode_text(b"").unwrap(), b"");
+    assert_eq!(decode_text(b"abc").unwrap(), b"abc");
+    assert_eq!(
+        decode_text(b"\\000a\\\\b\\377").unwrap(),
+        [0x00, b'a', b'\\', b'b', 0xFF]
+    );
+    assert!(decode_text(b"\\").is_err());
+    assert!(decode_text(b"\\400").is_err());
+    assert!(decode_text(b"\\18").is_err());
+}
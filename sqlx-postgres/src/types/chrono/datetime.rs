@@ -86,25 +86,45 @@ impl<Tz: TimeZone> Encode<'_, Postgres> for DateTime<Tz> {
 
 impl<'r> Decode<'r, Postgres> for DateTime<Local> {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
-        let naive = <NaiveDateTime as Decode<Postgres>>::decode(value)?;
-        Ok(Local.from_utc_datetime(&naive))
+        Ok(decode_timestamptz(value)?.with_timezone(&Local))
     }
 }
 
 impl<'r> Decode<'r, Postgres> for DateTime<Utc> {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
-        let naive = <NaiveDateTime as Decode<Postgres>>::decode(value)?;
-        Ok(Utc.from_utc_datetime(&naive))
+        Ok(decode_timestamptz(value)?.with_timezone(&Utc))
     }
 }
 
+/// Decodes with the offset of the session `TimeZone`.
+///
+/// In the binary format, `TIMESTAMPTZ` is sent as a point in time without an offset, so the
+/// offset is only known if the `TimeZone` of the session is a fixed offset, such as
+/// `UTC` (the default set by SQLx) or one set with `SET TIME ZONE 2`. Otherwise, such as for
+/// `Europe/Berlin`, the value is decoded in UTC.
 impl<'r> Decode<'r, Postgres> for DateTime<FixedOffset> {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
-        let naive = <NaiveDateTime as Decode<Postgres>>::decode(value)?;
-        Ok(Utc.fix().from_utc_datetime(&naive))
+        decode_timestamptz(value)
     }
 }
 
+fn decode_timestamptz(value: PgValueRef<'_>) -> Result<DateTime<FixedOffset>, BoxDynError> {
+    if value.format() == PgValueFormat::Text {
+        // the text format includes the offset of the session `TimeZone`
+        if let Ok(datetime) = DateTime::parse_from_str(value.as_str()?, "%Y-%m-%d %H:%M:%S%.f%#z") {
+            return Ok(datetime);
+        }
+    }
+
+    let offset = value
+        .time_zone_offset
+        .and_then(FixedOffset::east_opt)
+        .unwrap_or(Utc.fix());
+
+    let naive = <NaiveDateTime as Decode<Postgres>>::decode(value)?;
+    Ok(offset.from_utc_datetime(&naive))
+}
+
 #[inline]
 fn postgres_epoch_datetime() -> NaiveDateTime {
     NaiveDate::from_ymd_opt(2000, 1, 1)
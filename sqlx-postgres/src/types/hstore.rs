@@ -0,0 +1,304 @@
+use std::collections::btree_map::{self, BTreeMap};
+use std::fmt::{self, Display, Formatter, Write};
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+
+use sqlx_core::bytes::Buf;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::{array_compatible, Type};
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+/// Key-value pairs (`hstore`) support for Postgres.
+///
+/// Keys are unique and values may be `NULL`, so this wraps a `BTreeMap<String, Option<String>>`
+/// and dereferences to it.
+///
+/// See [the Postgres manual, Appendix F, Section 18][PG.F.18] for details on using `hstore`.
+///
+/// [PG.F.18]: https://www.postgresql.org/docs/current/hstore.html
+///
+/// ### Note: Extension Required
+/// The `hstore` extension is not enabled by default in Postgres. You will need to do so explicitly:
+///
+/// ```ignore
+/// CREATE EXTENSION IF NOT EXISTS "hstore";
+/// ```
+///
+/// ```rust
+/// use sqlx_postgres::types::PgHstore;
+///
+/// let mut attributes = PgHstore::from_iter([("color", Some("red")), ("size", None)]);
+/// attributes.insert("material".into(), Some("wool".into()));
+///
+/// assert_eq!(attributes.to_string(), r#""color"=>"red", "material"=>"wool", "size"=>NULL"#);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PgHstore(pub BTreeMap<String, Option<String>>);
+
+impl Deref for PgHstore {
+    type Target = BTreeMap<String, Option<String>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for PgHstore {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<BTreeMap<String, Option<String>>> for PgHstore {
+    fn from(value: BTreeMap<String, Option<String>>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<PgHstore> for BTreeMap<String, Option<String>> {
+    fn from(value: PgHstore) -> Self {
+        value.0
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, Option<V>)> for PgHstore {
+    fn from_iter<T: IntoIterator<Item = (K, Option<V>)>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(key, value)| (key.into(), value.map(Into::into)))
+                .collect(),
+        )
+    }
+}
+
+impl IntoIterator for PgHstore {
+    type Item = (String, Option<String>);
+    type IntoIter = btree_map::IntoIter<String, Option<String>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Formats the pairs like Postgres does, e.g. `"a"=>"1", "b"=>NULL`.
+impl Display for PgHstore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, (key, value)) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            write_quoted(f, key)?;
+            f.write_str("=>")?;
+
+            match value {
+                Some(value) => write_quoted(f, value)?,
+                None => f.write_str("NULL")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses the text format of `hstore`, e.g. `a=>1, "b c"=>NULL`.
+///
+/// If a key appears more than once, the first value is kept, like Postgres does.
+impl FromStr for PgHstore {
+    type Err = BoxDynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut pairs = BTreeMap::new();
+        let mut chars = s.chars().peekable();
+
+        loop {
+            skip_whitespace(&mut chars);
+
+            if chars.peek().is_none() {
+                return Ok(Self(pairs));
+            }
+
+            let key = parse_string(&mut chars)?.ok_or("hstore keys may not be NULL")?;
+
+            skip_whitespace(&mut chars);
+
+            if chars.next() != Some('=') || chars.next() != Some('>') {
+                return Err(format!("expected `=>` after hstore key {key:?}").into());
+            }
+
+            skip_whitespace(&mut chars);
+
+            let value = parse_string(&mut chars)?;
+
+            pairs.entry(key).or_insert(value);
+
+            skip_whitespace(&mut chars);
+
+            match chars.next() {
+                Some(',') | None => {}
+                Some(c) => {
+                    return Err(format!("expected `,` between hstore pairs, got {c:?}").into())
+                }
+            }
+        }
+    }
+}
+
+impl Type<Postgres> for PgHstore {
+    fn type_info() -> PgTypeInfo {
+        // Since `hstore` is enabled by an extension, it does not have a stable OID.
+        PgTypeInfo::with_name("hstore")
+    }
+}
+
+impl PgHasArrayType for PgHstore {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_hstore")
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        // the server names the resolved type `hstore[]`, not `_hstore`
+        *ty == Self::array_type_info() || array_compatible::<PgHstore>(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for PgHstore {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.extend(i32::try_from(self.0.len())?.to_be_bytes());
+
+        for (key, value) in &self.0 {
+            buf.extend(i32::try_from(key.len())?.to_be_bytes());
+            buf.extend(key.as_bytes());
+
+            match value {
+                Some(value) => {
+                    buf.extend(i32::try_from(value.len())?.to_be_bytes());
+                    buf.extend(value.as_bytes());
+                }
+                None => buf.extend((-1_i32).to_be_bytes()),
+            }
+        }
+
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, Postgres> for PgHstore {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let mut buf = value.as_bytes()?;
+                let mut pairs = BTreeMap::new();
+
+                let count = read_len(&mut buf)?.ok_or("negative hstore pair count")?;
+
+                for _ in 0..count {
+                    let key = read_str(&mut buf)?.ok_or("hstore keys may not be NULL")?;
+                    let value = read_str(&mut buf)?;
+
+                    pairs.insert(key.to_owned(), value.map(str::to_owned));
+                }
+
+                Ok(Self(pairs))
+            }
+
+            PgValueFormat::Text => value.as_str()?.parse(),
+        }
+    }
+}
+
+// `None` for the length of `NULL`
+fn read_len(buf: &mut &[u8]) -> Result<Option<usize>, BoxDynError> {
+    if buf.len() < 4 {
+        return Err("unexpected end of hstore data".into());
+    }
+
+    Ok(usize::try_from(buf.get_i32()).ok())
+}
+
+fn read_str<'a>(buf: &mut &'a [u8]) -> Result<Option<&'a str>, BoxDynError> {
+    let Some(len) = read_len(buf)? else {
+        return Ok(None);
+    };
+
+    if buf.len() < len {
+        return Err("unexpected end of hstore data".into());
+    }
+
+    let (s, rest) = buf.split_at(len);
+    *buf = rest;
+
+    Ok(Some(std::str::from_utf8(s)?))
+}
+
+fn write_quoted(f: &mut Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+
+    for c in s.chars() {
+        if c == '"' || c == '\\' {
+            f.write_char('\\')?;
+        }
+
+        f.write_char(c)?;
+    }
+
+    f.write_char('"')
+}
+
+fn skip_whitespace(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+// A quoted or bare string, or `None` for a bare `NULL`.
+fn parse_string(
+    chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+) -> Result<Option<String>, BoxDynError> {
+    let mut s = String::new();
+
+    if chars.next_if_eq(&'"').is_some() {
+        loop {
+            match chars.next() {
+                Some('"') => return Ok(Some(s)),
+                Some('\\') => s.push(chars.next().ok_or("unexpected end of hstore text")?),
+                Some(c) => s.push(c),
+                None => return Err("unterminated string in hstore text".into()),
+            }
+        }
+    }
+
+    while let Some(c) = chars.next_if(|&c| !c.is_whitespace() && c != ',' && c != '=') {
+        if c == '\\' {
+            s.push(chars.next().ok_or("unexpected end of hstore text")?);
+        } else {
+            s.push(c);
+        }
+    }
+
+    if s.is_empty() {
+        return Err("expected a string in hstore text".into());
+    }
+
+    Ok((!s.eq_ignore_ascii_case("NULL")).then_some(s))
+}
+
+#[test]
+fn it_parses_and_formats_hstore_text() {
+    let hstore: PgHstore = r#"a=>1, "b c" => NULL, "d\"e"=>"f\\g", a=>2"#.parse().unwrap();
+
+    assert_eq!(
+        hstore,
+        PgHstore::from_iter([("a", Some("1")), ("b c", None), ("d\"e", Some("f\\g")),])
+    );
+    assert_eq!(
+        hstore.to_string(),
+        r#""a"=>"1", "b c"=>NULL, "d\"e"=>"f\\g""#
+    );
+    assert_eq!(hstore.to_string().parse::<PgHstore>().unwrap(), hstore);
+
+    assert!("".parse::<PgHstore>().unwrap().is_empty());
+    assert!("a".parse::<PgHstore>().is_err());
+    assert!("NULL=>1".parse::<PgHstore>().is_err());
+}
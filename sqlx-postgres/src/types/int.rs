@@ -1,3 +1,5 @@
+use std::num::{NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8};
+
 use byteorder::{BigEndian, ByteOrder};
 
 use crate::decode::Decode;
@@ -153,3 +155,22 @@ impl Decode<'_, Postgres> for i64 {
         int_decode(value)
     }
 }
+
+// `Type`, `Encode` and `Decode` for the `NonZero*` types are provided generically by
+// `sqlx-core`; arrays of them use the same array types as their primitive counterparts.
+macro_rules! impl_non_zero_array {
+    ($($int:ty => $non_zero:ty),* $(,)?) => {
+        $(impl PgHasArrayType for $non_zero {
+            fn array_type_info() -> PgTypeInfo {
+                <$int as PgHasArrayType>::array_type_info()
+            }
+        })*
+    };
+}
+
+impl_non_zero_array! {
+    i8 => NonZeroI8,
+    i16 => NonZeroI16,
+    i32 => NonZeroI32,
+    i64 => NonZeroI64,
+}
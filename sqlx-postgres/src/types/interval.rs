@@ -45,14 +45,93 @@ impl<'de> Decode<'de, Postgres> for PgInterval {
                 })
             }
 
-            // TODO: Implement parsing of text mode
-            PgValueFormat::Text => {
-                Err("not implemented: decode `INTERVAL` in text mode (unprepared queries)".into())
-            }
+            PgValueFormat::Text => decode_text(value.as_str()?),
         }
     }
 }
 
+// Parses the default `postgres` `IntervalStyle`, e.g. `1 year -2 mons +3 days -04:05:06.789`.
+fn decode_text(text: &str) -> Result<PgInterval, BoxDynError> {
+    let mut interval = PgInterval::default();
+    let mut tokens = text.split_whitespace();
+
+    let overflow = || format!("INTERVAL out of range: {text:?}");
+
+    while let Some(token) = tokens.next() {
+        if token.contains(':') {
+            interval.microseconds = interval
+                .microseconds
+                .checked_add(decode_text_time(token)?)
+                .ok_or_else(overflow)?;
+
+            continue;
+        }
+
+        let quantity: i32 = token.parse()?;
+
+        let (field, multiplier) = match tokens.next() {
+            Some("year" | "years") => (&mut interval.months, 12),
+            Some("mon" | "mons") => (&mut interval.months, 1),
+            Some("day" | "days") => (&mut interval.days, 1),
+            _ => {
+                return Err(format!(
+                "unsupported INTERVAL text {text:?}; only `IntervalStyle = postgres` is supported"
+            )
+                .into())
+            }
+        };
+
+        *field = quantity
+            .checked_mul(multiplier)
+            .and_then(|value| field.checked_add(value))
+            .ok_or_else(overflow)?;
+    }
+
+    Ok(interval)
+}
+
+// Parses `[+-]hours:minutes:seconds[.fraction]` into microseconds.
+fn decode_text_time(token: &str) -> Result<i64, BoxDynError> {
+    let (negative, token) = match token.strip_prefix('-') {
+        Some(token) => (true, token),
+        None => (false, token.strip_prefix('+').unwrap_or(token)),
+    };
+
+    let mut parts = token.split(':');
+
+    let (Some(hours), Some(minutes), Some(seconds), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(format!("invalid INTERVAL time {token:?}").into());
+    };
+
+    let (seconds, fraction) = seconds.split_once('.').unwrap_or((seconds, ""));
+
+    if fraction.len() > 6 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!("invalid INTERVAL time {token:?}").into());
+    }
+
+    let hours: i64 = hours.parse()?;
+    let minutes: i64 = minutes.parse()?;
+    let seconds: i64 = seconds.parse()?;
+    let fraction: i64 = format!("{fraction:0<6}").parse()?;
+
+    let microseconds = hours
+        .checked_mul(60)
+        .and_then(|m| m.checked_add(minutes))
+        .and_then(|m| m.checked_mul(60))
+        .and_then(|s| s.checked_add(seconds))
+        .and_then(|s| s.checked_mul(1_000_000))
+        .and_then(|us| us.checked_add(fraction))
+        .ok_or_else(|| format!("INTERVAL time out of range: {token:?}"))?;
+
+    Ok(if negative {
+        -microseconds
+    } else {
+        microseconds
+    })
+}
+
 impl Encode<'_, Postgres> for PgInterval {
     fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
         buf.extend(&self.microseconds.to_be_bytes());
@@ -310,6 +389,36 @@ fn test_encode_interval() {
     );
 }
 
+#[test]
+fn test_decode_text_interval() {
+    let interval = |months, days, microseconds| PgInterval {
+        months,
+        days,
+        microseconds,
+    };
+
+    assert_eq!(decode_text("00:00:00").unwrap(), interval(0, 0, 0));
+    assert_eq!(decode_text("1 day").unwrap(), interval(0, 1, 0));
+    assert_eq!(
+        decode_text("1 year 2 mons 3 days 04:05:06.789").unwrap(),
+        interval(14, 3, 14_706_789_000)
+    );
+    assert_eq!(
+        decode_text("-1 years +2 mons -3 days -04:05:06.000001").unwrap(),
+        interval(-10, -3, -14_706_000_001)
+    );
+    assert_eq!(
+        decode_text("100:00:00").unwrap(),
+        interval(0, 0, 360_000_000_000)
+    );
+
+    assert!(decode_text("P1Y2M3DT4H5M6S").is_err());
+    assert!(decode_text("@ 1 year").is_err());
+    assert!(decode_text("1 fortnight").is_err());
+    assert!(decode_text("00:00:00.1234567").is_err());
+    assert!(decode_text("178956971 years").is_err());
+}
+
 #[test]
 fn test_pginterval_std() {
     // Case for positive duration
@@ -16,10 +16,13 @@
 //! | `()`                                  | VOID                                                 |
 //! | [`PgInterval`]                        | INTERVAL                                             |
 //! | [`PgRange<T>`](PgRange)               | INT8RANGE, INT4RANGE, TSRANGE, TSTZRANGE, DATERANGE, NUMRANGE |
+//! | [`PgMultiRange<T>`](PgMultiRange)     | INT8MULTIRANGE, INT4MULTIRANGE, TSMULTIRANGE, TSTZMULTIRANGE, DATEMULTIRANGE, NUMMULTIRANGE |
 //! | [`PgMoney`]                           | MONEY                                                |
 //! | [`PgLTree`]                           | LTREE                                                |
 //! | [`PgLQuery`]                          | LQUERY                                               |
 //! | [`PgCiText`]                          | CITEXT<sup>1</sup>                                   |
+//! | [`PgHstore`]                          | HSTORE                                               |
+//! | [`PgByteaReader`]                     | BYTEA, TEXT (decode only)                            |
 //!
 //! <sup>1</sup> SQLx generally considers `CITEXT` to be compatible with `String`, `&str`, etc.,
 //! but this wrapper type is available for edge cases, such as `CITEXT[]` which Postgres
@@ -51,11 +54,16 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `chrono::DateTime<Utc>`               | TIMESTAMPTZ                                          |
 //! | `chrono::DateTime<Local>`             | TIMESTAMPTZ                                          |
+//! | `chrono::DateTime<FixedOffset>`       | TIMESTAMPTZ                                          |
 //! | `chrono::NaiveDateTime`               | TIMESTAMP                                            |
 //! | `chrono::NaiveDate`                   | DATE                                                 |
 //! | `chrono::NaiveTime`                   | TIME                                                 |
 //! | [`PgTimeTz`]                          | TIMETZ                                               |
 //!
+//! `DateTime<FixedOffset>` is decoded with the offset of the session `TimeZone` if it is a
+//! fixed offset, and in UTC otherwise. The query macros infer `TIMESTAMPTZ` as `DateTime<Utc>`
+//! unless `SQLX_POSTGRES_TIMESTAMPTZ` is set to `fixed_offset` or `local`.
+//!
 //! ### [`time`](https://crates.io/crates/time)
 //!
 //! Requires the `time` Cargo feature flag.
@@ -183,9 +191,11 @@ pub(crate) use sqlx_core::types::{Json, Type};
 
 mod array;
 mod bool;
+mod bytea_reader;
 mod bytes;
 mod citext;
 mod float;
+mod hstore;
 mod int;
 mod interval;
 mod lquery;
@@ -193,6 +203,7 @@ mod ltree;
 // Not behind a Cargo feature because we require JSON in the driver implementation.
 mod json;
 mod money;
+mod multirange;
 mod oid;
 mod range;
 mod record;
@@ -207,8 +218,16 @@ mod time_tz;
 #[cfg(feature = "bigdecimal")]
 mod bigdecimal;
 
-#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
-mod numeric;
+#[cfg(any(
+    feature = "bigdecimal",
+    feature = "rust_decimal",
+    feature = "any",
+    feature = "json"
+))]
+pub(crate) mod numeric;
+
+#[cfg(feature = "any")]
+mod any;
 
 #[cfg(feature = "rust_decimal")]
 mod rust_decimal;
@@ -235,7 +254,9 @@ mod mac_address;
 mod bit_vec;
 
 pub use array::PgHasArrayType;
+pub use bytea_reader::PgByteaReader;
 pub use citext::PgCiText;
+pub use hstore::PgHstore;
 pub use interval::PgInterval;
 pub use lquery::PgLQuery;
 pub use lquery::PgLQueryLevel;
@@ -245,6 +266,7 @@ pub use ltree::PgLTree;
 pub use ltree::PgLTreeLabel;
 pub use ltree::PgLTreeParseError;
 pub use money::PgMoney;
+pub use multirange::PgMultiRange;
 pub use oid::Oid;
 pub use range::PgRange;
 
@@ -0,0 +1,354 @@
+use std::fmt::{self, Display, Formatter};
+
+use sqlx_core::bytes::Buf;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::type_info::PgTypeKind;
+use crate::types::{PgRange, Type};
+use crate::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+
+/// A Postgres multirange, an ordered list of non-overlapping ranges (Postgres 14 and newer).
+///
+/// Postgres normalizes multiranges: ranges which overlap or are adjacent are merged, and the
+/// ranges are sorted. Decoded values are always normalized, but encoded values don't have to be.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct PgMultiRange<T> {
+    pub ranges: Vec<PgRange<T>>,
+}
+
+impl<T> From<Vec<PgRange<T>>> for PgMultiRange<T> {
+    fn from(ranges: Vec<PgRange<T>>) -> Self {
+        Self { ranges }
+    }
+}
+
+impl<T> FromIterator<PgRange<T>> for PgMultiRange<T> {
+    fn from_iter<I: IntoIterator<Item = PgRange<T>>>(iter: I) -> Self {
+        Self {
+            ranges: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> IntoIterator for PgMultiRange<T> {
+    type Item = PgRange<T>;
+    type IntoIter = std::vec::IntoIter<PgRange<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+impl Type<Postgres> for PgMultiRange<i32> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INT4_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<i32>(ty)
+    }
+}
+
+impl Type<Postgres> for PgMultiRange<i64> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INT8_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<i64>(ty)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl Type<Postgres> for PgMultiRange<bigdecimal::BigDecimal> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUM_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<bigdecimal::BigDecimal>(ty)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl Type<Postgres> for PgMultiRange<rust_decimal::Decimal> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::NUM_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<rust_decimal::Decimal>(ty)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Type<Postgres> for PgMultiRange<chrono::NaiveDate> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<chrono::NaiveDate>(ty)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Type<Postgres> for PgMultiRange<chrono::NaiveDateTime> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<chrono::NaiveDateTime>(ty)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> Type<Postgres> for PgMultiRange<chrono::DateTime<Tz>> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TSTZ_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<chrono::DateTime<Tz>>(ty)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for PgMultiRange<time::Date> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<time::Date>(ty)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for PgMultiRange<time::PrimitiveDateTime> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<time::PrimitiveDateTime>(ty)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Type<Postgres> for PgMultiRange<time::OffsetDateTime> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::TSTZ_MULTIRANGE
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        multirange_compatible::<time::OffsetDateTime>(ty)
+    }
+}
+
+impl PgHasArrayType for PgMultiRange<i32> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::INT4_MULTIRANGE_ARRAY
+    }
+}
+
+impl PgHasArrayType for PgMultiRange<i64> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::INT8_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl PgHasArrayType for PgMultiRange<bigdecimal::BigDecimal> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::NUM_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl PgHasArrayType for PgMultiRange<rust_decimal::Decimal> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::NUM_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl PgHasArrayType for PgMultiRange<chrono::NaiveDate> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl PgHasArrayType for PgMultiRange<chrono::NaiveDateTime> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> PgHasArrayType for PgMultiRange<chrono::DateTime<Tz>> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::TSTZ_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "time")]
+impl PgHasArrayType for PgMultiRange<time::Date> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::DATE_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "time")]
+impl PgHasArrayType for PgMultiRange<time::PrimitiveDateTime> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::TS_MULTIRANGE_ARRAY
+    }
+}
+
+#[cfg(feature = "time")]
+impl PgHasArrayType for PgMultiRange<time::OffsetDateTime> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::TSTZ_MULTIRANGE_ARRAY
+    }
+}
+
+impl<'q, T> Encode<'q, Postgres> for PgMultiRange<T>
+where
+    T: Encode<'q, Postgres>,
+{
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        // https://github.com/postgres/postgres/blob/REL_14_0/src/backend/utils/adt/multirangetypes.c#L318
+
+        let count = i32::try_from(self.ranges.len())
+            .map_err(|_| format!("too many ranges in a multirange: {}", self.ranges.len()))?;
+
+        buf.extend(&count.to_be_bytes());
+
+        for range in &self.ranges {
+            buf.encode(range)?;
+        }
+
+        // multiranges are themselves never null
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r, T> Decode<'r, Postgres> for PgMultiRange<T>
+where
+    T: Type<Postgres> + for<'a> Decode<'a, Postgres>,
+{
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let range_ty = if let PgTypeKind::Multirange(range) = &value.type_info.0.kind() {
+            range.clone()
+        } else {
+            return Err(format!("unexpected non-multirange type {}", value.type_info).into());
+        };
+
+        match value.format {
+            PgValueFormat::Binary => {
+                let mut buf = value.as_bytes()?;
+
+                let count = buf.get_i32();
+                let mut ranges = Vec::with_capacity(count.try_into().unwrap_or(0));
+
+                for _ in 0..count {
+                    ranges.push(PgRange::decode(PgValueRef::get(
+                        &mut buf,
+                        value.format,
+                        range_ty.clone(),
+                        value.time_zone_offset,
+                    ))?);
+                }
+
+                Ok(PgMultiRange { ranges })
+            }
+
+            PgValueFormat::Text => {
+                // https://github.com/postgres/postgres/blob/REL_14_0/src/backend/utils/adt/multirangetypes.c#L122
+
+                let s = value.as_str()?.trim();
+
+                let s = s
+                    .strip_prefix('{')
+                    .and_then(|s| s.strip_suffix('}'))
+                    .ok_or_else(|| format!("expected a multirange literal in braces, got {s:?}"))?;
+
+                let mut ranges = Vec::new();
+                let mut start = None;
+                let mut in_quotes = false;
+                let mut in_escape = false;
+
+                for (i, ch) in s.char_indices() {
+                    match ch {
+                        _ if in_escape => in_escape = false,
+                        '\\' if in_quotes => in_escape = true,
+                        '"' => in_quotes = !in_quotes,
+                        _ if in_quotes => {}
+
+                        '[' | '(' if start.is_none() => start = Some(i),
+
+                        ']' | ')' => {
+                            let Some(range_start) = start.take() else {
+                                return Err(
+                                    format!("unexpected `{ch}` in multirange literal").into()
+                                );
+                            };
+
+                            ranges.push(PgRange::decode(PgValueRef {
+                                type_info: range_ty.clone(),
+                                format: PgValueFormat::Text,
+                                value: Some(&s.as_bytes()[range_start..=i]),
+                                row: None,
+                                time_zone_offset: value.time_zone_offset,
+                            })?);
+                        }
+
+                        _ => {}
+                    }
+                }
+
+                if start.is_some() {
+                    return Err("unterminated range in multirange literal".into());
+                }
+
+                Ok(PgMultiRange { ranges })
+            }
+        }
+    }
+}
+
+impl<T> Display for PgMultiRange<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+
+        for (i, range) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+
+            write!(f, "{range}")?;
+        }
+
+        f.write_str("}")
+    }
+}
+
+fn multirange_compatible<E: Type<Postgres>>(ty: &PgTypeInfo) -> bool {
+    // we require the declared type to be a _multirange_ of a range with an
+    // element type that is acceptable
+    if let PgTypeKind::Multirange(range) = &ty.kind() {
+        if let PgTypeKind::Range(element) = &range.kind() {
+            return E::compatible(element);
+        }
+    }
+
+    false
+}
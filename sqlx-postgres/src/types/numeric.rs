@@ -1,7 +1,6 @@
 use sqlx_core::bytes::Buf;
 
 use crate::error::BoxDynError;
-use crate::PgArgumentBuffer;
 
 /// Represents a `NUMERIC` value in the **Postgres** wire protocol.
 #[derive(Debug, PartialEq, Eq)]
@@ -100,7 +99,8 @@ impl PgNumeric {
     ///
     /// * If `digits.len()` overflows `i16`
     /// * If any element in `digits` is greater than or equal to 10000
-    pub(crate) fn encode(&self, buf: &mut PgArgumentBuffer) {
+    #[cfg(any(feature = "bigdecimal", feature = "rust_decimal", feature = "any"))]
+    pub(crate) fn encode(&self, buf: &mut crate::PgArgumentBuffer) {
         match *self {
             PgNumeric::Number {
                 ref digits,
@@ -134,3 +134,60 @@ impl PgNumeric {
         }
     }
 }
+
+/// Format a `NUMERIC` value the way Postgres does, with `scale` digits after the decimal point.
+#[cfg(any(feature = "any", feature = "json"))]
+pub(crate) fn format_numeric(numeric: &PgNumeric) -> String {
+    use std::fmt::Write;
+
+    let PgNumeric::Number {
+        sign,
+        digits,
+        weight,
+        scale,
+    } = numeric
+    else {
+        return "NaN".to_owned();
+    };
+
+    let weight = i64::from(*weight);
+    let digit = |i: i64| {
+        usize::try_from(i)
+            .ok()
+            .and_then(|i| digits.get(i))
+            .copied()
+            .unwrap_or(0)
+    };
+
+    let mut s = String::new();
+
+    if *sign == PgNumericSign::Negative && !digits.is_empty() {
+        s.push('-');
+    }
+
+    if weight < 0 {
+        s.push('0');
+    } else {
+        write!(s, "{}", digit(0)).unwrap();
+
+        for i in 1..=weight {
+            write!(s, "{:04}", digit(i)).unwrap();
+        }
+    }
+
+    if *scale > 0 {
+        let scale = *scale as usize;
+        let mut fraction = String::with_capacity(scale + 3);
+
+        for i in (weight + 1..).take(scale.div_ceil(4)) {
+            write!(fraction, "{:04}", digit(i)).unwrap();
+        }
+
+        fraction.truncate(scale);
+
+        s.push('.');
+        s.push_str(&fraction);
+    }
+
+    s
+}
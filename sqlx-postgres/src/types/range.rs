@@ -329,6 +329,8 @@ where
     T: Type<Postgres> + for<'a> Decode<'a, Postgres>,
 {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let time_zone_offset = value.time_zone_offset;
+
         match value.format {
             PgValueFormat::Binary => {
                 let element_ty = if let PgTypeKind::Range(element) = &value.type_info.0.kind() {
@@ -349,8 +351,12 @@ where
                 }
 
                 if !flags.contains(RangeFlags::LB_INF) {
-                    let value =
-                        T::decode(PgValueRef::get(&mut buf, value.format, element_ty.clone()))?;
+                    let value = T::decode(PgValueRef::get(
+                        &mut buf,
+                        value.format,
+                        element_ty.clone(),
+                        value.time_zone_offset,
+                    ))?;
 
                     start = if flags.contains(RangeFlags::LB_INC) {
                         Bound::Included(value)
@@ -360,8 +366,12 @@ where
                 }
 
                 if !flags.contains(RangeFlags::UB_INF) {
-                    let value =
-                        T::decode(PgValueRef::get(&mut buf, value.format, element_ty.clone()))?;
+                    let value = T::decode(PgValueRef::get(
+                        &mut buf,
+                        value.format,
+                        element_ty.clone(),
+                        value.time_zone_offset,
+                    ))?;
 
                     end = if flags.contains(RangeFlags::UB_INC) {
                         Bound::Included(value)
@@ -451,6 +461,7 @@ where
                             format: PgValueFormat::Text,
                             value: Some(element.as_bytes()),
                             row: None,
+                            time_zone_offset,
                         })?);
 
                         if count == 1 {
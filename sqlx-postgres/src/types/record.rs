@@ -63,6 +63,7 @@ pub struct PgRecordDecoder<'r> {
     typ: PgTypeInfo,
     fmt: PgValueFormat,
     ind: usize,
+    time_zone_offset: Option<i32>,
 }
 
 impl<'r> PgRecordDecoder<'r> {
@@ -71,6 +72,7 @@ impl<'r> PgRecordDecoder<'r> {
         let fmt = value.format();
         let mut buf = value.as_bytes()?;
         let typ = value.type_info;
+        let time_zone_offset = value.time_zone_offset;
 
         match fmt {
             PgValueFormat::Binary => {
@@ -88,6 +90,7 @@ impl<'r> PgRecordDecoder<'r> {
             fmt,
             typ,
             ind: 0,
+            time_zone_offset,
         })
     }
 
@@ -137,7 +140,12 @@ impl<'r> PgRecordDecoder<'r> {
 
                 self.ind += 1;
 
-                T::decode(PgValueRef::get(&mut self.buf, self.fmt, element_type))
+                T::decode(PgValueRef::get(
+                    &mut self.buf,
+                    self.fmt,
+                    element_type,
+                    self.time_zone_offset,
+                ))
             }
 
             PgValueFormat::Text => {
@@ -198,6 +206,7 @@ impl<'r> PgRecordDecoder<'r> {
                     format: self.fmt,
                     value: buf,
                     row: None,
+                    time_zone_offset: self.time_zone_offset,
                 })
             }
         }
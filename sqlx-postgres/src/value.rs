@@ -20,6 +20,8 @@ pub struct PgValueRef<'r> {
     pub(crate) row: Option<&'r Bytes>,
     pub(crate) type_info: PgTypeInfo,
     pub(crate) format: PgValueFormat,
+    // offset of the session `TimeZone` in seconds east of UTC, if it is a fixed offset
+    pub(crate) time_zone_offset: Option<i32>,
 }
 
 /// Implementation of [`Value`] for PostgreSQL.
@@ -28,10 +30,16 @@ pub struct PgValue {
     pub(crate) value: Option<Bytes>,
     pub(crate) type_info: PgTypeInfo,
     pub(crate) format: PgValueFormat,
+    pub(crate) time_zone_offset: Option<i32>,
 }
 
 impl<'r> PgValueRef<'r> {
-    pub(crate) fn get(buf: &mut &'r [u8], format: PgValueFormat, ty: PgTypeInfo) -> Self {
+    pub(crate) fn get(
+        buf: &mut &'r [u8],
+        format: PgValueFormat,
+        ty: PgTypeInfo,
+        time_zone_offset: Option<i32>,
+    ) -> Self {
         let mut element_len = buf.get_i32();
 
         let element_val = if element_len == -1 {
@@ -48,6 +56,7 @@ impl<'r> PgValueRef<'r> {
             row: None,
             type_info: ty,
             format,
+            time_zone_offset,
         }
     }
 
@@ -77,6 +86,7 @@ impl Value for PgValue {
             row: None,
             type_info: self.type_info.clone(),
             format: self.format,
+            time_zone_offset: self.time_zone_offset,
         }
     }
 
@@ -105,6 +115,7 @@ impl<'r> ValueRef<'r> for PgValueRef<'r> {
             value,
             format: self.format,
             type_info: self.type_info.clone(),
+            time_zone_offset: self.time_zone_offset,
         }
     }
 
@@ -1,6 +1,7 @@
 use crate::{
     Either, Sqlite, SqliteArgumentValue, SqliteArguments, SqliteColumn, SqliteConnectOptions,
     SqliteConnection, SqliteQueryResult, SqliteRow, SqliteTransactionManager, SqliteTypeInfo,
+    SqliteValue,
 };
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
@@ -8,7 +9,7 @@ use futures_util::{StreamExt, TryFutureExt, TryStreamExt};
 
 use sqlx_core::any::{
     Any, AnyArguments, AnyColumn, AnyConnectOptions, AnyConnectionBackend, AnyQueryResult, AnyRow,
-    AnyStatement, AnyTypeInfo, AnyTypeInfoKind, AnyValueKind,
+    AnyStatement, AnyTypeInfo, AnyTypeInfoKind, AnyValue, AnyValueError, AnyValueKind,
 };
 
 use crate::type_info::DataType;
@@ -16,7 +17,10 @@ use sqlx_core::connection::{ConnectOptions, Connection};
 use sqlx_core::database::Database;
 use sqlx_core::describe::Describe;
 use sqlx_core::executor::Executor;
-use sqlx_core::transaction::TransactionManager;
+use sqlx_core::transaction::{TransactionManager, TransactionOptions};
+use sqlx_core::value::Value;
+use std::borrow::Cow;
+use std::time::Duration;
 
 sqlx_core::declare_driver_with_optional_migrate!(DRIVER = Sqlite);
 
@@ -33,7 +37,7 @@ impl AnyConnectionBackend for SqliteConnection {
         Connection::close_hard(*self)
     }
 
-    fn ping(&mut self) -> BoxFuture<'_, sqlx_core::Result<()>> {
+    fn ping(&mut self) -> BoxFuture<'_, sqlx_core::Result<Duration>> {
         Connection::ping(self)
     }
 
@@ -41,6 +45,10 @@ impl AnyConnectionBackend for SqliteConnection {
         SqliteTransactionManager::begin(self)
     }
 
+    fn begin_with(&mut self, options: TransactionOptions) -> BoxFuture<'_, sqlx_core::Result<()>> {
+        SqliteTransactionManager::begin_with(self, options)
+    }
+
     fn commit(&mut self) -> BoxFuture<'_, sqlx_core::Result<()>> {
         SqliteTransactionManager::commit(self)
     }
@@ -53,6 +61,10 @@ impl AnyConnectionBackend for SqliteConnection {
         SqliteTransactionManager::start_rollback(self)
     }
 
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
     fn shrink_buffers(&mut self) {
         // NO-OP.
     }
@@ -82,7 +94,7 @@ impl AnyConnectionBackend for SqliteConnection {
 
         Box::pin(
             self.worker
-                .execute(query, args, self.row_channel_size, persistent)
+                .execute(query, args, self.row_channel_size, persistent, None)
                 .map_ok(flume::Receiver::into_stream)
                 .try_flatten_stream()
                 .map(
@@ -105,7 +117,7 @@ impl AnyConnectionBackend for SqliteConnection {
         Box::pin(async move {
             let stream = self
                 .worker
-                .execute(query, args, self.row_channel_size, persistent)
+                .execute(query, args, self.row_channel_size, persistent, None)
                 .map_ok(flume::Receiver::into_stream)
                 .await?;
             futures_util::pin_mut!(stream);
@@ -138,6 +150,10 @@ impl<'a> TryFrom<&'a SqliteTypeInfo> for AnyTypeInfo {
     type Error = sqlx_core::Error;
 
     fn try_from(sqlite_type: &'a SqliteTypeInfo) -> Result<Self, Self::Error> {
+        if let Some(kind) = sqlx_core::any::mapped_kind::<Sqlite>(sqlite_type) {
+            return Ok(AnyTypeInfo { kind });
+        }
+
         Ok(AnyTypeInfo {
             kind: match &sqlite_type.0 {
                 DataType::Null => AnyTypeInfoKind::Null,
@@ -146,6 +162,9 @@ impl<'a> TryFrom<&'a SqliteTypeInfo> for AnyTypeInfo {
                 DataType::Float => AnyTypeInfoKind::Double,
                 DataType::Blob => AnyTypeInfoKind::Blob,
                 DataType::Text => AnyTypeInfoKind::Text,
+                DataType::Date => AnyTypeInfoKind::Date,
+                DataType::Time => AnyTypeInfoKind::Time,
+                DataType::Datetime => AnyTypeInfoKind::Timestamp,
                 _ => {
                     return Err(sqlx_core::Error::AnyDriverError(
                         format!("Any driver does not support the SQLite type {sqlite_type:?}")
@@ -183,11 +202,37 @@ impl<'a> TryFrom<&'a SqliteRow> for AnyRow {
     }
 }
 
+impl<'a> TryFrom<&'a SqliteValue> for AnyValue {
+    type Error = AnyValueError;
+
+    fn try_from(value: &'a SqliteValue) -> Result<Self, Self::Error> {
+        AnyValue::map_from::<Sqlite>(value.as_ref())
+    }
+}
+
+impl<'a> TryFrom<&'a AnyValue> for SqliteValue {
+    type Error = AnyValueError;
+
+    fn try_from(value: &'a AnyValue) -> Result<Self, Self::Error> {
+        let argument =
+            map_value(value.kind.clone()).map_err(|_| AnyValueError::UnsupportedKind {
+                database: Sqlite::NAME,
+                type_info: value.type_info().into_owned(),
+            })?;
+
+        // SQLite values carry their own storage class, the declared type is only used for `NULL`
+        Ok(SqliteValue::from_argument(&argument, SqliteTypeInfo(DataType::Null)))
+    }
+}
+
 impl<'a> TryFrom<&'a AnyConnectOptions> for SqliteConnectOptions {
     type Error = sqlx_core::Error;
 
     fn try_from(opts: &'a AnyConnectOptions) -> Result<Self, Self::Error> {
         let mut opts_out = SqliteConnectOptions::from_url(&opts.database_url)?;
+        for command in &opts.init_commands {
+            opts_out = opts_out.init_command(command.clone());
+        }
         opts_out.log_settings = opts.log_settings.clone();
         Ok(opts_out)
     }
@@ -200,23 +245,35 @@ fn map_arguments(args: AnyArguments<'_>) -> SqliteArguments<'_> {
             .values
             .0
             .into_iter()
-            .map(|val| match val {
-                AnyValueKind::Null => SqliteArgumentValue::Null,
-                AnyValueKind::Bool(b) => SqliteArgumentValue::Int(b as i32),
-                AnyValueKind::SmallInt(i) => SqliteArgumentValue::Int(i as i32),
-                AnyValueKind::Integer(i) => SqliteArgumentValue::Int(i),
-                AnyValueKind::BigInt(i) => SqliteArgumentValue::Int64(i),
-                AnyValueKind::Real(r) => SqliteArgumentValue::Double(r as f64),
-                AnyValueKind::Double(d) => SqliteArgumentValue::Double(d),
-                AnyValueKind::Text(t) => SqliteArgumentValue::Text(t),
-                AnyValueKind::Blob(b) => SqliteArgumentValue::Blob(b),
+            .map(|val| {
                 // AnyValueKind is `#[non_exhaustive]` but we should have covered everything
-                _ => unreachable!("BUG: missing mapping for {val:?}"),
+                map_value(val)
+                    .unwrap_or_else(|val| unreachable!("BUG: missing mapping for {val:?}"))
             })
             .collect(),
     }
 }
 
+fn map_value(value: AnyValueKind<'_>) -> Result<SqliteArgumentValue<'_>, AnyValueKind<'_>> {
+    Ok(match value {
+        AnyValueKind::Null => SqliteArgumentValue::Null,
+        AnyValueKind::Bool(b) => SqliteArgumentValue::Int(b as i32),
+        AnyValueKind::SmallInt(i) => SqliteArgumentValue::Int(i as i32),
+        AnyValueKind::Integer(i) => SqliteArgumentValue::Int(i),
+        AnyValueKind::BigInt(i) => SqliteArgumentValue::Int64(i),
+        AnyValueKind::Real(r) => SqliteArgumentValue::Double(r as f64),
+        AnyValueKind::Double(d) => SqliteArgumentValue::Double(d),
+        AnyValueKind::Text(t) => SqliteArgumentValue::Text(t),
+        AnyValueKind::Blob(b) => SqliteArgumentValue::Blob(b),
+        AnyValueKind::Decimal(d) => SqliteArgumentValue::Text(d),
+        AnyValueKind::Date(d) => SqliteArgumentValue::Text(Cow::Owned(d.to_string())),
+        AnyValueKind::Time(t) => SqliteArgumentValue::Text(Cow::Owned(t.to_string())),
+        AnyValueKind::Timestamp(t) => SqliteArgumentValue::Text(Cow::Owned(t.to_string())),
+        AnyValueKind::Uuid(u) => SqliteArgumentValue::Blob(Cow::Owned(u.0.to_vec())),
+        value => return Err(value),
+    })
+}
+
 fn map_result(res: SqliteQueryResult) -> AnyQueryResult {
     AnyQueryResult {
         rows_affected: res.rows_affected(),
@@ -72,6 +72,10 @@ impl<'q> Arguments<'q> for SqliteArguments<'q> {
     fn len(&self) -> usize {
         self.values.len()
     }
+
+    fn private_try_clone(&self) -> Option<Self> {
+        Some(self.clone())
+    }
 }
 
 impl SqliteArguments<'_> {
@@ -1,10 +1,11 @@
 use crate::connection::explain::explain;
-use crate::connection::ConnectionState;
+use crate::connection::{execute, ConnectionState};
 use crate::describe::Describe;
 use crate::error::Error;
 use crate::statement::VirtualStatement;
 use crate::type_info::DataType;
 use crate::{Sqlite, SqliteColumn};
+use sqlx_core::row::Row;
 use sqlx_core::Either;
 use std::convert::identity;
 
@@ -50,7 +51,17 @@ pub(crate) fn describe(conn: &mut ConnectionState, query: &str) -> Result<Descri
         for col in 0..num {
             let name = stmt.handle.column_name(col).to_owned();
 
-            let type_info = if let Some(ty) = stmt.handle.column_decltype(col) {
+            // an `ANY` column only holds values of any type unchanged in a `STRICT` table;
+            // otherwise it has `NUMERIC` affinity, so its type is inferred as for an expression
+            let decltype = stmt.handle.column_decltype(col).filter(|ty| {
+                ty.0 != DataType::Any
+                    || stmt
+                        .handle
+                        .column_table(col)
+                        .is_some_and(|(schema, table)| is_strict_table(conn, &schema, &table))
+            });
+
+            let type_info = if let Some(ty) = decltype {
                 ty
             } else {
                 // if that fails, we back up and attempt to step the statement
@@ -92,3 +103,24 @@ pub(crate) fn describe(conn: &mut ConnectionState, query: &str) -> Result<Descri
         nullable,
     })
 }
+
+// `false` if the table isn't `STRICT`, or SQLite is older than 3.37 and has no `STRICT` tables.
+fn is_strict_table(conn: &mut ConnectionState, schema: &str, table: &str) -> bool {
+    let query = format!(
+        "PRAGMA \"{}\".table_list(\"{}\")",
+        schema.replace('"', "\"\""),
+        table.replace('"', "\"\"")
+    );
+
+    let Ok(mut rows) = execute::iter(conn, &query, None, false) else {
+        return false;
+    };
+
+    // the columns are `schema`, `name`, `type`, `ncol`, `wr` and `strict`
+    let strict = rows.find_map(|res| match res {
+        Ok(Either::Right(row)) => Some(row.try_get::<bool, _>(5).unwrap_or(false)),
+        _ => None,
+    });
+
+    strict.unwrap_or(false)
+}
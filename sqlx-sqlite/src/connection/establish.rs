@@ -50,6 +50,8 @@ pub struct EstablishParams {
     extensions: IndexMap<CString, Option<CString>>,
     pub(crate) thread_name: String,
     pub(crate) command_channel_size: usize,
+    foreign_key_check_on_commit: bool,
+    strict_bools: bool,
     #[cfg(feature = "regexp")]
     register_regexp_function: bool,
 }
@@ -159,33 +161,14 @@ impl EstablishParams {
             extensions,
             thread_name: (options.thread_name)(thread_id as u64),
             command_channel_size: options.command_channel_size,
+            foreign_key_check_on_commit: cfg!(debug_assertions)
+                && options.foreign_key_check_on_commit,
+            strict_bools: options.strict_bools,
             #[cfg(feature = "regexp")]
             register_regexp_function: options.register_regexp_function,
         })
     }
 
-    // Enable extension loading via the db_config function, as recommended by the docs rather
-    // than the more obvious `sqlite3_enable_load_extension`
-    // https://www.sqlite.org/c3ref/db_config.html
-    // https://www.sqlite.org/c3ref/c_dbconfig_defensive.html#sqlitedbconfigenableloadextension
-    unsafe fn sqlite3_set_load_extension(
-        db: *mut sqlite3,
-        mode: SqliteLoadExtensionMode,
-    ) -> Result<(), Error> {
-        let status = sqlite3_db_config(
-            db,
-            SQLITE_DBCONFIG_ENABLE_LOAD_EXTENSION,
-            mode.to_int(),
-            null::<i32>(),
-        );
-
-        if status != SQLITE_OK {
-            return Err(Error::Database(Box::new(SqliteError::new(db))));
-        }
-
-        Ok(())
-    }
-
     pub(crate) fn establish(&self) -> Result<ConnectionState, Error> {
         let mut handle = null_mut();
 
@@ -217,53 +200,8 @@ impl EstablishParams {
             sqlite3_extended_result_codes(handle.as_ptr(), 1);
         }
 
-        if !self.extensions.is_empty() {
-            // Enable loading extensions
-            unsafe {
-                Self::sqlite3_set_load_extension(handle.as_ptr(), SqliteLoadExtensionMode::Enable)?;
-            }
-
-            for ext in self.extensions.iter() {
-                // `sqlite3_load_extension` is unusual as it returns its errors via an out-pointer
-                // rather than by calling `sqlite3_errmsg`
-                let mut error = null_mut();
-                status = unsafe {
-                    sqlite3_load_extension(
-                        handle.as_ptr(),
-                        ext.0.as_ptr(),
-                        ext.1.as_ref().map_or(null(), |e| e.as_ptr()),
-                        addr_of_mut!(error),
-                    )
-                };
-
-                if status != SQLITE_OK {
-                    // SAFETY: We become responsible for any memory allocation at `&error`, so test
-                    // for null and take an RAII version for returns
-                    let err_msg = if !error.is_null() {
-                        unsafe {
-                            let e = CStr::from_ptr(error).into();
-                            sqlite3_free(error as *mut c_void);
-                            e
-                        }
-                    } else {
-                        CString::new("Unknown error when loading extension")
-                            .expect("text should be representable as a CString")
-                    };
-                    return Err(Error::Database(Box::new(SqliteError::extension(
-                        handle.as_ptr(),
-                        &err_msg,
-                    ))));
-                }
-            } // Preempt any hypothetical security issues arising from leaving ENABLE_LOAD_EXTENSION
-              // on by disabling the flag again once we've loaded all the requested modules.
-              // Fail-fast (via `?`) if disabling the extension loader didn't work for some reason,
-              // avoids an unexpected state going undetected.
-            unsafe {
-                Self::sqlite3_set_load_extension(
-                    handle.as_ptr(),
-                    SqliteLoadExtensionMode::DisableAll,
-                )?;
-            }
+        for (name, entry_point) in &self.extensions {
+            load_extension(&handle, name, entry_point.as_deref())?;
         }
 
         #[cfg(feature = "regexp")]
@@ -293,9 +231,91 @@ impl EstablishParams {
             handle,
             statements: Statements::new(self.statement_cache_capacity),
             transaction_depth: 0,
+            foreign_key_check_on_commit: self.foreign_key_check_on_commit,
+            strict_bools: self.strict_bools,
             log_settings: self.log_settings.clone(),
             progress_handler_callback: None,
             update_hook_callback: None,
+            commit_hook_callback: None,
+            rollback_hook_callback: None,
         })
     }
 }
+
+/// Load an extension into an open database, only enabling extension loading while it loads.
+pub(crate) fn load_extension(
+    handle: &ConnectionHandle,
+    name: &CStr,
+    entry_point: Option<&CStr>,
+) -> Result<(), Error> {
+    // Enable loading extensions
+    unsafe {
+        sqlite3_set_load_extension(handle.as_ptr(), SqliteLoadExtensionMode::Enable)?;
+    }
+
+    // `sqlite3_load_extension` is unusual as it returns its errors via an out-pointer
+    // rather than by calling `sqlite3_errmsg`
+    let mut error = null_mut();
+    let status = unsafe {
+        sqlite3_load_extension(
+            handle.as_ptr(),
+            name.as_ptr(),
+            entry_point.map_or(null(), |e| e.as_ptr()),
+            addr_of_mut!(error),
+        )
+    };
+
+    let result = if status != SQLITE_OK {
+        // SAFETY: We become responsible for any memory allocation at `&error`, so test
+        // for null and take an RAII version for returns
+        let err_msg = if !error.is_null() {
+            unsafe {
+                let e = CStr::from_ptr(error).into();
+                sqlite3_free(error as *mut c_void);
+                e
+            }
+        } else {
+            CString::new("Unknown error when loading extension")
+                .expect("text should be representable as a CString")
+        };
+
+        Err(Error::Database(Box::new(SqliteError::extension(
+            handle.as_ptr(),
+            &err_msg,
+        ))))
+    } else {
+        Ok(())
+    };
+
+    // Preempt any hypothetical security issues arising from leaving ENABLE_LOAD_EXTENSION
+    // on by disabling the flag again once the extension is loaded, or failed to load.
+    // Fail-fast (via `?`) if disabling the extension loader didn't work for some reason,
+    // avoids an unexpected state going undetected.
+    unsafe {
+        sqlite3_set_load_extension(handle.as_ptr(), SqliteLoadExtensionMode::DisableAll)?;
+    }
+
+    result
+}
+
+// Enable extension loading via the db_config function, as recommended by the docs rather
+// than the more obvious `sqlite3_enable_load_extension`
+// https://www.sqlite.org/c3ref/db_config.html
+// https://www.sqlite.org/c3ref/c_dbconfig_defensive.html#sqlitedbconfigenableloadextension
+unsafe fn sqlite3_set_load_extension(
+    db: *mut sqlite3,
+    mode: SqliteLoadExtensionMode,
+) -> Result<(), Error> {
+    let status = sqlite3_db_config(
+        db,
+        SQLITE_DBCONFIG_ENABLE_LOAD_EXTENSION,
+        mode.to_int(),
+        null::<i32>(),
+    );
+
+    if status != SQLITE_OK {
+        return Err(Error::Database(Box::new(SqliteError::new(db))));
+    }
+
+    Ok(())
+}
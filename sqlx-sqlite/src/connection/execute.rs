@@ -16,6 +16,11 @@ pub struct ExecuteIter<'a> {
     args_used: usize,
 
     goto_next: bool,
+
+    /// set once a step fails; stepping the statement again would execute it again
+    failed: bool,
+
+    strict_bools: bool,
 }
 
 pub(crate) fn iter<'a>(
@@ -27,7 +32,8 @@ pub(crate) fn iter<'a>(
     // fetch the cached statement or allocate a new one
     let statement = conn.statements.get(query, persistent)?;
 
-    let logger = QueryLogger::new(query, conn.log_settings.clone());
+    let num_arguments = args.as_ref().map_or(0, |args| args.values.len());
+    let logger = QueryLogger::new(query, num_arguments, conn.log_settings.clone());
 
     Ok(ExecuteIter {
         handle: &mut conn.handle,
@@ -36,6 +42,8 @@ pub(crate) fn iter<'a>(
         args,
         args_used: 0,
         goto_next: true,
+        failed: false,
+        strict_bools: conn.strict_bools,
     })
 }
 
@@ -67,6 +75,23 @@ impl Iterator for ExecuteIter<'_> {
     type Item = Result<Either<SqliteQueryResult, SqliteRow>, Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let item = self.step();
+
+        if let Some(Err(error)) = &item {
+            self.failed = true;
+            self.logger.record_error(error);
+        }
+
+        item
+    }
+}
+
+impl ExecuteIter<'_> {
+    fn step(&mut self) -> Option<<Self as Iterator>::Item> {
         let statement = if self.goto_next {
             let statement = match self.statement.prepare_next(self.handle) {
                 Ok(Some(statement)) => statement,
@@ -101,6 +126,7 @@ impl Iterator for ExecuteIter<'_> {
                     statement.handle,
                     statement.columns,
                     statement.column_names,
+                    self.strict_bools,
                 ))))
             }
             Ok(false) => {
@@ -29,10 +29,11 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
             Err(error) => return stream::once(future::ready(Err(error))).boxed(),
         };
         let persistent = query.persistent() && arguments.is_some();
+        let timeout = query.timeout();
 
         Box::pin(
             self.worker
-                .execute(sql, arguments, self.row_channel_size, persistent)
+                .execute(sql, arguments, self.row_channel_size, persistent, timeout)
                 .map_ok(flume::Receiver::into_stream)
                 .try_flatten_stream(),
         )
@@ -54,11 +55,12 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
             Err(error) => return future::ready(Err(error)).boxed(),
         };
         let persistent = query.persistent() && arguments.is_some();
+        let timeout = query.timeout();
 
         Box::pin(async move {
             let stream = self
                 .worker
-                .execute(sql, arguments, self.row_channel_size, persistent)
+                .execute(sql, arguments, self.row_channel_size, persistent, timeout)
                 .map_ok(flume::Receiver::into_stream)
                 .try_flatten_stream();
 
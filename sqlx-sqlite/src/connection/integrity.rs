@@ -0,0 +1,105 @@
+use sqlx_core::row::Row;
+use sqlx_core::Either;
+
+use crate::connection::{execute, ConnectionState};
+use crate::error::Error;
+use crate::query::query;
+use crate::{SqliteConnection, SqliteError, SqliteRow};
+
+/// A row which violates a foreign key constraint, as reported by
+/// [`PRAGMA foreign_key_check`](https://www.sqlite.org/pragma.html#pragma_foreign_key_check).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqliteForeignKeyViolation {
+    /// The table containing the row with the dangling reference.
+    pub table: String,
+    /// The `rowid` of the offending row, or `None` if `table` is a `WITHOUT ROWID` table.
+    pub rowid: Option<i64>,
+    /// The table that is referred to.
+    pub parent: String,
+    /// The index of the violated constraint in the output of `PRAGMA foreign_key_list(table)`.
+    pub fkid: i64,
+}
+
+/// A problem reported by [`PRAGMA integrity_check`](https://www.sqlite.org/pragma.html#pragma_integrity_check).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqliteIntegrityViolation {
+    /// The description of the problem, e.g. `NULL value in users.name`.
+    pub message: String,
+}
+
+impl SqliteForeignKeyViolation {
+    fn from_row(row: &SqliteRow) -> Result<Self, Error> {
+        Ok(Self {
+            table: row.try_get(0)?,
+            rowid: row.try_get(1)?,
+            parent: row.try_get(2)?,
+            fkid: row.try_get(3)?,
+        })
+    }
+}
+
+impl SqliteConnection {
+    /// Find the rows which violate a foreign key constraint, using
+    /// [`PRAGMA foreign_key_check`](https://www.sqlite.org/pragma.html#pragma_foreign_key_check).
+    ///
+    /// SQLite does not check existing rows when foreign key enforcement is turned on,
+    /// so this can find violations introduced while it was turned off.
+    ///
+    /// See also [`SqliteConnectOptions::foreign_key_check_on_commit()`][crate::SqliteConnectOptions::foreign_key_check_on_commit].
+    pub async fn foreign_key_check(&mut self) -> Result<Vec<SqliteForeignKeyViolation>, Error> {
+        query("PRAGMA foreign_key_check")
+            .fetch_all(self)
+            .await?
+            .iter()
+            .map(SqliteForeignKeyViolation::from_row)
+            .collect()
+    }
+
+    /// Check the database for corruption and constraint violations, using
+    /// [`PRAGMA integrity_check`](https://www.sqlite.org/pragma.html#pragma_integrity_check).
+    ///
+    /// Returns an empty `Vec` if no problems were found.
+    ///
+    /// Note that this does not check foreign key constraints;
+    /// use [`foreign_key_check()`][Self::foreign_key_check] for that.
+    ///
+    /// This reads the entire database, which can take a while. Like
+    /// [`vacuum_into()`][Self::vacuum_into], its progress can be followed with a progress handler.
+    pub async fn integrity_check(&mut self) -> Result<Vec<SqliteIntegrityViolation>, Error> {
+        let messages: Vec<String> = query("PRAGMA integrity_check")
+            .fetch_all(self)
+            .await?
+            .iter()
+            .map(|row| row.try_get(0))
+            .collect::<Result<_, _>>()?;
+
+        // a single row of `ok` is returned if there are no problems
+        if messages.len() == 1 && messages[0] == "ok" {
+            return Ok(Vec::new());
+        }
+
+        Ok(messages
+            .into_iter()
+            .map(|message| SqliteIntegrityViolation { message })
+            .collect())
+    }
+}
+
+/// Run on the worker thread before committing the outermost transaction.
+pub(crate) fn check_foreign_keys_before_commit(conn: &mut ConnectionState) -> Result<(), Error> {
+    let mut violations = Vec::new();
+
+    for res in execute::iter(conn, "PRAGMA foreign_key_check", None, false)? {
+        if let Either::Right(row) = res? {
+            violations.push(SqliteForeignKeyViolation::from_row(&row)?);
+        }
+    }
+
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    Err(Error::Database(Box::new(
+        SqliteError::foreign_key_violations(&violations),
+    )))
+}
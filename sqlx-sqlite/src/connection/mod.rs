@@ -6,16 +6,18 @@ use std::os::raw::{c_int, c_void};
 use std::panic::catch_unwind;
 use std::ptr;
 use std::ptr::NonNull;
+use std::time::Duration;
 
 use futures_core::future::BoxFuture;
 use futures_intrusive::sync::MutexGuard;
 use futures_util::future;
 use libsqlite3_sys::{
-    sqlite3, sqlite3_progress_handler, sqlite3_update_hook, SQLITE_DELETE, SQLITE_INSERT,
-    SQLITE_UPDATE,
+    sqlite3, sqlite3_commit_hook, sqlite3_get_autocommit, sqlite3_progress_handler,
+    sqlite3_rollback_hook, sqlite3_update_hook, SQLITE_DELETE, SQLITE_INSERT, SQLITE_UPDATE,
 };
 
 pub(crate) use handle::ConnectionHandle;
+pub use integrity::{SqliteForeignKeyViolation, SqliteIntegrityViolation};
 use sqlx_core::common::StatementCache;
 pub(crate) use sqlx_core::connection::*;
 use sqlx_core::error::Error;
@@ -35,7 +37,9 @@ pub(crate) mod execute;
 mod executor;
 mod explain;
 mod handle;
+mod integrity;
 pub(crate) mod intmap;
+mod vacuum;
 
 mod worker;
 
@@ -91,12 +95,34 @@ pub struct UpdateHookResult<'a> {
 pub(crate) struct UpdateHookHandler(NonNull<dyn FnMut(UpdateHookResult) + Send + 'static>);
 unsafe impl Send for UpdateHookHandler {}
 
+pub(crate) struct CommitHookHandler(NonNull<dyn FnMut() -> bool + Send + 'static>);
+unsafe impl Send for CommitHookHandler {}
+
+pub(crate) struct RollbackHookHandler(NonNull<dyn FnMut() + Send + 'static>);
+unsafe impl Send for RollbackHookHandler {}
+
+/// Whether a connection is in a transaction, returned by
+/// [`LockedSqliteHandle::transaction_state()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SqliteTransactionState {
+    /// No transaction is open; each statement is committed as soon as it completes.
+    Autocommit,
+    /// A transaction was opened with `BEGIN` and has not been committed or rolled back yet.
+    InTransaction,
+}
+
 pub(crate) struct ConnectionState {
     pub(crate) handle: ConnectionHandle,
 
     // transaction status
     pub(crate) transaction_depth: usize,
 
+    // run `PRAGMA foreign_key_check` before committing the outermost transaction
+    pub(crate) foreign_key_check_on_commit: bool,
+
+    // fail to decode integers other than 0 and 1 as `bool`
+    pub(crate) strict_bools: bool,
+
     pub(crate) statements: Statements,
 
     log_settings: LogSettings,
@@ -106,9 +132,34 @@ pub(crate) struct ConnectionState {
     progress_handler_callback: Option<Handler>,
 
     update_hook_callback: Option<UpdateHookHandler>,
+
+    commit_hook_callback: Option<CommitHookHandler>,
+
+    rollback_hook_callback: Option<RollbackHookHandler>,
 }
 
 impl ConnectionState {
+    pub(crate) fn set_progress_handler<F>(&mut self, num_ops: i32, callback: F)
+    where
+        F: FnMut() -> bool + Send + 'static,
+    {
+        unsafe {
+            let callback_boxed = Box::new(callback);
+            // SAFETY: `Box::into_raw()` always returns a non-null pointer.
+            let callback = NonNull::new_unchecked(Box::into_raw(callback_boxed));
+            let handler = callback.as_ptr() as *mut _;
+            self.remove_progress_handler();
+            self.progress_handler_callback = Some(Handler(callback));
+
+            sqlite3_progress_handler(
+                self.handle.as_ptr(),
+                num_ops,
+                Some(progress_callback::<F>),
+                handler,
+            );
+        }
+    }
+
     /// Drops the `progress_handler_callback` if it exists.
     pub(crate) fn remove_progress_handler(&mut self) {
         if let Some(mut handler) = self.progress_handler_callback.take() {
@@ -127,6 +178,24 @@ impl ConnectionState {
             }
         }
     }
+
+    pub(crate) fn remove_commit_hook(&mut self) {
+        if let Some(mut handler) = self.commit_hook_callback.take() {
+            unsafe {
+                sqlite3_commit_hook(self.handle.as_ptr(), None, ptr::null_mut());
+                let _ = { Box::from_raw(handler.0.as_mut()) };
+            }
+        }
+    }
+
+    pub(crate) fn remove_rollback_hook(&mut self) {
+        if let Some(mut handler) = self.rollback_hook_callback.take() {
+            unsafe {
+                sqlite3_rollback_hook(self.handle.as_ptr(), None, ptr::null_mut());
+                let _ = { Box::from_raw(handler.0.as_mut()) };
+            }
+        }
+    }
 }
 
 pub(crate) struct Statements {
@@ -156,6 +225,52 @@ impl SqliteConnection {
 
         Ok(LockedSqliteHandle { guard })
     }
+
+    /// Load an [extension](https://www.sqlite.org/loadext.html) into this connection.
+    ///
+    /// This is the run-time equivalent of
+    /// [`SqliteConnectOptions::extension_with_entrypoint()`], for connections which are already
+    /// open, such as in [`PoolOptions::after_connect()`][crate::pool::PoolOptions::after_connect].
+    /// If `entry_point` is `None`, SQLite derives it from the name of the extension.
+    ///
+    /// Extension loading is only enabled while the extension loads, so the `load_extension()`
+    /// SQL function stays disabled.
+    ///
+    /// ```rust,no_run
+    /// # use sqlx_sqlite::SqlitePoolOptions;
+    /// # async fn pool() -> Result<(), sqlx_core::error::Error> {
+    /// let pool = SqlitePoolOptions::new()
+    ///     .after_connect(|conn, _meta| {
+    ///         Box::pin(async move { conn.load_extension("mod_spatialite", None).await })
+    ///     })
+    ///     .connect("sqlite://data.db")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "extension")]
+    pub async fn load_extension(
+        &mut self,
+        name: &str,
+        entry_point: Option<&str>,
+    ) -> Result<(), Error> {
+        use std::ffi::CString;
+
+        let name = CString::new(name)
+            .map_err(|_| err_protocol!("extension names must not contain nul bytes: {name:?}"))?;
+
+        let entry_point = entry_point
+            .map(|e| {
+                CString::new(e).map_err(|_| {
+                    err_protocol!("extension entry points must not contain nul bytes: {e:?}")
+                })
+            })
+            .transpose()?;
+
+        let guard = self.worker.unlock_db().await?;
+
+        establish::load_extension(&guard.handle, &name, entry_point.as_deref())
+    }
 }
 
 impl Debug for SqliteConnection {
@@ -199,7 +314,7 @@ impl Connection for SqliteConnection {
     }
 
     /// Ensure the background worker thread is alive and accepting commands.
-    fn ping(&mut self) -> BoxFuture<'_, Result<(), Error>> {
+    fn ping(&mut self) -> BoxFuture<'_, Result<Duration, Error>> {
         Box::pin(self.worker.ping())
     }
 
@@ -284,6 +399,33 @@ extern "C" fn update_hook<F>(
     }
 }
 
+/// Implements a C binding to a commit hook. The function returns `0` if the user-provided
+/// callback returns `true`, and `1` otherwise to turn the commit into a rollback.
+extern "C" fn commit_hook<F>(callback: *mut c_void) -> c_int
+where
+    F: FnMut() -> bool,
+{
+    unsafe {
+        let r = catch_unwind(|| {
+            let callback: *mut F = callback.cast::<F>();
+            (*callback)()
+        });
+        c_int::from(!r.unwrap_or_default())
+    }
+}
+
+extern "C" fn rollback_hook<F>(callback: *mut c_void)
+where
+    F: FnMut(),
+{
+    unsafe {
+        let _ = catch_unwind(|| {
+            let callback: *mut F = callback.cast::<F>();
+            (*callback)()
+        });
+    }
+}
+
 impl LockedSqliteHandle<'_> {
     /// Returns the underlying sqlite3* connection handle.
     ///
@@ -330,44 +472,101 @@ impl LockedSqliteHandle<'_> {
     pub fn set_progress_handler<F>(&mut self, num_ops: i32, callback: F)
     where
         F: FnMut() -> bool + Send + 'static,
+    {
+        self.guard.set_progress_handler(num_ops, callback);
+    }
+
+    pub fn set_update_hook<F>(&mut self, callback: F)
+    where
+        F: FnMut(UpdateHookResult) + Send + 'static,
     {
         unsafe {
             let callback_boxed = Box::new(callback);
             // SAFETY: `Box::into_raw()` always returns a non-null pointer.
             let callback = NonNull::new_unchecked(Box::into_raw(callback_boxed));
             let handler = callback.as_ptr() as *mut _;
-            self.guard.remove_progress_handler();
-            self.guard.progress_handler_callback = Some(Handler(callback));
+            self.guard.remove_update_hook();
+            self.guard.update_hook_callback = Some(UpdateHookHandler(callback));
 
-            sqlite3_progress_handler(
+            sqlite3_update_hook(
                 self.as_raw_handle().as_mut(),
-                num_ops,
-                Some(progress_callback::<F>),
+                Some(update_hook::<F>),
                 handler,
             );
         }
     }
 
-    pub fn set_update_hook<F>(&mut self, callback: F)
+    /// Sets a commit hook that is invoked whenever a transaction is committed, including the
+    /// implicit transaction of a statement which writes outside of `BEGIN`.
+    ///
+    /// If the callback returns `false`, the commit is turned into a rollback.
+    ///
+    /// The callback runs on the worker thread while the commit is in progress, so it must not
+    /// block or use this connection; forward an event to a channel instead.
+    ///
+    /// Only a single commit hook may be defined at one time per database connection; setting a
+    /// new commit hook replaces the old one.
+    pub fn set_commit_hook<F>(&mut self, callback: F)
     where
-        F: FnMut(UpdateHookResult) + Send + 'static,
+        F: FnMut() -> bool + Send + 'static,
     {
         unsafe {
             let callback_boxed = Box::new(callback);
             // SAFETY: `Box::into_raw()` always returns a non-null pointer.
             let callback = NonNull::new_unchecked(Box::into_raw(callback_boxed));
             let handler = callback.as_ptr() as *mut _;
-            self.guard.remove_update_hook();
-            self.guard.update_hook_callback = Some(UpdateHookHandler(callback));
+            self.guard.remove_commit_hook();
+            self.guard.commit_hook_callback = Some(CommitHookHandler(callback));
 
-            sqlite3_update_hook(
+            sqlite3_commit_hook(
                 self.as_raw_handle().as_mut(),
-                Some(update_hook::<F>),
+                Some(commit_hook::<F>),
+                handler,
+            );
+        }
+    }
+
+    /// Sets a rollback hook that is invoked whenever a transaction is rolled back, including
+    /// when a commit hook turns a commit into a rollback.
+    ///
+    /// It is not invoked when the connection is closed with a transaction open.
+    ///
+    /// Like [`set_commit_hook()`][Self::set_commit_hook], the callback runs on the worker
+    /// thread and replaces any rollback hook set before.
+    pub fn set_rollback_hook<F>(&mut self, callback: F)
+    where
+        F: FnMut() + Send + 'static,
+    {
+        unsafe {
+            let callback_boxed = Box::new(callback);
+            // SAFETY: `Box::into_raw()` always returns a non-null pointer.
+            let callback = NonNull::new_unchecked(Box::into_raw(callback_boxed));
+            let handler = callback.as_ptr() as *mut _;
+            self.guard.remove_rollback_hook();
+            self.guard.rollback_hook_callback = Some(RollbackHookHandler(callback));
+
+            sqlite3_rollback_hook(
+                self.as_raw_handle().as_mut(),
+                Some(rollback_hook::<F>),
                 handler,
             );
         }
     }
 
+    /// Returns whether the connection is in a transaction.
+    ///
+    /// Unlike the transaction depth tracked by SQLx, this also reflects transactions opened or
+    /// closed by executing `BEGIN`, `COMMIT` or `ROLLBACK` directly.
+    pub fn transaction_state(&mut self) -> SqliteTransactionState {
+        let autocommit = unsafe { sqlite3_get_autocommit(self.as_raw_handle().as_mut()) };
+
+        if autocommit != 0 {
+            SqliteTransactionState::Autocommit
+        } else {
+            SqliteTransactionState::InTransaction
+        }
+    }
+
     /// Removes the progress handler on a database connection. The method does nothing if no handler was set.
     pub fn remove_progress_handler(&mut self) {
         self.guard.remove_progress_handler();
@@ -376,6 +575,16 @@ impl LockedSqliteHandle<'_> {
     pub fn remove_update_hook(&mut self) {
         self.guard.remove_update_hook();
     }
+
+    /// Removes the commit hook on a database connection. The method does nothing if no hook was set.
+    pub fn remove_commit_hook(&mut self) {
+        self.guard.remove_commit_hook();
+    }
+
+    /// Removes the rollback hook on a database connection. The method does nothing if no hook was set.
+    pub fn remove_rollback_hook(&mut self) {
+        self.guard.remove_rollback_hook();
+    }
 }
 
 impl Drop for ConnectionState {
@@ -384,6 +593,8 @@ impl Drop for ConnectionState {
         self.statements.clear();
         self.remove_progress_handler();
         self.remove_update_hook();
+        self.remove_commit_hook();
+        self.remove_rollback_hook();
     }
 }
 
@@ -0,0 +1,76 @@
+use std::path::Path;
+
+use sqlx_core::executor::Executor;
+
+use crate::error::Error;
+use crate::query::query;
+use crate::query_scalar::query_scalar;
+use crate::SqliteConnection;
+
+impl SqliteConnection {
+    /// Write a compacted copy of the main database to a new file at `path`, using
+    /// [`VACUUM INTO`](https://www.sqlite.org/lang_vacuum.html#vacuum_with_an_into_clause).
+    ///
+    /// Unlike copying the database file, this produces a consistent snapshot even while other
+    /// connections are writing to the database, so it can be used to make backups of a live
+    /// database. `path` must not exist, or be an empty file.
+    ///
+    /// Returns an error for an in-memory database, as SQLite would make the copy in memory too.
+    ///
+    /// The copy runs on the worker thread of this connection, so it does not block the async
+    /// runtime, but it does hold on to this connection until it completes. `VACUUM` cannot be
+    /// run within a transaction.
+    ///
+    /// ### Progress and Cancellation
+    /// For a large database this can take a while. Set a progress handler with
+    /// [`LockedSqliteHandle::set_progress_handler()`][crate::LockedSqliteHandle::set_progress_handler]
+    /// beforehand to be called periodically while it runs; returning `false` from the handler
+    /// cancels the copy.
+    ///
+    /// ```rust,no_run
+    /// # async fn example() -> sqlx_core::Result<()> {
+    /// # use sqlx_core::connection::Connection;
+    /// # use std::sync::atomic::{AtomicU64, Ordering};
+    /// # use std::sync::Arc;
+    /// let mut conn = sqlx_sqlite::SqliteConnection::connect("sqlite://data.db").await?;
+    ///
+    /// let steps = Arc::new(AtomicU64::new(0));
+    /// let progress = steps.clone();
+    ///
+    /// conn.lock_handle().await?.set_progress_handler(10_000, move || {
+    ///     progress.fetch_add(1, Ordering::Relaxed);
+    ///     true
+    /// });
+    ///
+    /// conn.vacuum_into("backup.db").await?;
+    ///
+    /// conn.lock_handle().await?.remove_progress_handler();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn vacuum_into(&mut self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        let path = path
+            .to_str()
+            .ok_or_else(|| err_protocol!("backup paths must be valid UTF-8: {path:?}"))?;
+
+        // the target is opened with the same flags as the main database,
+        // which would silently keep the copy in memory
+        let file: String =
+            query_scalar("SELECT file FROM pragma_database_list WHERE name = 'main'")
+                .fetch_one(&mut *self)
+                .await?;
+
+        if file.is_empty() {
+            return Err(err_protocol!(
+                "cannot VACUUM INTO a file from an in-memory database"
+            ));
+        }
+
+        // the target of `INTO` may be any expression, including a bind parameter
+        self.execute(query("VACUUM INTO ?").bind(path)).await?;
+
+        Ok(())
+    }
+}
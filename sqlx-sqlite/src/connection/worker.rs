@@ -3,6 +3,7 @@ use std::future::Future;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::thread;
+use std::time::{Duration, Instant};
 
 use futures_channel::oneshot;
 use futures_intrusive::sync::{Mutex, MutexGuard};
@@ -10,14 +11,17 @@ use tracing::span::Span;
 
 use sqlx_core::describe::Describe;
 use sqlx_core::error::Error;
+use sqlx_core::query_context::QueryContext;
 use sqlx_core::transaction::{
-    begin_ansi_transaction_sql, commit_ansi_transaction_sql, rollback_ansi_transaction_sql,
+    begin_ansi_transaction_sql, commit_ansi_transaction_sql, nested_transaction_options_error,
+    rollback_ansi_transaction_sql,
 };
 use sqlx_core::Either;
 
 use crate::connection::describe::describe;
 use crate::connection::establish::EstablishParams;
 use crate::connection::execute;
+use crate::connection::integrity;
 use crate::connection::ConnectionState;
 use crate::{Sqlite, SqliteArguments, SqliteQueryResult, SqliteRow, SqliteStatement};
 
@@ -27,8 +31,11 @@ use crate::{Sqlite, SqliteArguments, SqliteQueryResult, SqliteRow, SqliteStateme
 //       OS resource usage. Low priority because a high concurrent load for SQLite3 is very
 //       unlikely.
 
+// How often, in virtual machine instructions, the deadline of a query with a timeout is checked.
+const TIMEOUT_PROGRESS_OPS: i32 = 1000;
+
 pub(crate) struct ConnectionWorker {
-    command_tx: flume::Sender<(Command, tracing::Span)>,
+    command_tx: flume::Sender<(Command, tracing::Span, Option<QueryContext>)>,
     /// Mutex for locking access to the database.
     pub(crate) shared: Arc<WorkerSharedState>,
 }
@@ -51,10 +58,13 @@ enum Command {
         query: Box<str>,
         arguments: Option<SqliteArguments<'static>>,
         persistent: bool,
+        timeout: Option<Duration>,
         tx: flume::Sender<Result<Either<SqliteQueryResult, SqliteRow>, Error>>,
     },
     Begin {
         tx: rendezvous_oneshot::Sender<Result<(), Error>>,
+        // fail instead of establishing a savepoint if a transaction is already active
+        outermost: bool,
     },
     Commit {
         tx: rendezvous_oneshot::Sender<Result<(), Error>>,
@@ -115,8 +125,9 @@ impl ConnectionWorker {
                 // would rollback an already completed transaction.
                 let mut ignore_next_start_rollback = false;
 
-                for (cmd, span) in command_rx {
+                for (cmd, span, context) in command_rx {
                     let _guard = span.enter();
+                    let _context = context.map(QueryContext::enter);
                     match cmd {
                         Command::Prepare { query, tx } => {
                             tx.send(prepare(&mut conn, &query).map(|prepared| {
@@ -135,33 +146,55 @@ impl ConnectionWorker {
                             query,
                             arguments,
                             persistent,
+                            timeout,
                             tx,
                         } => {
-                            let iter = match execute::iter(&mut conn, &query, arguments, persistent)
-                            {
-                                Ok(iter) => iter,
-                                Err(e) => {
-                                    tx.send(Err(e)).ok();
+                            if let Some(timeout) = timeout {
+                                if conn.progress_handler_callback.is_some() {
+                                    tx.send(Err(Error::Configuration(
+                                        "per-query timeouts cannot be used while a progress handler is set".into(),
+                                    )))
+                                    .ok();
                                     continue;
                                 }
-                            };
 
-                            for res in iter {
-                                if tx.send(res).is_err() {
-                                    break;
+                                // interrupts the query once the deadline has passed
+                                let deadline = Instant::now() + timeout;
+                                conn.set_progress_handler(TIMEOUT_PROGRESS_OPS, move || {
+                                    Instant::now() < deadline
+                                });
+                            }
+
+                            match execute::iter(&mut conn, &query, arguments, persistent) {
+                                Ok(iter) => {
+                                    for res in iter {
+                                        if tx.send(res).is_err() {
+                                            break;
+                                        }
+                                    }
+                                }
+                                Err(e) => {
+                                    tx.send(Err(e)).ok();
                                 }
                             }
 
+                            if timeout.is_some() {
+                                conn.remove_progress_handler();
+                            }
+
                             update_cached_statements_size(&conn, &shared.cached_statements_size);
                         }
-                        Command::Begin { tx } => {
+                        Command::Begin { tx, outermost } => {
                             let depth = conn.transaction_depth;
-                            let res =
+                            let res = if outermost && depth > 0 {
+                                Err(nested_transaction_options_error())
+                            } else {
                                 conn.handle
                                     .exec(begin_ansi_transaction_sql(depth))
                                     .map(|_| {
                                         conn.transaction_depth += 1;
-                                    });
+                                    })
+                            };
                             let res_ok = res.is_ok();
 
                             if tx.blocking_send(res).is_err() && res_ok {
@@ -188,11 +221,15 @@ impl ConnectionWorker {
                             let depth = conn.transaction_depth;
 
                             let res = if depth > 0 {
-                                conn.handle
-                                    .exec(commit_ansi_transaction_sql(depth))
-                                    .map(|_| {
-                                        conn.transaction_depth -= 1;
-                                    })
+                                if depth == 1 && conn.foreign_key_check_on_commit {
+                                    integrity::check_foreign_keys_before_commit(&mut conn)
+                                } else {
+                                    Ok(())
+                                }
+                                .and_then(|_| conn.handle.exec(commit_ansi_transaction_sql(depth)))
+                                .map(|_| {
+                                    conn.transaction_depth -= 1;
+                                })
                             } else {
                                 Ok(())
                             };
@@ -284,6 +321,7 @@ impl ConnectionWorker {
         args: Option<SqliteArguments<'_>>,
         chan_size: usize,
         persistent: bool,
+        timeout: Option<Duration>,
     ) -> Result<flume::Receiver<Result<Either<SqliteQueryResult, SqliteRow>, Error>>, Error> {
         let (tx, rx) = flume::bounded(chan_size);
 
@@ -293,9 +331,11 @@ impl ConnectionWorker {
                     query: query.into(),
                     arguments: args.map(SqliteArguments::into_static),
                     persistent,
+                    timeout,
                     tx,
                 },
                 Span::current(),
+                QueryContext::current(),
             ))
             .await
             .map_err(|_| Error::WorkerCrashed)?;
@@ -303,8 +343,8 @@ impl ConnectionWorker {
         Ok(rx)
     }
 
-    pub(crate) async fn begin(&mut self) -> Result<(), Error> {
-        self.oneshot_cmd_with_ack(|tx| Command::Begin { tx })
+    pub(crate) async fn begin(&mut self, outermost: bool) -> Result<(), Error> {
+        self.oneshot_cmd_with_ack(|tx| Command::Begin { tx, outermost })
             .await?
     }
 
@@ -320,12 +360,20 @@ impl ConnectionWorker {
 
     pub(crate) fn start_rollback(&mut self) -> Result<(), Error> {
         self.command_tx
-            .send((Command::Rollback { tx: None }, Span::current()))
+            .send((
+                Command::Rollback { tx: None },
+                Span::current(),
+                QueryContext::current(),
+            ))
             .map_err(|_| Error::WorkerCrashed)
     }
 
-    pub(crate) async fn ping(&mut self) -> Result<(), Error> {
-        self.oneshot_cmd(|tx| Command::Ping { tx }).await
+    pub(crate) async fn ping(&mut self) -> Result<Duration, Error> {
+        let started_at = Instant::now();
+
+        self.oneshot_cmd(|tx| Command::Ping { tx }).await?;
+
+        Ok(started_at.elapsed())
     }
 
     async fn oneshot_cmd<F, T>(&mut self, command: F) -> Result<T, Error>
@@ -335,7 +383,7 @@ impl ConnectionWorker {
         let (tx, rx) = oneshot::channel();
 
         self.command_tx
-            .send_async((command(tx), Span::current()))
+            .send_async((command(tx), Span::current(), QueryContext::current()))
             .await
             .map_err(|_| Error::WorkerCrashed)?;
 
@@ -349,7 +397,7 @@ impl ConnectionWorker {
         let (tx, rx) = rendezvous_oneshot::channel();
 
         self.command_tx
-            .send_async((command(tx), Span::current()))
+            .send_async((command(tx), Span::current(), QueryContext::current()))
             .await
             .map_err(|_| Error::WorkerCrashed)?;
 
@@ -364,8 +412,11 @@ impl ConnectionWorker {
         let (guard, res) = futures_util::future::join(
             // we need to join the wait queue for the lock before we send the message
             self.shared.conn.lock(),
-            self.command_tx
-                .send_async((Command::UnlockDb, Span::current())),
+            self.command_tx.send_async((
+                Command::UnlockDb,
+                Span::current(),
+                QueryContext::current(),
+            )),
         )
         .await;
 
@@ -382,7 +433,11 @@ impl ConnectionWorker {
 
         let send_res = self
             .command_tx
-            .send((Command::Shutdown { tx }, Span::current()))
+            .send((
+                Command::Shutdown { tx },
+                Span::current(),
+                QueryContext::current(),
+            ))
             .map_err(|_| Error::WorkerCrashed);
 
         async move {
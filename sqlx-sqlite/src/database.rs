@@ -1,4 +1,4 @@
-pub(crate) use sqlx_core::database::{Database, HasStatementCache};
+pub(crate) use sqlx_core::database::{Database, HasBindLimit, HasReturningId, HasStatementCache};
 
 use crate::{
     SqliteArgumentValue, SqliteArguments, SqliteColumn, SqliteConnection, SqliteQueryResult,
@@ -37,3 +37,18 @@ impl Database for Sqlite {
 }
 
 impl HasStatementCache for Sqlite {}
+
+impl HasReturningId for Sqlite {
+    fn supports_returning(_conn: &SqliteConnection) -> bool {
+        true
+    }
+
+    fn last_insert_id(_result: &SqliteQueryResult) -> Option<Self::Value> {
+        None
+    }
+}
+
+impl HasBindLimit for Sqlite {
+    // `SQLITE_LIMIT_VARIABLE_NUMBER` since 3.32.0; it was 999 before
+    const BIND_LIMIT: usize = 32766;
+}
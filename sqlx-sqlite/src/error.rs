@@ -1,17 +1,19 @@
 use std::error::Error as StdError;
 use std::ffi::CStr;
-use std::fmt::{self, Display, Formatter};
+use std::fmt::{self, Display, Formatter, Write};
 use std::os::raw::c_int;
 use std::{borrow::Cow, str::from_utf8_unchecked};
 
 use libsqlite3_sys::{
-    sqlite3, sqlite3_errmsg, sqlite3_extended_errcode, SQLITE_CONSTRAINT_CHECK,
-    SQLITE_CONSTRAINT_FOREIGNKEY, SQLITE_CONSTRAINT_NOTNULL, SQLITE_CONSTRAINT_PRIMARYKEY,
-    SQLITE_CONSTRAINT_UNIQUE,
+    sqlite3, sqlite3_errmsg, sqlite3_errstr, sqlite3_extended_errcode, SQLITE_BUSY,
+    SQLITE_CONSTRAINT_CHECK, SQLITE_CONSTRAINT_FOREIGNKEY, SQLITE_CONSTRAINT_NOTNULL,
+    SQLITE_CONSTRAINT_PRIMARYKEY, SQLITE_CONSTRAINT_UNIQUE,
 };
 
 pub(crate) use sqlx_core::error::*;
 
+use crate::SqliteForeignKeyViolation;
+
 // Error Codes And Messages
 // https://www.sqlite.org/c3ref/errcode.html
 
@@ -40,6 +42,43 @@ impl SqliteError {
         }
     }
 
+    /// An error returned by an SQLite function without a connection.
+    pub(crate) fn from_code(code: c_int) -> Self {
+        // returns English-language text that describes the result code
+        let message =
+            unsafe { from_utf8_unchecked(CStr::from_ptr(sqlite3_errstr(code)).to_bytes()) };
+
+        Self {
+            code,
+            message: message.to_owned(),
+        }
+    }
+
+    /// A foreign key violation found by `PRAGMA foreign_key_check` before committing.
+    pub(crate) fn foreign_key_violations(violations: &[SqliteForeignKeyViolation]) -> Self {
+        let mut message = format!(
+            "FOREIGN KEY constraint failed: `PRAGMA foreign_key_check` reported {} violation(s)",
+            violations.len()
+        );
+
+        for violation in violations {
+            let _ = write!(
+                message,
+                "; {} (rowid {}) references {}",
+                violation.table,
+                violation
+                    .rowid
+                    .map_or_else(|| "NULL".to_string(), |rowid| rowid.to_string()),
+                violation.parent
+            );
+        }
+
+        Self {
+            code: SQLITE_CONSTRAINT_FOREIGNKEY,
+            message,
+        }
+    }
+
     /// For errors during extension load, the error message is supplied via a separate pointer
     pub(crate) fn extension(handle: *mut sqlite3, error_msg: &CStr) -> Self {
         let mut err = Self::new(handle);
@@ -87,6 +126,11 @@ impl DatabaseError for SqliteError {
         self
     }
 
+    fn is_retryable(&self) -> bool {
+        // the primary result code is the lower 8 bits of the extended one
+        self.code & 0xff == SQLITE_BUSY
+    }
+
     fn kind(&self) -> ErrorKind {
         match self.code {
             SQLITE_CONSTRAINT_UNIQUE | SQLITE_CONSTRAINT_PRIMARYKEY => ErrorKind::UniqueViolation,
@@ -33,7 +33,10 @@ use std::sync::atomic::AtomicBool;
 
 pub use arguments::{SqliteArgumentValue, SqliteArguments};
 pub use column::SqliteColumn;
-pub use connection::{LockedSqliteHandle, SqliteConnection, SqliteOperation, UpdateHookResult};
+pub use connection::{
+    LockedSqliteHandle, SqliteConnection, SqliteForeignKeyViolation, SqliteIntegrityViolation,
+    SqliteOperation, SqliteTransactionState, UpdateHookResult,
+};
 pub use database::Sqlite;
 pub use error::SqliteError;
 pub use options::{
@@ -41,6 +44,7 @@ pub use options::{
 };
 pub use query_result::SqliteQueryResult;
 pub use row::SqliteRow;
+pub use rw_pool::{SqlitePoolOptionsExt, SqliteRwPool, SqliteRwPoolOptions};
 pub use statement::SqliteStatement;
 pub use transaction::SqliteTransactionManager;
 pub use type_info::SqliteTypeInfo;
@@ -63,12 +67,17 @@ mod logger;
 mod options;
 mod query_result;
 mod row;
+mod rw_pool;
+mod sequence;
+#[cfg(feature = "json")]
+mod serialize;
 mod statement;
 mod transaction;
 mod type_checking;
 mod type_info;
 pub mod types;
 mod value;
+pub mod vfs;
 
 #[cfg(feature = "any")]
 pub mod any;
@@ -4,8 +4,11 @@ use log::LevelFilter;
 use sqlx_core::connection::ConnectOptions;
 use sqlx_core::error::Error;
 use sqlx_core::executor::Executor;
+use sqlx_core::logger::QueryLogSink;
+use sqlx_core::statement_stats::StatementStats;
 use std::fmt::Write;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
 use url::Url;
 
@@ -46,6 +49,10 @@ impl ConnectOptions for SqliteConnectOptions {
                 }
             }
 
+            for command in &self.init_commands {
+                conn.execute(&**command).await?;
+            }
+
             Ok(conn)
         })
     }
@@ -59,6 +66,21 @@ impl ConnectOptions for SqliteConnectOptions {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
+
+    fn query_logger(mut self, logger: Arc<dyn QueryLogSink>) -> Self {
+        self.log_settings.set_query_logger(logger);
+        self
+    }
+
+    fn log_query_fingerprints(mut self, enabled: bool) -> Self {
+        self.log_settings.log_query_fingerprints(enabled);
+        self
+    }
+
+    fn statement_stats(mut self, stats: StatementStats) -> Self {
+        self.log_settings.set_statement_stats(stats);
+        self
+    }
 }
 
 impl SqliteConnectOptions {
@@ -82,6 +82,12 @@ pub struct SqliteConnectOptions {
 
     pub(crate) optimize_on_close: OptimizeOnClose,
 
+    pub(crate) foreign_key_check_on_commit: bool,
+
+    pub(crate) strict_bools: bool,
+
+    pub(crate) init_commands: Vec<String>,
+
     #[cfg(feature = "regexp")]
     pub(crate) register_regexp_function: bool,
 }
@@ -204,6 +210,9 @@ impl SqliteConnectOptions {
             command_channel_size: 50,
             row_channel_size: 50,
             optimize_on_close: OptimizeOnClose::Disabled,
+            foreign_key_check_on_commit: false,
+            strict_bools: false,
+            init_commands: Vec::new(),
             #[cfg(feature = "regexp")]
             register_regexp_function: false,
         }
@@ -232,6 +241,37 @@ impl SqliteConnectOptions {
         self.pragma("foreign_keys", if on { "ON" } else { "OFF" })
     }
 
+    /// Check for foreign key violations before committing a transaction, in debug builds only.
+    ///
+    /// SQLite silently allows rows which violate a foreign key constraint to be inserted while
+    /// enforcement is turned off with [`foreign_keys(false)`][Self::foreign_keys].
+    /// With this enabled, committing the outermost transaction first runs
+    /// [`PRAGMA foreign_key_check`](https://www.sqlite.org/pragma.html#pragma_foreign_key_check)
+    /// and fails with a foreign key violation if it reports any rows,
+    /// leaving the transaction open so it can be rolled back.
+    ///
+    /// This has no effect in release builds (without `debug_assertions`).
+    ///
+    /// By default, this is disabled.
+    ///
+    /// See also [`SqliteConnection::foreign_key_check()`][crate::SqliteConnection::foreign_key_check].
+    pub fn foreign_key_check_on_commit(mut self, on: bool) -> Self {
+        self.foreign_key_check_on_commit = on;
+        self
+    }
+
+    /// Make decoding a `bool` fail for integers other than `0` and `1`.
+    ///
+    /// SQLite has no actual boolean type, so booleans are stored as integers, and a `BOOLEAN`
+    /// column can hold any value. By default, any integer other than `0` decodes as `true`;
+    /// with this enabled, such values are an error instead.
+    ///
+    /// By default, this is disabled.
+    pub fn strict_bools(mut self, on: bool) -> Self {
+        self.strict_bools = on;
+        self
+    }
+
     /// Set the [`SQLITE_OPEN_MEMORY` flag](https://sqlite.org/c3ref/open.html).
     ///
     /// By default, this is disabled.
@@ -355,6 +395,27 @@ impl SqliteConnectOptions {
         self
     }
 
+    /// Adds a statement to execute on every new connection, after the pragmas and collations
+    /// set by these options.
+    ///
+    /// The statements are executed in the order they were added. They can also be given with
+    /// the `init-command` parameter of the connection URL, which may be repeated.
+    ///
+    /// ```rust
+    /// # use sqlx_sqlite::SqliteConnectOptions;
+    /// let options = SqliteConnectOptions::new()
+    ///     .init_command("CREATE TEMP TABLE scratch (id INTEGER PRIMARY KEY, value TEXT)");
+    /// ```
+    pub fn init_command(mut self, sql: impl Into<String>) -> Self {
+        self.init_commands.push(sql.into());
+        self
+    }
+
+    /// Get the statements executed on every new connection.
+    pub fn get_init_commands(&self) -> &[String] {
+        &self.init_commands
+    }
+
     /// Add a custom collation for comparing strings in SQL.
     ///
     /// If a collation with the same name already exists, it will be replaced.
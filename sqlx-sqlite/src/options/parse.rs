@@ -100,6 +100,8 @@ impl SqliteConnectOptions {
 
                     "vfs" => options.vfs = Some(Cow::Owned(value.into_owned())),
 
+                    "init-command" => options.init_commands.push(value.into_owned()),
+
                     _ => {
                         return Err(Error::Configuration(
                             format!("unknown query parameter `{key}` while parsing connection URL")
@@ -140,6 +142,10 @@ impl SqliteConnectOptions {
             url.query_pairs_mut().append_pair("vfs", vfs);
         }
 
+        for command in &self.init_commands {
+            url.query_pairs_mut().append_pair("init-command", command);
+        }
+
         url
     }
 }
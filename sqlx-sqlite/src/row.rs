@@ -32,6 +32,7 @@ impl SqliteRow {
         statement: &StatementHandle,
         columns: &Arc<Vec<SqliteColumn>>,
         column_names: &Arc<HashMap<UStr, usize>>,
+        strict_bools: bool,
     ) -> Self {
         let size = statement.column_count();
         let mut values = Vec::with_capacity(size);
@@ -40,7 +41,7 @@ impl SqliteRow {
             values.push(unsafe {
                 let raw = statement.column_value(i);
 
-                SqliteValue::new(raw, columns[i].type_info.clone())
+                SqliteValue::new(raw, columns[i].type_info.clone(), strict_bools)
             });
         }
 
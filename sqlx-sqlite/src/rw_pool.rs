@@ -0,0 +1,224 @@
+use std::cmp;
+
+use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
+use sqlx_core::describe::Describe;
+use sqlx_core::error::Error;
+use sqlx_core::executor::{Execute, Executor};
+use sqlx_core::pool::private_is_read_only;
+use sqlx_core::transaction::Transaction;
+use sqlx_core::Either;
+
+use crate::{
+    Sqlite, SqliteConnectOptions, SqliteJournalMode, SqlitePool, SqlitePoolOptions,
+    SqliteQueryResult, SqliteRow, SqliteStatement, SqliteTypeInfo,
+};
+
+/// Implements [`reader_writer_split()`][Self::reader_writer_split] on [`SqlitePoolOptions`].
+pub trait SqlitePoolOptionsExt {
+    /// Connect one connection for writing, and up to `max_connections` read-only connections,
+    /// to the same database in WAL mode.
+    ///
+    /// SQLite only allows one writer at a time, so with a regular pool, connections which want to
+    /// write wait on each other with `SQLITE_BUSY` and retries. In WAL mode, readers don't block
+    /// the writer or each other, so [`SqliteRwPool`] sends reads to the readers and queues writes
+    /// for the single writer instead.
+    ///
+    /// The other options apply to both the readers and the writer, except that the writer is
+    /// capped at one connection.
+    fn reader_writer_split(self) -> SqliteRwPoolOptions;
+}
+
+impl SqlitePoolOptionsExt for SqlitePoolOptions {
+    fn reader_writer_split(self) -> SqliteRwPoolOptions {
+        SqliteRwPoolOptions { options: self }
+    }
+}
+
+/// Options to connect a [`SqliteRwPool`], from
+/// [`SqlitePoolOptionsExt::reader_writer_split()`].
+#[derive(Debug, Clone)]
+pub struct SqliteRwPoolOptions {
+    options: SqlitePoolOptions,
+}
+
+impl SqliteRwPoolOptions {
+    /// Connect to the database file at `url`.
+    pub async fn connect(self, url: &str) -> Result<SqliteRwPool, Error> {
+        self.connect_with(url.parse()?).await
+    }
+
+    /// Connect to the database file of `options`.
+    ///
+    /// The journal mode is set to WAL unless another mode was set explicitly.
+    /// The readers are opened read-only, whatever [`SqliteConnectOptions::read_only()`] is set to.
+    pub async fn connect_with(self, options: SqliteConnectOptions) -> Result<SqliteRwPool, Error> {
+        if options.in_memory {
+            return Err(Error::Configuration(
+                "reader_writer_split() requires a database file; \
+                 every in-memory connection would open a different database"
+                    .into(),
+            ));
+        }
+
+        let mut writer_options = options.clone().read_only(false);
+
+        if matches!(
+            writer_options.pragmas.get("journal_mode"),
+            None | Some(None)
+        ) {
+            writer_options = writer_options.journal_mode(SqliteJournalMode::Wal);
+        }
+
+        // changing the journal mode is a write, so it is left to the writer
+        let mut reader_options = options.read_only(true);
+        reader_options.pragmas.insert("journal_mode".into(), None);
+
+        let min_writers = cmp::min(self.options.get_min_connections(), 1);
+
+        // the writer connects first so the database exists and is in WAL mode for the readers
+        let writer = self
+            .options
+            .clone()
+            .max_connections(1)
+            .min_connections(min_writers)
+            .connect_with(writer_options)
+            .await?;
+
+        let reader = match self.options.connect_with(reader_options).await {
+            Ok(reader) => reader,
+            Err(e) => {
+                writer.close().await;
+                return Err(e);
+            }
+        };
+
+        Ok(SqliteRwPool { reader, writer })
+    }
+}
+
+/// A pool of one writer and many readers of the same SQLite database in WAL mode,
+/// from [`SqlitePoolOptionsExt::reader_writer_split()`].
+///
+/// When used as an [`Executor`], a query that only reads goes to the readers, and anything else
+/// to the writer. Queries are routed by their SQL without parsing it, like
+/// [`ReplicaPool`][sqlx_core::pool::ReplicaPool]: a query is only sent to the readers if every
+/// statement in it starts with `SELECT`, `VALUES`, `WITH` or `SHOW` and doesn't mention `INSERT`,
+/// `UPDATE`, `DELETE`, `REPLACE`, `MERGE`, `INTO` or `SHARE`. The readers are read-only
+/// connections, so a write that is routed to them anyway fails instead of being executed, and
+/// unlike with a `ReplicaPool`, queries calling functions are sent to them as well.
+///
+/// Transactions are always started on the writer. Use [`reader()`][Self::reader] or
+/// [`writer()`][Self::writer] to pick a pool explicitly.
+///
+/// ```rust,no_run
+/// # async fn example() -> sqlx::Result<()> {
+/// use sqlx::sqlite::{SqlitePoolOptions, SqlitePoolOptionsExt};
+///
+/// let pool = SqlitePoolOptions::new()
+///     .max_connections(8)
+///     .reader_writer_split()
+///     .connect("sqlite://app.db?mode=rwc")
+///     .await?;
+///
+/// // executed by the writer
+/// sqlx::query("INSERT INTO events (name) VALUES (?)")
+///     .bind("login")
+///     .execute(&pool)
+///     .await?;
+///
+/// // executed by one of the readers, in parallel with writes
+/// let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM events")
+///     .fetch_one(&pool)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SqliteRwPool {
+    reader: SqlitePool,
+    writer: SqlitePool,
+}
+
+impl SqliteRwPool {
+    /// The pool of read-only connections.
+    pub fn reader(&self) -> &SqlitePool {
+        &self.reader
+    }
+
+    /// The pool of the single connection that writes.
+    pub fn writer(&self) -> &SqlitePool {
+        &self.writer
+    }
+
+    /// Start a transaction on the writer.
+    pub async fn begin(&self) -> Result<Transaction<'static, Sqlite>, Error> {
+        self.writer.begin().await
+    }
+
+    /// Close the readers and the writer.
+    pub async fn close(&self) {
+        self.reader.close().await;
+        self.writer.close().await;
+    }
+
+    /// Returns `true` if [`close()`][Self::close] has been called.
+    pub fn is_closed(&self) -> bool {
+        self.reader.is_closed() || self.writer.is_closed()
+    }
+
+    fn route(&self, sql: &str) -> &SqlitePool {
+        // unlike replicas, the readers are read-only connections, so a function which writes
+        // fails there instead of being executed
+        if private_is_read_only(sql, true) {
+            &self.reader
+        } else {
+            &self.writer
+        }
+    }
+}
+
+impl<'p> Executor<'p> for &'p SqliteRwPool {
+    type Database = Sqlite;
+
+    fn fetch_many<'e, 'q: 'e, E>(
+        self,
+        query: E,
+    ) -> BoxStream<'e, Result<Either<SqliteQueryResult, SqliteRow>, Error>>
+    where
+        'p: 'e,
+        E: 'q + Execute<'q, Sqlite>,
+    {
+        self.route(query.sql()).fetch_many(query)
+    }
+
+    fn fetch_optional<'e, 'q: 'e, E>(
+        self,
+        query: E,
+    ) -> BoxFuture<'e, Result<Option<SqliteRow>, Error>>
+    where
+        'p: 'e,
+        E: 'q + Execute<'q, Sqlite>,
+    {
+        self.route(query.sql()).fetch_optional(query)
+    }
+
+    fn prepare_with<'e, 'q: 'e>(
+        self,
+        sql: &'q str,
+        parameters: &'e [SqliteTypeInfo],
+    ) -> BoxFuture<'e, Result<SqliteStatement<'q>, Error>>
+    where
+        'p: 'e,
+    {
+        self.route(sql).prepare_with(sql, parameters)
+    }
+
+    #[doc(hidden)]
+    fn describe<'e, 'q: 'e>(self, sql: &'q str) -> BoxFuture<'e, Result<Describe<Sqlite>, Error>>
+    where
+        'p: 'e,
+    {
+        self.route(sql).describe(sql)
+    }
+}
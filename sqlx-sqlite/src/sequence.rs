@@ -0,0 +1,41 @@
+use futures_core::future::BoxFuture;
+
+use sqlx_core::executor::Executor;
+use sqlx_core::sequence::SequenceSupport;
+
+use crate::error::Error;
+use crate::query_scalar::query_scalar;
+use crate::{Sqlite, SqliteConnection};
+
+impl SequenceSupport for Sqlite {
+    fn next_sequence_values<'c>(
+        conn: &'c mut SqliteConnection,
+        name: &'c str,
+        n: u32,
+    ) -> BoxFuture<'c, Result<Vec<i64>, Error>> {
+        Box::pin(async move {
+            // language=SQLite
+            conn.execute(
+                "CREATE TABLE IF NOT EXISTS _sqlx_sequences (
+                    name TEXT PRIMARY KEY,
+                    value INTEGER NOT NULL
+                )",
+            )
+            .await?;
+
+            // The upsert is a single statement, so it is atomic even outside of a transaction.
+            // language=SQLite
+            let last: i64 = query_scalar(
+                "INSERT INTO _sqlx_sequences (name, value) VALUES (?1, ?2) \
+                 ON CONFLICT (name) DO UPDATE SET value = value + excluded.value \
+                 RETURNING value",
+            )
+            .bind(name)
+            .bind(i64::from(n))
+            .fetch_one(&mut *conn)
+            .await?;
+
+            Ok((last - i64::from(n) + 1..=last).collect())
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use std::borrow::Cow;
+
+use sqlx_core::row::{ColumnValue, Row, SerializeColumns, SerializeRow};
+
+use crate::error::BoxDynError;
+use crate::type_info::DataType;
+use crate::value::ValueRef;
+use crate::SqliteRow;
+
+/// Values are serialized by their storage class, except that integers in columns declared as
+/// `BOOLEAN` are serialized as booleans, and integers in columns declared as `DATETIME` as
+/// timestamps (the number of seconds since 1970-01-01 00:00:00 UTC). Dates and times stored as
+/// text are serialized as they are stored.
+impl SerializeColumns for SqliteRow {
+    fn column_value(&self, index: usize) -> Result<ColumnValue<'_>, BoxDynError> {
+        let declared = &self.columns()[index].type_info.0;
+        let value = self.try_get_raw(index)?;
+
+        Ok(match value.type_info().0 {
+            DataType::Null => ColumnValue::Null,
+
+            DataType::Integer | DataType::Int4 | DataType::Bool => match declared {
+                DataType::Bool => ColumnValue::Bool(value.int64() != 0),
+                DataType::Datetime => ColumnValue::Timestamp {
+                    micros: value
+                        .int64()
+                        .checked_mul(1_000_000)
+                        .ok_or("timestamp is out of range")?,
+                    utc: true,
+                },
+                _ => ColumnValue::Int(value.int64()),
+            },
+
+            DataType::Float => ColumnValue::Float(value.double()),
+            DataType::Blob => ColumnValue::Bytes(Cow::Borrowed(value.blob())),
+            _ => ColumnValue::Text(Cow::Borrowed(value.text()?)),
+        })
+    }
+}
+
+impl serde::Serialize for SqliteRow {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializeRow::new(self).serialize(serializer)
+    }
+}
@@ -121,6 +121,24 @@ impl StatementHandle {
         }
     }
 
+    /// The schema and the name of the table the column is from,
+    /// or `None` if the column is an expression.
+    pub(crate) fn column_table(&self, index: usize) -> Option<(String, String)> {
+        unsafe {
+            let db_name = sqlite3_column_database_name(self.0.as_ptr(), index as c_int);
+            let table_name = sqlite3_column_table_name(self.0.as_ptr(), index as c_int);
+
+            if db_name.is_null() || table_name.is_null() {
+                return None;
+            }
+
+            Some((
+                CStr::from_ptr(db_name).to_string_lossy().into_owned(),
+                CStr::from_ptr(table_name).to_string_lossy().into_owned(),
+            ))
+        }
+    }
+
     pub(crate) fn column_nullable(&self, index: usize) -> Result<Option<bool>, Error> {
         unsafe {
             // https://sqlite.org/c3ref/column_database_name.html
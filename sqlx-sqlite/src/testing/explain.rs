@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use futures_core::future::BoxFuture;
+
+use crate::error::Error;
+use crate::query_as::query_as;
+use crate::SqliteConnection;
+
+use super::plan::{ExplainPlan, QueryPlan};
+
+impl ExplainPlan for SqliteConnection {
+    fn explain_plan<'e>(&'e mut self, query: &'e str) -> BoxFuture<'e, Result<QueryPlan, Error>> {
+        Box::pin(async move {
+            let explain = format!("EXPLAIN QUERY PLAN {query}");
+
+            let rows: Vec<(i64, i64, i64, String)> = query_as(&explain).fetch_all(self).await?;
+
+            // every node refers to its parent by `id`; the root nodes have a parent of 0
+            let mut depths = HashMap::with_capacity(rows.len());
+            let mut lines = Vec::with_capacity(rows.len());
+
+            for (id, parent, _, detail) in rows {
+                let depth = depths.get(&parent).map_or(0, |depth| depth + 1);
+                depths.insert(id, depth);
+
+                lines.push(format!("{}{detail}", "  ".repeat(depth)));
+            }
+
+            Ok(QueryPlan::new(lines))
+        })
+    }
+}
@@ -7,6 +7,8 @@ use std::path::{Path, PathBuf};
 
 pub(crate) use sqlx_core::testing::*;
 
+mod explain;
+
 const BASE_PATH: &str = "target/sqlx/test-dbs";
 
 impl TestSupport for Sqlite {
@@ -2,7 +2,7 @@ use futures_core::future::BoxFuture;
 
 use crate::{Sqlite, SqliteConnection};
 use sqlx_core::error::Error;
-use sqlx_core::transaction::TransactionManager;
+use sqlx_core::transaction::{AccessMode, TransactionManager, TransactionOptions};
 
 /// Implementation of [`TransactionManager`] for SQLite.
 pub struct SqliteTransactionManager;
@@ -11,7 +11,29 @@ impl TransactionManager for SqliteTransactionManager {
     type Database = Sqlite;
 
     fn begin(conn: &mut SqliteConnection) -> BoxFuture<'_, Result<(), Error>> {
-        Box::pin(conn.worker.begin())
+        Box::pin(conn.worker.begin(false))
+    }
+
+    fn begin_with(
+        conn: &mut SqliteConnection,
+        options: TransactionOptions,
+    ) -> BoxFuture<'_, Result<(), Error>> {
+        Box::pin(async move {
+            // SQLite transactions are always `SERIALIZABLE`, which satisfies every other level
+            if options.get_access_mode() == Some(AccessMode::ReadOnly) {
+                return Err(Error::Configuration(
+                    "SQLite does not support read-only transactions".into(),
+                ));
+            }
+
+            if options.get_deferrable() {
+                return Err(Error::Configuration(
+                    "SQLite does not support deferrable transactions".into(),
+                ));
+            }
+
+            conn.worker.begin(true).await
+        })
     }
 
     fn commit(conn: &mut SqliteConnection) -> BoxFuture<'_, Result<(), Error>> {
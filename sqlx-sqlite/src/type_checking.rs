@@ -1,8 +1,12 @@
-#[allow(unused_imports)]
-use sqlx_core as sqlx;
-
 use crate::Sqlite;
 
+// The paths used below will also be emitted by the macros so they have to match the final facade.
+#[allow(unused_imports, dead_code)]
+mod sqlx {
+    pub use crate as sqlite;
+    pub use sqlx_core::*;
+}
+
 // f32 is not included below as REAL represents a floating point value
 // stored as an 8-byte IEEE floating point number (i.e. an f64)
 // For more info see: https://www.sqlite.org/datatype3.html#storage_classes_and_datatypes
@@ -19,6 +23,7 @@ impl_type_checking!(
         f64,
         String,
         Vec<u8>,
+        sqlx::sqlite::types::SqliteDynamicValue,
 
         #[cfg(all(feature = "chrono", not(feature = "time")))]
         sqlx::types::chrono::NaiveDate,
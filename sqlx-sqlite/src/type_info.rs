@@ -35,6 +35,8 @@ pub(crate) enum DataType {
     Date,
     Time,
     Datetime,
+    /// Chosen if the column's declared type is `ANY` and it belongs to a `STRICT` table.
+    Any,
 }
 
 /// Type information for a SQLite type.
@@ -67,6 +69,7 @@ impl TypeInfo for SqliteTypeInfo {
             DataType::Date => "DATE",
             DataType::Time => "TIME",
             DataType::Datetime => "DATETIME",
+            DataType::Any => "ANY",
         }
     }
 }
@@ -103,6 +106,8 @@ impl FromStr for DataType {
             "time" => DataType::Time,
             "datetime" | "timestamp" => DataType::Datetime,
 
+            "any" => DataType::Any,
+
             _ if s.contains("int") => DataType::Integer,
 
             _ if s.contains("char") || s.contains("clob") || s.contains("text") => DataType::Text,
@@ -157,5 +162,7 @@ fn test_data_type_from_str() -> Result<(), BoxDynError> {
     assert_eq!(DataType::Time, "TIME".parse()?);
     assert_eq!(DataType::Date, "DATE".parse()?);
 
+    assert_eq!(DataType::Any, "ANY".parse()?);
+
     Ok(())
 }
@@ -0,0 +1,113 @@
+//! The representations of the `Any` driver for decimals, `DATE`, `TIME`, `DATETIME` and UUIDs.
+//!
+//! SQLite has no storage classes for these, so they're stored as `TEXT`, except for UUIDs which
+//! are stored as a `BLOB`.
+
+use std::borrow::Cow;
+
+use sqlx_core::any::{AnyDate, AnyDecimal, AnyTime, AnyTimestamp, AnyUuid};
+use sqlx_core::value::ValueRef;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::type_info::DataType;
+use crate::types::Type;
+use crate::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+
+impl Type<Sqlite> for AnyDecimal {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo(DataType::Text)
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        matches!(
+            ty.0,
+            DataType::Text | DataType::Integer | DataType::Int4 | DataType::Float
+        )
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for AnyDecimal {
+    fn encode_by_ref(
+        &self,
+        args: &mut Vec<SqliteArgumentValue<'q>>,
+    ) -> Result<IsNull, BoxDynError> {
+        args.push(SqliteArgumentValue::Text(Cow::Owned(self.0.clone())));
+
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, Sqlite> for AnyDecimal {
+    fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(AnyDecimal(match value.type_info().0 {
+            DataType::Integer | DataType::Int4 => value.int64().to_string(),
+            DataType::Float => value.double().to_string(),
+            _ => value.text()?.to_owned(),
+        }))
+    }
+}
+
+macro_rules! impl_text_repr {
+    ($ty:ty => $data_type:ident) => {
+        impl Type<Sqlite> for $ty {
+            fn type_info() -> SqliteTypeInfo {
+                SqliteTypeInfo(DataType::$data_type)
+            }
+
+            fn compatible(ty: &SqliteTypeInfo) -> bool {
+                matches!(ty.0, DataType::$data_type | DataType::Text)
+            }
+        }
+
+        impl<'q> Encode<'q, Sqlite> for $ty {
+            fn encode_by_ref(
+                &self,
+                args: &mut Vec<SqliteArgumentValue<'q>>,
+            ) -> Result<IsNull, BoxDynError> {
+                args.push(SqliteArgumentValue::Text(Cow::Owned(self.to_string())));
+
+                Ok(IsNull::No)
+            }
+        }
+
+        impl Decode<'_, Sqlite> for $ty {
+            fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+                value.text()?.parse()
+            }
+        }
+    };
+}
+
+impl_text_repr!(AnyDate => Date);
+impl_text_repr!(AnyTime => Time);
+impl_text_repr!(AnyTimestamp => Datetime);
+
+impl Type<Sqlite> for AnyUuid {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo(DataType::Blob)
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for AnyUuid {
+    fn encode_by_ref(
+        &self,
+        args: &mut Vec<SqliteArgumentValue<'q>>,
+    ) -> Result<IsNull, BoxDynError> {
+        args.push(SqliteArgumentValue::Blob(Cow::Owned(self.0.to_vec())));
+
+        Ok(IsNull::No)
+    }
+}
+
+impl Decode<'_, Sqlite> for AnyUuid {
+    fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = value.blob();
+
+        bytes
+            .try_into()
+            .map(AnyUuid)
+            .map_err(|_| format!("expected 16 bytes for a UUID, got {}", bytes.len()).into())
+    }
+}
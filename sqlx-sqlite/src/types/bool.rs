@@ -28,6 +28,14 @@ impl<'q> Encode<'q, Sqlite> for bool {
 
 impl<'r> Decode<'r, Sqlite> for bool {
     fn decode(value: SqliteValueRef<'r>) -> Result<bool, BoxDynError> {
-        Ok(value.int64() != 0)
+        match value.int64() {
+            0 => Ok(false),
+            1 => Ok(true),
+            v if value.strict_bools() => Err(format!(
+                "invalid value {v} for a boolean, expected 0 or 1 (strict booleans are enabled)"
+            )
+            .into()),
+            _ => Ok(true),
+        }
     }
 }
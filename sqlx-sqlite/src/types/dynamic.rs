@@ -0,0 +1,66 @@
+use std::borrow::Cow;
+
+use sqlx_core::value::ValueRef;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::type_info::DataType;
+use crate::types::Type;
+use crate::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+
+/// A value of any of SQLite's storage classes, as stored in an `ANY` column
+/// of a [`STRICT` table](https://www.sqlite.org/stricttables.html).
+///
+/// Values are decoded by their storage class, without any conversion.
+/// A `NULL` value decodes as `None` with `Option<SqliteDynamicValue>`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqliteDynamicValue {
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+impl Type<Sqlite> for SqliteDynamicValue {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo(DataType::Any)
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        !matches!(ty.0, DataType::Null | DataType::Numeric)
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for SqliteDynamicValue {
+    fn encode(self, args: &mut Vec<SqliteArgumentValue<'q>>) -> Result<IsNull, BoxDynError> {
+        args.push(match self {
+            SqliteDynamicValue::Integer(value) => SqliteArgumentValue::Int64(value),
+            SqliteDynamicValue::Real(value) => SqliteArgumentValue::Double(value),
+            SqliteDynamicValue::Text(value) => SqliteArgumentValue::Text(Cow::Owned(value)),
+            SqliteDynamicValue::Blob(value) => SqliteArgumentValue::Blob(Cow::Owned(value)),
+        });
+
+        Ok(IsNull::No)
+    }
+
+    fn encode_by_ref(
+        &self,
+        args: &mut Vec<SqliteArgumentValue<'q>>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.clone().encode(args)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for SqliteDynamicValue {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(match value.type_info().0 {
+            DataType::Integer | DataType::Int4 | DataType::Bool => {
+                SqliteDynamicValue::Integer(value.int64())
+            }
+            DataType::Float => SqliteDynamicValue::Real(value.double()),
+            DataType::Blob => SqliteDynamicValue::Blob(value.blob().to_owned()),
+            _ => SqliteDynamicValue::Text(value.text()?.to_owned()),
+        })
+    }
+}
@@ -17,6 +17,21 @@
 //! | `f64`                                 | REAL                                                 |
 //! | `&str`, [`String`]                    | TEXT                                                 |
 //! | `&[u8]`, `Vec<u8>`                    | BLOB                                                 |
+//! | [`SqliteDynamicValue`]                | ANY                                                  |
+//!
+//! #### Note: `STRICT` tables and `ANY`
+//! In a [`STRICT` table](https://www.sqlite.org/stricttables.html), the declared type of a column
+//! is the type of its values, and the macros trust it. A column declared `ANY` stores values of
+//! any storage class unchanged, which the macros decode as [`SqliteDynamicValue`].
+//!
+//! In other tables, the declared type only gives the column an affinity, which the macros use to
+//! pick a type. A column declared `ANY` in such a table has `NUMERIC` affinity instead, so its
+//! type is inferred from the query like that of an expression.
+//!
+//! #### Note: Booleans
+//! SQLite stores booleans as integers, and any integer other than `0` decodes as `true`.
+//! Enable [`SqliteConnectOptions::strict_bools()`][crate::SqliteConnectOptions::strict_bools]
+//! to make integers other than `0` and `1` an error instead.
 //!
 //! #### Note: Unsigned Integers
 //! Decoding of unsigned integer types simply performs a checked conversion
@@ -163,10 +178,15 @@
 
 pub(crate) use sqlx_core::types::*;
 
+pub use dynamic::SqliteDynamicValue;
+
+#[cfg(feature = "any")]
+mod any;
 mod bool;
 mod bytes;
 #[cfg(feature = "chrono")]
 mod chrono;
+mod dynamic;
 mod float;
 mod int;
 #[cfg(feature = "json")]
@@ -53,6 +53,12 @@ impl<'r> SqliteValueRef<'r> {
             SqliteValueData::Value(v) => v.text(),
         }
     }
+
+    pub(super) fn strict_bools(&self) -> bool {
+        match self.0 {
+            SqliteValueData::Value(v) => v.strict_bools,
+        }
+    }
 }
 
 impl<'r> ValueRef<'r> for SqliteValueRef<'r> {
@@ -79,30 +85,94 @@ impl<'r> ValueRef<'r> for SqliteValueRef<'r> {
 
 #[derive(Clone)]
 pub struct SqliteValue {
-    pub(crate) handle: Arc<ValueHandle>,
+    data: ValueData,
     pub(crate) type_info: SqliteTypeInfo,
+    // set by `SqliteConnectOptions::strict_bools()`
+    pub(crate) strict_bools: bool,
+}
+
+#[derive(Clone)]
+enum ValueData {
+    // a value copied out of a statement
+    Handle(Arc<ValueHandle>),
+    // a value built without a statement, e.g. converted from an `AnyValue`
+    #[cfg_attr(not(feature = "any"), allow(dead_code))]
+    Owned(Arc<OwnedValue>),
 }
 
 pub(crate) struct ValueHandle(NonNull<sqlite3_value>);
 
+// Every representation of an owned value is computed up front, like SQLite does on first
+// access, so that the accessors can return borrowed text and blobs for numbers too.
+struct OwnedValue {
+    data_type: DataType,
+    int64: i64,
+    double: f64,
+    bytes: Box<[u8]>,
+}
+
 // SAFE: only protected value objects are stored in SqliteValue
 unsafe impl Send for ValueHandle {}
 unsafe impl Sync for ValueHandle {}
 
 impl SqliteValue {
-    pub(crate) unsafe fn new(value: *mut sqlite3_value, type_info: SqliteTypeInfo) -> Self {
+    pub(crate) unsafe fn new(
+        value: *mut sqlite3_value,
+        type_info: SqliteTypeInfo,
+        strict_bools: bool,
+    ) -> Self {
         debug_assert!(!value.is_null());
 
         Self {
             type_info,
-            handle: Arc::new(ValueHandle(NonNull::new_unchecked(sqlite3_value_dup(
-                value,
+            strict_bools,
+            data: ValueData::Handle(Arc::new(ValueHandle(NonNull::new_unchecked(
+                sqlite3_value_dup(value),
             )))),
         }
     }
 
+    /// Create a value from an argument, converting it like SQLite would once bound.
+    #[cfg(feature = "any")]
+    pub(crate) fn from_argument(
+        argument: &crate::SqliteArgumentValue<'_>,
+        type_info: SqliteTypeInfo,
+    ) -> Self {
+        use crate::SqliteArgumentValue;
+
+        let value = match argument {
+            SqliteArgumentValue::Null => OwnedValue {
+                data_type: DataType::Null,
+                int64: 0,
+                double: 0.0,
+                bytes: Box::default(),
+            },
+            SqliteArgumentValue::Int(i) => OwnedValue::integer((*i).into()),
+            SqliteArgumentValue::Int64(i) => OwnedValue::integer(*i),
+            SqliteArgumentValue::Double(d) => OwnedValue {
+                data_type: DataType::Float,
+                int64: *d as i64,
+                double: *d,
+                bytes: format!("{d:?}").into_bytes().into(),
+            },
+            SqliteArgumentValue::Text(t) => OwnedValue::text(DataType::Text, t.as_bytes()),
+            SqliteArgumentValue::Blob(b) => OwnedValue::text(DataType::Blob, b),
+        };
+
+        Self {
+            type_info,
+            strict_bools: false,
+            data: ValueData::Owned(Arc::new(value)),
+        }
+    }
+
     fn type_info_opt(&self) -> Option<SqliteTypeInfo> {
-        let dt = DataType::from_code(unsafe { sqlite3_value_type(self.handle.0.as_ptr()) });
+        let dt = match &self.data {
+            ValueData::Handle(handle) => {
+                DataType::from_code(unsafe { sqlite3_value_type(handle.0.as_ptr()) })
+            }
+            ValueData::Owned(value) => value.data_type,
+        };
 
         if let DataType::Null = dt {
             None
@@ -112,22 +182,33 @@ impl SqliteValue {
     }
 
     fn int64(&self) -> i64 {
-        unsafe { sqlite3_value_int64(self.handle.0.as_ptr()) }
+        match &self.data {
+            ValueData::Handle(handle) => unsafe { sqlite3_value_int64(handle.0.as_ptr()) },
+            ValueData::Owned(value) => value.int64,
+        }
     }
 
     fn double(&self) -> f64 {
-        unsafe { sqlite3_value_double(self.handle.0.as_ptr()) }
+        match &self.data {
+            ValueData::Handle(handle) => unsafe { sqlite3_value_double(handle.0.as_ptr()) },
+            ValueData::Owned(value) => value.double,
+        }
     }
 
     fn blob(&self) -> &[u8] {
-        let len = unsafe { sqlite3_value_bytes(self.handle.0.as_ptr()) } as usize;
+        let handle = match &self.data {
+            ValueData::Handle(handle) => handle,
+            ValueData::Owned(value) => return &value.bytes,
+        };
+
+        let len = unsafe { sqlite3_value_bytes(handle.0.as_ptr()) } as usize;
 
         if len == 0 {
             // empty blobs are NULL so just return an empty slice
             return &[];
         }
 
-        let ptr = unsafe { sqlite3_value_blob(self.handle.0.as_ptr()) } as *const u8;
+        let ptr = unsafe { sqlite3_value_blob(handle.0.as_ptr()) } as *const u8;
         debug_assert!(!ptr.is_null());
 
         unsafe { from_raw_parts(ptr, len) }
@@ -152,7 +233,12 @@ impl Value for SqliteValue {
     }
 
     fn is_null(&self) -> bool {
-        unsafe { sqlite3_value_type(self.handle.0.as_ptr()) == SQLITE_NULL }
+        match &self.data {
+            ValueData::Handle(handle) => unsafe {
+                sqlite3_value_type(handle.0.as_ptr()) == SQLITE_NULL
+            },
+            ValueData::Owned(value) => value.data_type == DataType::Null,
+        }
     }
 }
 
@@ -164,6 +250,32 @@ impl Drop for ValueHandle {
     }
 }
 
+#[cfg(feature = "any")]
+impl OwnedValue {
+    fn integer(i: i64) -> Self {
+        OwnedValue {
+            data_type: DataType::Integer,
+            int64: i,
+            double: i as f64,
+            bytes: i.to_string().into_bytes().into(),
+        }
+    }
+
+    // text and blobs which aren't a whole number are zero when read as one, as in SQLite
+    fn text(data_type: DataType, bytes: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(bytes);
+        let text = text.trim();
+        let double = text.parse::<f64>().unwrap_or(0.0);
+
+        OwnedValue {
+            data_type,
+            int64: text.parse::<i64>().unwrap_or(double as i64),
+            double,
+            bytes: bytes.into(),
+        }
+    }
+}
+
 // #[cfg(feature = "any")]
 // impl<'r> From<SqliteValueRef<'r>> for crate::any::AnyValueRef<'r> {
 //     #[inline]
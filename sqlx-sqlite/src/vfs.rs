@@ -0,0 +1,478 @@
+//! Custom [VFS](https://www.sqlite.org/vfs.html) implementations written in Rust.
+//!
+//! A VFS is the layer through which SQLite reads and writes database files. Registering one
+//! with [`register_vfs()`] and then selecting it with
+//! [`SqliteConnectOptions::vfs()`][crate::SqliteConnectOptions::vfs] lets SQLite read
+//! databases which are not on the local filesystem, such as a file in an object store which is
+//! fetched with range requests.
+//!
+//! The files of a VFS are read-only, so connections using it should be opened with
+//! [`.read_only(true)`][crate::SqliteConnectOptions::read_only], and temporary tables cannot be
+//! created.
+
+use std::ffi::{CStr, CString};
+use std::future::Future;
+use std::io;
+use std::os::raw::{c_char, c_int, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::sync::Mutex;
+
+use futures_core::future::BoxFuture;
+use libsqlite3_sys::{
+    sqlite3_file, sqlite3_int64, sqlite3_io_methods, sqlite3_vfs, sqlite3_vfs_find,
+    sqlite3_vfs_register, SQLITE_CANTOPEN, SQLITE_IOCAP_IMMUTABLE, SQLITE_IOERR,
+    SQLITE_IOERR_FSTAT, SQLITE_IOERR_READ, SQLITE_IOERR_SHORT_READ, SQLITE_NOTFOUND, SQLITE_OK,
+    SQLITE_OPEN_MAIN_DB, SQLITE_OPEN_READONLY, SQLITE_READONLY,
+};
+
+use crate::error::Error;
+use crate::SqliteError;
+
+/// The longest path SQLite may pass to a registered VFS, in bytes.
+const MAX_PATHNAME: c_int = 1024;
+
+/// A VFS whose files are read through async functions.
+///
+/// SQLite calls into the VFS from the thread running the connection, which blocks on the
+/// returned futures until they complete. These futures are not polled within an async runtime,
+/// so those which rely on one, for example to perform network I/O, should be spawned on it and
+/// awaited through their handle, which completes on any executor:
+///
+/// ```rust,ignore
+/// # use std::io;
+/// # use futures_core::future::BoxFuture;
+/// # use sqlx_sqlite::vfs::SqliteVfsFile;
+/// struct RemoteFile {
+///     runtime: tokio::runtime::Handle,
+///     url: String,
+/// }
+///
+/// # async fn fetch_range(_url: String, _offset: u64, _len: usize) -> io::Result<Vec<u8>> { todo!() }
+/// impl SqliteVfsFile for RemoteFile {
+///     fn read_at<'a>(&'a self, offset: u64, buf: &'a mut [u8]) -> BoxFuture<'a, io::Result<usize>> {
+///         let fetch = self.runtime.spawn(fetch_range(self.url.clone(), offset, buf.len()));
+///
+///         Box::pin(async move {
+///             let bytes = fetch.await??;
+///             buf[..bytes.len()].copy_from_slice(&bytes);
+///             Ok(bytes.len())
+///         })
+///     }
+///     # fn size(&self) -> BoxFuture<'_, io::Result<u64>> { todo!() }
+/// }
+/// ```
+pub trait SqliteVfs: Send + Sync + 'static {
+    /// The type of the files opened by this VFS.
+    type File: SqliteVfsFile;
+
+    /// Open the database file at `path`.
+    ///
+    /// `path` is the filename given in the connection options, for example `data.db` for
+    /// `sqlite://data.db?vfs=...`.
+    fn open<'a>(&'a self, path: &'a str) -> BoxFuture<'a, io::Result<Self::File>>;
+}
+
+/// A read-only file opened by a [`SqliteVfs`].
+pub trait SqliteVfsFile: Send + Sync + 'static {
+    /// The size of the file, in bytes.
+    fn size(&self) -> BoxFuture<'_, io::Result<u64>>;
+
+    /// Read up to `buf.len()` bytes starting at `offset` into `buf`, and return the number of
+    /// bytes read.
+    ///
+    /// Fewer bytes may only be read at the end of the file.
+    fn read_at<'a>(&'a self, offset: u64, buf: &'a mut [u8]) -> BoxFuture<'a, io::Result<usize>>;
+}
+
+/// Register `vfs` with SQLite under `name`, so that connections can select it with
+/// [`SqliteConnectOptions::vfs()`][crate::SqliteConnectOptions::vfs].
+///
+/// The VFS is registered for the lifetime of the process, and is never the default VFS.
+///
+/// Returns an error if a VFS is already registered under `name`.
+pub fn register_vfs<V: SqliteVfs>(name: &str, vfs: V) -> Result<(), Error> {
+    // makes checking for an existing VFS and registering this one atomic
+    static REGISTER: Mutex<()> = Mutex::new(());
+
+    let c_name = CString::new(name)
+        .map_err(|_| Error::Configuration(format!("invalid VFS name: {name:?}").into()))?;
+
+    let _guard = REGISTER.lock().expect("BUG: panicked while holding a lock");
+
+    // SAFETY: both `sqlite3_vfs_find()` and `sqlite3_vfs_register()` initialize SQLite if needed
+    // and are thread-safe
+    // https://www.sqlite.org/c3ref/vfs_find.html
+    if !unsafe { sqlite3_vfs_find(c_name.as_ptr()) }.is_null() {
+        return Err(Error::Configuration(
+            format!("a VFS named {name:?} is already registered").into(),
+        ));
+    }
+
+    // SAFETY: a null name finds the default VFS, which every method not implemented in Rust
+    // is delegated to
+    let default = unsafe { sqlite3_vfs_find(ptr::null()) };
+
+    if default.is_null() {
+        return Err(Error::Configuration("SQLite has no default VFS".into()));
+    }
+
+    // The VFS is never unregistered, so its data is leaked.
+    let data: &'static mut VfsData<V> = Box::leak(Box::new(VfsData {
+        vfs,
+        default,
+        io_methods: io_methods::<V::File>(),
+    }));
+
+    let raw_vfs = Box::leak(Box::new(sqlite3_vfs {
+        iVersion: 2,
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        szOsFile: std::mem::size_of::<VfsFile<V::File>>() as c_int,
+        mxPathname: MAX_PATHNAME,
+        pNext: ptr::null_mut(),
+        zName: c_name.into_raw(),
+        pAppData: (data as *mut VfsData<V>).cast(),
+        xOpen: Some(x_open::<V>),
+        xDelete: Some(x_delete),
+        xAccess: Some(x_access),
+        xFullPathname: Some(x_full_pathname),
+        xDlOpen: Some(x_dl_open::<V>),
+        xDlError: Some(x_dl_error::<V>),
+        xDlSym: Some(x_dl_sym::<V>),
+        xDlClose: Some(x_dl_close::<V>),
+        xRandomness: Some(x_randomness::<V>),
+        xSleep: Some(x_sleep::<V>),
+        xCurrentTime: Some(x_current_time::<V>),
+        xGetLastError: Some(x_get_last_error::<V>),
+        xCurrentTimeInt64: Some(x_current_time_int64::<V>),
+        xSetSystemCall: None,
+        xGetSystemCall: None,
+        xNextSystemCall: None,
+    }));
+
+    // SAFETY: the VFS and everything it points to lives for the rest of the process
+    // https://www.sqlite.org/c3ref/vfs_find.html
+    let rc = unsafe { sqlite3_vfs_register(raw_vfs, 0) };
+
+    if rc == SQLITE_OK {
+        Ok(())
+    } else {
+        Err(Error::Database(Box::new(SqliteError::from_code(rc))))
+    }
+}
+
+struct VfsData<V: SqliteVfs> {
+    vfs: V,
+    // the VFS which methods not implemented in Rust are delegated to
+    default: *mut sqlite3_vfs,
+    io_methods: sqlite3_io_methods,
+}
+
+// SQLite allocates `szOsFile` bytes for each file and passes them to `xOpen()`, which must start
+// with a `sqlite3_file`.
+#[repr(C)]
+struct VfsFile<F> {
+    base: sqlite3_file,
+    file: F,
+}
+
+/// Block on a future called from a VFS method, returning `None` if it panicked, as unwinding
+/// into SQLite is undefined behavior.
+fn block_on<F: Future>(future: F) -> Option<F::Output> {
+    panic::catch_unwind(AssertUnwindSafe(|| futures_executor::block_on(future))).ok()
+}
+
+// SAFETY (for every method below): SQLite only calls them with the `sqlite3_vfs` registered for
+// `V`, whose `pAppData` is a `VfsData<V>`, and with files opened by `x_open::<V>()`.
+
+unsafe fn data<'a, V: SqliteVfs>(vfs: *mut sqlite3_vfs) -> &'a VfsData<V> {
+    &*(*vfs).pAppData.cast::<VfsData<V>>()
+}
+
+unsafe fn file<'a, F>(file: *mut sqlite3_file) -> &'a F {
+    &(*file.cast::<VfsFile<F>>()).file
+}
+
+unsafe extern "C" fn x_open<V: SqliteVfs>(
+    vfs: *mut sqlite3_vfs,
+    name: *const c_char,
+    file: *mut sqlite3_file,
+    flags: c_int,
+    out_flags: *mut c_int,
+) -> c_int {
+    // `xClose()` is only called if `pMethods` is set
+    (*file).pMethods = ptr::null();
+
+    // journals and temporary files cannot be created
+    if flags & SQLITE_OPEN_MAIN_DB == 0 || name.is_null() {
+        return SQLITE_CANTOPEN;
+    }
+
+    let Ok(path) = CStr::from_ptr(name).to_str() else {
+        return SQLITE_CANTOPEN;
+    };
+
+    let data = data::<V>(vfs);
+
+    let opened = match block_on(data.vfs.open(path)) {
+        Some(Ok(opened)) => opened,
+        Some(Err(error)) => {
+            tracing::debug!(%error, path, "failed to open file in SQLite VFS");
+            return SQLITE_CANTOPEN;
+        }
+        None => return SQLITE_CANTOPEN,
+    };
+
+    ptr::write(
+        file.cast::<VfsFile<V::File>>(),
+        VfsFile {
+            base: sqlite3_file {
+                pMethods: &data.io_methods,
+            },
+            file: opened,
+        },
+    );
+
+    if !out_flags.is_null() {
+        *out_flags = SQLITE_OPEN_MAIN_DB | SQLITE_OPEN_READONLY;
+    }
+
+    SQLITE_OK
+}
+
+unsafe extern "C" fn x_delete(_vfs: *mut sqlite3_vfs, _name: *const c_char, _sync: c_int) -> c_int {
+    SQLITE_READONLY
+}
+
+unsafe extern "C" fn x_access(
+    _vfs: *mut sqlite3_vfs,
+    _name: *const c_char,
+    _flags: c_int,
+    out: *mut c_int,
+) -> c_int {
+    // only asked about journals, which never exist
+    *out = 0;
+    SQLITE_OK
+}
+
+unsafe extern "C" fn x_full_pathname(
+    _vfs: *mut sqlite3_vfs,
+    name: *const c_char,
+    n_out: c_int,
+    out: *mut c_char,
+) -> c_int {
+    // paths are passed to `SqliteVfs::open()` as they were given
+    let name = CStr::from_ptr(name).to_bytes_with_nul();
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    if name.len() as c_int > n_out {
+        return SQLITE_CANTOPEN;
+    }
+
+    ptr::copy_nonoverlapping(name.as_ptr().cast(), out, name.len());
+    SQLITE_OK
+}
+
+unsafe extern "C" fn x_dl_open<V: SqliteVfs>(
+    vfs: *mut sqlite3_vfs,
+    name: *const c_char,
+) -> *mut c_void {
+    let default = data::<V>(vfs).default;
+    (*default)
+        .xDlOpen
+        .map_or(ptr::null_mut(), |f| f(default, name))
+}
+
+unsafe extern "C" fn x_dl_error<V: SqliteVfs>(vfs: *mut sqlite3_vfs, n: c_int, out: *mut c_char) {
+    let default = data::<V>(vfs).default;
+
+    if let Some(f) = (*default).xDlError {
+        f(default, n, out);
+    }
+}
+
+unsafe extern "C" fn x_dl_sym<V: SqliteVfs>(
+    vfs: *mut sqlite3_vfs,
+    handle: *mut c_void,
+    symbol: *const c_char,
+) -> Option<unsafe extern "C" fn(*mut sqlite3_vfs, *mut c_void, *const c_char)> {
+    let default = data::<V>(vfs).default;
+    (*default).xDlSym.and_then(|f| f(default, handle, symbol))
+}
+
+unsafe extern "C" fn x_dl_close<V: SqliteVfs>(vfs: *mut sqlite3_vfs, handle: *mut c_void) {
+    let default = data::<V>(vfs).default;
+
+    if let Some(f) = (*default).xDlClose {
+        f(default, handle);
+    }
+}
+
+unsafe extern "C" fn x_randomness<V: SqliteVfs>(
+    vfs: *mut sqlite3_vfs,
+    n: c_int,
+    out: *mut c_char,
+) -> c_int {
+    let default = data::<V>(vfs).default;
+    (*default).xRandomness.map_or(0, |f| f(default, n, out))
+}
+
+unsafe extern "C" fn x_sleep<V: SqliteVfs>(vfs: *mut sqlite3_vfs, microseconds: c_int) -> c_int {
+    let default = data::<V>(vfs).default;
+    (*default).xSleep.map_or(0, |f| f(default, microseconds))
+}
+
+unsafe extern "C" fn x_current_time<V: SqliteVfs>(vfs: *mut sqlite3_vfs, out: *mut f64) -> c_int {
+    let default = data::<V>(vfs).default;
+    (*default)
+        .xCurrentTime
+        .map_or(SQLITE_IOERR, |f| f(default, out))
+}
+
+unsafe extern "C" fn x_get_last_error<V: SqliteVfs>(
+    vfs: *mut sqlite3_vfs,
+    n: c_int,
+    out: *mut c_char,
+) -> c_int {
+    let default = data::<V>(vfs).default;
+    (*default).xGetLastError.map_or(0, |f| f(default, n, out))
+}
+
+unsafe extern "C" fn x_current_time_int64<V: SqliteVfs>(
+    vfs: *mut sqlite3_vfs,
+    out: *mut sqlite3_int64,
+) -> c_int {
+    let default = data::<V>(vfs).default;
+
+    match (*default).xCurrentTimeInt64 {
+        Some(f) if (*default).iVersion >= 2 => f(default, out),
+        _ => SQLITE_IOERR,
+    }
+}
+
+fn io_methods<F: SqliteVfsFile>() -> sqlite3_io_methods {
+    sqlite3_io_methods {
+        iVersion: 1,
+        xClose: Some(x_close::<F>),
+        xRead: Some(x_read::<F>),
+        xWrite: Some(x_write),
+        xTruncate: Some(x_truncate),
+        xSync: Some(x_sync),
+        xFileSize: Some(x_file_size::<F>),
+        xLock: Some(x_lock),
+        xUnlock: Some(x_lock),
+        xCheckReservedLock: Some(x_check_reserved_lock),
+        xFileControl: Some(x_file_control),
+        xSectorSize: Some(x_sector_size),
+        xDeviceCharacteristics: Some(x_device_characteristics),
+        xShmMap: None,
+        xShmLock: None,
+        xShmBarrier: None,
+        xShmUnmap: None,
+        xFetch: None,
+        xUnfetch: None,
+    }
+}
+
+unsafe extern "C" fn x_close<F>(file: *mut sqlite3_file) -> c_int {
+    ptr::drop_in_place(file.cast::<VfsFile<F>>());
+    SQLITE_OK
+}
+
+unsafe extern "C" fn x_read<F: SqliteVfsFile>(
+    file: *mut sqlite3_file,
+    buf: *mut c_void,
+    amount: c_int,
+    offset: sqlite3_int64,
+) -> c_int {
+    let buf = std::slice::from_raw_parts_mut(buf.cast::<u8>(), amount as usize);
+    let file = self::file::<F>(file);
+
+    let mut read = 0;
+
+    // the file may return fewer bytes than asked for before its end
+    while read < buf.len() {
+        #[allow(clippy::cast_sign_loss)]
+        let offset = offset as u64 + read as u64;
+
+        match block_on(file.read_at(offset, &mut buf[read..])) {
+            Some(Ok(0)) => break,
+            Some(Ok(n)) => read += n,
+            Some(Err(error)) => {
+                tracing::debug!(%error, offset, "failed to read file in SQLite VFS");
+                return SQLITE_IOERR_READ;
+            }
+            None => return SQLITE_IOERR_READ,
+        }
+    }
+
+    if read < buf.len() {
+        // SQLite requires the rest of the buffer to be zeroed after a short read
+        buf[read..].fill(0);
+        return SQLITE_IOERR_SHORT_READ;
+    }
+
+    SQLITE_OK
+}
+
+unsafe extern "C" fn x_write(
+    _file: *mut sqlite3_file,
+    _buf: *const c_void,
+    _amount: c_int,
+    _offset: sqlite3_int64,
+) -> c_int {
+    SQLITE_READONLY
+}
+
+unsafe extern "C" fn x_truncate(_file: *mut sqlite3_file, _size: sqlite3_int64) -> c_int {
+    SQLITE_READONLY
+}
+
+unsafe extern "C" fn x_sync(_file: *mut sqlite3_file, _flags: c_int) -> c_int {
+    SQLITE_OK
+}
+
+unsafe extern "C" fn x_file_size<F: SqliteVfsFile>(
+    file: *mut sqlite3_file,
+    out: *mut sqlite3_int64,
+) -> c_int {
+    match block_on(self::file::<F>(file).size()) {
+        Some(Ok(size)) => {
+            #[allow(clippy::cast_possible_wrap)]
+            let size = size as sqlite3_int64;
+            *out = size;
+            SQLITE_OK
+        }
+        Some(Err(error)) => {
+            tracing::debug!(%error, "failed to get the size of a file in SQLite VFS");
+            SQLITE_IOERR_FSTAT
+        }
+        None => SQLITE_IOERR_FSTAT,
+    }
+}
+
+// the files cannot change, so there is nothing to lock
+unsafe extern "C" fn x_lock(_file: *mut sqlite3_file, _lock: c_int) -> c_int {
+    SQLITE_OK
+}
+
+unsafe extern "C" fn x_check_reserved_lock(_file: *mut sqlite3_file, out: *mut c_int) -> c_int {
+    *out = 0;
+    SQLITE_OK
+}
+
+unsafe extern "C" fn x_file_control(
+    _file: *mut sqlite3_file,
+    _op: c_int,
+    _arg: *mut c_void,
+) -> c_int {
+    SQLITE_NOTFOUND
+}
+
+unsafe extern "C" fn x_sector_size(_file: *mut sqlite3_file) -> c_int {
+    512
+}
+
+unsafe extern "C" fn x_device_characteristics(_file: *mut sqlite3_file) -> c_int {
+    SQLITE_IOCAP_IMMUTABLE
+}
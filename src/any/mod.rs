@@ -5,9 +5,13 @@ use std::sync::Once;
 
 pub use sqlx_core::any::driver::install_drivers;
 
+#[cfg(feature = "mock")]
+pub use sqlx_core::any::mock;
+
 pub use sqlx_core::any::{
-    Any, AnyArguments, AnyConnectOptions, AnyExecutor, AnyKind, AnyPoolOptions, AnyQueryResult,
-    AnyRow, AnyStatement, AnyTransactionManager, AnyTypeInfo, AnyValue, AnyValueRef,
+    register_type, Any, AnyArguments, AnyConnectOptions, AnyExecutor, AnyKind, AnyMappedValue,
+    AnyPoolOptions, AnyQueryResult, AnyRow, AnyStatement, AnyTransactionManager, AnyTypeInfo,
+    AnyValue, AnyValueError, AnyValueRef,
 };
 
 pub(crate) mod reexports {
@@ -40,6 +44,8 @@ pub fn install_default_drivers() {
             sqlx_postgres::any::DRIVER,
             #[cfg(feature = "sqlite")]
             sqlx_sqlite::any::DRIVER,
+            #[cfg(feature = "mock")]
+            sqlx_core::any::mock::DRIVER,
         ])
         .expect("non-default drivers already installed")
     });
@@ -10,26 +10,34 @@ pub use sqlx_core::database::{self, Database};
 pub use sqlx_core::describe::Describe;
 pub use sqlx_core::executor::{Execute, Executor};
 pub use sqlx_core::from_row::FromRow;
+pub use sqlx_core::logger::{QueryLogEntry, QueryLogSink};
 pub use sqlx_core::pool::{self, Pool};
 #[doc(hidden)]
 pub use sqlx_core::query::query_with_result as __query_with_result;
 pub use sqlx_core::query::{query, query_with};
 pub use sqlx_core::query_as::{query_as, query_as_with};
 pub use sqlx_core::query_builder::{self, QueryBuilder};
+pub use sqlx_core::query_context::QueryContext;
+pub use sqlx_core::query_fingerprint::QueryFingerprint;
 #[doc(hidden)]
 pub use sqlx_core::query_scalar::query_scalar_with_result as __query_scalar_with_result;
 pub use sqlx_core::query_scalar::{query_scalar, query_scalar_with};
 pub use sqlx_core::raw_sql::{raw_sql, RawSql};
-pub use sqlx_core::row::Row;
+pub use sqlx_core::row::{self, Row};
+pub use sqlx_core::sequence::{self, Sequence, SequenceSupport};
 pub use sqlx_core::statement::Statement;
-pub use sqlx_core::transaction::{Transaction, TransactionManager};
+pub use sqlx_core::statement_stats::{self, StatementStat, StatementStats};
+pub use sqlx_core::transaction::{
+    AccessMode, IsolationLevel, Rollback, Transaction, TransactionAbort, TransactionManager,
+    TransactionOptions,
+};
 pub use sqlx_core::type_info::TypeInfo;
 pub use sqlx_core::types::Type;
 pub use sqlx_core::value::{Value, ValueRef};
 pub use sqlx_core::Either;
 
 #[doc(inline)]
-pub use sqlx_core::error::{self, Error, Result};
+pub use sqlx_core::error::{self, Error, Result, RowDecodeError};
 
 #[cfg(feature = "migrate")]
 pub use sqlx_core::migrate;
@@ -60,7 +68,7 @@ pub extern crate sqlx_macros;
 // derives
 #[cfg(feature = "derive")]
 #[doc(hidden)]
-pub use sqlx_macros::{FromRow, Type};
+pub use sqlx_macros::{FromRow, IntoArguments, Type};
 
 // We can't do our normal facade approach with an attribute, but thankfully we can now
 // have docs out-of-line quite easily.
@@ -72,6 +80,9 @@ pub use sqlx_macros::test;
 #[cfg(feature = "migrate")]
 pub use sqlx_core::testing;
 
+#[cfg(feature = "migrate")]
+pub use sqlx_core::assert_plan;
+
 #[doc(hidden)]
 pub use sqlx_core::rt::test_block_on;
 
@@ -55,7 +55,8 @@
 /// server with the schema that the query string will be checked against. All variants of `query!()`
 /// use [dotenv]<sup>1</sup> so this can be in a `.env` file instead.
 ///
-///     * Or, `.sqlx` must exist at the workspace root. See [Offline Mode](#offline-mode-requires-the-offline-feature)
+///     * Or, `.sqlx` must exist at the workspace root, or in the directory set by `offline-dir` in
+///       `[workspace.metadata.sqlx]`. See [Offline Mode](#offline-mode-requires-the-offline-feature)
 ///       below.
 ///
 /// * The query must be a string literal, or concatenation of string literals using `+` (useful
@@ -275,6 +276,32 @@
 /// # }
 /// ```
 ///
+/// ##### MySQL: `BINARY(16)` as `Uuid`
+/// MySQL has no UUID type, so UUIDs are usually stored as `BINARY(16)`, which is inferred as
+/// `Vec<u8>`. With the `uuid` feature enabled, set `SQLX_MYSQL_BINARY16_AS_UUID=true` (in the
+/// environment or a `.env` file) to infer `BINARY(16)` columns as `uuid::Uuid` instead,
+/// or override a single column with `` id as `id: uuid::Uuid` ``.
+///
+/// A `Uuid` may be passed directly as a bind parameter for a `BINARY(16)` column
+/// as it is encoded as its 16 bytes.
+///
+/// ##### MySQL: `TINYINT(1)` as `bool`
+/// MySQL has no boolean type, and `BOOLEAN` is an alias of `TINYINT(1)`, so `TINYINT(1)` columns
+/// are inferred as `bool`. Set `SQLX_MYSQL_TINYINT1_AS_BOOL=false` to infer them as `i8` instead,
+/// or override a single column with `` flag as `flag: i8` ``.
+///
+/// ##### MySQL: `TIME` as a duration
+/// MySQL `TIME` values may be negative or exceed 24 hours, but are inferred as a time of day
+/// (`chrono::NaiveTime` or `time::Time`). Set `SQLX_MYSQL_TIME_AS_DURATION=true` to infer them
+/// as `chrono::TimeDelta` or `time::Duration` instead, or override a single column with
+/// `` elapsed as `elapsed: chrono::TimeDelta` ``.
+///
+/// ##### Postgres: `TIMESTAMPTZ` time zone
+/// With the `chrono` feature enabled, `TIMESTAMPTZ` is inferred as `DateTime<Utc>`.
+/// Set `SQLX_POSTGRES_TIMESTAMPTZ=fixed_offset` to infer `DateTime<FixedOffset>` instead,
+/// which is decoded with the offset of the session `TimeZone` if it is a fixed offset,
+/// or `SQLX_POSTGRES_TIMESTAMPTZ=local` to infer `DateTime<Local>`.
+///
 /// ##### Overrides cheatsheet
 ///
 /// | Syntax    | Nullability     | Type       |
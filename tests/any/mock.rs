@@ -0,0 +1,274 @@
+use sqlx::any::mock::{MockDatabase, MockRow};
+use sqlx::{AccessMode, AnyPool, Connection, IsolationLevel, Row, TransactionOptions};
+
+#[sqlx_macros::test]
+async fn it_returns_scripted_responses() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let mock = MockDatabase::new("scripted-responses");
+
+    mock.expect("SELECT id, name FROM users WHERE id = ?")
+        .returns_rows([MockRow::new().column("id", 1_i64).column("name", "Alice")]);
+    mock.expect("DELETE FROM users").returns_rows_affected(3);
+    mock.expect("INSERT INTO users (name) VALUES (?)")
+        .returns_last_insert_id(4);
+
+    let pool = AnyPool::connect(&mock.url()).await?;
+
+    let row = sqlx::query("SELECT id, name\n  FROM users WHERE id = ?")
+        .bind(1_i64)
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(row.get::<i64, _>("id"), 1);
+    assert_eq!(row.get::<String, _>("name"), "Alice");
+
+    let result = sqlx::query("DELETE FROM users").execute(&pool).await?;
+    assert_eq!(result.rows_affected(), 3);
+
+    let result = sqlx::query("INSERT INTO users (name) VALUES (?)")
+        .bind("Bob")
+        .execute(&pool)
+        .await?;
+    assert_eq!(result.last_insert_id(), Some(4));
+
+    mock.verify();
+
+    let executed = mock.executed();
+    assert_eq!(executed.len(), 3);
+    assert_eq!(executed[2].arguments().len(), 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fails_unexpected_queries() -> anyhow::Result<()> {
+    let mock = MockDatabase::new("unexpected-queries");
+
+    mock.expect("SELECT 1")
+        .returns_rows([MockRow::new().column("value", 1_i32)]);
+    mock.expect("SELECT 2")
+        .returns_error(sqlx::Error::RowNotFound);
+
+    let mut conn = mock.connection();
+
+    let res = sqlx::query("SELECT 2").fetch_one(&mut conn).await;
+    assert!(matches!(res, Err(sqlx::Error::Protocol(_))));
+
+    let value: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(value, 1);
+
+    let res = sqlx::query("SELECT 2").execute(&mut conn).await;
+    assert!(matches!(res, Err(sqlx::Error::RowNotFound)));
+
+    let res = sqlx::query("SELECT 3").execute(&mut conn).await;
+    assert!(matches!(res, Err(sqlx::Error::Protocol(_))));
+
+    mock.verify();
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_records_transactions() -> anyhow::Result<()> {
+    let mock = MockDatabase::new("transactions");
+
+    mock.expect("BEGIN").succeeds();
+    mock.expect("UPDATE accounts SET balance = balance - 10")
+        .returns_rows_affected(1);
+    mock.expect("SAVEPOINT _sqlx_savepoint_1").succeeds();
+    mock.expect("UPDATE accounts SET balance = balance + 10")
+        .returns_error(sqlx::Error::RowNotFound);
+    mock.expect("ROLLBACK TO SAVEPOINT _sqlx_savepoint_1")
+        .succeeds();
+    mock.expect("COMMIT").succeeds();
+
+    let mut conn = mock.connection();
+    let mut tx = conn.begin().await?;
+
+    sqlx::query("UPDATE accounts SET balance = balance - 10")
+        .execute(&mut *tx)
+        .await?;
+
+    {
+        let mut savepoint = tx.begin().await?;
+
+        let res = sqlx::query("UPDATE accounts SET balance = balance + 10")
+            .execute(&mut *savepoint)
+            .await;
+        assert!(res.is_err());
+    }
+
+    tx.commit().await?;
+
+    let executed: Vec<String> = mock
+        .executed()
+        .iter()
+        .map(|query| query.sql().to_owned())
+        .collect();
+
+    assert_eq!(
+        executed,
+        [
+            "BEGIN",
+            "UPDATE accounts SET balance = balance - 10",
+            "SAVEPOINT _sqlx_savepoint_1",
+            "UPDATE accounts SET balance = balance + 10",
+            "ROLLBACK TO SAVEPOINT _sqlx_savepoint_1",
+            "COMMIT",
+        ]
+    );
+
+    mock.verify();
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fails_unexpected_transaction_statements() -> anyhow::Result<()> {
+    let mock = MockDatabase::new("unexpected-transactions");
+
+    mock.expect("BEGIN").succeeds();
+    mock.expect("COMMIT")
+        .returns_error(sqlx::Error::Protocol("commit failed".into()));
+    mock.expect("ROLLBACK").succeeds();
+
+    let mut conn = mock.connection();
+
+    let tx = conn.begin().await?;
+    assert!(tx.commit().await.is_err());
+
+    // the failed commit left the transaction open, so dropping it rolled it back
+    mock.verify();
+
+    assert!(conn.begin().await.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_begins_transactions_with_options() -> anyhow::Result<()> {
+    let mock = MockDatabase::new("transaction-options");
+
+    mock.expect("BEGIN ISOLATION LEVEL SERIALIZABLE READ ONLY DEFERRABLE")
+        .succeeds();
+    mock.expect("COMMIT").succeeds();
+    mock.expect("BEGIN").succeeds();
+    mock.expect("ROLLBACK").succeeds();
+
+    let mut conn = mock.connection();
+
+    let tx = conn
+        .begin_with_options(
+            TransactionOptions::new()
+                .isolation_level(IsolationLevel::Serializable)
+                .read_only(true)
+                .deferrable(true),
+        )
+        .await?;
+
+    assert_eq!(tx.isolation_level(), Some(IsolationLevel::Serializable));
+    assert_eq!(tx.access_mode(), Some(AccessMode::ReadOnly));
+
+    tx.commit().await?;
+
+    let mut tx = conn.begin_with_options(TransactionOptions::new()).await?;
+
+    assert_eq!(tx.isolation_level(), None);
+    assert!(tx
+        .begin_with_options(TransactionOptions::new())
+        .await
+        .is_err());
+
+    tx.rollback().await?;
+
+    let executed: Vec<String> = mock
+        .executed()
+        .iter()
+        .map(|query| query.sql().to_owned())
+        .collect();
+
+    assert_eq!(
+        executed,
+        [
+            "BEGIN ISOLATION LEVEL SERIALIZABLE READ ONLY DEFERRABLE",
+            "COMMIT",
+            "BEGIN",
+            "ROLLBACK",
+        ]
+    );
+
+    mock.verify();
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_allows_mocks_with_the_same_name() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let first = MockDatabase::new("same-name");
+    let second = MockDatabase::new("same-name");
+
+    assert_ne!(first.url(), second.url());
+
+    first.expect("SELECT 1").returns_rows_affected(0);
+
+    let mut conn = first.connection();
+    sqlx::query("SELECT 1").execute(&mut conn).await?;
+
+    let url = second.url();
+    drop(second);
+
+    assert!(sqlx::AnyConnection::connect(&url).await.is_err());
+
+    first.verify();
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_returns_null_columns() -> anyhow::Result<()> {
+    let mock = MockDatabase::new("null-columns");
+
+    mock.expect("SELECT email FROM users")
+        .returns_rows([MockRow::new().column("email", None::<String>)]);
+
+    let mut conn = mock.connection();
+
+    let email: Option<String> = sqlx::query_scalar("SELECT email FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(email, None);
+
+    conn.close().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_returns_the_error_of_a_transaction_which_failed_to_roll_back() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let mock = MockDatabase::new("failed-rollback");
+
+    mock.expect("BEGIN ISOLATION LEVEL SERIALIZABLE READ WRITE")
+        .succeeds();
+    mock.expect("ROLLBACK")
+        .returns_error(sqlx::Error::Protocol("rollback failed".into()));
+
+    let pool = AnyPool::connect(&mock.url()).await?;
+
+    let res = pool
+        .transaction_with_retry(IsolationLevel::Serializable, |_| {
+            Box::pin(async { Err::<(), _>(sqlx::Error::RowNotFound) })
+        })
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::RowNotFound)));
+
+    mock.verify();
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use sqlx::any::{AnyConnectOptions, AnyPoolOptions};
-use sqlx::Executor;
+use sqlx::{AnyPool, Executor};
 use std::sync::{
     atomic::{AtomicI32, AtomicUsize, Ordering},
     Arc, Mutex,
@@ -216,6 +216,472 @@ async fn test_pool_callbacks() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_reports_leaked_connections() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let leaked = Arc::new(Mutex::new(Vec::new()));
+
+    let pool = AnyPoolOptions::new()
+        .leak_detection_threshold(Duration::from_millis(100))
+        .on_leak({
+            let leaked = leaked.clone();
+            move |leak| {
+                assert!(leak.held_for() >= Duration::from_millis(100));
+                leaked.lock().unwrap().push(leak.tag().map(str::to_owned));
+            }
+        })
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let mut leaked_conn = pool.acquire().await?;
+    leaked_conn.set_tag("leaked");
+
+    let mut long_running = pool.acquire().await?;
+    long_running.set_tag("long-running");
+    long_running.detach_with_timeout(Duration::from_secs(60));
+
+    // returned before the threshold
+    drop(pool.acquire().await?);
+
+    sqlx_core::rt::sleep(Duration::from_millis(300)).await;
+
+    assert_eq!(*leaked.lock().unwrap(), [Some("leaked".to_owned())]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_raises_watchdog_alerts_for_a_long_acquire_queue() -> anyhow::Result<()> {
+    use sqlx::pool::PoolWatchdog;
+
+    sqlx::any::install_default_drivers();
+
+    let alerts = Arc::new(Mutex::new(Vec::new()));
+
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .watchdog(
+            PoolWatchdog::new(Duration::from_millis(50))
+                .max_waiting(2)
+                .consecutive_samples(2),
+        )
+        .on_watchdog_alert({
+            let alerts = alerts.clone();
+            move |alert| {
+                alerts
+                    .lock()
+                    .unwrap()
+                    .push((alert.waiting(), alert.consecutive_samples()));
+            }
+        })
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let held = pool.acquire().await?;
+
+    let waiters: Vec<_> = (0..3)
+        .map(|_| {
+            let pool = pool.clone();
+            sqlx_core::rt::spawn(async move { drop(pool.acquire().await) })
+        })
+        .collect();
+
+    sqlx_core::rt::sleep(Duration::from_millis(300)).await;
+
+    // raised once for the whole streak
+    assert_eq!(*alerts.lock().unwrap(), [(3, 2)]);
+
+    drop(held);
+
+    for waiter in waiters {
+        waiter.await;
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_serves_waiters_by_priority_then_fairness() -> anyhow::Result<()> {
+    use sqlx::pool::Fairness;
+
+    sqlx::any::install_default_drivers();
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .fairness(Fairness::Lifo)
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let held = pool.acquire().await?;
+    let mut waiters = Vec::new();
+
+    for (name, priority) in [("batch 1", 0), ("batch 2", 0), ("interactive", 10)] {
+        let pool = pool.clone();
+        let order = order.clone();
+
+        waiters.push(sqlx_core::rt::spawn(async move {
+            let conn = pool.acquire_with_priority(priority).await;
+            order.lock().unwrap().push(name);
+            drop(conn);
+        }));
+
+        // let each task start waiting before the next
+        sqlx_core::rt::sleep(Duration::from_millis(50)).await;
+    }
+
+    drop(held);
+
+    for waiter in waiters {
+        waiter.await;
+    }
+
+    assert_eq!(
+        *order.lock().unwrap(),
+        ["interactive", "batch 2", "batch 1"]
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_serves_waiters_in_fifo_order() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let held = pool.acquire().await?;
+    let mut waiters = Vec::new();
+
+    for (name, priority) in [("first", 0), ("second", 0), ("third", 0)] {
+        let pool = pool.clone();
+        let order = order.clone();
+
+        waiters.push(sqlx_core::rt::spawn(async move {
+            let conn = pool.acquire_with_priority(priority).await;
+            order.lock().unwrap().push(name);
+
+            // hold on to the connection so the next waiter has to wait for it too
+            sqlx_core::rt::sleep(Duration::from_millis(10)).await;
+            drop(conn);
+        }));
+
+        // let each task start waiting before the next
+        sqlx_core::rt::sleep(Duration::from_millis(50)).await;
+    }
+
+    drop(held);
+
+    for waiter in waiters {
+        waiter.await;
+    }
+
+    assert_eq!(*order.lock().unwrap(), ["first", "second", "third"]);
+
+    // once a priority is used, tasks with the same priority are still served in order
+    order.lock().unwrap().clear();
+
+    let held = pool.acquire_with_priority(1).await?;
+    let mut waiters = Vec::new();
+
+    for (name, priority) in [("batch 1", 0), ("batch 2", 0), ("interactive", 10)] {
+        let pool = pool.clone();
+        let order = order.clone();
+
+        waiters.push(sqlx_core::rt::spawn(async move {
+            let conn = pool.acquire_with_priority(priority).await;
+            order.lock().unwrap().push(name);
+            drop(conn);
+        }));
+
+        sqlx_core::rt::sleep(Duration::from_millis(50)).await;
+    }
+
+    drop(held);
+
+    for waiter in waiters {
+        waiter.await;
+    }
+
+    assert_eq!(
+        *order.lock().unwrap(),
+        ["interactive", "batch 1", "batch 2"]
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_retires_connections_after_max_uses() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .max_uses(2)
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    // a temporary table only exists on the connection which created it
+    pool.execute("CREATE TEMPORARY TABLE uses (id INTEGER)")
+        .await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM uses")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(count, 0);
+
+    // the connection was closed after its second use, so the table is gone
+    assert!(pool.execute("SELECT COUNT(*) FROM uses").await.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_exposes_pool_metrics() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .acquire_timeout(Duration::from_millis(100))
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let conn = pool.acquire().await?;
+
+    // the only connection is held
+    assert!(matches!(
+        pool.acquire().await,
+        Err(sqlx::Error::PoolTimedOut)
+    ));
+
+    drop(conn.detach());
+
+    let _conn = pool.acquire().await?;
+
+    let metrics = pool.metrics();
+    assert_eq!(metrics.size(), 1);
+    assert_eq!(metrics.num_idle(), 0);
+    assert_eq!(metrics.num_waiting(), 0);
+    assert_eq!(metrics.connections_opened(), 2);
+    assert_eq!(metrics.connections_closed(), 1);
+    assert_eq!(metrics.acquire_timeouts(), 1);
+
+    // `connect()` acquires a connection to test it
+    let acquire_time = metrics.acquire_time();
+    assert_eq!(acquire_time.count(), 3);
+    assert_eq!(
+        acquire_time.buckets().last().map(|(_, count)| *count),
+        Some(3)
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_checks_the_health_of_a_pool() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let pool = AnyPoolOptions::new()
+        .max_connections(2)
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    // with no idle connections, one is opened to ping
+    let health = pool.health_check().await?;
+    assert_eq!(health.latencies().len(), 1);
+    assert_eq!(health.num_failed(), 0);
+    assert!(health.min_latency() <= health.mean_latency());
+    assert!(health.mean_latency() <= health.max_latency());
+    assert_eq!(pool.num_idle(), 1);
+
+    let held = pool.acquire().await?;
+    drop(pool.acquire().await?);
+    drop(held);
+
+    // connections are returned to the pool in the background
+    while pool.num_idle() < 2 {
+        sqlx_core::rt::sleep(Duration::from_millis(1)).await;
+    }
+
+    let health = pool.health_check().await?;
+    assert_eq!(health.latencies().len(), 2);
+    assert_eq!(pool.num_idle(), 2);
+
+    // the connections can still be used
+    let value: i32 = sqlx::query_scalar("SELECT 1")
+        .fetch_one(&mut *pool.acquire().await?)
+        .await?;
+    assert_eq!(value, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_retries_acquire_with_a_custom_classifier() -> anyhow::Result<()> {
+    use sqlx::pool::RetryPolicy;
+
+    sqlx::any::install_default_drivers();
+
+    let retried = Arc::new(AtomicUsize::new(0));
+
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .acquire_timeout(Duration::from_millis(10))
+        .retry_policy(
+            RetryPolicy::new()
+                .max_attempts(3)
+                .backoff(Duration::from_millis(1), Duration::from_millis(1))
+                .retry_if({
+                    let retried = retried.clone();
+                    move |error| {
+                        retried.fetch_add(1, Ordering::SeqCst);
+                        matches!(error, sqlx::Error::PoolTimedOut)
+                    }
+                }),
+        )
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    // the only connection is held, so every attempt times out
+    let _conn = pool.acquire().await?;
+
+    assert!(matches!(
+        pool.acquire().await,
+        Err(sqlx::Error::PoolTimedOut)
+    ));
+    // the error of the last attempt isn't classified
+    assert_eq!(retried.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_retries_queries_executed_on_the_pool() -> anyhow::Result<()> {
+    use sqlx::pool::RetryPolicy;
+
+    sqlx::any::install_default_drivers();
+
+    let retried = Arc::new(AtomicUsize::new(0));
+
+    let pool = AnyPoolOptions::new()
+        .retry_policy(
+            RetryPolicy::new()
+                .max_attempts(3)
+                .backoff(Duration::from_millis(1), Duration::from_millis(1))
+                .retry_queries(true)
+                .retry_if({
+                    let retried = retried.clone();
+                    move |error| {
+                        retried.fetch_add(1, Ordering::SeqCst);
+                        matches!(error, sqlx::Error::Database(_))
+                    }
+                }),
+        )
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    // both streamed queries and `fetch_optional()` are retried
+    assert!(pool.execute("SELECT * FROM not_a_table").await.is_err());
+    assert_eq!(retried.swap(0, Ordering::SeqCst), 2);
+
+    assert!(sqlx::query("SELECT * FROM not_a_table")
+        .fetch_optional(&pool)
+        .await
+        .is_err());
+    assert_eq!(retried.swap(0, Ordering::SeqCst), 2);
+
+    // queries on a connection or in a transaction are not
+    let mut conn = pool.acquire().await?;
+    assert!(conn.execute("SELECT * FROM not_a_table").await.is_err());
+    drop(conn);
+
+    let mut tx = pool.begin().await?;
+    assert!(tx.execute("SELECT * FROM not_a_table").await.is_err());
+    drop(tx);
+
+    assert_eq!(retried.load(Ordering::SeqCst), 0);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_records_statement_stats_across_the_pool() -> anyhow::Result<()> {
+    use sqlx::StatementStats;
+
+    sqlx::any::install_default_drivers();
+
+    // one connection, so every statement below is executed after the ones before it finished
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .statement_stats(StatementStats::new())
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    for _ in 0..3 {
+        let value: i64 = sqlx::query_scalar("SELECT 1 + 1").fetch_one(&pool).await?;
+        assert_eq!(value, 2);
+    }
+
+    let mut conn = pool.acquire().await?;
+    assert!(conn.execute("SELECT * FROM missing").await.is_err());
+    // the stats of a statement may be recorded after it returned, e.g. by the worker thread of
+    // SQLite, but always before the next statement is executed
+    conn.execute("SELECT 42").await?;
+    drop(conn);
+
+    let stats = pool.statement_stats();
+
+    let stat = stats.iter().find(|s| s.sql() == "SELECT 1 + 1").unwrap();
+    assert_eq!(stat.executions(), 3);
+    assert_eq!(stat.errors(), 0);
+    assert_eq!(stat.rows_returned(), 3);
+    assert!(stat.max_time() <= stat.total_time());
+
+    let stat = stats
+        .iter()
+        .find(|s| s.sql() == "SELECT * FROM missing")
+        .unwrap();
+    assert_eq!(stat.executions(), 1);
+    assert_eq!(stat.errors(), 1);
+
+    assert!(stats
+        .windows(2)
+        .all(|w| w[0].total_time() >= w[1].total_time()));
+
+    // grouped by fingerprint, statements differing only in literals are counted together
+    let pool = AnyPoolOptions::new()
+        .statement_stats(StatementStats::new().by_fingerprint(true))
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+    conn.execute("SELECT 1").await?;
+    conn.execute("select 2").await?;
+    drop(conn);
+
+    let fingerprint = sqlx::QueryFingerprint::new("SELECT 1");
+    let stats = pool.statement_stats();
+    let stat = stats
+        .iter()
+        .find(|s| s.sql() == fingerprint.normalized())
+        .unwrap();
+    assert_eq!(stat.executions(), 2);
+
+    assert!(AnyPool::connect(&dotenvy::var("DATABASE_URL")?)
+        .await?
+        .statement_stats()
+        .is_empty());
+
+    Ok(())
+}
+
 #[ignore]
 #[sqlx_macros::test]
 async fn test_connection_maintenance() -> anyhow::Result<()> {
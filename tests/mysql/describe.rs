@@ -69,3 +69,20 @@ async fn uses_alias_name() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_describes_show_statements() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let d = conn.describe("SHOW COLUMNS FROM tweet").await?;
+
+    assert_eq!(d.columns()[0].name(), "Field");
+    assert_eq!(d.columns()[1].name(), "Type");
+
+    // the connection is still usable afterwards
+    let d = conn.describe("SELECT * FROM tweet").await?;
+
+    assert_eq!(d.columns()[0].name(), "id");
+
+    Ok(())
+}
@@ -66,12 +66,72 @@ async fn reversible(mut conn: PoolConnection<MySql>) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx::test(migrations = false)]
+async fn in_schema(mut conn: PoolConnection<MySql>) -> anyhow::Result<()> {
+    conn.execute("DROP DATABASE IF EXISTS tenant_a").await?;
+
+    let database: Option<String> = conn.fetch_one("SELECT DATABASE()").await?.get(0);
+
+    let migrator = Migrator::new(Path::new("tests/mysql/migrations_simple")).await?;
+
+    migrator.run_in_schema(&mut conn, "tenant_a").await?;
+
+    // check outcome
+    let res: String = conn
+        .fetch_one("SELECT some_payload FROM tenant_a.migrations_simple_test")
+        .await?
+        .get(0);
+    assert_eq!(res, "110_suffix");
+
+    let applied: i64 = conn
+        .fetch_one("SELECT COUNT(*) FROM tenant_a._sqlx_migrations")
+        .await?
+        .get(0);
+    assert_eq!(applied, 2);
+
+    // running it a 2nd time should still work
+    migrator.run_in_schema(&mut conn, "tenant_a").await?;
+
+    // the previous database is restored afterwards
+    let res: Option<String> = conn.fetch_one("SELECT DATABASE()").await?.get(0);
+    assert_eq!(res, database);
+
+    conn.execute("DROP DATABASE tenant_a").await?;
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = false)]
+async fn delimiter(mut conn: PoolConnection<MySql>) -> anyhow::Result<()> {
+    clean_up(&mut conn).await?;
+
+    let migrator = Migrator::new(Path::new("tests/mysql/migrations_delimiter")).await?;
+
+    // run migration
+    migrator.run(&mut conn).await?;
+
+    // check outcome
+    let res: i64 = conn
+        .fetch_one("SELECT some_payload FROM migrations_delimiter_test")
+        .await?
+        .get(0);
+    assert_eq!(res, 11);
+
+    Ok(())
+}
+
 /// Ensure that we have a clean initial state.
 async fn clean_up(conn: &mut MySqlConnection) -> anyhow::Result<()> {
     conn.execute("DROP TABLE migrations_simple_test").await.ok();
     conn.execute("DROP TABLE migrations_reversible_test")
         .await
         .ok();
+    conn.execute("DROP TABLE migrations_delimiter_test")
+        .await
+        .ok();
+    conn.execute("DROP PROCEDURE IF EXISTS migrations_delimiter_insert")
+        .await
+        .ok();
     conn.execute("DROP TABLE _sqlx_migrations").await.ok();
 
     Ok(())
@@ -0,0 +1,17 @@
+CREATE TABLE migrations_delimiter_test (
+    some_id BIGINT NOT NULL PRIMARY KEY,
+    some_payload BIGINT NOT NULL
+);
+
+-- the body of the procedure contains `;`, so it needs a different delimiter
+DELIMITER //
+
+CREATE PROCEDURE migrations_delimiter_insert(IN id BIGINT)
+BEGIN
+    INSERT INTO migrations_delimiter_test (some_id, some_payload) VALUES (id, id * 10);
+    UPDATE migrations_delimiter_test SET some_payload = some_payload + 1 WHERE some_id = id;
+END //
+
+DELIMITER ;
+
+CALL migrations_delimiter_insert(1);
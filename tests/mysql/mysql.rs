@@ -1,8 +1,12 @@
 use futures::TryStreamExt;
-use sqlx::mysql::{MySql, MySqlConnection, MySqlPool, MySqlPoolOptions, MySqlRow};
+use sqlx::mysql::{
+    MySql, MySqlAuthPlugin, MySqlConnectOptions, MySqlConnection, MySqlPool, MySqlPoolOptions,
+    MySqlRow,
+};
 use sqlx::{Column, Connection, Executor, Row, Statement, TypeInfo};
 use sqlx_test::{new, setup_if_needed};
 use std::env;
+use std::time::Duration;
 
 #[sqlx_macros::test]
 async fn it_connects() -> anyhow::Result<()> {
@@ -43,6 +47,256 @@ async fn it_can_fail_at_querying() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_send_query_attributes() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let res = sqlx::query_scalar::<_, i32>("SELECT ?")
+        .bind(1_i32)
+        .attribute("trace_id", "0af7651916cd43dd8448eb211c80319c")
+        .fetch_one(&mut conn)
+        .await;
+
+    match res {
+        Ok(value) => assert_eq!(value, 1),
+        // MariaDB and MySQL before 8.0.23 do not support query attributes
+        Err(sqlx::Error::Protocol(_)) => {}
+        Err(e) => return Err(e.into()),
+    }
+
+    // the connection is still usable either way
+    let value: i32 = sqlx::query_scalar("SELECT 2").fetch_one(&mut conn).await?;
+    assert_eq!(value, 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_sends_connection_query_attributes_with_every_statement() -> anyhow::Result<()> {
+    let options: MySqlConnectOptions = dotenvy::var("DATABASE_URL")?.parse()?;
+    let options = options.query_attribute("app_name", "sqlx-tests");
+
+    let mut conn = match MySqlConnection::connect_with(&options).await {
+        Ok(conn) => conn,
+        // MariaDB and MySQL before 8.0.23 do not support query attributes
+        Err(sqlx::Error::Protocol(_)) => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+
+    // both the text and the binary protocol send the attributes
+    conn.execute("SELECT 1").await?;
+
+    let value: i32 = sqlx::query_scalar("SELECT ?")
+        .bind(2_i32)
+        .attribute("trace_id", "0af7651916cd43dd8448eb211c80319c")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(value, 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_overrides_the_statement_timeout_of_a_query() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let default = statement_timeout_millis(&mut conn, "GLOBAL", None).await?;
+
+    let timeout =
+        statement_timeout_millis(&mut conn, "SESSION", Some(Duration::from_secs(5))).await?;
+    assert_eq!(timeout, 5000);
+
+    // restored before the next query without a timeout
+    let timeout = statement_timeout_millis(&mut conn, "SESSION", None).await?;
+    assert_eq!(timeout, default);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_executes_many_statements() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let results: Vec<_> = conn
+        .execute_many_statements(
+            "CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY); \
+             INSERT INTO users (id) VALUES (1), (2); \
+             SELECT id FROM users ORDER BY id",
+        )
+        .try_collect()
+        .await?;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[1].len(), 1);
+    assert_eq!(
+        results[1][0].as_ref().left().map(|r| r.rows_affected()),
+        Some(2)
+    );
+
+    let ids: Vec<i32> = results[2]
+        .iter()
+        .filter_map(|v| v.as_ref().right())
+        .map(|row| row.get(0))
+        .collect();
+    assert_eq!(ids, [1, 2]);
+
+    // the first statement to fail ends the stream
+    let mut results = conn.execute_many_statements("SELECT 1; SELECT * FROM not_a_table; SELECT 2");
+
+    assert!(results.try_next().await?.is_some());
+    assert!(results.try_next().await.is_err());
+    drop(results);
+
+    // the connection can be used again afterwards
+    let one: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(one, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_rejects_many_statements_when_disabled() -> anyhow::Result<()> {
+    let options: MySqlConnectOptions = dotenvy::var("DATABASE_URL")?.parse()?;
+    let mut conn = MySqlConnection::connect_with(&options.multi_statements(false)).await?;
+
+    let result: Result<Vec<_>, _> = conn
+        .execute_many_statements("SELECT 1; SELECT 2")
+        .try_collect()
+        .await;
+    assert!(matches!(result, Err(sqlx::Error::Configuration(_))));
+
+    assert!(conn.execute("SELECT 1; SELECT 2").await.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fetches_the_password_for_every_connection() -> anyhow::Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let url = url::Url::parse(&dotenvy::var("DATABASE_URL")?)?;
+    let password = url.password().unwrap_or_default().to_owned();
+
+    let fetched = Arc::new(AtomicUsize::new(0));
+
+    let options: MySqlConnectOptions = url.as_str().parse()?;
+    let options = options.password_from_fn({
+        let fetched = fetched.clone();
+        move || {
+            fetched.fetch_add(1, Ordering::SeqCst);
+            let password = password.clone();
+            async move { Ok(password) }
+        }
+    });
+
+    MySqlConnection::connect_with(&options)
+        .await?
+        .close()
+        .await?;
+    MySqlConnection::connect_with(&options)
+        .await?
+        .close()
+        .await?;
+
+    assert_eq!(fetched.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_allocates_values_from_sequences() -> anyhow::Result<()> {
+    use sqlx::Sequence;
+
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("DELETE FROM _sqlx_sequences WHERE name = 'it_allocates_values'")
+        .await
+        .ok();
+
+    // emulated with a table, unless the server is MariaDB and the sequence was created
+    let first = Sequence::next(&mut conn, "it_allocates_values").await?;
+    assert_eq!(first, 1);
+    assert_eq!(
+        Sequence::next_n(&mut conn, "it_allocates_values", 3).await?,
+        [2, 3, 4]
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_allocates_values_from_sequences_in_a_transaction() -> anyhow::Result<()> {
+    use sqlx::Sequence;
+
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE sequence_orders (id BIGINT PRIMARY KEY)")
+        .await?;
+
+    // the first use creates the table of emulated sequences, if needed
+    Sequence::next(&mut conn, "it_allocates_values_in_a_transaction").await?;
+
+    let mut tx = conn.begin().await?;
+
+    let id = Sequence::next(&mut *tx, "it_allocates_values_in_a_transaction").await?;
+    sqlx::query("INSERT INTO sequence_orders (id) VALUES (?)")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+    // allocating another value does not commit the pending insert
+    Sequence::next(&mut *tx, "it_allocates_values_in_a_transaction").await?;
+
+    tx.rollback().await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sequence_orders")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_rejects_auth_plugins_that_are_not_allowed() -> anyhow::Result<()> {
+    let options: MySqlConnectOptions = dotenvy::var("DATABASE_URL")?.parse()?;
+
+    // the server asks for a different plugin than the one offered
+    let result =
+        MySqlConnection::connect_with(&options.auth_plugins([MySqlAuthPlugin::MySqlClearPassword]))
+            .await;
+
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_decodes_bools_strictly_when_enabled() -> anyhow::Result<()> {
+    let options: MySqlConnectOptions = dotenvy::var("DATABASE_URL")?.parse()?;
+
+    for strict in [false, true] {
+        let mut conn = MySqlConnection::connect_with(&options.clone().strict_bools(strict)).await?;
+
+        conn.execute("CREATE TEMPORARY TABLE flags (flag TINYINT(1) NOT NULL)")
+            .await?;
+        conn.execute("INSERT INTO flags (flag) VALUES (0), (1), (2)")
+            .await?;
+
+        let flags: Vec<Result<bool, _>> = sqlx::query("SELECT flag FROM flags ORDER BY flag")
+            .try_map(|row: MySqlRow| Ok(row.try_get::<bool, _>(0)))
+            .fetch_all(&mut conn)
+            .await?;
+
+        assert!(matches!(flags[0], Ok(false)));
+        assert!(matches!(flags[1], Ok(true)));
+        assert_eq!(flags[2].is_err(), strict);
+    }
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_executes() -> anyhow::Result<()> {
     let mut conn = new::<MySql>().await?;
@@ -353,6 +607,45 @@ INSERT INTO too_many_years ( the ) VALUES ( ? );
 
     assert_eq!(the, 2142);
 
+    let the: i32 = sqlx::query_scalar("SELECT the FROM too_many_years")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(the, 2142);
+
+    Ok(())
+}
+
+#[cfg(feature = "rust_decimal")]
+#[sqlx_macros::test]
+async fn it_can_bind_and_return_unsigned_decimals() -> anyhow::Result<()> {
+    use sqlx::types::Decimal;
+
+    let mut conn = new::<MySql>().await?;
+
+    sqlx::raw_sql(
+        r#"
+CREATE TEMPORARY TABLE prices (
+    id INT PRIMARY KEY AUTO_INCREMENT,
+    amount DECIMAL(10, 2) UNSIGNED NOT NULL
+);
+    "#,
+    )
+    .execute(&mut conn)
+    .await?;
+
+    let amount: Decimal = "12345.67".parse()?;
+
+    sqlx::query("INSERT INTO prices ( amount ) VALUES ( ? )")
+        .bind(amount)
+        .execute(&mut conn)
+        .await?;
+
+    let row = conn.fetch_one("SELECT amount FROM prices").await?;
+
+    assert_eq!(row.column(0).type_info().name(), "DECIMAL UNSIGNED");
+    assert_eq!(row.try_get::<Decimal, _>(0)?, amount);
+
     Ok(())
 }
 
@@ -386,6 +679,21 @@ async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_exposes_statement_parameter_types() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let statement = conn
+        .prepare("SELECT * FROM tweet WHERE id = ? AND text = ?")
+        .await?;
+
+    // the server may not report a useful type for every parameter
+    let parameters = statement.parameters().and_then(|p| p.left()).unwrap();
+    assert_eq!(parameters.len(), 2);
+
+    Ok(())
+}
+
 // repro is more reliable with the basic scheduler used by `#[tokio::test]`
 #[cfg(feature = "_rt-tokio")]
 #[tokio::test]
@@ -500,6 +808,49 @@ async fn it_can_work_with_transactions() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_roll_back_to_a_named_savepoint() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+    sqlx::raw_sql("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY);")
+        .execute(&mut conn)
+        .await?;
+
+    let mut tx = conn.begin().await?;
+    sqlx::query("INSERT INTO users (id) VALUES (?)")
+        .bind(1_i32)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.savepoint("before_second").await?;
+    sqlx::query("INSERT INTO users (id) VALUES (?)")
+        .bind(2_i32)
+        .execute(&mut *tx)
+        .await?;
+
+    // the savepoint can be rolled back to more than once
+    for _ in 0..2 {
+        tx.rollback_to("before_second").await?;
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+            .fetch_one(&mut *tx)
+            .await?;
+        assert_eq!(count, 1);
+    }
+
+    tx.release("before_second").await?;
+
+    // names are not quoted, so anything but a plain identifier is rejected
+    assert!(tx.savepoint("a; DROP TABLE users").await.is_err());
+    assert!(tx.savepoint("_sqlx_savepoint_1").await.is_err());
+
+    tx.commit().await?;
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_handle_split_packets() -> anyhow::Result<()> {
     // This will only take effect on new connections
@@ -578,3 +929,177 @@ async fn select_statement_count(conn: &mut MySqlConnection) -> Result<i64, sqlx:
     .fetch_one(conn)
     .await
 }
+
+#[sqlx_macros::test]
+async fn it_sets_the_default_statement_timeout() -> anyhow::Result<()> {
+    let pool = MySqlPoolOptions::new()
+        .max_connections(1)
+        .default_statement_timeout(Duration::from_millis(1500))
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    assert_eq!(
+        statement_timeout_millis(&mut conn, "SESSION", None).await?,
+        1500
+    );
+
+    conn.set_statement_timeout(None).await?;
+    assert_eq!(conn.statement_timeout(), None);
+    assert_eq!(
+        statement_timeout_millis(&mut conn, "SESSION", None).await?,
+        0
+    );
+
+    // the timeout is reset when the connection is returned to the pool
+    drop(conn);
+
+    let mut conn = pool.acquire().await?;
+
+    assert_eq!(
+        statement_timeout_millis(&mut conn, "SESSION", None).await?,
+        1500
+    );
+
+    Ok(())
+}
+
+// The `SESSION` or `GLOBAL` statement timeout in milliseconds, read by a query with
+// `query_timeout`: `max_statement_time` (in seconds) on MariaDB, `max_execution_time` on MySQL.
+async fn statement_timeout_millis(
+    conn: &mut MySqlConnection,
+    scope: &str,
+    query_timeout: Option<Duration>,
+) -> anyhow::Result<u64> {
+    let version: String = sqlx::query_scalar("SELECT VERSION()")
+        .fetch_one(&mut *conn)
+        .await?;
+
+    let sql = if version.contains("MariaDB") {
+        format!("SELECT CAST(@@{scope}.max_statement_time * 1000 AS UNSIGNED)")
+    } else {
+        format!("SELECT @@{scope}.max_execution_time")
+    };
+
+    let mut query = sqlx::query_scalar(&sql);
+
+    if let Some(timeout) = query_timeout {
+        query = query.timeout(timeout);
+    }
+
+    Ok(query.fetch_one(&mut *conn).await?)
+}
+
+#[cfg(feature = "uuid")]
+#[sqlx_macros::test]
+async fn it_binds_and_decodes_uuids_in_binary16_columns() -> anyhow::Result<()> {
+    use sqlx::types::Uuid;
+
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE binary16_uuids (id BINARY(16) PRIMARY KEY)")
+        .await?;
+
+    let id = Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19")?;
+
+    sqlx::query("INSERT INTO binary16_uuids (id) VALUES (?)")
+        .bind(id)
+        .execute(&mut conn)
+        .await?;
+
+    let row = sqlx::query("SELECT id FROM binary16_uuids WHERE id = ?")
+        .bind(id)
+        .fetch_one(&mut conn)
+        .await?;
+
+    let type_info = row.column(0).type_info();
+    assert_eq!(type_info.name(), "BINARY");
+    assert!(type_info.__is_binary16());
+
+    assert_eq!(row.try_get::<Uuid, _>(0)?, id);
+
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[sqlx_macros::test]
+async fn it_serializes_rows() -> anyhow::Result<()> {
+    use serde_json::json;
+
+    let mut conn = new::<MySql>().await?;
+
+    let row = sqlx::query(
+        "SELECT CAST(1 AS SIGNED) AS id, 'a' AS name, CAST(12.50 AS DECIMAL(10, 2)) AS price, \
+         X'DEADBEEF' AS data, CAST('{\"a\": [1]}' AS JSON) AS doc, \
+         CAST('2024-05-01 12:30:00' AS DATETIME) AS created_at, NULL AS missing",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(
+        serde_json::to_value(&row)?,
+        json!({
+            "id": 1,
+            "name": "a",
+            "price": "12.50",
+            "data": "deadbeef",
+            "doc": { "a": [1] },
+            "created_at": "2024-05-01T12:30:00",
+            "missing": null,
+        })
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_estimates_rows() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE events (id INT PRIMARY KEY);
+INSERT INTO events (id) VALUES (1), (2), (3), (4), (5), (6), (7), (8), (9), (10);
+ANALYZE TABLE events;
+        "#,
+    )
+    .await?;
+
+    // InnoDB statistics are sampled, so these are only close to the actual count
+    let estimate = conn.estimate_rows("events").await?;
+    assert!((1..=20).contains(&estimate), "{estimate}");
+
+    let estimate = conn.estimate_rows("SELECT * FROM events").await?;
+    assert!((1..=20).contains(&estimate), "{estimate}");
+
+    assert_eq!(conn.estimate_rows("SELECT 1").await?, 1);
+
+    assert!(conn.estimate_rows("no_such_table").await.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_returns_generated_id() -> anyhow::Result<()> {
+    use sqlx::QueryBuilder;
+
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        "CREATE TEMPORARY TABLE users (id BIGINT PRIMARY KEY AUTO_INCREMENT, name TEXT NOT NULL)",
+    )
+    .await?;
+
+    let mut builder = QueryBuilder::<MySql>::new("INSERT INTO users (name) VALUES (");
+    builder.push_bind("alice").push(")");
+    let first: i64 = builder.returning_id("id", &mut conn).await?;
+
+    let mut builder = QueryBuilder::<MySql>::new("INSERT INTO users (name) VALUES (");
+    builder.push_bind("bob").push(")");
+    let second: i64 = builder.returning_id("id", &mut conn).await?;
+
+    assert_eq!(second, first + 1);
+
+    Ok(())
+}
@@ -176,3 +176,41 @@ async fn it_gets_comments(pool: MySqlPool) -> sqlx::Result<()> {
 
     Ok(())
 }
+
+#[sqlx::test(
+    migrations = "tests/mysql/migrations",
+    fixtures("users", "posts", "comments")
+)]
+async fn it_snapshots_fixtures(pool: MySqlPool) -> anyhow::Result<()> {
+    use sqlx::testing::TestSupport;
+    use sqlx::MySql;
+
+    let mut conn = pool.acquire().await?;
+
+    let comments =
+        r#"SELECT comment_id, post_id, user_id, content FROM comment ORDER BY comment_id"#;
+
+    let expected: Vec<(i32, i32, i32, String)> =
+        sqlx::query_as(comments).fetch_all(&mut *conn).await?;
+
+    let fixture = <MySql as TestSupport>::snapshot(&mut conn)
+        .await?
+        .additive_fixture()?
+        .to_string();
+
+    assert!(fixture.contains("INSERT INTO `user` (`user_id`, `username`)"));
+    assert!(!fixture.contains("_sqlx_migrations"));
+
+    // recreate the data from the fixture
+    sqlx::raw_sql("DELETE FROM comment; DELETE FROM post; DELETE FROM user;")
+        .execute(&mut *conn)
+        .await?;
+    sqlx::raw_sql(&fixture).execute(&mut *conn).await?;
+
+    let comments: Vec<(i32, i32, i32, String)> =
+        sqlx::query_as(comments).fetch_all(&mut *conn).await?;
+
+    assert_eq!(comments, expected);
+
+    Ok(())
+}
@@ -9,7 +9,7 @@ use sqlx::{Executor, Row};
 
 use sqlx::types::Text;
 
-use sqlx::mysql::types::MySqlTime;
+use sqlx::mysql::types::{MySqlDecimal, MySqlTime};
 use sqlx_mysql::types::MySqlTimeSign;
 
 use sqlx_test::{new, test_type};
@@ -86,7 +86,9 @@ test_type!(mysql_time<MySqlTime>(MySql,
 
 #[cfg(feature = "chrono")]
 mod chrono {
-    use sqlx::types::chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+    use sqlx::types::chrono::{
+        DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Utc,
+    };
 
     use super::*;
 
@@ -103,6 +105,27 @@ mod chrono {
         "TIME '05:10:20.115100'" == NaiveTime::from_hms_micro_opt(5, 10, 20, 115100).unwrap()
     ));
 
+    test_type!(chrono_time_delta<TimeDelta>(MySql,
+        "TIME '00:00:00.000000'" == TimeDelta::zero(),
+        "TIME '838:59:59.0'" == TimeDelta::try_seconds(838 * 3600 + 59 * 60 + 59).unwrap(),
+        "TIME '-838:59:59.0'" == TimeDelta::try_seconds(-(838 * 3600 + 59 * 60 + 59)).unwrap(),
+        "TIME '-25:10:20.115100'" == -TimeDelta::try_seconds(25 * 3600 + 10 * 60 + 20).unwrap() - TimeDelta::microseconds(115100)
+    ));
+
+    #[sqlx_macros::test]
+    async fn test_encode_chrono_time_delta_out_of_range() -> anyhow::Result<()> {
+        let mut conn = new::<MySql>().await?;
+
+        let res = sqlx::query("SELECT ?")
+            .bind(TimeDelta::try_hours(-839).unwrap())
+            .fetch_one(&mut conn)
+            .await;
+
+        assert!(matches!(res, Err(sqlx::Error::Encode(_))));
+
+        Ok(())
+    }
+
     test_type!(chrono_date_time<NaiveDateTime>(MySql,
         "TIMESTAMP '2019-01-02 05:10:20'" == NaiveDate::from_ymd_opt(2019, 1, 2).unwrap().and_hms_opt(5, 10, 20).unwrap()
     ));
@@ -157,7 +180,7 @@ mod chrono {
 mod time_tests {
     use time::macros::{date, time};
 
-    use sqlx::types::time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+    use sqlx::types::time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
 
     use super::*;
 
@@ -177,6 +200,14 @@ mod time_tests {
         "TIME '05:10:20.115100'" == time!(5:10:20.115100)
     ));
 
+    test_type!(time_duration<Duration>(
+        MySql,
+        "TIME '00:00:00.000000'" == Duration::ZERO,
+        "TIME '838:59:59.0'" == Duration::new(838 * 3600 + 59 * 60 + 59, 0),
+        "TIME '-838:59:59.0'" == Duration::new(-(838 * 3600 + 59 * 60 + 59), 0),
+        "TIME '-25:10:20.115100'" == -Duration::new(25 * 3600 + 10 * 60 + 20, 115_100_000)
+    ));
+
     test_type!(time_date_time<PrimitiveDateTime>(
         MySql,
         "TIMESTAMP '2019-01-02 05:10:20'" == date!(2019 - 1 - 2).with_time(time!(5:10:20)),
@@ -254,6 +285,14 @@ test_type!(decimal<sqlx::types::Decimal>(MySql,
     "CAST(12345.6789 AS DECIMAL(9, 4))" == sqlx::types::Decimal::from_str("12345.6789").unwrap(),
 ));
 
+test_type!(mysql_decimal<MySqlDecimal>(MySql,
+    "CAST(0 AS DECIMAL(1, 0))" == "0".parse::<MySqlDecimal>().unwrap(),
+    "CAST(12.34 AS DECIMAL(6, 4))" == "12.3400".parse::<MySqlDecimal>().unwrap(),
+    "CAST(-12345.6789 AS DECIMAL(9, 4))" == "-12345.6789".parse::<MySqlDecimal>().unwrap(),
+    "CAST('12345678901234567890123456789012345.678901234567890123456789012345' AS DECIMAL(65, 30))"
+        == "12345678901234567890123456789012345.678901234567890123456789012345".parse::<MySqlDecimal>().unwrap(),
+));
+
 #[cfg(feature = "json")]
 mod json_tests {
     use serde_json::{json, Value as JsonValue};
@@ -342,6 +381,61 @@ CREATE TEMPORARY TABLE with_bits (
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_decimal_exact_round_trip() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE with_decimals (
+    id INT PRIMARY KEY AUTO_INCREMENT,
+    value DECIMAL(65, 30) NOT NULL
+);
+    "#,
+    )
+    .await?;
+
+    let values = [
+        "99999999999999999999999999999999999.999999999999999999999999999999",
+        "-99999999999999999999999999999999999.999999999999999999999999999999",
+        "0.000000000000000000000000000001",
+        "1.000000000000000000000000000000",
+    ];
+
+    for value in values {
+        sqlx::query("INSERT INTO with_decimals (value) VALUES (?)")
+            .bind(value.parse::<MySqlDecimal>().unwrap())
+            .execute(&mut conn)
+            .await?;
+    }
+
+    // BINARY
+    let decoded: Vec<MySqlDecimal> =
+        sqlx::query_scalar("SELECT value FROM with_decimals ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(
+        decoded.iter().map(|v| v.as_str()).collect::<Vec<_>>(),
+        values
+    );
+
+    // TEXT
+    let rows = conn
+        .fetch_all("SELECT value FROM with_decimals ORDER BY id")
+        .await?;
+
+    for (row, value) in rows.iter().zip(values) {
+        assert_eq!(row.try_get::<MySqlDecimal, _>(0)?.as_str(), value);
+    }
+
+    // too many digits for `Decimal`, but not for `MySqlDecimal`
+    #[cfg(feature = "rust_decimal")]
+    assert!(sqlx::types::Decimal::try_from(decoded[0].clone()).is_err());
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_text_adapter() -> anyhow::Result<()> {
     #[derive(sqlx::FromRow, Debug, PartialEq, Eq)]
@@ -562,6 +562,36 @@ async fn test_from_row_with_rename_all() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "macros")]
+#[sqlx_macros::test]
+async fn test_from_row_with_rename_all_fn() -> anyhow::Result<()> {
+    fn column_name(field: &str) -> String {
+        format!("acct_{}", field.to_uppercase())
+    }
+
+    #[derive(Debug, sqlx::FromRow)]
+    #[sqlx(rename_all = column_name)]
+    struct Account {
+        id: i32,
+        name: String,
+        #[sqlx(default)]
+        surname: Option<String>,
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let account: Account =
+        sqlx::query_as(r#"SELECT * from (VALUES (1, 'foo')) accounts("acct_ID", "acct_NAME")"#)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(1, account.id);
+    assert_eq!("foo", account.name);
+    assert_eq!(None, account.surname);
+
+    Ok(())
+}
+
 #[cfg(feature = "macros")]
 #[sqlx_macros::test]
 async fn test_from_row_tuple() -> anyhow::Result<()> {
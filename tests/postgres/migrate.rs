@@ -4,6 +4,7 @@ use sqlx::postgres::{PgConnection, Postgres};
 use sqlx::Executor;
 use sqlx::Row;
 use std::path::Path;
+use std::time::Duration;
 
 #[sqlx::test(migrations = false)]
 async fn simple(mut conn: PoolConnection<Postgres>) -> anyhow::Result<()> {
@@ -85,6 +86,71 @@ async fn no_tx(mut conn: PoolConnection<Postgres>) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx::test(migrations = false)]
+async fn times_out_waiting_for_the_lock(pool: sqlx::PgPool) -> anyhow::Result<()> {
+    use sqlx::migrate::{Migrate, MigrateError};
+
+    let mut conn = pool.acquire().await?;
+    clean_up(&mut conn).await?;
+
+    let mut migrator = Migrator::new(Path::new("tests/postgres/migrations_simple")).await?;
+    migrator.set_lock_timeout(Duration::from_millis(500));
+
+    // another client is applying migrations
+    let mut other = pool.acquire().await?;
+    other.lock().await?;
+
+    let res = migrator.run(&mut conn).await;
+    assert!(matches!(res, Err(MigrateError::LockTimeout(_))));
+
+    other.unlock().await?;
+    migrator.run(&mut conn).await?;
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = false)]
+async fn in_schema(mut conn: PoolConnection<Postgres>) -> anyhow::Result<()> {
+    clean_up(&mut conn).await?;
+    conn.execute("DROP SCHEMA IF EXISTS tenant_a, tenant_b CASCADE")
+        .await?;
+
+    let search_path: String = conn.fetch_one("SHOW search_path").await?.get(0);
+
+    let migrator = Migrator::new(Path::new("tests/postgres/migrations_simple")).await?;
+
+    // each schema keeps its own migration history
+    for schema in ["tenant_a", "tenant_b"] {
+        migrator.run_in_schema(&mut conn, schema).await?;
+
+        let res: String = conn
+            .fetch_one(&*format!(
+                "SELECT some_payload FROM {schema}.migrations_simple_test"
+            ))
+            .await?
+            .get(0);
+        assert_eq!(res, "110_suffix");
+
+        let applied: i64 = conn
+            .fetch_one(&*format!("SELECT COUNT(*) FROM {schema}._sqlx_migrations"))
+            .await?
+            .get(0);
+        assert_eq!(applied, 2);
+    }
+
+    // running it a 2nd time should still work
+    migrator.run_in_schema(&mut conn, "tenant_a").await?;
+
+    // the search path is restored afterwards
+    let res: String = conn.fetch_one("SHOW search_path").await?.get(0);
+    assert_eq!(res, search_path);
+
+    conn.execute("DROP SCHEMA tenant_a, tenant_b CASCADE")
+        .await?;
+
+    Ok(())
+}
+
 /// Ensure that we have a clean initial state.
 async fn clean_up(conn: &mut PgConnection) -> anyhow::Result<()> {
     conn.execute("DROP DATABASE IF EXISTS test_db").await.ok();
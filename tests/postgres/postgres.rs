@@ -2,10 +2,13 @@ use futures::{Stream, StreamExt, TryStreamExt};
 
 use sqlx::postgres::types::Oid;
 use sqlx::postgres::{
-    PgAdvisoryLock, PgConnectOptions, PgConnection, PgDatabaseError, PgErrorPosition, PgListener,
-    PgPoolOptions, PgRow, PgSeverity, Postgres,
+    PgAdvisoryLock, PgConnectOptions, PgConnection, PgDatabaseError, PgErrorPosition,
+    PgHoldableCursor, PgListener, PgPoolOptions, PgRow, PgSeverity, Postgres,
+};
+use sqlx::{
+    AccessMode, Column, Connection, Executor, IsolationLevel, Row, Statement, TransactionOptions,
+    TypeInfo,
 };
-use sqlx::{Column, Connection, Executor, Row, Statement, TypeInfo};
 use sqlx_core::{bytes::Bytes, error::BoxDynError};
 use sqlx_test::{new, pool, setup_if_needed};
 use std::env;
@@ -232,6 +235,56 @@ CREATE TEMPORARY TABLE json_stuff (obj json, obj2 jsonb);
     Ok(())
 }
 
+#[cfg(feature = "json")]
+#[sqlx_macros::test]
+async fn it_serializes_rows() -> anyhow::Result<()> {
+    use serde_json::json;
+    use sqlx::row::{DecimalFormat, SerializeColumns, SerializeOptions};
+
+    let mut conn = new::<Postgres>().await?;
+
+    let row = sqlx::query(
+        "SELECT 1::int4 AS id, 'a'::text AS name, 12.50::numeric AS price, true AS active, \
+         '\\xdeadbeef'::bytea AS data, '{\"a\": [1]}'::jsonb AS doc, \
+         '2024-05-01'::date AS day, '2024-05-01 12:30:00.5+00'::timestamptz AS created_at, \
+         'a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11'::uuid AS uuid, NULL::text AS missing",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(
+        serde_json::to_value(&row)?,
+        json!({
+            "id": 1,
+            "name": "a",
+            "price": "12.50",
+            "active": true,
+            "data": "deadbeef",
+            "doc": { "a": [1] },
+            "day": "2024-05-01",
+            "created_at": "2024-05-01T12:30:00.500000Z",
+            "uuid": "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11",
+            "missing": null,
+        })
+    );
+
+    let options = SerializeOptions::new().decimals(DecimalFormat::Number);
+
+    assert_eq!(
+        serde_json::to_value(row.serialize_with(options))?["price"],
+        json!(12.5)
+    );
+
+    // columns without a JSON representation are an error
+    let row = sqlx::query("SELECT '1 day'::interval AS period")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(serde_json::to_value(&row).is_err());
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_works_with_cache_disabled() -> anyhow::Result<()> {
     setup_if_needed();
@@ -252,6 +305,134 @@ async fn it_works_with_cache_disabled() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_allocates_values_from_sequences() -> anyhow::Result<()> {
+    use sqlx::Sequence;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY SEQUENCE \"Order IDs\" START 10")
+        .await?;
+
+    assert_eq!(Sequence::next(&mut conn, "\"Order IDs\"").await?, 10);
+    assert_eq!(
+        Sequence::next_n(&mut conn, "\"Order IDs\"", 3).await?,
+        [11, 12, 13]
+    );
+
+    // sequences must be created beforehand
+    assert!(Sequence::next(&mut conn, "no_such_sequence").await.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fetches_the_password_for_every_new_connection() -> anyhow::Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    setup_if_needed();
+
+    let url = url::Url::parse(&env::var("DATABASE_URL")?)?;
+    let password = url.password().unwrap_or_default().to_owned();
+
+    let fetched = Arc::new(AtomicUsize::new(0));
+
+    let options: PgConnectOptions = url.as_str().parse()?;
+    let options = options.password_provider({
+        let fetched = fetched.clone();
+        move || {
+            fetched.fetch_add(1, Ordering::SeqCst);
+            let password = password.clone();
+            async move { Ok(password) }
+        }
+    });
+
+    let pool = PgPoolOptions::new()
+        .max_connections(2)
+        .connect_with(options)
+        .await?;
+
+    let conn1 = pool.acquire().await?;
+    let conn2 = pool.acquire().await?;
+
+    drop((conn1, conn2));
+
+    // the provider is only called if the server asks for a password
+    assert!(matches!(fetched.load(Ordering::SeqCst), 0 | 2));
+
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_replaces_connections_after_reloading_tls() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    // CI also runs this suite over TLS, with `sslmode=verify-ca`
+    let pool = PgPoolOptions::new()
+        .max_connections(2)
+        .connect(&env::var("DATABASE_URL")?)
+        .await?;
+
+    let held = pool.acquire().await?;
+    drop(pool.acquire().await?);
+
+    // connections are returned to the pool in the background
+    while pool.num_idle() < 1 {
+        sqlx_core::rt::sleep(Duration::from_millis(1)).await;
+    }
+
+    let opened = pool.metrics().connections_opened();
+
+    pool.reload_tls().await;
+
+    // the idle connection is closed immediately
+    assert_eq!(pool.num_idle(), 0);
+    assert_eq!(pool.size(), 1);
+
+    // the held connection is closed when it's returned
+    drop(held);
+
+    let _a = pool.acquire().await?;
+    let _b = pool.acquire().await?;
+
+    let metrics = pool.metrics();
+    assert_eq!(metrics.connections_opened(), opened + 2);
+    assert_eq!(metrics.connections_closed(), 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fails_over_from_an_unreachable_replica() -> anyhow::Result<()> {
+    setup_if_needed();
+
+    let primary_options: PgConnectOptions = env::var("DATABASE_URL")?.parse()?;
+    // nothing listens on port 1, so connecting is refused and retried until the acquire timeout
+    let replica_options = primary_options.clone().port(1);
+
+    let pool = PgPoolOptions::new()
+        .acquire_timeout(Duration::from_secs(30))
+        .connect_replicated_with(primary_options, [replica_options])
+        .await?
+        .replica_acquire_timeout(Duration::from_millis(200));
+
+    let started = std::time::Instant::now();
+
+    let one: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&pool).await?;
+    assert_eq!(one, 1);
+
+    assert!(started.elapsed() < Duration::from_secs(10));
+
+    // the replica is then skipped
+    assert!(std::ptr::eq(pool.read(), pool.write()));
+
+    pool.close().await;
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_executes_with_pool() -> anyhow::Result<()> {
     let pool = sqlx_test::pool::<Postgres>().await?;
@@ -562,6 +743,54 @@ async fn it_can_work_with_nested_transactions() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_roll_back_to_a_named_savepoint() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TABLE IF NOT EXISTS _sqlx_users_5082 (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    conn.execute("TRUNCATE _sqlx_users_5082").await?;
+
+    let mut tx = conn.begin().await?;
+    sqlx::query("INSERT INTO _sqlx_users_5082 (id) VALUES ($1)")
+        .bind(1_i32)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.savepoint("before_second").await?;
+    sqlx::query("INSERT INTO _sqlx_users_5082 (id) VALUES ($1)")
+        .bind(2_i32)
+        .execute(&mut *tx)
+        .await?;
+
+    // the savepoint can be rolled back to more than once
+    for _ in 0..2 {
+        tx.rollback_to("before_second").await?;
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM _sqlx_users_5082")
+            .fetch_one(&mut *tx)
+            .await?;
+        assert_eq!(count, 1);
+    }
+
+    tx.release("before_second").await?;
+
+    // names are not quoted, so anything but a plain identifier is rejected
+    assert!(tx
+        .savepoint("a; DROP TABLE _sqlx_users_5082")
+        .await
+        .is_err());
+    assert!(tx.savepoint("_sqlx_savepoint_1").await.is_err());
+
+    tx.commit().await?;
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM _sqlx_users_5082")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_drop_multiple_transactions() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -595,6 +824,67 @@ async fn it_can_drop_multiple_transactions() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_begin_with_isolation_level_and_access_mode() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut tx = conn
+        .begin_with(IsolationLevel::Serializable, AccessMode::ReadOnly)
+        .await?;
+
+    assert_eq!(tx.isolation_level(), Some(IsolationLevel::Serializable));
+    assert_eq!(tx.access_mode(), Some(AccessMode::ReadOnly));
+
+    let (isolation_level, read_only): (String, String) = sqlx::query_as(
+        "SELECT current_setting('transaction_isolation'), current_setting('transaction_read_only')",
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    assert_eq!(isolation_level, "serializable");
+    assert_eq!(read_only, "on");
+
+    // the isolation level can not be set for a savepoint
+    assert!(tx
+        .begin_with(IsolationLevel::ReadCommitted, AccessMode::ReadWrite)
+        .await
+        .is_err());
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_begin_with_options() -> anyhow::Result<()> {
+    let pool = pool::<Postgres>().await?;
+
+    let mut tx = pool
+        .begin_with_options(
+            TransactionOptions::new()
+                .isolation_level(IsolationLevel::Serializable)
+                .read_only(true)
+                .deferrable(true),
+        )
+        .await?;
+
+    let (isolation_level, read_only, deferrable): (String, String, String) = sqlx::query_as(
+        "SELECT current_setting('transaction_isolation'), \
+                current_setting('transaction_read_only'), \
+                current_setting('transaction_deferrable')",
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    assert_eq!(isolation_level, "serializable");
+    assert_eq!(read_only, "on");
+    assert_eq!(deferrable, "on");
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
 // run with `cargo test --features postgres -- --ignored --nocapture pool_smoke_test`
 #[ignore]
 #[sqlx_macros::test]
@@ -813,6 +1103,53 @@ async fn it_closes_statement_from_cache_issue_470() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_reprepares_statements_after_the_result_type_changes() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+
+    for auto_reprepare in [true, false] {
+        let mut conn =
+            PgConnection::connect_with(&options.clone().auto_reprepare(auto_reprepare)).await?;
+
+        conn.execute("CREATE TEMPORARY TABLE reprepare_test (id INTEGER PRIMARY KEY)")
+            .await?;
+        conn.execute("INSERT INTO reprepare_test (id) VALUES (1)")
+            .await?;
+
+        let row = sqlx::query("SELECT * FROM reprepare_test")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(row.len(), 1);
+
+        conn.execute("ALTER TABLE reprepare_test ADD COLUMN name TEXT NOT NULL DEFAULT 'a'")
+            .await?;
+
+        let result = sqlx::query("SELECT * FROM reprepare_test")
+            .fetch_one(&mut conn)
+            .await;
+
+        if auto_reprepare {
+            let row = result?;
+
+            assert_eq!(row.len(), 2);
+            assert_eq!(row.try_get::<String, _>("name")?, "a");
+            assert_eq!(conn.cached_statements_size(), 1);
+        } else {
+            let err = result.unwrap_err();
+
+            assert_eq!(
+                err.into_database_error().unwrap().code().as_deref(),
+                Some("0A000")
+            );
+        }
+    }
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_sets_application_name() -> anyhow::Result<()> {
     sqlx_test::setup_if_needed();
@@ -833,6 +1170,57 @@ async fn it_sets_application_name() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_reports_notices() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let mut options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    options = options.notice_callback({
+        let received = received.clone();
+        move |notice| received.lock().unwrap().push(notice.message().to_owned())
+    });
+
+    let mut conn = PgConnection::connect_with(&options).await?;
+    let mut notices = conn.notices();
+
+    conn.execute("DO $$ BEGIN RAISE NOTICE 'hello from plpgsql'; END $$")
+        .await?;
+
+    assert_eq!(*received.lock().unwrap(), ["hello from plpgsql"]);
+
+    let notice = notices.next().await.unwrap();
+
+    assert_eq!(notice.severity(), PgSeverity::Notice);
+    assert_eq!(notice.message(), "hello from plpgsql");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_ends_notices_when_returned_to_pool() -> anyhow::Result<()> {
+    let pool = pool::<Postgres>().await?;
+
+    let mut conn = pool.acquire().await?;
+    let notices = conn.notices();
+
+    conn.execute("DO $$ BEGIN RAISE NOTICE 'first user'; END $$")
+        .await?;
+    drop(conn);
+
+    // the stream ends once the connection is back in the pool
+    let messages: Vec<String> = sqlx_core::rt::timeout(
+        Duration::from_secs(5),
+        notices.map(|notice| notice.message().to_owned()).collect(),
+    )
+    .await?;
+
+    assert_eq!(messages, ["first user"]);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_handle_parameter_status_message_issue_484() -> anyhow::Result<()> {
     new::<Postgres>().await?.execute("SET NAMES 'UTF8'").await?;
@@ -869,6 +1257,37 @@ async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_exposes_statement_parameter_types() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let statement = conn.prepare("SELECT $1::int4, $2::uuid").await?;
+    let parameters = statement.parameters().and_then(|p| p.left()).unwrap();
+
+    let names: Vec<&str> = parameters.iter().map(|ty| ty.name()).collect();
+    assert_eq!(names, ["INT4", "UUID"]);
+
+    Ok(())
+}
+
+#[cfg(feature = "any")]
+#[sqlx_macros::test]
+async fn it_exposes_statement_parameters_through_any() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let mut conn = sqlx::AnyConnection::connect(&env::var("DATABASE_URL")?).await?;
+
+    let statement = conn.prepare("SELECT $1::int4, $2::text").await?;
+    let parameters = statement.parameters().and_then(|p| p.left()).unwrap();
+    assert_eq!(parameters.len(), 2);
+
+    // the `Any` driver doesn't support `POINT`, so only the number of parameters is known
+    let statement = conn.prepare("SELECT $1::int4, $2::point IS NULL").await?;
+    assert_eq!(statement.parameters().and_then(|p| p.right()), Some(2));
+
+    Ok(())
+}
+
 // repro is more reliable with the basic scheduler used by `#[tokio::test]`
 #[cfg(feature = "_rt-tokio")]
 #[tokio::test]
@@ -1058,35 +1477,187 @@ async fn test_listener_cleanup() -> anyhow::Result<()> {
 }
 
 #[sqlx_macros::test]
-async fn test_pg_listener_allows_pool_to_close() -> anyhow::Result<()> {
-    let pool = pool::<Postgres>().await?;
+async fn it_counts_listens_on_the_same_channel() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgListener, PgListenerHub};
+    use sqlx_core::rt::timeout;
 
-    // acquires and holds a connection which would normally prevent the pool from closing
+    let pool = pool::<Postgres>().await?;
     let mut listener = PgListener::connect_with(&pool).await?;
 
-    sqlx_core::rt::spawn(async move {
-        listener.recv().await.unwrap();
-    });
+    listener.listen("counted_channel").await?;
+    listener
+        .listen_all(["counted_channel", "counted_other_channel"])
+        .await?;
 
-    // would previously hang forever since `PgListener` had no way to know the pool wanted to close
-    pool.close().await;
+    // one of the two listens on the channel is undone
+    listener.unlisten("counted_channel").await?;
 
-    Ok(())
-}
+    sqlx::query("SELECT pg_notify('counted_channel', 'still listening')")
+        .execute(&pool)
+        .await?;
 
-#[sqlx_macros::test]
-async fn it_supports_domain_types_in_composite_domain_types() -> anyhow::Result<()> {
-    // Only supported in Postgres 11+
-    let mut conn = new::<Postgres>().await?;
-    if matches!(conn.server_version_num(), Some(version) if version < 110000) {
-        return Ok(());
-    }
+    let notification = timeout(Duration::from_secs(5), listener.recv()).await??;
+    assert_eq!(notification.payload(), "still listening");
 
-    conn.execute(
-        r#"
-DROP TABLE IF EXISTS heating_bills;
-DROP DOMAIN IF EXISTS winter_year_month;
-DROP TYPE IF EXISTS year_month;
+    listener.unlisten("counted_channel").await?;
+
+    sqlx::query("SELECT pg_notify('counted_channel', 'not listening')")
+        .execute(&pool)
+        .await?;
+
+    assert!(timeout(Duration::from_millis(100), listener.recv())
+        .await
+        .is_err());
+
+    drop(listener);
+
+    // dropping one subscription of a hub doesn't stop the others on the same channel
+    let hub = PgListenerHub::connect_with(&pool).await?;
+
+    let first = hub.subscribe("counted_channel").await?;
+    let mut second = hub.subscribe("counted_channel").await?;
+
+    drop(first);
+
+    for payload in ["one", "two"] {
+        sqlx::query("SELECT pg_notify('counted_channel', $1)")
+            .bind(payload)
+            .execute(&pool)
+            .await?;
+    }
+
+    for payload in ["one", "two"] {
+        let notification = timeout(Duration::from_secs(5), second.next()).await?;
+        assert_eq!(notification.unwrap().payload(), payload);
+    }
+
+    hub.close().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_pg_listener_allows_pool_to_close() -> anyhow::Result<()> {
+    let pool = pool::<Postgres>().await?;
+
+    // acquires and holds a connection which would normally prevent the pool from closing
+    let mut listener = PgListener::connect_with(&pool).await?;
+
+    sqlx_core::rt::spawn(async move {
+        listener.recv().await.unwrap();
+    });
+
+    // would previously hang forever since `PgListener` had no way to know the pool wanted to close
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_multiplexes_notifications_with_a_listener_hub() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgListenerHub, PgNotification, PgSubscription};
+    use sqlx_core::rt::timeout;
+
+    let pool = pool::<Postgres>().await?;
+    let hub = PgListenerHub::connect_with(&pool).await?;
+
+    let mut all = hub.subscribe("hub_channel").await?;
+    let mut urgent = hub
+        .subscribe_with_filter("hub_channel", |n| n.payload().starts_with("urgent"))
+        .await?;
+    let mut other = hub.subscribe("hub_other_channel").await?;
+
+    for payload in ["routine", "urgent: disk full"] {
+        sqlx::query("SELECT pg_notify('hub_channel', $1)")
+            .bind(payload)
+            .execute(&pool)
+            .await?;
+    }
+
+    async fn next(subscription: &mut PgSubscription) -> Option<PgNotification> {
+        timeout(Duration::from_secs(5), subscription.next())
+            .await
+            .ok()
+            .flatten()
+    }
+
+    assert_eq!(next(&mut all).await.unwrap().payload(), "routine");
+    assert_eq!(next(&mut all).await.unwrap().payload(), "urgent: disk full");
+    assert_eq!(
+        next(&mut urgent).await.unwrap().payload(),
+        "urgent: disk full"
+    );
+
+    // nothing was sent on the other channel
+    assert!(timeout(Duration::from_millis(100), other.next())
+        .await
+        .is_err());
+
+    hub.close().await?;
+
+    // every subscription ends once the hub is closed
+    assert!(next(&mut all).await.is_none());
+    assert!(next(&mut other).await.is_none());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reports_when_a_listener_hub_reconnects() -> anyhow::Result<()> {
+    use sqlx::postgres::{PgListenerHub, PgListenerHubEvent};
+    use sqlx_core::rt::timeout;
+
+    let pool = pool::<Postgres>().await?;
+    let hub = PgListenerHub::connect_with(&pool).await?;
+
+    let mut events = hub.events();
+    let mut subscription = hub.subscribe("hub_reconnect_channel").await?;
+
+    // the last statement of the connection of the hub is its `LISTEN`
+    sqlx::query(
+        "SELECT pg_terminate_backend(pid) FROM pg_stat_activity \
+         WHERE pid <> pg_backend_pid() AND query LIKE '%hub_reconnect_channel%'",
+    )
+    .execute(&pool)
+    .await?;
+
+    assert_eq!(
+        timeout(Duration::from_secs(5), events.next()).await?,
+        Some(PgListenerHubEvent::Disconnected)
+    );
+    assert_eq!(
+        timeout(Duration::from_secs(5), events.next()).await?,
+        Some(PgListenerHubEvent::Reconnected)
+    );
+
+    // the subscription keeps receiving notifications after reconnecting
+    sqlx::query("SELECT pg_notify('hub_reconnect_channel', 'hello')")
+        .execute(&pool)
+        .await?;
+
+    let notification = timeout(Duration::from_secs(5), subscription.next()).await?;
+    assert_eq!(notification.unwrap().payload(), "hello");
+
+    hub.close().await?;
+
+    assert_eq!(timeout(Duration::from_secs(5), events.next()).await?, None);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_supports_domain_types_in_composite_domain_types() -> anyhow::Result<()> {
+    // Only supported in Postgres 11+
+    let mut conn = new::<Postgres>().await?;
+    if matches!(conn.server_version_num(), Some(version) if version < 110000) {
+        return Ok(());
+    }
+
+    conn.execute(
+        r#"
+DROP TABLE IF EXISTS heating_bills;
+DROP DOMAIN IF EXISTS winter_year_month;
+DROP TYPE IF EXISTS year_month;
 DROP DOMAIN IF EXISTS month_id;
 
 CREATE DOMAIN month_id AS INT2 CHECK (1 <= value AND value <= 12);
@@ -1202,6 +1773,68 @@ CREATE TABLE heating_bills (
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_refreshes_enum_types_with_new_labels() -> anyhow::Result<()> {
+    use sqlx::postgres::PgTypeKind;
+
+    fn labels(row: &PgRow) -> Vec<String> {
+        match row.column(0).type_info().kind() {
+            PgTypeKind::Enum(labels) => labels.to_vec(),
+            kind => panic!("expected an enum, got {kind:?}"),
+        }
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+DROP TYPE IF EXISTS refreshed_mood;
+CREATE TYPE refreshed_mood AS ENUM ('ok');
+    "#,
+    )
+    .await?;
+
+    let select = "SELECT $1::text::refreshed_mood";
+
+    let row = sqlx::query(select).bind("ok").fetch_one(&mut conn).await?;
+    assert_eq!(labels(&row), ["ok"]);
+
+    conn.execute("ALTER TYPE refreshed_mood ADD VALUE 'happy'")
+        .await?;
+
+    // the first row with the new label is described by the cached type
+    let row = sqlx::query(select)
+        .bind("happy")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(row.try_get_unchecked::<String, _>(0)?, "happy");
+
+    let row = sqlx::query(select)
+        .bind("happy")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(labels(&row), ["ok", "happy"]);
+
+    let oid = row.column(0).type_info().oid();
+
+    conn.execute(
+        r#"
+DROP TYPE refreshed_mood;
+CREATE TYPE refreshed_mood AS ENUM ('sad');
+    "#,
+    )
+    .await?;
+
+    let info = conn.refresh_type("refreshed_mood").await?;
+    assert_ne!(info.oid(), oid);
+    assert!(matches!(info.kind(), PgTypeKind::Enum(labels) if labels[..] == ["sad"]));
+
+    let row = sqlx::query(select).bind("sad").fetch_one(&mut conn).await?;
+    assert_eq!(labels(&row), ["sad"]);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_resolves_custom_type_in_array() -> anyhow::Result<()> {
     // Only supported in Postgres 11+
@@ -1516,6 +2149,104 @@ async fn test_pg_server_num() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_cancels_a_query_after_its_timeout() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let res = sqlx::query("SELECT pg_sleep(5)")
+        .timeout(Duration::from_millis(50))
+        .execute(&mut conn)
+        .await;
+
+    match res {
+        // query_canceled
+        Err(sqlx::Error::Database(err)) => assert_eq!(err.code().as_deref(), Some("57014")),
+        _ => panic!("expected the query to be canceled"),
+    }
+
+    // the timeout of the connection is restored afterwards
+    let timeout: String = sqlx::query_scalar("SHOW statement_timeout")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(timeout, "0");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_sets_a_statement_timeout_when_connecting() -> anyhow::Result<()> {
+    sqlx_test::setup_if_needed();
+
+    let options: PgConnectOptions = env::var("DATABASE_URL")?.parse().unwrap();
+    let mut conn =
+        PgConnection::connect_with(&options.statement_timeout(Duration::from_secs(30))).await?;
+
+    assert_eq!(conn.statement_timeout(), Some(Duration::from_secs(30)));
+
+    let timeout: String = sqlx::query_scalar("SHOW statement_timeout")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(timeout, "30s");
+
+    // a query with a timeout overrides it until the query completes
+    let timeout: String = sqlx::query_scalar("SHOW statement_timeout")
+        .timeout(Duration::from_secs(5))
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(timeout, "5s");
+
+    let timeout: String = sqlx::query_scalar("SHOW statement_timeout")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(timeout, "30s");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_execute_a_pipeline() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER NOT NULL)")
+        .await?;
+
+    let mut pipeline = conn.pipeline();
+
+    for id in 1..=3_i32 {
+        pipeline.push(sqlx::query("INSERT INTO users (id) VALUES ($1)").bind(id));
+    }
+
+    pipeline.push(sqlx::query("SELECT id FROM users ORDER BY id"));
+
+    let results = pipeline.execute().await?;
+    assert_eq!(results.len(), 4);
+
+    for result in &results[..3] {
+        assert!(matches!(&result[..], [sqlx::Either::Left(r)] if r.rows_affected() == 1));
+    }
+
+    let ids = results[3]
+        .iter()
+        .filter_map(|r| r.as_ref().right())
+        .map(|row| row.try_get::<i32, _>(0))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(ids, [1, 2, 3]);
+
+    // a failing query rolls back the whole pipeline
+    let mut pipeline = conn.pipeline();
+    pipeline.push(sqlx::query("INSERT INTO users (id) VALUES (4)"));
+    pipeline.push(sqlx::query("SELECT 1 / 0"));
+    assert!(pipeline.execute().await.is_err());
+
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 3);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_copy_in() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -1549,6 +2280,95 @@ async fn it_can_copy_in() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_copy_in_binary_rows() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    conn.execute(
+        r#"
+        CREATE TEMPORARY TABLE users (id INTEGER NOT NULL, name TEXT);
+    "#,
+    )
+    .await?;
+
+    let mut copy = conn
+        .copy_in_raw(
+            r#"
+        COPY users (id, name) FROM STDIN WITH (FORMAT BINARY);
+    "#,
+        )
+        .await?
+        .with_binary_format()?;
+
+    copy.send_row((1_i32, "alice")).await?;
+    copy.send_row((2_i32, None::<String>)).await?;
+
+    // the row must have a value for each column
+    assert!(copy.send_row((3_i32,)).await.is_err());
+
+    let rows = copy.finish().await?;
+    assert_eq!(rows, 2);
+
+    let users: Vec<(i32, Option<String>)> =
+        sqlx::query_as("SELECT id, name FROM users ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(users, [(1, Some("alice".to_owned())), (2, None)]);
+
+    // text `COPY` cannot be sent binary rows
+    let copy = conn
+        .copy_in_raw("COPY users (id) FROM STDIN WITH (FORMAT CSV);")
+        .await?;
+
+    assert!(copy.with_binary_format().is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_copy_in_rows_by_partition() -> anyhow::Result<()> {
+    use sqlx::postgres::PgPartitionedCopyIn;
+
+    let pool = pool::<Postgres>().await?;
+
+    for day in 1..=2 {
+        pool.execute(&*format!(
+            "DROP TABLE IF EXISTS _sqlx_events_{day}; \
+             CREATE TABLE _sqlx_events_{day} (id INTEGER NOT NULL, day INTEGER NOT NULL);"
+        ))
+        .await?;
+    }
+
+    let mut copy = PgPartitionedCopyIn::new(&pool, |day: &i32| {
+        format!("COPY _sqlx_events_{day} (id, day) FROM STDIN (FORMAT BINARY)")
+    })
+    .max_partitions(2);
+
+    let rows = futures::stream::iter([(1_i32, 1_i32), (2, 2), (3, 1)].map(Ok::<_, sqlx::Error>));
+    copy.send_stream(rows, |&(_, day)| day).await?;
+
+    assert_eq!(copy.partitions().count(), 2);
+
+    // a third partition exceeds `max_partitions`
+    assert!(copy.send_row(3, (4_i32, 3_i32)).await.is_err());
+
+    let counts = copy.finish().await?;
+    assert_eq!(counts.get(&1), Some(&2));
+    assert_eq!(counts.get(&2), Some(&1));
+
+    let ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM _sqlx_events_1 ORDER BY id")
+        .fetch_all(&pool)
+        .await?;
+    assert_eq!(ids, [1, 3]);
+
+    for day in 1..=2 {
+        pool.execute(&*format!("DROP TABLE _sqlx_events_{day}"))
+            .await?;
+    }
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_abort_copy_in() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -1834,14 +2654,14 @@ async fn test_advisory_locks() -> anyhow::Result<()> {
 }
 
 #[sqlx_macros::test]
-async fn test_postgres_bytea_hex_deserialization_errors() -> anyhow::Result<()> {
+async fn test_postgres_bytea_escape_deserialization() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
     conn.execute("SET bytea_output = 'escape';").await?;
-    for value in ["", "DEADBEEF"] {
+    for (value, expected) in [("", &[][..]), ("DEADBEEF", &[0xDE, 0xAD, 0xBE, 0xEF])] {
         let query = format!("SELECT '\\x{value}'::bytea");
-        let res: sqlx::Result<Vec<u8>> = conn.fetch_one(query.as_str()).await?.try_get(0usize);
-        // Deserialization only supports hex format so this should error and definitely not panic.
-        res.unwrap_err();
+        let res: Vec<u8> = conn.fetch_one(query.as_str()).await?.try_get(0usize)?;
+        // The escape format is decoded as well as the hex format.
+        assert_eq!(res, expected);
     }
     Ok(())
 }
@@ -1934,3 +2754,268 @@ async fn test_issue_3052() {
         "expected encode error, got {too_large_error:?}",
     );
 }
+
+#[sqlx_macros::test]
+async fn it_decodes_bytea_and_interval_in_text_format() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgInterval;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // unprepared queries return values in text format
+    let sql = r"SELECT '\x00ff5c41'::bytea, '1 year 2 mons -3 days 04:05:06.5'::interval";
+
+    for bytea_output in ["hex", "escape"] {
+        conn.execute(&*format!("SET bytea_output = '{bytea_output}'"))
+            .await?;
+
+        let row = conn.fetch_one(sql).await?;
+
+        assert_eq!(row.try_get::<Vec<u8>, _>(0)?, [0x00, 0xFF, b'\\', b'A']);
+        assert_eq!(row.try_get::<[u8; 4], _>(0)?, [0x00, 0xFF, b'\\', b'A']);
+        assert_eq!(
+            row.try_get::<PgInterval, _>(1)?,
+            PgInterval {
+                months: 14,
+                days: -3,
+                microseconds: 14_706_500_000,
+            }
+        );
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_asserts_query_plans() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE plan_test (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+SET enable_seqscan = off;
+        "#,
+    )
+    .await?;
+
+    sqlx::assert_plan!(
+        conn,
+        "SELECT name FROM plan_test WHERE id = 1",
+        contains("Index Scan using plan_test_pkey on plan_test")
+    );
+
+    sqlx::assert_plan!(
+        conn,
+        "SELECT name FROM plan_test WHERE id = 1",
+        not_contains("(actual")
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reads_bytea_incrementally() -> anyhow::Result<()> {
+    use futures::io::AsyncReadExt;
+    use sqlx::postgres::types::PgByteaReader;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let data: Vec<u8> = (0..=255).cycle().take(10_000).collect();
+
+    let mut reader: PgByteaReader = sqlx::query_scalar("SELECT $1::bytea")
+        .bind(&data)
+        .fetch_one(&mut conn)
+        .await?;
+
+    let mut header = [0u8; 4];
+    reader.read_exact(&mut header).await?;
+    assert_eq!(header, [0, 1, 2, 3]);
+
+    let chunks: Vec<_> = reader.into_chunks(4096).try_collect().await?;
+    assert_eq!(
+        chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>(),
+        [4096, 4096, 1804]
+    );
+    assert_eq!(chunks.concat(), data[4..]);
+
+    // `TEXT` is read as its UTF-8 bytes
+    let reader: PgByteaReader = sqlx::query_scalar("SELECT 'hello'::text")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(&reader.into_bytes()[..], b"hello");
+
+    let chunks: Vec<_> = conn
+        .fetch_bytea_chunks(4096, |offset, len| {
+            sqlx::query("SELECT substring($3::bytea FROM $1 FOR $2)")
+                .bind(offset)
+                .bind(len)
+                .bind(data.clone())
+        })
+        .try_collect()
+        .await?;
+
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks.concat(), data);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_sets_the_default_statement_timeout() -> anyhow::Result<()> {
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .default_statement_timeout(Duration::from_millis(1500))
+        .connect(&dotenvy::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    let timeout: String = sqlx::query_scalar("SHOW statement_timeout")
+        .fetch_one(&mut *conn)
+        .await?;
+    assert_eq!(timeout, "1500ms");
+
+    let res = sqlx::query("SELECT pg_sleep(3)").execute(&mut *conn).await;
+    assert!(res.is_err());
+
+    conn.set_statement_timeout(None).await?;
+    assert_eq!(conn.statement_timeout(), None);
+
+    // the timeout is reset when the connection is returned to the pool
+    drop(conn);
+
+    let mut conn = pool.acquire().await?;
+    assert_eq!(conn.statement_timeout(), Some(Duration::from_millis(1500)));
+
+    let timeout: String = sqlx::query_scalar("SHOW statement_timeout")
+        .fetch_one(&mut *conn)
+        .await?;
+    assert_eq!(timeout, "1500ms");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_numbers_placeholders_of_nested_conditions() -> anyhow::Result<()> {
+    use sqlx::query_builder::Cond;
+    use sqlx::QueryBuilder;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let mut builder = QueryBuilder::<Postgres>::new("SELECT value FROM generate_series(1, ");
+    builder.push_bind(5_i32).push(") AS value WHERE ");
+
+    builder
+        .push_cond(Cond::all([
+            Cond::sql("value > ").bind(1_i32),
+            Cond::any([
+                Cond::sql("value = ").bind(2_i32),
+                Cond::sql("value BETWEEN ")
+                    .bind(4_i32)
+                    .push(" AND ")
+                    .bind(5_i32),
+            ]),
+        ]))
+        .push(" ORDER BY value");
+
+    assert!(builder.sql().ends_with(
+        "WHERE (value > $2) AND ((value = $3) OR (value BETWEEN $4 AND $5)) ORDER BY value"
+    ));
+
+    let values: Vec<i32> = builder.build_query_scalar().fetch_all(&mut conn).await?;
+
+    assert_eq!(values, [2, 4, 5]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_pages_through_a_holdable_cursor() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut tx = conn.begin().await?;
+
+    let cursor = tx
+        .fetch_paged_holdable(
+            "numbers",
+            sqlx::query("SELECT value FROM generate_series(1, $1) AS value").bind(10_i32),
+        )
+        .await?;
+
+    tx.commit().await?;
+
+    // the cursor outlives the transaction it was declared in
+    let page: Vec<i32> = cursor
+        .fetch_page(&mut conn, 1, 4)
+        .await?
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+    assert_eq!(page, [5, 6, 7, 8]);
+
+    let first: Vec<i32> = cursor
+        .fetch_page(&mut conn, 0, 4)
+        .await?
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+    assert_eq!(first, [1, 2, 3, 4]);
+
+    assert_eq!(cursor.fetch_page(&mut conn, 2, 4).await?.len(), 2);
+    assert!(cursor.fetch_page(&mut conn, 3, 4).await?.is_empty());
+
+    cursor.close(&mut conn).await?;
+
+    // the cursor no longer exists
+    let cursor = PgHoldableCursor::from_name("numbers");
+    assert!(cursor.fetch_page(&mut conn, 0, 4).await.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_estimates_rows() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE "Events" (id INT NOT NULL) PARTITION BY RANGE (id);
+CREATE TEMPORARY TABLE events_low PARTITION OF "Events" FOR VALUES FROM (0) TO (100);
+CREATE TEMPORARY TABLE events_high PARTITION OF "Events" FOR VALUES FROM (100) TO (1000);
+INSERT INTO "Events" (id) SELECT generate_series(0, 299);
+ANALYZE "Events";
+        "#,
+    )
+    .await?;
+
+    // summed over the partitions
+    assert_eq!(conn.estimate_rows("\"Events\"").await?, 300);
+    assert_eq!(conn.estimate_rows("events_low").await?, 100);
+    assert_eq!(conn.estimate_rows("SELECT * FROM \"Events\"").await?, 300);
+    assert_eq!(conn.estimate_rows("select 1").await?, 1);
+
+    assert!(conn.estimate_rows("no_such_table").await.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_returns_generated_non_integer_keys() -> anyhow::Result<()> {
+    use sqlx::QueryBuilder;
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        "CREATE TEMPORARY TABLE tags (key TEXT PRIMARY KEY DEFAULT 'tag-' || md5(random()::text), \
+         name TEXT NOT NULL)",
+    )
+    .await?;
+
+    let mut builder = QueryBuilder::<Postgres>::new("INSERT INTO tags (name) VALUES (");
+    builder.push_bind("rust").push(")");
+
+    let key: String = builder.returning_id("key", &mut conn).await?;
+
+    assert!(key.starts_with("tag-"));
+
+    Ok(())
+}
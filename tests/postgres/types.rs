@@ -3,7 +3,7 @@ extern crate time_ as time;
 use std::net::SocketAddr;
 use std::ops::Bound;
 
-use sqlx::postgres::types::{Oid, PgCiText, PgInterval, PgMoney, PgRange};
+use sqlx::postgres::types::{Oid, PgCiText, PgHstore, PgInterval, PgMoney, PgRange};
 use sqlx::postgres::Postgres;
 use sqlx_test::{new, test_decode_type, test_prepared_type, test_type};
 
@@ -126,6 +126,14 @@ test_type!(i32_array<[i32; 4]>(Postgres,
 
 test_type!(i64(Postgres, "9358295312::bigint" == 9358295312_i64));
 
+test_type!(non_zero_i64<std::num::NonZeroI64>(Postgres,
+    "9358295312::bigint" == std::num::NonZeroI64::new(9358295312).unwrap()
+));
+
+test_type!(non_zero_i32_vec<Vec<std::num::NonZeroI32>>(Postgres,
+    "'{5,10,-50}'::int[]" == vec![5, 10, -50].into_iter().map(|i| std::num::NonZeroI32::new(i).unwrap()).collect::<Vec<_>>()
+));
+
 test_type!(f32(Postgres, "9419.122::real" == 9419.122_f32));
 
 test_type!(f64(
@@ -306,6 +314,30 @@ mod chrono {
         "TIMETZ '05:10:20.115100-05'" == PgTimeTz { time: NaiveTime::from_hms_micro(5, 10, 20, 115100), offset: FixedOffset::west(60 * 60 * 5) },
         "TIMETZ '05:10:20+02'" == PgTimeTz { time: NaiveTime::from_hms(5, 10, 20), offset: FixedOffset::east(60 * 60 * 2 )}
     ));
+
+    #[sqlx_macros::test]
+    async fn it_decodes_timestamptz_with_the_session_offset() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        conn.execute("SET TIME ZONE 2").await?;
+
+        let datetime: DateTime<FixedOffset> =
+            sqlx::query_scalar("SELECT TIMESTAMPTZ '2019-01-02 05:10:20+00'")
+                .fetch_one(&mut conn)
+                .await?;
+        assert_eq!(datetime.to_rfc3339(), "2019-01-02T07:10:20+02:00");
+
+        // a zone with daylight saving time has no single offset, so UTC is used
+        conn.execute("SET TIME ZONE 'Europe/Berlin'").await?;
+
+        let datetime: DateTime<FixedOffset> =
+            sqlx::query_scalar("SELECT TIMESTAMPTZ '2019-01-02 05:10:20+00'")
+                .fetch_one(&mut conn)
+                .await?;
+        assert_eq!(datetime.to_rfc3339(), "2019-01-02T05:10:20+00:00");
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "time")]
@@ -522,6 +554,29 @@ test_type!(int4range<PgRange<i32>>(Postgres,
     "'[1,2]'::int4range" == PgRange::from((INC1, EXC3)),
 ));
 
+#[cfg(any(postgres_14, postgres_15))]
+test_type!(int4multirange<sqlx::postgres::types::PgMultiRange<i32>>(Postgres,
+    "'{}'::int4multirange" == sqlx::postgres::types::PgMultiRange::<i32>::default(),
+    "'{(,2)}'::int4multirange" == sqlx::postgres::types::PgMultiRange::from(vec![PgRange::from((UNB, EXC2))]),
+    "'{[1,2), [3,4]}'::int4multirange" == sqlx::postgres::types::PgMultiRange::from(vec![
+        PgRange::from((INC1, EXC2)),
+        PgRange::from((Bound::Included(3), Bound::Excluded(5))),
+    ]),
+    // overlapping ranges are merged
+    "'{[1,2], (1,3), [5,)}'::int4multirange" == sqlx::postgres::types::PgMultiRange::from(vec![
+        PgRange::from((INC1, EXC3)),
+        PgRange::from((Bound::Included(5), UNB)),
+    ]),
+));
+
+#[cfg(any(postgres_14, postgres_15))]
+test_type!(int4multirange_vec<Vec<sqlx::postgres::types::PgMultiRange<i32>>>(Postgres,
+    "array['{[1,2)}', '{}']::int4multirange[]" == vec![
+        sqlx::postgres::types::PgMultiRange::from(vec![PgRange::from((INC1, EXC2))]),
+        sqlx::postgres::types::PgMultiRange::default(),
+    ],
+));
+
 test_prepared_type!(interval<PgInterval>(
     Postgres,
     "INTERVAL '1h'"
@@ -564,6 +619,22 @@ test_prepared_type!(citext_array<Vec<PgCiText>>(Postgres,
     ],
 ));
 
+test_type!(hstore<PgHstore>(Postgres,
+    "''::hstore" == PgHstore::default(),
+    r#"'a=>1, "b c"=>NULL, "d\"e"=>"f"'::hstore"# == PgHstore::from_iter([
+        ("a", Some("1")),
+        ("b c", None),
+        ("d\"e", Some("f")),
+    ]),
+));
+
+test_type!(hstore_vec<Vec<PgHstore>>(Postgres,
+    "array['a=>1', 'b=>NULL']::hstore[]" == vec![
+        PgHstore::from_iter([("a", Some("1"))]),
+        PgHstore::from_iter([("b", None::<&str>)]),
+    ],
+));
+
 // FIXME: needed to disable `ltree` tests in version that don't have a binary format for it
 // but `PgLTree` should just fall back to text format
 #[cfg(any(postgres_14, postgres_15))]
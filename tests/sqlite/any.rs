@@ -1,4 +1,4 @@
-use sqlx::{Any, Sqlite};
+use sqlx::{AccessMode, Any, Connection, Executor, IsolationLevel, QueryBuilder, Row, Sqlite};
 use sqlx_test::new;
 
 #[sqlx_macros::test]
@@ -33,3 +33,199 @@ async fn issue_3179() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_begins_with_isolation_level_with_any() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let mut conn = new::<Any>().await?;
+
+    let mut tx = conn
+        .begin_with(IsolationLevel::Serializable, AccessMode::ReadWrite)
+        .await?;
+    assert_eq!(tx.isolation_level(), Some(IsolationLevel::Serializable));
+
+    let one: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut *tx).await?;
+    assert_eq!(one, 1);
+
+    tx.rollback().await?;
+
+    // SQLite has no read-only transactions
+    assert!(conn
+        .begin_with(IsolationLevel::Serializable, AccessMode::ReadOnly)
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_returns_generated_id_with_any() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let mut conn = new::<Any>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+        .await?;
+
+    for expected in 1..4_i64 {
+        let mut builder = QueryBuilder::<Any>::new("INSERT INTO users (name) VALUES (");
+        builder.push_bind(format!("user {expected}")).push(")");
+
+        let id: i64 = builder.returning_id("id", &mut conn).await?;
+
+        assert_eq!(id, expected);
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reads_registered_types_with_any() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let mut conn = new::<Any>().await?;
+
+    conn.execute(
+        "CREATE TEMPORARY TABLE flags (id INTEGER PRIMARY KEY, enabled BOOLEAN NOT NULL);
+         INSERT INTO flags (enabled) VALUES (TRUE);",
+    )
+    .await?;
+
+    // `BOOLEAN` is not supported by the `Any` driver
+    let res: Result<i64, _> = sqlx::query_scalar("SELECT enabled FROM flags")
+        .fetch_one(&mut conn)
+        .await;
+    assert!(res.is_err());
+
+    sqlx::any::register_type::<Sqlite, i64, i64>("boolean", |value| value);
+
+    let enabled: i64 = sqlx::query_scalar("SELECT enabled FROM flags")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(enabled, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reads_dates_and_times_with_any() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let mut conn = new::<Any>().await?;
+
+    conn.execute(
+        "CREATE TEMPORARY TABLE events (id INTEGER PRIMARY KEY, day DATE, at TIME, happened_at DATETIME);
+         INSERT INTO events (day, at, happened_at) VALUES ('2024-05-01', '12:30:00', '2024-05-01 12:30:00');",
+    )
+    .await?;
+
+    let row = sqlx::query("SELECT day, at, happened_at FROM events")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(row.try_get::<String, _>("day")?, "2024-05-01");
+    assert_eq!(row.try_get::<String, _>("at")?, "12:30:00");
+    assert_eq!(
+        row.try_get::<String, _>("happened_at")?,
+        "2024-05-01 12:30:00"
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_borrowed_and_nested_options() -> anyhow::Result<()> {
+    sqlx::any::install_default_drivers();
+
+    let mut conn = new::<Any>().await?;
+
+    let name = Some(String::from("alice"));
+    let age: Option<i32> = None;
+    let nickname: Option<Option<String>> = Some(None);
+
+    let row = sqlx::query("SELECT ?, ?, ?, ?, ?")
+        .bind(name.as_ref())
+        .bind(&name)
+        .bind(age.as_ref())
+        .bind(&nickname)
+        .bind(Some(name.as_deref()))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(
+        row.try_get::<Option<String>, _>(0)?.as_deref(),
+        Some("alice")
+    );
+    assert_eq!(
+        row.try_get::<Option<String>, _>(1)?.as_deref(),
+        Some("alice")
+    );
+    assert_eq!(row.try_get::<Option<i32>, _>(2)?, None);
+    assert_eq!(row.try_get::<Option<String>, _>(3)?, None);
+    assert_eq!(
+        row.try_get::<Option<String>, _>(4)?.as_deref(),
+        Some("alice")
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_runs_init_commands_with_any() -> anyhow::Result<()> {
+    use sqlx::any::AnyConnectOptions;
+    use sqlx::ConnectOptions;
+    use std::str::FromStr;
+
+    sqlx::any::install_default_drivers();
+
+    let mut conn = AnyConnectOptions::from_str("sqlite::memory:")?
+        .init_command("CREATE TEMP TABLE scratch (value INTEGER)")
+        .init_command("INSERT INTO scratch (value) VALUES (42)")
+        .connect()
+        .await?;
+
+    let value: i64 = sqlx::query_scalar("SELECT value FROM scratch")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 42);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_converts_values_to_and_from_any() -> anyhow::Result<()> {
+    use sqlx::any::AnyValue;
+    use sqlx::sqlite::SqliteValue;
+    use sqlx::{Value, ValueRef};
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let row = sqlx::query("SELECT 70000, 'hello', x'0001', 0.5, NULL")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let values = (0..row.len())
+        .map(|i| Ok(AnyValue::try_from(&row.try_get_raw(i)?.to_owned())?))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    assert_eq!(values[0].try_decode::<i64>()?, 70_000);
+    assert_eq!(values[1].try_decode::<String>()?, "hello");
+    assert_eq!(values[2].try_decode::<Vec<u8>>()?, [0, 1]);
+    assert_eq!(values[3].try_decode::<f64>()?, 0.5);
+    assert!(values[4].is_null());
+
+    let values = values
+        .iter()
+        .map(SqliteValue::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(values[0].try_decode::<i64>()?, 70_000);
+    assert_eq!(values[1].try_decode::<String>()?, "hello");
+    assert_eq!(values[2].try_decode::<Vec<u8>>()?, [0, 1]);
+    assert_eq!(values[3].try_decode::<f64>()?, 0.5);
+    assert!(values[4].is_null());
+
+    Ok(())
+}
@@ -12,3 +12,37 @@ test_type!(origin_enum<Origin>(Sqlite,
     "1" == Origin::Foo,
     "2" == Origin::Bar,
 ));
+
+#[derive(sqlx::IntoArguments)]
+struct NewTweet<'a> {
+    text: &'a str,
+    #[sqlx(position = 1)]
+    id: i64,
+    #[sqlx(skip)]
+    #[allow(dead_code)]
+    draft: bool,
+    owner_id: Option<i64>,
+}
+
+#[sqlx_macros::test]
+async fn it_binds_arguments_from_a_struct() -> anyhow::Result<()> {
+    let mut conn = sqlx_test::new::<Sqlite>().await?;
+
+    let tweet = NewTweet {
+        text: "Hello, World",
+        id: 42,
+        draft: true,
+        owner_id: None,
+    };
+
+    let (id, text, owner_id): (i64, String, Option<i64>) =
+        sqlx::query_as_with("SELECT ?1, ?2, ?3", tweet)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(id, 42);
+    assert_eq!(text, "Hello, World");
+    assert_eq!(owner_id, None);
+
+    Ok(())
+}
@@ -138,6 +138,34 @@ async fn it_describes_temporary_table() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_describes_any_columns_of_strict_tables() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        "CREATE TEMPORARY TABLE strict_any(a ANY, b INTEGER NOT NULL) STRICT;
+         CREATE TEMPORARY TABLE loose_any(a ANY);
+         INSERT INTO loose_any(a) VALUES ('text');",
+    )
+    .await?;
+
+    let d = conn.describe("SELECT a, b FROM strict_any").await?;
+
+    assert_eq!(d.column(0).type_info().name(), "ANY");
+    assert_eq!(d.nullable(0), Some(true));
+
+    assert_eq!(d.column(1).type_info().name(), "INTEGER");
+    assert_eq!(d.nullable(1), Some(false));
+
+    // `ANY` only has `NUMERIC` affinity outside of a `STRICT` table,
+    // so the type is inferred from the values instead
+    let d = conn.describe("SELECT a FROM loose_any").await?;
+
+    assert_eq!(d.column(0).type_info().name(), "TEXT");
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_describes_expression_from_empty_table() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -1,4 +1,4 @@
-use sqlx::migrate::Migrator;
+use sqlx::migrate::{MigrateError, MigrationProgress, Migrator};
 use sqlx::pool::PoolConnection;
 use sqlx::sqlite::{Sqlite, SqliteConnection};
 use sqlx::Executor;
@@ -66,6 +66,58 @@ async fn reversible(mut conn: PoolConnection<Sqlite>) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx::test(migrations = false)]
+async fn reports_progress(mut conn: PoolConnection<Sqlite>) -> anyhow::Result<()> {
+    clean_up(&mut conn).await?;
+
+    let migrator = Migrator::new(Path::new("tests/sqlite/migrations_simple")).await?;
+
+    let mut events = Vec::new();
+
+    migrator
+        .run_with_progress(&mut conn, |progress| match progress {
+            MigrationProgress::Applying {
+                migration,
+                index,
+                total,
+            } => events.push(format!("applying {} ({index}/{total})", migration.version)),
+            MigrationProgress::Applied { migration, .. } => {
+                events.push(format!("applied {}", migration.version))
+            }
+            _ => unreachable!(),
+        })
+        .await?;
+
+    assert_eq!(
+        events,
+        [
+            "applying 20220721115250 (0/2)",
+            "applied 20220721115250",
+            "applying 20220721115524 (1/2)",
+            "applied 20220721115524",
+        ]
+    );
+
+    // nothing is left to apply the 2nd time
+    events.clear();
+    migrator
+        .run_with_progress(&mut conn, |progress| events.push(format!("{progress:?}")))
+        .await?;
+    assert!(events.is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = false)]
+async fn in_schema_is_not_supported(mut conn: PoolConnection<Sqlite>) -> anyhow::Result<()> {
+    let migrator = Migrator::new(Path::new("tests/sqlite/migrations_simple")).await?;
+
+    let res = migrator.run_in_schema(&mut conn, "tenant_a").await;
+    assert!(matches!(res, Err(MigrateError::SchemaNotSupported)));
+
+    Ok(())
+}
+
 /// Ensure that we have a clean initial state.
 async fn clean_up(conn: &mut SqliteConnection) -> anyhow::Result<()> {
     conn.execute("DROP TABLE migrations_simple_test").await.ok();
@@ -1,17 +1,24 @@
 use futures::TryStreamExt;
 use rand::{Rng, SeedableRng};
 use rand_xoshiro::Xoshiro256PlusPlus;
-use sqlx::sqlite::{SqliteConnectOptions, SqliteOperation, SqlitePoolOptions};
+use sqlx::sqlite::{
+    SqliteConnectOptions, SqliteForeignKeyViolation, SqliteOperation, SqlitePoolOptions,
+    SqliteTransactionState,
+};
 use sqlx::{
-    query, sqlite::Sqlite, sqlite::SqliteRow, Column, ConnectOptions, Connection, Executor, Row,
-    SqliteConnection, SqlitePool, Statement, TypeInfo,
+    query, sqlite::Sqlite, sqlite::SqliteRow, AccessMode, Column, ConnectOptions, Connection,
+    Executor, IsolationLevel, QueryBuilder, Rollback, Row, SqliteConnection, SqlitePool, Statement,
+    TransactionAbort, TransactionOptions, TypeInfo,
 };
 use sqlx_test::new;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 #[sqlx_macros::test]
 async fn it_connects() -> anyhow::Result<()> {
-    Ok(new::<Sqlite>().await?.ping().await?)
+    new::<Sqlite>().await?.ping().await?;
+
+    Ok(())
 }
 
 #[sqlx_macros::test]
@@ -112,6 +119,53 @@ async fn test_bind_multiple_statements_multiple_values() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_fetches_into_an_existing_vec() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let mut values: Vec<i32> = Vec::with_capacity(8);
+
+    for _ in 0..3 {
+        values.clear();
+
+        let count = sqlx::query_scalar("SELECT 1 UNION ALL SELECT 2 UNION ALL SELECT 3")
+            .fetch_into(&mut conn, &mut values)
+            .await?;
+
+        assert_eq!(count, 3);
+        assert_eq!(values, [1, 2, 3]);
+        assert_eq!(values.capacity(), 8);
+    }
+
+    // rows are appended after the existing ones
+    let count = sqlx::query_as::<_, (i32,)>("SELECT 4")
+        .fetch_into(&mut conn, &mut Vec::new())
+        .await?;
+    assert_eq!(count, 1);
+
+    let count = sqlx::query_scalar("SELECT 4")
+        .fetch_into(&mut conn, &mut values)
+        .await?;
+    assert_eq!(count, 1);
+    assert_eq!(values, [1, 2, 3, 4]);
+
+    // on error, nothing is appended
+    let res = sqlx::query_scalar::<_, i32>("SELECT 5 UNION ALL SELECT 'six'")
+        .fetch_into(&mut conn, &mut values)
+        .await;
+    assert!(res.is_err());
+    assert_eq!(values, [1, 2, 3, 4]);
+
+    let mut rows = Vec::new();
+    let count = sqlx::query("SELECT 1 UNION ALL SELECT 2")
+        .fetch_into(&mut conn, &mut rows)
+        .await?;
+    assert_eq!(count, 2);
+    assert_eq!(rows[1].try_get::<i32, _>(0)?, 2);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_bind_multiple_statements_same_value() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -205,6 +259,48 @@ async fn it_executes_with_pool() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_can_roll_back_to_a_named_savepoint() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    let mut tx = conn.begin().await?;
+    sqlx::query("INSERT INTO users (id) VALUES (?)")
+        .bind(1_i32)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.savepoint("before_second").await?;
+    sqlx::query("INSERT INTO users (id) VALUES (?)")
+        .bind(2_i32)
+        .execute(&mut *tx)
+        .await?;
+
+    // the savepoint can be rolled back to more than once
+    for _ in 0..2 {
+        tx.rollback_to("before_second").await?;
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+            .fetch_one(&mut *tx)
+            .await?;
+        assert_eq!(count, 1);
+    }
+
+    tx.release("before_second").await?;
+
+    // names are not quoted, so anything but a plain identifier is rejected
+    assert!(tx.savepoint("a; DROP TABLE users").await.is_err());
+    assert!(tx.savepoint("_sqlx_savepoint_1").await.is_err());
+
+    tx.commit().await?;
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
 #[cfg(sqlite_ipaddr)]
 #[sqlx_macros::test]
 async fn it_opens_with_extension() -> anyhow::Result<()> {
@@ -220,6 +316,41 @@ async fn it_opens_with_extension() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg(all(sqlite_ipaddr, feature = "sqlite-extension"))]
+#[sqlx_macros::test]
+async fn it_loads_an_extension_into_an_open_connection() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.load_extension("ipaddr", None).await?;
+    conn.execute("SELECT ipmasklen('192.168.16.12/24');")
+        .await?;
+    conn.close().await?;
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite-extension")]
+#[sqlx_macros::test]
+async fn it_fails_to_load_a_missing_extension() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    assert!(conn
+        .load_extension("sqlx_no_such_extension", None)
+        .await
+        .is_err());
+
+    // extension loading is disabled again afterwards
+    assert!(conn
+        .execute("SELECT load_extension('sqlx_no_such_extension')")
+        .await
+        .is_err());
+
+    let one: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(one, 1);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_opens_in_memory() -> anyhow::Result<()> {
     // If the filename is ":memory:", then a private, temporary in-memory database
@@ -369,6 +500,204 @@ SELECT id, other FROM users WHERE id = last_insert_rowid();
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_returns_generated_id() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+        .await?;
+
+    for expected in 1..4_i64 {
+        let mut builder = QueryBuilder::<Sqlite>::new("INSERT INTO users (name) VALUES (");
+        builder.push_bind(format!("user {expected}")).push(")");
+
+        let id: i64 = builder.returning_id("id", &mut conn).await?;
+
+        assert_eq!(id, expected);
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_bulk_inserts_rows_in_chunks() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+        .await?;
+
+    // more rows than fit in a single statement with two bind parameters each
+    let rows = (0..20_000_i64).map(|id| (id, format!("user {id}")));
+
+    let result = QueryBuilder::<Sqlite>::bulk_insert(
+        "users",
+        &["id", "name"],
+        rows,
+        |mut b, (id, name)| {
+            b.push_bind(id).push_bind(name);
+        },
+        &mut conn,
+    )
+    .await?;
+
+    assert_eq!(result.rows_affected(), 20_000);
+
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 20_000);
+
+    // a failing chunk rolls back the chunks before it
+    let rows = (19_000..40_000_i64)
+        .rev()
+        .map(|id| (id, format!("user {id}")));
+
+    let res = QueryBuilder::<Sqlite>::bulk_insert(
+        "users",
+        &["id", "name"],
+        rows,
+        |mut b, (id, name)| {
+            b.push_bind(id).push_bind(name);
+        },
+        &mut conn,
+    )
+    .await;
+
+    assert!(res.is_err());
+
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM users")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 20_000);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_begin_with_isolation_level_and_access_mode() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let mut tx = conn
+        .begin_with(IsolationLevel::Serializable, AccessMode::ReadWrite)
+        .await?;
+
+    assert_eq!(tx.isolation_level(), Some(IsolationLevel::Serializable));
+    assert_eq!(tx.access_mode(), Some(AccessMode::ReadWrite));
+
+    // the isolation level can not be set for a savepoint
+    assert!(tx
+        .begin_with(IsolationLevel::Serializable, AccessMode::ReadWrite)
+        .await
+        .is_err());
+
+    tx.rollback().await?;
+
+    // SQLite has no read-only transactions
+    assert!(conn
+        .begin_with(IsolationLevel::Serializable, AccessMode::ReadOnly)
+        .await
+        .is_err());
+
+    // the connection must still be usable for regular transactions
+    conn.begin().await?.commit().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_begin_with_options() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let tx = conn
+        .begin_with_options(
+            TransactionOptions::new().isolation_level(IsolationLevel::ReadCommitted),
+        )
+        .await?;
+
+    assert_eq!(tx.isolation_level(), Some(IsolationLevel::ReadCommitted));
+    assert_eq!(tx.access_mode(), None);
+
+    tx.rollback().await?;
+
+    // SQLite has no deferrable transactions
+    assert!(conn
+        .begin_with_options(TransactionOptions::new().deferrable(true))
+        .await
+        .is_err());
+
+    conn.begin().await?.commit().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_rolls_back_a_transaction_with_a_value() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE balances (balance INTEGER NOT NULL)")
+        .await?;
+
+    conn.execute("INSERT INTO balances (balance) VALUES (10)")
+        .await?;
+
+    async fn withdraw(conn: &mut SqliteConnection, amount: i64) -> sqlx::Result<Result<i64, i64>> {
+        conn.transaction_with_rollback(|txn| {
+            Box::pin(async move {
+                let balance: i64 = sqlx::query_scalar(
+                    "UPDATE balances SET balance = balance - ? RETURNING balance",
+                )
+                .bind(amount)
+                .fetch_one(&mut **txn)
+                .await?;
+
+                if balance < 0 {
+                    Err(Rollback(balance))?;
+                }
+
+                Ok::<_, TransactionAbort<_>>(balance)
+            })
+        })
+        .await
+    }
+
+    assert_eq!(withdraw(&mut conn, 4).await?, Ok(6));
+    assert_eq!(withdraw(&mut conn, 8).await?, Err(-2));
+
+    let balance: i64 = sqlx::query_scalar("SELECT balance FROM balances")
+        .fetch_one(&mut conn)
+        .await?;
+
+    // only the first withdrawal was committed
+    assert_eq!(balance, 6);
+
+    // errors roll back as well
+    let result = conn
+        .transaction_with_rollback(|txn| {
+            Box::pin(async move {
+                sqlx::query("UPDATE balances SET balance = 0")
+                    .execute(&mut **txn)
+                    .await?;
+
+                sqlx::query("SELECT * FROM not_a_table")
+                    .execute(&mut **txn)
+                    .await?;
+
+                Ok::<_, TransactionAbort<()>>(())
+            })
+        })
+        .await;
+
+    assert!(result.is_err());
+
+    let balance: i64 = sqlx::query_scalar("SELECT balance FROM balances")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(balance, 6);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_interleaves_reads_and_writes() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -848,3 +1177,1132 @@ async fn test_multiple_set_update_hook_calls_drop_old_handler() -> anyhow::Resul
     assert_eq!(1, Arc::strong_count(&ref_counted_object));
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn test_commit_and_rollback_hooks() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let commits = Arc::new(AtomicUsize::new(0));
+    let rollbacks = Arc::new(AtomicUsize::new(0));
+
+    {
+        let mut handle = conn.lock_handle().await?;
+
+        let counter = commits.clone();
+        handle.set_commit_hook(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            true
+        });
+
+        let counter = rollbacks.clone();
+        handle.set_rollback_hook(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert_eq!(
+            handle.transaction_state(),
+            SqliteTransactionState::Autocommit
+        );
+    }
+
+    let mut tx = conn.begin().await?;
+    sqlx::query("INSERT INTO tweet ( id, text ) VALUES ( 4, 'Hello, World' )")
+        .execute(&mut *tx)
+        .await?;
+
+    assert_eq!(
+        tx.lock_handle().await?.transaction_state(),
+        SqliteTransactionState::InTransaction
+    );
+
+    tx.rollback().await?;
+    assert_eq!(commits.load(Ordering::SeqCst), 0);
+    assert_eq!(rollbacks.load(Ordering::SeqCst), 1);
+
+    sqlx::query("INSERT INTO tweet ( id, text ) VALUES ( 4, 'Hello, World' )")
+        .execute(&mut conn)
+        .await?;
+    assert_eq!(commits.load(Ordering::SeqCst), 1);
+
+    // a commit hook returning `false` turns the commit into a rollback
+    conn.lock_handle().await?.set_commit_hook(|| false);
+
+    let res = sqlx::query("INSERT INTO tweet ( id, text ) VALUES ( 5, 'Hello, World' )")
+        .execute(&mut conn)
+        .await;
+    assert!(res.is_err());
+    assert_eq!(rollbacks.load(Ordering::SeqCst), 2);
+
+    let mut handle = conn.lock_handle().await?;
+    handle.remove_commit_hook();
+    handle.remove_rollback_hook();
+    drop(handle);
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tweet WHERE id IN (4, 5)")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_asserts_query_plans() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE plan_test (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+CREATE INDEX plan_test_name ON plan_test (name);
+        "#,
+    )
+    .await?;
+
+    sqlx::assert_plan!(
+        conn,
+        "SELECT id FROM plan_test WHERE name = 'Alice'",
+        contains("USING COVERING INDEX plan_test_name")
+    );
+
+    sqlx::assert_plan!(
+        conn,
+        "SELECT id FROM plan_test WHERE name = 'Alice'",
+        not_contains("SCAN")
+    );
+
+    sqlx::assert_plan!(
+        conn,
+        "SELECT name FROM plan_test WHERE id > 1",
+        contains("SEARCH plan_test USING INTEGER PRIMARY KEY")
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reports_foreign_key_violations() -> anyhow::Result<()> {
+    use std::str::FromStr;
+
+    let mut conn = SqliteConnectOptions::from_str("sqlite::memory:")?
+        .foreign_keys(false)
+        .foreign_key_check_on_commit(true)
+        .connect()
+        .await?;
+
+    conn.execute(
+        r#"
+CREATE TABLE parent (id INTEGER PRIMARY KEY);
+CREATE TABLE child (id INTEGER PRIMARY KEY, parent_id INTEGER REFERENCES parent (id));
+INSERT INTO parent (id) VALUES (1);
+INSERT INTO child (id, parent_id) VALUES (10, 1);
+        "#,
+    )
+    .await?;
+
+    assert!(conn.foreign_key_check().await?.is_empty());
+    assert!(conn.integrity_check().await?.is_empty());
+
+    let mut tx = conn.begin().await?;
+    tx.execute("INSERT INTO child (id, parent_id) VALUES (20, 2)")
+        .await?;
+
+    assert_eq!(
+        tx.foreign_key_check().await?,
+        vec![SqliteForeignKeyViolation {
+            table: "child".into(),
+            rowid: Some(20),
+            parent: "parent".into(),
+            fkid: 0,
+        }]
+    );
+
+    // the check on commit is only enabled in debug builds
+    if cfg!(debug_assertions) {
+        let err = tx.commit().await.unwrap_err();
+        assert!(err
+            .as_database_error()
+            .is_some_and(|e| e.is_foreign_key_violation()));
+
+        // the transaction was rolled back when it was dropped
+        assert!(conn.foreign_key_check().await?.is_empty());
+    } else {
+        tx.commit().await?;
+
+        assert_eq!(conn.foreign_key_check().await?.len(), 1);
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_allocates_values_from_sequences() -> anyhow::Result<()> {
+    use sqlx::Sequence;
+
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+
+    assert_eq!(Sequence::next(&mut conn, "orders").await?, 1);
+    assert_eq!(Sequence::next_n(&mut conn, "orders", 3).await?, [2, 3, 4]);
+    assert!(Sequence::next_n(&mut conn, "orders", 0).await?.is_empty());
+
+    // sequences are independent
+    assert_eq!(Sequence::next(&mut conn, "lines").await?, 1);
+
+    // the counter is part of the transaction
+    let mut tx = conn.begin().await?;
+    assert_eq!(Sequence::next(&mut *tx, "orders").await?, 5);
+    tx.rollback().await?;
+
+    assert_eq!(Sequence::next(&mut conn, "orders").await?, 5);
+
+    assert!(Sequence::next(&mut conn, "").await.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_does_not_estimate_rows() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    assert!(matches!(
+        conn.estimate_rows("tweet").await,
+        Err(sqlx::Error::Configuration(_))
+    ));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_vacuums_into_a_backup() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("backup.db");
+
+    let mut conn = SqliteConnectOptions::new()
+        .filename(dir.path().join("live.db"))
+        .create_if_missing(true)
+        .connect()
+        .await?;
+
+    conn.execute(
+        r#"
+CREATE TABLE notes (id INTEGER PRIMARY KEY, body TEXT NOT NULL);
+INSERT INTO notes (body) VALUES ('first'), ('second');
+        "#,
+    )
+    .await?;
+
+    let progress = Arc::new(AtomicUsize::new(0));
+    let progress_ = progress.clone();
+    conn.lock_handle().await?.set_progress_handler(1, move || {
+        progress_.fetch_add(1, Ordering::SeqCst);
+        true
+    });
+
+    conn.vacuum_into(&path).await?;
+    assert!(progress.load(Ordering::SeqCst) > 0);
+
+    // the target must not already contain a database
+    assert!(conn.vacuum_into(&path).await.is_err());
+
+    // returning `false` from the progress handler cancels it
+    conn.lock_handle()
+        .await?
+        .set_progress_handler(1, move || false);
+    assert!(conn
+        .vacuum_into(dir.path().join("cancelled.db"))
+        .await
+        .is_err());
+    conn.lock_handle().await?.remove_progress_handler();
+
+    let mut backup = SqliteConnectOptions::new()
+        .filename(&path)
+        .read_only(true)
+        .connect()
+        .await?;
+
+    assert!(backup.integrity_check().await?.is_empty());
+
+    let bodies: Vec<String> = sqlx::query_scalar("SELECT body FROM notes ORDER BY id")
+        .fetch_all(&mut backup)
+        .await?;
+    assert_eq!(bodies, ["first", "second"]);
+
+    // an in-memory database would be copied into memory
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+    assert!(conn
+        .vacuum_into(dir.path().join("memory.db"))
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_ignores_a_default_statement_timeout() -> anyhow::Result<()> {
+    let pool = SqlitePoolOptions::new()
+        .default_statement_timeout(std::time::Duration::from_secs(1))
+        .connect("sqlite::memory:")
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    assert!(!conn.supports_statement_timeout());
+    assert_eq!(conn.statement_timeout(), None);
+    assert_eq!(
+        sqlx::query_scalar::<_, i64>("SELECT 1")
+            .fetch_one(&mut *conn)
+            .await?,
+        1
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_interrupts_a_query_after_its_timeout() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let res = sqlx::query_scalar::<_, i64>(
+        "WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c) SELECT count(*) FROM c",
+    )
+    .timeout(std::time::Duration::from_millis(50))
+    .fetch_one(&mut conn)
+    .await;
+
+    match res {
+        Err(sqlx::Error::Database(err)) => assert_eq!(err.message(), "interrupted"),
+        _ => panic!("expected an interrupt"),
+    }
+
+    // the timeout does not apply to later queries
+    let value: i64 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(value, 1);
+
+    // and cannot be combined with a progress handler
+    conn.lock_handle()
+        .await?
+        .set_progress_handler(1000, || true);
+
+    let res = sqlx::query("SELECT 1")
+        .timeout(std::time::Duration::from_secs(1))
+        .execute(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Configuration(_))));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_continues_past_rows_which_fail_to_decode() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let results: Vec<_> = sqlx::query_scalar::<_, i64>(
+        "SELECT 1 UNION ALL SELECT 'two' UNION ALL SELECT 3 UNION ALL SELECT x'04'",
+    )
+    .fetch_lossy(&mut conn)
+    .try_collect()
+    .await?;
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].as_ref().ok(), Some(&1));
+    assert_eq!(results[2].as_ref().ok(), Some(&3));
+
+    let invalid: Vec<_> = results
+        .iter()
+        .filter_map(|res| res.as_ref().err())
+        .collect();
+
+    assert_eq!(invalid.len(), 2);
+    assert_eq!(invalid[0].index, 1);
+    assert_eq!(invalid[0].row.get::<String, _>(0), "two");
+    assert!(matches!(
+        invalid[0].source,
+        sqlx::Error::ColumnDecode { .. }
+    ));
+    assert_eq!(invalid[1].index, 3);
+
+    // errors which are not decoding errors still end the stream
+    let res: Result<Vec<_>, _> = sqlx::query_as::<_, (i64,)>("SELECT * FROM not_a_table")
+        .fetch_lossy(&mut conn)
+        .try_collect()
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_builds_nested_conditions() -> anyhow::Result<()> {
+    use sqlx::query_builder::Cond;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let mut builder = QueryBuilder::<Sqlite>::new(
+        "SELECT value FROM (SELECT 1 AS value UNION ALL SELECT 2 UNION ALL SELECT 3 UNION ALL SELECT 4) WHERE ",
+    );
+
+    builder
+        .push_cond(Cond::all([
+            Cond::sql("value > ").bind(1_i32),
+            Cond::any([
+                Cond::sql("value = ").bind(2_i32),
+                !Cond::sql("value < ").bind(4_i32),
+            ]),
+            Cond::any([]).push(" OR 1=1"),
+        ]))
+        .push(" ORDER BY value");
+
+    assert!(builder.sql().ends_with(
+        "WHERE (value > ?) AND ((value = ?) OR (NOT (value < ?))) AND ((1=0) OR 1=1) ORDER BY value"
+    ));
+
+    let values: Vec<i32> = builder.build_query_scalar().fetch_all(&mut conn).await?;
+
+    assert_eq!(values, [2, 4]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_passes_executed_statements_to_a_query_logger() -> anyhow::Result<()> {
+    use sqlx::QueryLogEntry;
+    use std::sync::Mutex;
+
+    let entries = Arc::new(Mutex::new(Vec::new()));
+
+    let pool: SqlitePool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .query_logger({
+            let entries = entries.clone();
+            Arc::new(move |entry: &QueryLogEntry<'_>| {
+                entries.lock().unwrap().push((
+                    entry.sql().to_owned(),
+                    entry.arguments(),
+                    entry.rows_returned(),
+                    entry.is_success(),
+                ));
+            })
+        })
+        .connect("sqlite::memory:")
+        .await?;
+
+    let values: Vec<i32> = sqlx::query_scalar("SELECT ?1 UNION ALL SELECT ?2")
+        .bind(1_i32)
+        .bind(2_i32)
+        .fetch_all(&pool)
+        .await?;
+    assert_eq!(values, [1, 2]);
+
+    let res = sqlx::query("SELECT * FROM not_a_table")
+        .execute(&pool)
+        .await;
+    assert!(res.is_err());
+
+    // the entry is passed to the logger after the result, on the connection's worker thread
+    pool.acquire().await?.ping().await?;
+
+    let entries = entries.lock().unwrap();
+    let logged: Vec<_> = entries
+        .iter()
+        .filter(|(sql, ..)| !sql.starts_with("PRAGMA"))
+        .cloned()
+        .collect();
+
+    assert_eq!(
+        logged,
+        [
+            ("SELECT ?1 UNION ALL SELECT ?2".to_owned(), 2, 2, true),
+            ("SELECT * FROM not_a_table".to_owned(), 0, 0, false),
+        ]
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fetches_rows_grouped_by_a_key_column() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let groups = sqlx::query(
+        "SELECT 'a' AS child, 2 AS parent UNION ALL SELECT 'b', 1 UNION ALL SELECT 'c', 2",
+    )
+    .fetch_grouped::<i64, (String,)>("parent", &mut conn)
+    .await?;
+
+    assert_eq!(
+        groups.into_iter().collect::<Vec<_>>(),
+        [
+            (2, vec![("a".to_owned(),), ("c".to_owned(),)]),
+            (1, vec![("b".to_owned(),)]),
+        ]
+    );
+
+    let res = sqlx::query("SELECT 1 AS parent")
+        .fetch_grouped::<i64, (i64,)>("not_a_column", &mut conn)
+        .await;
+    assert!(matches!(res, Err(sqlx::Error::ColumnNotFound(_))));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_exposes_the_number_of_statement_parameters() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let statement = conn.prepare("SELECT ?1, ?2, ?1").await?;
+    assert_eq!(statement.parameters().and_then(|p| p.right()), Some(2));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reads_a_database_through_a_custom_vfs() -> anyhow::Result<()> {
+    use futures::future::BoxFuture;
+    use sqlx::sqlite::vfs::{register_vfs, SqliteVfs, SqliteVfsFile};
+    use std::str::FromStr;
+    use std::{cmp, io};
+
+    // reads the whole file into memory when it is opened
+    struct InMemoryVfs;
+    struct InMemoryFile(Vec<u8>);
+
+    impl SqliteVfs for InMemoryVfs {
+        type File = InMemoryFile;
+
+        fn open<'a>(&'a self, path: &'a str) -> BoxFuture<'a, io::Result<InMemoryFile>> {
+            Box::pin(async move { std::fs::read(path).map(InMemoryFile) })
+        }
+    }
+
+    impl SqliteVfsFile for InMemoryFile {
+        fn size(&self) -> BoxFuture<'_, io::Result<u64>> {
+            Box::pin(async move { Ok(self.0.len() as u64) })
+        }
+
+        fn read_at<'a>(
+            &'a self,
+            offset: u64,
+            buf: &'a mut [u8],
+        ) -> BoxFuture<'a, io::Result<usize>> {
+            Box::pin(async move {
+                let start = cmp::min(offset as usize, self.0.len());
+                let end = cmp::min(start + buf.len(), self.0.len());
+
+                buf[..end - start].copy_from_slice(&self.0[start..end]);
+                Ok(end - start)
+            })
+        }
+    }
+
+    register_vfs("sqlx-test-in-memory", InMemoryVfs)?;
+
+    // the name is taken
+    assert!(register_vfs("sqlx-test-in-memory", InMemoryVfs).is_err());
+
+    let mut conn = SqliteConnectOptions::from_str(&dotenvy::var("DATABASE_URL")?)?
+        .vfs("sqlx-test-in-memory")
+        .read_only(true)
+        .connect()
+        .await?;
+
+    let name: String = sqlx::query_scalar("SELECT name FROM accounts WHERE id = 1")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(name, "Herp Derpinson");
+
+    // the files are read-only
+    assert!(conn
+        .execute("INSERT INTO accounts (name, is_active) VALUES ('vfs', true)")
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_decodes_bools_strictly_when_enabled() -> anyhow::Result<()> {
+    use std::str::FromStr;
+
+    for strict in [false, true] {
+        let mut conn = SqliteConnectOptions::from_str("sqlite::memory:")?
+            .strict_bools(strict)
+            .connect()
+            .await?;
+
+        let flags: Vec<Result<bool, _>> =
+            sqlx::query("SELECT 0 UNION ALL SELECT 1 UNION ALL SELECT 2")
+                .try_map(|row: SqliteRow| Ok(row.try_get::<bool, _>(0)))
+                .fetch_all(&mut conn)
+                .await?;
+
+        assert!(matches!(flags[0], Ok(false)));
+        assert!(matches!(flags[1], Ok(true)));
+        assert_eq!(flags[2].is_err(), strict);
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_runs_init_commands_on_every_new_connection() -> anyhow::Result<()> {
+    use std::str::FromStr;
+
+    let options = SqliteConnectOptions::from_str(
+        "sqlite::memory:?init-command=CREATE+TEMP+TABLE+scratch+(value+TEXT)",
+    )?
+    .init_command("INSERT INTO scratch (value) VALUES ('ready')");
+
+    assert_eq!(options.get_init_commands().len(), 2);
+
+    for _ in 0..2 {
+        let mut conn = options.connect().await?;
+
+        let values: Vec<String> = sqlx::query_scalar("SELECT value FROM scratch")
+            .fetch_all(&mut conn)
+            .await?;
+
+        assert_eq!(values, ["ready"]);
+    }
+
+    let error = SqliteConnectOptions::from_str("sqlite::memory:")?
+        .init_command("INSERT INTO missing (value) VALUES (1)")
+        .connect()
+        .await
+        .expect_err("a failing init command should fail the connection");
+
+    assert!(error.as_database_error().is_some());
+
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[sqlx_macros::test]
+async fn it_serializes_rows() -> anyhow::Result<()> {
+    use serde_json::json;
+    use sqlx::row::{BytesFormat, SerializeColumns, SerializeOptions, TimestampFormat};
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        "CREATE TEMPORARY TABLE serialized (flag BOOLEAN, created_at DATETIME, data BLOB)",
+    )
+    .await?;
+    conn.execute("INSERT INTO serialized VALUES (1, 1714566600, X'DEADBEEF')")
+        .await?;
+
+    let row = sqlx::query(
+        "SELECT 1 AS id, 'a' AS name, 1.5 AS score, NULL AS missing, * FROM serialized",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(
+        serde_json::to_value(&row)?,
+        json!({
+            "id": 1,
+            "name": "a",
+            "score": 1.5,
+            "missing": null,
+            "flag": true,
+            "created_at": "2024-05-01T12:30:00Z",
+            "data": "deadbeef",
+        })
+    );
+
+    let options = SerializeOptions::new()
+        .bytes(BytesFormat::Array)
+        .timestamps(TimestampFormat::UnixMillis);
+    let value = serde_json::to_value(row.serialize_with(options))?;
+
+    assert_eq!(value["created_at"], json!(1_714_566_600_000_i64));
+    assert_eq!(value["data"], json!([0xde, 0xad, 0xbe, 0xef]));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_splits_reads_and_writes() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqlitePoolOptionsExt;
+
+    let dir = tempfile::tempdir()?;
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(4)
+        .reader_writer_split()
+        .connect_with(
+            SqliteConnectOptions::new()
+                .filename(dir.path().join("split.db"))
+                .create_if_missing(true),
+        )
+        .await?;
+
+    pool.execute("CREATE TABLE events (name TEXT NOT NULL)")
+        .await?;
+
+    sqlx::query("INSERT INTO events (name) VALUES (?)")
+        .bind("login")
+        .execute(&pool)
+        .await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM events")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(count, 1);
+
+    let journal_mode: String = sqlx::query_scalar("PRAGMA journal_mode")
+        .fetch_one(pool.writer())
+        .await?;
+    assert_eq!(journal_mode, "wal");
+
+    // the readers cannot write, even when asked to directly
+    assert!(pool
+        .reader()
+        .execute("INSERT INTO events (name) VALUES ('logout')")
+        .await
+        .is_err());
+
+    // a reader sees the data while the writer is in a transaction
+    let mut tx = pool.begin().await?;
+    tx.execute("INSERT INTO events (name) VALUES ('logout')")
+        .await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM events")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(count, 1);
+
+    tx.commit().await?;
+
+    assert!(sqlx::sqlite::SqlitePoolOptions::new()
+        .reader_writer_split()
+        .connect("sqlite::memory:")
+        .await
+        .is_err());
+
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_routes_reads_to_replicas() -> anyhow::Result<()> {
+    let dir = tempfile::tempdir()?;
+
+    let primary_options = SqliteConnectOptions::new()
+        .filename(dir.path().join("primary.db"))
+        .create_if_missing(true);
+    let replica_options = SqliteConnectOptions::new()
+        .filename(dir.path().join("replica.db"))
+        .create_if_missing(true);
+    // fails to connect, as the directory doesn't exist
+    let missing_options =
+        SqliteConnectOptions::new().filename(dir.path().join("missing").join("replica.db"));
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(2)
+        .acquire_timeout(std::time::Duration::from_secs(5))
+        .connect_replicated_with(primary_options, [missing_options, replica_options])
+        .await?;
+
+    pool.execute("CREATE TABLE source (name TEXT NOT NULL)")
+        .await?;
+
+    // the first read fails over from the missing replica, which is then skipped
+    let one: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&pool).await?;
+    assert_eq!(one, 1);
+
+    // the replica has its own table, so we can tell which database a read went to
+    pool.read()
+        .execute("CREATE TABLE source (name TEXT NOT NULL); INSERT INTO source VALUES ('replica')")
+        .await?;
+
+    sqlx::query("INSERT INTO source (name) VALUES (?)")
+        .bind("primary")
+        .execute(&pool)
+        .await?;
+
+    for _ in 0..4 {
+        let name: String = sqlx::query_scalar("SELECT name FROM source")
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(name, "replica");
+    }
+
+    let name: String = sqlx::query_scalar("SELECT name FROM source")
+        .fetch_one(pool.write())
+        .await?;
+    assert_eq!(name, "primary");
+
+    // function calls may write, so they go to the primary unless routed explicitly
+    let name: String = sqlx::query_scalar("SELECT upper(name) FROM source")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(name, "PRIMARY");
+
+    let name: String = sqlx::query_scalar("SELECT upper(name) FROM source")
+        .fetch_one(&pool.on_replica())
+        .await?;
+    assert_eq!(name, "REPLICA");
+
+    let mut tx = pool.begin().await?;
+    let name: String = sqlx::query_scalar("SELECT name FROM source")
+        .fetch_one(&mut *tx)
+        .await?;
+    assert_eq!(name, "primary");
+    tx.rollback().await?;
+
+    let health = pool.health_check().await;
+    assert!(health[0].is_err());
+    assert!(health[1].is_ok());
+
+    pool.close().await;
+    assert!(pool.is_closed());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fails_over_from_a_busy_replica() -> anyhow::Result<()> {
+    use std::time::Duration;
+
+    let dir = tempfile::tempdir()?;
+
+    let primary_options = SqliteConnectOptions::new()
+        .filename(dir.path().join("primary.db"))
+        .create_if_missing(true);
+    let replica_options = SqliteConnectOptions::new()
+        .filename(dir.path().join("replica.db"))
+        .create_if_missing(true);
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .acquire_timeout(Duration::from_secs(30))
+        .connect_replicated_with(primary_options, [replica_options])
+        .await?
+        .replica_acquire_timeout(Duration::from_millis(50));
+
+    pool.execute("CREATE TABLE source (name TEXT NOT NULL); INSERT INTO source VALUES ('primary')")
+        .await?;
+    pool.read()
+        .execute("CREATE TABLE source (name TEXT NOT NULL); INSERT INTO source VALUES ('replica')")
+        .await?;
+
+    // while the only connection to the replica is in use, reads go to the primary
+    let held = pool.read().acquire().await?;
+
+    let name: String = sqlx::query_scalar("SELECT name FROM source")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(name, "primary");
+
+    drop(held);
+
+    // connections are returned to the pool in the background
+    while pool.read().num_idle() < 1 {
+        sqlx_core::rt::sleep(Duration::from_millis(1)).await;
+    }
+
+    // the replica was busy, not down, so it isn't skipped
+    let name: String = sqlx::query_scalar("SELECT name FROM source")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(name, "replica");
+
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_retries_queries_on_busy_errors() -> anyhow::Result<()> {
+    use sqlx::pool::RetryPolicy;
+    use std::time::Duration;
+
+    let dir = tempfile::tempdir()?;
+
+    let options = SqliteConnectOptions::new()
+        .filename(dir.path().join("busy.db"))
+        .create_if_missing(true)
+        .busy_timeout(Duration::ZERO);
+
+    let mut locker = SqliteConnection::connect_with(&options).await?;
+    locker
+        .execute("CREATE TABLE events (name TEXT NOT NULL)")
+        .await?;
+    locker.execute("BEGIN IMMEDIATE").await?;
+
+    // without a retry policy, the write fails while the other connection holds the lock
+    let pool = SqlitePoolOptions::new()
+        .connect_with(options.clone())
+        .await?;
+
+    let error = pool
+        .execute("INSERT INTO events (name) VALUES ('login')")
+        .await
+        .unwrap_err();
+    assert!(error.as_database_error().unwrap().is_retryable());
+
+    pool.close().await;
+
+    let pool = SqlitePoolOptions::new()
+        .retry_policy(
+            RetryPolicy::new()
+                .max_attempts(50)
+                .backoff(Duration::from_millis(10), Duration::from_millis(20))
+                .retry_queries(true),
+        )
+        .connect_with(options)
+        .await?;
+
+    let commit = sqlx_core::rt::spawn(async move {
+        sqlx_core::rt::sleep(Duration::from_millis(100)).await;
+        locker.execute("COMMIT").await.map(|_| locker)
+    });
+
+    sqlx::query("INSERT INTO events (name) VALUES (?)")
+        .bind("login")
+        .execute(&pool)
+        .await?;
+
+    commit.await?;
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM events")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(count, 1);
+
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_retries_transactions_on_busy_errors() -> anyhow::Result<()> {
+    use sqlx::pool::RetryPolicy;
+    use std::time::Duration;
+
+    let dir = tempfile::tempdir()?;
+
+    let options = SqliteConnectOptions::new()
+        .filename(dir.path().join("busy.db"))
+        .create_if_missing(true)
+        .busy_timeout(Duration::ZERO);
+
+    let mut locker = SqliteConnection::connect_with(&options).await?;
+    locker
+        .execute("CREATE TABLE events (name TEXT NOT NULL)")
+        .await?;
+    locker.execute("BEGIN IMMEDIATE").await?;
+
+    let pool = SqlitePoolOptions::new()
+        .retry_policy(
+            RetryPolicy::new()
+                .max_attempts(50)
+                .backoff(Duration::from_millis(10), Duration::from_millis(20)),
+        )
+        .connect_with(options)
+        .await?;
+
+    let commit = sqlx_core::rt::spawn(async move {
+        sqlx_core::rt::sleep(Duration::from_millis(100)).await;
+        locker.execute("COMMIT").await
+    });
+
+    let attempts = AtomicUsize::new(0);
+
+    let count: i64 = pool
+        .transaction_with_retry(IsolationLevel::Serializable, |txn| {
+            attempts.fetch_add(1, Ordering::SeqCst);
+
+            Box::pin(async move {
+                sqlx::query("INSERT INTO events (name) VALUES ('login')")
+                    .execute(&mut **txn)
+                    .await?;
+
+                sqlx::query_scalar("SELECT COUNT(*) FROM events")
+                    .fetch_one(&mut **txn)
+                    .await
+            })
+        })
+        .await?;
+
+    commit.await?;
+
+    assert_eq!(count, 1);
+    assert!(attempts.load(Ordering::SeqCst) > 1);
+
+    // other errors are returned without retrying
+    let attempts = AtomicUsize::new(0);
+
+    let error = pool
+        .transaction_with_retry(IsolationLevel::Serializable, |txn| {
+            attempts.fetch_add(1, Ordering::SeqCst);
+
+            Box::pin(async move {
+                sqlx::query("INSERT INTO missing (name) VALUES ('login')")
+                    .execute(&mut **txn)
+                    .await
+            })
+        })
+        .await
+        .unwrap_err();
+
+    assert!(!error.as_database_error().unwrap().is_retryable());
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM events")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(count, 1);
+
+    pool.close().await;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_placeholder_lists() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let mut builder = QueryBuilder::<Sqlite>::new(
+        "SELECT x FROM (SELECT 1 AS x UNION SELECT 2 UNION SELECT 3 UNION SELECT 4) WHERE x > ",
+    );
+    builder
+        .push_bind(1_i32)
+        .push(" AND x IN ")
+        .push_placeholder_list([1_i32, 2, 4])
+        .push(" ORDER BY x");
+
+    assert!(builder
+        .sql()
+        .ends_with("WHERE x > ? AND x IN (?, ?, ?) ORDER BY x"));
+
+    let xs: Vec<i32> = builder.build_query_scalar().fetch_all(&mut conn).await?;
+    assert_eq!(xs, [2, 4]);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_binds_borrowed_and_nested_options() -> anyhow::Result<()> {
+    struct Filter {
+        name: Option<String>,
+        age: Option<i32>,
+        nickname: Option<Option<String>>,
+    }
+
+    let filter = Filter {
+        name: Some("alice".into()),
+        age: None,
+        nickname: Some(None),
+    };
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let mut builder = QueryBuilder::<Sqlite>::new("SELECT ");
+    builder
+        .separated(", ")
+        .push_bind(filter.name.as_ref())
+        .push_bind(&filter.name)
+        .push_bind(filter.name.as_deref())
+        .push_bind(filter.age.as_ref())
+        .push_bind(&filter.nickname)
+        .push_bind(None::<Option<String>>)
+        .push_bind(Some(Some(filter.name.as_deref())));
+
+    let row = builder.build().fetch_one(&mut conn).await?;
+
+    assert_eq!(
+        row.try_get::<Option<String>, _>(0)?.as_deref(),
+        Some("alice")
+    );
+    assert_eq!(
+        row.try_get::<Option<String>, _>(1)?.as_deref(),
+        Some("alice")
+    );
+    assert_eq!(
+        row.try_get::<Option<String>, _>(2)?.as_deref(),
+        Some("alice")
+    );
+    assert_eq!(row.try_get::<Option<i32>, _>(3)?, None);
+    assert_eq!(row.try_get::<Option<String>, _>(4)?, None);
+    assert_eq!(row.try_get::<Option<String>, _>(5)?, None);
+    assert_eq!(
+        row.try_get::<Option<String>, _>(6)?.as_deref(),
+        Some("alice")
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_paginates_by_keyset_and_offset() -> anyhow::Result<()> {
+    use sqlx::query_builder::{Cond, Paginate};
+
+    let mut conn = new::<Sqlite>().await?;
+
+    sqlx::query(
+        "CREATE TEMPORARY TABLE posts (id INTEGER PRIMARY KEY, author TEXT NOT NULL, title TEXT NOT NULL)",
+    )
+    .execute(&mut conn)
+    .await?;
+
+    for id in 1..=7_i64 {
+        let author = if id % 2 == 0 { "even" } else { "odd" };
+
+        sqlx::query("INSERT INTO posts (id, author, title) VALUES (?, ?, ?)")
+            .bind(id)
+            .bind(author)
+            .bind(format!("post {id}"))
+            .execute(&mut conn)
+            .await?;
+    }
+
+    let mut token: Option<String> = None;
+    let mut pages = Vec::new();
+
+    loop {
+        let paginate = Paginate::<(String, i64)>::keyset(2, ["author", "id"])
+            .descending()
+            .resume(token.as_deref())
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        let mut builder = QueryBuilder::<Sqlite>::new("SELECT author, id FROM posts WHERE ");
+        builder
+            .push_cond(Cond::all([Cond::sql("id > ").bind(1_i64), paginate.cond()]))
+            .push_paginate(&paginate);
+
+        let rows: Vec<(String, i64)> = builder.build_query_as().fetch_all(&mut conn).await?;
+        let page = paginate.page(rows, |row| row.clone());
+
+        pages.push(page.items.iter().map(|(_, id)| *id).collect::<Vec<_>>());
+        assert_eq!(page.has_more, page.next_token.is_some());
+
+        token = page.next_token;
+
+        if token.is_none() {
+            break;
+        }
+    }
+
+    assert_eq!(pages, [vec![7, 5], vec![3, 6], vec![4, 2]]);
+
+    let paginate = Paginate::offset(3)
+        .resume(Some("3"))
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    let mut builder = QueryBuilder::<Sqlite>::new("SELECT id FROM posts ORDER BY id");
+    builder.push_paginate(&paginate);
+
+    assert!(builder.sql().ends_with(" LIMIT 4 OFFSET 3"));
+
+    let ids: Vec<i64> = builder.build_query_scalar().fetch_all(&mut conn).await?;
+    let page = paginate.page(ids, |_| ());
+
+    assert_eq!(page.items, [4, 5, 6]);
+    assert!(page.has_more);
+    assert_eq!(page.next_token.as_deref(), Some("6"));
+
+    Ok(())
+}
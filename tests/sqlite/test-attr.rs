@@ -1,6 +1,7 @@
 // The no-arg variant is covered by other tests already.
 
-use sqlx::{Row, SqlitePool};
+use sqlx::pool::PoolConnection;
+use sqlx::{Connection, Row, Sqlite, SqliteConnection, SqlitePool};
 
 const MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("tests/sqlite/migrations");
 
@@ -97,3 +98,87 @@ async fn it_gets_comments(pool: SqlitePool) -> sqlx::Result<()> {
 
     Ok(())
 }
+
+// Both of these insert the same user into the database they share, which only works
+// because each of them is rolled back.
+#[sqlx::test(rollback, migrator = "MIGRATOR", fixtures("users"))]
+async fn it_rolls_back_a_test(mut conn: PoolConnection<Sqlite>) -> sqlx::Result<()> {
+    sqlx::query(r#"INSERT INTO "user"(username) VALUES ('carol')"#)
+        .execute(&mut *conn)
+        .await?;
+
+    let usernames: Vec<String> =
+        sqlx::query_scalar(r#"SELECT username FROM "user" ORDER BY username"#)
+            .fetch_all(&mut *conn)
+            .await?;
+
+    assert_eq!(usernames, ["alice", "bob", "carol"]);
+
+    Ok(())
+}
+
+#[sqlx::test(rollback, migrator = "MIGRATOR", fixtures("users"))]
+async fn it_rolls_back_savepoints(mut conn: PoolConnection<Sqlite>) -> sqlx::Result<()> {
+    let mut tx = conn.begin().await?;
+
+    sqlx::query(r#"INSERT INTO "user"(username) VALUES ('carol')"#)
+        .execute(&mut *tx)
+        .await?;
+
+    // releases the savepoint, but not the transaction of the test
+    tx.commit().await?;
+
+    let mut tx = conn.begin().await?;
+
+    sqlx::query(r#"DELETE FROM "user" WHERE username = 'alice'"#)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.rollback().await?;
+
+    let usernames: Vec<String> =
+        sqlx::query_scalar(r#"SELECT username FROM "user" ORDER BY username"#)
+            .fetch_all(&mut *conn)
+            .await?;
+
+    assert_eq!(usernames, ["alice", "bob", "carol"]);
+
+    Ok(())
+}
+
+async fn seed_carol(conn: &mut SqliteConnection) -> sqlx::Result<()> {
+    // runs after the fixtures, so it can refer to their rows
+    sqlx::query(
+        r#"INSERT INTO "user"(username) SELECT 'carol' FROM "user" WHERE username = 'alice'"#,
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+#[sqlx::test(migrator = "MIGRATOR", fixtures("users"), seeder = seed_carol)]
+async fn it_runs_a_seeder(pool: SqlitePool) -> sqlx::Result<()> {
+    let usernames: Vec<String> =
+        sqlx::query_scalar(r#"SELECT username FROM "user" ORDER BY username"#)
+            .fetch_all(&pool)
+            .await?;
+
+    assert_eq!(usernames, ["alice", "bob", "carol"]);
+
+    Ok(())
+}
+
+#[sqlx::test(rollback, migrator = "MIGRATOR", fixtures("users"), seeder = seed_carol)]
+async fn it_runs_a_seeder_for_a_shared_database(
+    mut conn: PoolConnection<Sqlite>,
+) -> sqlx::Result<()> {
+    let usernames: Vec<String> =
+        sqlx::query_scalar(r#"SELECT username FROM "user" ORDER BY username"#)
+            .fetch_all(&mut *conn)
+            .await?;
+
+    assert_eq!(usernames, ["alice", "bob", "carol"]);
+
+    Ok(())
+}
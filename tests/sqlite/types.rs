@@ -1,5 +1,6 @@
 extern crate time_ as time;
 
+use sqlx::sqlite::types::SqliteDynamicValue;
 use sqlx::sqlite::{Sqlite, SqliteRow};
 use sqlx_core::executor::Executor;
 use sqlx_core::row::Row;
@@ -18,6 +19,22 @@ test_type!(i32(Sqlite, "94101" == 94101_i32));
 
 test_type!(i64(Sqlite, "9358295312" == 9358295312_i64));
 
+test_type!(dynamic<SqliteDynamicValue>(Sqlite,
+    "94101" == SqliteDynamicValue::Integer(94101),
+    "1.5" == SqliteDynamicValue::Real(1.5),
+    "'text'" == SqliteDynamicValue::Text("text".into()),
+    "x'0001'" == SqliteDynamicValue::Blob(vec![0, 1])
+));
+
+test_type!(non_zero_i32<std::num::NonZeroI32>(Sqlite,
+    "94101" == std::num::NonZeroI32::new(94101).unwrap()
+));
+
+test_type!(non_zero_u32_option<Option<std::num::NonZeroU32>>(Sqlite,
+    "NULL" == None::<std::num::NonZeroU32>,
+    "3000000000" == std::num::NonZeroU32::new(3000000000)
+));
+
 // NOTE: This behavior can be surprising. Floating-point parameters are widening to double which can
 //       result in strange rounding.
 test_type!(f32(Sqlite, "3.1410000324249268" == 3.141f32 as f64 as f32));